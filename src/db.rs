@@ -15,16 +15,15 @@ async fn configure_sqlite_pool(pool: &SqlitePool) -> Result<()> {
     Ok(())
 }
 
-/// Opens or creates the local SQLite pool at `~/.mostrix/mostrix.db`.
+/// Opens or creates the local SQLite pool at `~/.mostrix/mostrix.db` (or the active profile's).
 ///
 /// Applies WAL mode and a 5s busy timeout via [`configure_sqlite_pool`] to reduce
 /// `SQLITE_BUSY` failures under concurrent access.
 pub async fn init_db() -> Result<SqlitePool> {
     let pool: SqlitePool;
     let name = env!("CARGO_PKG_NAME");
-    let home_dir =
-        dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Unable to get home directory"))?;
-    let app_dir = home_dir.join(format!(".{}", name));
+    let app_dir = crate::settings::data_dir()
+        .ok_or_else(|| anyhow::anyhow!("Unable to get home directory"))?;
     let db_path = app_dir.join(format!("{}.db", name));
     let db_url = format!(
         "sqlite://{}",
@@ -107,7 +106,8 @@ pub async fn init_db() -> Result<SqlitePool> {
                 buyer_chat_last_seen INTEGER,
                 seller_chat_last_seen INTEGER,
                 buyer_shared_key_hex TEXT,
-                seller_shared_key_hex TEXT
+                seller_shared_key_hex TEXT,
+                bond_resolution TEXT
             );
             "#,
         )
@@ -129,10 +129,31 @@ pub async fn init_db() -> Result<SqlitePool> {
         // Run migrations for existing databases
         migrate_db(&pool).await?;
     }
+    create_book_cache_table(&pool).await?;
 
     Ok(pool)
 }
 
+/// Order book cache (`util/order_utils/book_cache.rs`): latest pending order event per Mostro
+/// instance and order id. Created on every start, so older databases pick it up too.
+pub async fn create_book_cache_table(pool: &SqlitePool) -> Result<()> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS book_cache (
+            mostro_pubkey TEXT NOT NULL,
+            order_id TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            expires_at INTEGER,
+            event_json TEXT NOT NULL,
+            PRIMARY KEY (mostro_pubkey, order_id)
+        );
+        "#,
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
 /// Run database migrations for existing databases
 async fn migrate_db(pool: &SqlitePool) -> Result<()> {
     // Migration: Add initiator_info and counterpart_info columns if they don't exist
@@ -180,6 +201,8 @@ async fn migrate_db(pool: &SqlitePool) -> Result<()> {
         check_column_exists(pool, "admin_disputes", "buyer_shared_key_hex").await?;
     let has_seller_shared_key_hex =
         check_column_exists(pool, "admin_disputes", "seller_shared_key_hex").await?;
+    let has_bond_resolution =
+        check_column_exists(pool, "admin_disputes", "bond_resolution").await?;
     let has_request_id = check_column_exists(pool, "orders", "request_id").await?;
     let has_trade_index = check_column_exists(pool, "orders", "trade_index").await?;
     let has_last_seen_dm_ts = check_column_exists(pool, "orders", "last_seen_dm_ts").await?;
@@ -199,6 +222,7 @@ async fn migrate_db(pool: &SqlitePool) -> Result<()> {
         || !has_seller_chat_last_seen
         || !has_buyer_shared_key_hex
         || !has_seller_shared_key_hex
+        || !has_bond_resolution
         || !has_request_id
         || !has_trade_index
         || !has_last_seen_dm_ts
@@ -302,6 +326,12 @@ async fn migrate_db(pool: &SqlitePool) -> Result<()> {
             .await?;
         }
 
+        if !has_bond_resolution {
+            sqlx::query("ALTER TABLE admin_disputes ADD COLUMN bond_resolution TEXT")
+                .execute(&mut *tx)
+                .await?;
+        }
+
         if !has_request_id {
             sqlx::query(
                 r#"
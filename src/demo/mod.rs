@@ -0,0 +1,227 @@
+//! `--demo`: run Mostrix against an in-process relay and a scripted Mostro daemon.
+//!
+//! [`DemoSession::start`] points the app at a throwaway home directory (settings, database,
+//! chat transcripts and downloads all live under it and are deleted on exit), starts a
+//! [`DemoRelay`] on `127.0.0.1` and a [`MostroSimulator`] connected to it. Nothing leaves the
+//! machine. The same relay and simulator drive the end-to-end tests in `tests/demo_tests.rs`.
+
+mod relay;
+mod simulator;
+
+pub use relay::DemoRelay;
+pub use simulator::{MostroSimulator, SimulatorConfig};
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use anyhow::Result;
+use bitcoin::hashes::{sha256, Hash};
+use bitcoin::secp256k1::{Secp256k1, SecretKey};
+use lightning_invoice::{Currency, InvoiceBuilder, PaymentSecret};
+use mostro_core::prelude::Transport;
+use nostr_sdk::prelude::*;
+
+use crate::settings::Settings;
+
+/// Currencies the simulator accepts (kind 38385 `fiat_currencies_accepted`).
+pub const DEMO_FIAT_CURRENCIES: [&str; 4] = ["USD", "EUR", "ARS", "VES"];
+
+/// Fixed BTC prices used instead of a price API; demo trades never reach the network.
+const DEMO_BTC_PRICES: [(&str, f64); 4] = [
+    ("USD", 100_000.0),
+    ("EUR", 92_000.0),
+    ("ARS", 120_000_000.0),
+    ("VES", 10_000_000.0),
+];
+
+/// Demo invoices stay valid for an hour, longer than any scripted trade.
+const DEMO_INVOICE_EXPIRY: Duration = Duration::from_secs(60 * 60);
+
+static DEMO_HOME: OnceLock<PathBuf> = OnceLock::new();
+
+/// Whether this process runs with `--demo`.
+pub fn is_demo_mode() -> bool {
+    DEMO_HOME.get().is_some()
+}
+
+/// Throwaway home directory used instead of the real one in demo mode.
+pub fn demo_home_dir() -> Option<PathBuf> {
+    DEMO_HOME.get().cloned()
+}
+
+/// Parse `--demo` / `--demo=v1` / `--demo=v2` from the command line.
+///
+/// `v2` (NIP-44 direct messages) is the default; `v1` runs the deprecated gift-wrap transport.
+pub fn demo_transport_from_args<I>(args: I) -> Result<Option<Transport>>
+where
+    I: IntoIterator<Item = String>,
+{
+    for arg in args {
+        match arg.as_str() {
+            "--demo" | "--demo=v2" => return Ok(Some(Transport::Nip44Direct)),
+            #[allow(deprecated)]
+            "--demo=v1" => return Ok(Some(Transport::GiftWrap)),
+            other if other.starts_with("--demo=") => {
+                anyhow::bail!(
+                    "Unknown demo protocol '{}': use --demo=v1 or --demo=v2",
+                    other
+                )
+            }
+            _ => {}
+        }
+    }
+    Ok(None)
+}
+
+/// Sats for `fiat_amount` at the fixed demo price, with `premium` percent applied.
+pub fn demo_market_sats(fiat_amount: i64, fiat_code: &str, premium: i64) -> i64 {
+    let price = DEMO_BTC_PRICES
+        .iter()
+        .find(|(code, _)| *code == fiat_code)
+        .map(|(_, price)| *price)
+        .unwrap_or(100_000.0);
+    let btc = fiat_amount as f64 / price;
+    let sats = btc * 100_000_000.0 / (1.0 + premium as f64 / 100.0);
+    sats.round().max(1.0) as i64
+}
+
+/// A signed regtest BOLT11 invoice for `sats`; it can be parsed and checked but never paid.
+pub fn demo_invoice(sats: i64, description: &str) -> String {
+    let secp = Secp256k1::new();
+    let node_key = SecretKey::from_slice(&Keys::generate().secret_key().to_secret_bytes())
+        .expect("nostr secret keys are valid secp256k1 keys");
+    let preimage = Keys::generate().secret_key().to_secret_bytes();
+    let payment_secret = PaymentSecret(Keys::generate().secret_key().to_secret_bytes());
+    InvoiceBuilder::new(Currency::Regtest)
+        .description(description.to_string())
+        .payment_hash(sha256::Hash::hash(&preimage))
+        .payment_secret(payment_secret)
+        .current_timestamp()
+        .min_final_cltv_expiry_delta(144)
+        .amount_milli_satoshis(sats.max(1) as u64 * 1000)
+        .expiry_time(DEMO_INVOICE_EXPIRY)
+        .build_signed(|hash| secp.sign_ecdsa_recoverable(hash, &node_key))
+        .expect("demo invoice fields are complete")
+        .to_string()
+}
+
+/// Relay, simulator and throwaway data directory for one `--demo` run.
+///
+/// Dropping the session stops the relay and simulator and deletes the directory.
+pub struct DemoSession {
+    home_dir: PathBuf,
+    relay: DemoRelay,
+    simulator: MostroSimulator,
+}
+
+impl DemoSession {
+    /// Create the throwaway home directory, then start the relay and the simulator.
+    ///
+    /// Must run before the database or settings are touched; a process can start one session.
+    pub async fn start(transport: Transport) -> Result<Self> {
+        let home_dir = std::env::temp_dir().join(format!("mostrix-demo-{}", std::process::id()));
+        if home_dir.exists() {
+            std::fs::remove_dir_all(&home_dir)?;
+        }
+        std::fs::create_dir_all(&home_dir)?;
+        DEMO_HOME
+            .set(home_dir.clone())
+            .map_err(|_| anyhow::anyhow!("Demo mode was already started in this process"))?;
+
+        let relay = DemoRelay::start().await?;
+        let simulator = MostroSimulator::start(
+            &relay.url(),
+            SimulatorConfig {
+                transport,
+                ..SimulatorConfig::default()
+            },
+        )
+        .await?;
+
+        Ok(Self {
+            home_dir,
+            relay,
+            simulator,
+        })
+    }
+
+    pub fn home_dir(&self) -> &Path {
+        &self.home_dir
+    }
+
+    pub fn relay(&self) -> &DemoRelay {
+        &self.relay
+    }
+
+    pub fn simulator(&self) -> &MostroSimulator {
+        &self.simulator
+    }
+
+    /// Write the demo `settings.toml` (local relay, simulator pubkey, `identity_keys` as nsec)
+    /// so the regular settings loader picks it up.
+    pub fn write_settings(&self, identity_keys: &Keys) -> Result<()> {
+        let settings = Settings {
+            mostro_pubkey: self.simulator.pubkey().to_hex(),
+            nsec_privkey: identity_keys.secret_key().to_bech32()?,
+            relays: vec![self.relay.url()],
+            ..Settings::default()
+        };
+        let dir = self.home_dir.join(format!(".{}", env!("CARGO_PKG_NAME")));
+        std::fs::create_dir_all(&dir)?;
+        std::fs::write(
+            dir.join("settings.toml"),
+            toml::to_string_pretty(&settings)?,
+        )?;
+        Ok(())
+    }
+}
+
+impl Drop for DemoSession {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_dir_all(&self.home_dir) {
+            log::warn!(
+                "Could not remove demo directory {}: {}",
+                self.home_dir.display(),
+                e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lightning_invoice::Bolt11Invoice;
+    use std::str::FromStr;
+
+    #[test]
+    fn demo_flag_selects_transport() {
+        let args = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(demo_transport_from_args(args(&["mostrix"])).unwrap(), None);
+        assert_eq!(
+            demo_transport_from_args(args(&["mostrix", "--demo"])).unwrap(),
+            Some(Transport::Nip44Direct)
+        );
+        #[allow(deprecated)]
+        let v1 = Transport::GiftWrap;
+        assert_eq!(
+            demo_transport_from_args(args(&["mostrix", "--demo=v1"])).unwrap(),
+            Some(v1)
+        );
+        assert!(demo_transport_from_args(args(&["mostrix", "--demo=v3"])).is_err());
+    }
+
+    #[test]
+    fn demo_invoice_parses_with_requested_amount() {
+        let invoice = Bolt11Invoice::from_str(&demo_invoice(21_000, "test")).unwrap();
+        assert_eq!(invoice.amount_milli_satoshis(), Some(21_000_000));
+        assert!(!invoice.is_expired());
+    }
+
+    #[test]
+    fn demo_market_sats_applies_premium() {
+        assert_eq!(demo_market_sats(100, "USD", 0), 100_000);
+        assert!(demo_market_sats(100, "USD", 5) < 100_000);
+    }
+}
@@ -0,0 +1,304 @@
+//! Minimal in-memory NIP-01 relay for `--demo` and end-to-end tests.
+//!
+//! Speaks just enough of the protocol for Mostrix and the simulator: `EVENT` (signature check,
+//! NIP-40 expiration, replaceable/addressable replacement, `OK`), `REQ` (stored matches honouring
+//! `limit`, then `EOSE`, then live events) and `CLOSE`. Nothing is persisted.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use futures::{SinkExt, StreamExt};
+use nostr_sdk::prelude::*;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+
+/// Live fan-out buffer; a subscriber lagging further behind misses events (like a real relay).
+const BROADCAST_CAPACITY: usize = 1024;
+
+#[derive(Default)]
+struct RelayStore {
+    events: Vec<Event>,
+}
+
+impl RelayStore {
+    /// Stores `event`, dropping the versions it replaces. Returns `false` for stale replacements.
+    fn insert(&mut self, event: &Event) -> bool {
+        if event.kind.is_ephemeral() {
+            return true;
+        }
+        if self.events.iter().any(|e| e.id == event.id) {
+            return true;
+        }
+        if event.kind.is_replaceable() || event.kind.is_addressable() {
+            let identifier = event.tags.identifier();
+            let same_slot = |e: &Event| {
+                e.kind == event.kind
+                    && e.pubkey == event.pubkey
+                    && (!event.kind.is_addressable() || e.tags.identifier() == identifier)
+            };
+            if self
+                .events
+                .iter()
+                .any(|e| same_slot(e) && e.created_at > event.created_at)
+            {
+                return false;
+            }
+            self.events.retain(|e| !same_slot(e));
+        }
+        self.events.push(event.clone());
+        true
+    }
+
+    /// Stored events matching any of `filters`, newest first, honouring each filter's `limit`.
+    fn query(&self, filters: &[Filter]) -> Vec<Event> {
+        let now = Timestamp::now();
+        let mut out: Vec<Event> = Vec::new();
+        for filter in filters {
+            let mut matches: Vec<&Event> = self
+                .events
+                .iter()
+                .filter(|e| !e.is_expired_at(now))
+                .filter(|e| filter.match_event(e, MatchEventOptions::new()))
+                .collect();
+            matches.sort_by_key(|e| std::cmp::Reverse(e.created_at));
+            if let Some(limit) = filter.limit {
+                matches.truncate(limit);
+            }
+            for event in matches {
+                if !out.iter().any(|e| e.id == event.id) {
+                    out.push(event.clone());
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Handle to a running demo relay; the listener task stops when this is dropped.
+pub struct DemoRelay {
+    addr: SocketAddr,
+    store: Arc<Mutex<RelayStore>>,
+    task: JoinHandle<()>,
+}
+
+impl DemoRelay {
+    /// Bind `127.0.0.1` on an ephemeral port and start accepting connections.
+    pub async fn start() -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let store = Arc::new(Mutex::new(RelayStore::default()));
+        let (live_tx, _) = broadcast::channel::<Event>(BROADCAST_CAPACITY);
+
+        let task_store = Arc::clone(&store);
+        let task = tokio::spawn(async move {
+            loop {
+                let Ok((stream, _)) = listener.accept().await else {
+                    continue;
+                };
+                let store = Arc::clone(&task_store);
+                let live_tx = live_tx.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = serve_connection(stream, store, live_tx).await {
+                        log::debug!("[demo relay] connection closed: {e}");
+                    }
+                });
+            }
+        });
+
+        log::info!("[demo relay] listening on ws://{addr}");
+        Ok(Self { addr, store, task })
+    }
+
+    /// `ws://127.0.0.1:<port>` URL to put in `relays`.
+    pub fn url(&self) -> String {
+        format!("ws://{}", self.addr)
+    }
+
+    /// Stored events matching `filter` (newest first); used by tests to inspect published state.
+    pub fn stored_events(&self, filter: Filter) -> Vec<Event> {
+        match self.store.lock() {
+            Ok(store) => store.query(&[filter]),
+            Err(_) => Vec::new(),
+        }
+    }
+}
+
+impl Drop for DemoRelay {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn serve_connection(
+    stream: TcpStream,
+    store: Arc<Mutex<RelayStore>>,
+    live_tx: broadcast::Sender<Event>,
+) -> Result<()> {
+    let mut ws = tokio_tungstenite::accept_async(stream).await?;
+    let mut live_rx = live_tx.subscribe();
+    let mut subscriptions: HashMap<SubscriptionId, Vec<Filter>> = HashMap::new();
+
+    loop {
+        tokio::select! {
+            incoming = ws.next() => {
+                let text = match incoming {
+                    Some(Ok(WsMessage::Text(text))) => text,
+                    Some(Ok(WsMessage::Close(_))) | None => return Ok(()),
+                    Some(Ok(_)) => continue,
+                    Some(Err(e)) => return Err(e.into()),
+                };
+                for reply in handle_client_message(text.as_str(), &store, &live_tx, &mut subscriptions) {
+                    ws.send(WsMessage::text(reply.as_json())).await?;
+                }
+            }
+            live = live_rx.recv() => {
+                let event = match live {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        log::warn!("[demo relay] subscriber lagged, skipped {skipped} events");
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return Ok(()),
+                };
+                for (sub_id, filters) in &subscriptions {
+                    if filters.iter().any(|f| f.match_event(&event, MatchEventOptions::new())) {
+                        let reply = RelayMessage::event(sub_id.clone(), event.clone());
+                        ws.send(WsMessage::text(reply.as_json())).await?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn handle_client_message(
+    text: &str,
+    store: &Arc<Mutex<RelayStore>>,
+    live_tx: &broadcast::Sender<Event>,
+    subscriptions: &mut HashMap<SubscriptionId, Vec<Filter>>,
+) -> Vec<RelayMessage<'static>> {
+    let message = match ClientMessage::from_json(text) {
+        Ok(message) => message,
+        Err(e) => return vec![RelayMessage::notice(format!("invalid message: {e}"))],
+    };
+
+    match message {
+        ClientMessage::Event(event) => {
+            let event = event.into_owned();
+            if let Err(e) = event.verify() {
+                return vec![RelayMessage::ok(event.id, false, format!("invalid: {e}"))];
+            }
+            if event.is_expired() {
+                return vec![RelayMessage::ok(event.id, false, "invalid: event expired")];
+            }
+            let accepted = match store.lock() {
+                Ok(mut store) => store.insert(&event),
+                Err(_) => false,
+            };
+            if !accepted {
+                return vec![RelayMessage::ok(
+                    event.id,
+                    false,
+                    "duplicate: newer version stored",
+                )];
+            }
+            let id = event.id;
+            // No receivers just means nobody is subscribed yet.
+            let _ = live_tx.send(event);
+            vec![RelayMessage::ok(id, true, "")]
+        }
+        ClientMessage::Req {
+            subscription_id,
+            filters,
+        } => {
+            let subscription_id = subscription_id.into_owned();
+            let filters: Vec<Filter> = filters.into_iter().map(|f| f.into_owned()).collect();
+            let stored = match store.lock() {
+                Ok(store) => store.query(&filters),
+                Err(_) => Vec::new(),
+            };
+            let mut replies: Vec<RelayMessage<'static>> = stored
+                .into_iter()
+                .map(|event| RelayMessage::event(subscription_id.clone(), event))
+                .collect();
+            replies.push(RelayMessage::eose(subscription_id.clone()));
+            subscriptions.insert(subscription_id, filters);
+            replies
+        }
+        ClientMessage::Close(subscription_id) => {
+            subscriptions.remove(subscription_id.as_ref());
+            Vec::new()
+        }
+        ClientMessage::Count {
+            subscription_id,
+            filter,
+        } => {
+            let count = match store.lock() {
+                Ok(store) => store.query(&[filter.into_owned()]).len(),
+                Err(_) => 0,
+            };
+            vec![RelayMessage::count(subscription_id.into_owned(), count)]
+        }
+        _ => vec![RelayMessage::notice("unsupported message")],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signed(keys: &Keys, builder: EventBuilder) -> Event {
+        builder.finalize(keys).expect("sign")
+    }
+
+    #[test]
+    fn addressable_events_replace_by_author_kind_and_identifier() {
+        let keys = Keys::generate();
+        let mut store = RelayStore::default();
+        let old = signed(
+            &keys,
+            EventBuilder::new(Kind::Custom(38383), "")
+                .tag(Tag::identifier("a"))
+                .custom_created_at(Timestamp::from(1_000)),
+        );
+        let other = signed(
+            &keys,
+            EventBuilder::new(Kind::Custom(38383), "").tag(Tag::identifier("b")),
+        );
+        let new = signed(
+            &keys,
+            EventBuilder::new(Kind::Custom(38383), "")
+                .tag(Tag::identifier("a"))
+                .custom_created_at(Timestamp::from(2_000)),
+        );
+        assert!(store.insert(&old));
+        assert!(store.insert(&other));
+        assert!(store.insert(&new));
+        assert!(!store.insert(&old), "older version must not replace newer");
+
+        let stored = store.query(&[Filter::new().kind(Kind::Custom(38383))]);
+        assert_eq!(stored.len(), 2);
+        assert!(stored.iter().any(|e| e.id == new.id));
+        assert!(!stored.iter().any(|e| e.id == old.id));
+    }
+
+    #[test]
+    fn query_honours_limit_newest_first() {
+        let keys = Keys::generate();
+        let mut store = RelayStore::default();
+        for ts in [10u64, 30, 20] {
+            store.insert(&signed(
+                &keys,
+                EventBuilder::new(Kind::TextNote, ts.to_string())
+                    .custom_created_at(Timestamp::from(ts)),
+            ));
+        }
+        let stored = store.query(&[Filter::new().kind(Kind::TextNote).limit(2)]);
+        let contents: Vec<&str> = stored.iter().map(|e| e.content.as_str()).collect();
+        assert_eq!(contents, vec!["30", "20"]);
+    }
+}
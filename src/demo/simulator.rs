@@ -0,0 +1,984 @@
+//! Scripted Mostro daemon for `--demo` and end-to-end tests.
+//!
+//! The simulator plays both the Mostro node and the counterparty of every trade. It publishes the
+//! instance info event (kind 38385) and order events (kind 38383), answers Mostrix requests over
+//! the configured [`Transport`], and — after [`SimulatorConfig::peer_delay`] — performs the
+//! counterparty's steps itself: taking your listings, paying hold invoices, sending fiat and
+//! releasing sats. Lightning is never touched; invoices are throwaway regtest BOLT11 strings.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use futures::StreamExt;
+use lightning_invoice::Bolt11Invoice;
+use mostro_core::prelude::*;
+use nostr_sdk::prelude::*;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+use super::{demo_invoice, demo_market_sats, DEMO_FIAT_CURRENCIES};
+
+/// Pending listings expire after a day, like a default mostrod install.
+const PENDING_ORDER_EXPIRATION_SECS: i64 = 24 * 60 * 60;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How the simulator behaves; [`Default`] is what `--demo` uses.
+#[derive(Debug, Clone)]
+pub struct SimulatorConfig {
+    /// Wire transport advertised in the info event and used for every DM.
+    pub transport: Transport,
+    /// Pause before each scripted counterparty step.
+    pub peer_delay: Duration,
+    /// Publish sample listings from the simulated counterparty on startup.
+    pub seed_order_book: bool,
+    /// Let the simulated counterparty take listings created from Mostrix.
+    pub peer_takes_new_orders: bool,
+}
+
+impl Default for SimulatorConfig {
+    fn default() -> Self {
+        Self {
+            transport: Transport::Nip44Direct,
+            peer_delay: Duration::from_secs(3),
+            seed_order_book: true,
+            peer_takes_new_orders: true,
+        }
+    }
+}
+
+/// Counterparty steps the simulator runs on its own after [`SimulatorConfig::peer_delay`].
+#[derive(Debug, Clone, Copy)]
+enum PeerStep {
+    TakeOrder,
+    PayHoldInvoice,
+    SendFiat,
+    Release,
+    AcceptCooperativeCancel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Buyer,
+    Seller,
+}
+
+struct SimTrade {
+    order: SmallOrder,
+    /// Trade key of the Mostrix user, once they made or took the order.
+    client: Option<PublicKey>,
+    client_is_maker: bool,
+    buyer_invoice_ready: bool,
+    hold_invoice_paid: bool,
+}
+
+impl SimTrade {
+    fn client_role(&self) -> Option<Role> {
+        self.client?;
+        let maker_is_buyer = self.order.kind == Some(mostro_core::order::Kind::Buy);
+        Some(if maker_is_buyer == self.client_is_maker {
+            Role::Buyer
+        } else {
+            Role::Seller
+        })
+    }
+
+    fn status(&self) -> Option<Status> {
+        self.order.status
+    }
+
+    fn set_status(&mut self, status: Status) {
+        self.order.status = Some(status);
+    }
+}
+
+/// Outgoing work collected under the trades lock and performed after releasing it.
+#[derive(Default)]
+struct Effects {
+    messages: Vec<(PublicKey, Message)>,
+    publish: Option<SmallOrder>,
+    dispute: Option<(Uuid, Role)>,
+    schedule: Option<(Uuid, PeerStep)>,
+}
+
+impl Effects {
+    fn send(mut self, to: PublicKey, message: Message) -> Self {
+        self.messages.push((to, message));
+        self
+    }
+
+    fn publish(mut self, order: &SmallOrder) -> Self {
+        self.publish = Some(order.clone());
+        self
+    }
+
+    fn schedule(mut self, order_id: Uuid, step: PeerStep) -> Self {
+        self.schedule = Some((order_id, step));
+        self
+    }
+}
+
+struct Engine {
+    keys: Keys,
+    peer_keys: Keys,
+    client: Client,
+    config: SimulatorConfig,
+    trades: Mutex<HashMap<Uuid, SimTrade>>,
+}
+
+/// Handle to a running simulator; its tasks stop when this is dropped.
+pub struct MostroSimulator {
+    engine: Arc<Engine>,
+    task: JoinHandle<()>,
+}
+
+impl MostroSimulator {
+    /// Connect to `relay_url`, publish the instance info (and sample listings) and start
+    /// answering DMs addressed to a freshly generated Mostro key.
+    pub async fn start(relay_url: &str, config: SimulatorConfig) -> Result<Self> {
+        let client = Client::new();
+        client.add_relay(relay_url).await?;
+        client.connect().and_wait(CONNECT_TIMEOUT).await;
+
+        let engine = Arc::new(Engine {
+            keys: Keys::generate(),
+            peer_keys: Keys::generate(),
+            client: client.clone(),
+            config,
+            trades: Mutex::new(HashMap::new()),
+        });
+
+        engine.publish_info().await?;
+        if engine.config.seed_order_book {
+            engine.seed_order_book().await?;
+        }
+
+        let mut notifications = client.notifications();
+        let inbox = Filter::new()
+            .kind(engine.config.transport.event_kind())
+            .pubkey(engine.keys.public_key());
+        client.subscribe(inbox).await?;
+
+        let task_engine = Arc::clone(&engine);
+        let task = tokio::spawn(async move {
+            while let Some(notification) = notifications.next().await {
+                let ClientNotification::Event { event, .. } = notification else {
+                    continue;
+                };
+                let engine = Arc::clone(&task_engine);
+                tokio::spawn(async move { engine.handle_event(*event).await });
+            }
+        });
+
+        log::info!(
+            "[demo mostro] running as {} over {}",
+            engine.keys.public_key(),
+            engine.config.transport
+        );
+        Ok(Self { engine, task })
+    }
+
+    /// Mostro pubkey to put in `mostro_pubkey`.
+    pub fn pubkey(&self) -> PublicKey {
+        self.engine.keys.public_key()
+    }
+
+    /// Trade pubkey the simulated counterparty uses (shown as the peer in trades).
+    pub fn peer_pubkey(&self) -> PublicKey {
+        self.engine.peer_keys.public_key()
+    }
+
+    pub fn transport(&self) -> Transport {
+        self.engine.config.transport
+    }
+
+    /// Current state of `order_id` as the simulator sees it.
+    pub async fn order(&self, order_id: Uuid) -> Option<SmallOrder> {
+        let trades = self.engine.trades.lock().await;
+        trades.get(&order_id).map(|t| t.order.clone())
+    }
+
+    /// Listings the simulated counterparty published and nobody took yet.
+    pub async fn pending_peer_orders(&self) -> Vec<SmallOrder> {
+        let trades = self.engine.trades.lock().await;
+        let mut orders: Vec<SmallOrder> = trades
+            .values()
+            .filter(|t| t.client.is_none() && t.status() == Some(Status::Pending))
+            .map(|t| t.order.clone())
+            .collect();
+        orders.sort_by_key(|o| o.id);
+        orders
+    }
+}
+
+impl Drop for MostroSimulator {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+impl Engine {
+    async fn publish_info(&self) -> Result<()> {
+        let transport = self.config.transport;
+        let tags = [
+            Tag::identifier(self.keys.public_key().to_hex()),
+            custom_tag("mostro_version", "demo"),
+            custom_tag("protocol_version", transport.protocol_version().to_string()),
+            custom_tag("pow", "0"),
+            custom_tag("fee", "0.006"),
+            custom_tag("bond_enabled", "false"),
+            custom_tag("min_order_amount", "100"),
+            custom_tag("max_order_amount", "1000000"),
+            custom_tag("expiration_hours", "24"),
+            custom_tag("expiration_seconds", "900"),
+            custom_tag("max_orders_per_response", "10"),
+            custom_tag("fiat_currencies_accepted", DEMO_FIAT_CURRENCIES.join(",")),
+            custom_tag("lnd_node_alias", "mostrix-demo"),
+            custom_tag("lnd_networks", "regtest"),
+        ];
+        let event = EventBuilder::new(nostr_sdk::prelude::Kind::Custom(NOSTR_INFO_EVENT_KIND), "")
+            .tags(tags)
+            .finalize(&self.keys)?;
+        self.client.send_event(&event).await?;
+        Ok(())
+    }
+
+    async fn seed_order_book(&self) -> Result<()> {
+        use mostro_core::order::Kind as OrderKind;
+
+        let now = Timestamp::now().as_secs() as i64;
+        let peer = self.peer_keys.public_key().to_hex();
+        let samples = [
+            (OrderKind::Sell, "USD", None, 50, "Bank transfer", 1),
+            (OrderKind::Sell, "EUR", None, 30, "SEPA,Revolut", 0),
+            (
+                OrderKind::Sell,
+                "ARS",
+                Some((10_000, 100_000)),
+                0,
+                "MercadoPago",
+                2,
+            ),
+            (OrderKind::Buy, "USD", None, 25, "Zelle", -1),
+            (OrderKind::Buy, "VES", None, 2_000, "Pago movil", 0),
+        ];
+
+        let mut trades = self.trades.lock().await;
+        for (kind, fiat_code, range, fiat_amount, payment_method, premium) in samples {
+            let (min_amount, max_amount) = range.unzip();
+            let (buyer_trade_pubkey, seller_trade_pubkey) = match kind {
+                OrderKind::Buy => (Some(peer.clone()), None),
+                OrderKind::Sell => (None, Some(peer.clone())),
+            };
+            let order = SmallOrder::new(
+                Some(Uuid::new_v4()),
+                Some(kind),
+                Some(Status::Pending),
+                0,
+                fiat_code.to_string(),
+                min_amount,
+                max_amount,
+                fiat_amount,
+                payment_method.to_string(),
+                premium,
+                buyer_trade_pubkey,
+                seller_trade_pubkey,
+                None,
+                Some(now),
+                Some(now + PENDING_ORDER_EXPIRATION_SECS),
+            );
+            self.publish_order(&order).await?;
+            if let Some(id) = order.id {
+                trades.insert(
+                    id,
+                    SimTrade {
+                        order,
+                        client: None,
+                        client_is_maker: false,
+                        // The simulated buyer always has its payout invoice ready.
+                        buyer_invoice_ready: kind == OrderKind::Buy,
+                        hold_invoice_paid: false,
+                    },
+                );
+            }
+        }
+        Ok(())
+    }
+
+    async fn publish_order(&self, order: &SmallOrder) -> Result<()> {
+        let Some(id) = order.id else {
+            return Ok(());
+        };
+        let mut tags = vec![Tag::identifier(id.to_string())];
+        if let Some(kind) = order.kind {
+            tags.push(custom_tag("k", kind.to_string()));
+        }
+        tags.push(custom_tag("f", order.fiat_code.clone()));
+        if let Some(status) = order.status {
+            tags.push(custom_tag("s", status.to_string()));
+        }
+        tags.push(custom_tag("amt", order.amount.to_string()));
+        match (order.min_amount, order.max_amount) {
+            (Some(min), Some(max)) => {
+                tags.push(Tag::custom("fa", [min.to_string(), max.to_string()]))
+            }
+            _ => tags.push(custom_tag("fa", order.fiat_amount.to_string())),
+        }
+        tags.push(Tag::custom(
+            "pm",
+            order
+                .payment_method
+                .split(',')
+                .map(|m| m.trim().to_string()),
+        ));
+        tags.push(custom_tag("premium", order.premium.to_string()));
+        tags.push(custom_tag("network", "regtest"));
+        tags.push(custom_tag("layer", "lightning"));
+        if let Some(expires_at) = order.expires_at {
+            if order.status == Some(Status::Pending) {
+                tags.push(Tag::expiration(Timestamp::from(expires_at.max(0) as u64)));
+            }
+        }
+        tags.push(custom_tag("y", "mostro"));
+        tags.push(custom_tag("z", "order"));
+
+        let event = EventBuilder::new(nostr_sdk::prelude::Kind::Custom(NOSTR_ORDER_EVENT_KIND), "")
+            .tags(tags)
+            .finalize(&self.keys)?;
+        self.client.send_event(&event).await?;
+        Ok(())
+    }
+
+    async fn publish_dispute(&self, dispute_id: Uuid, initiator: Role) -> Result<()> {
+        let initiator = match initiator {
+            Role::Buyer => "buyer",
+            Role::Seller => "seller",
+        };
+        let tags = [
+            Tag::identifier(dispute_id.to_string()),
+            custom_tag("s", "initiated"),
+            custom_tag("initiator", initiator),
+            custom_tag("y", "mostro"),
+            custom_tag("z", "dispute"),
+        ];
+        let event = EventBuilder::new(
+            nostr_sdk::prelude::Kind::Custom(NOSTR_DISPUTE_EVENT_KIND),
+            "",
+        )
+        .tags(tags)
+        .finalize(&self.keys)?;
+        self.client.send_event(&event).await?;
+        Ok(())
+    }
+
+    async fn send(&self, to: PublicKey, message: &Message) {
+        let wrapped = wrap_message_with(
+            self.config.transport,
+            message,
+            &self.keys,
+            &self.keys,
+            to,
+            WrapOptions::default(),
+        )
+        .await;
+        match wrapped {
+            Ok(event) => {
+                if let Err(e) = self.client.send_event(&event).await {
+                    log::warn!("[demo mostro] failed to publish reply: {e}");
+                }
+            }
+            Err(e) => log::warn!("[demo mostro] failed to wrap reply: {e}"),
+        }
+    }
+
+    /// Publish `effects`; returns the counterparty step to run next, if any.
+    ///
+    /// Order and dispute events go out before the DMs, so a client reacting to a DM already sees
+    /// the new state on the relay.
+    async fn deliver(&self, effects: Effects) -> Option<(Uuid, PeerStep)> {
+        if let Some(order) = &effects.publish {
+            if let Err(e) = self.publish_order(order).await {
+                log::warn!("[demo mostro] failed to publish order event: {e}");
+            }
+        }
+        if let Some((dispute_id, initiator)) = effects.dispute {
+            if let Err(e) = self.publish_dispute(dispute_id, initiator).await {
+                log::warn!("[demo mostro] failed to publish dispute event: {e}");
+            }
+        }
+        for (to, message) in &effects.messages {
+            self.send(*to, message).await;
+        }
+        effects.schedule
+    }
+
+    async fn apply(self: &Arc<Self>, effects: Effects) {
+        let Some(first) = self.deliver(effects).await else {
+            return;
+        };
+        let engine = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut next = Some(first);
+            while let Some((order_id, step)) = next {
+                tokio::time::sleep(engine.config.peer_delay).await;
+                let effects = engine.run_peer_step(order_id, step).await;
+                next = engine.deliver(effects).await;
+            }
+        });
+    }
+
+    async fn handle_event(self: Arc<Self>, event: Event) {
+        let unwrapped = match unwrap_incoming(&event, &self.keys).await {
+            Ok(Some(unwrapped)) => unwrapped,
+            Ok(None) => return,
+            Err(e) => {
+                log::debug!("[demo mostro] ignoring event {}: {e}", event.id);
+                return;
+            }
+        };
+        let sender = unwrapped.sender;
+        let kind = unwrapped.message.get_inner_message_kind().clone();
+        log::info!("[demo mostro] {:?} from {}", kind.action, sender);
+
+        let mut trades = self.trades.lock().await;
+        let outcome = match kind.action {
+            Action::NewOrder => self.new_order(&mut trades, sender, &kind),
+            Action::TakeSell | Action::TakeBuy => self.take_order(&mut trades, sender, &kind),
+            Action::AddInvoice => add_invoice(&mut trades, sender, &kind),
+            Action::FiatSent => fiat_sent(&mut trades, sender, &kind, self.peer_pubkey()),
+            Action::Release => release(&mut trades, sender, &kind),
+            Action::Dispute => dispute(&mut trades, sender, &kind),
+            Action::RateUser => rate_user(&mut trades, sender, &kind),
+            Action::Cancel => cancel(&mut trades, sender, &kind),
+            _ => Err(CantDoReason::InvalidAction),
+        };
+        drop(trades);
+
+        let effects = outcome.unwrap_or_else(|reason| {
+            log::info!("[demo mostro] cant-do {:?}: {:?}", kind.action, reason);
+            Effects::default().send(
+                sender,
+                Message::cant_do(
+                    kind.id,
+                    kind.request_id,
+                    Some(Payload::CantDo(Some(reason))),
+                ),
+            )
+        });
+        self.apply(effects).await;
+    }
+
+    fn peer_pubkey(&self) -> PublicKey {
+        self.peer_keys.public_key()
+    }
+
+    fn new_order(
+        &self,
+        trades: &mut HashMap<Uuid, SimTrade>,
+        sender: PublicKey,
+        kind: &MessageKind,
+    ) -> Result<Effects, CantDoReason> {
+        let Some(Payload::Order(requested)) = &kind.payload else {
+            return Err(CantDoReason::InvalidParameters);
+        };
+        let order_kind = requested.kind.ok_or(CantDoReason::InvalidOrderKind)?;
+        if !DEMO_FIAT_CURRENCIES.contains(&requested.fiat_code.as_str()) {
+            return Err(CantDoReason::InvalidFiatCurrency);
+        }
+        match (requested.min_amount, requested.max_amount) {
+            (Some(min), Some(max)) if min <= 0 || min >= max => {
+                return Err(CantDoReason::InvalidAmount)
+            }
+            (Some(_), Some(_)) => {}
+            _ if requested.fiat_amount <= 0 => return Err(CantDoReason::InvalidAmount),
+            _ => {}
+        }
+
+        let now = Timestamp::now().as_secs() as i64;
+        let mut order = requested.clone();
+        let order_id = Uuid::new_v4();
+        order.id = Some(order_id);
+        order.status = Some(Status::Pending);
+        order.created_at = Some(now);
+        order.expires_at = Some(now + PENDING_ORDER_EXPIRATION_SECS);
+        let buyer_invoice_ready = order.buyer_invoice.is_some();
+        order.buyer_invoice = None;
+        match order_kind {
+            mostro_core::order::Kind::Buy => order.buyer_trade_pubkey = Some(sender.to_hex()),
+            mostro_core::order::Kind::Sell => order.seller_trade_pubkey = Some(sender.to_hex()),
+        }
+
+        let reply = Message::new_order(
+            Some(order_id),
+            kind.request_id,
+            kind.trade_index,
+            Action::NewOrder,
+            Some(Payload::Order(order.clone())),
+        );
+        let mut effects = Effects::default().send(sender, reply).publish(&order);
+        if self.config.peer_takes_new_orders {
+            effects = effects.schedule(order_id, PeerStep::TakeOrder);
+        }
+        trades.insert(
+            order_id,
+            SimTrade {
+                order,
+                client: Some(sender),
+                client_is_maker: true,
+                buyer_invoice_ready,
+                hold_invoice_paid: false,
+            },
+        );
+        Ok(effects)
+    }
+
+    fn take_order(
+        &self,
+        trades: &mut HashMap<Uuid, SimTrade>,
+        sender: PublicKey,
+        kind: &MessageKind,
+    ) -> Result<Effects, CantDoReason> {
+        let order_id = kind.id.ok_or(CantDoReason::InvalidParameters)?;
+        let trade = trades.get_mut(&order_id).ok_or(CantDoReason::NotFound)?;
+        if trade.client.is_some() {
+            return Err(CantDoReason::InvalidPeer);
+        }
+        if trade.status() != Some(Status::Pending) {
+            return Err(CantDoReason::NotAllowedByStatus);
+        }
+        let expected_kind = match kind.action {
+            Action::TakeSell => mostro_core::order::Kind::Sell,
+            _ => mostro_core::order::Kind::Buy,
+        };
+        if trade.order.kind != Some(expected_kind) {
+            return Err(CantDoReason::InvalidOrderKind);
+        }
+
+        let (fiat_amount, invoice) = match &kind.payload {
+            Some(Payload::Amount(amount)) if *amount > 0 => (Some(*amount), None),
+            Some(Payload::PaymentRequest(_, invoice, amount)) => (*amount, Some(invoice.clone())),
+            _ => (None, None),
+        };
+        if let (Some(min), Some(max)) = (trade.order.min_amount, trade.order.max_amount) {
+            let amount = fiat_amount.ok_or(CantDoReason::OutOfRangeFiatAmount)?;
+            if amount < min || amount > max {
+                return Err(CantDoReason::OutOfRangeFiatAmount);
+            }
+            trade.order.fiat_amount = amount;
+        }
+        if let Some(invoice) = &invoice {
+            if Bolt11Invoice::from_str(invoice).is_err() {
+                return Err(CantDoReason::InvalidInvoice);
+            }
+        }
+        if trade.order.amount == 0 {
+            trade.order.amount = demo_market_sats(
+                trade.order.fiat_amount,
+                &trade.order.fiat_code,
+                trade.order.premium,
+            );
+        }
+        trade.client = Some(sender);
+        trade.client_is_maker = false;
+
+        let effects = if expected_kind == mostro_core::order::Kind::Sell {
+            // Client is the buyer; the simulated seller pays the hold invoice once the payout
+            // invoice is known.
+            trade.order.buyer_trade_pubkey = Some(sender.to_hex());
+            if invoice.is_some() {
+                trade.buyer_invoice_ready = true;
+                trade.set_status(Status::WaitingPayment);
+                let reply = order_message(
+                    &trade.order,
+                    kind.request_id,
+                    Action::WaitingSellerToPay,
+                    None,
+                );
+                Effects::default()
+                    .send(sender, reply)
+                    .schedule(order_id, PeerStep::PayHoldInvoice)
+            } else {
+                trade.set_status(Status::WaitingBuyerInvoice);
+                let payload = Payload::Order(trade.order.clone());
+                let reply = order_message(
+                    &trade.order,
+                    kind.request_id,
+                    Action::AddInvoice,
+                    Some(payload),
+                );
+                Effects::default().send(sender, reply)
+            }
+        } else {
+            // Client is the seller: hand out a hold invoice and pay it on their behalf.
+            trade.order.seller_trade_pubkey = Some(sender.to_hex());
+            trade.set_status(Status::WaitingPayment);
+            let payload = Payload::PaymentRequest(
+                Some(trade.order.clone()),
+                demo_invoice(trade.order.amount, "Mostro demo hold invoice"),
+                None,
+            );
+            let reply = order_message(
+                &trade.order,
+                kind.request_id,
+                Action::PayInvoice,
+                Some(payload),
+            );
+            Effects::default()
+                .send(sender, reply)
+                .schedule(order_id, PeerStep::PayHoldInvoice)
+        };
+        Ok(effects.publish(&trade.order))
+    }
+
+    /// Run a scripted counterparty step; stale steps (state moved on meanwhile) are no-ops.
+    async fn run_peer_step(&self, order_id: Uuid, step: PeerStep) -> Effects {
+        let mut trades = self.trades.lock().await;
+        let Some(trade) = trades.get_mut(&order_id) else {
+            return Effects::default();
+        };
+        let Some(client) = trade.client else {
+            return Effects::default();
+        };
+        let role = trade.client_role();
+        let peer = self.peer_pubkey();
+
+        match (step, trade.status()) {
+            (PeerStep::TakeOrder, Some(Status::Pending)) => {
+                if trade.order.amount == 0 {
+                    trade.order.amount = demo_market_sats(
+                        trade
+                            .order
+                            .fiat_amount
+                            .max(trade.order.min_amount.unwrap_or(0)),
+                        &trade.order.fiat_code,
+                        trade.order.premium,
+                    );
+                }
+                if let (Some(min), Some(_)) = (trade.order.min_amount, trade.order.max_amount) {
+                    trade.order.fiat_amount = min;
+                }
+                trade.set_status(Status::WaitingPayment);
+                let effects = if role == Some(Role::Seller) {
+                    // Simulated buyer takes with its payout invoice; the client pays the hold invoice.
+                    trade.order.buyer_trade_pubkey = Some(peer.to_hex());
+                    trade.buyer_invoice_ready = true;
+                    let payload = Payload::PaymentRequest(
+                        Some(trade.order.clone()),
+                        demo_invoice(trade.order.amount, "Mostro demo hold invoice"),
+                        None,
+                    );
+                    Effects::default().send(
+                        client,
+                        order_message(&trade.order, None, Action::PayInvoice, Some(payload)),
+                    )
+                } else {
+                    trade.order.seller_trade_pubkey = Some(peer.to_hex());
+                    Effects::default().send(
+                        client,
+                        order_message(&trade.order, None, Action::WaitingSellerToPay, None),
+                    )
+                };
+                effects
+                    .publish(&trade.order)
+                    .schedule(order_id, PeerStep::PayHoldInvoice)
+            }
+            (PeerStep::PayHoldInvoice, Some(Status::WaitingPayment)) => {
+                trade.hold_invoice_paid = true;
+                if !trade.buyer_invoice_ready {
+                    trade.set_status(Status::WaitingBuyerInvoice);
+                    let payload = Payload::Order(trade.order.clone());
+                    return Effects::default()
+                        .send(
+                            client,
+                            order_message(&trade.order, None, Action::AddInvoice, Some(payload)),
+                        )
+                        .publish(&trade.order);
+                }
+                trade.set_status(Status::Active);
+                let payload = Some(Payload::Order(trade.order.clone()));
+                match role {
+                    Some(Role::Buyer) => Effects::default().send(
+                        client,
+                        order_message(
+                            &trade.order,
+                            None,
+                            Action::HoldInvoicePaymentAccepted,
+                            payload,
+                        ),
+                    ),
+                    _ => Effects::default()
+                        .send(
+                            client,
+                            order_message(&trade.order, None, Action::BuyerTookOrder, payload),
+                        )
+                        .schedule(order_id, PeerStep::SendFiat),
+                }
+                .publish(&trade.order)
+            }
+            (PeerStep::SendFiat, Some(Status::Active)) => {
+                trade.set_status(Status::FiatSent);
+                let payload = Payload::Peer(Peer::new(peer.to_hex(), None));
+                Effects::default()
+                    .send(
+                        client,
+                        order_message(&trade.order, None, Action::FiatSentOk, Some(payload)),
+                    )
+                    .publish(&trade.order)
+            }
+            (PeerStep::Release, Some(Status::FiatSent)) => {
+                trade.set_status(Status::Success);
+                Effects::default()
+                    .send(
+                        client,
+                        order_message(&trade.order, None, Action::Released, None),
+                    )
+                    .send(
+                        client,
+                        order_message(&trade.order, None, Action::PurchaseCompleted, None),
+                    )
+                    .send(
+                        client,
+                        order_message(&trade.order, None, Action::Rate, None),
+                    )
+                    .publish(&trade.order)
+            }
+            (PeerStep::AcceptCooperativeCancel, Some(Status::Active | Status::FiatSent)) => {
+                trade.set_status(Status::CooperativelyCanceled);
+                Effects::default()
+                    .send(
+                        client,
+                        order_message(&trade.order, None, Action::CooperativeCancelAccepted, None),
+                    )
+                    .publish(&trade.order)
+            }
+            _ => Effects::default(),
+        }
+    }
+}
+
+fn custom_tag(key: &str, value: impl Into<String>) -> Tag {
+    Tag::custom(key, [value.into()])
+}
+
+fn order_message(
+    order: &SmallOrder,
+    request_id: Option<u64>,
+    action: Action,
+    payload: Option<Payload>,
+) -> Message {
+    Message::new_order(order.id, request_id, None, action, payload)
+}
+
+/// The trade `kind.id` refers to, provided `sender` is the Mostrix party in it.
+fn client_trade<'a>(
+    trades: &'a mut HashMap<Uuid, SimTrade>,
+    sender: PublicKey,
+    kind: &MessageKind,
+) -> Result<&'a mut SimTrade, CantDoReason> {
+    let order_id = kind.id.ok_or(CantDoReason::InvalidParameters)?;
+    let trade = trades.get_mut(&order_id).ok_or(CantDoReason::NotFound)?;
+    if trade.client != Some(sender) {
+        return Err(CantDoReason::IsNotYourOrder);
+    }
+    Ok(trade)
+}
+
+fn add_invoice(
+    trades: &mut HashMap<Uuid, SimTrade>,
+    sender: PublicKey,
+    kind: &MessageKind,
+) -> Result<Effects, CantDoReason> {
+    let trade = client_trade(trades, sender, kind)?;
+    if trade.client_role() != Some(Role::Buyer) {
+        return Err(CantDoReason::InvalidPeer);
+    }
+    if trade.status() != Some(Status::WaitingBuyerInvoice) {
+        return Err(CantDoReason::NotAllowedByStatus);
+    }
+    let Some(Payload::PaymentRequest(_, invoice, _)) = &kind.payload else {
+        return Err(CantDoReason::InvalidInvoice);
+    };
+    if Bolt11Invoice::from_str(invoice).is_err() {
+        return Err(CantDoReason::InvalidInvoice);
+    }
+    trade.buyer_invoice_ready = true;
+
+    let order_id = kind.id.unwrap_or_default();
+    let effects = if trade.hold_invoice_paid {
+        trade.set_status(Status::Active);
+        let payload = Payload::Order(trade.order.clone());
+        Effects::default().send(
+            sender,
+            order_message(
+                &trade.order,
+                kind.request_id,
+                Action::HoldInvoicePaymentAccepted,
+                Some(payload),
+            ),
+        )
+    } else {
+        trade.set_status(Status::WaitingPayment);
+        Effects::default()
+            .send(
+                sender,
+                order_message(
+                    &trade.order,
+                    kind.request_id,
+                    Action::WaitingSellerToPay,
+                    None,
+                ),
+            )
+            .schedule(order_id, PeerStep::PayHoldInvoice)
+    };
+    Ok(effects.publish(&trade.order))
+}
+
+fn fiat_sent(
+    trades: &mut HashMap<Uuid, SimTrade>,
+    sender: PublicKey,
+    kind: &MessageKind,
+    peer: PublicKey,
+) -> Result<Effects, CantDoReason> {
+    let trade = client_trade(trades, sender, kind)?;
+    if trade.client_role() != Some(Role::Buyer) {
+        return Err(CantDoReason::InvalidPeer);
+    }
+    if trade.status() != Some(Status::Active) {
+        return Err(CantDoReason::NotAllowedByStatus);
+    }
+    trade.set_status(Status::FiatSent);
+    let payload = Payload::Peer(Peer::new(peer.to_hex(), None));
+    Ok(Effects::default()
+        .send(
+            sender,
+            order_message(
+                &trade.order,
+                kind.request_id,
+                Action::FiatSentOk,
+                Some(payload),
+            ),
+        )
+        .publish(&trade.order)
+        .schedule(kind.id.unwrap_or_default(), PeerStep::Release))
+}
+
+fn release(
+    trades: &mut HashMap<Uuid, SimTrade>,
+    sender: PublicKey,
+    kind: &MessageKind,
+) -> Result<Effects, CantDoReason> {
+    let trade = client_trade(trades, sender, kind)?;
+    if trade.client_role() != Some(Role::Seller) {
+        return Err(CantDoReason::InvalidPeer);
+    }
+    if !matches!(trade.status(), Some(Status::Active | Status::FiatSent)) {
+        return Err(CantDoReason::NotAllowedByStatus);
+    }
+    trade.set_status(Status::Success);
+    Ok(Effects::default()
+        .send(
+            sender,
+            order_message(
+                &trade.order,
+                kind.request_id,
+                Action::HoldInvoicePaymentSettled,
+                None,
+            ),
+        )
+        .send(
+            sender,
+            order_message(&trade.order, None, Action::Rate, None),
+        )
+        .publish(&trade.order))
+}
+
+fn dispute(
+    trades: &mut HashMap<Uuid, SimTrade>,
+    sender: PublicKey,
+    kind: &MessageKind,
+) -> Result<Effects, CantDoReason> {
+    let trade = client_trade(trades, sender, kind)?;
+    if !matches!(trade.status(), Some(Status::Active | Status::FiatSent)) {
+        return Err(CantDoReason::NotAllowedByStatus);
+    }
+    let initiator = trade.client_role().ok_or(CantDoReason::InvalidPeer)?;
+    trade.set_status(Status::Dispute);
+    let dispute_id = Uuid::new_v4();
+    let reply = Message::new_dispute(
+        trade.order.id,
+        kind.request_id,
+        None,
+        Action::DisputeInitiatedByYou,
+        Some(Payload::Dispute(dispute_id, None)),
+    );
+    let mut effects = Effects::default().send(sender, reply).publish(&trade.order);
+    effects.dispute = Some((dispute_id, initiator));
+    Ok(effects)
+}
+
+fn rate_user(
+    trades: &mut HashMap<Uuid, SimTrade>,
+    sender: PublicKey,
+    kind: &MessageKind,
+) -> Result<Effects, CantDoReason> {
+    let trade = client_trade(trades, sender, kind)?;
+    if trade.status() != Some(Status::Success) {
+        return Err(CantDoReason::NotAllowedByStatus);
+    }
+    let rating = match kind.payload {
+        Some(Payload::RatingUser(rating)) if (MIN_RATING..=MAX_RATING).contains(&rating) => rating,
+        _ => return Err(CantDoReason::InvalidRating),
+    };
+    Ok(Effects::default().send(
+        sender,
+        order_message(
+            &trade.order,
+            kind.request_id,
+            Action::RateReceived,
+            Some(Payload::RatingUser(rating)),
+        ),
+    ))
+}
+
+fn cancel(
+    trades: &mut HashMap<Uuid, SimTrade>,
+    sender: PublicKey,
+    kind: &MessageKind,
+) -> Result<Effects, CantDoReason> {
+    let trade = client_trade(trades, sender, kind)?;
+    match trade.status() {
+        Some(Status::Active | Status::FiatSent) => Ok(Effects::default()
+            .send(
+                sender,
+                order_message(
+                    &trade.order,
+                    kind.request_id,
+                    Action::CooperativeCancelInitiatedByYou,
+                    None,
+                ),
+            )
+            .schedule(
+                kind.id.unwrap_or_default(),
+                PeerStep::AcceptCooperativeCancel,
+            )),
+        Some(
+            Status::Pending
+            | Status::WaitingBuyerInvoice
+            | Status::WaitingPayment
+            | Status::WaitingTakerBond
+            | Status::WaitingMakerBond,
+        ) => {
+            trade.set_status(Status::Canceled);
+            Ok(Effects::default()
+                .send(
+                    sender,
+                    order_message(&trade.order, kind.request_id, Action::Canceled, None),
+                )
+                .publish(&trade.order))
+        }
+        _ => Err(CantDoReason::NotAllowedByStatus),
+    }
+}
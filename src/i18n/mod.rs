@@ -0,0 +1,297 @@
+//! Localization: message catalogs and the active UI language.
+//!
+//! Catalogs are TOML files embedded at build time (`en.toml`, `es.toml`, `pt.toml`). Tables
+//! flatten to dotted keys, so `[help] close_hint = "…"` is looked up as `help.close_hint`.
+//! English is the reference catalog: [`tr`] falls back to it when the active catalog lacks a key,
+//! and to the key itself when English lacks it too, so a missing translation never blanks a
+//! widget. `catalogs_cover_every_english_key` keeps the other catalogs complete.
+//!
+//! Unlike the color theme, which is passed to renderers, the language is process-wide: some
+//! user-facing text (e.g. `CantDo` descriptions) is formatted in background tasks.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+const EN_CATALOG: &str = include_str!("en.toml");
+const ES_CATALOG: &str = include_str!("es.toml");
+const PT_CATALOG: &str = include_str!("pt.toml");
+
+/// UI languages selectable from Settings (`language` in `settings.toml`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    Portuguese,
+}
+
+impl Language {
+    /// Cycle order used by Settings → Change Language.
+    pub const ALL: [Language; 3] = [Language::English, Language::Spanish, Language::Portuguese];
+
+    /// ISO 639-1 code stored in `settings.toml`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::Portuguese => "pt",
+        }
+    }
+
+    /// Native name shown in the Settings tab (not translated on purpose).
+    pub fn label(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+            Language::Portuguese => "Português",
+        }
+    }
+
+    /// Next language in [`Self::ALL`], wrapping around.
+    pub fn next(self) -> Language {
+        let idx = Self::ALL.iter().position(|l| *l == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    fn as_u8(self) -> u8 {
+        match self {
+            Language::English => 0,
+            Language::Spanish => 1,
+            Language::Portuguese => 2,
+        }
+    }
+
+    fn from_u8(v: u8) -> Language {
+        match v {
+            1 => Language::Spanish,
+            2 => Language::Portuguese,
+            _ => Language::English,
+        }
+    }
+
+    fn catalog_source(self) -> &'static str {
+        match self {
+            Language::English => EN_CATALOG,
+            Language::Spanish => ES_CATALOG,
+            Language::Portuguese => PT_CATALOG,
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = anyhow::Error;
+
+    /// Accepts ISO codes and locale-style values (`es_AR`, `pt-BR`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_lowercase();
+        let code = lower.split(['_', '-', '.']).next().unwrap_or_default();
+        match code {
+            "" | "en" | "english" => Ok(Language::English),
+            "es" | "spanish" | "español" => Ok(Language::Spanish),
+            "pt" | "portuguese" | "português" => Ok(Language::Portuguese),
+            _ => Err(anyhow::anyhow!("Unknown language '{}'", s.trim())),
+        }
+    }
+}
+
+static ACTIVE_LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+/// Install the language used by [`tr`].
+pub fn set_language(lang: Language) {
+    ACTIVE_LANGUAGE.store(lang.as_u8(), Ordering::Relaxed);
+}
+
+/// Install the language named by `settings.toml` (`language`), falling back to English.
+pub fn set_language_from_setting(name: &str) -> Language {
+    let lang = Language::from_str(name).unwrap_or_else(|e| {
+        log::warn!("{}; using English", e);
+        Language::English
+    });
+    set_language(lang);
+    lang
+}
+
+/// Language currently used by [`tr`].
+pub fn language() -> Language {
+    Language::from_u8(ACTIVE_LANGUAGE.load(Ordering::Relaxed))
+}
+
+/// Parse a catalog into a flat `dotted.key → text` map.
+pub fn parse_catalog(source: &str) -> anyhow::Result<HashMap<String, String>> {
+    let table: toml::Table = toml::from_str(source)?;
+    let mut out = HashMap::new();
+    flatten_into(&mut out, "", &table)?;
+    Ok(out)
+}
+
+fn flatten_into(
+    out: &mut HashMap<String, String>,
+    prefix: &str,
+    table: &toml::Table,
+) -> anyhow::Result<()> {
+    for (k, v) in table {
+        let key = if prefix.is_empty() {
+            k.clone()
+        } else {
+            format!("{prefix}.{k}")
+        };
+        match v {
+            toml::Value::String(s) => {
+                out.insert(key, s.clone());
+            }
+            toml::Value::Table(t) => flatten_into(out, &key, t)?,
+            other => {
+                return Err(anyhow::anyhow!(
+                    "Catalog key '{}' must be a string, found {}",
+                    key,
+                    other.type_str()
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn catalog(lang: Language) -> &'static HashMap<String, String> {
+    static CATALOGS: [OnceLock<HashMap<String, String>>; 3] =
+        [OnceLock::new(), OnceLock::new(), OnceLock::new()];
+    CATALOGS[lang.as_u8() as usize].get_or_init(|| {
+        parse_catalog(lang.catalog_source()).unwrap_or_else(|e| {
+            log::error!("Invalid {} message catalog: {}", lang.code(), e);
+            HashMap::new()
+        })
+    })
+}
+
+/// Translate `key` in the active language.
+pub fn tr(key: &'static str) -> &'static str {
+    tr_in(language(), key)
+}
+
+/// Translate `key` in `lang`, falling back to English and then to the key itself.
+///
+/// An empty key renders as an empty string (timeline steps without a top label).
+pub fn tr_in(lang: Language, key: &'static str) -> &'static str {
+    if key.is_empty() {
+        return "";
+    }
+    if let Some(text) = catalog(lang).get(key) {
+        return text.as_str();
+    }
+    match catalog(Language::English).get(key) {
+        Some(text) => text.as_str(),
+        None => {
+            log::warn!("Missing message catalog key: {}", key);
+            key
+        }
+    }
+}
+
+/// Translate `key` and substitute `{name}` placeholders.
+pub fn tr_args(key: &'static str, args: &[(&str, &str)]) -> String {
+    let mut text = tr(key).to_string();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{name}}}"), value);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn placeholders(text: &str) -> BTreeSet<String> {
+        text.split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn catalogs_cover_every_english_key() {
+        let english = parse_catalog(EN_CATALOG).expect("en.toml parses");
+        assert!(!english.is_empty());
+        for lang in [Language::Spanish, Language::Portuguese] {
+            let translated = parse_catalog(lang.catalog_source())
+                .unwrap_or_else(|e| panic!("{}.toml does not parse: {e}", lang.code()));
+            let mut missing: Vec<&String> = english
+                .keys()
+                .filter(|k| !translated.contains_key(*k))
+                .collect();
+            missing.sort();
+            assert!(
+                missing.is_empty(),
+                "{}.toml is missing keys: {missing:?}",
+                lang.code()
+            );
+            let mut stale: Vec<&String> = translated
+                .keys()
+                .filter(|k| !english.contains_key(*k))
+                .collect();
+            stale.sort();
+            assert!(
+                stale.is_empty(),
+                "{}.toml has keys not in en.toml: {stale:?}",
+                lang.code()
+            );
+        }
+    }
+
+    #[test]
+    fn translations_keep_placeholders() {
+        let english = parse_catalog(EN_CATALOG).unwrap();
+        for lang in [Language::Spanish, Language::Portuguese] {
+            let translated = parse_catalog(lang.catalog_source()).unwrap();
+            for (key, text) in &english {
+                if let Some(other) = translated.get(key) {
+                    assert_eq!(
+                        placeholders(text),
+                        placeholders(other),
+                        "{}.toml placeholder mismatch for {key}",
+                        lang.code()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tr_in_falls_back_to_english_then_key() {
+        assert_eq!(
+            tr_in(Language::English, "help.close_hint"),
+            "Esc, Enter or Ctrl+H to close"
+        );
+        assert_ne!(
+            tr_in(Language::Spanish, "help.close_hint"),
+            tr_in(Language::English, "help.close_hint")
+        );
+        assert_eq!(tr_in(Language::Spanish, "no.such.key"), "no.such.key");
+        assert_eq!(tr_in(Language::Portuguese, ""), "");
+    }
+
+    #[test]
+    fn language_parses_codes_and_locales() {
+        assert_eq!(Language::from_str("es").unwrap(), Language::Spanish);
+        assert_eq!(
+            Language::from_str("es_AR.UTF-8").unwrap(),
+            Language::Spanish
+        );
+        assert_eq!(Language::from_str("pt-BR").unwrap(), Language::Portuguese);
+        assert_eq!(Language::from_str("").unwrap(), Language::English);
+        assert!(Language::from_str("fr").is_err());
+        for lang in Language::ALL {
+            assert_eq!(Language::from_str(lang.code()).unwrap(), lang);
+        }
+        assert_eq!(Language::Portuguese.next(), Language::English);
+    }
+
+    #[test]
+    fn parse_catalog_rejects_non_string_values() {
+        assert!(parse_catalog("[help]\nclose_hint = 3\n").is_err());
+        let flat = parse_catalog("[a.b]\nc = \"x\"\n").unwrap();
+        assert_eq!(flat.get("a.b.c").map(String::as_str), Some("x"));
+    }
+}
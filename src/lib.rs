@@ -1,6 +1,9 @@
 // Library crate for Mostrix - exposes modules for testing
 pub mod db;
+pub mod demo;
+pub mod i18n;
 pub mod models;
+pub mod profile;
 pub mod settings;
 pub mod shared;
 pub mod ui;
@@ -1,13 +1,18 @@
 pub mod db;
+pub mod demo;
+pub mod i18n;
 pub mod models;
+pub mod profile;
 pub mod settings;
 pub mod shared;
 pub mod startup;
 pub mod ui;
 pub mod util;
 
+use crate::i18n::set_language_from_setting;
 use crate::models::AdminDispute;
 use crate::models::User;
+use crate::profile::{list_profiles, profile_from_args, set_active_profile, ProfileArg};
 use crate::settings::{init_settings, Settings};
 use crate::ui::helpers::{
     admin_chat_keys_clone_for_role, apply_admin_chat_updates, apply_user_order_chat_updates,
@@ -15,16 +20,25 @@ use crate::ui::helpers::{
     sync_user_order_history_messages_from_db,
 };
 use crate::ui::key_handler::{
-    apply_pending_runtime_reloads, create_app_channels, handle_key_event,
-    handle_mouse_invoice_paste_fallback, reload_runtime_session_after_reconnect,
-    respawn_chat_listener, respawn_trade_dm_listener, AppChannels, RuntimeReconnectContext,
+    apply_pending_runtime_reloads, create_app_channels, handle_key_event, handle_mouse_event,
+    handle_mouse_invoice_paste_fallback, paste_into_archive_search, paste_into_backup_form,
+    reload_runtime_session_after_reconnect, respawn_chat_listener, respawn_trade_dm_listener,
+    AppChannels, RuntimeReconnectContext,
 };
+use crate::ui::saved_search_alert::show_next_saved_search_alert;
+use crate::ui::settings_reload::show_pending_settings_change;
 use crate::ui::{LnAddressVerifyResult, MostroInfoFetchResult, OperationResult};
+use crate::util::attachment_preprocess::AttachmentPreprocessOptions;
+use crate::util::backup::{apply_pending_restore, backup_data_dir};
 use crate::util::{
-    blossom_servers_from_settings, handle_message_notification, handle_operation_result,
-    install_background_panic_hook, order_utils::validate_range_amount, set_chat_router_cmd_tx,
-    set_dm_router_cmd_tx, set_fatal_error_tx, set_order_result_tx, spawn_save_attachment,
-    spawn_send_order_chat_attachment, untrack_dispute_chat_parties,
+    automation_firings_for_notification, dismiss_prompt_answered_by_automation,
+    handle_local_api_call, handle_message_notification, handle_operation_result, init_proxy,
+    install_background_panic_hook, local_api_socket_path, notification_event_line,
+    order_utils::validate_range_amount, set_chat_router_cmd_tx, set_dm_router_cmd_tx,
+    set_fatal_error_tx, set_order_result_tx, spawn_automation_firing, spawn_blossom_cleanup_task,
+    spawn_retention_task, spawn_save_attachment, spawn_send_order_chat_attachment,
+    start_local_api_server, untrack_dispute_chat_parties, AutomationContext, AutomationFiring,
+    BlossomUploadOptions, LocalApiAccess, LocalApiCall, LocalApiContext,
 };
 use crossterm::event::EventStream;
 use mostro_core::prelude::*;
@@ -39,7 +53,7 @@ use crossterm::terminal::{
 };
 use crossterm::{
     self,
-    event::{Event, KeyEvent, MouseButton, MouseEventKind},
+    event::{Event, KeyEvent, KeyModifiers, MouseButton, MouseEventKind},
 };
 use fern::Dispatch;
 use futures::StreamExt;
@@ -58,7 +72,10 @@ async fn apply_order_result(pool: &SqlitePool, app: &mut AppState, result: Opera
     let is_dispute_related = matches!(&result, OperationResult::Info(msg)
         if (msg.contains("Dispute") && msg.contains("taken successfully"))
             || msg.contains("Dispute finalized"));
-    let resync_my_trades_from_db = matches!(&result, OperationResult::OrderHistoryDeleted { .. });
+    let resync_my_trades_from_db = matches!(
+        &result,
+        OperationResult::OrderHistoryDeleted { .. } | OperationResult::RetentionPurged { .. }
+    );
     let refresh_maker_book_cache = matches!(
         &result,
         OperationResult::MyTradesMakerBookChanged | OperationResult::Success(_)
@@ -101,7 +118,10 @@ fn drain_save_attachment_queue(
     }
 }
 
-/// Drains pending send-attachment jobs (encrypt → Blossom → order chat DM).
+/// Drains pending send-attachment jobs (preprocess, or encrypt → Blossom → order chat DM).
+///
+/// Blossom and preprocessing options come from the applied settings, so a reloaded
+/// `settings.toml` affects the next attachment.
 fn drain_send_order_attachment_queue(
     send_attachment_rx: &mut UnboundedReceiver<crate::util::SendOrderAttachmentJob>,
     client: &Client,
@@ -110,19 +130,87 @@ fn drain_send_order_attachment_queue(
     mostro_info: &Option<crate::util::MostroInstanceInfo>,
     order_result_tx: &UnboundedSender<OperationResult>,
 ) {
-    let servers = blossom_servers_from_settings(settings);
+    let mut options = None;
     while let Ok(job) = send_attachment_rx.try_recv() {
+        let (blossom, preprocess) = options.get_or_insert_with(|| {
+            let applied = crate::settings::applied_settings();
+            let settings = applied.as_ref().unwrap_or(settings);
+            (
+                BlossomUploadOptions::from_settings(settings),
+                AttachmentPreprocessOptions::from_settings(settings),
+            )
+        });
         spawn_send_order_chat_attachment(
             job,
             client.clone(),
             pool.clone(),
-            servers.clone(),
+            blossom.clone(),
+            *preprocess,
             mostro_info.clone(),
             order_result_tx.clone(),
         );
     }
 }
 
+/// Spawns automation rule firings for one incoming trade DM and closes the prompt they answer.
+fn dispatch_automation_firings(
+    app: &mut AppState,
+    firings: Vec<AutomationFiring>,
+    client: &Client,
+    pool: &SqlitePool,
+    mostro_pubkey: PublicKey,
+    order_result_tx: &UnboundedSender<OperationResult>,
+) {
+    if firings.is_empty() {
+        return;
+    }
+    let ctx = AutomationContext {
+        pool: pool.clone(),
+        client: client.clone(),
+        mostro_pubkey,
+        mostro_info: app.mostro_info.clone(),
+        ln_address: crate::settings::applied_settings()
+            .map(|s| s.ln_address)
+            .unwrap_or_default(),
+        order_result_tx: order_result_tx.clone(),
+    };
+    for firing in firings {
+        dismiss_prompt_answered_by_automation(app, firing.order_id);
+        spawn_automation_firing(firing, ctx.clone());
+    }
+}
+
+/// Starts the local JSON-RPC socket when `local_api` allows it. Failures are shown, not fatal.
+async fn start_local_api(
+    app: &mut AppState,
+    setting: &str,
+    call_tx: UnboundedSender<LocalApiCall>,
+    events_tx: tokio::sync::broadcast::Sender<String>,
+) -> Option<(tokio::task::JoinHandle<()>, std::path::PathBuf)> {
+    let started = async {
+        let access = LocalApiAccess::from_setting(setting)?;
+        if access == LocalApiAccess::Off {
+            return Ok(None);
+        }
+        let path = local_api_socket_path()
+            .ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
+        let handle = start_local_api_server(&path, access, call_tx, events_tx).await?;
+        Ok::<_, anyhow::Error>(Some((handle, path)))
+    };
+    match started.await {
+        Ok(server) => server,
+        Err(e) => {
+            log::error!("Local API not started: {}", e);
+            if matches!(app.mode, UiMode::UserMode(UserMode::Normal)) {
+                app.mode = UiMode::operation_result(OperationResult::Error(format!(
+                    "Local API not started: {e}"
+                )));
+            }
+            None
+        }
+    }
+}
+
 /// Drains completed background tasks so the UI can show popups without waiting for input.
 async fn drain_order_result_queue(
     order_result_rx: &mut UnboundedReceiver<OperationResult>,
@@ -134,7 +222,9 @@ async fn drain_order_result_queue(
     }
 }
 
-use crate::ui::{AdminMode, AppState, ChatAttachment, UiMode, UserRole};
+use crate::ui::profile_picker::run_profile_picker;
+use crate::ui::terminal_graphics::{flush_graphics_overlay, GraphicsProtocol};
+use crate::ui::{AdminMode, AppState, ChatAttachment, UiMode, UserMode, UserRole};
 use sqlx::SqlitePool;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
 
@@ -209,13 +299,34 @@ fn apply_pasted_text_to_active_input(app: &mut AppState, pasted_text: &str) {
         }
     }
 
-    // Handle paste for the Observer Shared key field
+    paste_into_backup_form(app, pasted_text);
+    paste_into_archive_search(app, pasted_text);
+
+    // Handle paste for the focused Observer field (Shared key or Sign pubkey)
     if app.observer_inputs_editable() {
         let filtered_text: String = pasted_text.chars().filter(|c| !c.is_control()).collect();
-        app.observer_shared_key_input.push_str(&filtered_text);
+        app.observer_focused_input_mut().push_str(&filtered_text);
     }
 }
 
+/// Applies `--profile`, or asks with the startup picker when named profiles exist.
+/// Returns `false` when the user quit the picker.
+fn select_startup_profile(arg: Option<ProfileArg>) -> Result<bool, anyhow::Error> {
+    let named = settings::base_data_dir()
+        .map(|base| list_profiles(&base))
+        .unwrap_or_default();
+    let name = match arg {
+        Some(ProfileArg::Named(name)) => name,
+        None if named.is_empty() => return Ok(true),
+        Some(ProfileArg::Pick) | None => match run_profile_picker(named)? {
+            Some(name) => name,
+            None => return Ok(false),
+        },
+    };
+    set_active_profile(&name)?;
+    Ok(true)
+}
+
 /// Draws the TUI interface with tabs and active content.
 /// The "Orders" tab shows a table of pending orders and highlights the selected row.
 use crate::ui::ui_draw;
@@ -229,17 +340,43 @@ async fn main() -> Result<(), anyhow::Error> {
         .expect("rustls default crypto provider");
 
     log::info!("MostriX started");
+    let demo_transport = demo::demo_transport_from_args(std::env::args())?;
+    let profile_arg = profile_from_args(std::env::args())?;
+    if demo_transport.is_some() && profile_arg.is_some() {
+        anyhow::bail!("--profile cannot be combined with --demo");
+    }
+    // `--demo` must start before the database and settings resolve their home directory.
+    let demo_session = match demo_transport {
+        Some(transport) => Some(demo::DemoSession::start(transport).await?),
+        None => None,
+    };
+    // Likewise the profile, which picks the data directory under `~/.mostrix`.
+    if demo_session.is_none() && !select_startup_profile(profile_arg)? {
+        return Ok(());
+    }
+    // A restore staged from Settings is swapped in before the database opens.
+    let applied_restore = match backup_data_dir() {
+        Some(data_dir) => apply_pending_restore(&data_dir)
+            .map_err(|e| anyhow::anyhow!("Error applying the staged backup restore: {e:#}"))?,
+        None => None,
+    };
     let pool = db::init_db().await?;
     // Derive the user's `nsec` from the DB identity/index-0 key (mnemonic-backed),
     // so DB keys and settings stay in sync on first launch.
     let identity_keys = User::get_identity_keys(&pool)
         .await
         .map_err(|e| anyhow::anyhow!("Error deriving identity keys: {}", e))?;
+    if let Some(demo) = &demo_session {
+        demo.write_settings(&identity_keys)?;
+    }
     let init = init_settings(Some(identity_keys))
         .map_err(|e| anyhow::anyhow!("Error loading settings: {}", e))?;
     let settings = init.settings;
     // Initialize logger
     setup_logger(&settings.log_level).expect("Can't initialize logger");
+    set_language_from_setting(&settings.language);
+    // Before any relay or HTTP client exists, so nothing connects around the proxy.
+    init_proxy(settings).map_err(|e| anyhow::anyhow!("Error in proxy setting: {}", e))?;
     enable_raw_mode()?;
     let mut out = stdout();
     execute!(
@@ -280,6 +417,9 @@ async fn main() -> Result<(), anyhow::Error> {
         mut fatal_error_rx,
         ln_address_result_tx,
         mut ln_address_result_rx,
+        local_api_call_tx,
+        mut local_api_call_rx,
+        local_api_events_tx,
     } = create_app_channels();
 
     // Set fatal error tx for the app channels
@@ -337,6 +477,30 @@ async fn main() -> Result<(), anyhow::Error> {
     )
     .await?;
 
+    let local_api_server = start_local_api(
+        &mut app,
+        &settings.local_api,
+        local_api_call_tx,
+        local_api_events_tx.clone(),
+    )
+    .await;
+
+    if let Some(restored) = applied_restore {
+        log::info!("{}", restored.message());
+        app.mode = UiMode::operation_result(OperationResult::Info(restored.message()));
+    }
+
+    app.image_protocol =
+        GraphicsProtocol::from_setting(&settings.image_preview).unwrap_or_else(|e| {
+            log::warn!("{e}; using Unicode half blocks for image previews");
+            GraphicsProtocol::HalfBlocks
+        });
+
+    if settings.blossom_delete_after_trade {
+        spawn_blossom_cleanup_task(pool.clone());
+    }
+    spawn_retention_task(pool.clone(), order_result_tx.clone());
+
     // Event handling: keyboard input and periodic UI refresh.
     let mut events = EventStream::new();
     let mut refresh_interval = interval(Duration::from_millis(150));
@@ -470,7 +634,30 @@ async fn main() -> Result<(), anyhow::Error> {
             }
             notification = message_notification_rx.recv() => {
                 if let Some(notification) = notification {
+                    // No subscribers is the normal case; the send error is meaningless then.
+                    let _ = local_api_events_tx.send(notification_event_line(&notification));
+                    let firings = automation_firings_for_notification(&mut app, &notification);
                     handle_message_notification(notification, &mut app);
+                    dispatch_automation_firings(
+                        &mut app,
+                        firings,
+                        &client,
+                        &pool,
+                        mostro_pubkey,
+                        &order_result_tx,
+                    );
+                }
+            }
+            local_api_call = local_api_call_rx.recv() => {
+                if let Some(call) = local_api_call {
+                    let ctx = LocalApiContext {
+                        pool: pool.clone(),
+                        client: client.clone(),
+                        mostro_pubkey,
+                        order_result_tx: order_result_tx.clone(),
+                        dm_subscription_tx: dm_subscription_tx.clone(),
+                    };
+                    handle_local_api_call(&mut app, &orders, call, &ctx);
                 }
             }
             admin_chat_result = admin_chat_updates_rx.recv() => {
@@ -574,16 +761,25 @@ async fn main() -> Result<(), anyhow::Error> {
 
                 // Handle right-click paste when mouse capture is enabled.
                 // Some terminals do not emit Event::Paste for mouse paste.
-                if let Event::Mouse(mouse_event) = event {
-                    if matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Right)) {
-                        if let Ok(mut clipboard) = arboard::Clipboard::new() {
-                            if let Ok(text) = clipboard.get_text() {
-                                apply_pasted_text_to_active_input(&mut app, &text);
+                // Other clicks and the wheel resolve against the last frame's hit map and
+                // may yield a key that goes through the regular key handler below.
+                let event = match event {
+                    Event::Mouse(mouse_event) => {
+                        if matches!(mouse_event.kind, MouseEventKind::Down(MouseButton::Right)) {
+                            if let Ok(mut clipboard) = arboard::Clipboard::new() {
+                                if let Ok(text) = clipboard.get_text() {
+                                    apply_pasted_text_to_active_input(&mut app, &text);
+                                }
                             }
+                            continue;
+                        }
+                        match handle_mouse_event(mouse_event, &mut app) {
+                            Some(code) => Event::Key(KeyEvent::new(code, KeyModifiers::NONE)),
+                            None => continue,
                         }
                     }
-                    continue;
-                }
+                    event => event,
+                };
 
                 if handle_mouse_invoice_paste_fallback(&event, &mut app) {
                     continue;
@@ -703,6 +899,9 @@ async fn main() -> Result<(), anyhow::Error> {
             }
         }
 
+        show_pending_settings_change(&mut app);
+        show_next_saved_search_alert(&mut app);
+
         // Ensure Pending dispute selection stays valid when the list changes.
         {
             let disputes_lock = match disputes.lock() {
@@ -795,6 +994,20 @@ async fn main() -> Result<(), anyhow::Error> {
             ),
         ];
         terminal.draw(|f| ui_draw(f, &mut app, &orders, &disputes, Some(&status_lines)))?;
+        // Kitty/Sixel previews are written after the frame; erasing a Sixel needs a full repaint.
+        if flush_graphics_overlay(
+            terminal.backend_mut(),
+            &mut app.graphics_overlay,
+            app.image_protocol,
+        )? {
+            terminal.clear()?;
+            terminal.draw(|f| ui_draw(f, &mut app, &orders, &disputes, Some(&status_lines)))?;
+            flush_graphics_overlay(
+                terminal.backend_mut(),
+                &mut app.graphics_overlay,
+                app.image_protocol,
+            )?;
+        }
     }
 
     // Restore terminal to its original state.
@@ -805,6 +1018,12 @@ async fn main() -> Result<(), anyhow::Error> {
         crossterm::event::DisableMouseCapture
     )?;
     terminal.show_cursor()?;
+    if let Some((handle, path)) = local_api_server {
+        handle.abort();
+        let _ = std::fs::remove_file(path);
+    }
+    // Stops the demo relay and simulator and deletes the throwaway directory.
+    drop(demo_session);
 
     Ok(())
 }
@@ -5,6 +5,8 @@ use nostr_sdk::prelude::*;
 use sqlx::sqlite::SqlitePool;
 use sqlx::{QueryBuilder, Sqlite};
 
+use crate::util::order_utils::BondSlashChoice;
+
 #[derive(Debug, Default, Clone, sqlx::FromRow)]
 pub struct User {
     pub i0_pubkey: String,
@@ -701,6 +703,31 @@ impl Order {
         Ok(rows)
     }
 
+    /// Fetches every order row in a terminal history status (retention candidates).
+    pub async fn get_terminal_history_orders(pool: &SqlitePool) -> Result<Vec<Order>> {
+        let mut qb: QueryBuilder<'_, Sqlite> =
+            QueryBuilder::new("SELECT * FROM orders WHERE lower(COALESCE(status, '')) IN (");
+        {
+            let mut separated = qb.separated(", ");
+            for s in TERMINAL_ORDER_HISTORY_STATUSES {
+                separated.push_bind(*s);
+            }
+        }
+        qb.push(")");
+        let rows = qb.build_query_as::<Order>().fetch_all(pool).await?;
+        Ok(rows)
+    }
+
+    /// Ids of all stored order rows, any status.
+    pub async fn list_all_ids(pool: &SqlitePool) -> Result<Vec<String>> {
+        let rows = sqlx::query_scalar::<_, String>(
+            r#"SELECT id FROM orders WHERE id IS NOT NULL AND id != ''"#,
+        )
+        .fetch_all(pool)
+        .await?;
+        Ok(rows)
+    }
+
     /// Deletes one order row when it is in a terminal status.
     /// Returns number of deleted rows (0 when order is non-terminal or missing).
     pub async fn delete_terminal_order_by_id(pool: &SqlitePool, order_id: &str) -> Result<u64> {
@@ -771,6 +798,10 @@ pub struct AdminDispute {
     pub seller_chat_last_seen: Option<i64>,
     pub buyer_shared_key_hex: Option<String>,
     pub seller_shared_key_hex: Option<String>,
+    /// Bond slash choice sent with the admin settle / cancel ([`BondSlashChoice::as_str`]);
+    /// `None` until finalized from this client.
+    #[sqlx(default)]
+    pub bond_resolution: Option<String>,
 }
 
 impl AdminDispute {
@@ -873,6 +904,7 @@ impl AdminDispute {
             seller_chat_last_seen: None,
             buyer_shared_key_hex,
             seller_shared_key_hex,
+            bond_resolution: None,
         };
 
         // Try insert; if id already exists, perform an update instead
@@ -1160,6 +1192,20 @@ impl AdminDispute {
         Ok(())
     }
 
+    /// Record the bond slash choice sent when this client finalized the dispute.
+    pub async fn set_bond_resolution(
+        pool: &SqlitePool,
+        order_id: &str,
+        bond: BondSlashChoice,
+    ) -> Result<()> {
+        sqlx::query(r#"UPDATE admin_disputes SET bond_resolution = ? WHERE id = ?"#)
+            .bind(bond.as_str())
+            .bind(order_id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
     /// Advance a taken dispute to `status` by Mostro dispute UUID.
     ///
     /// No-op (returns `false`) when the row is missing or already finalized, so a
@@ -0,0 +1,163 @@
+//! `--profile <name>`: separate identities (keys, database, chats, settings) on one machine.
+//!
+//! The `default` profile keeps the historical `~/.mostrix` layout, so existing installs are
+//! untouched. A named profile lives in `~/.mostrix/profiles/<name>/` with the same layout: its
+//! own `mostrix.db` (and so its own mnemonic), `settings.toml`, chat transcripts, downloads and
+//! local API socket. The profile is picked once at startup, before the database opens.
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use anyhow::Result;
+
+/// Name shown for the `~/.mostrix` profile; also accepted by `--profile`.
+pub const DEFAULT_PROFILE: &str = "default";
+/// Folder under `~/.mostrix` that holds the named profiles.
+pub const PROFILES_DIR: &str = "profiles";
+const MAX_PROFILE_NAME_LEN: usize = 32;
+
+static ACTIVE_PROFILE: OnceLock<String> = OnceLock::new();
+
+/// What `--profile` asked for on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileArg {
+    /// `--profile <name>` / `--profile=<name>`.
+    Named(String),
+    /// Bare `--profile`: open the picker even when no named profile exists yet.
+    Pick,
+}
+
+/// Parse `--profile <name>`, `--profile=<name>` or a bare `--profile` from the command line.
+pub fn profile_from_args<I>(args: I) -> Result<Option<ProfileArg>>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+    while let Some(arg) = args.next() {
+        if let Some(name) = arg.strip_prefix("--profile=") {
+            validate_profile_name(name)?;
+            return Ok(Some(ProfileArg::Named(name.to_string())));
+        }
+        if arg == "--profile" {
+            return match args.next_if(|next| !next.starts_with("--")) {
+                Some(name) => {
+                    validate_profile_name(&name)?;
+                    Ok(Some(ProfileArg::Named(name)))
+                }
+                None => Ok(Some(ProfileArg::Pick)),
+            };
+        }
+    }
+    Ok(None)
+}
+
+/// Profile names become folder names: 1–32 lowercase letters, digits, `-` or `_`.
+pub fn validate_profile_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.len() > MAX_PROFILE_NAME_LEN {
+        anyhow::bail!(
+            "Profile name must be 1-{} characters long",
+            MAX_PROFILE_NAME_LEN
+        );
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+    {
+        anyhow::bail!(
+            "Invalid profile name '{}': use lowercase letters, digits, '-' or '_'",
+            name
+        );
+    }
+    Ok(())
+}
+
+/// Select the profile for this process. Must run before the database or settings are touched.
+pub fn set_active_profile(name: &str) -> Result<()> {
+    validate_profile_name(name)?;
+    ACTIVE_PROFILE
+        .set(name.to_string())
+        .map_err(|_| anyhow::anyhow!("The profile was already selected in this process"))
+}
+
+/// The named profile in use, or `None` for the default `~/.mostrix` one.
+pub fn active_profile() -> Option<&'static str> {
+    ACTIVE_PROFILE
+        .get()
+        .map(String::as_str)
+        .filter(|name| *name != DEFAULT_PROFILE)
+}
+
+/// Display name of the profile in use (`default` when none was chosen).
+pub fn active_profile_label() -> &'static str {
+    active_profile().unwrap_or(DEFAULT_PROFILE)
+}
+
+/// Data directory of `profile` under the shared `base` (`~/.mostrix`).
+pub fn profile_dir(base: &Path, profile: Option<&str>) -> PathBuf {
+    match profile.filter(|name| *name != DEFAULT_PROFILE) {
+        Some(name) => base.join(PROFILES_DIR).join(name),
+        None => base.to_path_buf(),
+    }
+}
+
+/// Named profiles found under `base/profiles`, sorted; folders with invalid names are skipped.
+pub fn list_profiles(base: &Path) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(base.join(PROFILES_DIR)) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name != DEFAULT_PROFILE && validate_profile_name(name).is_ok())
+        .collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(v: &[&str]) -> Vec<String> {
+        v.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn profile_flag_forms() {
+        assert_eq!(profile_from_args(args(&["mostrix"])).unwrap(), None);
+        assert_eq!(
+            profile_from_args(args(&["mostrix", "--profile", "desk"])).unwrap(),
+            Some(ProfileArg::Named("desk".to_string()))
+        );
+        assert_eq!(
+            profile_from_args(args(&["mostrix", "--profile=solver-1"])).unwrap(),
+            Some(ProfileArg::Named("solver-1".to_string()))
+        );
+        assert_eq!(
+            profile_from_args(args(&["mostrix", "--profile", "--demo"])).unwrap(),
+            Some(ProfileArg::Pick)
+        );
+        assert!(profile_from_args(args(&["mostrix", "--profile", "../etc"])).is_err());
+        assert!(profile_from_args(args(&["mostrix", "--profile=Desk"])).is_err());
+    }
+
+    #[test]
+    fn profile_dirs_and_listing() {
+        let base = std::env::temp_dir().join(format!("mostrix_profiles_{}", uuid::Uuid::new_v4()));
+        assert_eq!(profile_dir(&base, None), base);
+        assert_eq!(profile_dir(&base, Some(DEFAULT_PROFILE)), base);
+        assert_eq!(
+            profile_dir(&base, Some("desk")),
+            base.join("profiles").join("desk")
+        );
+
+        assert!(list_profiles(&base).is_empty());
+        for name in ["solver", "desk", "Bad Name", DEFAULT_PROFILE] {
+            std::fs::create_dir_all(base.join(PROFILES_DIR).join(name)).unwrap();
+        }
+        std::fs::write(base.join(PROFILES_DIR).join("notes.txt"), "x").unwrap();
+        assert_eq!(list_profiles(&base), vec!["desk", "solver"]);
+        let _ = std::fs::remove_dir_all(base);
+    }
+}
@@ -1,6 +1,9 @@
 use crate::SETTINGS;
 use nostr_sdk::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
 use std::{env, fs, path::PathBuf};
 
 /// Embedded default `settings.toml` used to bootstrap configuration on first run.
@@ -9,6 +12,18 @@ const DEFAULT_SETTINGS_TOML: &str = include_str!("../settings.toml");
 pub const MOSTRO_STAGING_PUBKEY: &str =
     "82fa8cb978b43c79b2156585bac2c011176a21d2aead6d9f7c575c005be88390";
 
+/// What the session knows about `settings.toml` on disk.
+///
+/// `applied` is what the running session reflects: the merge base for in-app saves and the
+/// diff base for external edits. `seen` fingerprints the file contents already handled by the
+/// watcher or written by the app, so each external edit is reported once.
+struct DiskSync {
+    applied: Settings,
+    seen: u64,
+}
+
+static DISK_SYNC: Mutex<Option<DiskSync>> = Mutex::new(None);
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Settings {
     pub mostro_pubkey: String,
@@ -22,15 +37,89 @@ pub struct Settings {
     /// Lightning address for receiving sats when acting as buyer (`user@domain.com`). Empty string = unset.
     #[serde(default)]
     pub ln_address: String,
-    /// Blossom servers for encrypted chat attachment uploads (tried in order). Empty = built-in defaults.
+    /// Blossom servers for encrypted chat attachment uploads (tried healthiest first). Empty = built-in defaults.
     #[serde(default)]
     pub blossom_servers: Vec<String>,
+    /// Also upload each attachment to a second Blossom server.
+    #[serde(default)]
+    pub blossom_mirror: bool,
+    /// Delete our uploaded attachments from Blossom once their trade is finished.
+    #[serde(default = "default_blossom_delete_after_trade")]
+    pub blossom_delete_after_trade: bool,
+    /// Color theme: `dark`, `light`, `high-contrast`, `color-blind` or `custom` (`~/.mostrix/theme.toml`).
+    #[serde(default = "default_theme")]
+    pub theme: String,
+    /// UI language: `en`, `es` or `pt`.
+    #[serde(default = "default_language")]
+    pub language: String,
+    /// SOCKS5 proxy for relays and HTTP (`socks5://[user:pass@]host:port`, e.g. Tor). Empty = direct.
+    #[serde(default)]
+    pub proxy: String,
+    /// Kill switch for `~/.mostrix/automation.toml` rules. Off by default.
+    #[serde(default)]
+    pub automation_enabled: bool,
+    /// Local JSON-RPC socket `~/.mostrix/api/mostrix.sock`: `off`, `read` or `read-write`.
+    #[serde(default = "default_local_api")]
+    pub local_api: String,
+    /// Chat image previews: `auto`, `halfblocks`, `kitty` or `sixel`.
+    #[serde(default = "default_image_preview")]
+    pub image_preview: String,
+    /// Clipboard for copies: `auto` (local, else OSC 52), `local` or `osc52`.
+    #[serde(default = "default_clipboard")]
+    pub clipboard: String,
+    /// Downscale outgoing image attachments whose longest side exceeds this (pixels). 0 = never.
+    #[serde(default = "default_attachment_max_image_side")]
+    pub attachment_max_image_side: u32,
+    /// Re-encode outgoing JPEG attachments larger than this after metadata stripping (KB). 0 = never.
+    #[serde(default = "default_attachment_recompress_above_kb")]
+    pub attachment_recompress_above_kb: u32,
+    /// Purge finished trades, their transcripts and downloads after this many days. 0 = keep.
+    #[serde(default)]
+    pub retention_trade_days: u32,
+    /// Same for trades that went through a dispute. 0 = keep.
+    #[serde(default)]
+    pub retention_disputed_trade_days: u32,
+    /// Remove saved attachments in `~/.mostrix/downloads` older than this many days. 0 = keep.
+    #[serde(default)]
+    pub retention_downloads_days: u32,
 }
 
 fn default_user_mode() -> String {
     "user".to_string()
 }
 
+fn default_blossom_delete_after_trade() -> bool {
+    true
+}
+
+fn default_theme() -> String {
+    "dark".to_string()
+}
+
+fn default_language() -> String {
+    "en".to_string()
+}
+
+fn default_local_api() -> String {
+    "off".to_string()
+}
+
+fn default_image_preview() -> String {
+    "auto".to_string()
+}
+
+fn default_clipboard() -> String {
+    "auto".to_string()
+}
+
+fn default_attachment_max_image_side() -> u32 {
+    2048
+}
+
+fn default_attachment_recompress_above_kb() -> u32 {
+    1024
+}
+
 pub struct InitSettingsResult {
     pub settings: &'static Settings,
     /// True when this process generated a brand-new `settings.toml` file
@@ -50,10 +139,64 @@ impl Default for Settings {
             user_mode: "user".to_string(),
             ln_address: String::new(),
             blossom_servers: Vec::new(),
+            blossom_mirror: false,
+            blossom_delete_after_trade: default_blossom_delete_after_trade(),
+            theme: default_theme(),
+            language: default_language(),
+            proxy: String::new(),
+            automation_enabled: false,
+            local_api: default_local_api(),
+            image_preview: default_image_preview(),
+            clipboard: default_clipboard(),
+            attachment_max_image_side: default_attachment_max_image_side(),
+            attachment_recompress_above_kb: default_attachment_recompress_above_kb(),
+            retention_trade_days: 0,
+            retention_disputed_trade_days: 0,
+            retention_downloads_days: 0,
         }
     }
 }
 
+/// Home directory Mostrix keeps `.mostrix/` under: the user's, or the throwaway one in `--demo`.
+pub fn home_dir() -> Option<PathBuf> {
+    crate::demo::demo_home_dir().or_else(dirs::home_dir)
+}
+
+/// `~/.mostrix`, shared by every profile (named profiles live in its `profiles/` folder).
+pub fn base_data_dir() -> Option<PathBuf> {
+    Some(home_dir()?.join(format!(".{}", env!("CARGO_PKG_NAME"))))
+}
+
+/// Data directory of the active profile: `~/.mostrix`, or `~/.mostrix/profiles/<name>`.
+pub fn data_dir() -> Option<PathBuf> {
+    Some(crate::profile::profile_dir(
+        &base_data_dir()?,
+        crate::profile::active_profile(),
+    ))
+}
+
+/// Portable install probe: `settings.toml` next to the executable. Ignored in `--demo` and
+/// for named profiles.
+fn portable_settings_path() -> Option<PathBuf> {
+    if crate::demo::is_demo_mode() || crate::profile::active_profile().is_some() {
+        return None;
+    }
+    env::current_exe()
+        .ok()
+        .and_then(|p| p.parent().map(|dir| dir.join("settings.toml")))
+}
+
+/// File settings are saved to: the portable file when it exists, else the profile's
+/// `settings.toml` (`~/.mostrix/settings.toml` for the default profile).
+pub fn settings_file_path() -> Result<PathBuf, anyhow::Error> {
+    let hidden_file_path = data_dir()
+        .ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?
+        .join("settings.toml");
+    Ok(portable_settings_path()
+        .filter(|p| p.exists())
+        .unwrap_or(hidden_file_path))
+}
+
 /// Constructs (or copies) the configuration file and loads it.
 /// Returns a reference to the global `SETTINGS`, initializing it on first use.
 pub fn init_settings(identity_keys: Option<Keys>) -> Result<InitSettingsResult, anyhow::Error> {
@@ -66,6 +209,7 @@ pub fn init_settings(identity_keys: Option<Keys>) -> Result<InitSettingsResult,
 
     let (settings, did_generate_new_settings_file) =
         init_or_load_settings_from_disk(identity_keys.as_ref())?;
+    track_settings_file(&settings);
 
     // It's fine if another thread initialized SETTINGS first; in that case we just reuse it.
     if SETTINGS.set(settings).is_err() {
@@ -126,39 +270,34 @@ fn validate_currencies_config(settings_path: &PathBuf) -> Result<(), anyhow::Err
     Ok(())
 }
 
+/// Load and parse a settings file (rejecting the deprecated `currencies` key).
+fn load_settings_from_path(path: &PathBuf) -> Result<Settings, anyhow::Error> {
+    validate_currencies_config(path)?;
+
+    let cfg = config::Config::builder()
+        .add_source(config::File::from(path.as_path()))
+        .build()
+        .map_err(|e| anyhow::anyhow!("settings.toml malformed: {}", e))?;
+
+    let settings: Settings = cfg
+        .try_deserialize()
+        .map_err(|e| anyhow::anyhow!("Error deserializing settings.toml: {}", e))?;
+
+    Ok(settings)
+}
+
 /// Internal helper: ensure settings file exists and load it from disk
 fn init_or_load_settings_from_disk(
     identity_keys: Option<&Keys>,
 ) -> Result<(Settings, bool), anyhow::Error> {
-    // Legacy location: ~/.mostrix/settings.toml (kept for backwards compatibility).
-    let home_dir =
-        dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
-    let package_name = env!("CARGO_PKG_NAME");
-    let hidden_dir = home_dir.join(format!(".{package_name}"));
+    // Legacy location: ~/.mostrix/settings.toml (kept for backwards compatibility),
+    // or ~/.mostrix/profiles/<name>/settings.toml for a named profile.
+    let hidden_dir = data_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
     let hidden_file = hidden_dir.join("settings.toml");
 
-    // Helper: load a settings file from the given path.
-    fn load_settings_from_path(path: &PathBuf) -> Result<Settings, anyhow::Error> {
-        validate_currencies_config(path)?;
-
-        let cfg = config::Config::builder()
-            .add_source(config::File::from(path.as_path()))
-            .build()
-            .map_err(|e| anyhow::anyhow!("settings.toml malformed: {}", e))?;
-
-        let settings: Settings = cfg
-            .try_deserialize()
-            .map_err(|e| anyhow::anyhow!("Error deserializing settings.toml: {}", e))?;
-
-        Ok(settings)
-    }
-
     // Portable install probe: `settings.toml` next to the executable.
     // If present, load it read-only and reuse the same placeholder validation.
-    let executable_settings_path: Option<PathBuf> = env::current_exe()
-        .ok()
-        .and_then(|p| p.parent().map(|dir| dir.join("settings.toml")));
-    if let Some(path) = &executable_settings_path {
+    if let Some(path) = &portable_settings_path() {
         if path.exists() {
             let settings = load_settings_from_path(path)?;
             if settings.mostro_pubkey == "mostro_pubkey_hex_format"
@@ -321,26 +460,182 @@ pub fn load_settings_from_disk() -> Result<Settings, anyhow::Error> {
     Ok(init_or_load_settings_from_disk(None)?.0)
 }
 
-/// Save settings to file
-pub fn save_settings(settings: &Settings) -> Result<(), anyhow::Error> {
-    let home_dir =
-        dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
-    let package_name = env!("CARGO_PKG_NAME");
-    let hidden_file_path = home_dir
-        .join(format!(".{package_name}"))
-        .join("settings.toml");
-    let executable_file_path = env::current_exe()
+fn fingerprint(raw: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    raw.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Start tracking the settings file the session was started with.
+fn track_settings_file(settings: &Settings) {
+    let seen = settings_file_path()
         .ok()
-        .and_then(|p| p.parent().map(|dir| dir.join("settings.toml")));
-    let target_settings_file = executable_file_path
-        .filter(|p| p.exists())
-        .unwrap_or(hidden_file_path);
+        .and_then(|path| fs::read_to_string(path).ok())
+        .map(|raw| fingerprint(&raw))
+        .unwrap_or_default();
+    if let Ok(mut sync) = DISK_SYNC.lock() {
+        *sync = Some(DiskSync {
+            applied: settings.clone(),
+            seen,
+        });
+    }
+}
+
+/// Settings the running session currently reflects (`None` before [`init_settings`]).
+pub fn applied_settings() -> Option<Settings> {
+    DISK_SYNC
+        .lock()
+        .ok()?
+        .as_ref()
+        .map(|sync| sync.applied.clone())
+}
 
-    let toml_string = toml::to_string_pretty(settings)
+/// Record that the session now runs with `settings` (an external edit was accepted).
+pub fn mark_settings_applied(settings: &Settings) {
+    if let Ok(mut sync) = DISK_SYNC.lock() {
+        if let Some(sync) = sync.as_mut() {
+            sync.applied = settings.clone();
+        }
+    }
+}
+
+/// Parse `settings.toml` if its contents changed since the last poll or in-app save.
+///
+/// Returns `None` while the file is unchanged (or unreadable); each distinct content is
+/// reported once, valid or not.
+pub fn poll_settings_file() -> Option<Result<Settings, anyhow::Error>> {
+    let path = settings_file_path().ok()?;
+    let raw = fs::read_to_string(&path).ok()?;
+    let seen = fingerprint(&raw);
+    {
+        let mut sync = DISK_SYNC.lock().ok()?;
+        let sync = sync.as_mut()?;
+        if sync.seen == seen {
+            return None;
+        }
+        sync.seen = seen;
+    }
+    Some(load_settings_from_path(&path))
+}
+
+fn settings_table(settings: &Settings) -> Result<toml::Table, anyhow::Error> {
+    toml::Table::try_from(settings)
+        .map_err(|e| anyhow::anyhow!("Failed to serialize settings: {}", e))
+}
+
+/// Three-way merge of top-level keys for an in-app save.
+///
+/// Keys `ours` changed relative to `base` take our value; every other key keeps the on-disk
+/// value from `theirs`. Returns the settings to write and the new applied state, which only
+/// picks up the keys this save actually changes on disk.
+fn merge_settings(
+    base: &Settings,
+    ours: &Settings,
+    theirs: &Settings,
+) -> Result<(Settings, Settings), anyhow::Error> {
+    let base = settings_table(base)?;
+    let ours = settings_table(ours)?;
+    let mut merged = settings_table(theirs)?;
+    let mut applied = base.clone();
+    for (key, value) in ours {
+        if base.get(&key) == Some(&value) {
+            continue;
+        }
+        if merged.get(&key) != Some(&value) {
+            applied.insert(key.clone(), value.clone());
+        }
+        merged.insert(key, value);
+    }
+    let into_settings = |table: toml::Table| {
+        toml::Value::Table(table)
+            .try_into::<Settings>()
+            .map_err(|e| anyhow::anyhow!("Failed to merge settings: {}", e))
+    };
+    Ok((into_settings(merged)?, into_settings(applied)?))
+}
+
+/// A settings file ready to be written: from [`prepare_settings_write`], finished with
+/// [`SettingsWrite::commit`] once the file has been replaced.
+pub struct SettingsWrite {
+    contents: String,
+    applied: Option<Settings>,
+    external_edit: bool,
+}
+
+impl SettingsWrite {
+    pub fn contents(&self) -> &str {
+        &self.contents
+    }
+
+    /// Record the written file as known to the session.
+    ///
+    /// After merging over an external edit the file stays "unseen", so the watcher still
+    /// offers the edited keys for reload.
+    pub fn commit(self) {
+        let Ok(mut sync) = DISK_SYNC.lock() else {
+            return;
+        };
+        let (Some(sync), Some(applied)) = (sync.as_mut(), self.applied) else {
+            return;
+        };
+        sync.applied = applied;
+        if !self.external_edit {
+            sync.seen = fingerprint(&self.contents);
+        }
+    }
+}
+
+/// Build the file contents for saving `settings`, merged with the on-disk file.
+///
+/// The file may have been edited outside the app since the session last read it; only the
+/// keys `settings` changes relative to the applied state overwrite it. A file that exists but
+/// does not parse is never overwritten.
+pub fn prepare_settings_write(settings: &Settings) -> Result<SettingsWrite, anyhow::Error> {
+    let target_settings_file = settings_file_path()?;
+    let sync = DISK_SYNC
+        .lock()
+        .map_err(|_| anyhow::anyhow!("Settings sync state lock poisoned"))?;
+
+    let (to_write, applied, external_edit) =
+        match (sync.as_ref(), fs::read_to_string(&target_settings_file)) {
+            (Some(state), Ok(raw)) => {
+                let external_edit = fingerprint(&raw) != state.seen;
+                let on_disk = load_settings_from_path(&target_settings_file).map_err(|e| {
+                    anyhow::anyhow!(
+                        "settings.toml on disk is invalid, not overwriting it: {}",
+                        e
+                    )
+                })?;
+                if external_edit {
+                    log::info!("settings.toml changed on disk since it was last read; merging");
+                }
+                let (merged, applied) = merge_settings(&state.applied, settings, &on_disk)?;
+                (merged, Some(applied), external_edit)
+            }
+            _ => (
+                settings.clone(),
+                sync.is_some().then(|| settings.clone()),
+                false,
+            ),
+        };
+
+    let contents = toml::to_string_pretty(&to_write)
         .map_err(|e| anyhow::anyhow!("Failed to serialize settings: {}", e))?;
+    Ok(SettingsWrite {
+        contents,
+        applied,
+        external_edit,
+    })
+}
+
+/// Save settings to file, merging with a newer on-disk version (see [`prepare_settings_write`]).
+pub fn save_settings(settings: &Settings) -> Result<(), anyhow::Error> {
+    let target_settings_file = settings_file_path()?;
+    let write = prepare_settings_write(settings)?;
 
-    fs::write(&target_settings_file, toml_string)
+    fs::write(&target_settings_file, write.contents())
         .map_err(|e| anyhow::anyhow!("Failed to write settings file: {}", e))?;
+    write.commit();
 
     Ok(())
 }
@@ -396,4 +691,41 @@ user_mode = "user"
         let result: Result<Settings, _> = toml::from_str(toml);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn in_app_save_merges_over_external_edit() {
+        let base = Settings {
+            relays: vec!["wss://relay.example.com".to_string()],
+            ..Settings::default()
+        };
+        // Hand edit on disk: new relay list and theme.
+        let theirs = Settings {
+            relays: vec!["wss://other.example.com".to_string()],
+            theme: "light".to_string(),
+            ..base.clone()
+        };
+        // Stale in-app copy that only switches the mode.
+        let ours = Settings {
+            user_mode: "admin".to_string(),
+            ..base.clone()
+        };
+        let (merged, applied) = merge_settings(&base, &ours, &theirs).unwrap();
+        assert_eq!(merged.user_mode, "admin");
+        assert_eq!(merged.relays, theirs.relays);
+        assert_eq!(merged.theme, "light");
+        // The session picked up the mode switch, not the unapplied hand edit.
+        assert_eq!(applied.user_mode, "admin");
+        assert_eq!(applied.relays, base.relays);
+        assert_eq!(applied.theme, base.theme);
+
+        // A copy freshly loaded from disk carries the hand edit; it is still not "applied".
+        let fresh = Settings {
+            user_mode: "admin".to_string(),
+            ..theirs.clone()
+        };
+        let (merged, applied) = merge_settings(&base, &fresh, &theirs).unwrap();
+        assert_eq!(merged.theme, "light");
+        assert_eq!(applied.theme, base.theme);
+        assert_eq!(applied.user_mode, "admin");
+    }
 }
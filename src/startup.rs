@@ -25,16 +25,20 @@ use crate::ui::network_status::spawn_network_status_monitor;
 use crate::ui::startup_splash::{
     dot_count_from_elapsed, render_startup_splash, SPLASH_MIN_DISPLAY_MS, SPLASH_TICK_MS,
 };
-use crate::ui::{AppState, OperationResult, UiMode, UserRole};
+use crate::ui::{AppState, OperationResult, Theme, UiMode, UserMode, UserRole};
+use crate::util::fiat::BookOrder;
+use crate::util::payment_methods::{init_payment_methods, PaymentMethodCatalog};
+use crate::util::saved_searches::{init_saved_searches, SavedSearches};
+use crate::util::settings_reload::spawn_settings_file_watcher;
 use crate::util::{
     any_relay_reachable, catch_unwind_request_fatal_restart, connect_client_safely,
     fetch_mostro_instance_info, hydrate_startup_active_order_dm_state, listen_for_chat_messages,
-    listen_for_order_messages,
+    listen_for_order_messages, nostr_client_builder,
     order_utils::{
         run_relay_order_db_reconcile_once, run_targeted_relay_order_db_reconcile_tick,
         start_fetch_scheduler, FetchSchedulerResult,
     },
-    StartupDmHydration,
+    AutomationEngine, StartupDmHydration,
 };
 
 pub struct PostTerminalStartupInput<'a> {
@@ -62,7 +66,7 @@ pub struct StartupBootstrap {
     pub client: Client,
     pub mostro_pubkey: PublicKey,
     pub current_mostro_pubkey: Arc<Mutex<PublicKey>>,
-    pub orders: Arc<Mutex<Vec<SmallOrder>>>,
+    pub orders: Arc<Mutex<Vec<BookOrder>>>,
     pub disputes: Arc<Mutex<Vec<Dispute>>>,
     pub order_task: JoinHandle<()>,
     pub dispute_task: JoinHandle<()>,
@@ -88,7 +92,7 @@ pub async fn run_post_terminal_startup(
         .nsec_privkey
         .parse::<Keys>()
         .map_err(|e| anyhow::anyhow!("Invalid NSEC privkey: {}", e))?;
-    let client = Client::builder()
+    let client = nostr_client_builder()
         .authenticator(SignerAuthenticator::new(my_keys.clone()))
         .build();
 
@@ -141,7 +145,46 @@ pub async fn run_post_terminal_startup(
         }
     }
     app.currencies_filter = input.settings.currencies_filter.clone();
+    app.apply_theme_setting(&input.settings.theme);
+    match AutomationEngine::load_from_disk() {
+        Ok(engine) => app.automation = engine,
+        Err(e) => {
+            log::error!("Automation rules not loaded: {}", e);
+            if matches!(app.mode, UiMode::UserMode(UserMode::Normal)) {
+                app.mode = UiMode::operation_result(OperationResult::Error(format!(
+                    "Automation rules not loaded: {e}"
+                )));
+            }
+        }
+    }
+    app.automation.enabled = input.settings.automation_enabled;
+    match PaymentMethodCatalog::load_from_disk() {
+        Ok(catalog) => init_payment_methods(catalog),
+        Err(e) => {
+            log::error!(
+                "Payment method catalog not loaded, using built-in list: {}",
+                e
+            );
+            if matches!(app.mode, UiMode::UserMode(UserMode::Normal)) {
+                app.mode = UiMode::operation_result(OperationResult::Error(format!(
+                    "Payment method catalog not loaded: {e}"
+                )));
+            }
+        }
+    }
+    match SavedSearches::load_from_disk() {
+        Ok(searches) => init_saved_searches(searches),
+        Err(e) => {
+            log::error!("Saved searches not loaded: {}", e);
+            if matches!(app.mode, UiMode::UserMode(UserMode::Normal)) {
+                app.mode = UiMode::operation_result(OperationResult::Error(format!(
+                    "Saved searches not loaded: {e}"
+                )));
+            }
+        }
+    }
     hydrate_app_admin_keys_from_privkey(&mut app, &input.settings.admin_privkey);
+    spawn_settings_file_watcher();
 
     if !relays_reachable {
         app.offline_overlay_message = Some(
@@ -296,6 +339,8 @@ pub async fn run_startup_with_splash(
         return run_post_terminal_startup(input, &phase_tx).await;
     }
 
+    // The splash is drawn before `AppState` exists; use the configured palette directly.
+    let theme = &Theme::for_preset_name(&input.settings.theme);
     let splash_started = Instant::now();
     let mut splash_tick = interval(Duration::from_millis(SPLASH_TICK_MS));
     splash_tick.tick().await;
@@ -309,7 +354,7 @@ pub async fn run_startup_with_splash(
             _ = splash_tick.tick() => {
                 let dots = dot_count_from_elapsed(&splash_started);
                 let phase = phase_rx.borrow().clone();
-                terminal.draw(|f| render_startup_splash(f, dots, &phase))?;
+                terminal.draw(|f| render_startup_splash(f, dots, &phase, theme))?;
             }
         }
     };
@@ -320,7 +365,7 @@ pub async fn run_startup_with_splash(
             _ = splash_tick.tick() => {
                 let dots = dot_count_from_elapsed(&splash_started);
                 let phase = phase_rx.borrow().clone();
-                terminal.draw(|f| render_startup_splash(f, dots, &phase))?;
+                terminal.draw(|f| render_startup_splash(f, dots, &phase, theme))?;
             }
         }
     }
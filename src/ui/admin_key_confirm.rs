@@ -1,9 +1,9 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 
-use super::{helpers, BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::{helpers, hit_test, Theme};
 
 /// Renders a generic key confirmation popup
 pub fn render_admin_key_confirm(
@@ -11,8 +11,9 @@ pub fn render_admin_key_confirm(
     title: &str,
     key_string: &str,
     selected_button: bool,
+    theme: &Theme,
 ) {
-    render_admin_key_confirm_with_message(f, title, key_string, selected_button, None);
+    render_admin_key_confirm_with_message(f, title, key_string, selected_button, None, theme);
 }
 
 /// Renders a generic key confirmation popup with optional custom message
@@ -22,6 +23,7 @@ pub fn render_admin_key_confirm_with_message(
     key_string: &str,
     selected_button: bool,
     custom_message: Option<&str>,
+    theme: &Theme,
 ) {
     let area = f.area();
     let popup_width = 80;
@@ -33,7 +35,7 @@ pub fn render_admin_key_confirm_with_message(
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     f.render_widget(block, popup);
 
     let chunks = Layout::new(
@@ -56,7 +58,7 @@ pub fn render_admin_key_confirm_with_message(
     let message = custom_message.unwrap_or("Do you want to save this key in settings file?");
     let message_lines: Vec<Line> = message
         .lines()
-        .map(|l| Line::from(Span::styled(l, Style::default().fg(Color::White))))
+        .map(|l| Line::from(Span::styled(l, Style::default().fg(theme.text))))
         .collect();
     f.render_widget(
         Paragraph::new(message_lines).alignment(ratatui::layout::Alignment::Center),
@@ -78,7 +80,7 @@ pub fn render_admin_key_confirm_with_message(
                 Span::styled(
                     display_key,
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
             ]))
@@ -110,16 +112,17 @@ pub fn render_admin_key_confirm_with_message(
         ],
     )
     .split(centered_button_area);
+    hit_test::record_yes_no(button_chunks[0], button_chunks[2]);
 
     // YES button
     let yes_style = if selected_button {
         Style::default()
-            .bg(Color::Green)
-            .fg(Color::Black)
+            .bg(theme.positive)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
         Style::default()
-            .fg(Color::Green)
+            .fg(theme.positive)
             .add_modifier(Modifier::BOLD)
     };
 
@@ -135,9 +138,9 @@ pub fn render_admin_key_confirm_with_message(
             "✓ YES",
             Style::default()
                 .fg(if selected_button {
-                    Color::Black
+                    theme.on_fill
                 } else {
-                    Color::Green
+                    theme.positive
                 })
                 .add_modifier(Modifier::BOLD),
         )]))
@@ -148,11 +151,13 @@ pub fn render_admin_key_confirm_with_message(
     // NO button
     let no_style = if !selected_button {
         Style::default()
-            .bg(Color::Red)
-            .fg(Color::Black)
+            .bg(theme.negative)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        Style::default()
+            .fg(theme.negative)
+            .add_modifier(Modifier::BOLD)
     };
 
     let no_block = Block::default().borders(Borders::ALL).style(no_style);
@@ -167,9 +172,9 @@ pub fn render_admin_key_confirm_with_message(
             "✗ NO",
             Style::default()
                 .fg(if !selected_button {
-                    Color::Black
+                    theme.on_fill
                 } else {
-                    Color::Red
+                    theme.negative
                 })
                 .add_modifier(Modifier::BOLD),
         )]))
@@ -184,7 +189,7 @@ pub fn render_admin_key_confirm_with_message(
             Span::styled(
                 "Left/Right",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to select, ", Style::default()),
@@ -192,7 +197,7 @@ pub fn render_admin_key_confirm_with_message(
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to confirm, ", Style::default()),
@@ -200,7 +205,7 @@ pub fn render_admin_key_confirm_with_message(
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to cancel", Style::default()),
@@ -215,6 +220,7 @@ pub fn render_saved_ln_address_invoice_confirm(
     f: &mut ratatui::Frame,
     selected_button: bool,
     body: &str,
+    theme: &Theme,
 ) {
     let area = f.area();
     let popup_width = 82;
@@ -225,7 +231,7 @@ pub fn render_saved_ln_address_invoice_confirm(
     let block = Block::default()
         .title("⚡ Use saved Lightning address?")
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     f.render_widget(block, popup);
 
     let chunks = Layout::new(
@@ -242,7 +248,7 @@ pub fn render_saved_ln_address_invoice_confirm(
 
     f.render_widget(
         Paragraph::new(body)
-            .style(Style::default().fg(Color::White))
+            .style(Style::default().fg(theme.text))
             .alignment(ratatui::layout::Alignment::Center)
             .wrap(Wrap { trim: true }),
         chunks[1],
@@ -270,15 +276,16 @@ pub fn render_saved_ln_address_invoice_confirm(
         ],
     )
     .split(centered_button_area);
+    hit_test::record_yes_no(button_chunks[0], button_chunks[2]);
 
     let yes_style = if selected_button {
         Style::default()
-            .bg(Color::Green)
-            .fg(Color::Black)
+            .bg(theme.positive)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
         Style::default()
-            .fg(Color::Green)
+            .fg(theme.positive)
             .add_modifier(Modifier::BOLD)
     };
 
@@ -294,9 +301,9 @@ pub fn render_saved_ln_address_invoice_confirm(
             "✓ YES",
             Style::default()
                 .fg(if selected_button {
-                    Color::Black
+                    theme.on_fill
                 } else {
-                    Color::Green
+                    theme.positive
                 })
                 .add_modifier(Modifier::BOLD),
         )]))
@@ -306,11 +313,13 @@ pub fn render_saved_ln_address_invoice_confirm(
 
     let no_style = if !selected_button {
         Style::default()
-            .bg(Color::Red)
-            .fg(Color::Black)
+            .bg(theme.negative)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        Style::default()
+            .fg(theme.negative)
+            .add_modifier(Modifier::BOLD)
     };
 
     let no_block = Block::default().borders(Borders::ALL).style(no_style);
@@ -325,9 +334,9 @@ pub fn render_saved_ln_address_invoice_confirm(
             "✗ NO",
             Style::default()
                 .fg(if !selected_button {
-                    Color::Black
+                    theme.on_fill
                 } else {
-                    Color::Red
+                    theme.negative
                 })
                 .add_modifier(Modifier::BOLD),
         )]))
@@ -341,7 +350,7 @@ pub fn render_saved_ln_address_invoice_confirm(
             Span::styled(
                 "Left/Right",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to select, ", Style::default()),
@@ -349,7 +358,7 @@ pub fn render_saved_ln_address_invoice_confirm(
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to confirm, ", Style::default()),
@@ -357,7 +366,7 @@ pub fn render_saved_ln_address_invoice_confirm(
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to cancel", Style::default()),
@@ -1,5 +1,5 @@
-use std::collections::{HashMap, HashSet};
-
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 use uuid::Uuid;
@@ -10,19 +10,30 @@ use zeroize::{Zeroize, Zeroizing};
 
 use crate::models::AdminDispute;
 use crate::ui::admin_state::AdminMode;
+use crate::ui::backup_popup::BackupFormState;
 use crate::ui::chat::{
-    AdminChatLastSeen, ChatParty, DisputeChatMessage, DisputeFilter, OrderChatLastSeen,
-    UserChatChannel, UserOrderChatMessage,
+    AdminChatLastSeen, ChatParty, DisputeChatMessage, DisputeFilter, ObserverField,
+    OrderChatLastSeen, UserChatChannel, UserOrderChatMessage,
 };
+use crate::ui::command_palette::CommandPaletteState;
 use crate::ui::helpers::OrderChatListItem;
+use crate::ui::hit_test::HitMap;
+use crate::ui::image_preview_popup::ImagePreviewState;
 use crate::ui::navigation::{AdminTab, Tab, UserRole};
 use crate::ui::orders::{
     BuyerInvoicePreference, FormState, InvoiceInputState, KeyInputState, MessageNotification,
     MessageViewState, OperationResult, OrderChatStaticHeader, OrderMessage, RatingOrderState,
 };
+use crate::ui::terminal_graphics::{GraphicsOverlay, GraphicsProtocol};
+use crate::ui::theme::{Theme, ThemePreset};
 use crate::ui::user_state::UserMode;
-use crate::util::{transport_from_instance, MostroInstanceInfo};
-use nostr_sdk::prelude::Keys;
+use crate::util::chat_utils::{observer_linked_dispute, ObserverChat};
+use crate::util::saved_searches::SavedSearchMatch;
+use crate::util::settings_reload::SettingsFileChange;
+use crate::util::{
+    transport_from_instance, AutomationEngine, MostroInstanceInfo, PendingOrderAttachment,
+};
+use nostr_sdk::prelude::{Keys, PublicKey};
 
 #[derive(Debug)]
 pub enum UiMode {
@@ -34,6 +45,10 @@ pub enum UiMode {
     NewMessageNotification(MessageNotification, Action, InvoiceInputState), // Popup for new message with invoice input state
     OperationResult(Box<OperationResult>), // Show operation result (success or error)
     HelpPopup(Tab, Box<UiMode>), // Context-aware shortcuts (Ctrl+H); 2nd = mode to restore on close
+    /// Fuzzy-searchable action list (Ctrl+P); 2nd = mode to restore on close
+    CommandPalette(CommandPaletteState, Box<UiMode>),
+    /// Chat image preview (V in a Save attachment list); 2nd = mode to restore on close
+    ImagePreview(ImagePreviewState, Box<UiMode>),
     /// Full descriptions for every Settings menu item (Shift+H on Settings); 2nd = mode to restore on close
     SettingsInstructionsPopup(UserRole, Box<UiMode>),
     /// Save attachment popup: list index of selected attachment (Ctrl+S in dispute chat).
@@ -44,6 +59,8 @@ pub enum UiMode {
     UserSaveAttachmentPopup(String, usize),
     /// User order chat send attachment file picker: pinned order id (Ctrl+O on My Trades tab).
     UserSendAttachmentPicker(String),
+    /// My Trades: preprocessed attachment with size/privacy summary, waiting for Enter to upload.
+    UserAttachmentSendConfirm(Box<PendingOrderAttachment>),
     /// Settings: enter Mostro pubkey (`npub` or hex).
     AddMostroPubkey(KeyInputState),
     /// Settings: confirm Mostro pubkey (hex string, Yes/No).
@@ -62,7 +79,17 @@ pub enum UiMode {
     ConfirmClearCurrencies(bool),  // (selected_button: true=Yes, false=No)
     ConfirmDeleteHistoryOrder(uuid::Uuid, bool), // (order_id, selected_button)
     ConfirmBulkDeleteHistory(bool), // (selected_button)
-    ConfirmExit(bool),             // (selected_button: true=Yes, false=No)
+    /// Settings → Data Retention: what a purge would remove, with Purge / Cancel.
+    RetentionPreview(Box<crate::util::RetentionPlan>, bool), // (plan, selected_button)
+    /// Live book order matched a saved search: Go to order / Dismiss.
+    SavedSearchAlert(Box<SavedSearchMatch>, bool), // (match, selected_button)
+    /// `settings.toml` was edited outside the app: diff with Apply / Ignore.
+    ConfirmSettingsReload(Box<SettingsFileChange>, bool), // (change, selected_button)
+    /// Settings → Create Encrypted Backup / Restore From Backup form.
+    BackupForm(BackupFormState),
+    /// Staged restore of a backup older than the live database; Yes applies it on restart.
+    ConfirmRestoreBackup(Box<crate::util::backup::StagedRestore>, bool), // (staged, selected_button)
+    ConfirmExit(bool), // (selected_button: true=Yes, false=No)
 
     // Generate new keys flow (Settings tab)
     ConfirmGenerateNewKeys(bool), // (selected_button: true=Yes, false=No)
@@ -114,6 +141,12 @@ impl Clone for UiMode {
             UiMode::HelpPopup(tab, previous_mode) => {
                 UiMode::HelpPopup(*tab, Box::new((**previous_mode).clone()))
             }
+            UiMode::CommandPalette(state, previous_mode) => {
+                UiMode::CommandPalette(state.clone(), Box::new((**previous_mode).clone()))
+            }
+            UiMode::ImagePreview(state, previous_mode) => {
+                UiMode::ImagePreview(state.clone(), Box::new((**previous_mode).clone()))
+            }
             UiMode::SettingsInstructionsPopup(role, previous_mode) => {
                 UiMode::SettingsInstructionsPopup(*role, Box::new((**previous_mode).clone()))
             }
@@ -125,6 +158,9 @@ impl Clone for UiMode {
             UiMode::UserSendAttachmentPicker(order_id) => {
                 UiMode::UserSendAttachmentPicker(order_id.clone())
             }
+            UiMode::UserAttachmentSendConfirm(pending) => {
+                UiMode::UserAttachmentSendConfirm(pending.clone())
+            }
             UiMode::AddMostroPubkey(state) => UiMode::AddMostroPubkey(state.clone()),
             UiMode::ConfirmMostroPubkey(key, selected) => {
                 UiMode::ConfirmMostroPubkey(key.clone(), *selected)
@@ -150,6 +186,19 @@ impl Clone for UiMode {
             UiMode::ConfirmBulkDeleteHistory(selected) => {
                 UiMode::ConfirmBulkDeleteHistory(*selected)
             }
+            UiMode::RetentionPreview(plan, selected) => {
+                UiMode::RetentionPreview(plan.clone(), *selected)
+            }
+            UiMode::SavedSearchAlert(found, selected) => {
+                UiMode::SavedSearchAlert(found.clone(), *selected)
+            }
+            UiMode::ConfirmSettingsReload(change, selected) => {
+                UiMode::ConfirmSettingsReload(change.clone(), *selected)
+            }
+            UiMode::BackupForm(state) => UiMode::BackupForm(state.clone()),
+            UiMode::ConfirmRestoreBackup(staged, selected) => {
+                UiMode::ConfirmRestoreBackup(staged.clone(), *selected)
+            }
             UiMode::ConfirmExit(selected) => UiMode::ConfirmExit(*selected),
             UiMode::ConfirmGenerateNewKeys(selected) => UiMode::ConfirmGenerateNewKeys(*selected),
             // Clamp cloning of secret mnemonic to avoid duplicating sensitive seed words.
@@ -252,12 +301,34 @@ pub struct AppState {
     /// generation are ignored so a slow relay reply cannot overwrite a newer
     /// transcript or restored-empty state.
     pub observer_fetch_generation: u64,
+    /// Observer mode: optional `pub(K_sign)` locator (hex or npub) narrowing the relay filter.
+    pub observer_sign_pubkey_input: String,
+    /// Observer mode: input that receives typing and paste (Tab switches).
+    pub observer_focus: ObserverField,
+    /// Observer mode: inner signers seen in the observed chat (links it to a taken dispute).
+    pub observer_participants: Vec<PublicKey>,
+    /// Observer mode: dropping this sender ends the live subscription for the current fetch.
+    pub observer_live_stop: Option<tokio::sync::oneshot::Sender<()>>,
+    /// Observer mode: the solver jumped to the linked dispute with Ctrl+G; the observed chat
+    /// stays loaded (and live) until they leave Disputes in Progress for another tab.
+    pub observer_parked: bool,
+    /// Dispute Archive: search typed in the tab (see `util::dispute_archive`).
+    pub archive_query: String,
+    /// Dispute Archive: selected row in the current search results.
+    pub archive_selected: usize,
+    pub archive_table_state: TableState,
     /// Parsed `admin_privkey` from settings (dispute chat, classification). Updated on save / reload.
     pub admin_keys: Option<Keys>,
     /// After switching to admin mode (Settings → Switch Mode) or saving admin key: reload disputes from DB in main.
     pub pending_admin_disputes_reload: bool,
     /// Cached copy of currencies filter from settings (used for UI-side filtering).
     pub currencies_filter: Vec<String>,
+    /// Orders tab payment method filter (canonical catalog name); `None` shows all. Cycled with `p`.
+    pub book_payment_filter: Option<String>,
+    /// Saved-search alerts waiting for the UI to be idle (see `ui/saved_search_alert.rs`).
+    pub saved_search_alerts: VecDeque<SavedSearchMatch>,
+    /// Latest external edit of `settings.toml` not yet confirmed (see `ui/settings_reload.rs`).
+    pub pending_settings_change: Option<SettingsFileChange>,
     /// Cached Mostro instance info (kind 38385 event), if available.
     pub mostro_info: Option<MostroInstanceInfo>,
     /// Wire transport resolved from [`Self::mostro_info`] (`protocol_version` tag).
@@ -284,6 +355,18 @@ pub struct AppState {
     /// Preserved New Order form draft so leaving/returning to the tab keeps input.
     /// Cleared on explicit cancel (Esc) or successful submit.
     pub order_form_draft: Option<FormState>,
+    /// Selected theme preset (`theme` in settings.toml).
+    pub theme_preset: ThemePreset,
+    /// Palette `ui_draw` passes to every renderer.
+    pub theme: Theme,
+    /// Automation rules (`~/.mostrix/automation.toml`) and the Settings kill switch.
+    pub automation: AutomationEngine,
+    /// Clickable regions of the last drawn frame, used by the mouse handler.
+    pub hit_map: HitMap,
+    /// How image previews are drawn (`image_preview` setting, resolved at startup).
+    pub image_protocol: GraphicsProtocol,
+    /// Kitty/Sixel image written over the last frame by the main loop.
+    pub graphics_overlay: GraphicsOverlay,
 }
 
 impl AppState {
@@ -342,9 +425,20 @@ impl AppState {
             observer_loading: false,
             observer_error: None,
             observer_fetch_generation: 0,
+            observer_sign_pubkey_input: String::new(),
+            observer_focus: ObserverField::default(),
+            observer_participants: Vec::new(),
+            observer_live_stop: None,
+            observer_parked: false,
+            archive_query: String::new(),
+            archive_selected: 0,
+            archive_table_state: TableState::default(),
             admin_keys: None,
             pending_admin_disputes_reload: false,
             currencies_filter: Vec::new(),
+            book_payment_filter: None,
+            saved_search_alerts: VecDeque::new(),
+            pending_settings_change: None,
             mostro_info: None,
             transport: Transport::default(),
             offline_overlay_message: None,
@@ -354,9 +448,21 @@ impl AppState {
             pending_post_take_operation_result: None,
             fatal_exit_on_close: false,
             order_form_draft: None,
+            theme_preset: ThemePreset::default(),
+            theme: Theme::default(),
+            automation: AutomationEngine::default(),
+            hit_map: HitMap::default(),
+            image_protocol: GraphicsProtocol::default(),
+            graphics_overlay: GraphicsOverlay::default(),
         }
     }
 
+    /// Apply the `theme` setting: resolve the preset (loading `theme.toml` for `custom`).
+    pub fn apply_theme_setting(&mut self, name: &str) {
+        self.theme_preset = ThemePreset::from_str(name).unwrap_or_default();
+        self.theme = Theme::for_preset_name(name);
+    }
+
     /// Replace cached instance info and keep [`Self::transport`] in sync.
     pub fn set_mostro_info(&mut self, info: Option<MostroInstanceInfo>) {
         self.transport = transport_from_instance(info.as_ref());
@@ -374,6 +480,15 @@ impl AppState {
             )
     }
 
+    /// True when the Dispute Archive search field should accept typing and paste.
+    pub fn archive_inputs_editable(&self) -> bool {
+        matches!(self.active_tab, Tab::Admin(AdminTab::DisputeArchive))
+            && matches!(
+                self.mode,
+                UiMode::Normal | UiMode::AdminMode(AdminMode::Normal)
+            )
+    }
+
     fn bump_observer_fetch_generation(&mut self) -> u64 {
         self.observer_fetch_generation = self.observer_fetch_generation.saturating_add(1);
         self.observer_fetch_generation
@@ -382,22 +497,77 @@ impl AppState {
     /// Invalidate in-flight Observer fetches, then mark a new fetch as current.
     pub fn begin_observer_fetch(&mut self) -> u64 {
         let generation = self.bump_observer_fetch_generation();
+        self.observer_live_stop = None;
         for msg in &mut self.observer_messages {
             msg.content.zeroize();
         }
         self.observer_messages.clear();
+        self.observer_participants.clear();
         self.observer_error = None;
         self.observer_loading = true;
         generation
     }
 
+    /// Stop signal for the live subscription of the current fetch; replaced by the next fetch
+    /// and dropped by [`Self::clear_observer_secrets`].
+    pub fn observer_live_stop_signal(&mut self) -> tokio::sync::oneshot::Receiver<()> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.observer_live_stop = Some(tx);
+        rx
+    }
+
+    /// True while the live subscription of the loaded chat is running.
+    pub fn observer_is_live(&self) -> bool {
+        self.observer_live_stop.is_some() && !self.observer_loading && self.observer_error.is_none()
+    }
+
+    /// Merge fetched or live Observer messages, skipping ones already shown (the live
+    /// subscription overlaps the initial fetch), and keep them in timestamp order.
+    pub fn append_observer_chat(&mut self, chat: ObserverChat) {
+        for msg in chat.messages {
+            let duplicate = self.observer_messages.iter().any(|m| {
+                m.timestamp == msg.timestamp && m.sender == msg.sender && m.content == msg.content
+            });
+            if !duplicate {
+                self.observer_messages.push(msg);
+            }
+        }
+        self.observer_messages.sort_by_key(|m| m.timestamp);
+        for pk in chat.participants {
+            if !self.observer_participants.contains(&pk) {
+                self.observer_participants.push(pk);
+            }
+        }
+    }
+
+    /// Taken dispute whose parties wrote the observed chat, if any.
+    pub fn observer_linked_dispute(&self) -> Option<&AdminDispute> {
+        observer_linked_dispute(
+            &self.admin_disputes_in_progress,
+            &self.observer_participants,
+        )
+    }
+
+    /// Observer input that currently receives typing and paste.
+    pub fn observer_focused_input_mut(&mut self) -> &mut String {
+        match self.observer_focus {
+            ObserverField::SharedKey => &mut self.observer_shared_key_input,
+            ObserverField::SignPubkey => &mut self.observer_sign_pubkey_input,
+        }
+    }
+
     /// Securely wipe all observer inputs and fetched content.
     /// Uses `zeroize` to overwrite strings before clearing them, then
     /// resets error state to safe defaults.
     pub fn clear_observer_secrets(&mut self) {
         self.bump_observer_fetch_generation();
+        self.observer_live_stop = None;
+        self.observer_parked = false;
         self.observer_shared_key_input.zeroize();
         self.observer_shared_key_input.clear();
+        self.observer_sign_pubkey_input.clear();
+        self.observer_focus = ObserverField::default();
+        self.observer_participants.clear();
 
         for msg in &mut self.observer_messages {
             msg.content.zeroize();
@@ -468,6 +638,30 @@ mod tests {
         assert!(!app.observer_inputs_editable());
     }
 
+    #[test]
+    fn append_observer_chat_skips_live_duplicates_and_keeps_order() {
+        let mut app = AppState::new(UserRole::Admin);
+        let signer = Keys::generate().public_key();
+        let mut later = dummy_observer_message("later");
+        later.timestamp = 5;
+        app.append_observer_chat(ObserverChat {
+            messages: vec![later.clone()],
+            participants: vec![signer],
+        });
+        // Live overlap re-delivers `later` alongside an older message.
+        app.append_observer_chat(ObserverChat {
+            messages: vec![dummy_observer_message("earlier"), later],
+            participants: vec![signer],
+        });
+        let contents: Vec<&str> = app
+            .observer_messages
+            .iter()
+            .map(|m| m.content.as_str())
+            .collect();
+        assert_eq!(contents, vec!["earlier", "later"]);
+        assert_eq!(app.observer_participants, vec![signer]);
+    }
+
     #[test]
     fn clear_observer_secrets_invalidates_in_flight_fetch_generation() {
         let mut app = AppState::new(UserRole::Admin);
@@ -0,0 +1,319 @@
+//! Settings → Create Encrypted Backup / Restore From Backup: file path and passphrase form.
+
+use std::path::PathBuf;
+
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use zeroize::Zeroizing;
+
+use crate::i18n::{tr, tr_args};
+use crate::ui::helpers::{create_centered_popup, render_yes_no_buttons};
+use crate::ui::Theme;
+use crate::util::backup::{expand_user_path, StagedRestore, MIN_PASSPHRASE_CHARS};
+
+const BACKUP_POPUP_WIDTH: u16 = 76;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BackupFormKind {
+    Create,
+    Restore,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BackupField {
+    Path,
+    Passphrase,
+    Confirm,
+}
+
+/// Form state; passphrases are zeroized when the popup closes.
+#[derive(Clone, Debug)]
+pub struct BackupFormState {
+    pub kind: BackupFormKind,
+    pub path: String,
+    pub passphrase: Zeroizing<String>,
+    pub confirm: Zeroizing<String>,
+    pub focus: BackupField,
+    pub error: Option<String>,
+}
+
+impl BackupFormState {
+    /// Create form, prefilled with the suggested archive path and focused on the passphrase.
+    pub fn create(default_path: String) -> Self {
+        Self {
+            kind: BackupFormKind::Create,
+            path: default_path,
+            passphrase: Zeroizing::new(String::new()),
+            confirm: Zeroizing::new(String::new()),
+            focus: BackupField::Passphrase,
+            error: None,
+        }
+    }
+
+    pub fn restore() -> Self {
+        Self {
+            kind: BackupFormKind::Restore,
+            path: String::new(),
+            passphrase: Zeroizing::new(String::new()),
+            confirm: Zeroizing::new(String::new()),
+            focus: BackupField::Path,
+            error: None,
+        }
+    }
+
+    pub fn fields(&self) -> &'static [BackupField] {
+        match self.kind {
+            BackupFormKind::Create => &[
+                BackupField::Path,
+                BackupField::Passphrase,
+                BackupField::Confirm,
+            ],
+            BackupFormKind::Restore => &[BackupField::Path, BackupField::Passphrase],
+        }
+    }
+
+    pub fn focused_input_mut(&mut self) -> &mut String {
+        match self.focus {
+            BackupField::Path => &mut self.path,
+            BackupField::Passphrase => &mut self.passphrase,
+            BackupField::Confirm => &mut self.confirm,
+        }
+    }
+
+    /// Moves focus by `step` fields, wrapping around.
+    pub fn move_focus(&mut self, step: isize) {
+        let fields = self.fields();
+        let current = fields.iter().position(|f| *f == self.focus).unwrap_or(0) as isize;
+        let next = (current + step).rem_euclid(fields.len() as isize) as usize;
+        self.focus = fields[next];
+    }
+
+    pub fn focus_is_last(&self) -> bool {
+        self.fields().last() == Some(&self.focus)
+    }
+
+    /// Checks the inputs; on success returns the archive path and passphrase.
+    pub fn validate(&self) -> Result<(PathBuf, Zeroizing<String>), String> {
+        if self.path.trim().is_empty() {
+            return Err(tr("backup.err_path").to_string());
+        }
+        let path = expand_user_path(&self.path);
+        let shown = path.display().to_string();
+        match self.kind {
+            BackupFormKind::Create if path.exists() => {
+                return Err(tr_args("backup.err_exists", &[("path", &shown)]));
+            }
+            BackupFormKind::Restore if !path.is_file() => {
+                return Err(tr_args("backup.err_missing", &[("path", &shown)]));
+            }
+            _ => {}
+        }
+        if self.passphrase.chars().count() < MIN_PASSPHRASE_CHARS {
+            return Err(tr_args(
+                "backup.err_short",
+                &[("min", &MIN_PASSPHRASE_CHARS.to_string())],
+            ));
+        }
+        if self.kind == BackupFormKind::Create && *self.passphrase != *self.confirm {
+            return Err(tr("backup.err_mismatch").to_string());
+        }
+        Ok((path, self.passphrase.clone()))
+    }
+}
+
+fn field_label(field: BackupField) -> &'static str {
+    match field {
+        BackupField::Path => tr("backup.path"),
+        BackupField::Passphrase => tr("backup.passphrase"),
+        BackupField::Confirm => tr("backup.confirm"),
+    }
+}
+
+fn field_line(state: &BackupFormState, field: BackupField, theme: &Theme) -> Line<'static> {
+    let focused = state.focus == field;
+    let value = match field {
+        BackupField::Path if state.path.is_empty() => Span::styled(
+            tr("backup.path_placeholder"),
+            Style::default().fg(theme.muted),
+        ),
+        BackupField::Path => Span::raw(state.path.clone()),
+        BackupField::Passphrase => Span::raw("•".repeat(state.passphrase.chars().count())),
+        BackupField::Confirm => Span::raw("•".repeat(state.confirm.chars().count())),
+    };
+    let label_style = if focused {
+        Style::default()
+            .fg(theme.primary)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(theme.text)
+    };
+    let mut spans = vec![
+        Span::styled(if focused { "▶ " } else { "  " }, label_style),
+        Span::styled(format!("{:<20}", field_label(field)), label_style),
+        value.style(if focused {
+            Style::default().fg(theme.primary).bg(theme.input_bg)
+        } else {
+            Style::default().fg(theme.text)
+        }),
+    ];
+    if focused {
+        spans.push(Span::styled("▏", Style::default().fg(theme.primary)));
+    }
+    Line::from(spans)
+}
+
+pub fn render_backup_form(f: &mut ratatui::Frame, state: &BackupFormState, theme: &Theme) {
+    let (title, note) = match state.kind {
+        BackupFormKind::Create => (tr("backup.create_title"), tr("backup.create_note")),
+        BackupFormKind::Restore => (tr("backup.restore_title"), tr("backup.restore_note")),
+    };
+    let fields = state.fields();
+    let popup = create_centered_popup(f.area(), BACKUP_POPUP_WIDTH, fields.len() as u16 * 2 + 9);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(theme.background));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let [body, status, hint] = Layout::vertical([
+        Constraint::Min(1),
+        Constraint::Length(2),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let mut lines = vec![Line::from("")];
+    for field in fields {
+        lines.push(field_line(state, *field, theme));
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::styled(note, Style::default().fg(theme.muted)));
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), body);
+
+    if let Some(error) = &state.error {
+        f.render_widget(
+            Paragraph::new(Line::styled(
+                error.clone(),
+                Style::default().fg(theme.negative),
+            ))
+            .wrap(Wrap { trim: true }),
+            status,
+        );
+    }
+    f.render_widget(
+        Paragraph::new(Line::styled(
+            tr("backup.hint"),
+            Style::default().fg(theme.muted),
+        ))
+        .alignment(ratatui::layout::Alignment::Center),
+        hint,
+    );
+}
+
+/// Asks before restoring a backup older than the live database.
+pub fn render_restore_confirm(
+    f: &mut ratatui::Frame,
+    staged: &StagedRestore,
+    selected_button: bool,
+    theme: &Theme,
+) {
+    let popup = create_centered_popup(f.area(), BACKUP_POPUP_WIDTH, 14);
+    f.render_widget(Clear, popup);
+    let block = Block::default()
+        .title(Span::styled(
+            tr("backup.confirm_title"),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(theme.background));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let [body, buttons] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(3)]).areas(inner);
+    let message = tr_args(
+        "backup.confirm_newer",
+        &[
+            ("date", &staged.backup_date()),
+            ("current", &staged.current_db.last_trade_index.to_string()),
+            ("backup", &staged.backup_db.last_trade_index.to_string()),
+        ],
+    );
+    let lines = vec![
+        Line::styled(
+            staged.archive.display().to_string(),
+            Style::default().fg(theme.muted),
+        ),
+        Line::from(""),
+        Line::styled(message, Style::default().fg(theme.text)),
+    ];
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), body);
+    render_yes_no_buttons(
+        f,
+        buttons,
+        selected_button,
+        tr("backup.restore_anyway"),
+        tr("retention.cancel"),
+        theme,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn create_form_masks_passphrases_and_checks_confirmation() {
+        let theme = &Theme::dark();
+        let mut state = BackupFormState::create("/nonexistent/dir/backup.mxbak".to_string());
+        state.passphrase.push_str("hunter22!");
+        state.move_focus(1);
+        assert_eq!(state.focus, BackupField::Confirm);
+        assert!(state.focus_is_last());
+        state.confirm.push_str("hunter22?");
+        assert_eq!(state.validate().unwrap_err(), tr("backup.err_mismatch"));
+        state.confirm.pop();
+        state.confirm.push('!');
+        assert!(state.validate().is_ok());
+
+        let mut terminal = Terminal::new(TestBackend::new(90, 24)).unwrap();
+        terminal
+            .draw(|f| render_backup_form(f, &state, theme))
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        let text: String = (0..buf.area.height)
+            .flat_map(|y| (0..buf.area.width).map(move |x| (x, y)))
+            .map(|pos| buf[pos].symbol().to_string())
+            .collect();
+        assert!(text.contains("backup.mxbak"));
+        assert!(text.contains("•••••••••"));
+        assert!(!text.contains("hunter"));
+    }
+
+    #[test]
+    fn restore_form_requires_existing_file_and_wraps_focus() {
+        let mut state = BackupFormState::restore();
+        state.move_focus(-1);
+        assert_eq!(state.focus, BackupField::Passphrase);
+        state.path = "/nonexistent/backup.mxbak".to_string();
+        state.passphrase.push_str("long enough");
+        assert!(state
+            .validate()
+            .unwrap_err()
+            .contains("/nonexistent/backup.mxbak"));
+    }
+}
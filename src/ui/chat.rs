@@ -15,6 +15,14 @@ pub enum DisputeFilter {
     Finalized,  // Show only finalized disputes (Settled, SellerRefunded, Released)
 }
 
+/// Focused input on the Observer tab.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ObserverField {
+    #[default]
+    SharedKey, // Disclosed K_conv (required)
+    SignPubkey, // Optional pub(K_sign) locator
+}
+
 impl Display for ChatParty {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -0,0 +1,404 @@
+//! Command palette (Ctrl+P): fuzzy search over every action available on the current tab.
+//!
+//! Entries reuse the Ctrl+H help catalog (`"Key: description"` lines) and the Settings menu
+//! rows, so labels and shortcuts stay in one place. Running an entry never calls an action
+//! directly: it resolves to a key binding that `handle_key_event` replays after the palette
+//! closes (see `key_handler/command_palette.rs`).
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use super::constants::*;
+use super::tabs::settings_tab::{settings_menu_rows, SettingsMenuAction};
+use super::{helpers, AdminTab, AppState, DisputeFilter, Tab, Theme, UserRole, UserTab};
+use crate::i18n::{tr, tr_args};
+
+const PALETTE_WIDTH: u16 = 76;
+const PALETTE_MAX_HEIGHT: u16 = 20;
+
+/// Query and highlighted row of the open palette.
+#[derive(Clone, Debug, Default)]
+pub struct CommandPaletteState {
+    pub query: String,
+    /// Index into the filtered entries.
+    pub selected: usize,
+}
+
+/// How a palette entry runs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PaletteAction {
+    /// Replay a key binding, on `tab` when set (switching there first), else on the current tab.
+    Key {
+        tab: Option<Tab>,
+        code: KeyCode,
+        modifiers: KeyModifiers,
+    },
+    /// Select a Settings menu row and press Enter on it.
+    Settings(SettingsMenuAction),
+    /// Switch to a tab.
+    GoTo(Tab),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaletteEntry {
+    pub label: String,
+    pub shortcut: String,
+    pub action: PaletteAction,
+}
+
+impl PaletteEntry {
+    /// Build an entry from a help catalog line (`"Shift+F: Mark fiat as sent"`).
+    fn from_help(line: &str, code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let (shortcut, label) = line.split_once(": ").unwrap_or(("", line));
+        Self {
+            label: label.to_string(),
+            shortcut: shortcut.to_string(),
+            action: PaletteAction::Key {
+                tab: None,
+                code,
+                modifiers,
+            },
+        }
+    }
+}
+
+/// Every action for the current role and tab: tab-local bindings first, then Settings rows,
+/// tab jumps, help and quit.
+pub fn palette_entries(app: &AppState) -> Vec<PaletteEntry> {
+    use KeyCode::{Char, Delete, End, Enter};
+    let none = KeyModifiers::NONE;
+    let shift = KeyModifiers::SHIFT;
+    let ctrl = KeyModifiers::CONTROL;
+    let help = |key: &'static str, code: KeyCode, modifiers: KeyModifiers| {
+        PaletteEntry::from_help(tr(key), code, modifiers)
+    };
+
+    let mut entries = match app.active_tab {
+        Tab::User(UserTab::Orders) => vec![
+            help(HELP_ORDERS_ENTER_TAKE, Enter, none),
+            help(HELP_ORDERS_P_PAYMENT_FILTER, Char('p'), none),
+        ],
+        Tab::User(UserTab::Messages) => vec![help(HELP_MSG_ENTER_OPEN, Enter, none)],
+        Tab::User(UserTab::MyTrades) => vec![
+            help(HELP_MY_TRADES_SHIFT_F_FIAT_SENT, Char('F'), shift),
+            help(HELP_MY_TRADES_SHIFT_R_RELEASE, Char('R'), shift),
+            help(HELP_MY_TRADES_SHIFT_C_CANCEL, Char('C'), shift),
+            help(HELP_MY_TRADES_SHIFT_D_DISPUTE, Char('D'), shift),
+            help(HELP_MY_TRADES_SHIFT_V_RATE, Char('V'), shift),
+            help(HELP_MY_TRADES_SHIFT_K_KCONV, Char('K'), shift),
+            help(HELP_MY_TRADES_SHIFT_I, Char('I'), shift),
+            help(HELP_MY_TRADES_TAB_CHAT, KeyCode::Tab, none),
+            help(HELP_MY_TRADES_CTRL_S_ATTACH, Char('s'), ctrl),
+            help(HELP_MY_TRADES_CTRL_O_SEND, Char('o'), ctrl),
+            help(HELP_MY_TRADES_CTRL_SHIFT_O_RETRY, Char('O'), ctrl | shift),
+            help(HELP_DIP_END_BOTTOM, End, none),
+            help("palette.delete_history_order", Delete, none),
+            help("palette.clean_history", Delete, ctrl),
+        ],
+        Tab::Admin(AdminTab::DisputesPending) => vec![help(HELP_DP_ENTER_TAKE, Enter, none)],
+        Tab::Admin(AdminTab::DisputesInProgress) => {
+            let filter = match app.dispute_filter {
+                DisputeFilter::InProgress => FILTER_VIEW_FINALIZED,
+                DisputeFilter::Finalized => FILTER_VIEW_IN_PROGRESS,
+            };
+            let mut entries = vec![
+                help(HELP_DIP_SHIFT_F_RESOLVE, Char('F'), shift),
+                help(filter, Char('C'), shift),
+                help(HELP_DIP_TAB_PARTY, KeyCode::Tab, none),
+                help(HELP_DIP_SHIFT_I_INPUT, Char('I'), shift),
+                help(HELP_DIP_CTRL_S_ATTACH, Char('s'), ctrl),
+                help(HELP_DIP_END_BOTTOM, End, none),
+            ];
+            if app.observer_parked {
+                entries.push(help(HELP_DIP_CTRL_G_OBSERVER, Char('g'), ctrl));
+            }
+            entries
+        }
+        Tab::Admin(AdminTab::DisputeArchive) => vec![help(HELP_ARC_ESC_CLEAR, KeyCode::Esc, none)],
+        Tab::Admin(AdminTab::Observer) => vec![
+            help(HELP_OBS_ENTER_LOAD, Enter, none),
+            help(HELP_OBS_CTRL_G_DISPUTE, Char('g'), ctrl),
+            help(HELP_OBS_CTRL_C_CLEAR, Char('c'), ctrl),
+            help(HELP_OBS_CTRL_S_ATTACH, Char('s'), ctrl),
+        ],
+        _ => Vec::new(),
+    };
+
+    entries.extend(
+        settings_menu_rows(app.user_role)
+            .iter()
+            .map(|(action, label_key)| PaletteEntry {
+                label: tr(label_key).to_string(),
+                shortcut: tr("tabs.settings").to_string(),
+                action: PaletteAction::Settings(*action),
+            }),
+    );
+
+    let exit_tab = Tab::last(app.user_role);
+    for (idx, title) in Tab::get_titles(app.user_role).iter().enumerate() {
+        let tab = Tab::from_index(idx, app.user_role);
+        if tab != app.active_tab && tab != exit_tab {
+            entries.push(PaletteEntry {
+                label: tr_args("palette.go_to", &[("tab", title)]),
+                shortcut: "←/→".to_string(),
+                action: PaletteAction::GoTo(tab),
+            });
+        }
+    }
+
+    entries.push(help(HELP_KEY, Char('h'), ctrl));
+    entries.push(PaletteEntry {
+        label: tr("palette.quit").to_string(),
+        shortcut: tr("palette.quit_shortcut").to_string(),
+        action: PaletteAction::Key {
+            tab: Some(exit_tab),
+            code: Enter,
+            modifiers: none,
+        },
+    });
+    entries
+}
+
+/// Case-insensitive subsequence match. Returns a score (higher is better) and the matched
+/// char positions in `candidate`; consecutive runs and word starts score extra, gaps cost.
+/// Spaces in the query are ignored so "open disp" matches "Open a dispute".
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<(i32, Vec<usize>)> {
+    let needle: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if needle.is_empty() {
+        return Some((0, Vec::new()));
+    }
+
+    let chars: Vec<char> = candidate.chars().collect();
+    let mut positions: Vec<usize> = Vec::with_capacity(needle.len());
+    let mut score = 0i32;
+    let mut next = 0usize;
+    for (pos, c) in chars.iter().enumerate() {
+        if next == needle.len() {
+            break;
+        }
+        if !c.to_lowercase().eq(std::iter::once(needle[next])) {
+            continue;
+        }
+        score += 1;
+        match positions.last() {
+            Some(&last) if last + 1 == pos => score += 5,
+            Some(&last) => score -= (pos - last - 1).min(5) as i32,
+            None => score -= pos.min(5) as i32,
+        }
+        if pos == 0 || !chars[pos - 1].is_alphanumeric() {
+            score += 3;
+        }
+        positions.push(pos);
+        next += 1;
+    }
+    (next == needle.len()).then_some((score, positions))
+}
+
+/// Entries matching `query`, best first (catalog order breaks ties), with matched positions.
+pub fn filter_entries(entries: &[PaletteEntry], query: &str) -> Vec<(PaletteEntry, Vec<usize>)> {
+    let mut matches: Vec<(i32, usize, Vec<usize>)> = entries
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, entry)| {
+            fuzzy_match(query, &entry.label).map(|(score, positions)| (score, idx, positions))
+        })
+        .collect();
+    matches.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    matches
+        .into_iter()
+        .map(|(_, idx, positions)| (entries[idx].clone(), positions))
+        .collect()
+}
+
+/// Index of a Settings action in the role's menu (what `selected_settings_option` expects).
+pub fn settings_option_index(role: UserRole, action: SettingsMenuAction) -> Option<usize> {
+    settings_menu_rows(role)
+        .iter()
+        .position(|(row_action, _)| *row_action == action)
+}
+
+fn entry_line(
+    entry: &PaletteEntry,
+    positions: &[usize],
+    width: u16,
+    theme: &Theme,
+) -> Line<'static> {
+    let matched = Style::default()
+        .fg(theme.primary)
+        .add_modifier(Modifier::BOLD);
+    let mut spans: Vec<Span<'static>> = entry
+        .label
+        .chars()
+        .enumerate()
+        .map(|(idx, c)| {
+            if positions.contains(&idx) {
+                Span::styled(c.to_string(), matched)
+            } else {
+                Span::raw(c.to_string())
+            }
+        })
+        .collect();
+    let used = Line::from(spans.clone()).width() + entry.shortcut.chars().count();
+    let padding = (width as usize).saturating_sub(used + 2).max(1);
+    spans.push(Span::raw(" ".repeat(padding)));
+    spans.push(Span::styled(
+        entry.shortcut.clone(),
+        Style::default().fg(theme.muted),
+    ));
+    Line::from(spans)
+}
+
+/// Render the palette popup over the current tab.
+pub fn render_command_palette(f: &mut ratatui::Frame, app: &AppState, state: &CommandPaletteState) {
+    let theme = &app.theme;
+    let matches = filter_entries(&palette_entries(app), &state.query);
+    let area = f.area();
+    let height = (matches.len() as u16 + 5).clamp(7, PALETTE_MAX_HEIGHT);
+    let popup = helpers::create_centered_popup(area, PALETTE_WIDTH, height);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Span::styled(
+            tr("palette.title"),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(theme.background));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let chunks = Layout::new(
+        Direction::Vertical,
+        [
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ],
+    )
+    .split(inner);
+
+    let query_line = if state.query.is_empty() {
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(theme.primary)),
+            Span::styled(tr("palette.placeholder"), Style::default().fg(theme.muted)),
+        ])
+    } else {
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(theme.primary)),
+            Span::styled(format!("{}▏", state.query), Style::default().fg(theme.text)),
+        ])
+    };
+    f.render_widget(Paragraph::new(query_line), chunks[0]);
+
+    if matches.is_empty() {
+        f.render_widget(
+            Paragraph::new(Span::styled(
+                tr("palette.no_matches"),
+                Style::default().fg(theme.warning),
+            )),
+            chunks[2],
+        );
+    } else {
+        let row_width = chunks[2].width.saturating_sub(2);
+        let items: Vec<ListItem> = matches
+            .iter()
+            .map(|(entry, positions)| ListItem::new(entry_line(entry, positions, row_width, theme)))
+            .collect();
+        let list = List::new(items)
+            .highlight_style(Style::default().bg(theme.primary).fg(theme.on_fill))
+            .highlight_symbol("▶ ");
+        let selected = state.selected.min(matches.len() - 1);
+        let mut list_state = ListState::default().with_selected(Some(selected));
+        f.render_stateful_widget(list, chunks[2], &mut list_state);
+    }
+
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            tr("palette.footer"),
+            Style::default().fg(theme.muted),
+        ))
+        .alignment(ratatui::layout::Alignment::Center),
+        chunks[3],
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_prefers_word_starts_and_runs() {
+        assert!(fuzzy_match("xyz", "Open a dispute").is_none());
+        let (_, positions) = fuzzy_match("open disp", "Open a dispute").unwrap();
+        assert_eq!(positions, vec![0, 1, 2, 3, 7, 8, 9, 10]);
+        let (tight, _) = fuzzy_match("rel", "Release sats").unwrap();
+        let (loose, _) = fuzzy_match("rel", "Clear all").unwrap_or((i32::MIN, Vec::new()));
+        assert!(tight > loose);
+        assert_eq!(fuzzy_match("", "anything"), Some((0, Vec::new())));
+    }
+
+    #[test]
+    fn my_trades_palette_lists_trade_actions_and_settings() {
+        let mut app = AppState::new(UserRole::User);
+        app.active_tab = Tab::User(UserTab::MyTrades);
+        let entries = palette_entries(&app);
+
+        let dispute = entries
+            .iter()
+            .find(|e| e.shortcut == "Shift+D")
+            .expect("dispute entry");
+        assert_eq!(
+            dispute.action,
+            PaletteAction::Key {
+                tab: None,
+                code: KeyCode::Char('D'),
+                modifiers: KeyModifiers::SHIFT,
+            }
+        );
+        assert!(entries
+            .iter()
+            .any(|e| e.action == PaletteAction::Settings(SettingsMenuAction::AddRelay)));
+        assert!(!entries
+            .iter()
+            .any(|e| e.action == PaletteAction::GoTo(Tab::User(UserTab::MyTrades))));
+        // Admin-only Settings rows never leak into the user palette.
+        assert!(!entries
+            .iter()
+            .any(|e| e.action == PaletteAction::Settings(SettingsMenuAction::ChangeAdminKey)));
+    }
+
+    #[test]
+    fn filter_ranks_best_match_first() {
+        let mut app = AppState::new(UserRole::User);
+        app.active_tab = Tab::User(UserTab::MyTrades);
+        let entries = palette_entries(&app);
+        let matches = filter_entries(&entries, "add relay");
+        assert_eq!(
+            matches.first().map(|(e, _)| e.action),
+            Some(PaletteAction::Settings(SettingsMenuAction::AddRelay))
+        );
+        assert!(filter_entries(&entries, "zzzz").is_empty());
+    }
+
+    #[test]
+    fn settings_option_index_follows_role_menu() {
+        assert_eq!(
+            settings_option_index(UserRole::User, SettingsMenuAction::SwitchMode),
+            Some(0)
+        );
+        assert_eq!(
+            settings_option_index(UserRole::Admin, SettingsMenuAction::GenerateNewKeys),
+            None
+        );
+    }
+}
@@ -1,56 +1,68 @@
 //! UI constants: help text, footer hints, and other shared strings.
 //! Centralizes copy to avoid duplication and keep the UI consistent.
+//!
+//! String constants are message catalog keys (see [`crate::i18n`]); render them with
+//! [`crate::i18n::tr`]. The English copy lives in `src/i18n/en.toml`.
+
+use crate::i18n::tr;
 
 // --- Help popup (Ctrl+H) ---
 
-pub const HELP_CLOSE_HINT: &str = "Esc, Enter or Ctrl+H to close";
+pub const HELP_CLOSE_HINT: &str = "help.close_hint";
 
 /// Footer hint shown in help and disputes footer
-pub const HELP_KEY: &str = "Ctrl+H: Help";
+pub const HELP_KEY: &str = "help.key";
 
 // Filter toggle (Disputes in Progress)
-pub const FILTER_VIEW_FINALIZED: &str = "Shift+C: View Finalized";
-pub const FILTER_VIEW_IN_PROGRESS: &str = "Shift+C: View In Progress";
+pub const FILTER_VIEW_FINALIZED: &str = "filter.view_finalized";
+pub const FILTER_VIEW_IN_PROGRESS: &str = "filter.view_in_progress";
 
 // Help popup titles (per tab)
-pub const HELP_TITLE_DISPUTES_IN_PROGRESS: &str = "Disputes in Progress — Shortcuts";
-pub const HELP_TITLE_DISPUTES_PENDING: &str = "Disputes Pending — Shortcuts";
-pub const HELP_TITLE_OBSERVER: &str = "Observer — Shortcuts";
-pub const HELP_TITLE_SETTINGS_ADMIN: &str = "Settings (Admin) — Shortcuts";
-pub const HELP_TITLE_SETTINGS_USER: &str = "Settings (User) — Shortcuts";
-pub const HELP_TITLE_EXIT: &str = "Exit — Shortcuts";
-pub const HELP_TITLE_ORDERS: &str = "Orders — Shortcuts";
-pub const HELP_TITLE_MY_TRADES: &str = "Order Chat — Shortcuts";
-pub const HELP_TITLE_MESSAGES: &str = "Messages — Shortcuts";
-pub const HELP_TITLE_CREATE_NEW_ORDER: &str = "Create New Order — Shortcuts";
+pub const HELP_TITLE_DISPUTES_IN_PROGRESS: &str = "help_title.disputes_in_progress";
+pub const HELP_TITLE_DISPUTES_PENDING: &str = "help_title.disputes_pending";
+pub const HELP_TITLE_DISPUTE_ARCHIVE: &str = "help_title.dispute_archive";
+pub const HELP_TITLE_OBSERVER: &str = "help_title.observer";
+pub const HELP_TITLE_SETTINGS_ADMIN: &str = "help_title.settings_admin";
+pub const HELP_TITLE_SETTINGS_USER: &str = "help_title.settings_user";
+pub const HELP_TITLE_EXIT: &str = "help_title.exit";
+pub const HELP_TITLE_ORDERS: &str = "help_title.orders";
+pub const HELP_TITLE_MY_TRADES: &str = "help_title.my_trades";
+pub const HELP_TITLE_MESSAGES: &str = "help_title.messages";
+pub const HELP_TITLE_CREATE_NEW_ORDER: &str = "help_title.create_new_order";
 
 // Help popup lines (Disputes in Progress)
-pub const HELP_DIP_TAB_PARTY: &str = "Tab: Switch Party (Buyer/Seller)";
-pub const HELP_DIP_SELECT_DISPUTE: &str = "↑↓: Select dispute (sidebar)";
-pub const HELP_DIP_SCROLL_CHAT: &str = "PgUp/PgDn: Scroll chat";
-pub const HELP_DIP_END_BOTTOM: &str = "End: Jump to bottom of chat";
-pub const HELP_DIP_SHIFT_F_RESOLVE: &str = "Shift+F: Resolve (finalize) dispute";
-pub const HELP_DIP_SHIFT_I_INPUT: &str = "Shift+I: Enable/disable message input";
-pub const HELP_DIP_ENTER_SEND: &str = "Enter: Send message (when input enabled)";
-pub const HELP_DIP_CTRL_S_ATTACH: &str = "Ctrl+S: Save attachment (choose from list)";
+pub const HELP_DIP_TAB_PARTY: &str = "help.dip_tab_party";
+pub const HELP_DIP_SELECT_DISPUTE: &str = "help.dip_select_dispute";
+pub const HELP_DIP_SCROLL_CHAT: &str = "help.dip_scroll_chat";
+pub const HELP_DIP_END_BOTTOM: &str = "help.dip_end_bottom";
+pub const HELP_DIP_SHIFT_F_RESOLVE: &str = "help.dip_shift_f_resolve";
+pub const HELP_DIP_SHIFT_I_INPUT: &str = "help.dip_shift_i_input";
+pub const HELP_DIP_ENTER_SEND: &str = "help.dip_enter_send";
+pub const HELP_DIP_CTRL_S_ATTACH: &str = "help.dip_ctrl_s_attach";
+pub const HELP_DIP_CTRL_G_OBSERVER: &str = "help.dip_ctrl_g_observer";
 
 // Help popup lines (Disputes Pending)
-pub const HELP_DP_ENTER_TAKE: &str = "Enter: Take selected dispute";
-pub const HELP_DP_SELECT_DISPUTE: &str = "↑↓: Select dispute";
+pub const HELP_DP_ENTER_TAKE: &str = "help.dp_enter_take";
+pub const HELP_DP_SELECT_DISPUTE: &str = "help.dp_select_dispute";
+
+// Help popup lines (Dispute Archive)
+pub const HELP_ARC_TYPE_SEARCH: &str = "help.arc_type_search";
+pub const HELP_ARC_SELECT: &str = "help.arc_select";
+pub const HELP_ARC_ESC_CLEAR: &str = "help.arc_esc_clear";
 
 // Help popup lines (Observer)
-pub const HELP_OBS_ENTER_LOAD: &str = "Enter: Load chat for Shared key";
+pub const HELP_OBS_ENTER_LOAD: &str = "help.obs_enter_load";
 #[cfg(any(
     target_os = "linux",
     target_os = "freebsd",
     target_os = "openbsd",
     target_os = "netbsd"
 ))]
-pub const HELP_OBS_PASTE_SHARED_KEY: &str = "Ctrl+Shift+V: Paste into Shared key field";
+pub const HELP_OBS_PASTE_SHARED_KEY: &str = "help.obs_paste_shared_key_ctrl_shift_v";
 #[cfg(target_os = "windows")]
-pub const HELP_OBS_PASTE_SHARED_KEY: &str = "Ctrl+V: Paste into Shared key field";
+pub const HELP_OBS_PASTE_SHARED_KEY: &str = "help.obs_paste_shared_key_ctrl_v";
 #[cfg(target_os = "macos")]
-pub const HELP_OBS_PASTE_SHARED_KEY: &str = "Cmd+V: Paste into Shared key field";
+pub const HELP_OBS_PASTE_SHARED_KEY: &str = "help.obs_paste_shared_key_cmd_v";
 #[cfg(not(any(
     target_os = "linux",
     target_os = "freebsd",
@@ -59,140 +71,117 @@ pub const HELP_OBS_PASTE_SHARED_KEY: &str = "Cmd+V: Paste into Shared key field"
     target_os = "windows",
     target_os = "macos"
 )))]
-pub const HELP_OBS_PASTE_SHARED_KEY: &str = "Ctrl+V: Paste into Shared key field";
-pub const HELP_OBS_SCROLL_LINE: &str = "↑↓: Scroll messages";
-pub const HELP_OBS_SCROLL_PAGE: &str = "PgUp/PgDn: Scroll page";
-pub const HELP_OBS_ESC_CLEAR_ERR: &str = "Esc: Clear error";
-pub const HELP_OBS_CTRL_C_CLEAR: &str = "Ctrl+C: Clear all";
-pub const HELP_OBS_CTRL_S_ATTACH: &str = "Ctrl+S: Save attachment";
+pub const HELP_OBS_PASTE_SHARED_KEY: &str = "help.obs_paste_shared_key_ctrl_v";
+pub const HELP_OBS_SCROLL_LINE: &str = "help.obs_scroll_line";
+pub const HELP_OBS_SCROLL_PAGE: &str = "help.obs_scroll_page";
+pub const HELP_OBS_ESC_CLEAR_ERR: &str = "help.obs_esc_clear_err";
+pub const HELP_OBS_CTRL_C_CLEAR: &str = "help.obs_ctrl_c_clear";
+pub const HELP_OBS_CTRL_S_ATTACH: &str = "help.obs_ctrl_s_attach";
+pub const HELP_OBS_TAB_FIELD: &str = "help.obs_tab_field";
+pub const HELP_OBS_CTRL_G_DISPUTE: &str = "help.obs_ctrl_g_dispute";
 
 // Help popup lines (Settings)
-pub const HELP_SETTINGS_SWITCH_FROM_MENU: &str =
-    "Enter on \"Switch Mode\": Toggle User/Admin (saved to settings.toml)";
-pub const HELP_SETTINGS_SHIFT_H_FULL: &str = "Shift+H: Explain every settings option";
-pub const HELP_SETTINGS_SELECT_OPTION: &str = "↑↓: Select option";
-pub const HELP_SETTINGS_ENTER_OPEN: &str = "Enter: Open selected option";
+pub const HELP_SETTINGS_SWITCH_FROM_MENU: &str = "help.settings_switch_from_menu";
+pub const HELP_SETTINGS_SHIFT_H_FULL: &str = "help.settings_shift_h_full";
+pub const HELP_SETTINGS_SELECT_OPTION: &str = "help.settings_select_option";
+pub const HELP_SETTINGS_ENTER_OPEN: &str = "help.settings_enter_open";
 
 /// Footer for the Settings instructions overlay (Shift+H).
-pub const SETTINGS_INSTRUCTIONS_CLOSE_HINT: &str = "Esc, Enter, Shift+H or Ctrl+H to close";
+pub const SETTINGS_INSTRUCTIONS_CLOSE_HINT: &str = "help.settings_instructions_close";
 
 // Help popup lines (Exit)
-pub const HELP_EXIT_ENTER_CONFIRM: &str = "Enter: Confirm exit (then Yes/No)";
+pub const HELP_EXIT_ENTER_CONFIRM: &str = "help.exit_enter_confirm";
 
 // Help popup lines (Orders)
-pub const HELP_ORDERS_ENTER_TAKE: &str =
-    "Enter: Take selected order (or cancel if it is your pending listing)";
-pub const HELP_ORDERS_SELECT: &str = "↑↓: Select order";
+pub const HELP_ORDERS_ENTER_TAKE: &str = "help.orders_enter_take";
+pub const HELP_ORDERS_SELECT: &str = "help.orders_select";
+pub const HELP_ORDERS_P_PAYMENT_FILTER: &str = "help.orders_p_payment_filter";
 /// Confirmation body when Enter on Orders targets a maker pending order we own.
-pub const HELP_ORDERS_CANCEL_PENDING_MSG: &str =
-    "Cancel this pending order? It will be removed from the order book.";
+pub const HELP_ORDERS_CANCEL_PENDING_MSG: &str = "help.orders_cancel_pending_msg";
 
 // Help popup lines (My Trades)
-pub const HELP_MY_TRADES_NAV: &str = "↑↓: Select order";
-pub const HELP_MY_TRADES_ENTER_SEND: &str = "Enter: Send message (when input enabled)";
-pub const HELP_MY_TRADES_TAB_CHAT: &str = "Tab: Switch Peer/Solver chat (after solver assignment)";
-pub const HELP_MY_TRADES_SHIFT_I: &str = "Shift+I: Enable/disable message input";
-pub const HELP_MY_TRADES_SHIFT_C_CANCEL: &str = "Shift+C: Cancel order (cooperative cancel)";
-pub const HELP_MY_TRADES_SHIFT_F_FIAT_SENT: &str = "Shift+F: Mark fiat as sent (FiatSent message)";
-pub const HELP_MY_TRADES_SHIFT_R_RELEASE: &str = "Shift+R: Release sats (Release message)";
-pub const HELP_MY_TRADES_SHIFT_V_RATE: &str = "Shift+V: Rate counterparty (open rating popup)";
-pub const HELP_MY_TRADES_SHIFT_D_DISPUTE: &str = "Shift+D: Open a dispute (Dispute message)";
-pub const HELP_MY_TRADES_SHIFT_H_HELP: &str = "Shift+H: Show shortcuts help";
-pub const HELP_MY_TRADES_SHIFT_K_KCONV: &str =
-    "Shift+K: Reveal Shared key (read-only grant for solvers; never your signing key)";
-pub const HELP_MY_TRADES_CTRL_S_ATTACH: &str = "Ctrl+S: Save attachment (choose from list)";
-pub const HELP_MY_TRADES_CTRL_O_SEND: &str = "Ctrl+O: Send attachment (file picker)";
-pub const HELP_MY_TRADES_CTRL_SHIFT_O_RETRY: &str =
-    "Ctrl+Shift+O: Retry chat send (blob already on Blossom)";
+pub const HELP_MY_TRADES_NAV: &str = "help.my_trades_nav";
+pub const HELP_MY_TRADES_ENTER_SEND: &str = "help.my_trades_enter_send";
+pub const HELP_MY_TRADES_TAB_CHAT: &str = "help.my_trades_tab_chat";
+pub const HELP_MY_TRADES_SHIFT_I: &str = "help.my_trades_shift_i";
+pub const HELP_MY_TRADES_SHIFT_C_CANCEL: &str = "help.my_trades_shift_c_cancel";
+pub const HELP_MY_TRADES_SHIFT_F_FIAT_SENT: &str = "help.my_trades_shift_f_fiat_sent";
+pub const HELP_MY_TRADES_SHIFT_R_RELEASE: &str = "help.my_trades_shift_r_release";
+pub const HELP_MY_TRADES_SHIFT_V_RATE: &str = "help.my_trades_shift_v_rate";
+pub const HELP_MY_TRADES_SHIFT_D_DISPUTE: &str = "help.my_trades_shift_d_dispute";
+pub const HELP_MY_TRADES_SHIFT_H_HELP: &str = "help.my_trades_shift_h_help";
+pub const HELP_MY_TRADES_SHIFT_K_KCONV: &str = "help.my_trades_shift_k_kconv";
+pub const HELP_MY_TRADES_CTRL_S_ATTACH: &str = "help.my_trades_ctrl_s_attach";
+pub const HELP_MY_TRADES_CTRL_O_SEND: &str = "help.my_trades_ctrl_o_send";
+pub const HELP_MY_TRADES_CTRL_SHIFT_O_RETRY: &str = "help.my_trades_ctrl_shift_o_retry";
 
 // Confirmation messages for My Trades actions
-pub const HELP_MY_TRADES_CANCEL_MSG: &str =
-    "Cancel this order? This sends a cooperative Cancel request.";
-pub const HELP_MY_TRADES_FIAT_SENT_MSG: &str =
-    "Confirm fiat sent for this order? This sends a FiatSent message.";
-pub const HELP_MY_TRADES_RELEASE_MSG: &str =
-    "Release sats for this order? This sends a Release message.";
-pub const HELP_MY_TRADES_DISPUTE_MSG: &str = concat!(
-    "Open a dispute for this order?\n",
-    "\n",
-    "A solver will be assigned and can read the dispute chat.\n",
-    "Only do this if the trade is stuck — try the order chat first.",
-);
+pub const HELP_MY_TRADES_CANCEL_MSG: &str = "help.my_trades_cancel_msg";
+pub const HELP_MY_TRADES_FIAT_SENT_MSG: &str = "help.my_trades_fiat_sent_msg";
+pub const HELP_MY_TRADES_RELEASE_MSG: &str = "help.my_trades_release_msg";
+pub const HELP_MY_TRADES_DISPUTE_MSG: &str = "help.my_trades_dispute_msg";
 /// Shown when Shift+D is pressed on an order whose status cannot be disputed.
-pub const HELP_MY_TRADES_DISPUTE_UNAVAILABLE: &str =
-    "Dispute is only available once the trade is active (waiting for fiat or fiat sent).";
+pub const HELP_MY_TRADES_DISPUTE_UNAVAILABLE: &str = "help.my_trades_dispute_unavailable";
 
 /// Multi-line body for Messages-tab confirmation when Mostro reports hold invoice paid (`HoldInvoicePaymentAccepted`).
 /// Last line matches [`HELP_MY_TRADES_CANCEL_MSG`] (cooperative cancel).
-pub const VIEW_MESSAGE_HOLD_INVOICE_PREVIEW: &str = concat!(
-    "Hold invoice payment accepted — confirm fiat was sent?\n",
-    "\n",
-    "YES — Send FiatSent (you sent fiat).\n",
-    "NO — Close without sending.\n",
-    "CANCEL — Start cooperative cancel (both sides must agree; same as My Trades Shift+C).\n",
-    "\n",
-    "Cancel path: ",
-    "Cancel this order? This sends a cooperative Cancel request.",
-);
+pub const VIEW_MESSAGE_HOLD_INVOICE_PREVIEW: &str = "view_message.hold_invoice_preview";
 
 /// Multi-line body for `Action::BuyerTookOrder` in the Messages tab (waiting for buyer fiat; optional cooperative cancel).
-pub const VIEW_MESSAGE_BUYER_TOOK_ORDER_PREVIEW: &str = concat!(
-    "Buyer took order — do you want to start a cooperative cancel?\n",
-    "\n",
-    "CANCEL — Start cooperative cancel (both sides must agree; same as My Trades Shift+C).\n",
-    "NO — Close without canceling.\n",
-);
+pub const VIEW_MESSAGE_BUYER_TOOK_ORDER_PREVIEW: &str = "view_message.buyer_took_order_preview";
 
 // Help popup lines (Messages)
-pub const HELP_MSG_ENTER_OPEN: &str = "Enter: Open selected message";
-pub const HELP_MSG_SELECT: &str = "↑↓: Select message";
+pub const HELP_MSG_ENTER_OPEN: &str = "help.msg_enter_open";
+pub const HELP_MSG_SELECT: &str = "help.msg_select";
 
 // Help popup lines (Create New Order)
-pub const HELP_CNO_CHANGE_FIELD: &str = "↑↓: Change field";
-pub const HELP_CNO_TAB_NEXT: &str = "Tab: Next field";
-pub const HELP_CNO_ENTER_CONFIRM: &str = "Enter: Confirm order (from form)";
+pub const HELP_CNO_CHANGE_FIELD: &str = "help.cno_change_field";
+pub const HELP_CNO_TAB_NEXT: &str = "help.cno_tab_next";
+pub const HELP_CNO_ENTER_CONFIRM: &str = "help.cno_enter_confirm";
 
 // --- Footer (Disputes in Progress) ---
 
 /// Hint shown in the Save Attachment popup footer (↑↓ Select, Enter Save, Esc Cancel).
-pub const SAVE_ATTACHMENT_POPUP_HINT: &str = "↑↓ Select, Enter Save, Esc Cancel";
-
-pub const FOOTER_CTRL_S_SAVE_FILE: &str = " | Ctrl+S: Save file";
-pub const FOOTER_CTRL_O_SEND_FILE: &str = " | Ctrl+O: Send file";
-pub const FOOTER_CTRL_SHIFT_O_RETRY: &str = " | Ctrl+Shift+O: Retry send";
-pub const FOOTER_SENDING_ATTACHMENT: &str = " | Sending attachment…";
-pub const FOOTER_UP_DOWN_SELECT: &str = "↑↓: Select";
-pub const FOOTER_UP_DOWN_SELECT_DISPUTE: &str = "↑↓: Select Dispute";
-pub const FOOTER_TAB_PARTY: &str = "Tab: Party";
-pub const FOOTER_TAB_SWITCH_PARTY: &str = "Tab: Switch Party";
-pub const FOOTER_ENTER_SEND: &str = "Enter: Send";
-pub const FOOTER_SHIFT_F_RESOLVE: &str = "Shift+F: Resolve";
-pub const FOOTER_SHIFT_I_DISABLE: &str = "Shift+I: Disable";
-pub const FOOTER_SHIFT_I_ENABLE: &str = "Shift+I: Enable";
-pub const FOOTER_PGUP_PGDN_SCROLL: &str = "PgUp/PgDn: Scroll";
-pub const FOOTER_END_BOTTOM: &str = "End: Bottom";
-pub const FOOTER_NAV_CHAT: &str = "↑↓: Navigate Chat";
-pub const FOOTER_PGUP_PGDN_SCROLL_CHAT: &str = "PgUp/PgDn: Scroll Chat";
+pub const SAVE_ATTACHMENT_POPUP_HINT: &str = "footer.save_attachment_popup";
+
+pub const FOOTER_CTRL_S_SAVE_FILE: &str = "footer.ctrl_s_save_file";
+pub const FOOTER_CTRL_G_BACK_OBSERVER: &str = "footer.ctrl_g_back_observer";
+pub const FOOTER_CTRL_O_SEND_FILE: &str = "footer.ctrl_o_send_file";
+pub const FOOTER_CTRL_SHIFT_O_RETRY: &str = "footer.ctrl_shift_o_retry";
+pub const FOOTER_SENDING_ATTACHMENT: &str = "footer.sending_attachment";
+pub const FOOTER_UP_DOWN_SELECT: &str = "footer.up_down_select";
+pub const FOOTER_UP_DOWN_SELECT_DISPUTE: &str = "footer.up_down_select_dispute";
+pub const FOOTER_TAB_PARTY: &str = "footer.tab_party";
+pub const FOOTER_TAB_SWITCH_PARTY: &str = "footer.tab_switch_party";
+pub const FOOTER_ENTER_SEND: &str = "footer.enter_send";
+pub const FOOTER_SHIFT_F_RESOLVE: &str = "footer.shift_f_resolve";
+pub const FOOTER_SHIFT_I_DISABLE: &str = "footer.shift_i_disable";
+pub const FOOTER_SHIFT_I_ENABLE: &str = "footer.shift_i_enable";
+pub const FOOTER_PGUP_PGDN_SCROLL: &str = "footer.pgup_pgdn_scroll";
+pub const FOOTER_END_BOTTOM: &str = "footer.end_bottom";
+pub const FOOTER_NAV_CHAT: &str = "footer.nav_chat";
+pub const FOOTER_PGUP_PGDN_SCROLL_CHAT: &str = "footer.pgup_pgdn_scroll_chat";
 
 // --- Footer (My Trades / Order Chat) ---
 
-pub const FOOTER_MYTRADES_SELECT_ORDER: &str = "↑↓: Select order";
-pub const FOOTER_MYTRADES_TAB_CHAT: &str = "Tab: Peer/Solver chat";
-pub const FOOTER_MYTRADES_ENTER_SEND: &str = "Enter: Send";
-pub const FOOTER_MYTRADES_SHIFT_I_DISABLE: &str = "Shift+I: Disable input";
-pub const FOOTER_MYTRADES_SHIFT_I_ENABLE: &str = "Shift+I: Enable input";
-pub const FOOTER_MYTRADES_SHIFT_C_CANCEL: &str = "Shift+C: Cancel order";
-pub const FOOTER_MYTRADES_SHIFT_D_DISPUTE: &str = "Shift+D: Dispute";
-pub const FOOTER_MYTRADES_SHIFT_F_FIAT_SENT: &str = "Shift+F: Mark fiat sent";
-pub const FOOTER_MYTRADES_SHIFT_R_RELEASE: &str = "Shift+R: Release sats";
-pub const FOOTER_MYTRADES_SHIFT_V_RATE: &str = "Shift+V: Rate counterparty";
-pub const FOOTER_MYTRADES_SHIFT_K_KCONV: &str = "Shift+K: Reveal Shared key";
-pub const FOOTER_MYTRADES_PGUP_PGDN_SCROLL_CHAT: &str = "PgUp/PgDn: Scroll chat";
-pub const FOOTER_MYTRADES_END_BOTTOM: &str = "End: Bottom";
+pub const FOOTER_MYTRADES_SELECT_ORDER: &str = "footer.mytrades_select_order";
+pub const FOOTER_MYTRADES_TAB_CHAT: &str = "footer.mytrades_tab_chat";
+pub const FOOTER_MYTRADES_ENTER_SEND: &str = "footer.mytrades_enter_send";
+pub const FOOTER_MYTRADES_SHIFT_I_DISABLE: &str = "footer.mytrades_shift_i_disable";
+pub const FOOTER_MYTRADES_SHIFT_I_ENABLE: &str = "footer.mytrades_shift_i_enable";
+pub const FOOTER_MYTRADES_SHIFT_C_CANCEL: &str = "footer.mytrades_shift_c_cancel";
+pub const FOOTER_MYTRADES_SHIFT_D_DISPUTE: &str = "footer.mytrades_shift_d_dispute";
+pub const FOOTER_MYTRADES_SHIFT_F_FIAT_SENT: &str = "footer.mytrades_shift_f_fiat_sent";
+pub const FOOTER_MYTRADES_SHIFT_R_RELEASE: &str = "footer.mytrades_shift_r_release";
+pub const FOOTER_MYTRADES_SHIFT_V_RATE: &str = "footer.mytrades_shift_v_rate";
+pub const FOOTER_MYTRADES_SHIFT_K_KCONV: &str = "footer.mytrades_shift_k_kconv";
+pub const FOOTER_MYTRADES_PGUP_PGDN_SCROLL_CHAT: &str = "footer.mytrades_pgup_pgdn_scroll_chat";
+pub const FOOTER_MYTRADES_END_BOTTOM: &str = "footer.mytrades_end_bottom";
 
 /// Step label for the buy order flow
 ///
-/// Describes the top and bottom of the step label of the orders flow in UI
+/// Describes the top and bottom of the step label of the orders flow in UI.
+/// Both fields are catalog keys under `flow.*`; an empty key renders as an empty line.
 #[derive(Copy, Clone, Debug)]
 pub struct StepLabel {
     pub top: &'static str,
@@ -200,143 +189,155 @@ pub struct StepLabel {
 }
 
 impl StepLabel {
+    #[must_use]
+    pub fn top_text(self) -> &'static str {
+        tr(self.top)
+    }
+
+    #[must_use]
+    pub fn bottom_text(self) -> &'static str {
+        tr(self.bottom)
+    }
+
     #[must_use]
     pub fn as_single_line(self) -> String {
-        format!("{} {}", self.top, self.bottom).trim().to_string()
+        format!("{} {}", self.top_text(), self.bottom_text())
+            .trim()
+            .to_string()
     }
 }
 
 pub const BUY_ORDER_FLOW_STEPS_MAKER: [StepLabel; 6] = [
     StepLabel {
-        top: "Wait for",
-        bottom: "Seller",
+        top: "flow.wait_for",
+        bottom: "flow.seller",
     },
     StepLabel {
-        top: "Paste",
-        bottom: "Invoice",
+        top: "flow.paste",
+        bottom: "flow.invoice",
     },
     StepLabel {
-        top: "Order",
-        bottom: "Active",
+        top: "flow.order",
+        bottom: "flow.active",
     },
     StepLabel {
-        top: "Send",
-        bottom: "Fiat",
+        top: "flow.send",
+        bottom: "flow.fiat",
     },
     StepLabel {
-        top: "Wait for",
-        bottom: "Sats",
+        top: "flow.wait_for",
+        bottom: "flow.sats",
     },
     StepLabel {
-        top: "Rate",
-        bottom: "Counterparty",
+        top: "flow.rate",
+        bottom: "flow.counterparty",
     },
 ];
 
 pub const BUY_ORDER_FLOW_STEPS_TAKER: [StepLabel; 6] = [
     StepLabel {
-        top: "Pay Hold",
-        bottom: "Invoice",
+        top: "flow.pay_hold",
+        bottom: "flow.invoice",
     },
     StepLabel {
-        top: "Wait for",
-        bottom: "Buyer Invoice",
+        top: "flow.wait_for",
+        bottom: "flow.buyer_invoice",
     },
     StepLabel {
-        top: "Order",
-        bottom: "Active",
+        top: "flow.order",
+        bottom: "flow.active",
     },
     StepLabel {
-        top: "Wait for",
-        bottom: "Fiat",
+        top: "flow.wait_for",
+        bottom: "flow.fiat",
     },
     StepLabel {
-        top: "Release",
-        bottom: "Sats",
+        top: "flow.release",
+        bottom: "flow.sats",
     },
     StepLabel {
-        top: "Rate",
-        bottom: "Counterparty",
+        top: "flow.rate",
+        bottom: "flow.counterparty",
     },
 ];
 
 pub const SELL_ORDER_FLOW_STEPS_MAKER: [StepLabel; 6] = [
     StepLabel {
-        top: "Wait for",
-        bottom: "Buyer",
+        top: "flow.wait_for",
+        bottom: "flow.buyer",
     },
     StepLabel {
-        top: "Pay Hold",
-        bottom: "Invoice",
+        top: "flow.pay_hold",
+        bottom: "flow.invoice",
     },
     StepLabel {
-        top: "Order",
-        bottom: "Active",
+        top: "flow.order",
+        bottom: "flow.active",
     },
     StepLabel {
-        top: "Wait for",
-        bottom: "Fiat",
+        top: "flow.wait_for",
+        bottom: "flow.fiat",
     },
     StepLabel {
-        top: "Release",
-        bottom: "Sats",
+        top: "flow.release",
+        bottom: "flow.sats",
     },
     StepLabel {
-        top: "Rate",
-        bottom: "Counterparty",
+        top: "flow.rate",
+        bottom: "flow.counterparty",
     },
 ];
 
 pub const SELL_ORDER_FLOW_STEPS_TAKER: [StepLabel; 6] = [
     StepLabel {
-        top: "Add",
-        bottom: "Invoice",
+        top: "flow.add",
+        bottom: "flow.invoice",
     },
     StepLabel {
-        top: "Wait for",
-        bottom: "Seller",
+        top: "flow.wait_for",
+        bottom: "flow.seller",
     },
     StepLabel {
-        top: "Order",
-        bottom: "Active",
+        top: "flow.order",
+        bottom: "flow.active",
     },
     StepLabel {
-        top: "Send",
-        bottom: "Fiat",
+        top: "flow.send",
+        bottom: "flow.fiat",
     },
     StepLabel {
-        top: "Wait for",
-        bottom: "Sats",
+        top: "flow.wait_for",
+        bottom: "flow.sats",
     },
     StepLabel {
-        top: "Rate",
-        bottom: "Counterparty",
+        top: "flow.rate",
+        bottom: "flow.counterparty",
     },
 ];
 
 pub const GENERIC_ORDER_FLOW_STEPS_TAKER: [StepLabel; 6] = [
     StepLabel {
-        top: "Payment",
-        bottom: "/ Wait",
+        top: "flow.payment",
+        bottom: "flow.slash_wait",
     },
     StepLabel {
         top: "",
-        bottom: "Invoice",
+        bottom: "flow.invoice",
     },
     StepLabel {
-        top: "Order",
-        bottom: "Active",
+        top: "flow.order",
+        bottom: "flow.active",
     },
     StepLabel {
         top: "",
-        bottom: "Fiat",
+        bottom: "flow.fiat",
     },
     StepLabel {
         top: "",
-        bottom: "Sats",
+        bottom: "flow.sats",
     },
     StepLabel {
         top: "",
-        bottom: "Rate",
+        bottom: "flow.rate",
     },
 ];
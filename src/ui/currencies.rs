@@ -4,6 +4,8 @@
 //! (kind 38385 `fiat_currencies_accepted`). When the instance advertises none
 //! (meaning "all currencies accepted"), we fall back to this curated ISO-4217
 //! list so the user still gets a searchable dropdown with human-readable names.
+//! Each entry also carries its ISO minor units, which `util::fiat` uses to parse
+//! and format decimal amounts.
 //!
 //! Note: we deliberately do not render emoji flags. Regional-indicator flag
 //! emoji are not supported by most terminal fonts and fall back to bare letter
@@ -15,6 +17,8 @@
 pub struct CurrencyMeta {
     pub code: &'static str,
     pub name: &'static str,
+    /// ISO 4217 minor units: digits after the decimal point (2 for cents, 3 for fils).
+    pub minor_units: u8,
 }
 
 /// An owned currency entry shown in the picker (code + human name).
@@ -29,206 +33,282 @@ pub const CURRENCIES: &[CurrencyMeta] = &[
     CurrencyMeta {
         code: "USD",
         name: "US Dollar",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "EUR",
         name: "Euro",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "GBP",
         name: "Pound Sterling",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "JPY",
         name: "Japanese Yen",
+        minor_units: 0,
     },
     CurrencyMeta {
         code: "CHF",
         name: "Swiss Franc",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "CAD",
         name: "Canadian Dollar",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "AUD",
         name: "Australian Dollar",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "NZD",
         name: "New Zealand Dollar",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "CNY",
         name: "Chinese Yuan",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "HKD",
         name: "Hong Kong Dollar",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "SGD",
         name: "Singapore Dollar",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "INR",
         name: "Indian Rupee",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "RUB",
         name: "Russian Ruble",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "BRL",
         name: "Brazilian Real",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "ARS",
         name: "Argentine Peso",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "MXN",
         name: "Mexican Peso",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "CLP",
         name: "Chilean Peso",
+        minor_units: 0,
     },
     CurrencyMeta {
         code: "COP",
         name: "Colombian Peso",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "PEN",
         name: "Peruvian Sol",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "UYU",
         name: "Uruguayan Peso",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "VES",
         name: "Venezuelan Bolívar",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "BOB",
         name: "Bolivian Boliviano",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "PYG",
         name: "Paraguayan Guaraní",
+        minor_units: 0,
     },
     CurrencyMeta {
         code: "CRC",
         name: "Costa Rican Colón",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "GTQ",
         name: "Guatemalan Quetzal",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "DOP",
         name: "Dominican Peso",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "CUP",
         name: "Cuban Peso",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "ZAR",
         name: "South African Rand",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "NGN",
         name: "Nigerian Naira",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "KES",
         name: "Kenyan Shilling",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "GHS",
         name: "Ghanaian Cedi",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "EGP",
         name: "Egyptian Pound",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "MAD",
         name: "Moroccan Dirham",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "TRY",
         name: "Turkish Lira",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "AED",
         name: "UAE Dirham",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "SAR",
         name: "Saudi Riyal",
+        minor_units: 2,
+    },
+    CurrencyMeta {
+        code: "KWD",
+        name: "Kuwaiti Dinar",
+        minor_units: 3,
+    },
+    CurrencyMeta {
+        code: "BHD",
+        name: "Bahraini Dinar",
+        minor_units: 3,
+    },
+    CurrencyMeta {
+        code: "OMR",
+        name: "Omani Rial",
+        minor_units: 3,
+    },
+    CurrencyMeta {
+        code: "JOD",
+        name: "Jordanian Dinar",
+        minor_units: 3,
+    },
+    CurrencyMeta {
+        code: "TND",
+        name: "Tunisian Dinar",
+        minor_units: 3,
     },
     CurrencyMeta {
         code: "ILS",
         name: "Israeli New Shekel",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "PLN",
         name: "Polish Złoty",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "CZK",
         name: "Czech Koruna",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "HUF",
         name: "Hungarian Forint",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "RON",
         name: "Romanian Leu",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "SEK",
         name: "Swedish Krona",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "NOK",
         name: "Norwegian Krone",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "DKK",
         name: "Danish Krone",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "UAH",
         name: "Ukrainian Hryvnia",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "THB",
         name: "Thai Baht",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "IDR",
         name: "Indonesian Rupiah",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "MYR",
         name: "Malaysian Ringgit",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "PHP",
         name: "Philippine Peso",
+        minor_units: 2,
     },
     CurrencyMeta {
         code: "VND",
         name: "Vietnamese Đồng",
+        minor_units: 0,
     },
     CurrencyMeta {
         code: "KRW",
         name: "South Korean Won",
+        minor_units: 0,
     },
 ];
 
@@ -243,6 +323,11 @@ pub fn name_for(code: &str) -> &'static str {
     lookup(code).map(|m| m.name).unwrap_or("")
 }
 
+/// Decimal places allowed for `code`; unknown codes get the common 2.
+pub fn minor_units(code: &str) -> u8 {
+    lookup(code).map(|m| m.minor_units).unwrap_or(2)
+}
+
 /// Build the option list shown in the picker.
 ///
 /// When `accepted` is non-empty, only those instance-advertised codes are shown
@@ -323,6 +408,14 @@ mod tests {
         assert_eq!(opts[1].code, "EUR");
     }
 
+    #[test]
+    fn minor_units_follow_iso_4217_and_default_to_two() {
+        assert_eq!(minor_units("usd"), 2);
+        assert_eq!(minor_units("JPY"), 0);
+        assert_eq!(minor_units("KWD"), 3);
+        assert_eq!(minor_units("XYZ"), 2);
+    }
+
     #[test]
     fn filter_matches_code_prefix_and_name_substring() {
         let opts = resolve_options(&[]);
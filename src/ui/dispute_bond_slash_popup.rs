@@ -1,9 +1,9 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
-use super::{helpers, BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::{helpers, Theme};
 use crate::util::order_utils::BondSlashChoice;
 
 /// Centered bond-slash submenu overlay on top of the finalize popup.
@@ -11,6 +11,7 @@ pub fn render_bond_slash_overlay(
     f: &mut ratatui::Frame,
     parent_area: Rect,
     selected_choice_index: usize,
+    theme: &Theme,
 ) {
     let popup_width = 52.min(parent_area.width.saturating_sub(4));
     let popup_height = 12.min(parent_area.height.saturating_sub(2));
@@ -20,7 +21,7 @@ pub fn render_bond_slash_overlay(
     let block = Block::default()
         .title("⚔️ Bond resolution")
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
 
     let inner = block.inner(popup);
     f.render_widget(block, popup);
@@ -48,11 +49,11 @@ pub fn render_bond_slash_overlay(
         let selected = i == selected_choice_index;
         let style = if selected {
             Style::default()
-                .bg(PRIMARY_COLOR)
-                .fg(BACKGROUND_COLOR)
+                .bg(theme.primary)
+                .fg(theme.background)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Color::White)
+            Style::default().fg(theme.text)
         };
         let prefix = if selected { "▶ " } else { "  " };
         choice_lines.push(Line::from(vec![Span::styled(
@@ -70,21 +71,21 @@ pub fn render_bond_slash_overlay(
             Span::styled(
                 "↑↓",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(" select  "),
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(" apply  "),
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(" back"),
@@ -121,20 +122,22 @@ mod tests {
 
     #[test]
     fn render_bond_slash_overlay_selected_none() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_bond_slash_overlay(f, f.area(), 0))
+            .draw(|f| render_bond_slash_overlay(f, f.area(), 0, theme))
             .unwrap();
         assert_overlay_chrome(terminal.backend().buffer());
     }
 
     #[test]
     fn render_bond_slash_overlay_selected_slash_buyer() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_bond_slash_overlay(f, f.area(), 1))
+            .draw(|f| render_bond_slash_overlay(f, f.area(), 1, theme))
             .unwrap();
         assert_overlay_chrome(terminal.backend().buffer());
     }
@@ -1,9 +1,9 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
-use super::{helpers, BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::{helpers, hit_test, Theme};
 use crate::util::order_utils::BondSlashChoice;
 
 /// Render the dispute finalization confirmation popup
@@ -14,6 +14,7 @@ pub fn render_finalization_confirm(
     is_settle: bool,
     bond: BondSlashChoice,
     selected_button: bool,
+    theme: &Theme,
 ) {
     let bond_ui_enabled =
         crate::util::mostro_info::instance_bonds_enabled(app.mostro_info.as_ref());
@@ -34,7 +35,7 @@ pub fn render_finalization_confirm(
         let block = Block::default()
             .title("❌ Error")
             .borders(Borders::ALL)
-            .style(Style::default().bg(BACKGROUND_COLOR).fg(Color::Red));
+            .style(Style::default().bg(theme.background).fg(theme.negative));
 
         let inner = block.inner(popup);
         f.render_widget(block, popup);
@@ -57,7 +58,7 @@ pub fn render_finalization_confirm(
         lines.push(Line::from(""));
         lines.push(Line::from(vec![Span::styled(
             "Press ESC to close",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )]));
 
         let paragraph = Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center);
@@ -76,20 +77,20 @@ pub fn render_finalization_confirm(
         (
             "💰 Pay buyer",
             "Settle in favor of the buyer.\nBuyer receives the full escrow amount.",
-            Color::Green,
+            theme.positive,
         )
     } else {
         (
             "↩️ Refund seller",
             "Cancel the order and refund the seller.\nSeller receives the full escrow amount back.",
-            Color::Red,
+            theme.negative,
         )
     };
 
     let block = Block::default()
         .title(format!("⚠️ Confirm {action_title}"))
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
 
     let inner_area = block.inner(popup);
     f.render_widget(block, popup);
@@ -125,7 +126,7 @@ pub fn render_finalization_confirm(
             ),
             Span::styled(
                 &selected_dispute.dispute_id,
-                Style::default().fg(PRIMARY_COLOR),
+                Style::default().fg(theme.primary),
             ),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -135,7 +136,7 @@ pub fn render_finalization_confirm(
     // Action description
     let description_lines: Vec<Line> = action_description
         .lines()
-        .map(|line| Line::from(vec![Span::styled(line, Style::default().fg(Color::White))]))
+        .map(|line| Line::from(vec![Span::styled(line, Style::default().fg(theme.text))]))
         .collect();
     f.render_widget(
         Paragraph::new(description_lines).alignment(ratatui::layout::Alignment::Center),
@@ -146,7 +147,7 @@ pub fn render_finalization_confirm(
         f.render_widget(
             Paragraph::new(Line::from(vec![
                 Span::styled("Bond: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::styled(bond.label(), Style::default().fg(PRIMARY_COLOR)),
+                Span::styled(bond.label(), Style::default().fg(theme.primary)),
             ]))
             .alignment(ratatui::layout::Alignment::Center),
             chunks[bond_chunk],
@@ -176,12 +177,13 @@ pub fn render_finalization_confirm(
         ],
     )
     .split(centered_button_area);
+    hit_test::record_yes_no(button_chunks[0], button_chunks[2]);
 
     // YES button - always use green when highlighted
     let yes_style = if selected_button {
         Style::default()
-            .bg(Color::Green)
-            .fg(Color::Black)
+            .bg(theme.positive)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
         Style::default()
@@ -201,9 +203,9 @@ pub fn render_finalization_confirm(
             "✓ YES",
             Style::default()
                 .fg(if selected_button {
-                    Color::Black
+                    theme.on_fill
                 } else {
-                    Color::Green
+                    theme.positive
                 })
                 .add_modifier(Modifier::BOLD),
         )]))
@@ -214,11 +216,13 @@ pub fn render_finalization_confirm(
     // NO button
     let no_style = if !selected_button {
         Style::default()
-            .bg(Color::Red)
-            .fg(Color::Black)
+            .bg(theme.negative)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        Style::default()
+            .fg(theme.negative)
+            .add_modifier(Modifier::BOLD)
     };
 
     let no_block = Block::default().borders(Borders::ALL).style(no_style);
@@ -233,9 +237,9 @@ pub fn render_finalization_confirm(
             "✗ NO",
             Style::default()
                 .fg(if !selected_button {
-                    Color::Black
+                    theme.on_fill
                 } else {
-                    Color::Red
+                    theme.negative
                 })
                 .add_modifier(Modifier::BOLD),
         )]))
@@ -250,7 +254,7 @@ pub fn render_finalization_confirm(
             Span::styled(
                 "Left/Right",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to select, ", Style::default()),
@@ -258,7 +262,7 @@ pub fn render_finalization_confirm(
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to confirm", Style::default()),
@@ -274,7 +278,7 @@ pub fn render_finalization_confirm(
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to cancel", Style::default()),
@@ -1,10 +1,10 @@
 use ratatui::layout::{Constraint, Direction, Flex, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use super::dispute_bond_slash_popup;
-use super::{AppState, BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::{AppState, Theme};
 use crate::ui::helpers::{format_local_timestamp, format_user_rating, is_dispute_finalized};
 use crate::util::order_utils::BondSlashChoice;
 
@@ -18,6 +18,7 @@ pub fn render_finalization_popup(
     slash_submenu_open: bool,
     slash_submenu_index: usize,
 ) {
+    let theme = &app.theme;
     let bond_ui_enabled =
         crate::util::mostro_info::instance_bonds_enabled(app.mostro_info.as_ref());
     // Find the dispute by dispute_id (or fallback to order_id for backwards compatibility)
@@ -37,7 +38,7 @@ pub fn render_finalization_popup(
         let block = Block::default()
             .title("❌ Error")
             .borders(Borders::ALL)
-            .style(Style::default().bg(BACKGROUND_COLOR).fg(Color::Red));
+            .style(Style::default().bg(theme.background).fg(theme.negative));
 
         // Calculate inner area (excluding borders)
         let inner = block.inner(popup);
@@ -64,7 +65,7 @@ pub fn render_finalization_popup(
         lines.push(Line::from(""));
         lines.push(Line::from(vec![Span::styled(
             "Press ESC or ENTER to close",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )]));
 
         let paragraph = Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center);
@@ -84,7 +85,7 @@ pub fn render_finalization_popup(
         let block = Block::default()
             .title("❌ Data Integrity Error")
             .borders(Borders::ALL)
-            .style(Style::default().bg(BACKGROUND_COLOR).fg(Color::Red));
+            .style(Style::default().bg(theme.background).fg(theme.negative));
 
         let inner = block.inner(popup);
         f.render_widget(block, popup);
@@ -115,7 +116,7 @@ pub fn render_finalization_popup(
         lines.push(Line::from(""));
         lines.push(Line::from(vec![Span::styled(
             "Press ESC or ENTER to close",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )]));
 
         let paragraph = Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center);
@@ -136,7 +137,7 @@ pub fn render_finalization_popup(
     let block = Block::default()
         .title("⚖️ Finalize Dispute")
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     f.render_widget(block, popup);
 
     // Inner layout for content
@@ -157,7 +158,7 @@ pub fn render_finalization_popup(
     .split(inner_area);
 
     // Content area - scrollable details
-    render_dispute_details(f, chunks[0], selected_dispute);
+    render_dispute_details(f, chunks[0], selected_dispute, theme);
 
     // Buttons area - pass dispute status to check if finalized
     let dispute_is_finalized = is_dispute_finalized(selected_dispute).unwrap_or(false);
@@ -168,10 +169,11 @@ pub fn render_finalization_popup(
         bond,
         dispute_is_finalized,
         bond_ui_enabled,
+        theme,
     );
 
     if bond_ui_enabled && slash_submenu_open {
-        dispute_bond_slash_popup::render_bond_slash_overlay(f, popup, slash_submenu_index);
+        dispute_bond_slash_popup::render_bond_slash_overlay(f, popup, slash_submenu_index, theme);
     }
 }
 
@@ -180,6 +182,7 @@ fn render_dispute_details(
     f: &mut ratatui::Frame,
     area: Rect,
     dispute: &crate::models::AdminDispute,
+    theme: &Theme,
 ) {
     // Truncate pubkeys for display
     let truncate_pubkey = |pubkey: &str| -> String {
@@ -242,14 +245,14 @@ fn render_dispute_details(
     let mut lines = vec![
         Line::from(vec![
             Span::styled("Order ID: ", Style::default().add_modifier(Modifier::BOLD)),
-            Span::styled(&dispute.id, Style::default().fg(PRIMARY_COLOR)),
+            Span::styled(&dispute.id, Style::default().fg(theme.primary)),
         ]),
         Line::from(vec![
             Span::styled(
                 "Dispute ID: ",
                 Style::default().add_modifier(Modifier::BOLD),
             ),
-            Span::styled(&dispute.dispute_id, Style::default().fg(Color::Cyan)),
+            Span::styled(&dispute.dispute_id, Style::default().fg(theme.accent)),
         ]),
         Line::from(""),
         Line::from(vec![
@@ -281,7 +284,7 @@ fn render_dispute_details(
             "━━━ PARTIES ━━━",
             Style::default()
                 .add_modifier(Modifier::BOLD)
-                .fg(PRIMARY_COLOR),
+                .fg(theme.primary),
         )]),
         Line::from(""),
     ];
@@ -295,12 +298,12 @@ fn render_dispute_details(
     lines.push(Line::from(vec![Span::styled(
         buyer_role_str,
         Style::default()
-            .fg(Color::Green)
+            .fg(theme.positive)
             .add_modifier(Modifier::BOLD),
     )]));
     lines.push(Line::from(vec![
         Span::raw("  Pubkey: "),
-        Span::styled(&buyer_pubkey_display, Style::default().fg(PRIMARY_COLOR)),
+        Span::styled(&buyer_pubkey_display, Style::default().fg(theme.primary)),
         Span::raw("  |  Privacy: "),
         Span::raw(buyer_privacy),
     ]));
@@ -315,11 +318,13 @@ fn render_dispute_details(
     };
     lines.push(Line::from(vec![Span::styled(
         seller_role_str,
-        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        Style::default()
+            .fg(theme.negative)
+            .add_modifier(Modifier::BOLD),
     )]));
     lines.push(Line::from(vec![
         Span::raw("  Pubkey: "),
-        Span::styled(&seller_pubkey_display, Style::default().fg(PRIMARY_COLOR)),
+        Span::styled(&seller_pubkey_display, Style::default().fg(theme.primary)),
         Span::raw("  |  Privacy: "),
         Span::raw(seller_privacy),
     ]));
@@ -331,14 +336,14 @@ fn render_dispute_details(
         "━━━ FINANCIAL ━━━",
         Style::default()
             .add_modifier(Modifier::BOLD)
-            .fg(PRIMARY_COLOR),
+            .fg(theme.primary),
     )]));
     lines.push(Line::from(""));
     lines.push(Line::from(vec![
         Span::styled("Amount: ", Style::default().add_modifier(Modifier::BOLD)),
         Span::styled(
             format!("{} sats", dispute.amount),
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme.warning),
         ),
     ]));
 
@@ -378,6 +383,7 @@ fn render_action_buttons(
     bond: BondSlashChoice,
     is_finalized: bool,
     bond_ui_enabled: bool,
+    theme: &Theme,
 ) {
     let button_chunks = if bond_ui_enabled {
         Layout::new(
@@ -399,16 +405,14 @@ fn render_action_buttons(
 
     // Button 0: Pay buyer (settle in buyer's favor)
     let pay_buyer_style = if is_finalized {
-        Style::default()
-            .fg(Color::DarkGray)
-            .add_modifier(Modifier::DIM)
+        Style::default().fg(theme.muted).add_modifier(Modifier::DIM)
     } else if selected_button == 0 {
         Style::default()
-            .bg(Color::Green)
-            .fg(Color::Black)
+            .bg(theme.positive)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::Green)
+        Style::default().fg(theme.positive)
     };
     let pay_buyer_block = Block::default()
         .title("💰 Pay buyer")
@@ -427,16 +431,14 @@ fn render_action_buttons(
 
     // Button 1: Refund seller
     let refund_seller_style = if is_finalized {
-        Style::default()
-            .fg(Color::DarkGray)
-            .add_modifier(Modifier::DIM)
+        Style::default().fg(theme.muted).add_modifier(Modifier::DIM)
     } else if selected_button == 1 {
         Style::default()
-            .bg(Color::Red)
-            .fg(Color::Black)
+            .bg(theme.negative)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::Red)
+        Style::default().fg(theme.negative)
     };
     let refund_seller_block = Block::default()
         .title("↩️ Refund seller")
@@ -457,11 +459,11 @@ fn render_action_buttons(
         let bond_label = bond.label();
         let bond_style = if selected_button == 2 {
             Style::default()
-                .bg(PRIMARY_COLOR)
-                .fg(BACKGROUND_COLOR)
+                .bg(theme.primary)
+                .fg(theme.background)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(PRIMARY_COLOR)
+            Style::default().fg(theme.primary)
         };
         let bond_block = Block::default()
             .title("Bond")
@@ -1,16 +1,17 @@
 use std::sync::{Arc, Mutex};
 
-use mostro_core::prelude::*;
 use ratatui::layout::Alignment;
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 
 use crate::shared::permissions::SolverPermission;
 use crate::ui::orders::strip_new_order_messages_and_clamp_selected;
 use crate::ui::*;
+use crate::util::active_proxy;
 use crate::util::fatal::request_fatal_restart;
+use crate::util::fiat::BookOrder;
 
 /// Preferred content height so bordered tab panels can still show one data row
 /// (top border + row + bottom border) after the panel drops its own header.
@@ -53,13 +54,31 @@ fn shell_chrome_heights(total_height: u16, show_status: bool) -> (u16, u16) {
 }
 
 /// Main UI draw function, extracted from `ui::mod`.
+///
+/// Also rebuilds `app.hit_map` from the regions renderers record while drawing.
 pub fn ui_draw(
     f: &mut ratatui::Frame,
     app: &mut AppState,
-    orders: &Arc<Mutex<Vec<SmallOrder>>>,
+    orders: &Arc<Mutex<Vec<BookOrder>>>,
     disputes: &Arc<Mutex<Vec<mostro_core::prelude::Dispute>>>,
     status_line: Option<&[String]>,
 ) {
+    hit_test::begin_frame();
+    app.graphics_overlay.begin_frame();
+    draw_frame(f, app, orders, disputes, status_line);
+    app.hit_map = hit_test::finish_frame(&app.mode);
+}
+
+fn draw_frame(
+    f: &mut ratatui::Frame,
+    app: &mut AppState,
+    orders: &Arc<Mutex<Vec<BookOrder>>>,
+    disputes: &Arc<Mutex<Vec<mostro_core::prelude::Dispute>>>,
+    status_line: Option<&[String]>,
+) {
+    // Copied out so renderers that take `&mut AppState` can still borrow the palette.
+    let palette = app.theme;
+    let theme = &palette;
     let (tab_h, status_h) = shell_chrome_heights(f.area().height, status_line.is_some());
     let chunks = Layout::new(
         Direction::Vertical,
@@ -72,13 +91,13 @@ pub fn ui_draw(
     .split(f.area());
 
     if tab_h > 0 {
-        tabs::render_tabs(f, chunks[0], app.active_tab, app.user_role);
+        tabs::render_tabs(f, chunks[0], app.active_tab, app.user_role, theme);
     }
 
     // Fatal restart prompt: render only the popup overlay (no additional locks).
     if app.fatal_exit_on_close {
         if let UiMode::OperationResult(result) = &app.mode {
-            operation_result::render_operation_result(f, result);
+            operation_result::render_operation_result(f, result, theme);
         }
         return;
     }
@@ -125,6 +144,7 @@ pub fn ui_draw(
                 content_area,
                 &messages,
                 app.selected_message_idx,
+                theme,
             )
         }
         (Tab::User(UserTab::MostroInfo), UserRole::User) => {
@@ -135,12 +155,21 @@ pub fn ui_draw(
             content_area,
             app.user_role,
             app.selected_settings_option,
+            app.theme_preset,
+            app.automation.enabled,
+            theme,
         ),
         (Tab::User(UserTab::CreateNewOrder), UserRole::User) => {
             if let UiMode::UserMode(UserMode::CreatingOrder(form)) = &app.mode {
-                order_form::render_order_form(f, content_area, form, app.mostro_info.as_ref());
+                order_form::render_order_form(
+                    f,
+                    content_area,
+                    form,
+                    app.mostro_info.as_ref(),
+                    theme,
+                );
             } else {
-                order_form::render_form_initializing(f, content_area);
+                order_form::render_form_initializing(f, content_area, theme);
             }
         }
         (Tab::Admin(AdminTab::DisputesPending), UserRole::Admin) => {
@@ -149,6 +178,9 @@ pub fn ui_draw(
         (Tab::Admin(AdminTab::DisputesInProgress), UserRole::Admin) => {
             tabs::disputes_in_progress_tab::render_disputes_in_progress(f, content_area, app)
         }
+        (Tab::Admin(AdminTab::DisputeArchive), UserRole::Admin) => {
+            tabs::dispute_archive_tab::render_dispute_archive_tab(f, content_area, app)
+        }
         (Tab::Admin(AdminTab::Observer), UserRole::Admin) => {
             tabs::observer_tab::render_observer_tab(f, content_area, app)
         }
@@ -161,15 +193,18 @@ pub fn ui_draw(
                 content_area,
                 app.user_role,
                 app.selected_settings_option,
+                app.theme_preset,
+                app.automation.enabled,
+                theme,
             )
         }
         (Tab::User(UserTab::Exit), UserRole::User)
         | (Tab::Admin(AdminTab::Exit), UserRole::Admin) => {
-            tabs::tab_content::render_exit_tab(f, content_area)
+            tabs::tab_content::render_exit_tab(f, content_area, theme)
         }
         _ => {
             // Fallback for invalid combinations
-            tabs::tab_content::render_coming_soon(f, content_area, "Unknown")
+            tabs::tab_content::render_coming_soon(f, content_area, "Unknown", theme)
         }
     }
 
@@ -185,7 +220,19 @@ pub fn ui_draw(
                     0
                 }
             };
-            status::render_status_bar(f, chunks[2], lines, pending_count);
+            let proxy_label = active_proxy().map(|proxy| proxy.label());
+            // No badge in `--demo`: the throwaway home has a single, unnamed profile.
+            let profile_label =
+                (!crate::demo::is_demo_mode()).then(crate::profile::active_profile_label);
+            status::render_status_bar(
+                f,
+                chunks[2],
+                lines,
+                pending_count,
+                profile_label,
+                proxy_label.as_deref(),
+                theme,
+            );
         }
     }
 
@@ -195,36 +242,40 @@ pub fn ui_draw(
         selected_button,
     }) = &app.mode
     {
-        order_confirm::render_order_confirm(f, form, *selected_button);
+        order_confirm::render_order_confirm(f, form, *selected_button, theme);
     }
 
     // Waiting for Mostro popup overlay (user mode only)
     if let UiMode::UserMode(UserMode::WaitingForMostro(_)) = &app.mode {
-        waiting::render_waiting(f);
+        waiting::render_waiting(f, theme);
     }
 
     // Waiting for take order popup overlay (user mode only)
     if let UiMode::UserMode(UserMode::WaitingTakeOrder(_)) = &app.mode {
-        waiting::render_waiting(f);
+        waiting::render_waiting(f, theme);
     }
 
     // Waiting for AddInvoice popup overlay (user mode only)
     if let UiMode::UserMode(UserMode::WaitingAddInvoice) = &app.mode {
-        waiting::render_waiting(f);
+        waiting::render_waiting(f, theme);
     }
 
     // Waiting for take dispute popup overlay (admin mode only)
     if let UiMode::AdminMode(AdminMode::WaitingTakeDispute(_)) = &app.mode {
-        waiting::render_waiting(f);
+        waiting::render_waiting(f, theme);
     }
     // Waiting for add solver popup overlay (admin mode only)
     if let UiMode::AdminMode(AdminMode::WaitingAddSolver) = &app.mode {
-        waiting::render_waiting_with_message(f, "Adding solver and waiting for confirmation...");
+        waiting::render_waiting_with_message(
+            f,
+            "Adding solver and waiting for confirmation...",
+            theme,
+        );
     }
 
     // Operation result popup overlay (shared)
     if let UiMode::OperationResult(result) = &app.mode {
-        operation_result::render_operation_result(f, result);
+        operation_result::render_operation_result(f, result, theme);
     }
 
     // Generate new keys flow popups
@@ -233,10 +284,11 @@ pub fn ui_draw(
             f,
             app.user_role == UserRole::Admin,
             *selected_button,
+            theme,
         );
     }
     if let UiMode::BackupNewKeys(mnemonic) = &app.mode {
-        generate_keys_popup::render_backup_new_keys(f, mnemonic.as_str());
+        generate_keys_popup::render_backup_new_keys(f, mnemonic.as_str(), theme);
     }
 
     // Help popup (Ctrl+H)
@@ -244,9 +296,20 @@ pub fn ui_draw(
         help_popup::render_help_popup(f, app, *tab);
     }
 
+    // Chat image preview (V in a Save attachment list)
+    if let UiMode::ImagePreview(state, _) = &app.mode {
+        let state = state.clone();
+        image_preview_popup::render_image_preview_popup(f, app, &state);
+    }
+
+    // Command palette (Ctrl+P)
+    if let UiMode::CommandPalette(state, _) = &app.mode {
+        command_palette::render_command_palette(f, app, state);
+    }
+
     // Settings: full option reference (Shift+H)
     if let UiMode::SettingsInstructionsPopup(role, _) = &app.mode {
-        help_popup::render_settings_instructions_popup(f, *role);
+        help_popup::render_settings_instructions_popup(f, *role, theme);
     }
 
     // Save attachment popup (Ctrl+S in dispute chat)
@@ -269,6 +332,11 @@ pub fn ui_draw(
         crate::ui::send_attachment_picker::render_user_send_attachment_picker(f, app);
     }
 
+    // Size and privacy summary before an attachment upload (Enter in the picker)
+    if let UiMode::UserAttachmentSendConfirm(pending) = &app.mode {
+        crate::ui::send_attachment_picker::render_user_attachment_send_confirm(f, pending, theme);
+    }
+
     // Shared settings popups
     if let UiMode::AddMostroPubkey(key_state) = &app.mode {
         key_input_popup::render_key_input_popup(
@@ -278,6 +346,7 @@ pub fn ui_draw(
             "npub... / hex...",
             key_state,
             false,
+            theme,
         );
     }
     if let UiMode::ConfirmMostroPubkey(key_string, selected_button) = &app.mode {
@@ -286,6 +355,7 @@ pub fn ui_draw(
             "🌐 Confirm Mostro Pubkey",
             key_string,
             *selected_button,
+            theme,
         );
     }
     if let UiMode::AddRelay(key_state) = &app.mode {
@@ -296,6 +366,7 @@ pub fn ui_draw(
             "wss://...",
             key_state,
             false,
+            theme,
         );
     }
     if let UiMode::ConfirmRelay(relay_string, selected_button) = &app.mode {
@@ -304,6 +375,7 @@ pub fn ui_draw(
             "📡 Confirm Relay",
             relay_string,
             *selected_button,
+            theme,
         );
     }
     if let UiMode::AddLnAddress(key_state) = &app.mode {
@@ -314,6 +386,7 @@ pub fn ui_draw(
             "you@wallet.example.com",
             key_state,
             false,
+            theme,
         );
     }
     if let UiMode::ConfirmLnAddress(addr, selected_button) = &app.mode {
@@ -323,6 +396,7 @@ pub fn ui_draw(
             addr,
             *selected_button,
             Some("Verify LNURL endpoint and save this address to settings.toml?"),
+            theme,
         );
     }
     if let UiMode::ConfirmClearLnAddress(selected_button) = &app.mode {
@@ -332,6 +406,7 @@ pub fn ui_draw(
             "",
             *selected_button,
             Some("Remove the saved buyer Lightning address from settings?"),
+            theme,
         );
     }
     if let UiMode::ConfirmSavedLnAddressForInvoice(_, selected_button) = &app.mode {
@@ -346,7 +421,12 @@ Confirm using this address from Settings as your invoice?\n\n\
 Yes: fill invoice from Settings.\n\
 No: paste BOLT11 or Lightning address manually."
         );
-        admin_key_confirm::render_saved_ln_address_invoice_confirm(f, *selected_button, &body);
+        admin_key_confirm::render_saved_ln_address_invoice_confirm(
+            f,
+            *selected_button,
+            &body,
+            theme,
+        );
     }
     if let UiMode::AddCurrency(key_state) = &app.mode {
         key_input_popup::render_key_input_popup(
@@ -356,6 +436,7 @@ No: paste BOLT11 or Lightning address manually."
             "USD",
             key_state,
             false,
+            theme,
         );
     }
     if let UiMode::ConfirmCurrency(currency_string, selected_button) = &app.mode {
@@ -365,6 +446,7 @@ No: paste BOLT11 or Lightning address manually."
             currency_string,
             *selected_button,
             Some("Do you want to add this currency filter?"),
+            theme,
         );
     }
     if let UiMode::ConfirmClearCurrencies(selected_button) = &app.mode {
@@ -374,6 +456,7 @@ No: paste BOLT11 or Lightning address manually."
             "",
             *selected_button,
             Some("Are you sure you want to clear all currencies filters?"),
+            theme,
         );
     }
     if let UiMode::ConfirmDeleteHistoryOrder(order_id, selected_button) = &app.mode {
@@ -383,8 +466,24 @@ No: paste BOLT11 or Lightning address manually."
             &order_id.to_string(),
             *selected_button,
             Some("Delete selected terminal order from local database history?"),
+            theme,
         );
     }
+    if let UiMode::RetentionPreview(plan, selected_button) = &app.mode {
+        crate::ui::retention_preview::render_retention_preview(f, plan, *selected_button, theme);
+    }
+    if let UiMode::SavedSearchAlert(found, selected_button) = &app.mode {
+        crate::ui::saved_search_alert::render_saved_search_alert(f, found, *selected_button, theme);
+    }
+    if let UiMode::ConfirmSettingsReload(change, selected_button) = &app.mode {
+        crate::ui::settings_reload::render_settings_reload(f, change, *selected_button, theme);
+    }
+    if let UiMode::BackupForm(state) = &app.mode {
+        crate::ui::backup_popup::render_backup_form(f, state, theme);
+    }
+    if let UiMode::ConfirmRestoreBackup(staged, selected_button) = &app.mode {
+        crate::ui::backup_popup::render_restore_confirm(f, staged, *selected_button, theme);
+    }
     if let UiMode::ConfirmBulkDeleteHistory(selected_button) = &app.mode {
         admin_key_confirm::render_admin_key_confirm_with_message(
             f,
@@ -392,12 +491,13 @@ No: paste BOLT11 or Lightning address manually."
             "",
             *selected_button,
             Some("Delete all success/canceled orders from local database history?"),
+            theme,
         );
     }
 
     // Admin key input popup overlay
     if let UiMode::AdminMode(AdminMode::AddSolver(add_solver_state)) = &app.mode {
-        render_add_solver_popup(f, add_solver_state);
+        render_add_solver_popup(f, add_solver_state, theme);
     }
     if let UiMode::AdminMode(AdminMode::SetupAdminKey(key_state)) = &app.mode {
         key_input_popup::render_key_input_popup(
@@ -407,6 +507,7 @@ No: paste BOLT11 or Lightning address manually."
             "nsec... / hex...",
             key_state,
             true,
+            theme,
         );
     }
 
@@ -422,6 +523,7 @@ No: paste BOLT11 or Lightning address manually."
                 "Do you want to take the dispute with id: {}?",
                 dispute_id
             )),
+            theme,
         );
     }
     if let UiMode::AdminMode(AdminMode::ConfirmAddSolver {
@@ -439,6 +541,7 @@ No: paste BOLT11 or Lightning address manually."
                 "Add this pubkey as dispute solver with '{}' permission?",
                 permission.as_label()
             )),
+            theme,
         );
     }
     if let UiMode::AdminMode(AdminMode::ConfirmAdminKey(key_string, selected_button)) = &app.mode {
@@ -447,12 +550,13 @@ No: paste BOLT11 or Lightning address manually."
             "🔐 Confirm Admin Key",
             key_string,
             *selected_button,
+            theme,
         );
     }
 
     // Exit confirmation popup
     if let UiMode::ConfirmExit(selected_button) = &app.mode {
-        exit_confirm::render_exit_confirm(f, *selected_button);
+        exit_confirm::render_exit_confirm(f, *selected_button, theme);
     }
 
     // Dispute finalization popup (bond slash submenu overlays when open)
@@ -490,17 +594,18 @@ No: paste BOLT11 or Lightning address manually."
             *is_settle,
             *bond,
             *selected_button,
+            theme,
         );
     }
 
     // Waiting for dispute finalization
     if let UiMode::AdminMode(AdminMode::WaitingDisputeFinalization(_)) = &app.mode {
-        waiting::render_waiting(f);
+        waiting::render_waiting(f, theme);
     }
 
     // Taking order popup overlay (user mode only)
     if let UiMode::UserMode(UserMode::TakingOrder(take_state)) = &app.mode {
-        order_take::render_order_take(f, take_state);
+        order_take::render_order_take(f, take_state, theme);
     }
 
     // New message notification popup overlay
@@ -510,25 +615,31 @@ No: paste BOLT11 or Lightning address manually."
             notification,
             action.clone(),
             invoice_state,
+            app.mostro_info.as_ref(),
+            theme,
         );
     }
 
     // Viewing message popup overlay
     if let UiMode::ViewingMessage(view_state) = &app.mode {
-        tabs::tab_content::render_message_view(f, view_state);
+        tabs::tab_content::render_message_view(f, view_state, theme);
     }
 
     if let UiMode::RatingOrder(state) = &app.mode {
-        tabs::tab_content::render_rating_order(f, state);
+        tabs::tab_content::render_rating_order(f, state, theme);
     }
 
     // Non-blocking offline overlay (does not affect current mode).
     if let Some(message) = app.offline_overlay_message.as_deref() {
-        offline_overlay::render_offline_overlay(f, message);
+        offline_overlay::render_offline_overlay(f, message, theme);
     }
 }
 
-fn render_add_solver_popup(f: &mut ratatui::Frame, add_solver_state: &AddSolverState) {
+fn render_add_solver_popup(
+    f: &mut ratatui::Frame,
+    add_solver_state: &AddSolverState,
+    theme: &Theme,
+) {
     let area = f.area();
     let popup = crate::ui::helpers::create_centered_popup(area, 84, 13);
     f.render_widget(Clear, popup);
@@ -536,7 +647,7 @@ fn render_add_solver_popup(f: &mut ratatui::Frame, add_solver_state: &AddSolverS
     let block = Block::default()
         .title("Add Solver")
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     f.render_widget(block, popup);
 
     let chunks = Layout::new(
@@ -558,7 +669,7 @@ fn render_add_solver_popup(f: &mut ratatui::Frame, add_solver_state: &AddSolverS
         Paragraph::new("Enter solver pubkey (npub... or hex):")
             .style(
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             )
             .alignment(Alignment::Center),
@@ -575,15 +686,15 @@ fn render_add_solver_popup(f: &mut ratatui::Frame, add_solver_state: &AddSolverS
         Paragraph::new(input_display)
             .style(
                 Style::default()
-                    .fg(PRIMARY_COLOR)
-                    .bg(Color::Black)
+                    .fg(theme.primary)
+                    .bg(theme.input_bg)
                     .add_modifier(Modifier::BOLD),
             )
             .wrap(Wrap { trim: true })
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .style(Style::default().fg(PRIMARY_COLOR)),
+                    .style(Style::default().fg(theme.primary)),
             ),
         chunks[2],
     );
@@ -591,23 +702,23 @@ fn render_add_solver_popup(f: &mut ratatui::Frame, add_solver_state: &AddSolverS
     let is_read_selected = add_solver_state.permission == SolverPermission::Read;
     let read_style = if is_read_selected {
         Style::default()
-            .fg(Color::Black)
-            .bg(PRIMARY_COLOR)
+            .fg(theme.on_fill)
+            .bg(theme.primary)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::Gray)
+        Style::default().fg(theme.secondary)
     };
     let read_write_style = if is_read_selected {
-        Style::default().fg(Color::Gray)
+        Style::default().fg(theme.secondary)
     } else {
         Style::default()
-            .fg(Color::Black)
-            .bg(PRIMARY_COLOR)
+            .fg(theme.on_fill)
+            .bg(theme.primary)
             .add_modifier(Modifier::BOLD)
     };
 
     let permission_line = Line::from(vec![
-        Span::styled("Permission: ", Style::default().fg(Color::White)),
+        Span::styled("Permission: ", Style::default().fg(theme.text)),
         Span::styled(" [ Read ] ", read_style),
         Span::styled("   ", Style::default()),
         Span::styled(" [ Read-Write ] ", read_write_style),
@@ -620,7 +731,7 @@ fn render_add_solver_popup(f: &mut ratatui::Frame, add_solver_state: &AddSolverS
     f.render_widget(
         Paragraph::new(Line::from(Span::styled(
             "(Left/Right to switch)",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )))
         .alignment(Alignment::Center),
         chunks[5],
@@ -632,7 +743,7 @@ fn render_add_solver_popup(f: &mut ratatui::Frame, add_solver_state: &AddSolverS
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to continue", Style::default()),
@@ -641,13 +752,17 @@ fn render_add_solver_popup(f: &mut ratatui::Frame, add_solver_state: &AddSolverS
         chunks[6],
     );
 
-    crate::ui::helpers::render_help_text(f, chunks[7], "Press ", "Esc", " to cancel");
+    crate::ui::helpers::render_help_text(f, chunks[7], "Press ", "Esc", " to cancel", theme);
 }
 
 #[cfg(test)]
 mod tests {
     use super::{shell_chrome_heights, ui_draw, FULL_STATUS_BAR_HEIGHT, FULL_TAB_BAR_HEIGHT};
-    use crate::ui::{AppState, UserRole};
+    use crate::ui::helpers::build_observer_scrollview_content;
+    use crate::ui::{
+        AppState, ChatSender, DisputeChatMessage, Tab, Theme, ThemePreset, UserRole, UserTab,
+    };
+    use crate::util::fiat::BookOrder;
     use mostro_core::prelude::*;
     use ratatui::backend::TestBackend;
     use ratatui::Terminal;
@@ -665,6 +780,46 @@ mod tests {
         flat.contains(needle)
     }
 
+    /// Top-left cells of every occurrence of `needle` within a single row.
+    fn find_text(buf: &ratatui::buffer::Buffer, needle: &str) -> Vec<(u16, u16)> {
+        let mut found = Vec::new();
+        for y in 0..buf.area.height {
+            let row: Vec<&str> = (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect();
+            for x in 0..row.len() {
+                if row[x..].concat().starts_with(needle) {
+                    found.push((x as u16, y));
+                }
+            }
+        }
+        found
+    }
+
+    fn draw_user_tab(
+        app: &mut AppState,
+        tab: UserTab,
+        orders: Vec<SmallOrder>,
+    ) -> Terminal<TestBackend> {
+        app.active_tab = Tab::User(tab);
+        let orders: Vec<BookOrder> = orders.into_iter().map(BookOrder::whole).collect();
+        let orders = Arc::new(Mutex::new(orders));
+        let disputes = Arc::new(Mutex::new(Vec::new()));
+        let mut terminal = Terminal::new(TestBackend::new(140, 30)).expect("terminal");
+        terminal
+            .draw(|f| ui_draw(f, app, &orders, &disputes, None))
+            .expect("draw");
+        terminal
+    }
+
+    fn chat_message(sender: ChatSender, content: &str) -> DisputeChatMessage {
+        DisputeChatMessage {
+            sender,
+            content: content.to_string(),
+            timestamp: 1_700_000_000,
+            target_party: None,
+            attachment: None,
+        }
+    }
+
     fn initiated_dispute(nibble: u8) -> Dispute {
         let mut dispute = Dispute::new(Uuid::new_v4(), "active".to_string());
         dispute.id = Uuid::from_bytes([nibble * 0x11; 16]);
@@ -692,6 +847,75 @@ mod tests {
         assert_eq!(shell_chrome_heights(3, true), (0, 0));
     }
 
+    /// Each built-in preset reaches the widgets the palette exists for: buy/sell cells in the
+    /// order book, the New Order form field strips and the dispute chat party headers.
+    #[test]
+    fn every_builtin_preset_colors_buy_sell_chat_parties_and_form_fields() {
+        let presets = [
+            ThemePreset::Dark,
+            ThemePreset::Light,
+            ThemePreset::HighContrast,
+            ThemePreset::ColorBlindSafe,
+        ];
+        for preset in presets {
+            let palette = Theme::builtin(preset);
+            let mut app = AppState::new(UserRole::User);
+            app.theme = palette;
+
+            let book = [Kind::Buy, Kind::Sell, Kind::Buy, Kind::Sell]
+                .into_iter()
+                .map(|kind| SmallOrder {
+                    id: Some(Uuid::new_v4()),
+                    kind: Some(kind),
+                    fiat_code: "USD".to_string(),
+                    fiat_amount: 100,
+                    payment_method: "SEPA".to_string(),
+                    ..Default::default()
+                })
+                .collect();
+            let terminal = draw_user_tab(&mut app, UserTab::Orders, book);
+            let buf = terminal.backend().buffer();
+            // The selected row is drawn with the highlight style; each kind is listed twice.
+            for (label, color) in [("buy", palette.buy), ("sell", palette.sell)] {
+                assert!(
+                    find_text(buf, label)
+                        .into_iter()
+                        .any(|(x, y)| buf[(x, y)].fg == color),
+                    "{preset:?} {label}"
+                );
+            }
+
+            let terminal = draw_user_tab(&mut app, UserTab::CreateNewOrder, Vec::new());
+            let buf = terminal.backend().buffer();
+            assert!(
+                buf.content().iter().any(|cell| cell.bg == palette.field_bg),
+                "{preset:?} form field background"
+            );
+
+            let messages = [
+                chat_message(ChatSender::Admin, "hello"),
+                chat_message(ChatSender::Buyer, "paid"),
+                chat_message(ChatSender::Seller, "received"),
+            ];
+            let content = build_observer_scrollview_content(&messages, 80, None, &palette);
+            let mut terminal = Terminal::new(TestBackend::new(80, 12)).expect("terminal");
+            terminal
+                .draw(|f| {
+                    f.render_widget(ratatui::widgets::Paragraph::new(content.lines), f.area())
+                })
+                .expect("draw");
+            let buf = terminal.backend().buffer();
+            for (label, color) in [
+                ("Admin - ", palette.party_admin),
+                ("Buyer - ", palette.party_buyer),
+                ("Seller - ", palette.party_seller),
+            ] {
+                let (x, y) = find_text(buf, label)[0];
+                assert_eq!(buf[(x, y)].fg, color, "{preset:?} {label}");
+            }
+        }
+    }
+
     /// Regression (Hermeme on #125): fixed 3+3 shell chrome left only 2 content
     /// rows on an 8-row terminal, so Disputes Pending showed borders and no data.
     #[test]
@@ -1,12 +1,13 @@
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
-use super::{helpers, BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::{helpers, Theme};
+use crate::i18n::tr;
 
 /// Renders an exit confirmation popup
-pub fn render_exit_confirm(f: &mut ratatui::Frame, selected_button: bool) {
+pub fn render_exit_confirm(f: &mut ratatui::Frame, selected_button: bool, theme: &Theme) {
     let area = f.area();
     let popup_width = 60;
     let popup_height = 11; // Increased height to ensure help text fits inside
@@ -16,9 +17,9 @@ pub fn render_exit_confirm(f: &mut ratatui::Frame, selected_button: bool) {
     f.render_widget(Clear, popup);
 
     let block = Block::default()
-        .title(" Exit Mostrix ")
+        .title(tr("exit.title"))
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
 
     // Get inner area (inside borders) for content layout
     let inner_area = block.inner(popup);
@@ -42,8 +43,8 @@ pub fn render_exit_confirm(f: &mut ratatui::Frame, selected_button: bool) {
     // Confirmation message
     f.render_widget(
         Paragraph::new(Line::from(vec![Span::styled(
-            "Are you sure you want to exit Mostrix?",
-            Style::default().fg(Color::White),
+            tr("exit.question"),
+            Style::default().fg(theme.text),
         )]))
         .alignment(ratatui::layout::Alignment::Center)
         .wrap(ratatui::widgets::Wrap { trim: true }),
@@ -51,27 +52,34 @@ pub fn render_exit_confirm(f: &mut ratatui::Frame, selected_button: bool) {
     );
 
     // YES/NO buttons
-    helpers::render_yes_no_buttons(f, chunks[3], selected_button, "✓ YES", "✗ NO");
+    helpers::render_yes_no_buttons(
+        f,
+        chunks[3],
+        selected_button,
+        tr("exit.yes"),
+        tr("exit.no"),
+        theme,
+    );
 
     // Help text - first line
     f.render_widget(
         Paragraph::new(Line::from(vec![
-            Span::styled("Use ", Style::default()),
+            Span::styled(tr("exit.use"), Style::default()),
             Span::styled(
                 "Left/Right",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" to select, ", Style::default()),
-            Span::styled("Press ", Style::default()),
+            Span::styled(tr("exit.to_select"), Style::default()),
+            Span::styled(tr("exit.press"), Style::default()),
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" to confirm", Style::default()),
+            Span::styled(tr("exit.to_confirm"), Style::default()),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         chunks[4],
@@ -80,14 +88,14 @@ pub fn render_exit_confirm(f: &mut ratatui::Frame, selected_button: bool) {
     // Help text for Esc key - second line
     f.render_widget(
         Paragraph::new(Line::from(vec![
-            Span::styled("Press ", Style::default()),
+            Span::styled(tr("exit.press"), Style::default()),
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(" to cancel", Style::default()),
+            Span::styled(tr("exit.to_cancel"), Style::default()),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         chunks[5],
@@ -113,9 +121,12 @@ mod tests {
 
     #[test]
     fn render_exit_confirm_yes_selected() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
-        terminal.draw(|f| render_exit_confirm(f, true)).unwrap();
+        terminal
+            .draw(|f| render_exit_confirm(f, true, theme))
+            .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Exit Mostrix"));
         assert!(buffer_contains(buf, "Are you sure"));
@@ -126,9 +137,12 @@ mod tests {
 
     #[test]
     fn render_exit_confirm_no_selected() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
-        terminal.draw(|f| render_exit_confirm(f, false)).unwrap();
+        terminal
+            .draw(|f| render_exit_confirm(f, false, theme))
+            .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Exit Mostrix"));
         assert!(buffer_contains(buf, "Are you sure"));
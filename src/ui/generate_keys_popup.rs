@@ -1,14 +1,15 @@
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
-use crate::ui::{helpers, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::{helpers, Theme};
 
 pub fn render_confirm_generate_new_keys(
     f: &mut ratatui::Frame,
     is_admin_mode: bool,
     selected_button: bool,
+    theme: &Theme,
 ) {
     let role = if is_admin_mode { "Admin" } else { "User" };
     // `admin_key_confirm` uses a fixed-height message area (2 rows).
@@ -32,10 +33,11 @@ Save backup and restart Mostrix after saving."
         "",
         selected_button,
         Some(custom_message.as_str()),
+        theme,
     );
 }
 
-pub fn render_backup_new_keys(f: &mut ratatui::Frame, mnemonic: &str) {
+pub fn render_backup_new_keys(f: &mut ratatui::Frame, mnemonic: &str, theme: &Theme) {
     let area = f.area();
     let popup_width = 90u16;
     // Needs to fit: comment (2 lines) + mnemonic (1 line) + help line.
@@ -48,11 +50,11 @@ pub fn render_backup_new_keys(f: &mut ratatui::Frame, mnemonic: &str) {
         .title(Span::styled(
             "🧾 Save Backup",
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         ))
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     let inner = block.inner(popup);
     f.render_widget(block, popup);
 
@@ -91,31 +93,31 @@ pub fn render_backup_new_keys(f: &mut ratatui::Frame, mnemonic: &str) {
     f.render_widget(
         Paragraph::new(comment_text)
             .alignment(ratatui::layout::Alignment::Center)
-            .style(Style::default().fg(Color::White)),
+            .style(Style::default().fg(theme.text)),
         chunks[1],
     );
 
     f.render_widget(
         Paragraph::new(mnemonic_text)
             .alignment(ratatui::layout::Alignment::Center)
-            .style(Style::default().fg(Color::White)),
+            .style(Style::default().fg(theme.text)),
         chunks[3],
     );
 
     f.render_widget(
         Paragraph::new(Line::from(vec![
-            Span::styled("Press ", Style::default().fg(Color::White)),
+            Span::styled("Press ", Style::default().fg(theme.text)),
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(" or "),
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw(" to close"),
@@ -144,10 +146,11 @@ mod tests {
 
     #[test]
     fn confirm_generate_admin_keys_shows_warning() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(100, 30);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_confirm_generate_new_keys(f, true, true))
+            .draw(|f| render_confirm_generate_new_keys(f, true, true, theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Generate Admin Keys"));
@@ -156,10 +159,11 @@ mod tests {
 
     #[test]
     fn confirm_generate_user_keys_shows_warning() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(100, 30);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_confirm_generate_new_keys(f, false, false))
+            .draw(|f| render_confirm_generate_new_keys(f, false, false, theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Generate User Keys"));
@@ -168,12 +172,13 @@ mod tests {
 
     #[test]
     fn backup_new_keys_shows_mnemonic_words() {
+        let theme = &Theme::dark();
         let mnemonic =
             "abandon ability able about above absent absorb abstract absurd abuse access accident";
         let backend = TestBackend::new(100, 30);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_backup_new_keys(f, mnemonic))
+            .draw(|f| render_backup_new_keys(f, mnemonic, theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Save Backup"));
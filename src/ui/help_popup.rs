@@ -1,10 +1,11 @@
 use ratatui::layout::{Constraint, Flex, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 
 use super::constants::*;
-use super::{AppState, DisputeFilter, BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::{AppState, DisputeFilter, Theme};
+use crate::i18n::tr;
 use crate::ui::navigation::{AdminTab, Tab, UserRole, UserTab};
 
 // 13 shortcuts, intro, close hint, borders, and one row of margin above and below.
@@ -13,6 +14,7 @@ const MY_TRADES_FULL_HELP_MIN_WIDTH: u16 = 60;
 
 /// Renders the context-aware keyboard shortcuts popup (Ctrl+H, and Shift+H on My Trades).
 pub fn render_help_popup(f: &mut ratatui::Frame, app: &AppState, tab: Tab) {
+    let theme = &app.theme;
     let area = f.area();
     let (title, plain_lines) = help_content(app, tab);
     let narrow_my_trades =
@@ -52,44 +54,44 @@ pub fn render_help_popup(f: &mut ratatui::Frame, app: &AppState, tab: Tab) {
         .title(Span::styled(
             title,
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         ))
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .style(Style::default().bg(theme.background));
     let inner = block.inner(popup);
     f.render_widget(block, popup);
 
     if compact_chrome {
         let mut lines: Vec<Line<'static>> = Vec::new();
         if matches!(tab, Tab::Admin(AdminTab::DisputesInProgress)) {
-            lines.push(help_disputes_in_progress_intro());
+            lines.push(help_disputes_in_progress_intro(theme));
         } else if compact_my_trades {
-            lines.extend(compact_my_trades_help(narrow_my_trades));
+            lines.extend(compact_my_trades_help(narrow_my_trades, theme));
         } else {
-            lines.push(help_my_trades_intro());
+            lines.push(help_my_trades_intro(theme));
         }
         if !compact_my_trades {
             for s in plain_lines {
-                lines.push(help_shortcut_line(&s));
+                lines.push(help_shortcut_line(&s, theme));
             }
         }
         lines.push(Line::from(Span::styled(
-            HELP_CLOSE_HINT,
-            Style::default().fg(Color::DarkGray),
+            tr(HELP_CLOSE_HINT),
+            Style::default().fg(theme.muted),
         )));
         let paragraph = Paragraph::new(Text::from(lines)).wrap(Wrap { trim: true });
         f.render_widget(paragraph, inner);
     } else {
         let content: Vec<Line> = plain_lines
             .into_iter()
-            .map(|s| Line::from(Span::styled(s, Style::default().fg(Color::White))))
+            .map(|s| Line::from(Span::styled(s, Style::default().fg(theme.text))))
             .collect();
         let mut all = content;
         all.push(Line::from(""));
         all.push(Line::from(Span::styled(
-            HELP_CLOSE_HINT,
-            Style::default().fg(Color::DarkGray),
+            tr(HELP_CLOSE_HINT),
+            Style::default().fg(theme.muted),
         )));
         let paragraph = Paragraph::new(all).wrap(Wrap { trim: true });
         f.render_widget(paragraph, inner);
@@ -97,25 +99,35 @@ pub fn render_help_popup(f: &mut ratatui::Frame, app: &AppState, tab: Tab) {
 }
 
 /// Full reference for every Settings menu row (Shift+H on Settings).
-pub fn render_settings_instructions_popup(f: &mut ratatui::Frame, user_role: UserRole) {
+pub fn render_settings_instructions_popup(
+    f: &mut ratatui::Frame,
+    user_role: UserRole,
+    theme: &Theme,
+) {
     let area = f.area();
-    let (title, mut lines) = settings_instruction_lines(user_role);
+    let (title, mut lines) = settings_instruction_lines(user_role, theme);
 
     let intro = Line::from(vec![
         Span::styled(
-            "Each row below matches one Settings list item. ",
-            Style::default().fg(Color::DarkGray),
+            tr("help.settings_instructions_intro"),
+            Style::default().fg(theme.muted),
+        ),
+        Span::styled("↑/↓", Style::default().fg(theme.primary)),
+        Span::styled(
+            tr("help.settings_instructions_move"),
+            Style::default().fg(theme.muted),
+        ),
+        Span::styled("Enter", Style::default().fg(theme.primary)),
+        Span::styled(
+            tr("help.settings_instructions_runs"),
+            Style::default().fg(theme.muted),
         ),
-        Span::styled("↑/↓", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" move · ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Enter", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" runs it.", Style::default().fg(Color::DarkGray)),
     ]);
     lines.insert(0, intro);
 
     lines.push(Line::from(Span::styled(
-        SETTINGS_INSTRUCTIONS_CLOSE_HINT,
-        Style::default().fg(Color::DarkGray),
+        tr(SETTINGS_INSTRUCTIONS_CLOSE_HINT),
+        Style::default().fg(theme.muted),
     )));
 
     // Use (nearly) the full viewport height so wrapped text has room on short terminals. A naive
@@ -140,11 +152,11 @@ pub fn render_settings_instructions_popup(f: &mut ratatui::Frame, user_role: Use
         .title(Span::styled(
             title,
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         ))
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .style(Style::default().bg(theme.background));
     let inner = block.inner(popup);
     f.render_widget(block, popup);
 
@@ -153,47 +165,56 @@ pub fn render_settings_instructions_popup(f: &mut ratatui::Frame, user_role: Use
     f.render_widget(paragraph, inner);
 }
 
-fn settings_instruction_block_style() -> (Style, Style) {
+fn settings_instruction_block_style(theme: &Theme) -> (Style, Style) {
     let title = Style::default()
-        .fg(PRIMARY_COLOR)
+        .fg(theme.primary)
         .add_modifier(Modifier::BOLD);
-    let body = Style::default().fg(Color::Gray);
+    let body = Style::default().fg(theme.secondary);
     (title, body)
 }
 
-fn help_disputes_in_progress_intro() -> Line<'static> {
+fn help_disputes_in_progress_intro(theme: &Theme) -> Line<'static> {
     Line::from(vec![
         Span::styled(
-            "Sidebar: pick a dispute · ",
-            Style::default().fg(Color::DarkGray),
+            tr("help.dip_intro_sidebar"),
+            Style::default().fg(theme.muted),
+        ),
+        Span::styled("↑/↓", Style::default().fg(theme.primary)),
+        Span::styled(tr("help.intro_sep"), Style::default().fg(theme.muted)),
+        Span::styled("Tab", Style::default().fg(theme.primary)),
+        Span::styled(tr("help.dip_intro_party"), Style::default().fg(theme.muted)),
+        Span::styled("Shift+C", Style::default().fg(theme.primary)),
+        Span::styled(
+            tr("help.dip_intro_filter"),
+            Style::default().fg(theme.muted),
         ),
-        Span::styled("↑/↓", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" · ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Tab", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" party · ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Shift+C", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" filter.", Style::default().fg(Color::DarkGray)),
     ])
 }
 
-fn help_my_trades_intro() -> Line<'static> {
+fn help_my_trades_intro(theme: &Theme) -> Line<'static> {
     Line::from(vec![
         Span::styled(
-            "Sidebar: pick an order · ",
-            Style::default().fg(Color::DarkGray),
+            tr("help.my_trades_intro_sidebar"),
+            Style::default().fg(theme.muted),
+        ),
+        Span::styled("Shift+I", Style::default().fg(theme.primary)),
+        Span::styled(
+            tr("help.my_trades_intro_chat"),
+            Style::default().fg(theme.muted),
+        ),
+        Span::styled("Ctrl+H", Style::default().fg(theme.primary)),
+        Span::styled(" / ", Style::default().fg(theme.muted)),
+        Span::styled("Shift+H", Style::default().fg(theme.primary)),
+        Span::styled(
+            tr("help.my_trades_intro_panel"),
+            Style::default().fg(theme.muted),
         ),
-        Span::styled("Shift+I", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" chat · ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Ctrl+H", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" / ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Shift+H", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" for this panel.", Style::default().fg(Color::DarkGray)),
     ])
 }
 
-fn compact_my_trades_help(narrow: bool) -> Vec<Line<'static>> {
+fn compact_my_trades_help(narrow: bool, theme: &Theme) -> Vec<Line<'static>> {
     if narrow {
-        let (title_style, _) = settings_instruction_block_style();
+        let (title_style, _) = settings_instruction_block_style(theme);
         return [
             "↑↓  Enter",
             "Tab  Shift+I",
@@ -206,119 +227,114 @@ fn compact_my_trades_help(narrow: bool) -> Vec<Line<'static>> {
     }
 
     [
-        "↑↓ / Enter: Select order / send message",
-        "Shift+I / Tab: Toggle input / Peer-Solver chat",
-        "Shift+C / Shift+F: Cancel order / mark fiat sent",
-        "Shift+R / Shift+D: Release sats / open dispute",
+        "help.my_trades_compact_select",
+        "help.my_trades_compact_input",
+        "help.my_trades_compact_cancel",
+        "help.my_trades_compact_release",
     ]
     .into_iter()
-    .map(help_shortcut_line)
+    .map(|key| help_shortcut_line(tr(key), theme))
     .collect()
 }
 
 /// Split `Key: description` help strings into bold key + gray body (same as Settings Shift+H rows).
-fn help_shortcut_line(s: &str) -> Line<'static> {
-    let (title_style, body_style) = settings_instruction_block_style();
+fn help_shortcut_line(s: &str, theme: &Theme) -> Line<'static> {
+    let (title_style, body_style) = settings_instruction_block_style(theme);
     match s.split_once(": ") {
         Some((key, rest)) => Line::from(vec![
             Span::styled(format!("▸ {key}: "), title_style),
             Span::styled(rest.to_string(), body_style),
         ]),
-        None => Line::from(Span::styled(
-            s.to_string(),
-            Style::default().fg(Color::White),
-        )),
+        None => Line::from(Span::styled(s.to_string(), Style::default().fg(theme.text))),
     }
 }
 
 /// One menu option as a single wrapped line: bold title prefix + body (compact for small terminals).
-fn push_settings_instruction_line(lines: &mut Vec<Line<'static>>, name: &str, description: &str) {
-    let (title_style, body_style) = settings_instruction_block_style();
+fn push_settings_instruction_line(
+    lines: &mut Vec<Line<'static>>,
+    name: &str,
+    description: &str,
+    theme: &Theme,
+) {
+    let (title_style, body_style) = settings_instruction_block_style(theme);
     lines.push(Line::from(vec![
         Span::styled(format!("▸ {name}: "), title_style),
         Span::styled(description.to_string(), body_style),
     ]));
 }
 
-fn settings_instruction_lines(user_role: UserRole) -> (String, Vec<Line<'static>>) {
+fn settings_instruction_lines(user_role: UserRole, theme: &Theme) -> (String, Vec<Line<'static>>) {
     let mut lines: Vec<Line<'static>> = Vec::new();
     let title = match user_role {
-        UserRole::Admin => "Settings (Admin) — All options",
-        UserRole::User => "Settings (User) — All options",
+        UserRole::Admin => tr("help.settings_instructions_title_admin"),
+        UserRole::User => tr("help.settings_instructions_title_user"),
     }
     .to_string();
 
-    let admin_entries: &[(&str, &str)] = &[
-        (
-            "Switch Mode (User ↔ Admin)",
-            "Toggle User vs Admin UI. Saves user_mode in settings.toml, reloads tabs, and may reload admin disputes.",
-        ),
-        (
-            "Change Mostro Pubkey",
-            "Set the Mostro daemon pubkey (npub or hex) used for subscriptions and orders.",
-        ),
+    // (row label, description) catalog keys; labels match the Settings tab rows.
+    let admin_entries: &[(&'static str, &'static str)] = &[
+        ("settings.switch_mode", "settings.desc.switch_mode_admin"),
         (
-            "Add Nostr Relay",
-            "Append a wss:// relay; duplicates are skipped.",
+            "settings.change_mostro_pubkey",
+            "settings.desc.change_mostro_pubkey",
         ),
+        ("settings.add_relay", "settings.desc.add_relay"),
         (
-            "Add Currency Filter",
-            "Add a fiat code (e.g. USD). The order book only shows matching orders.",
+            "settings.add_currency_filter",
+            "settings.desc.add_currency_filter",
         ),
         (
-            "Clear Currency Filters",
-            "Remove all filters so every configured currency can appear again.",
+            "settings.clear_currency_filters",
+            "settings.desc.clear_currency_filters",
         ),
+        ("settings.change_theme", "settings.desc.change_theme"),
+        ("settings.change_language", "settings.desc.change_language"),
+        ("settings.create_backup", "settings.desc.create_backup"),
+        ("settings.restore_backup", "settings.desc.restore_backup"),
+        ("settings.view_seed_words", "settings.desc.view_seed_words"),
         (
-            "View Seed Words",
-            "Show your BIP-39 mnemonic from the local database. Treat as highly sensitive.",
+            "settings.add_dispute_solver",
+            "settings.desc.add_dispute_solver",
         ),
         (
-            "Add Dispute Solver",
-            "Enter solver npub, use Left/Right to choose read or read-write, then confirm",
-        ),
-        (
-            "Change Admin Key",
-            "Set admin_privkey to the Mostro daemon nsec (operator actions + dispute chat).",
+            "settings.change_admin_key",
+            "settings.desc.change_admin_key",
         ),
     ];
 
-    let user_entries: &[(&str, &str)] = &[
-        (
-            "Switch Mode (User ↔ Admin)",
-            "Switch to Admin when you need dispute tools. Saves user_mode and reloads tabs.",
-        ),
-        (
-            "Change Mostro Pubkey",
-            "Set the Mostro daemon pubkey (npub or hex) used for subscriptions and orders.",
-        ),
-        (
-            "Add Nostr Relay",
-            "Append a wss:// relay; duplicates are skipped.",
-        ),
+    let user_entries: &[(&'static str, &'static str)] = &[
+        ("settings.switch_mode", "settings.desc.switch_mode_user"),
         (
-            "Set Lightning Address (buyer)",
-            "User mode only. Confirms save after fetching LNURL metadata (payRequest). On failure, settings are not updated.",
+            "settings.change_mostro_pubkey",
+            "settings.desc.change_mostro_pubkey",
         ),
+        ("settings.add_relay", "settings.desc.add_relay"),
+        ("settings.set_ln_address", "settings.desc.set_ln_address"),
         (
-            "Clear Lightning Address",
-            "User mode only. Remove the saved buyer Lightning address from settings.toml.",
+            "settings.clear_ln_address",
+            "settings.desc.clear_ln_address",
         ),
         (
-            "Add Currency Filter",
-            "Add a fiat code (e.g. USD). The order book only shows matching orders.",
+            "settings.add_currency_filter",
+            "settings.desc.add_currency_filter",
         ),
         (
-            "Clear Currency Filters",
-            "Remove all filters so every configured currency can appear again.",
+            "settings.clear_currency_filters",
+            "settings.desc.clear_currency_filters",
         ),
+        ("settings.change_theme", "settings.desc.change_theme"),
+        ("settings.change_language", "settings.desc.change_language"),
         (
-            "View Seed Words",
-            "Show your BIP-39 mnemonic from the local database. Treat as highly sensitive.",
+            "settings.toggle_automation",
+            "settings.desc.toggle_automation",
         ),
+        ("settings.data_retention", "settings.desc.data_retention"),
+        ("settings.create_backup", "settings.desc.create_backup"),
+        ("settings.restore_backup", "settings.desc.restore_backup"),
+        ("settings.view_seed_words", "settings.desc.view_seed_words"),
         (
-            "Generate New Keys",
-            "Rotate identity/trade keys. Confirm prompts and back up any new mnemonic.",
+            "settings.generate_new_keys",
+            "settings.desc.generate_new_keys",
         ),
     ];
 
@@ -327,7 +343,7 @@ fn settings_instruction_lines(user_role: UserRole) -> (String, Vec<Line<'static>
         UserRole::User => user_entries,
     };
     for (name, desc) in entries.iter() {
-        push_settings_instruction_line(&mut lines, name, desc);
+        push_settings_instruction_line(&mut lines, tr(name), tr(desc), theme);
     }
 
     (title, lines)
@@ -340,110 +356,127 @@ fn help_content(app: &AppState, tab: Tab) -> (String, Vec<String>) {
                 .and_then(|d| crate::ui::helpers::is_dispute_finalized(&d))
                 .unwrap_or(false);
             let filter_hint = match app.dispute_filter {
-                DisputeFilter::InProgress => FILTER_VIEW_FINALIZED,
-                DisputeFilter::Finalized => FILTER_VIEW_IN_PROGRESS,
+                DisputeFilter::InProgress => tr(FILTER_VIEW_FINALIZED),
+                DisputeFilter::Finalized => tr(FILTER_VIEW_IN_PROGRESS),
             };
             let mut lines = vec![
                 filter_hint.to_string(),
-                HELP_DIP_TAB_PARTY.to_string(),
-                HELP_DIP_SELECT_DISPUTE.to_string(),
-                HELP_DIP_SCROLL_CHAT.to_string(),
-                HELP_DIP_END_BOTTOM.to_string(),
-                HELP_DIP_SHIFT_F_RESOLVE.to_string(),
+                tr(HELP_DIP_TAB_PARTY).to_string(),
+                tr(HELP_DIP_SELECT_DISPUTE).to_string(),
+                tr(HELP_DIP_SCROLL_CHAT).to_string(),
+                tr(HELP_DIP_END_BOTTOM).to_string(),
+                tr(HELP_DIP_SHIFT_F_RESOLVE).to_string(),
             ];
             if !is_finalized {
-                lines.push(HELP_DIP_SHIFT_I_INPUT.to_string());
-                lines.push(HELP_DIP_ENTER_SEND.to_string());
-                lines.push(HELP_DIP_CTRL_S_ATTACH.to_string());
+                lines.push(tr(HELP_DIP_SHIFT_I_INPUT).to_string());
+                lines.push(tr(HELP_DIP_ENTER_SEND).to_string());
+                lines.push(tr(HELP_DIP_CTRL_S_ATTACH).to_string());
+            }
+            if app.observer_parked {
+                lines.push(tr(HELP_DIP_CTRL_G_OBSERVER).to_string());
             }
-            (HELP_TITLE_DISPUTES_IN_PROGRESS.to_string(), lines)
+            (tr(HELP_TITLE_DISPUTES_IN_PROGRESS).to_string(), lines)
         }
         Tab::Admin(AdminTab::DisputesPending) => (
-            HELP_TITLE_DISPUTES_PENDING.to_string(),
+            tr(HELP_TITLE_DISPUTES_PENDING).to_string(),
             vec![
-                HELP_DP_ENTER_TAKE.to_string(),
-                HELP_DP_SELECT_DISPUTE.to_string(),
+                tr(HELP_DP_ENTER_TAKE).to_string(),
+                tr(HELP_DP_SELECT_DISPUTE).to_string(),
+            ],
+        ),
+        Tab::Admin(AdminTab::DisputeArchive) => (
+            tr(HELP_TITLE_DISPUTE_ARCHIVE).to_string(),
+            vec![
+                tr(HELP_ARC_TYPE_SEARCH).to_string(),
+                tr(HELP_ARC_SELECT).to_string(),
+                tr(HELP_ARC_ESC_CLEAR).to_string(),
             ],
         ),
         Tab::Admin(AdminTab::Observer) => (
-            HELP_TITLE_OBSERVER.to_string(),
+            tr(HELP_TITLE_OBSERVER).to_string(),
             vec![
-                HELP_OBS_ENTER_LOAD.to_string(),
-                HELP_OBS_PASTE_SHARED_KEY.to_string(),
-                HELP_OBS_SCROLL_LINE.to_string(),
-                HELP_OBS_SCROLL_PAGE.to_string(),
-                HELP_OBS_ESC_CLEAR_ERR.to_string(),
-                HELP_OBS_CTRL_C_CLEAR.to_string(),
-                HELP_OBS_CTRL_S_ATTACH.to_string(),
+                tr(HELP_OBS_ENTER_LOAD).to_string(),
+                tr(HELP_OBS_PASTE_SHARED_KEY).to_string(),
+                tr(HELP_OBS_TAB_FIELD).to_string(),
+                tr(HELP_OBS_CTRL_G_DISPUTE).to_string(),
+                tr(HELP_OBS_SCROLL_LINE).to_string(),
+                tr(HELP_OBS_SCROLL_PAGE).to_string(),
+                tr(HELP_OBS_ESC_CLEAR_ERR).to_string(),
+                tr(HELP_OBS_CTRL_C_CLEAR).to_string(),
+                tr(HELP_OBS_CTRL_S_ATTACH).to_string(),
             ],
         ),
         Tab::Admin(AdminTab::Settings) => (
-            HELP_TITLE_SETTINGS_ADMIN.to_string(),
+            tr(HELP_TITLE_SETTINGS_ADMIN).to_string(),
             vec![
-                HELP_SETTINGS_SWITCH_FROM_MENU.to_string(),
-                HELP_SETTINGS_SHIFT_H_FULL.to_string(),
-                HELP_SETTINGS_SELECT_OPTION.to_string(),
-                HELP_SETTINGS_ENTER_OPEN.to_string(),
+                tr(HELP_SETTINGS_SWITCH_FROM_MENU).to_string(),
+                tr(HELP_SETTINGS_SHIFT_H_FULL).to_string(),
+                tr(HELP_SETTINGS_SELECT_OPTION).to_string(),
+                tr(HELP_SETTINGS_ENTER_OPEN).to_string(),
             ],
         ),
         Tab::Admin(AdminTab::Exit) => (
-            HELP_TITLE_EXIT.to_string(),
-            vec![HELP_EXIT_ENTER_CONFIRM.to_string()],
+            tr(HELP_TITLE_EXIT).to_string(),
+            vec![tr(HELP_EXIT_ENTER_CONFIRM).to_string()],
         ),
         Tab::User(UserTab::Orders) => (
-            HELP_TITLE_ORDERS.to_string(),
+            tr(HELP_TITLE_ORDERS).to_string(),
             vec![
-                HELP_ORDERS_ENTER_TAKE.to_string(),
-                HELP_ORDERS_SELECT.to_string(),
+                tr(HELP_ORDERS_ENTER_TAKE).to_string(),
+                tr(HELP_ORDERS_SELECT).to_string(),
+                tr(HELP_ORDERS_P_PAYMENT_FILTER).to_string(),
             ],
         ),
         Tab::User(UserTab::MyTrades) => (
-            HELP_TITLE_MY_TRADES.to_string(),
+            tr(HELP_TITLE_MY_TRADES).to_string(),
             vec![
-                HELP_MY_TRADES_NAV.to_string(),
-                HELP_MY_TRADES_ENTER_SEND.to_string(),
-                HELP_MY_TRADES_TAB_CHAT.to_string(),
-                HELP_MY_TRADES_SHIFT_I.to_string(),
-                HELP_MY_TRADES_SHIFT_C_CANCEL.to_string(),
-                HELP_MY_TRADES_SHIFT_F_FIAT_SENT.to_string(),
-                HELP_MY_TRADES_SHIFT_R_RELEASE.to_string(),
-                HELP_MY_TRADES_SHIFT_V_RATE.to_string(),
-                HELP_MY_TRADES_SHIFT_D_DISPUTE.to_string(),
-                HELP_MY_TRADES_SHIFT_K_KCONV.to_string(),
-                HELP_MY_TRADES_CTRL_S_ATTACH.to_string(),
-                HELP_MY_TRADES_CTRL_O_SEND.to_string(),
-                HELP_MY_TRADES_CTRL_SHIFT_O_RETRY.to_string(),
-                HELP_MY_TRADES_SHIFT_H_HELP.to_string(),
+                tr(HELP_MY_TRADES_NAV).to_string(),
+                tr(HELP_MY_TRADES_ENTER_SEND).to_string(),
+                tr(HELP_MY_TRADES_TAB_CHAT).to_string(),
+                tr(HELP_MY_TRADES_SHIFT_I).to_string(),
+                tr(HELP_MY_TRADES_SHIFT_C_CANCEL).to_string(),
+                tr(HELP_MY_TRADES_SHIFT_F_FIAT_SENT).to_string(),
+                tr(HELP_MY_TRADES_SHIFT_R_RELEASE).to_string(),
+                tr(HELP_MY_TRADES_SHIFT_V_RATE).to_string(),
+                tr(HELP_MY_TRADES_SHIFT_D_DISPUTE).to_string(),
+                tr(HELP_MY_TRADES_SHIFT_K_KCONV).to_string(),
+                tr(HELP_MY_TRADES_CTRL_S_ATTACH).to_string(),
+                tr(HELP_MY_TRADES_CTRL_O_SEND).to_string(),
+                tr(HELP_MY_TRADES_CTRL_SHIFT_O_RETRY).to_string(),
+                tr(HELP_MY_TRADES_SHIFT_H_HELP).to_string(),
             ],
         ),
         Tab::User(UserTab::Messages) => (
-            HELP_TITLE_MESSAGES.to_string(),
-            vec![HELP_MSG_ENTER_OPEN.to_string(), HELP_MSG_SELECT.to_string()],
+            tr(HELP_TITLE_MESSAGES).to_string(),
+            vec![
+                tr(HELP_MSG_ENTER_OPEN).to_string(),
+                tr(HELP_MSG_SELECT).to_string(),
+            ],
         ),
         Tab::User(UserTab::MostroInfo) | Tab::Admin(AdminTab::MostroInfo) => (
-            "Mostro instance info".to_string(),
-            vec!["View Mostro daemon status and accepted fiat currencies.".to_string()],
+            tr("help.mostro_info_title").to_string(),
+            vec![tr("help.mostro_info_view").to_string()],
         ),
         Tab::User(UserTab::CreateNewOrder) => (
-            HELP_TITLE_CREATE_NEW_ORDER.to_string(),
+            tr(HELP_TITLE_CREATE_NEW_ORDER).to_string(),
             vec![
-                HELP_CNO_CHANGE_FIELD.to_string(),
-                HELP_CNO_TAB_NEXT.to_string(),
-                HELP_CNO_ENTER_CONFIRM.to_string(),
+                tr(HELP_CNO_CHANGE_FIELD).to_string(),
+                tr(HELP_CNO_TAB_NEXT).to_string(),
+                tr(HELP_CNO_ENTER_CONFIRM).to_string(),
             ],
         ),
         Tab::User(UserTab::Settings) => (
-            HELP_TITLE_SETTINGS_USER.to_string(),
+            tr(HELP_TITLE_SETTINGS_USER).to_string(),
             vec![
-                HELP_SETTINGS_SWITCH_FROM_MENU.to_string(),
-                HELP_SETTINGS_SHIFT_H_FULL.to_string(),
-                HELP_SETTINGS_SELECT_OPTION.to_string(),
-                HELP_SETTINGS_ENTER_OPEN.to_string(),
+                tr(HELP_SETTINGS_SWITCH_FROM_MENU).to_string(),
+                tr(HELP_SETTINGS_SHIFT_H_FULL).to_string(),
+                tr(HELP_SETTINGS_SELECT_OPTION).to_string(),
+                tr(HELP_SETTINGS_ENTER_OPEN).to_string(),
             ],
         ),
         Tab::User(UserTab::Exit) => (
-            HELP_TITLE_EXIT.to_string(),
-            vec![HELP_EXIT_ENTER_CONFIRM.to_string()],
+            tr(HELP_TITLE_EXIT).to_string(),
+            vec![tr(HELP_EXIT_ENTER_CONFIRM).to_string()],
         ),
     }
 }
@@ -470,11 +503,13 @@ mod help_content_tests {
         let app = AppState::new(UserRole::User);
         let (_, lines) = help_content(&app, Tab::User(UserTab::MyTrades));
         assert!(
-            lines.iter().any(|l| l == HELP_MY_TRADES_SHIFT_D_DISPUTE),
+            lines
+                .iter()
+                .any(|l| l == tr(HELP_MY_TRADES_SHIFT_D_DISPUTE)),
             "Shift+D missing from My Trades help: {lines:?}"
         );
         assert!(
-            lines.iter().any(|l| l == HELP_MY_TRADES_SHIFT_K_KCONV),
+            lines.iter().any(|l| l == tr(HELP_MY_TRADES_SHIFT_K_KCONV)),
             "Shift+K missing from My Trades help: {lines:?}"
         );
     }
@@ -484,12 +519,12 @@ mod help_content_tests {
         let app = AppState::new(UserRole::Admin);
         let (_, lines) = help_content(&app, Tab::Admin(AdminTab::Observer));
         assert!(
-            lines.iter().any(|l| l == HELP_OBS_ENTER_LOAD),
+            lines.iter().any(|l| l == tr(HELP_OBS_ENTER_LOAD)),
             "Shared key load missing from Observer help: {lines:?}"
         );
         assert!(
-            !lines.iter().any(|l| l.contains("Tab: Switch")),
-            "Tab focus shortcut should be removed from Observer help now that only one field exists: {lines:?}"
+            lines.iter().any(|l| l == tr(HELP_OBS_TAB_FIELD)),
+            "Tab field switch missing from Observer help: {lines:?}"
         );
     }
 
@@ -512,7 +547,7 @@ mod help_content_tests {
             "Shift+F",
             "Shift+R",
             "Shift+D",
-            HELP_CLOSE_HINT,
+            tr(HELP_CLOSE_HINT),
         ] {
             assert!(
                 buffer_contains(buf, expected),
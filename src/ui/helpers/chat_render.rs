@@ -1,9 +1,9 @@
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::ListItem;
 
 use crate::ui::helpers::format_local_timestamp;
-use crate::ui::{ChatParty, ChatSender, DisputeChatMessage};
+use crate::ui::{ChatParty, ChatSender, DisputeChatMessage, Theme};
 
 use super::chat_visibility::message_visible_for_party;
 
@@ -58,6 +58,7 @@ pub(crate) fn wrap_text_to_lines(content: &str, max_width: u16) -> Vec<String> {
 fn format_message_lines(
     msg: &DisputeChatMessage,
     max_content_width: Option<u16>,
+    theme: &Theme,
 ) -> Vec<Line<'static>> {
     let date_str = format_local_timestamp(msg.timestamp, "%d-%m-%Y")
         .unwrap_or_else(|| "??-??-????".to_string());
@@ -65,14 +66,14 @@ fn format_message_lines(
         format_local_timestamp(msg.timestamp, "%H:%M").unwrap_or_else(|| "??:??".to_string());
 
     let (sender_label, sender_color, is_right_aligned) = match msg.sender {
-        ChatSender::Admin => ("Admin", Color::Cyan, false),
-        ChatSender::Buyer => ("Buyer", Color::Green, true),
-        ChatSender::Seller => ("Seller", Color::Magenta, true),
+        ChatSender::Admin => ("Admin", theme.party_admin, false),
+        ChatSender::Buyer => ("Buyer", theme.party_buyer, true),
+        ChatSender::Seller => ("Seller", theme.party_seller, true),
     };
     let content_color = msg
         .attachment
         .as_ref()
-        .map(|_| Color::Yellow)
+        .map(|_| theme.warning)
         .unwrap_or(sender_color);
 
     let header_text = format!("{} - {} - {}", sender_label, date_str, time_str);
@@ -109,21 +110,22 @@ fn format_message_lines(
 }
 
 /// Builds `ListItem`s from chat messages for display in the dispute chat list widget.
-pub fn build_chat_list_items(
-    messages: &[DisputeChatMessage],
+pub fn build_chat_list_items<'a>(
+    messages: &'a [DisputeChatMessage],
     active_chat_party: ChatParty,
     max_content_width: Option<u16>,
-) -> Vec<ListItem<'_>> {
-    let filtered_items: Vec<ListItem<'_>> = messages
+    theme: &Theme,
+) -> Vec<ListItem<'a>> {
+    let filtered_items: Vec<ListItem<'a>> = messages
         .iter()
         .filter(|msg| message_visible_for_party(msg, active_chat_party))
-        .map(|msg| ListItem::new(format_message_lines(msg, max_content_width)))
+        .map(|msg| ListItem::new(format_message_lines(msg, max_content_width, theme)))
         .collect();
 
     if filtered_items.is_empty() {
         return vec![ListItem::new(Line::from(Span::styled(
             "No messages yet. Start the conversation!",
-            Style::default().fg(Color::Gray),
+            Style::default().fg(theme.secondary),
         )))];
     }
 
@@ -144,6 +146,7 @@ pub fn build_chat_scrollview_content(
     active_chat_party: ChatParty,
     content_width: u16,
     max_content_width: Option<u16>,
+    theme: &Theme,
 ) -> ChatScrollViewContent {
     let mut lines = Vec::new();
     let mut line_start_per_message = Vec::new();
@@ -153,13 +156,13 @@ pub fn build_chat_scrollview_content(
         .filter(|m| message_visible_for_party(m, active_chat_party))
     {
         line_start_per_message.push(lines.len());
-        lines.extend(format_message_lines(msg, max_content_width));
+        lines.extend(format_message_lines(msg, max_content_width, theme));
     }
 
     if lines.is_empty() {
         lines.push(Line::from(Span::styled(
             "No messages yet. Start the conversation!",
-            Style::default().fg(Color::Gray),
+            Style::default().fg(theme.secondary),
         )));
     }
 
@@ -177,19 +180,20 @@ pub fn build_observer_scrollview_content(
     messages: &[DisputeChatMessage],
     content_width: u16,
     max_content_width: Option<u16>,
+    theme: &Theme,
 ) -> ChatScrollViewContent {
     let mut lines = Vec::new();
     let mut line_start_per_message = Vec::new();
 
     for msg in messages {
         line_start_per_message.push(lines.len());
-        lines.extend(format_message_lines(msg, max_content_width));
+        lines.extend(format_message_lines(msg, max_content_width, theme));
     }
 
     if lines.is_empty() {
         lines.push(Line::from(Span::styled(
             "No messages yet. Paste Shared key and press Enter to load.",
-            Style::default().fg(Color::Gray),
+            Style::default().fg(theme.secondary),
         )));
     }
 
@@ -205,13 +209,15 @@ pub fn build_observer_scrollview_content(
 #[cfg(test)]
 mod tests {
     use super::build_observer_scrollview_content;
+    use crate::ui::Theme;
 
     /// The UI label for the disclosed `K_conv` secret is "Shared key" (never
     /// confused with the persisted ECDH `order_chat_shared_key_hex`, which is
     /// not shown to users at all).
     #[test]
     fn observer_empty_hint_asks_for_shared_key() {
-        let content = build_observer_scrollview_content(&[], 40, Some(20));
+        let theme = &Theme::dark();
+        let content = build_observer_scrollview_content(&[], 40, Some(20), theme);
         let flat: String = content
             .lines
             .iter()
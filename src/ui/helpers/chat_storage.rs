@@ -186,10 +186,8 @@ fn chat_file_path(kind: ChatStorageKind, chat_id: &str) -> Option<PathBuf> {
     if uuid::Uuid::parse_str(chat_id).is_err() {
         return None;
     }
-    let home_dir = dirs::home_dir()?;
     Some(
-        home_dir
-            .join(".mostrix")
+        crate::settings::data_dir()?
             .join(kind.folder_name())
             .join(format!("{}.txt", chat_id)),
     )
@@ -646,14 +644,12 @@ fn inner_ids_file_path(
     if uuid::Uuid::parse_str(chat_id).is_err() {
         return None;
     }
-    let home_dir = dirs::home_dir()?;
     let name = match party_suffix {
         Some(sfx) => format!("{chat_id}.{sfx}.inner_ids"),
         None => format!("{chat_id}.inner_ids"),
     };
     Some(
-        home_dir
-            .join(".mostrix")
+        crate::settings::data_dir()?
             .join(kind.folder_name())
             .join(name),
     )
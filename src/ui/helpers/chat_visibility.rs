@@ -1,4 +1,6 @@
-use crate::ui::{AppState, ChatParty, ChatSender, DisputeChatMessage, UserOrderChatMessage};
+use crate::ui::{
+    AppState, ChatParty, ChatSender, DisputeChatMessage, UserChatChannel, UserOrderChatMessage,
+};
 
 use super::attachments::attachment_is_saveable;
 
@@ -44,12 +46,17 @@ pub fn get_visible_attachment_messages<'a>(
         .collect()
 }
 
-/// Returns attachment messages for the given order chat.
+/// Returns attachment messages for the given order chat channel (peer or solver).
 pub fn get_order_attachment_messages<'a>(
     app: &'a AppState,
     order_id: &str,
+    channel: UserChatChannel,
 ) -> Vec<&'a UserOrderChatMessage> {
-    app.order_chats
+    let chats = match channel {
+        UserChatChannel::Peer => &app.order_chats,
+        UserChatChannel::Solver => &app.user_dispute_chats,
+    };
+    chats
         .get(order_id)
         .map(|messages| {
             messages
@@ -60,9 +67,9 @@ pub fn get_order_attachment_messages<'a>(
         .unwrap_or_default()
 }
 
-/// Returns the number of attachment messages in the given order chat.
-pub fn count_order_attachments(app: &AppState, order_id: &str) -> usize {
-    get_order_attachment_messages(app, order_id).len()
+/// Returns the number of attachment messages in the given order chat channel.
+pub fn count_order_attachments(app: &AppState, order_id: &str, channel: UserChatChannel) -> usize {
+    get_order_attachment_messages(app, order_id, channel).len()
 }
 
 /// Returns the currently selected chat message (by index) for the given dispute, or None.
@@ -1,9 +1,11 @@
 use chrono::{DateTime, Local, Utc};
 use mostro_core::prelude::{DisputeStatus, UserInfo};
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier, Style};
 use std::str::FromStr;
 
 use crate::models::AdminDispute;
+use crate::ui::Theme;
+use crate::util::trade_deadlines::{format_countdown, DeadlineUrgency, TradeDeadline};
 
 /// Formats user rating with star visualization.
 /// Rating must be in 0-5 range. Returns formatted string with stars and stats.
@@ -27,13 +29,13 @@ pub fn is_dispute_finalized(selected_dispute: &AdminDispute) -> Option<bool> {
 }
 
 /// Color for a kebab-case dispute status in the admin header/sidebar.
-pub fn dispute_status_color(status: Option<&str>) -> Color {
+pub fn dispute_status_color(status: Option<&str>, theme: &Theme) -> Color {
     match status.and_then(|s| DisputeStatus::from_str(s).ok()) {
-        Some(DisputeStatus::Initiated) => Color::Yellow,
-        Some(DisputeStatus::InProgress) => Color::Green,
-        Some(DisputeStatus::Settled) | Some(DisputeStatus::Released) => Color::Green,
-        Some(DisputeStatus::SellerRefunded) => Color::Red,
-        None => Color::White,
+        Some(DisputeStatus::Initiated) => theme.warning,
+        Some(DisputeStatus::InProgress) => theme.positive,
+        Some(DisputeStatus::Settled) | Some(DisputeStatus::Released) => theme.positive,
+        Some(DisputeStatus::SellerRefunded) => theme.negative,
+        None => theme.text,
     }
 }
 
@@ -51,14 +53,29 @@ pub fn format_order_id(order_id: Option<uuid::Uuid>) -> String {
 
 /// Formats an order premium with an explicit sign and its semantic UI color.
 #[must_use]
-pub fn format_premium(premium: i64) -> (String, Color) {
+pub fn format_premium(premium: i64, theme: &Theme) -> (String, Color) {
     match premium {
-        0 => ("0%".to_string(), Color::Gray),
-        value if value > 0 => (format!("+{value}%"), Color::Green),
-        value => (format!("{value}%"), Color::Red),
+        0 => ("0%".to_string(), theme.secondary),
+        value if value > 0 => (format!("+{value}%"), theme.positive),
+        value => (format!("{value}%"), theme.negative),
     }
 }
 
+/// Countdown text and escalating style for a trade deadline (yellow near the end, bold red in
+/// the last stretch and once expired).
+#[must_use]
+pub fn format_deadline(deadline: &TradeDeadline, now: i64, theme: &Theme) -> (String, Style) {
+    let text = format_countdown(deadline.remaining_secs(now));
+    let style = match deadline.urgency(now) {
+        DeadlineUrgency::Normal => Style::default().fg(theme.secondary),
+        DeadlineUrgency::Warning => Style::default().fg(theme.warning),
+        DeadlineUrgency::Critical | DeadlineUrgency::Expired => Style::default()
+            .fg(theme.negative)
+            .add_modifier(Modifier::BOLD),
+    };
+    (text, style)
+}
+
 /// Truncated order id for compact displays (sidebar rows, header cards); no `"Order: "` prefix.
 /// Returns `"unknown"` when absent. Pairs with [`format_order_id`] (which keeps the prefix and
 /// is used in full-sentence contexts like popups).
@@ -192,18 +209,29 @@ mod premium_tests {
 
     #[test]
     fn preserves_sign_and_uses_semantic_color() {
-        assert_eq!(format_premium(2), ("+2%".to_string(), Color::Green));
-        assert_eq!(format_premium(-3), ("-3%".to_string(), Color::Red));
-        assert_eq!(format_premium(0), ("0%".to_string(), Color::Gray));
+        let theme = &Theme::dark();
+        assert_eq!(format_premium(2, theme), ("+2%".to_string(), Color::Green));
+        assert_eq!(format_premium(-3, theme), ("-3%".to_string(), Color::Red));
+        assert_eq!(format_premium(0, theme), ("0%".to_string(), Color::Gray));
     }
 
     #[test]
     fn dispute_status_color_matches_lifecycle() {
-        assert_eq!(dispute_status_color(Some("in-progress")), Color::Green);
-        assert_eq!(dispute_status_color(Some("seller-refunded")), Color::Red);
-        assert_eq!(dispute_status_color(Some("settled")), Color::Green);
-        assert_eq!(dispute_status_color(Some("initiated")), Color::Yellow);
-        assert_eq!(dispute_status_color(None), Color::White);
-        assert_eq!(dispute_status_color(Some("unknown")), Color::White);
+        let theme = &Theme::dark();
+        assert_eq!(
+            dispute_status_color(Some("in-progress"), theme),
+            Color::Green
+        );
+        assert_eq!(
+            dispute_status_color(Some("seller-refunded"), theme),
+            Color::Red
+        );
+        assert_eq!(dispute_status_color(Some("settled"), theme), Color::Green);
+        assert_eq!(
+            dispute_status_color(Some("initiated"), theme),
+            Color::Yellow
+        );
+        assert_eq!(dispute_status_color(None, theme), Color::White);
+        assert_eq!(dispute_status_color(Some("unknown"), theme), Color::White);
     }
 }
@@ -3,7 +3,8 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Borders, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState};
 
-use crate::ui::PRIMARY_COLOR;
+use crate::ui::hit_test::{self, HitTarget, PopupButton};
+use crate::ui::Theme;
 
 /// Vertical scrollbar for a bordered table/list whose selection scrolls with
 /// [`ratatui::widgets::TableState`] / [`ratatui::widgets::ListState`].
@@ -59,14 +60,21 @@ pub fn create_centered_popup(area: Rect, width: u16, height: u16) -> Rect {
 }
 
 /// Renders help text with a styled key binding.
-pub fn render_help_text(f: &mut ratatui::Frame, area: Rect, prefix: &str, key: &str, suffix: &str) {
+pub fn render_help_text(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    prefix: &str,
+    key: &str,
+    suffix: &str,
+    theme: &Theme,
+) {
     f.render_widget(
         Paragraph::new(Line::from(vec![
             Span::styled(prefix, Style::default()),
             Span::styled(
                 key,
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(suffix, Style::default()),
@@ -84,6 +92,7 @@ pub fn render_yes_no_buttons(
     selected_button: bool,
     yes_label: &str,
     no_label: &str,
+    theme: &Theme,
 ) {
     let button_width = 18;
     let separator_width = 1;
@@ -106,15 +115,16 @@ pub fn render_yes_no_buttons(
         ],
     )
     .split(centered_button_area);
+    hit_test::record_yes_no(button_chunks[0], button_chunks[2]);
 
     let yes_style = if selected_button {
         Style::default()
-            .bg(Color::Green)
-            .fg(Color::Black)
+            .bg(theme.positive)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
         Style::default()
-            .fg(Color::Green)
+            .fg(theme.positive)
             .add_modifier(Modifier::BOLD)
     };
 
@@ -132,9 +142,9 @@ pub fn render_yes_no_buttons(
             yes_label,
             Style::default()
                 .fg(if selected_button {
-                    Color::Black
+                    theme.on_fill
                 } else {
-                    Color::Green
+                    theme.positive
                 })
                 .add_modifier(Modifier::BOLD),
         )]))
@@ -144,11 +154,13 @@ pub fn render_yes_no_buttons(
 
     let no_style = if !selected_button {
         Style::default()
-            .bg(Color::Red)
-            .fg(Color::Black)
+            .bg(theme.negative)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        Style::default()
+            .fg(theme.negative)
+            .add_modifier(Modifier::BOLD)
     };
 
     let no_block = ratatui::widgets::Block::default()
@@ -165,9 +177,9 @@ pub fn render_yes_no_buttons(
             no_label,
             Style::default()
                 .fg(if !selected_button {
-                    Color::Black
+                    theme.on_fill
                 } else {
-                    Color::Red
+                    theme.negative
                 })
                 .add_modifier(Modifier::BOLD),
         )]))
@@ -184,6 +196,7 @@ pub fn render_yes_no_cancel_buttons(
     yes_label: &str,
     no_label: &str,
     cancel_label: &str,
+    theme: &Theme,
 ) {
     let button_chunks = Layout::new(
         Direction::Horizontal,
@@ -205,7 +218,7 @@ pub fn render_yes_no_cancel_buttons(
             let block_style = if is_on {
                 Style::default()
                     .bg(selected_bg)
-                    .fg(Color::Black)
+                    .fg(theme.on_fill)
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default().fg(base_fg).add_modifier(Modifier::BOLD)
@@ -221,7 +234,7 @@ pub fn render_yes_no_cancel_buttons(
                 Paragraph::new(Line::from(vec![Span::styled(
                     label,
                     Style::default()
-                        .fg(if is_on { Color::Black } else { base_fg })
+                        .fg(if is_on { theme.on_fill } else { base_fg })
                         .add_modifier(Modifier::BOLD),
                 )]))
                 .alignment(ratatui::layout::Alignment::Center),
@@ -229,29 +242,33 @@ pub fn render_yes_no_cancel_buttons(
             );
         };
 
+    hit_test::record(button_chunks[1], HitTarget::Button(PopupButton::Yes));
+    hit_test::record(button_chunks[2], HitTarget::Button(PopupButton::No));
+    hit_test::record(button_chunks[3], HitTarget::Button(PopupButton::Cancel));
+
     render_one(
         0,
         button_chunks[1],
         yes_label,
         selected,
-        Color::Green,
-        Color::Green,
+        theme.positive,
+        theme.positive,
     );
     render_one(
         1,
         button_chunks[2],
         no_label,
         selected,
-        Color::Red,
-        Color::Red,
+        theme.negative,
+        theme.negative,
     );
     render_one(
         2,
         button_chunks[3],
         cancel_label,
         selected,
-        Color::Yellow,
-        Color::Yellow,
+        theme.warning,
+        theme.warning,
     );
 }
 
@@ -331,10 +348,11 @@ mod tests {
 
     #[test]
     fn render_help_text_shows_prefix_key_and_suffix() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(40, 3);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_help_text(f, f.area(), "Press ", "Enter", " to confirm"))
+            .draw(|f| render_help_text(f, f.area(), "Press ", "Enter", " to confirm", theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Press "), "missing prefix");
@@ -344,10 +362,11 @@ mod tests {
 
     #[test]
     fn render_yes_no_buttons_shows_labels_and_yes_selection() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(50, 5);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_yes_no_buttons(f, f.area(), true, "YES", "NO"))
+            .draw(|f| render_yes_no_buttons(f, f.area(), true, "YES", "NO", theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "YES"), "missing YES label");
@@ -364,10 +383,11 @@ mod tests {
 
     #[test]
     fn render_yes_no_buttons_highlights_no_when_unselected() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(50, 5);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_yes_no_buttons(f, f.area(), false, "Confirm", "Cancel"))
+            .draw(|f| render_yes_no_buttons(f, f.area(), false, "Confirm", "Cancel", theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Confirm"));
@@ -384,11 +404,12 @@ mod tests {
 
     #[test]
     fn render_yes_no_cancel_buttons_shows_all_labels() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(60, 5);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
             .draw(|f| {
-                render_yes_no_cancel_buttons(f, f.area(), 0, "YES", "NO", "CANCEL");
+                render_yes_no_cancel_buttons(f, f.area(), 0, "YES", "NO", "CANCEL", theme);
             })
             .unwrap();
         let buf = terminal.backend().buffer();
@@ -399,6 +420,7 @@ mod tests {
 
     #[test]
     fn render_yes_no_cancel_buttons_highlights_each_selection() {
+        let theme = &Theme::dark();
         for (selected, expected_bg) in
             [(0u8, Color::Green), (1u8, Color::Red), (2u8, Color::Yellow)]
         {
@@ -406,7 +428,15 @@ mod tests {
             let mut terminal = Terminal::new(backend).unwrap();
             terminal
                 .draw(|f| {
-                    render_yes_no_cancel_buttons(f, f.area(), selected, "YES", "NO", "CANCEL");
+                    render_yes_no_cancel_buttons(
+                        f,
+                        f.area(),
+                        selected,
+                        "YES",
+                        "NO",
+                        "CANCEL",
+                        theme,
+                    );
                 })
                 .unwrap();
             let buf = terminal.backend().buffer();
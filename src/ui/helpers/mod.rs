@@ -44,7 +44,7 @@ pub use dispute_selection::{
     selected_pending_dispute,
 };
 pub use formatting::{
-    dispute_status_color, format_local_timestamp, format_order_id, format_premium,
+    dispute_status_color, format_deadline, format_local_timestamp, format_order_id, format_premium,
     format_user_rating, is_dispute_finalized, relative_time_compact, short_order_id,
 };
 pub use layout::{
@@ -56,8 +56,9 @@ pub use order_chat_projection::{
     order_chat_list_item_from_db_order, OrderChatListItem,
 };
 pub use order_selection::{
-    get_filtered_book_orders, move_book_order_selection, order_passes_currency_filter,
-    selected_book_display_idx, selected_filtered_book_order,
+    cycle_book_payment_filter, get_filtered_book_orders, move_book_order_selection,
+    order_passes_currency_filter, order_passes_payment_filter, selected_book_display_idx,
+    selected_filtered_book_order,
 };
 pub use startup::{
     admin_chat_keys_clone_for_role, apply_admin_chat_updates, apply_user_order_chat_updates,
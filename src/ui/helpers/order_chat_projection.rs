@@ -7,6 +7,8 @@ use uuid::Uuid;
 
 use crate::models::Order;
 use crate::ui::{AppState, OrderMessage};
+use crate::util::mostro_info::MostroInstanceInfo;
+use crate::util::trade_deadlines::{order_expiry_deadline, trade_step_deadline, TradeDeadline};
 
 /// One row in the My Trades sidebar, derived from order DMs. Live status, amounts, and `Payload::Peer`
 /// ratings. Static id/kind/created/trade/initiator come from [`crate::ui::AppState::order_chat_static`].
@@ -29,6 +31,29 @@ pub struct OrderChatListItem {
     pub solver_pubkey: Option<String>,
     /// Dispute UUID announced by Mostro for this order.
     pub dispute_id: Option<String>,
+    /// Timestamp of the latest trade DM; start of the current waiting step.
+    pub phase_started_at: Option<i64>,
+    /// Hold or bond invoice from the latest `PayInvoice` / `PayBondInvoice` DM (its expiry caps
+    /// the step deadline).
+    pub payment_invoice: Option<String>,
+    pub created_at: Option<i64>,
+    pub expires_at: Option<i64>,
+}
+
+impl OrderChatListItem {
+    /// Live deadline for this row: book expiry while `pending`, else the current waiting step.
+    #[must_use]
+    pub fn deadline(&self, info: Option<&MostroInstanceInfo>) -> Option<TradeDeadline> {
+        match self.status? {
+            Status::Pending => order_expiry_deadline(self.expires_at, self.created_at, info),
+            status => trade_step_deadline(
+                status,
+                self.phase_started_at,
+                self.payment_invoice.as_deref(),
+                info,
+            ),
+        }
+    }
 }
 
 /// Maker listings back on the book (`pending`) with no active trade-DM row in Messages.
@@ -59,6 +84,10 @@ pub fn order_chat_list_item_from_db_order(order: &Order) -> Option<OrderChatList
         seller_reputation: None,
         solver_pubkey: order.solver_pubkey.clone(),
         dispute_id: order.dispute_id.clone(),
+        phase_started_at: None,
+        payment_invoice: None,
+        created_at: order.created_at,
+        expires_at: order.expires_at,
     })
 }
 
@@ -93,6 +122,16 @@ fn merge_peer_fields(entry: &mut OrderChatListItem, peer: &Peer) {
 fn merge_message_into_entry(entry: &mut OrderChatListItem, msg: &OrderMessage) {
     entry.trade_index = entry.trade_index.or(Some(msg.trade_index));
     entry.status = status_from_message(msg).or(entry.status);
+    entry.phase_started_at = Some(msg.timestamp);
+    let action = &msg.message.get_inner_message_kind().action;
+    entry.payment_invoice = match action {
+        Action::PayInvoice | Action::PayBondInvoice => msg.buyer_invoice.clone(),
+        _ => None,
+    };
+    if let Some(snapshot) = &msg.order_snapshot {
+        entry.created_at = snapshot.created_at.or(entry.created_at);
+        entry.expires_at = snapshot.expires_at.or(entry.expires_at);
+    }
     let Some(payload) = &msg.message.get_inner_message_kind().payload else {
         return;
     };
@@ -153,6 +192,10 @@ fn build_order_chat_list_from_messages(messages: &[OrderMessage]) -> Vec<OrderCh
                     seller_reputation: None,
                     solver_pubkey: None,
                     dispute_id: None,
+                    phase_started_at: None,
+                    payment_invoice: None,
+                    created_at: None,
+                    expires_at: None,
                 };
                 merge_message_into_entry(&mut entry, msg);
                 entry
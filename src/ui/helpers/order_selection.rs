@@ -1,8 +1,9 @@
 //! Order-book selection helpers shared by Orders tab rendering and key handling.
 //!
 //! Selection is stored as an order UUID (`AppState.selected_order_id`) and always
-//! resolved against the **currency-filtered** book projection, so highlight, ↑↓,
-//! and Enter/take/cancel never target a row hidden by `currencies_filter`.
+//! resolved against the **filtered** book projection, so highlight, ↑↓, and
+//! Enter/take/cancel never target a row hidden by `currencies_filter` or
+//! `book_payment_filter`.
 
 use std::collections::HashSet;
 
@@ -10,6 +11,8 @@ use mostro_core::prelude::SmallOrder;
 use uuid::Uuid;
 
 use crate::ui::AppState;
+use crate::util::fiat::BookOrder;
+use crate::util::payment_methods::payment_methods;
 
 /// Whether `order` passes the active currency filter (empty filter = all pass).
 pub fn order_passes_currency_filter(order: &SmallOrder, currencies_filter: &[String]) -> bool {
@@ -20,15 +23,30 @@ pub fn order_passes_currency_filter(order: &SmallOrder, currencies_filter: &[Str
     filter_set.contains(&order.fiat_code.to_uppercase())
 }
 
-/// Currency-filtered book rows as `(original_index, order)` pairs.
+/// Whether `order` offers `payment_filter` (`None` = all pass).
+///
+/// Both sides are normalized through the payment method catalog, so an order
+/// listing `"mercado pago, zelle"` passes a `MercadoPago` filter.
+pub fn order_passes_payment_filter(order: &SmallOrder, payment_filter: Option<&str>) -> bool {
+    match payment_filter {
+        None => true,
+        Some(method) => payment_methods().list_contains(&order.payment_method, method),
+    }
+}
+
+/// Currency- and payment-filtered book rows as `(original_index, order)` pairs.
 pub fn get_filtered_book_orders(
-    orders: &[SmallOrder],
+    orders: &[BookOrder],
     currencies_filter: &[String],
-) -> Vec<(usize, SmallOrder)> {
+    payment_filter: Option<&str>,
+) -> Vec<(usize, BookOrder)> {
     orders
         .iter()
         .enumerate()
-        .filter(|(_, o)| order_passes_currency_filter(o, currencies_filter))
+        .filter(|(_, o)| {
+            order_passes_currency_filter(o, currencies_filter)
+                && order_passes_payment_filter(o, payment_filter)
+        })
         .map(|(i, o)| (i, o.clone()))
         .collect()
 }
@@ -36,10 +54,10 @@ pub fn get_filtered_book_orders(
 /// Display row of the current selection inside `filtered`.
 ///
 /// Falls back to the first visible row when nothing is selected or the selected
-/// id is hidden by the currency filter. Returns `None` only when `filtered` is empty.
+/// id is hidden by a filter. Returns `None` only when `filtered` is empty.
 pub fn selected_book_display_idx(
     selected_order_id: Option<Uuid>,
-    filtered: &[(usize, SmallOrder)],
+    filtered: &[(usize, BookOrder)],
 ) -> Option<usize> {
     if filtered.is_empty() {
         return None;
@@ -53,18 +71,26 @@ pub fn selected_book_display_idx(
 
 /// The order the Orders table currently shows as selected.
 ///
-/// Resolves `selected_order_id` against the currency-filtered book so Enter/take
+/// Resolves `selected_order_id` against the filtered book so Enter/take
 /// always acts on the highlighted row — never on a row hidden by the filter.
-pub fn selected_filtered_book_order(app: &AppState, orders: &[SmallOrder]) -> Option<SmallOrder> {
-    let mut filtered = get_filtered_book_orders(orders, &app.currencies_filter);
+pub fn selected_filtered_book_order(app: &AppState, orders: &[BookOrder]) -> Option<BookOrder> {
+    let mut filtered = get_filtered_book_orders(
+        orders,
+        &app.currencies_filter,
+        app.book_payment_filter.as_deref(),
+    );
     let idx = selected_book_display_idx(app.selected_order_id, &filtered)?;
     Some(filtered.swap_remove(idx).1)
 }
 
 /// Move Orders-tab selection `delta` rows within the filtered book, clamping at
 /// both ends, and store the landing order's id (when present).
-pub fn move_book_order_selection(app: &mut AppState, orders: &[SmallOrder], delta: isize) {
-    let filtered = get_filtered_book_orders(orders, &app.currencies_filter);
+pub fn move_book_order_selection(app: &mut AppState, orders: &[BookOrder], delta: isize) {
+    let filtered = get_filtered_book_orders(
+        orders,
+        &app.currencies_filter,
+        app.book_payment_filter.as_deref(),
+    );
     let Some(idx) = selected_book_display_idx(app.selected_order_id, &filtered) else {
         app.selected_order_id = None;
         return;
@@ -75,14 +101,37 @@ pub fn move_book_order_selection(app: &mut AppState, orders: &[SmallOrder], delt
     app.selected_order_id = filtered[new_idx].1.id;
 }
 
+/// Advance `book_payment_filter` through the canonical methods offered by the
+/// currency-filtered book (sorted), wrapping back to `None` after the last one.
+pub fn cycle_book_payment_filter(app: &mut AppState, orders: &[BookOrder]) {
+    let catalog = payment_methods();
+    let mut methods: Vec<String> = Vec::new();
+    for (_, order) in get_filtered_book_orders(orders, &app.currencies_filter, None) {
+        for name in catalog.normalize_list(&order.payment_method).split(',') {
+            if !name.is_empty() && !methods.iter().any(|m| m == name) {
+                methods.push(name.to_string());
+            }
+        }
+    }
+    methods.sort_by_key(|m| m.to_lowercase());
+    let next = match app.book_payment_filter.as_deref() {
+        None => 0,
+        Some(current) => methods
+            .iter()
+            .position(|m| m == current)
+            .map_or(0, |i| i + 1),
+    };
+    app.book_payment_filter = methods.get(next).cloned();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::ui::UserRole;
     use mostro_core::prelude::Kind;
 
-    fn order(id: Uuid, fiat: &str, payment: &str) -> SmallOrder {
-        SmallOrder {
+    fn order(id: Uuid, fiat: &str, payment: &str) -> BookOrder {
+        BookOrder::whole(SmallOrder {
             id: Some(id),
             kind: Some(Kind::Buy),
             fiat_code: fiat.to_string(),
@@ -90,7 +139,7 @@ mod tests {
             amount: 50_000,
             payment_method: payment.to_string(),
             ..Default::default()
-        }
+        })
     }
 
     #[test]
@@ -98,7 +147,7 @@ mod tests {
         let a = Uuid::new_v4();
         let b = Uuid::new_v4();
         let orders = vec![order(a, "USD", "sepa"), order(b, "EUR", "sepa")];
-        let filtered = get_filtered_book_orders(&orders, &[]);
+        let filtered = get_filtered_book_orders(&orders, &[], None);
         assert_eq!(filtered.len(), 2);
     }
 
@@ -107,7 +156,7 @@ mod tests {
         let a = Uuid::new_v4();
         let b = Uuid::new_v4();
         let orders = vec![order(a, "USD", "sepa"), order(b, "EUR", "sepa")];
-        let filtered = get_filtered_book_orders(&orders, &["EUR".to_string()]);
+        let filtered = get_filtered_book_orders(&orders, &["EUR".to_string()], None);
         assert_eq!(filtered.len(), 1);
         assert_eq!(filtered[0].1.id, Some(b));
     }
@@ -128,7 +177,7 @@ mod tests {
         app.selected_order_id = Some(usd_id);
         app.currencies_filter = vec!["EUR".to_string()];
 
-        let filtered = get_filtered_book_orders(&orders, &app.currencies_filter);
+        let filtered = get_filtered_book_orders(&orders, &app.currencies_filter, None);
         assert_eq!(
             selected_book_display_idx(app.selected_order_id, &filtered),
             Some(0),
@@ -188,6 +237,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn payment_filter_matches_aliases_inside_method_lists() {
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let orders = vec![
+            order(a, "ARS", "mercado pago, Transferencia"),
+            order(b, "ARS", "Cash"),
+        ];
+        let filtered = get_filtered_book_orders(&orders, &[], Some("MercadoPago"));
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].1.id, Some(a));
+
+        let mut app = AppState::new(UserRole::User);
+        app.book_payment_filter = Some("Cash".to_string());
+        let selected = selected_filtered_book_order(&app, &orders).expect("visible order");
+        assert_eq!(selected.id, Some(b));
+    }
+
+    #[test]
+    fn cycle_payment_filter_walks_canonical_methods_then_clears() {
+        let orders = vec![
+            order(Uuid::new_v4(), "ARS", "zelle, mercado pago"),
+            order(Uuid::new_v4(), "ARS", "MercadoPago"),
+            order(Uuid::new_v4(), "EUR", "SEPA"),
+        ];
+        let mut app = AppState::new(UserRole::User);
+        app.currencies_filter = vec!["ARS".to_string()];
+
+        cycle_book_payment_filter(&mut app, &orders);
+        assert_eq!(app.book_payment_filter.as_deref(), Some("MercadoPago"));
+        cycle_book_payment_filter(&mut app, &orders);
+        assert_eq!(app.book_payment_filter.as_deref(), Some("Zelle"));
+        cycle_book_payment_filter(&mut app, &orders);
+        assert_eq!(app.book_payment_filter, None);
+    }
+
     #[test]
     fn empty_filtered_list_yields_no_selection() {
         let orders = vec![order(Uuid::new_v4(), "USD", "sepa")];
@@ -519,15 +519,13 @@ pub fn apply_user_order_chat_updates(app: &mut AppState, updates: Vec<crate::ui:
                 continue;
             }
 
-            let (msg_content, attachment) = match update.channel {
-                UserChatChannel::Peer => match try_parse_attachment_message(&content) {
-                    Some((attachment, display)) => (display, Some(attachment)),
-                    None => (content.clone(), None),
-                },
-                UserChatChannel::Solver => (content.clone(), None),
+            let (msg_content, attachment) = match try_parse_attachment_message(&content) {
+                Some((attachment, display)) => (display, Some(attachment)),
+                None => (content.clone(), None),
             };
 
-            if let Some(ref att) = attachment {
+            // Legacy placeholder upgrades only exist in peer transcripts.
+            if let (Some(att), UserChatChannel::Peer) = (attachment.as_ref(), update.channel) {
                 if let Some(idx) = messages_vec.iter().position(|m| {
                     m.timestamp == ts
                         && m.attachment.is_none()
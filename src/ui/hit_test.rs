@@ -0,0 +1,176 @@
+//! Mouse hit-testing: clickable regions recorded while a frame is drawn.
+//!
+//! Renderers call [`record`] with the rectangle they just laid out; [`crate::ui::ui_draw`]
+//! collects the regions into [`HitMap`] (`AppState::hit_map`) so the mouse handler resolves
+//! clicks against what is actually on screen. Like the active theme, the per-frame list lives
+//! in a thread-local so nested helpers (popup buttons, tab bar) need no extra parameters.
+
+use std::cell::RefCell;
+use std::mem::Discriminant;
+
+use ratatui::layout::{Position, Rect};
+use uuid::Uuid;
+
+use crate::ui::{Tab, ThreeState, UiMode};
+
+/// Popup button identity, shared by YES/NO and YES/NO/CANCEL rows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PopupButton {
+    Yes,
+    No,
+    Cancel,
+}
+
+impl PopupButton {
+    pub const fn as_three_state(self) -> ThreeState {
+        match self {
+            Self::Yes => ThreeState::Yes,
+            Self::No => ThreeState::No,
+            Self::Cancel => ThreeState::Cancel,
+        }
+    }
+}
+
+/// Chat panes backed by a `tui_scrollview::ScrollViewState`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChatScroll {
+    /// My Trades order chat (`order_chat_scrollview_state`).
+    Order,
+    /// Admin dispute chat (`admin_chat_scrollview_state`).
+    Dispute,
+    /// Observer chat (`observer_scrollview_state`).
+    Observer,
+}
+
+/// What a recorded screen region stands for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HitTarget {
+    Tab(Tab),
+    /// Row of the Orders table, keyed by order id.
+    OrderRow(Uuid),
+    /// Row of the My Trades sidebar, keyed by order id.
+    MyTradesRow(Uuid),
+    Button(PopupButton),
+    /// List or table whose selection follows ↑/↓ (scroll wheel moves the selection).
+    List,
+    Chat(ChatScroll),
+}
+
+impl HitTarget {
+    fn is_scrollable(self) -> bool {
+        matches!(self, Self::List | Self::Chat(_))
+    }
+}
+
+thread_local! {
+    static FRAME_REGIONS: RefCell<Vec<(Rect, HitTarget)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Start collecting regions for a new frame.
+pub fn begin_frame() {
+    FRAME_REGIONS.with(|regions| regions.borrow_mut().clear());
+}
+
+/// Record a clickable region. Later records sit on top of earlier ones.
+pub fn record(area: Rect, target: HitTarget) {
+    if area.is_empty() {
+        return;
+    }
+    FRAME_REGIONS.with(|regions| regions.borrow_mut().push((area, target)));
+}
+
+/// Record the two halves of a YES/NO button row.
+pub fn record_yes_no(yes: Rect, no: Rect) {
+    record(yes, HitTarget::Button(PopupButton::Yes));
+    record(no, HitTarget::Button(PopupButton::No));
+}
+
+/// Take the regions recorded since [`begin_frame`].
+pub fn finish_frame(mode: &UiMode) -> HitMap {
+    HitMap {
+        regions: FRAME_REGIONS.with(|regions| std::mem::take(&mut *regions.borrow_mut())),
+        mode: Some(std::mem::discriminant(mode)),
+    }
+}
+
+/// Regions of the last drawn frame, plus the mode they were drawn for.
+#[derive(Clone, Debug, Default)]
+pub struct HitMap {
+    regions: Vec<(Rect, HitTarget)>,
+    mode: Option<Discriminant<UiMode>>,
+}
+
+impl HitMap {
+    /// Whether the map still describes the screen for `mode` (no popup opened or closed since).
+    #[must_use]
+    pub fn matches_mode(&self, mode: &UiMode) -> bool {
+        self.mode == Some(std::mem::discriminant(mode))
+    }
+
+    /// Topmost region under the cell.
+    #[must_use]
+    pub fn target_at(&self, column: u16, row: u16) -> Option<HitTarget> {
+        let position = Position::new(column, row);
+        self.regions
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains(position))
+            .map(|(_, target)| *target)
+    }
+
+    /// Topmost scrollable region (list or chat) under the cell, skipping rows and buttons.
+    #[must_use]
+    pub fn scroll_target_at(&self, column: u16, row: u16) -> Option<HitTarget> {
+        let position = Position::new(column, row);
+        self.regions
+            .iter()
+            .rev()
+            .find(|(area, target)| target.is_scrollable() && area.contains(position))
+            .map(|(_, target)| *target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::UserTab;
+
+    fn frame(regions: &[(Rect, HitTarget)]) -> HitMap {
+        begin_frame();
+        for (area, target) in regions {
+            record(*area, *target);
+        }
+        finish_frame(&UiMode::Normal)
+    }
+
+    #[test]
+    fn later_regions_win_and_scroll_lookup_skips_rows() {
+        let id = Uuid::new_v4();
+        let map = frame(&[
+            (Rect::new(0, 0, 20, 10), HitTarget::List),
+            (Rect::new(1, 2, 18, 1), HitTarget::OrderRow(id)),
+        ]);
+        assert_eq!(map.target_at(5, 2), Some(HitTarget::OrderRow(id)));
+        assert_eq!(map.target_at(5, 3), Some(HitTarget::List));
+        assert_eq!(map.scroll_target_at(5, 2), Some(HitTarget::List));
+        assert_eq!(map.target_at(25, 2), None);
+    }
+
+    #[test]
+    fn empty_regions_are_ignored_and_frames_start_clean() {
+        let _ = frame(&[(Rect::new(0, 0, 5, 5), HitTarget::List)]);
+        let map = frame(&[(
+            Rect::new(0, 0, 0, 3),
+            HitTarget::Tab(Tab::User(UserTab::Orders)),
+        )]);
+        assert_eq!(map.target_at(0, 0), None);
+    }
+
+    #[test]
+    fn map_is_tied_to_the_mode_it_was_drawn_for() {
+        let map = frame(&[]);
+        assert!(map.matches_mode(&UiMode::Normal));
+        assert!(!map.matches_mode(&UiMode::ConfirmExit(true)));
+        assert!(!HitMap::default().matches_mode(&UiMode::Normal));
+    }
+}
@@ -0,0 +1,232 @@
+//! Image preview popup (V in a Save attachment list): shows a decrypted chat image in the terminal.
+
+use std::sync::Arc;
+
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::i18n::{tr, tr_args};
+use crate::ui::terminal_graphics::{render_half_blocks, GraphicsPlacement, GraphicsProtocol};
+use crate::ui::{helpers, AppState, ChatAttachment, UiMode};
+use crate::util::image_preview::PreviewImage;
+
+/// Share of the screen the popup may use.
+const POPUP_WIDTH_PERCENT: u16 = 90;
+const POPUP_HEIGHT_PERCENT: u16 = 85;
+
+#[derive(Clone, Debug)]
+pub enum ImagePreviewState {
+    /// Download and decode in flight; `blossom_url` matches the result to this popup.
+    Loading {
+        filename: String,
+        blossom_url: String,
+    },
+    Ready(Arc<PreviewImage>),
+    Failed {
+        filename: String,
+        message: String,
+    },
+}
+
+impl ImagePreviewState {
+    pub fn filename(&self) -> &str {
+        match self {
+            Self::Loading { filename, .. } | Self::Failed { filename, .. } => filename,
+            Self::Ready(image) => &image.filename,
+        }
+    }
+
+    /// Whether this popup is still waiting for `blossom_url`.
+    pub fn is_loading(&self, url: &str) -> bool {
+        matches!(self, Self::Loading { blossom_url, .. } if blossom_url == url)
+    }
+}
+
+/// Open the popup in its loading state over the current mode (restored on close).
+pub fn open_image_preview(app: &mut AppState, attachment: &ChatAttachment) {
+    let previous = app.mode.clone();
+    app.mode = UiMode::ImagePreview(
+        ImagePreviewState::Loading {
+            filename: attachment.filename.clone(),
+            blossom_url: attachment.blossom_url.clone(),
+        },
+        Box::new(previous),
+    );
+}
+
+/// Apply a finished load if the popup for that image is still open.
+pub fn apply_image_preview_result(
+    app: &mut AppState,
+    result: Result<Arc<PreviewImage>, (String, String)>,
+) {
+    let UiMode::ImagePreview(state, _) = &mut app.mode else {
+        return;
+    };
+    match result {
+        Ok(image) if state.is_loading(&image.blossom_url) => {
+            *state = ImagePreviewState::Ready(image);
+        }
+        Err((url, message)) if state.is_loading(&url) => {
+            *state = ImagePreviewState::Failed {
+                filename: state.filename().to_string(),
+                message,
+            };
+        }
+        _ => {}
+    }
+}
+
+pub fn render_image_preview_popup(
+    f: &mut ratatui::Frame,
+    app: &mut AppState,
+    state: &ImagePreviewState,
+) {
+    let theme = &app.theme;
+    let area = f.area();
+    let popup = helpers::create_centered_popup(
+        area,
+        area.width * POPUP_WIDTH_PERCENT / 100,
+        area.height * POPUP_HEIGHT_PERCENT / 100,
+    );
+    f.render_widget(Clear, popup);
+
+    let title = match state {
+        ImagePreviewState::Ready(image) => format!(
+            " 🖼 {} ({}×{}) ",
+            image.filename,
+            image.image.width(),
+            image.image.height()
+        ),
+        _ => format!(" 🖼 {} ", state.filename()),
+    };
+    let block = Block::default()
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(theme.background));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let [body, footer] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            tr("image_preview.footer"),
+            Style::default().fg(theme.muted),
+        ))
+        .alignment(Alignment::Center),
+        footer,
+    );
+
+    match state {
+        ImagePreviewState::Loading { .. } => {
+            f.render_widget(
+                Paragraph::new(Span::styled(
+                    tr("image_preview.loading"),
+                    Style::default().fg(theme.text),
+                ))
+                .alignment(Alignment::Center),
+                centered_line(body),
+            );
+        }
+        ImagePreviewState::Failed { message, .. } => {
+            f.render_widget(
+                Paragraph::new(Line::from(Span::styled(
+                    tr_args("image_preview.failed", &[("error", message)]),
+                    Style::default().fg(theme.negative),
+                )))
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true }),
+                centered_line(body),
+            );
+        }
+        ImagePreviewState::Ready(image) => match app.image_protocol {
+            GraphicsProtocol::HalfBlocks => {
+                render_half_blocks(&image.image, body, f.buffer_mut(), theme.background);
+            }
+            GraphicsProtocol::Kitty | GraphicsProtocol::Sixel => {
+                app.graphics_overlay.pending = Some(GraphicsPlacement {
+                    area: body,
+                    image: Arc::clone(image),
+                });
+            }
+        },
+    }
+}
+
+fn centered_line(area: ratatui::layout::Rect) -> ratatui::layout::Rect {
+    let [line] = Layout::vertical([Constraint::Length(2)])
+        .flex(ratatui::layout::Flex::Center)
+        .areas(area);
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::chat::ChatAttachmentType;
+    use crate::ui::UserRole;
+    use image::{Rgba, RgbaImage};
+
+    fn attachment(url: &str) -> ChatAttachment {
+        ChatAttachment {
+            blossom_url: url.into(),
+            filename: "receipt.jpg".into(),
+            mime_type: Some("image/jpeg".into()),
+            file_type: ChatAttachmentType::Image,
+            decryption_key: Some(vec![1; 32]),
+        }
+    }
+
+    fn loaded(url: &str) -> Arc<PreviewImage> {
+        Arc::new(PreviewImage {
+            id: 1,
+            filename: "receipt.jpg".into(),
+            blossom_url: url.into(),
+            image: RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 255])),
+        })
+    }
+
+    #[test]
+    fn results_only_land_on_the_matching_open_preview() {
+        let mut app = AppState::new(UserRole::User);
+        open_image_preview(&mut app, &attachment("blossom://a/1"));
+
+        apply_image_preview_result(&mut app, Ok(loaded("blossom://a/other")));
+        assert!(matches!(
+            &app.mode,
+            UiMode::ImagePreview(ImagePreviewState::Loading { .. }, _)
+        ));
+
+        apply_image_preview_result(&mut app, Ok(loaded("blossom://a/1")));
+        assert!(matches!(
+            &app.mode,
+            UiMode::ImagePreview(ImagePreviewState::Ready(_), _)
+        ));
+
+        // A late error for the same URL does not replace the shown image.
+        apply_image_preview_result(&mut app, Err(("blossom://a/1".into(), "boom".into())));
+        assert!(matches!(
+            &app.mode,
+            UiMode::ImagePreview(ImagePreviewState::Ready(_), _)
+        ));
+    }
+
+    #[test]
+    fn failed_load_keeps_popup_with_message() {
+        let mut app = AppState::new(UserRole::Admin);
+        open_image_preview(&mut app, &attachment("blossom://a/2"));
+        apply_image_preview_result(&mut app, Err(("blossom://a/2".into(), "404".into())));
+        let UiMode::ImagePreview(ImagePreviewState::Failed { filename, message }, _) = &app.mode
+        else {
+            panic!("expected failed preview");
+        };
+        assert_eq!(filename, "receipt.jpg");
+        assert_eq!(message, "404");
+    }
+}
@@ -10,21 +10,23 @@ use crate::ui::{
 };
 use crate::util::fatal::request_fatal_restart;
 use crate::util::fetch_mostro_instance_info;
+use crate::util::fiat::BookOrder;
 use crate::util::listen_for_order_messages;
+use crate::util::ln_address::{parse_payout_invoice_input, PayoutInvoiceInput};
 use crate::util::order_utils::spawn_fetch_scheduler_loops;
 use crate::util::{
     any_relay_reachable, catch_unwind_request_fatal_restart, connect_client_safely,
-    hydrate_startup_active_order_dm_state, listen_for_chat_messages, set_chat_router_cmd_tx,
-    set_dm_router_cmd_tx, unsubscribe_dm_listener_subscriptions, ChatRouterCmd,
-    OrderDmSubscriptionCmd, StartupDmHydration,
+    hydrate_startup_active_order_dm_state, listen_for_chat_messages, nostr_client_builder,
+    set_chat_router_cmd_tx, set_dm_router_cmd_tx, unsubscribe_dm_listener_subscriptions,
+    ChatRouterCmd, OrderDmSubscriptionCmd, StartupDmHydration,
 };
-use mostro_core::prelude::{Dispute, SmallOrder, Transport};
+use mostro_core::prelude::{Dispute, Transport};
 use nostr_sdk::prelude::{Client, Keys, Output, PublicKey, SignerAuthenticator};
 use sqlx::SqlitePool;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
 use std::{
-    env, fs,
+    fs,
     time::{SystemTime, UNIX_EPOCH},
 };
 use tokio::sync::mpsc::{Receiver, Sender, UnboundedReceiver, UnboundedSender};
@@ -40,7 +42,7 @@ pub struct RuntimeReconnectContext<'a> {
     pub pool: &'a SqlitePool,
     pub message_listener_handle: &'a mut JoinHandle<()>,
     pub message_notification_tx: &'a UnboundedSender<MessageNotification>,
-    pub orders: Arc<Mutex<Vec<SmallOrder>>>,
+    pub orders: Arc<Mutex<Vec<BookOrder>>>,
     pub disputes: Arc<Mutex<Vec<Dispute>>>,
     pub order_fetch_task: &'a mut JoinHandle<()>,
     pub dispute_fetch_task: &'a mut JoinHandle<()>,
@@ -244,7 +246,7 @@ pub async fn apply_pending_key_reload(
     pool: &SqlitePool,
     message_listener_handle: &mut JoinHandle<()>,
     message_notification_tx: &UnboundedSender<MessageNotification>,
-    orders: Arc<Mutex<Vec<SmallOrder>>>,
+    orders: Arc<Mutex<Vec<BookOrder>>>,
     disputes: Arc<Mutex<Vec<Dispute>>>,
     order_fetch_task: &mut JoinHandle<()>,
     dispute_fetch_task: &mut JoinHandle<()>,
@@ -253,7 +255,7 @@ pub async fn apply_pending_key_reload(
     match load_settings_from_disk() {
         Ok(latest_settings) => match latest_settings.nsec_privkey.parse::<Keys>() {
             Ok(new_identity_keys) => {
-                let new_client = Client::builder()
+                let new_client = nostr_client_builder()
                     .authenticator(SignerAuthenticator::new(new_identity_keys.clone()))
                     .build();
                 let mut reload_error: Option<String> = None;
@@ -459,7 +461,7 @@ pub async fn apply_pending_fetch_scheduler_reload(
     mostro_pubkey: &mut PublicKey,
     current_mostro_pubkey: &Arc<Mutex<PublicKey>>,
     pool: &SqlitePool,
-    orders: Arc<Mutex<Vec<SmallOrder>>>,
+    orders: Arc<Mutex<Vec<BookOrder>>>,
     disputes: Arc<Mutex<Vec<Dispute>>>,
     order_fetch_task: &mut JoinHandle<()>,
     dispute_fetch_task: &mut JoinHandle<()>,
@@ -594,7 +596,7 @@ pub async fn apply_pending_runtime_reloads(
     pool: &SqlitePool,
     message_listener_handle: &mut JoinHandle<()>,
     message_notification_tx: &UnboundedSender<MessageNotification>,
-    orders: &Arc<Mutex<Vec<SmallOrder>>>,
+    orders: &Arc<Mutex<Vec<BookOrder>>>,
     disputes: &Arc<Mutex<Vec<Dispute>>>,
     order_fetch_task: &mut JoinHandle<()>,
     dispute_fetch_task: &mut JoinHandle<()>,
@@ -830,6 +832,10 @@ pub struct AppChannels {
     pub fatal_error_rx: UnboundedReceiver<String>,
     pub ln_address_result_tx: UnboundedSender<LnAddressVerifyResult>,
     pub ln_address_result_rx: UnboundedReceiver<LnAddressVerifyResult>,
+    pub local_api_call_tx: UnboundedSender<crate::util::LocalApiCall>,
+    pub local_api_call_rx: UnboundedReceiver<crate::util::LocalApiCall>,
+    /// New-DM event lines for `subscribe_events` connections of the local API.
+    pub local_api_events_tx: tokio::sync::broadcast::Sender<String>,
 }
 
 pub fn create_app_channels() -> AppChannels {
@@ -864,6 +870,10 @@ pub fn create_app_channels() -> AppChannels {
     let (fatal_error_tx, fatal_error_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
     let (ln_address_result_tx, ln_address_result_rx) =
         tokio::sync::mpsc::unbounded_channel::<LnAddressVerifyResult>();
+    let (local_api_call_tx, local_api_call_rx) =
+        tokio::sync::mpsc::unbounded_channel::<crate::util::LocalApiCall>();
+    let (local_api_events_tx, _) =
+        tokio::sync::broadcast::channel::<String>(crate::util::LOCAL_API_EVENT_CAPACITY);
 
     AppChannels {
         order_result_tx,
@@ -894,6 +904,9 @@ pub fn create_app_channels() -> AppChannels {
         fatal_error_rx,
         ln_address_result_tx,
         ln_address_result_rx,
+        local_api_call_tx,
+        local_api_call_rx,
+        local_api_events_tx,
     }
 }
 
@@ -953,7 +966,11 @@ pub fn spawn_verify_and_save_ln_address_task(
         match crate::util::ln_address::ln_address_pay_request_reachable(&trimmed).await {
             Ok(()) => match load_settings_from_disk() {
                 Ok(mut s) => {
-                    s.ln_address = trimmed.clone();
+                    // Store `user@domain` rather than a pasted `lightning:` URI wrapping it.
+                    s.ln_address = match parse_payout_invoice_input(&trimmed) {
+                        Ok(PayoutInvoiceInput::LightningAddress(address)) => address,
+                        _ => trimmed.clone(),
+                    };
                     match crate::settings::save_settings(&s) {
                         Ok(()) => {
                             log::info!("Lightning address saved after LNURL verification");
@@ -1136,28 +1153,16 @@ pub fn spawn_key_rotation_task(
 
             let mut s = crate::settings::load_settings_from_disk()?;
             s.nsec_privkey = derived_nsec.clone();
-            let toml_string = toml::to_string_pretty(&s)
-                .map_err(|e| anyhow::anyhow!("Failed to serialize settings: {}", e))?;
-
-            let home_dir =
-                dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
-            let package_name = env!("CARGO_PKG_NAME");
-            let hidden_file_path = home_dir
-                .join(format!(".{package_name}"))
-                .join("settings.toml");
-            let executable_file_path = env::current_exe()
-                .ok()
-                .and_then(|p| p.parent().map(|dir| dir.join("settings.toml")));
-            let target_settings_file = executable_file_path
-                .filter(|p| p.exists())
-                .unwrap_or(hidden_file_path);
+            let settings_write = crate::settings::prepare_settings_write(&s)?;
+
+            let target_settings_file = crate::settings::settings_file_path()?;
 
             let nanos = SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .unwrap_or_default()
                 .as_nanos();
             let tmp_path = target_settings_file.with_extension(format!("tmp-{}", nanos));
-            fs::write(&tmp_path, toml_string)
+            fs::write(&tmp_path, settings_write.contents())
                 .map_err(|e| anyhow::anyhow!("Failed to write temporary settings file: {}", e))?;
 
             if let Err(e) = tx.commit().await {
@@ -1171,6 +1176,7 @@ pub fn spawn_key_rotation_task(
                     e
                 ));
             }
+            settings_write.commit();
             Ok(())
         }
         .await;
@@ -0,0 +1,71 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use sqlx::SqlitePool;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::i18n::tr;
+use crate::ui::backup_popup::BackupFormKind;
+use crate::ui::{AppState, OperationResult, UiMode};
+use crate::util::backup::{spawn_create_backup_task, spawn_stage_restore_task};
+
+/// Handle a key while the backup / restore form is open. Consumes every key.
+pub fn handle_backup_form_key(
+    key_event: KeyEvent,
+    app: &mut AppState,
+    pool: &SqlitePool,
+    order_result_tx: &UnboundedSender<OperationResult>,
+) {
+    let UiMode::BackupForm(state) = &mut app.mode else {
+        return;
+    };
+    let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+    match key_event.code {
+        KeyCode::Esc => app.mode = UiMode::default_for_role(app.user_role),
+        KeyCode::Tab | KeyCode::Down => state.move_focus(1),
+        KeyCode::BackTab | KeyCode::Up => state.move_focus(-1),
+        KeyCode::Backspace => {
+            state.focused_input_mut().pop();
+            state.error = None;
+        }
+        KeyCode::Char(c) if !ctrl => {
+            state.focused_input_mut().push(c);
+            state.error = None;
+        }
+        KeyCode::Enter if !state.focus_is_last() => state.move_focus(1),
+        KeyCode::Enter => match state.validate() {
+            Err(message) => state.error = Some(message),
+            Ok((path, passphrase)) => {
+                let pending = match state.kind {
+                    BackupFormKind::Create => {
+                        spawn_create_backup_task(
+                            pool.clone(),
+                            path,
+                            passphrase,
+                            order_result_tx.clone(),
+                        );
+                        tr("backup.writing")
+                    }
+                    BackupFormKind::Restore => {
+                        spawn_stage_restore_task(
+                            pool.clone(),
+                            path,
+                            passphrase,
+                            order_result_tx.clone(),
+                        );
+                        tr("backup.verifying")
+                    }
+                };
+                app.mode = UiMode::operation_result(OperationResult::Info(pending.to_string()));
+            }
+        },
+        _ => {}
+    }
+}
+
+/// Bracketed paste into the focused field (control characters dropped).
+pub fn paste_into_backup_form(app: &mut AppState, pasted_text: &str) {
+    if let UiMode::BackupForm(state) = &mut app.mode {
+        let filtered: String = pasted_text.chars().filter(|c| !c.is_control()).collect();
+        state.focused_input_mut().push_str(&filtered);
+        state.error = None;
+    }
+}
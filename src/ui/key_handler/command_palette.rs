@@ -0,0 +1,169 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::ui::command_palette::{
+    filter_entries, palette_entries, settings_option_index, CommandPaletteState, PaletteAction,
+};
+use crate::ui::key_handler::navigation::switch_to_tab;
+use crate::ui::{AdminTab, AppState, Tab, UiMode, UserRole, UserTab};
+
+/// Open the palette over the current mode (Ctrl+P); the mode is restored when it closes.
+pub fn open_command_palette(app: &mut AppState) {
+    let previous = app.mode.clone();
+    app.mode = UiMode::CommandPalette(CommandPaletteState::default(), Box::new(previous));
+}
+
+/// Handle a key while the command palette is open.
+///
+/// Returns the key event to replay through `handle_key_event` when an entry runs, so palette
+/// actions share the exact code path of their shortcuts.
+pub fn handle_command_palette_key(key_event: KeyEvent, app: &mut AppState) -> Option<KeyEvent> {
+    let UiMode::CommandPalette(state, previous_mode) = &mut app.mode else {
+        return None;
+    };
+    let code = key_event.code;
+    let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+
+    if code == KeyCode::Esc || (ctrl && code == KeyCode::Char('p')) {
+        app.mode = (**previous_mode).clone();
+        return None;
+    }
+
+    match code {
+        KeyCode::Up => {
+            state.selected = state.selected.saturating_sub(1);
+            None
+        }
+        KeyCode::Down => {
+            let count = filter_entries(&palette_entries(app), &query_of(app)).len();
+            if let UiMode::CommandPalette(state, _) = &mut app.mode {
+                state.selected = (state.selected + 1).min(count.saturating_sub(1));
+            }
+            None
+        }
+        KeyCode::Backspace => {
+            state.query.pop();
+            state.selected = 0;
+            None
+        }
+        KeyCode::Char(c) if !ctrl => {
+            state.query.push(c);
+            state.selected = 0;
+            None
+        }
+        KeyCode::Enter => {
+            let matches = filter_entries(&palette_entries(app), &query_of(app));
+            let UiMode::CommandPalette(state, previous_mode) = &app.mode else {
+                return None;
+            };
+            let last = matches.len().saturating_sub(1);
+            let (entry, _) = matches.get(state.selected.min(last))?;
+            app.mode = (**previous_mode).clone();
+            run_action(app, entry.action)
+        }
+        _ => None,
+    }
+}
+
+fn query_of(app: &AppState) -> String {
+    match &app.mode {
+        UiMode::CommandPalette(state, _) => state.query.clone(),
+        _ => String::new(),
+    }
+}
+
+fn run_action(app: &mut AppState, action: PaletteAction) -> Option<KeyEvent> {
+    match action {
+        PaletteAction::GoTo(tab) => {
+            switch_to_tab(app, tab);
+            None
+        }
+        PaletteAction::Settings(settings_action) => {
+            let index = settings_option_index(app.user_role, settings_action)?;
+            let settings_tab = match app.user_role {
+                UserRole::User => Tab::User(UserTab::Settings),
+                UserRole::Admin => Tab::Admin(AdminTab::Settings),
+            };
+            switch_to_tab(app, settings_tab);
+            app.selected_settings_option = index;
+            Some(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+        }
+        PaletteAction::Key {
+            tab,
+            code,
+            modifiers,
+        } => {
+            if let Some(tab) = tab {
+                switch_to_tab(app, tab);
+            }
+            Some(KeyEvent::new(code, modifiers))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::tabs::settings_tab::SettingsMenuAction;
+    use crate::ui::UserMode;
+
+    fn press(app: &mut AppState, code: KeyCode) -> Option<KeyEvent> {
+        handle_command_palette_key(KeyEvent::new(code, KeyModifiers::NONE), app)
+    }
+
+    #[test]
+    fn typing_filters_and_enter_runs_settings_row() {
+        let mut app = AppState::new(UserRole::User);
+        app.mode = UiMode::UserMode(UserMode::Normal);
+        open_command_palette(&mut app);
+        for c in "add relay".chars() {
+            assert!(press(&mut app, KeyCode::Char(c)).is_none());
+        }
+        let replay = press(&mut app, KeyCode::Enter);
+        assert_eq!(replay.map(|k| k.code), Some(KeyCode::Enter));
+        assert_eq!(app.active_tab, Tab::User(UserTab::Settings));
+        assert_eq!(
+            Some(app.selected_settings_option),
+            settings_option_index(UserRole::User, SettingsMenuAction::AddRelay)
+        );
+        assert!(matches!(app.mode, UiMode::UserMode(UserMode::Normal)));
+    }
+
+    #[test]
+    fn esc_restores_previous_mode_and_goto_switches_tab() {
+        let mut app = AppState::new(UserRole::User);
+        app.mode = UiMode::UserMode(UserMode::Normal);
+        open_command_palette(&mut app);
+        assert!(press(&mut app, KeyCode::Esc).is_none());
+        assert!(matches!(app.mode, UiMode::UserMode(UserMode::Normal)));
+
+        open_command_palette(&mut app);
+        let Some(PaletteAction::GoTo(target)) = palette_entries(&app)
+            .iter()
+            .map(|e| e.action)
+            .find(|a| matches!(a, PaletteAction::GoTo(_)))
+        else {
+            panic!("palette lists tab jumps");
+        };
+        assert!(run_action(&mut app, PaletteAction::GoTo(target)).is_none());
+        assert_eq!(app.active_tab, target);
+    }
+
+    #[test]
+    fn selection_is_clamped_to_matches() {
+        let mut app = AppState::new(UserRole::Admin);
+        open_command_palette(&mut app);
+        for _ in 0..500 {
+            press(&mut app, KeyCode::Down);
+        }
+        let UiMode::CommandPalette(state, _) = &app.mode else {
+            panic!("palette stays open");
+        };
+        let count = palette_entries(&app).len();
+        assert_eq!(state.selected, count - 1);
+        press(&mut app, KeyCode::Char('x'));
+        let UiMode::CommandPalette(state, _) = &app.mode else {
+            panic!("palette stays open");
+        };
+        assert_eq!(state.selected, 0);
+    }
+}
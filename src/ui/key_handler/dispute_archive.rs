@@ -0,0 +1,81 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::ui::tabs::dispute_archive_tab::archive_result_count;
+use crate::ui::AppState;
+
+/// Dispute Archive search box: typing filters live, ↑↓ move through the results and Esc
+/// clears a non-empty search. Returns `None` for keys the tab does not own.
+pub fn handle_dispute_archive_key(app: &mut AppState, key_event: &KeyEvent) -> Option<bool> {
+    if !app.archive_inputs_editable() || key_event.modifiers.contains(KeyModifiers::CONTROL) {
+        return None;
+    }
+    match key_event.code {
+        KeyCode::Char(c) => {
+            app.archive_query.push(c);
+            app.archive_selected = 0;
+        }
+        KeyCode::Backspace => {
+            app.archive_query.pop();
+            app.archive_selected = 0;
+        }
+        KeyCode::Esc if !app.archive_query.is_empty() => {
+            app.archive_query.clear();
+            app.archive_selected = 0;
+        }
+        KeyCode::Up => app.archive_selected = app.archive_selected.saturating_sub(1),
+        KeyCode::Down => {
+            let last = archive_result_count(app).saturating_sub(1);
+            app.archive_selected = (app.archive_selected + 1).min(last);
+        }
+        _ => return None,
+    }
+    Some(true)
+}
+
+/// Append pasted text to the Dispute Archive search (control characters dropped).
+/// No-op unless the search box is editable.
+pub fn paste_into_archive_search(app: &mut AppState, text: &str) -> bool {
+    if !app.archive_inputs_editable() {
+        return false;
+    }
+    let filtered: String = text.chars().filter(|c| !c.is_control()).collect();
+    if filtered.is_empty() {
+        return false;
+    }
+    app.archive_query.push_str(&filtered);
+    app.archive_selected = 0;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{AdminTab, Tab, UiMode, UserRole};
+
+    fn press(app: &mut AppState, code: KeyCode) -> Option<bool> {
+        handle_dispute_archive_key(app, &KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn archive_search_owns_typing_and_esc_only_while_editable() {
+        let mut app = AppState::new(UserRole::Admin);
+        assert_eq!(press(&mut app, KeyCode::Char('q')), None);
+
+        app.active_tab = Tab::Admin(AdminTab::DisputeArchive);
+        for c in "fiat:usd".chars() {
+            assert_eq!(press(&mut app, KeyCode::Char(c)), Some(true));
+        }
+        press(&mut app, KeyCode::Backspace);
+        assert_eq!(app.archive_query, "fiat:us");
+        assert_eq!(press(&mut app, KeyCode::Left), None);
+
+        assert_eq!(press(&mut app, KeyCode::Esc), Some(true));
+        assert!(app.archive_query.is_empty());
+        // Empty search: Esc falls through to the global handler.
+        assert_eq!(press(&mut app, KeyCode::Esc), None);
+
+        app.mode = UiMode::HelpPopup(app.active_tab, Box::new(app.mode.clone()));
+        assert_eq!(press(&mut app, KeyCode::Char('x')), None);
+        assert!(!paste_into_archive_search(&mut app, "npub1"));
+    }
+}
@@ -1,6 +1,8 @@
+use crate::i18n::tr;
 use crate::models::{Order, ORDER_HISTORY_BULK_DELETE_STATUSES};
 use crate::shared::permissions::SolverPermission;
 use crate::ui::admin_state::AddSolverState;
+use crate::ui::backup_popup::BackupFormState;
 use crate::ui::helpers::{
     build_active_order_chat_list, save_order_chat_message, save_user_dispute_chat_message,
     selected_filtered_book_order, selected_filtered_dispute, selected_pending_dispute,
@@ -17,6 +19,8 @@ use crate::ui::orders::{
     message_action_compact_label_for_message, order_message_to_waiting_notification,
     strip_new_order_messages_and_clamp_selected,
 };
+use crate::ui::saved_search_alert::jump_to_book_order;
+use crate::ui::settings_reload::apply_settings_file_change;
 use crate::ui::{
     order_message_to_notification, AdminMode, AdminTab, AppState, ChatParty, InvoiceInputState,
     InvoiceNotificationActionSelection, MessageViewState, OperationResult, RatingOrderState, Tab,
@@ -36,7 +40,7 @@ use bip39::Mnemonic;
 use mostro_core::prelude::*;
 use nostr_sdk::prelude::FromMnemonic;
 use nostr_sdk::prelude::ToBech32;
-use nostr_sdk::prelude::{Keys, PublicKey, SecretKey};
+use nostr_sdk::prelude::{Keys, PublicKey, Timestamp};
 use std::collections::HashSet;
 use std::str::FromStr;
 
@@ -52,24 +56,29 @@ use crate::ui::key_handler::message_handlers::{
     submit_add_invoice,
 };
 use crate::ui::key_handler::settings::{
-    clear_currency_filters, clear_ln_address_from_settings, handle_mode_switch,
-    save_currency_to_settings, save_mostro_pubkey_to_settings, save_relay_to_settings,
-    validate_ln_address_format,
+    clear_currency_filters, clear_ln_address_from_settings, handle_automation_toggle,
+    handle_language_cycle, handle_mode_switch, handle_theme_cycle, save_currency_to_settings,
+    save_mostro_pubkey_to_settings, save_relay_to_settings, validate_ln_address_format,
 };
 use crate::ui::key_handler::validation::{
     normalize_mostro_pubkey, validate_currency, validate_relay,
 };
 use crate::ui::tabs::settings_tab::{settings_action_for_index, SettingsMenuAction};
+use crate::util::backup::{
+    backup_data_dir, confirm_staged_restore, default_backup_path, discard_staged_restore,
+};
 use crate::util::chat_utils::{
-    derive_shared_keys, fetch_observer_chat, keys_from_shared_hex, observer_known_signer_roles,
-    send_user_order_chat_message_via_shared_key,
+    fetch_observer_chat, observer_known_signer_roles, send_user_order_chat, stream_observer_chat,
 };
 use crate::util::dm_utils::{apply_saved_ln_address_invoice_choice, present_add_invoice_popup};
 use crate::util::order_utils::BondSlashChoice;
+use crate::util::{spawn_retention_preview_task, spawn_retention_purge_task, RetentionPolicy};
 
 fn invoice_popup_action_for_message_action(action: &Action) -> Option<Action> {
     match action {
-        Action::AddInvoice | Action::WaitingBuyerInvoice => Some(Action::AddInvoice),
+        Action::AddInvoice | Action::WaitingBuyerInvoice | Action::PaymentFailed => {
+            Some(Action::AddInvoice)
+        }
         Action::AddBondInvoice => Some(Action::AddBondInvoice),
         Action::PayInvoice | Action::WaitingSellerToPay => Some(Action::PayInvoice),
         Action::PayBondInvoice => Some(Action::PayBondInvoice),
@@ -102,9 +111,9 @@ struct DisputeChatTarget {
 }
 
 #[derive(Clone)]
-struct OrderChatTarget {
-    order_id: String,
-    channel: UserChatChannel,
+pub struct OrderChatTarget {
+    pub order_id: String,
+    pub channel: UserChatChannel,
 }
 
 struct EnterChatSendConfig {
@@ -169,7 +178,9 @@ fn resolve_selected_order_chat_target(app: &AppState) -> Option<OrderChatTarget>
         })
 }
 
-fn persist_local_user_chat_message(
+/// Save an outgoing user chat message locally and append it to the open conversation.
+/// Returns `false` (with an error popup) when the local write failed; the caller must not send.
+pub fn persist_local_user_chat_message(
     app: &mut AppState,
     target: &OrderChatTarget,
     local_msg: UserOrderChatMessage,
@@ -214,49 +225,11 @@ fn spawn_user_order_chat_send_task(
     let pool = ctx.pool.clone();
     let mostro_info = ctx.mostro_info.clone();
     tokio::spawn(async move {
-        let order = match Order::get_by_id(&pool, &order_id).await {
-            Ok(o) => o,
-            Err(e) => {
-                log::warn!("order chat send skipped (order not found): {}", e);
-                return;
-            }
-        };
-        let trade_sk = match order
-            .trade_keys
-            .as_deref()
-            .and_then(|h| SecretKey::from_str(h).ok())
-        {
-            Some(sk) => sk,
-            None => return,
-        };
-        let trade_keys = Keys::new(trade_sk);
-        let shared_keys = match channel {
-            UserChatChannel::Peer => order
-                .order_chat_shared_key_hex
-                .as_deref()
-                .and_then(keys_from_shared_hex)
-                .or_else(|| {
-                    let cp = order.counterparty_pubkey.as_deref()?;
-                    let pk = PublicKey::parse(cp).ok()?;
-                    derive_shared_keys(Some(&trade_keys), Some(&pk))
-                }),
-            UserChatChannel::Solver => order
-                .dispute_chat_shared_key_hex
-                .as_deref()
-                .and_then(keys_from_shared_hex)
-                .or_else(|| {
-                    let solver = order.solver_pubkey.as_deref()?;
-                    let pk = PublicKey::parse(solver).ok()?;
-                    derive_shared_keys(Some(&trade_keys), Some(&pk))
-                }),
-        };
-        let Some(shared_keys) = shared_keys else {
-            return;
-        };
-        if let Err(e) = send_user_order_chat_message_via_shared_key(
+        if let Err(e) = send_user_order_chat(
+            &pool,
             &client,
-            &trade_keys,
-            &shared_keys,
+            &order_id,
+            channel,
             &content,
             mostro_info.as_ref(),
         )
@@ -499,14 +472,24 @@ pub fn handle_enter_key(app: &mut AppState, ctx: &super::EnterKeyContext<'_>) ->
 
                 // Auto-submit: send to Mostro immediately (one-Enter flow).
                 // `UseSavedLnAddress` is recorded only after successful send (`InvoiceSubmitted` → main loop).
-                submit_add_invoice(app, ctx, order_id, trimmed, Some(order_id));
+                submit_add_invoice(
+                    app,
+                    ctx,
+                    order_id,
+                    trimmed,
+                    notification.sat_amount,
+                    Some(order_id),
+                );
             } else {
                 apply_saved_ln_address_invoice_choice(app, notification, false);
             }
             true
         }
-        UiMode::HelpPopup(..) | UiMode::SettingsInstructionsPopup(..) => {
-            // Close help / settings reference (mode restored in key_handler/mod.rs)
+        UiMode::HelpPopup(..)
+        | UiMode::SettingsInstructionsPopup(..)
+        | UiMode::CommandPalette(..)
+        | UiMode::ImagePreview(..) => {
+            // Close help / settings reference / preview, run palette entry (key_handler/mod.rs)
             true
         }
         UiMode::SaveAttachmentPopup(_) => {
@@ -523,8 +506,8 @@ pub fn handle_enter_key(app: &mut AppState, ctx: &super::EnterKeyContext<'_>) ->
             app.mode = UiMode::UserMode(UserMode::Normal);
             true
         }
-        UiMode::UserSendAttachmentPicker(_) => {
-            // Enter handled in key_handler/mod.rs while picker is open
+        UiMode::UserSendAttachmentPicker(_) | UiMode::UserAttachmentSendConfirm(_) => {
+            // Enter handled in key_handler/mod.rs while picker / send confirmation is open
             true
         }
         UiMode::OperationResult(_) => {
@@ -549,13 +532,7 @@ pub fn handle_enter_key(app: &mut AppState, ctx: &super::EnterKeyContext<'_>) ->
             true
         }
         UiMode::NewMessageNotification(notification, action, mut invoice_state) => {
-            handle_enter_message_notification(
-                app,
-                ctx,
-                &action,
-                &mut invoice_state,
-                notification.order_id,
-            );
+            handle_enter_message_notification(app, ctx, &action, &mut invoice_state, &notification);
             // Mode is updated inside handle_enter_message_notification
             true
         }
@@ -619,6 +596,73 @@ pub fn handle_enter_key(app: &mut AppState, ctx: &super::EnterKeyContext<'_>) ->
             }
             true
         }
+        UiMode::RetentionPreview(plan, selected_button) => {
+            if selected_button {
+                app.mode = UiMode::operation_result(OperationResult::Info(
+                    "Purging expired trades and files...".to_string(),
+                ));
+                spawn_retention_purge_task(ctx.pool.clone(), *plan, ctx.order_result_tx.clone());
+            } else {
+                app.mode = default_mode;
+            }
+            true
+        }
+        UiMode::SavedSearchAlert(found, selected_button) => {
+            app.mode = default_mode;
+            let Some(order_id) = found.order.id.filter(|_| selected_button) else {
+                return true;
+            };
+            let note = match ctx.orders.lock() {
+                Ok(orders) => jump_to_book_order(app, &orders, order_id),
+                Err(_) => Some("Order book unavailable (lock poisoned).".to_string()),
+            };
+            if let Some(note) = note {
+                app.mode = UiMode::operation_result(OperationResult::Info(note));
+            }
+            true
+        }
+        UiMode::ConfirmSettingsReload(change, selected_button) => {
+            app.mode = default_mode;
+            if !selected_button {
+                return true;
+            }
+            let message = apply_settings_file_change(app, &change);
+            if change.changed("mostro_pubkey") {
+                if let Ok(pubkey) = PublicKey::from_str(&change.settings.mostro_pubkey) {
+                    spawn_refresh_mostro_info_task(
+                        ctx.client.clone(),
+                        pubkey,
+                        ctx.mostro_info_tx.clone(),
+                        false,
+                    );
+                }
+            }
+            app.mode = UiMode::operation_result(OperationResult::Info(message));
+            true
+        }
+        UiMode::BackupForm(state) => {
+            // Typing and submit are handled in key_handler/backup.rs
+            app.mode = UiMode::BackupForm(state);
+            true
+        }
+        UiMode::ConfirmRestoreBackup(staged, selected_button) => {
+            let Some(data_dir) = backup_data_dir() else {
+                app.mode = UiMode::operation_result(OperationResult::Error(
+                    "Cannot locate the ~/.mostrix data directory.".to_string(),
+                ));
+                return true;
+            };
+            let result = if selected_button {
+                confirm_staged_restore(&data_dir).map(|()| staged.message())
+            } else {
+                discard_staged_restore(&data_dir).map(|()| tr("backup.canceled").to_string())
+            };
+            app.mode = UiMode::operation_result(match result {
+                Ok(message) => OperationResult::Info(message),
+                Err(e) => OperationResult::Error(format!("Restore failed: {e}")),
+            });
+            true
+        }
         UiMode::ConfirmGenerateNewKeys(selected_button) => {
             if !selected_button {
                 // NO: just close warning popup.
@@ -1050,7 +1094,7 @@ fn handle_enter_normal_mode(app: &mut AppState, ctx: &super::EnterKeyContext<'_>
                 .filter(|id| is_my_pending_book_order(app, *id, order.status))
             {
                 drop(orders_lock);
-                let msg = crate::ui::constants::HELP_ORDERS_CANCEL_PENDING_MSG;
+                let msg = tr(crate::ui::constants::HELP_ORDERS_CANCEL_PENDING_MSG);
                 let view_state =
                     build_order_action_view_state(order_id, Action::Cancel, msg.to_string());
                 app.mode = UiMode::ViewingMessage(view_state);
@@ -1104,7 +1148,7 @@ fn handle_enter_normal_mode(app: &mut AppState, ctx: &super::EnterKeyContext<'_>
                         invoice_input: String::new(),
                         focused: false,
                         just_pasted: false,
-                        copied_to_clipboard: false,
+                        copied_to_clipboard: None,
                         scroll_y: 0,
                         action_selection: InvoiceNotificationActionSelection::Primary,
                     };
@@ -1225,12 +1269,23 @@ fn handle_enter_normal_mode(app: &mut AppState, ctx: &super::EnterKeyContext<'_>
             return;
         }
 
-        // No UI field feeds an optional Signer pubkey locator at the moment, so the
-        // `authors` filter in `fetch_observer_chat` stays unrestricted.
-        let sign_pubkey = None;
+        // Optional Sign pubkey locator narrows the filter with `authors`.
+        let sign_pubkey = match crate::util::chat_utils::parse_optional_sign_pubkey(
+            &app.observer_sign_pubkey_input,
+        ) {
+            Ok(pk) => pk,
+            Err(e) => {
+                let msg = e.to_string();
+                app.observer_error = Some(msg.clone());
+                app.mode = UiMode::operation_result(OperationResult::Error(msg));
+                return;
+            }
+        };
 
-        // Spawn async fetch via the order_result channel
+        // Spawn async fetch via the order_result channel, then keep a live subscription
+        // open (until the tab is left or the fetch is restarted) appending new messages.
         let generation = app.begin_observer_fetch();
+        let stop = app.observer_live_stop_signal();
         let client = ctx.client.clone();
         let admin_pubkey = ctx.admin_chat_keys.map(|k| k.public_key());
         let known_roles =
@@ -1238,20 +1293,37 @@ fn handle_enter_normal_mode(app: &mut AppState, ctx: &super::EnterKeyContext<'_>
         let tx = ctx.order_result_tx.clone();
 
         tokio::spawn(async move {
+            let since = Timestamp::now();
             match fetch_observer_chat(&client, &key_str, sign_pubkey, &known_roles).await {
-                Ok(messages) => {
-                    let _ = tx.send(OperationResult::ObserverChatLoaded {
-                        generation,
-                        messages,
-                    });
+                Ok(chat) => {
+                    let _ = tx.send(OperationResult::ObserverChatLoaded { generation, chat });
                 }
                 Err(e) => {
                     let _ = tx.send(OperationResult::ObserverChatError {
                         generation,
                         message: e.to_string(),
                     });
+                    return;
                 }
             }
+            let live = stream_observer_chat(
+                &client,
+                &key_str,
+                sign_pubkey,
+                &known_roles,
+                since,
+                stop,
+                |chat| {
+                    let _ = tx.send(OperationResult::ObserverChatAppended { generation, chat });
+                },
+            )
+            .await;
+            if let Err(e) = live {
+                let _ = tx.send(OperationResult::ObserverChatError {
+                    generation,
+                    message: e.to_string(),
+                });
+            }
         });
     } else if matches!(
         app.active_tab,
@@ -1283,6 +1355,33 @@ fn handle_enter_normal_mode(app: &mut AppState, ctx: &super::EnterKeyContext<'_>
             Some(SettingsMenuAction::ClearCurrencyFilters) => {
                 app.mode = UiMode::ConfirmClearCurrencies(true);
             }
+            Some(SettingsMenuAction::ChangeTheme) => handle_theme_cycle(app),
+            Some(SettingsMenuAction::ChangeLanguage) => handle_language_cycle(),
+            Some(SettingsMenuAction::ToggleAutomation) => handle_automation_toggle(app),
+            Some(SettingsMenuAction::DataRetention) => {
+                let policy = match load_settings_from_disk() {
+                    Ok(settings) => RetentionPolicy::from_settings(&settings),
+                    Err(e) => {
+                        app.mode = UiMode::operation_result(OperationResult::Error(format!(
+                            "Failed to read settings: {e}"
+                        )));
+                        return;
+                    }
+                };
+                spawn_retention_preview_task(ctx.pool.clone(), policy, ctx.order_result_tx.clone());
+                app.mode = UiMode::operation_result(OperationResult::Info(
+                    "Building retention preview...".to_string(),
+                ));
+            }
+            Some(SettingsMenuAction::CreateBackup) => {
+                let default_path = default_backup_path(chrono::Local::now())
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_default();
+                app.mode = UiMode::BackupForm(BackupFormState::create(default_path));
+            }
+            Some(SettingsMenuAction::RestoreBackup) => {
+                app.mode = UiMode::BackupForm(BackupFormState::restore());
+            }
             Some(SettingsMenuAction::ViewSeedWords) => {
                 spawn_load_seed_words_task(ctx.pool.clone(), ctx.seed_words_tx.clone());
                 app.mode = UiMode::operation_result(OperationResult::Info(
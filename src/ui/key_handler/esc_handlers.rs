@@ -32,6 +32,8 @@ pub fn handle_esc_key(app: &mut AppState) -> bool {
             true
         }
         UiMode::HelpPopup(_, ref previous_mode)
+        | UiMode::CommandPalette(_, ref previous_mode)
+        | UiMode::ImagePreview(_, ref previous_mode)
         | UiMode::SettingsInstructionsPopup(_, ref previous_mode) => {
             app.mode = (**previous_mode).clone();
             true
@@ -52,6 +54,10 @@ pub fn handle_esc_key(app: &mut AppState) -> bool {
             crate::ui::send_attachment_picker::close_user_send_attachment_picker(app);
             true
         }
+        UiMode::UserAttachmentSendConfirm(_) => {
+            crate::ui::send_attachment_picker::cancel_user_attachment_send(app);
+            true
+        }
         UiMode::OperationResult(_) => {
             if app.fatal_exit_on_close {
                 return false;
@@ -162,7 +168,21 @@ pub fn handle_esc_key(app: &mut AppState) -> bool {
             app.mode = default_mode.clone();
             true
         }
-        UiMode::ConfirmDeleteHistoryOrder(_, _) | UiMode::ConfirmBulkDeleteHistory(_) => {
+        UiMode::ConfirmDeleteHistoryOrder(_, _)
+        | UiMode::ConfirmBulkDeleteHistory(_)
+        | UiMode::RetentionPreview(_, _)
+        | UiMode::SavedSearchAlert(_, _)
+        | UiMode::ConfirmSettingsReload(_, _)
+        | UiMode::BackupForm(_) => {
+            app.mode = default_mode.clone();
+            true
+        }
+        UiMode::ConfirmRestoreBackup(_, _) => {
+            if let Some(data_dir) = crate::util::backup::backup_data_dir() {
+                if let Err(e) = crate::util::backup::discard_staged_restore(&data_dir) {
+                    log::warn!("Could not discard staged restore: {e}");
+                }
+            }
             app.mode = default_mode.clone();
             true
         }
@@ -1,6 +1,7 @@
 use crate::ui::currencies::{filter_options, resolve_options};
 use crate::ui::orders::FormField;
 use crate::ui::{AppState, FormState, TakeOrderState, UiMode, UserMode};
+use crate::util::payment_methods::{payment_methods, toggle_in_list};
 use crossterm::event::KeyCode;
 
 /// True when the create-order form has a text-editable field focused (not buy/sell toggle).
@@ -132,6 +133,97 @@ fn close_currency_picker(form: &mut FormState) {
     form.currency_picker.selected = 0;
 }
 
+/// Intercept keys for the payment method multi-select on the Create New Order form.
+///
+/// Closed: Enter/Space or typing opens it, Backspace drops the last chosen method.
+/// Open: typing filters the catalog suggestions for the form's currency, Space toggles the
+/// highlighted method, Enter adds it (or the typed name when nothing matches) and closes.
+/// Space is typed into the name when nothing matches, so custom names can have spaces.
+pub fn handle_payment_method_picker_key(code: KeyCode, app: &mut AppState) -> Option<bool> {
+    let form = match &mut app.mode {
+        UiMode::UserMode(UserMode::CreatingOrder(form))
+            if form.focused == FormField::PaymentMethod =>
+        {
+            form
+        }
+        _ => return None,
+    };
+
+    if !form.payment_picker.open {
+        return match code {
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                open_payment_picker(form);
+                Some(true)
+            }
+            KeyCode::Char(c) if !c.is_control() => {
+                open_payment_picker(form);
+                form.payment_picker.filter.push(c);
+                Some(true)
+            }
+            KeyCode::Backspace => {
+                let mut names: Vec<&str> = form.payment_method.split(',').collect();
+                names.pop();
+                form.payment_method = names.join(",");
+                Some(true)
+            }
+            _ => None,
+        };
+    }
+
+    let catalog = payment_methods();
+    let options = catalog.suggestions(&form.fiat_code, &form.payment_picker.filter);
+    let n = options.len();
+    let highlighted = options
+        .get(form.payment_picker.selected.min(n.saturating_sub(1)))
+        .copied();
+    let typed = catalog.canonical(&form.payment_picker.filter);
+    match code {
+        KeyCode::Up if n > 0 => {
+            form.payment_picker.selected = (form.payment_picker.selected + n - 1) % n;
+        }
+        KeyCode::Down if n > 0 => {
+            form.payment_picker.selected = (form.payment_picker.selected + 1) % n;
+        }
+        // Space toggles the highlighted method; with no match it is part of a typed name.
+        KeyCode::Char(' ') => match highlighted {
+            Some(name) => form.payment_method = toggle_in_list(&form.payment_method, name),
+            None if !typed.is_empty() => form.payment_picker.filter.push(' '),
+            None => {}
+        },
+        KeyCode::Enter => {
+            let choice = highlighted.map(str::to_string).unwrap_or(typed);
+            if !choice.is_empty() && !catalog.list_contains(&form.payment_method, &choice) {
+                form.payment_method = toggle_in_list(&form.payment_method, &choice);
+            }
+            close_payment_picker(form);
+        }
+        KeyCode::Esc => close_payment_picker(form),
+        KeyCode::Backspace => {
+            form.payment_picker.filter.pop();
+            form.payment_picker.selected = 0;
+        }
+        KeyCode::Char(c) if !c.is_control() => {
+            form.payment_picker.filter.push(c);
+            form.payment_picker.selected = 0;
+        }
+        // Swallow everything else so the overlay stays modal.
+        _ => {}
+    }
+    Some(true)
+}
+
+fn open_payment_picker(form: &mut FormState) {
+    form.payment_picker.open = true;
+    form.payment_picker.filter.clear();
+    form.payment_picker.selected = 0;
+}
+
+fn close_payment_picker(form: &mut FormState) {
+    form.payment_picker.open = false;
+    form.payment_picker.filter.clear();
+    form.payment_picker.selected = 0;
+}
+
 /// Accept a typed ISO-4217 code (exactly three ASCII letters) when the instance
 /// advertises an empty accepted list (meaning all currencies).
 fn custom_currency_code(filter: &str) -> Option<String> {
@@ -165,10 +257,6 @@ pub fn handle_char_input(
                         // Toggle range mode
                         form.use_range = !form.use_range;
                     }
-                    FormField::PaymentMethod => {
-                        // Payment method descriptions may contain spaces.
-                        form.payment_method.push(' ');
-                    }
                     _ => {}
                 }
             }
@@ -251,7 +339,11 @@ pub fn handle_backspace(app: &mut AppState, validate_range_amount: &dyn Fn(&mut
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ui::{FormState, UserRole};
+    use crate::ui::key_handler::user_handlers::handle_enter_creating_order;
+    use crate::ui::order_form::has_fractional_fiat;
+    use crate::ui::{FormState, Tab, UserRole, UserTab};
+    use crate::util::order_utils::{range_take_amount, validate_range_amount};
+    use mostro_core::prelude::SmallOrder;
 
     #[test]
     fn creating_order_text_input_excludes_order_type_toggle() {
@@ -267,6 +359,49 @@ mod tests {
         assert!(!is_creating_order_text_input(&app));
     }
 
+    fn payment_form(app: &AppState) -> &FormState {
+        match &app.mode {
+            UiMode::UserMode(UserMode::CreatingOrder(form)) => form,
+            other => panic!("expected CreatingOrder, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn payment_picker_toggles_suggestions_and_adds_typed_names() {
+        let mut app = AppState::new(UserRole::User);
+        let mut form = FormState::new_default_form();
+        form.focused = FormField::PaymentMethod;
+        app.mode = UiMode::UserMode(UserMode::CreatingOrder(form));
+        let mut press = |code| handle_payment_method_picker_key(code, &mut app);
+
+        // Typing opens the picker filtered to "zel" → Zelle (USD form).
+        for c in "zel".chars() {
+            assert_eq!(press(KeyCode::Char(c)), Some(true));
+        }
+        assert_eq!(press(KeyCode::Char(' ')), Some(true));
+        for _ in 0..3 {
+            press(KeyCode::Backspace);
+        }
+        for c in "paypal".chars() {
+            press(KeyCode::Char(c));
+        }
+        assert_eq!(press(KeyCode::Enter), Some(true));
+        assert_eq!(payment_form(&app).payment_method, "Zelle,PayPal");
+        assert!(!payment_form(&app).payment_picker.open);
+
+        // Unknown names are added as typed; Backspace while closed drops the last one.
+        let mut press = |code| handle_payment_method_picker_key(code, &mut app);
+        press(KeyCode::Enter);
+        for c in "my  coop".chars() {
+            press(KeyCode::Char(c));
+        }
+        press(KeyCode::Enter);
+        assert_eq!(payment_form(&app).payment_method, "Zelle,PayPal,my coop");
+        let mut press = |code| handle_payment_method_picker_key(code, &mut app);
+        press(KeyCode::Backspace);
+        assert_eq!(payment_form(&app).payment_method, "Zelle,PayPal");
+    }
+
     #[test]
     fn custom_currency_code_accepts_three_letter_iso() {
         assert_eq!(custom_currency_code("kwd").as_deref(), Some("KWD"));
@@ -324,4 +459,53 @@ mod tests {
             other => panic!("expected CreatingOrder, got {other:?}"),
         }
     }
+
+    #[test]
+    fn fractional_fiat_input_is_kept_and_blocks_submit() {
+        let mut app = AppState::new(UserRole::User);
+        app.active_tab = Tab::User(UserTab::CreateNewOrder);
+        let mut form = FormState::new_default_form();
+        form.focused = FormField::FiatAmount;
+        form.fiat_code = "EUR".to_string();
+        app.mode = UiMode::UserMode(UserMode::CreatingOrder(form));
+        for c in "12.5".chars() {
+            handle_char_input(KeyCode::Char(c), &mut app, &|_| {});
+        }
+        let form = payment_form(&app).clone();
+        assert_eq!(form.fiat_amount, "12.5");
+        assert!(has_fractional_fiat(&form));
+        handle_enter_creating_order(&mut app, &form);
+        assert!(matches!(
+            app.mode,
+            UiMode::UserMode(UserMode::CreatingOrder(_))
+        ));
+
+        app.mode = UiMode::UserMode(UserMode::TakingOrder(TakeOrderState {
+            order: SmallOrder {
+                fiat_code: "EUR".to_string(),
+                min_amount: Some(10),
+                max_amount: Some(20),
+                ..Default::default()
+            }
+            .into(),
+            amount_input: String::new(),
+            is_range_order: true,
+            validation_error: None,
+            selected_button: true,
+        }));
+        for c in "15.5".chars() {
+            handle_char_input(KeyCode::Char(c), &mut app, &validate_range_amount);
+        }
+        match &app.mode {
+            UiMode::UserMode(UserMode::TakingOrder(take)) => {
+                assert_eq!(take.amount_input, "15.5");
+                assert_eq!(
+                    take.validation_error.as_deref(),
+                    Some("Mostro takes whole EUR amounts only")
+                );
+                assert_eq!(range_take_amount(take), None);
+            }
+            other => panic!("expected TakingOrder, got {other:?}"),
+        }
+    }
 }
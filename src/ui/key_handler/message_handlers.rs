@@ -37,8 +37,15 @@ pub fn submit_add_invoice(
     ctx: &EnterKeyContext<'_>,
     order_id: Uuid,
     invoice_input: String,
+    expected_sats: Option<i64>,
     remember_buyer_saved_ln_address_on_success: Option<Uuid>,
 ) {
+    // `--demo`: nothing can pay a real invoice, so an empty popup submits a simulated one.
+    let invoice_input = if invoice_input.trim().is_empty() && crate::demo::is_demo_mode() {
+        crate::demo::demo_invoice(expected_sats.unwrap_or(1), "mostrix demo payout")
+    } else {
+        invoice_input
+    };
     if invoice_input.trim().is_empty() {
         let _ = ctx.order_result_tx.send(OperationResult::Error(
             "Invoice cannot be empty".to_string(),
@@ -60,6 +67,7 @@ pub fn submit_add_invoice(
         match execute_add_invoice(
             &order_id,
             &invoice_input,
+            expected_sats,
             &pool_clone,
             &client_clone,
             mostro_pubkey,
@@ -87,6 +95,7 @@ pub fn submit_add_bond_invoice(
     ctx: &EnterKeyContext<'_>,
     order_id: Uuid,
     invoice_input: String,
+    expected_sats: Option<i64>,
 ) {
     if invoice_input.trim().is_empty() {
         let _ = ctx.order_result_tx.send(OperationResult::Error(
@@ -108,6 +117,7 @@ pub fn submit_add_bond_invoice(
         match execute_add_bond_invoice(
             &order_id,
             &invoice_input,
+            expected_sats,
             &pool_clone,
             &client_clone,
             mostro_pubkey,
@@ -372,10 +382,20 @@ pub fn handle_enter_message_notification(
     ctx: &EnterKeyContext<'_>,
     action: &mostro_core::prelude::Action,
     invoice_state: &mut crate::ui::InvoiceInputState,
-    order_id: Option<Uuid>,
+    notification: &crate::ui::MessageNotification,
 ) {
+    let order_id = notification.order_id;
+    let expected_sats = notification.sat_amount;
     match action {
         Action::AddInvoice => {
+            // After `PaymentFailed` the hold invoice is already settled, so the order can no
+            // longer be canceled: the secondary button just waits for Mostro's next retry.
+            if matches!(notification.action, Action::PaymentFailed)
+                && should_send_cancel_from_invoice_popup(invoice_state.action_selection)
+            {
+                app.mode = role_default_mode(app.user_role);
+                return;
+            }
             if should_send_cancel_from_invoice_popup(invoice_state.action_selection) {
                 spawn_cancel_from_notification(app, ctx, order_id);
                 return;
@@ -393,6 +413,7 @@ pub fn handle_enter_message_notification(
                 ctx,
                 order_id,
                 invoice_state.invoice_input.clone(),
+                expected_sats,
                 None,
             );
         }
@@ -409,7 +430,13 @@ pub fn handle_enter_message_notification(
                 return;
             };
 
-            submit_add_bond_invoice(app, ctx, order_id, invoice_state.invoice_input.clone());
+            submit_add_bond_invoice(
+                app,
+                ctx,
+                order_id,
+                invoice_state.invoice_input.clone(),
+                expected_sats,
+            );
         }
         Action::PayInvoice => {
             if should_send_cancel_from_invoice_popup(invoice_state.action_selection) {
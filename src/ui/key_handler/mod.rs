@@ -1,35 +1,46 @@
 mod admin_handlers;
 mod async_tasks;
+mod backup;
 mod chat_helpers;
+mod command_palette;
 mod confirmation;
+mod dispute_archive;
 mod enter_handlers;
 mod esc_handlers;
 mod form_input;
 mod input_helpers;
 mod message_handlers;
+mod mouse;
 mod navigation;
 mod settings;
 mod user_handlers;
 mod validation;
 
+use crate::i18n::tr;
+use crate::models::AdminDispute;
+use crate::ui::image_preview_popup::open_image_preview;
 use crate::ui::key_handler::chat_helpers::{
     build_order_action_view_state, build_rating_state_for_mytrades,
     resolve_selected_mytrades_order_status,
 };
+use crate::ui::key_handler::command_palette::{handle_command_palette_key, open_command_palette};
 use crate::ui::{
     helpers::{
-        active_order_chat_list_snapshot, get_order_attachment_messages,
+        active_order_chat_list_snapshot, cycle_book_payment_filter, get_order_attachment_messages,
         get_visible_attachment_messages, is_dispute_finalized, selected_filtered_dispute,
     },
     send_attachment_picker::{
-        close_user_send_attachment_picker, explorer_selection_is_sendable_file,
-        open_user_send_attachment_picker,
+        cancel_user_attachment_send, close_user_send_attachment_picker,
+        explorer_selection_is_sendable_file, open_user_send_attachment_picker,
     },
-    AdminMode, AdminTab, AppState, ChatAttachment, ChatSender, DisputeFilter,
+    AdminMode, AdminTab, AppState, ChatAttachment, ChatAttachmentType, ChatSender, DisputeFilter,
     InvoiceNotificationActionSelection, LnAddressVerifyResult, MostroInfoFetchResult,
     OperationResult, Tab, TakeOrderState, UiMode, UserChatChannel, UserMode, UserTab,
     ViewingMessageButtonSelection,
 };
+use crate::util::clipboard::{clipboard_mode, copy_osc52, ClipboardBackend, ClipboardMode};
+use crate::util::fiat::BookOrder;
+use crate::util::image_preview::{send_image_preview, spawn_image_preview};
 use crate::util::{MostroInstanceInfo, OrderDmSubscriptionCmd, SendOrderAttachmentJob};
 use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind};
 use mostro_core::prelude::*;
@@ -41,7 +52,7 @@ use zeroize::Zeroizing;
 
 /// Context passed to Enter and confirmation handlers to avoid too many arguments.
 pub struct EnterKeyContext<'a> {
-    pub orders: &'a Arc<Mutex<Vec<SmallOrder>>>,
+    pub orders: &'a Arc<Mutex<Vec<BookOrder>>>,
     pub disputes: &'a Arc<Mutex<Vec<Dispute>>>,
     pub pool: &'a SqlitePool,
     pub client: &'a Client,
@@ -102,12 +113,18 @@ fn trade_action_shortcut_next_mode(
     }
     let (order_id, status) = selected?;
     let (label, confirm_msg) = match action {
-        Action::Cancel => ("Cancel", crate::ui::constants::HELP_MY_TRADES_CANCEL_MSG),
+        Action::Cancel => (
+            "Cancel",
+            tr(crate::ui::constants::HELP_MY_TRADES_CANCEL_MSG),
+        ),
         Action::FiatSent => (
             "FiatSent",
-            crate::ui::constants::HELP_MY_TRADES_FIAT_SENT_MSG,
+            tr(crate::ui::constants::HELP_MY_TRADES_FIAT_SENT_MSG),
+        ),
+        Action::Release => (
+            "Release",
+            tr(crate::ui::constants::HELP_MY_TRADES_RELEASE_MSG),
         ),
-        Action::Release => ("Release", crate::ui::constants::HELP_MY_TRADES_RELEASE_MSG),
         _ => return None,
     };
     if is_terminal_order_status(status) {
@@ -143,13 +160,13 @@ fn dispute_shortcut_next_mode(
     }
     if !can_dispute_order_status(status) {
         return Some(UiMode::operation_result(OperationResult::Info(
-            crate::ui::constants::HELP_MY_TRADES_DISPUTE_UNAVAILABLE.to_string(),
+            tr(crate::ui::constants::HELP_MY_TRADES_DISPUTE_UNAVAILABLE).to_string(),
         )));
     }
     let view_state = build_order_action_view_state(
         order_id,
         Action::Dispute,
-        crate::ui::constants::HELP_MY_TRADES_DISPUTE_MSG.to_string(),
+        tr(crate::ui::constants::HELP_MY_TRADES_DISPUTE_MSG).to_string(),
     );
     Some(UiMode::ViewingMessage(view_state))
 }
@@ -161,10 +178,13 @@ pub use async_tasks::{
     respawn_trade_dm_listener, spawn_refresh_mostro_info_task, AppChannels,
     RuntimeReconnectContext,
 };
-pub use enter_handlers::handle_enter_key;
+pub use backup::paste_into_backup_form;
+pub use dispute_archive::paste_into_archive_search;
+pub use enter_handlers::{handle_enter_key, persist_local_user_chat_message, OrderChatTarget};
 pub use esc_handlers::handle_esc_key;
 pub use form_input::{handle_backspace, handle_char_input, is_creating_order_text_input};
 pub use input_helpers::{handle_invoice_input, handle_key_input};
+pub use mouse::handle_mouse_event;
 pub use navigation::{handle_navigation, handle_tab_navigation};
 pub use settings::handle_mode_switch;
 pub use validation::{
@@ -279,7 +299,7 @@ fn reset_disclosure_copied_indicator(mode: &mut UiMode, key_event: &KeyEvent) {
                 matches!(key_event.code, KeyCode::Char('c') | KeyCode::Char('C'))
                     && !key_event.modifiers.contains(KeyModifiers::CONTROL);
             if !is_unmodified_copy_key {
-                *copied_to_clipboard = false;
+                *copied_to_clipboard = None;
             }
         }
     }
@@ -299,7 +319,7 @@ fn copy_disclosed_shared_key_if_open(mode: &mut UiMode) -> bool {
 /// system clipboard.
 fn copy_disclosed_shared_key_if_open_with(
     mode: &mut UiMode,
-    copy_fn: impl FnOnce(String) -> bool,
+    copy_fn: impl FnOnce(String) -> Option<ClipboardBackend>,
 ) -> bool {
     if let UiMode::OperationResult(ref mut result) = mode {
         if let OperationResult::ConversationDisclosure {
@@ -395,11 +415,39 @@ fn linux_clipboard_copy_worker(text: String, result_tx: std::sync::mpsc::Sender<
 
 /// Handle clipboard copy for text (invoice, Shared key, etc.)
 ///
-/// Returns whether the write actually succeeded — `copied_to_clipboard` at the
-/// call site should only be set to `true` when this returns `true`, not merely
-/// because a copy attempt was made. On Linux the write runs on a background
-/// thread that keeps serving the selection after the result is reported.
-fn handle_clipboard_copy(text: String) -> bool {
+/// Returns the backend that took the text, or `None` when every allowed backend
+/// failed — `copied_to_clipboard` at the call site stores it, so the popup only
+/// reports a copy that happened and names how. The `clipboard` setting picks the
+/// backend; `auto` tries the local clipboard first and falls back to OSC 52 when
+/// there is none (SSH, headless tmux).
+fn handle_clipboard_copy(text: String) -> Option<ClipboardBackend> {
+    let mode = clipboard_mode();
+    if mode != ClipboardMode::Osc52 {
+        if copy_to_local_clipboard(text.clone()) {
+            return Some(ClipboardBackend::Local);
+        }
+        if mode == ClipboardMode::Local {
+            return None;
+        }
+        log::info!("Local clipboard unavailable; falling back to OSC 52");
+    }
+    match copy_osc52(&text) {
+        Ok(backend) => {
+            log::info!("Copied to clipboard via {}", backend.label());
+            Some(backend)
+        }
+        Err(e) => {
+            log::warn!("Failed to copy to clipboard via OSC 52: {}", e);
+            None
+        }
+    }
+}
+
+/// Copy with the local clipboard (`arboard`); returns whether the write succeeded.
+///
+/// On Linux the write runs on a background thread that keeps serving the
+/// selection after the result is reported.
+fn copy_to_local_clipboard(text: String) -> bool {
     #[cfg(target_os = "linux")]
     {
         let (tx, rx) = std::sync::mpsc::channel();
@@ -476,7 +524,13 @@ fn cycle_finalization_button(
     }
 }
 
+/// Read the local clipboard. With `clipboard = "osc52"` there is none to read: the
+/// terminal's own paste (bracketed paste) delivers the text instead.
 fn read_clipboard_text_best_effort() -> Option<String> {
+    if clipboard_mode() == ClipboardMode::Osc52 {
+        log::debug!("Clipboard read skipped (OSC 52 backend); use the terminal's paste");
+        return None;
+    }
     match arboard::Clipboard::new().and_then(|mut c| c.get_text()) {
         Ok(t) => Some(t),
         Err(e) => {
@@ -560,12 +614,67 @@ fn update_invoice_notification_action_selection(
     }
 }
 
+/// Attachment from an admin dispute chat with its ChaCha key filled from the admin/party ECDH
+/// secret when the sender's JSON omitted an inline key.
+fn admin_attachment_with_key(
+    att: &ChatAttachment,
+    sender: ChatSender,
+    dispute: &AdminDispute,
+    admin_chat_keys: Option<&Keys>,
+) -> ChatAttachment {
+    let mut attachment = att.clone();
+    if attachment.decryption_key.is_none() {
+        let sender_pk = match sender {
+            ChatSender::Buyer => dispute.buyer_pubkey.as_deref(),
+            ChatSender::Seller => dispute.seller_pubkey.as_deref(),
+            ChatSender::Admin => None,
+        }
+        .and_then(|pk| PublicKey::parse(pk).ok());
+        if let (Some(admin_keys), Some(sender_pk)) = (admin_chat_keys, sender_pk) {
+            if let Ok(shared) = crate::util::blossom::derive_shared_key(admin_keys, &sender_pk) {
+                attachment.decryption_key = Some(shared.to_vec());
+            }
+        }
+    }
+    attachment
+}
+
+/// Observer holds K_conv only; use it as the ChaCha key when the attachment JSON omitted an
+/// inline key.
+fn observer_attachment_with_key(app: &AppState, att: &ChatAttachment) -> ChatAttachment {
+    let mut attachment = att.clone();
+    if attachment.decryption_key.is_none() {
+        if let Some(keys) =
+            crate::util::chat_utils::keys_from_shared_hex(&app.observer_shared_key_input)
+        {
+            attachment.decryption_key = Some(keys.secret_key().secret_bytes().to_vec());
+        }
+    }
+    attachment
+}
+
+/// Fill a user chat attachment's missing key from the order row (peer or solver channel).
+async fn fill_user_attachment_key(
+    pool: &SqlitePool,
+    order_id: &str,
+    channel: UserChatChannel,
+    attachment: &mut ChatAttachment,
+) {
+    if attachment.decryption_key.is_some() {
+        return;
+    }
+    if let Ok(order) = crate::models::Order::get_by_id(pool, order_id).await {
+        attachment.decryption_key =
+            crate::util::chat_utils::user_chat_decryption_key_bytes(&order, channel);
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 /// Main key event handler - dispatches to appropriate handlers
 pub fn handle_key_event(
     key_event: KeyEvent,
     app: &mut AppState,
-    orders: &Arc<Mutex<Vec<SmallOrder>>>,
+    orders: &Arc<Mutex<Vec<BookOrder>>>,
     disputes: &Arc<Mutex<Vec<Dispute>>>,
     pool: &SqlitePool,
     client: &Client,
@@ -600,6 +709,51 @@ pub fn handle_key_event(
         return Some(true); // consume all other keys while help is open
     }
 
+    // Image preview popup (V in a save attachment list): close on Esc, Enter, V or q
+    if let UiMode::ImagePreview(_, ref previous_mode) = &app.mode {
+        if matches!(
+            code,
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('v' | 'V' | 'q')
+        ) {
+            app.mode = (**previous_mode).clone();
+        }
+        return Some(true);
+    }
+
+    // Command palette (Ctrl+P): edit the query, or close and replay the chosen entry's key
+    // binding so it runs through the same handlers as the shortcut itself.
+    if matches!(app.mode, UiMode::CommandPalette(..)) {
+        if let Some(replay) = handle_command_palette_key(key_event, app) {
+            return handle_key_event(
+                replay,
+                app,
+                orders,
+                disputes,
+                pool,
+                client,
+                mostro_pubkey,
+                current_mostro_pubkey,
+                order_result_tx,
+                ln_address_result_tx,
+                key_rotation_tx,
+                seed_words_tx,
+                mostro_info_tx,
+                validate_range_amount,
+                admin_chat_keys,
+                save_attachment_tx,
+                send_order_attachment_tx,
+                dm_subscription_tx,
+            );
+        }
+        return Some(true);
+    }
+
+    // Backup / restore form: every key edits the form (passphrases must not trigger shortcuts)
+    if matches!(app.mode, UiMode::BackupForm(_)) {
+        backup::handle_backup_form_key(key_event, app, pool, order_result_tx);
+        return Some(true);
+    }
+
     // Settings instructions (Shift+H): close like help (also Shift+H toggles)
     if let UiMode::SettingsInstructionsPopup(_, ref previous_mode) = &app.mode {
         let shift_h = key_event.modifiers.contains(KeyModifiers::SHIFT)
@@ -667,7 +821,14 @@ pub fn handle_key_event(
         if let Some(text) = read_clipboard_text_best_effort() {
             let filtered: String = text.chars().filter(|c| !c.is_control()).collect();
             if !filtered.is_empty() {
-                app.observer_shared_key_input.push_str(&filtered);
+                app.observer_focused_input_mut().push_str(&filtered);
+                return Some(true);
+            }
+        }
+    }
+    if app.archive_inputs_editable() && is_paste_shortcut(&key_event) {
+        if let Some(text) = read_clipboard_text_best_effort() {
+            if paste_into_archive_search(app, &text) {
                 return Some(true);
             }
         }
@@ -737,25 +898,12 @@ pub fn handle_key_event(
                     let list = get_visible_attachment_messages(app, id);
                     if let Some(msg) = list.get(selected_idx) {
                         if let Some(att) = &msg.attachment {
-                            let mut attachment = att.clone();
-                            if attachment.decryption_key.is_none() {
-                                if let (Some(admin_keys), Some(pk_str)) = (
-                                    admin_chat_keys,
-                                    match msg.sender {
-                                        ChatSender::Buyer => dispute.buyer_pubkey.as_deref(),
-                                        ChatSender::Seller => dispute.seller_pubkey.as_deref(),
-                                        ChatSender::Admin => None,
-                                    },
-                                ) {
-                                    if let Ok(sender_pk) = PublicKey::parse(pk_str) {
-                                        if let Ok(shared) = crate::util::blossom::derive_shared_key(
-                                            admin_keys, &sender_pk,
-                                        ) {
-                                            attachment.decryption_key = Some(shared.to_vec());
-                                        }
-                                    }
-                                }
-                            }
+                            let attachment = admin_attachment_with_key(
+                                att,
+                                msg.sender,
+                                dispute,
+                                admin_chat_keys,
+                            );
                             let _ = tx.send((dispute.dispute_id.clone(), attachment));
                         }
                     }
@@ -763,6 +911,26 @@ pub fn handle_key_event(
                 app.mode = UiMode::AdminMode(AdminMode::ManagingDispute);
                 return Some(true);
             }
+            KeyCode::Char('v' | 'V') => {
+                if let (Some(dispute), Some(id)) =
+                    (selected_dispute.as_ref(), dispute_id_key.as_ref())
+                {
+                    let list = get_visible_attachment_messages(app, id);
+                    let image = list.get(selected_idx).and_then(|msg| {
+                        msg.attachment
+                            .as_ref()
+                            .filter(|att| att.file_type == ChatAttachmentType::Image)
+                            .map(|att| {
+                                admin_attachment_with_key(att, msg.sender, dispute, admin_chat_keys)
+                            })
+                    });
+                    if let Some(attachment) = image {
+                        open_image_preview(app, &attachment);
+                        spawn_image_preview(attachment, order_result_tx.clone());
+                    }
+                }
+                return Some(true);
+            }
             _ => return Some(true), // consume other keys while popup is open
         }
     }
@@ -809,9 +977,30 @@ pub fn handle_key_event(
         }
     }
 
+    // Attachment send confirmation (size + privacy summary): Enter uploads, Esc cancels
+    if matches!(app.mode, UiMode::UserAttachmentSendConfirm(_)) {
+        match code {
+            KeyCode::Enter => {
+                let mode = std::mem::replace(&mut app.mode, UiMode::UserMode(UserMode::Normal));
+                if let UiMode::UserAttachmentSendConfirm(pending) = mode {
+                    match send_order_attachment_tx {
+                        Some(tx) => {
+                            let _ = tx.send(SendOrderAttachmentJob::Confirmed(pending));
+                        }
+                        None => app.sending_attachment_order_id = None,
+                    }
+                }
+            }
+            KeyCode::Esc => cancel_user_attachment_send(app),
+            _ => {}
+        }
+        return Some(true);
+    }
+
     // User order chat save attachment popup: Up/Down to select, Enter to save, Esc to cancel
     if let UiMode::UserSaveAttachmentPopup(ref pinned_order_id, selected_idx) = app.mode {
-        let list_len = get_order_attachment_messages(app, pinned_order_id).len();
+        let channel = app.active_user_chat_channel;
+        let list_len = get_order_attachment_messages(app, pinned_order_id, channel).len();
         match code {
             KeyCode::Esc => {
                 app.mode = UiMode::UserMode(UserMode::Normal);
@@ -835,7 +1024,7 @@ pub fn handle_key_event(
             }
             KeyCode::Enter => {
                 if let Some(tx) = save_attachment_tx {
-                    let list = get_order_attachment_messages(app, pinned_order_id);
+                    let list = get_order_attachment_messages(app, pinned_order_id, channel);
                     if let Some(msg) = list.get(selected_idx) {
                         if let Some(att) = &msg.attachment {
                             let mut attachment = att.clone();
@@ -843,16 +1032,13 @@ pub fn handle_key_event(
                             let pool = pool.clone();
                             let tx = tx.clone();
                             tokio::spawn(async move {
-                                if attachment.decryption_key.is_none() {
-                                    if let Ok(order) =
-                                        crate::models::Order::get_by_id(&pool, &order_id).await
-                                    {
-                                        attachment.decryption_key =
-                                            crate::util::chat_utils::order_chat_decryption_key_bytes(
-                                                &order,
-                                            );
-                                    }
-                                }
+                                fill_user_attachment_key(
+                                    &pool,
+                                    &order_id,
+                                    channel,
+                                    &mut attachment,
+                                )
+                                .await;
                                 let _ = tx.send((order_id, attachment));
                             });
                         }
@@ -861,6 +1047,24 @@ pub fn handle_key_event(
                 app.mode = UiMode::UserMode(UserMode::Normal);
                 return Some(true);
             }
+            KeyCode::Char('v' | 'V') => {
+                let list = get_order_attachment_messages(app, pinned_order_id, channel);
+                let image = list
+                    .get(selected_idx)
+                    .and_then(|msg| msg.attachment.clone())
+                    .filter(|att| att.file_type == ChatAttachmentType::Image);
+                if let Some(mut attachment) = image {
+                    let order_id = pinned_order_id.clone();
+                    open_image_preview(app, &attachment);
+                    let pool = pool.clone();
+                    let tx = order_result_tx.clone();
+                    tokio::spawn(async move {
+                        fill_user_attachment_key(&pool, &order_id, channel, &mut attachment).await;
+                        send_image_preview(attachment, tx).await;
+                    });
+                }
+                return Some(true);
+            }
             _ => return Some(true),
         }
     }
@@ -901,24 +1105,27 @@ pub fn handle_key_event(
                             app.observer_shared_key_input.chars().take(8).collect();
                         let id = format!("observer_{}", key_prefix);
 
-                        // Observer holds K_conv only; use it as the ChaCha key when the
-                        // attachment JSON omitted an inline key.
-                        let mut att_clone = (*att).clone();
-                        if att_clone.decryption_key.is_none() {
-                            if let Some(keys) = crate::util::chat_utils::keys_from_shared_hex(
-                                &app.observer_shared_key_input,
-                            ) {
-                                att_clone.decryption_key =
-                                    Some(keys.secret_key().secret_bytes().to_vec());
-                            }
-                        }
-
+                        let att_clone = observer_attachment_with_key(app, att);
                         let _ = tx.send((id, att_clone));
                     }
                 }
                 app.mode = UiMode::AdminMode(AdminMode::Normal);
                 return Some(true);
             }
+            KeyCode::Char('v' | 'V') => {
+                let image = app
+                    .observer_messages
+                    .iter()
+                    .filter_map(|m| m.attachment.as_ref())
+                    .nth(selected_idx)
+                    .filter(|att| att.file_type == ChatAttachmentType::Image)
+                    .map(|att| observer_attachment_with_key(app, att));
+                if let Some(attachment) = image {
+                    open_image_preview(app, &attachment);
+                    spawn_image_preview(attachment, order_result_tx.clone());
+                }
+                return Some(true);
+            }
             _ => return Some(true),
         }
     }
@@ -939,6 +1146,21 @@ pub fn handle_key_event(
         }
     }
 
+    // Ctrl+P: open the command palette from the same modes as help
+    if key_event.modifiers.contains(KeyModifiers::CONTROL) && code == KeyCode::Char('p') {
+        let can_open = matches!(
+            app.mode,
+            UiMode::Normal
+                | UiMode::UserMode(UserMode::Normal)
+                | UiMode::AdminMode(AdminMode::Normal)
+                | UiMode::AdminMode(AdminMode::ManagingDispute)
+        );
+        if can_open {
+            open_command_palette(app);
+            return Some(true);
+        }
+    }
+
     // Shift+H on Settings tab: explain every menu option (admin vs user text)
     if key_event.modifiers.contains(KeyModifiers::SHIFT)
         && matches!(code, KeyCode::Char('h') | KeyCode::Char('H'))
@@ -982,13 +1204,15 @@ pub fn handle_key_event(
             }
         }
         if let Tab::User(UserTab::MyTrades) = app.active_tab {
-            if app.mode.user_my_trades_interactive()
-                && app.active_user_chat_channel == UserChatChannel::Peer
-            {
+            if app.mode.user_my_trades_interactive() {
                 if let Some(row) =
                     active_order_chat_list_snapshot(app).get(app.selected_order_chat_idx)
                 {
-                    let list = get_order_attachment_messages(app, &row.order_id);
+                    let list = get_order_attachment_messages(
+                        app,
+                        &row.order_id,
+                        app.active_user_chat_channel,
+                    );
                     if !list.is_empty() {
                         app.mode = UiMode::UserSaveAttachmentPopup(row.order_id.clone(), 0);
                         return Some(true);
@@ -1041,6 +1265,24 @@ pub fn handle_key_event(
         }
     }
 
+    // 'p' on the Orders tab: cycle the payment method filter
+    if !key_event.modifiers.contains(KeyModifiers::CONTROL)
+        && matches!(code, KeyCode::Char('p') | KeyCode::Char('P'))
+        && matches!(app.active_tab, Tab::User(UserTab::Orders))
+        && matches!(
+            app.mode,
+            UiMode::Normal | UiMode::UserMode(UserMode::Normal)
+        )
+    {
+        match orders.lock() {
+            Ok(orders_lock) => cycle_book_payment_filter(app, &orders_lock),
+            Err(e) => crate::util::request_fatal_restart(format!(
+                "Mostrix encountered an internal error (poisoned orders lock: {e}). Please restart the app."
+            )),
+        }
+        return Some(true);
+    }
+
     // Handle invoice input first (before other key handling)
     if let UiMode::NewMessageNotification(
         _,
@@ -1088,7 +1330,7 @@ pub fn handle_key_event(
     ) = app.mode
     {
         if code != KeyCode::Char('c') && code != KeyCode::Char('C') {
-            invoice_state.copied_to_clipboard = false;
+            invoice_state.copied_to_clipboard = None;
         }
     }
 
@@ -1257,7 +1499,7 @@ pub fn handle_key_event(
                                     Some((conv, _sign_pk)) => {
                                         OperationResult::ConversationDisclosure {
                                             conv_hex: conv,
-                                            copied_to_clipboard: false,
+                                            copied_to_clipboard: None,
                                         }
                                     }
                                     None => OperationResult::Error(
@@ -1278,6 +1520,22 @@ pub fn handle_key_event(
         }
     }
 
+    // Ctrl+G: Observer chat <-> linked dispute in Disputes in Progress.
+    if matches!(code, KeyCode::Char('g') | KeyCode::Char('G'))
+        && key_event
+            .modifiers
+            .contains(crossterm::event::KeyModifiers::CONTROL)
+        && matches!(
+            app.mode,
+            UiMode::Normal
+                | UiMode::AdminMode(AdminMode::Normal)
+                | UiMode::AdminMode(AdminMode::ManagingDispute)
+        )
+        && navigation::toggle_observer_dispute_jump(app)
+    {
+        return Some(true);
+    }
+
     // Check if we're in admin chat input mode FIRST - this takes priority over all other key handling
     // (except invoice and key input which are handled earlier)
     // Note: Shift+F and Shift+I are handled before this, so they won't be intercepted
@@ -1297,11 +1555,11 @@ pub fn handle_key_event(
         if !is_ctrl {
             match code {
                 KeyCode::Char(c) => {
-                    app.observer_shared_key_input.push(c);
+                    app.observer_focused_input_mut().push(c);
                     return Some(true);
                 }
                 KeyCode::Backspace => {
-                    app.observer_shared_key_input.pop();
+                    app.observer_focused_input_mut().pop();
                     return Some(true);
                 }
                 _ => {}
@@ -1309,10 +1567,17 @@ pub fn handle_key_event(
         }
     }
 
+    if let Some(result) = dispute_archive::handle_dispute_archive_key(app, &key_event) {
+        return Some(result);
+    }
+
     // Currency dropdown (Create New Order) captures keys while open and on open triggers.
     if let Some(consumed) = form_input::handle_currency_picker_key(code, app) {
         return Some(consumed);
     }
+    if let Some(consumed) = form_input::handle_payment_method_picker_key(code, app) {
+        return Some(consumed);
+    }
 
     match code {
         KeyCode::Left | KeyCode::Right => {
@@ -1337,6 +1602,10 @@ pub fn handle_key_event(
                 | UiMode::ConfirmClearCurrencies(ref mut selected_button)
                 | UiMode::ConfirmDeleteHistoryOrder(_, ref mut selected_button)
                 | UiMode::ConfirmBulkDeleteHistory(ref mut selected_button)
+                | UiMode::RetentionPreview(_, ref mut selected_button)
+                | UiMode::SavedSearchAlert(_, ref mut selected_button)
+                | UiMode::ConfirmSettingsReload(_, ref mut selected_button)
+                | UiMode::ConfirmRestoreBackup(_, ref mut selected_button)
                 | UiMode::ConfirmGenerateNewKeys(ref mut selected_button)
                 | UiMode::ConfirmExit(ref mut selected_button) => {
                     *selected_button = !*selected_button; // Toggle between YES and NO
@@ -1595,7 +1864,9 @@ pub fn handle_key_event(
 #[cfg(test)]
 mod key_handler_tests {
     use super::*;
-    use crate::ui::{InvoiceInputState, InvoiceNotificationActionSelection, UserRole};
+    use crate::ui::{
+        InvoiceInputState, InvoiceNotificationActionSelection, ObserverField, UserRole,
+    };
     use crossterm::event::KeyModifiers;
 
     #[test]
@@ -1726,13 +1997,51 @@ mod key_handler_tests {
     }
 
     #[test]
-    fn observer_tab_ignores_tab_and_backtab_with_a_single_input_field() {
+    fn observer_tab_and_backtab_switch_the_focused_field() {
         let mut app = AppState::new(UserRole::Admin);
         app.active_tab = Tab::Admin(AdminTab::Observer);
         app.observer_shared_key_input = "abc".to_string();
         handle_tab_navigation(KeyCode::Tab, &mut app);
+        assert_eq!(app.observer_focus, ObserverField::SignPubkey);
+        app.observer_focused_input_mut().push_str("npub1");
         handle_tab_navigation(KeyCode::BackTab, &mut app);
+        assert_eq!(app.observer_focus, ObserverField::SharedKey);
+        assert_eq!(app.observer_shared_key_input, "abc");
+        assert_eq!(app.observer_sign_pubkey_input, "npub1");
+    }
+
+    #[test]
+    fn ctrl_g_parks_the_observer_session_while_viewing_the_linked_dispute() {
+        let mut app = AppState::new(UserRole::Admin);
+        let buyer = Keys::generate().public_key();
+        app.admin_disputes_in_progress = vec![AdminDispute {
+            dispute_id: "linked".to_string(),
+            status: Some("settled".to_string()),
+            buyer_pubkey: Some(buyer.to_hex()),
+            ..Default::default()
+        }];
+        app.active_tab = Tab::Admin(AdminTab::Observer);
+        app.observer_shared_key_input = "abc".to_string();
+        app.observer_participants = vec![buyer];
+
+        assert!(navigation::toggle_observer_dispute_jump(&mut app));
+        assert_eq!(app.active_tab, Tab::Admin(AdminTab::DisputesInProgress));
+        assert_eq!(app.selected_dispute_id.as_deref(), Some("linked"));
+        assert_eq!(app.dispute_filter, DisputeFilter::Finalized);
+        assert!(app.observer_parked);
         assert_eq!(app.observer_shared_key_input, "abc");
+
+        assert!(navigation::toggle_observer_dispute_jump(&mut app));
+        assert_eq!(app.active_tab, Tab::Admin(AdminTab::Observer));
+        assert!(!app.observer_parked);
+        assert_eq!(app.observer_shared_key_input, "abc");
+
+        // Parked, then moving on to any other tab drops the key.
+        assert!(navigation::toggle_observer_dispute_jump(&mut app));
+        navigation::switch_to_tab(&mut app, Tab::Admin(AdminTab::Settings));
+        assert!(!app.observer_parked);
+        assert!(app.observer_shared_key_input.is_empty());
+        assert!(!navigation::toggle_observer_dispute_jump(&mut app));
     }
 
     #[test]
@@ -1741,7 +2050,7 @@ mod key_handler_tests {
             invoice_input: String::new(),
             focused: true,
             just_pasted: false,
-            copied_to_clipboard: false,
+            copied_to_clipboard: None,
             scroll_y: 0,
             action_selection: InvoiceNotificationActionSelection::Primary,
         };
@@ -1767,7 +2076,7 @@ mod key_handler_tests {
     fn disclosure_mode(copied_to_clipboard: bool) -> UiMode {
         UiMode::operation_result(OperationResult::ConversationDisclosure {
             conv_hex: "a".repeat(64),
-            copied_to_clipboard,
+            copied_to_clipboard: copied_to_clipboard.then_some(ClipboardBackend::Local),
         })
     }
 
@@ -1778,7 +2087,9 @@ mod key_handler_tests {
     #[test]
     fn copy_disclosed_shared_key_sets_copied_flag_when_the_write_succeeds() {
         let mut mode = disclosure_mode(false);
-        assert!(copy_disclosed_shared_key_if_open_with(&mut mode, |_| true));
+        assert!(copy_disclosed_shared_key_if_open_with(&mut mode, |_| Some(
+            ClipboardBackend::Osc52Tmux
+        )));
         let UiMode::OperationResult(result) = &mode else {
             panic!("expected OperationResult mode");
         };
@@ -1786,7 +2097,11 @@ mod key_handler_tests {
             OperationResult::ConversationDisclosure {
                 copied_to_clipboard,
                 ..
-            } => assert!(*copied_to_clipboard, "C should mark the Shared key copied"),
+            } => assert_eq!(
+                *copied_to_clipboard,
+                Some(ClipboardBackend::Osc52Tmux),
+                "C should mark the Shared key copied, with the backend used"
+            ),
             other => panic!("expected ConversationDisclosure, got {other:?}"),
         }
     }
@@ -1795,7 +2110,7 @@ mod key_handler_tests {
     fn copy_disclosed_shared_key_leaves_flag_false_when_the_write_fails() {
         let mut mode = disclosure_mode(false);
         assert!(
-            copy_disclosed_shared_key_if_open_with(&mut mode, |_| false),
+            copy_disclosed_shared_key_if_open_with(&mut mode, |_| None),
             "the popup was still open and handled the key even though the copy failed"
         );
         let UiMode::OperationResult(result) = &mode else {
@@ -1806,7 +2121,7 @@ mod key_handler_tests {
                 copied_to_clipboard,
                 ..
             } => assert!(
-                !*copied_to_clipboard,
+                copied_to_clipboard.is_none(),
                 "a failed clipboard write must not report success"
             ),
             other => panic!("expected ConversationDisclosure, got {other:?}"),
@@ -1817,7 +2132,7 @@ mod key_handler_tests {
     fn copy_disclosed_shared_key_ignores_other_modes() {
         let mut mode = UiMode::operation_result(OperationResult::Info("hi".to_string()));
         assert!(
-            !copy_disclosed_shared_key_if_open_with(&mut mode, |_| true),
+            !copy_disclosed_shared_key_if_open_with(&mut mode, |_| Some(ClipboardBackend::Local)),
             "generic Info popup must not be treated as a Shared key copy target"
         );
     }
@@ -1834,7 +2149,7 @@ mod key_handler_tests {
                 copied_to_clipboard,
                 ..
             } => assert!(
-                !*copied_to_clipboard,
+                copied_to_clipboard.is_none(),
                 "non-C keys must clear the copied indicator"
             ),
             other => panic!("expected ConversationDisclosure, got {other:?}"),
@@ -1852,7 +2167,10 @@ mod key_handler_tests {
             OperationResult::ConversationDisclosure {
                 copied_to_clipboard,
                 ..
-            } => assert!(*copied_to_clipboard, "C key must not clear the indicator"),
+            } => assert!(
+                copied_to_clipboard.is_some(),
+                "C key must not clear the indicator"
+            ),
             other => panic!("expected ConversationDisclosure, got {other:?}"),
         }
     }
@@ -1870,7 +2188,7 @@ mod key_handler_tests {
                 copied_to_clipboard,
                 ..
             } => assert!(
-                !*copied_to_clipboard,
+                copied_to_clipboard.is_none(),
                 "Ctrl+C must clear the indicator consistently with the Observer clear-all shortcut"
             ),
             other => panic!("expected ConversationDisclosure, got {other:?}"),
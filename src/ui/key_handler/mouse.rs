@@ -0,0 +1,342 @@
+use crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind};
+
+use crate::ui::helpers::active_order_chat_list_snapshot;
+use crate::ui::hit_test::{ChatScroll, HitTarget, PopupButton};
+use crate::ui::key_handler::navigation::switch_to_tab;
+use crate::ui::{
+    AdminMode, AppState, InvoiceNotificationActionSelection, Tab, UiMode, UserMode, UserTab,
+    ViewingMessageButtonSelection,
+};
+
+/// Lines scrolled per wheel notch in chat panes.
+const WHEEL_SCROLL_LINES: usize = 3;
+
+/// Resolve a mouse event against the regions recorded by the last frame (`app.hit_map`).
+///
+/// Selection changes (tab, row, popup button, chat scroll) are applied here. The returned key,
+/// if any, is replayed through `handle_key_event` so confirmation and list navigation keep a
+/// single code path: a button click yields `Enter`, the wheel over a list yields `Up`/`Down`.
+pub fn handle_mouse_event(mouse: MouseEvent, app: &mut AppState) -> Option<KeyCode> {
+    // A popup opened or closed since the last draw: the recorded regions are stale.
+    if !app.hit_map.matches_mode(&app.mode) {
+        return None;
+    }
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            let target = app.hit_map.target_at(mouse.column, mouse.row)?;
+            handle_click(app, target)
+        }
+        MouseEventKind::ScrollUp => {
+            let target = app.hit_map.scroll_target_at(mouse.column, mouse.row)?;
+            handle_wheel(app, target, true)
+        }
+        MouseEventKind::ScrollDown => {
+            let target = app.hit_map.scroll_target_at(mouse.column, mouse.row)?;
+            handle_wheel(app, target, false)
+        }
+        _ => None,
+    }
+}
+
+/// Modes where the tab content (not a popup) owns the screen.
+fn is_main_view(mode: &UiMode) -> bool {
+    matches!(
+        mode,
+        UiMode::Normal
+            | UiMode::UserMode(UserMode::Normal)
+            | UiMode::AdminMode(AdminMode::Normal)
+            | UiMode::AdminMode(AdminMode::ManagingDispute)
+    )
+}
+
+fn handle_click(app: &mut AppState, target: HitTarget) -> Option<KeyCode> {
+    match target {
+        HitTarget::Tab(tab) => {
+            let creating_order = matches!(app.mode, UiMode::UserMode(UserMode::CreatingOrder(_)))
+                && matches!(app.active_tab, Tab::User(UserTab::CreateNewOrder));
+            if is_main_view(&app.mode) || creating_order {
+                switch_to_tab(app, tab);
+            }
+            None
+        }
+        HitTarget::OrderRow(order_id) => {
+            if is_main_view(&app.mode) && matches!(app.active_tab, Tab::User(UserTab::Orders)) {
+                app.selected_order_id = Some(order_id);
+            }
+            None
+        }
+        HitTarget::MyTradesRow(order_id) => {
+            if app.mode.user_my_trades_interactive()
+                && matches!(app.active_tab, Tab::User(UserTab::MyTrades))
+            {
+                let order_id = order_id.to_string();
+                if let Some(idx) = active_order_chat_list_snapshot(app)
+                    .iter()
+                    .position(|row| row.order_id == order_id)
+                {
+                    app.selected_order_chat_idx = idx;
+                }
+            }
+            None
+        }
+        HitTarget::Button(button) => select_popup_button(app, button).then_some(KeyCode::Enter),
+        HitTarget::List | HitTarget::Chat(_) => None,
+    }
+}
+
+fn handle_wheel(app: &mut AppState, target: HitTarget, up: bool) -> Option<KeyCode> {
+    if !is_main_view(&app.mode) {
+        return None;
+    }
+    let state = match target {
+        HitTarget::List => return Some(if up { KeyCode::Up } else { KeyCode::Down }),
+        HitTarget::Chat(ChatScroll::Order) => &mut app.order_chat_scrollview_state,
+        HitTarget::Chat(ChatScroll::Dispute) => &mut app.admin_chat_scrollview_state,
+        HitTarget::Chat(ChatScroll::Observer) => &mut app.observer_scrollview_state,
+        _ => return None,
+    };
+    for _ in 0..WHEEL_SCROLL_LINES {
+        if up {
+            state.scroll_up();
+        } else {
+            state.scroll_down();
+        }
+    }
+    None
+}
+
+/// Move the popup selection onto the clicked button. Returns false when the current mode has
+/// no such button, so the click is not turned into a confirmation.
+fn select_popup_button(app: &mut AppState, button: PopupButton) -> bool {
+    let yes = button == PopupButton::Yes;
+    if let UiMode::ViewingMessage(view_state) = &mut app.mode {
+        match &mut view_state.button_selection {
+            ViewingMessageButtonSelection::Three(selected) => {
+                *selected = button.as_three_state();
+            }
+            ViewingMessageButtonSelection::Two { .. } if button == PopupButton::Cancel => {
+                return false;
+            }
+            ViewingMessageButtonSelection::Two { yes_selected } => *yes_selected = yes,
+        }
+        return true;
+    }
+    if button == PopupButton::Cancel {
+        return false;
+    }
+    match &mut app.mode {
+        UiMode::NewMessageNotification(_, _, invoice_state) => {
+            invoice_state.action_selection = if yes {
+                InvoiceNotificationActionSelection::Primary
+            } else {
+                InvoiceNotificationActionSelection::Cancel
+            };
+        }
+        UiMode::UserMode(UserMode::TakingOrder(take_state)) => take_state.selected_button = yes,
+        UiMode::UserMode(UserMode::ConfirmingOrder {
+            selected_button, ..
+        })
+        | UiMode::AdminMode(AdminMode::ConfirmAddSolver {
+            selected_button, ..
+        })
+        | UiMode::AdminMode(AdminMode::ConfirmAdminKey(_, selected_button))
+        | UiMode::AdminMode(AdminMode::ConfirmTakeDispute(_, selected_button))
+        | UiMode::AdminMode(AdminMode::ConfirmFinalizeDispute {
+            selected_button, ..
+        })
+        | UiMode::ConfirmMostroPubkey(_, selected_button)
+        | UiMode::ConfirmRelay(_, selected_button)
+        | UiMode::ConfirmLnAddress(_, selected_button)
+        | UiMode::ConfirmSavedLnAddressForInvoice(_, selected_button)
+        | UiMode::ConfirmClearLnAddress(selected_button)
+        | UiMode::ConfirmCurrency(_, selected_button)
+        | UiMode::ConfirmClearCurrencies(selected_button)
+        | UiMode::ConfirmDeleteHistoryOrder(_, selected_button)
+        | UiMode::ConfirmBulkDeleteHistory(selected_button)
+        | UiMode::RetentionPreview(_, selected_button)
+        | UiMode::SavedSearchAlert(_, selected_button)
+        | UiMode::ConfirmSettingsReload(_, selected_button)
+        | UiMode::ConfirmRestoreBackup(_, selected_button)
+        | UiMode::ConfirmGenerateNewKeys(selected_button)
+        | UiMode::ConfirmExit(selected_button) => *selected_button = yes,
+        _ => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::hit_test;
+    use crate::ui::{MessageViewState, ThreeState, UserRole};
+    use crossterm::event::KeyModifiers;
+    use mostro_core::prelude::Action;
+    use ratatui::layout::Rect;
+    use uuid::Uuid;
+
+    fn click(column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    fn draw_regions(app: &mut AppState, regions: &[(Rect, HitTarget)]) {
+        hit_test::begin_frame();
+        for (area, target) in regions {
+            hit_test::record(*area, *target);
+        }
+        app.hit_map = hit_test::finish_frame(&app.mode);
+    }
+
+    #[test]
+    fn clicking_a_tab_switches_and_keeps_the_order_draft() {
+        let mut app = AppState::new(UserRole::User);
+        app.mode = UiMode::UserMode(UserMode::Normal);
+        draw_regions(
+            &mut app,
+            &[
+                (
+                    Rect::new(0, 0, 10, 3),
+                    HitTarget::Tab(Tab::User(UserTab::CreateNewOrder)),
+                ),
+                (
+                    Rect::new(10, 0, 10, 3),
+                    HitTarget::Tab(Tab::User(UserTab::MyTrades)),
+                ),
+            ],
+        );
+        assert_eq!(handle_mouse_event(click(2, 1), &mut app), None);
+        assert_eq!(app.active_tab, Tab::User(UserTab::CreateNewOrder));
+        let UiMode::UserMode(UserMode::CreatingOrder(form)) = &mut app.mode else {
+            panic!("Create New Order opens the form");
+        };
+        form.fiat_code = "EUR".to_string();
+
+        draw_regions(
+            &mut app,
+            &[(
+                Rect::new(10, 0, 10, 3),
+                HitTarget::Tab(Tab::User(UserTab::MyTrades)),
+            )],
+        );
+        handle_mouse_event(click(12, 1), &mut app);
+        assert_eq!(app.active_tab, Tab::User(UserTab::MyTrades));
+        assert!(matches!(app.mode, UiMode::UserMode(UserMode::Normal)));
+        assert_eq!(
+            app.order_form_draft.as_ref().map(|f| f.fiat_code.as_str()),
+            Some("EUR")
+        );
+    }
+
+    #[test]
+    fn clicking_an_order_row_selects_it_by_id() {
+        let mut app = AppState::new(UserRole::User);
+        let id = Uuid::new_v4();
+        draw_regions(
+            &mut app,
+            &[
+                (Rect::new(0, 3, 40, 10), HitTarget::List),
+                (Rect::new(1, 5, 38, 1), HitTarget::OrderRow(id)),
+            ],
+        );
+        handle_mouse_event(click(4, 5), &mut app);
+        assert_eq!(app.selected_order_id, Some(id));
+    }
+
+    #[test]
+    fn clicking_a_button_selects_it_then_confirms() {
+        let mut app = AppState::new(UserRole::User);
+        app.mode = UiMode::ConfirmExit(true);
+        draw_regions(
+            &mut app,
+            &[
+                (Rect::new(0, 0, 10, 3), HitTarget::Button(PopupButton::Yes)),
+                (Rect::new(11, 0, 10, 3), HitTarget::Button(PopupButton::No)),
+            ],
+        );
+        assert_eq!(
+            handle_mouse_event(click(12, 1), &mut app),
+            Some(KeyCode::Enter)
+        );
+        assert!(matches!(app.mode, UiMode::ConfirmExit(false)));
+    }
+
+    #[test]
+    fn clicking_a_three_state_button_selects_it() {
+        let mut app = AppState::new(UserRole::User);
+        app.mode = UiMode::ViewingMessage(MessageViewState {
+            message_content: String::new(),
+            order_id: None,
+            action: Action::FiatSentOk,
+            button_selection: ViewingMessageButtonSelection::Three(ThreeState::Yes),
+        });
+        draw_regions(
+            &mut app,
+            &[(
+                Rect::new(20, 0, 10, 3),
+                HitTarget::Button(PopupButton::Cancel),
+            )],
+        );
+        assert_eq!(
+            handle_mouse_event(click(22, 1), &mut app),
+            Some(KeyCode::Enter)
+        );
+        let UiMode::ViewingMessage(view_state) = &app.mode else {
+            panic!("still viewing the message");
+        };
+        assert_eq!(
+            view_state.button_selection,
+            ViewingMessageButtonSelection::Three(ThreeState::Cancel)
+        );
+    }
+
+    #[test]
+    fn stale_regions_are_ignored_after_a_mode_change() {
+        let mut app = AppState::new(UserRole::User);
+        app.mode = UiMode::UserMode(UserMode::Normal);
+        draw_regions(
+            &mut app,
+            &[(
+                Rect::new(0, 0, 10, 3),
+                HitTarget::Tab(Tab::User(UserTab::MyTrades)),
+            )],
+        );
+        app.mode = UiMode::ConfirmExit(true);
+        assert_eq!(handle_mouse_event(click(1, 1), &mut app), None);
+        assert_ne!(app.active_tab, Tab::User(UserTab::MyTrades));
+    }
+
+    #[test]
+    fn wheel_moves_list_selection_and_scrolls_chats() {
+        let mut app = AppState::new(UserRole::User);
+        app.mode = UiMode::UserMode(UserMode::Normal);
+        draw_regions(
+            &mut app,
+            &[
+                (Rect::new(0, 0, 10, 10), HitTarget::List),
+                (Rect::new(10, 0, 30, 10), HitTarget::Chat(ChatScroll::Order)),
+            ],
+        );
+        let wheel = |kind, column| MouseEvent {
+            kind,
+            column,
+            row: 2,
+            modifiers: KeyModifiers::NONE,
+        };
+        assert_eq!(
+            handle_mouse_event(wheel(MouseEventKind::ScrollDown, 3), &mut app),
+            Some(KeyCode::Down)
+        );
+        assert_eq!(
+            handle_mouse_event(wheel(MouseEventKind::ScrollDown, 20), &mut app),
+            None
+        );
+        assert_eq!(
+            app.order_chat_scrollview_state.offset().y,
+            WHEEL_SCROLL_LINES as u16
+        );
+    }
+}
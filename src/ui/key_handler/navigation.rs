@@ -4,9 +4,10 @@ use crate::ui::helpers::{
 };
 use crate::ui::orders::strip_new_order_messages_and_clamp_selected;
 use crate::ui::{
-    AdminMode, AdminTab, AppState, FormState, Tab, UiMode, UserChatChannel, UserMode, UserRole,
-    UserTab, ViewingMessageButtonSelection,
+    AdminMode, AdminTab, AppState, DisputeFilter, FormState, ObserverField, Tab, UiMode,
+    UserChatChannel, UserMode, UserRole, UserTab, ViewingMessageButtonSelection,
 };
+use crate::util::fiat::BookOrder;
 use crossterm::event::KeyCode;
 use mostro_core::prelude::*;
 use std::sync::{Arc, Mutex};
@@ -15,7 +16,7 @@ use std::sync::{Arc, Mutex};
 pub fn handle_navigation(
     code: KeyCode,
     app: &mut AppState,
-    orders: &Arc<Mutex<Vec<SmallOrder>>>,
+    orders: &Arc<Mutex<Vec<BookOrder>>>,
     disputes: &Arc<Mutex<Vec<mostro_core::prelude::Dispute>>>,
 ) {
     match code {
@@ -27,7 +28,7 @@ pub fn handle_navigation(
     }
 }
 
-fn handle_left_key(app: &mut AppState, _orders: &Arc<Mutex<Vec<SmallOrder>>>) {
+fn handle_left_key(app: &mut AppState, _orders: &Arc<Mutex<Vec<BookOrder>>>) {
     // Leaving Create New Order silently keeps the draft for when the user returns.
     if let UiMode::UserMode(UserMode::CreatingOrder(form)) = &app.mode {
         if matches!(app.active_tab, Tab::User(UserTab::CreateNewOrder)) {
@@ -87,6 +88,10 @@ fn handle_left_key(app: &mut AppState, _orders: &Arc<Mutex<Vec<SmallOrder>>>) {
         | UiMode::ConfirmClearCurrencies(ref mut selected_button)
         | UiMode::ConfirmDeleteHistoryOrder(_, ref mut selected_button)
         | UiMode::ConfirmBulkDeleteHistory(ref mut selected_button)
+        | UiMode::RetentionPreview(_, ref mut selected_button)
+        | UiMode::SavedSearchAlert(_, ref mut selected_button)
+        | UiMode::ConfirmSettingsReload(_, ref mut selected_button)
+        | UiMode::ConfirmRestoreBackup(_, ref mut selected_button)
         | UiMode::ConfirmExit(ref mut selected_button) => {
             // Switch to YES button (left side)
             *selected_button = true;
@@ -98,7 +103,7 @@ fn handle_left_key(app: &mut AppState, _orders: &Arc<Mutex<Vec<SmallOrder>>>) {
     }
 }
 
-fn handle_right_key(app: &mut AppState, _orders: &Arc<Mutex<Vec<SmallOrder>>>) {
+fn handle_right_key(app: &mut AppState, _orders: &Arc<Mutex<Vec<BookOrder>>>) {
     // Leaving Create New Order silently keeps the draft for when the user returns.
     if let UiMode::UserMode(UserMode::CreatingOrder(form)) = &app.mode {
         if matches!(app.active_tab, Tab::User(UserTab::CreateNewOrder)) {
@@ -158,6 +163,10 @@ fn handle_right_key(app: &mut AppState, _orders: &Arc<Mutex<Vec<SmallOrder>>>) {
         | UiMode::ConfirmClearCurrencies(ref mut selected_button)
         | UiMode::ConfirmDeleteHistoryOrder(_, ref mut selected_button)
         | UiMode::ConfirmBulkDeleteHistory(ref mut selected_button)
+        | UiMode::RetentionPreview(_, ref mut selected_button)
+        | UiMode::SavedSearchAlert(_, ref mut selected_button)
+        | UiMode::ConfirmSettingsReload(_, ref mut selected_button)
+        | UiMode::ConfirmRestoreBackup(_, ref mut selected_button)
         | UiMode::ConfirmExit(ref mut selected_button) => {
             // Switch to NO button (right side)
             *selected_button = false;
@@ -171,7 +180,7 @@ fn handle_right_key(app: &mut AppState, _orders: &Arc<Mutex<Vec<SmallOrder>>>) {
 
 fn handle_up_key(
     app: &mut AppState,
-    orders: &Arc<Mutex<Vec<SmallOrder>>>,
+    orders: &Arc<Mutex<Vec<BookOrder>>>,
     disputes: &Arc<Mutex<Vec<Dispute>>>,
 ) {
     match &mut app.mode {
@@ -252,6 +261,8 @@ fn handle_up_key(
         | UiMode::UserMode(UserMode::WaitingTakeOrder(_))
         | UiMode::UserMode(UserMode::WaitingAddInvoice)
         | UiMode::HelpPopup(..)
+        | UiMode::CommandPalette(..)
+        | UiMode::ImagePreview(..)
         | UiMode::SettingsInstructionsPopup(..)
         | UiMode::OperationResult(_)
         | UiMode::NewMessageNotification(_, _, _)
@@ -271,6 +282,7 @@ fn handle_up_key(
         | UiMode::ObserverSaveAttachmentPopup(_)
         | UiMode::UserSaveAttachmentPopup(_, _)
         | UiMode::UserSendAttachmentPicker(_)
+        | UiMode::UserAttachmentSendConfirm(_)
         | UiMode::AddMostroPubkey(_)
         | UiMode::ConfirmMostroPubkey(_, _)
         | UiMode::AddRelay(_)
@@ -284,6 +296,11 @@ fn handle_up_key(
         | UiMode::ConfirmClearCurrencies(_)
         | UiMode::ConfirmDeleteHistoryOrder(_, _)
         | UiMode::ConfirmBulkDeleteHistory(_)
+        | UiMode::RetentionPreview(_, _)
+        | UiMode::SavedSearchAlert(_, _)
+        | UiMode::ConfirmSettingsReload(_, _)
+        | UiMode::BackupForm(_)
+        | UiMode::ConfirmRestoreBackup(_, _)
         | UiMode::ConfirmGenerateNewKeys(_)
         | UiMode::BackupNewKeys(_)
         | UiMode::ConfirmExit(_) => {
@@ -294,7 +311,7 @@ fn handle_up_key(
 
 fn handle_down_key(
     app: &mut AppState,
-    orders: &Arc<Mutex<Vec<SmallOrder>>>,
+    orders: &Arc<Mutex<Vec<BookOrder>>>,
     disputes: &Arc<Mutex<Vec<Dispute>>>,
 ) {
     match &mut app.mode {
@@ -387,6 +404,8 @@ fn handle_down_key(
         | UiMode::UserMode(UserMode::WaitingTakeOrder(_))
         | UiMode::UserMode(UserMode::WaitingAddInvoice)
         | UiMode::HelpPopup(..)
+        | UiMode::CommandPalette(..)
+        | UiMode::ImagePreview(..)
         | UiMode::SettingsInstructionsPopup(..)
         | UiMode::OperationResult(_)
         | UiMode::NewMessageNotification(_, _, _)
@@ -406,6 +425,7 @@ fn handle_down_key(
         | UiMode::ObserverSaveAttachmentPopup(_)
         | UiMode::UserSaveAttachmentPopup(_, _)
         | UiMode::UserSendAttachmentPicker(_)
+        | UiMode::UserAttachmentSendConfirm(_)
         | UiMode::AddMostroPubkey(_)
         | UiMode::ConfirmMostroPubkey(_, _)
         | UiMode::AddRelay(_)
@@ -419,6 +439,11 @@ fn handle_down_key(
         | UiMode::ConfirmClearCurrencies(_)
         | UiMode::ConfirmDeleteHistoryOrder(_, _)
         | UiMode::ConfirmBulkDeleteHistory(_)
+        | UiMode::RetentionPreview(_, _)
+        | UiMode::SavedSearchAlert(_, _)
+        | UiMode::ConfirmSettingsReload(_, _)
+        | UiMode::BackupForm(_)
+        | UiMode::ConfirmRestoreBackup(_, _)
         | UiMode::ConfirmGenerateNewKeys(_)
         | UiMode::BackupNewKeys(_)
         | UiMode::ConfirmExit(_) => {
@@ -452,6 +477,24 @@ fn leave_creating_order_to_adjacent_tab(app: &mut AppState, to_prev: bool) {
     app.mode = UiMode::UserMode(UserMode::Normal);
 }
 
+/// Jump straight to `tab` (mouse click on the tab bar) with the same side effects as ←/→:
+/// an open Create New Order form is kept as a draft and restored on return.
+pub(crate) fn switch_to_tab(app: &mut AppState, tab: Tab) {
+    if tab == app.active_tab {
+        return;
+    }
+    if let UiMode::UserMode(UserMode::CreatingOrder(form)) = &app.mode {
+        app.order_form_draft = Some(form.clone());
+        app.mode = UiMode::UserMode(UserMode::Normal);
+    }
+    let prev_tab = app.active_tab;
+    app.active_tab = tab;
+    handle_tab_switch(app, prev_tab);
+    if let Tab::User(UserTab::CreateNewOrder) = app.active_tab {
+        app.mode = UiMode::UserMode(UserMode::CreatingOrder(restore_or_new_form(app)));
+    }
+}
+
 pub(crate) fn handle_tab_switch(app: &mut AppState, prev_tab: Tab) {
     // Clear pending notifications and mark messages as read when switching to Messages tab (user mode only)
     if let Tab::User(UserTab::Messages) = app.active_tab {
@@ -504,15 +547,60 @@ pub(crate) fn handle_tab_switch(app: &mut AppState, prev_tab: Tab) {
         }
     }
 
-    // Clear transient observer state when leaving Observer tab
-    if let Tab::Admin(AdminTab::Observer) = prev_tab {
+    // Clear transient observer state when leaving Observer tab, unless the solver jumped to
+    // the linked dispute (Ctrl+G): the key and live subscription are parked until they come
+    // back or move on to another tab.
+    if let Tab::Admin(AdminTab::Observer) = app.active_tab {
+        app.observer_parked = false;
+    } else if let Tab::Admin(AdminTab::Observer) = prev_tab {
+        let jumped = matches!(app.active_tab, Tab::Admin(AdminTab::DisputesInProgress));
+        if !(app.observer_parked && jumped) {
+            app.clear_observer_secrets();
+        }
+    } else if app.observer_parked && prev_tab == Tab::Admin(AdminTab::DisputesInProgress) {
         app.clear_observer_secrets();
     }
 }
 
+/// Ctrl+G: from the Observer tab, open the dispute linked to the observed chat in Disputes in
+/// Progress (keeping the observer session parked); from there, return to the Observer chat.
+/// Returns `false` when there is nothing to jump to.
+pub(crate) fn toggle_observer_dispute_jump(app: &mut AppState) -> bool {
+    match app.active_tab {
+        Tab::Admin(AdminTab::Observer) => {
+            let Some((dispute_id, finalized)) = app
+                .observer_linked_dispute()
+                .map(|d| (d.dispute_id.clone(), d.is_finalized()))
+            else {
+                return false;
+            };
+            app.observer_parked = true;
+            app.selected_dispute_id = Some(dispute_id);
+            app.dispute_filter = if finalized {
+                DisputeFilter::Finalized
+            } else {
+                DisputeFilter::InProgress
+            };
+            switch_to_tab(app, Tab::Admin(AdminTab::DisputesInProgress));
+            true
+        }
+        Tab::Admin(AdminTab::DisputesInProgress) if app.observer_parked => {
+            switch_to_tab(app, Tab::Admin(AdminTab::Observer));
+            true
+        }
+        _ => false,
+    }
+}
+
 /// Handle Tab and BackTab keys
 pub fn handle_tab_navigation(code: KeyCode, app: &mut AppState) {
     match code {
+        KeyCode::Tab | KeyCode::BackTab if app.observer_inputs_editable() => {
+            app.observer_focus = match app.observer_focus {
+                ObserverField::SharedKey => ObserverField::SignPubkey,
+                ObserverField::SignPubkey => ObserverField::SharedKey,
+            };
+        }
         KeyCode::Tab => {
             if let Tab::Admin(AdminTab::DisputesInProgress) = app.active_tab {
                 app.active_chat_party = match app.active_chat_party {
@@ -1,4 +1,6 @@
-use crate::ui::{AppState, UserRole};
+use crate::i18n::{language, set_language, tr_args};
+use crate::ui::theme::user_theme_path;
+use crate::ui::{AppState, OperationResult, Theme, ThemePreset, UiMode, UserRole};
 use lnurl::lightning_address::LightningAddress;
 use std::str::FromStr;
 
@@ -104,6 +106,72 @@ pub fn clear_currency_filters() {
     );
 }
 
+/// Cycle the color theme from Settings (Enter on "Change Theme").
+///
+/// `custom` is skipped when `~/.mostrix/theme.toml` does not exist, so the cycle never lands on
+/// a preset that silently renders as the dark fallback.
+pub fn handle_theme_cycle(app: &mut AppState) {
+    let has_user_theme = user_theme_path().is_some_and(|p| p.exists());
+    let mut next = app.theme_preset.next();
+    if next == ThemePreset::Custom && !has_user_theme {
+        next = next.next();
+    }
+
+    app.apply_theme_setting(next.as_str());
+    if next == ThemePreset::Custom {
+        if let Some(path) = user_theme_path() {
+            if let Err(e) = Theme::from_user_file(&path) {
+                app.mode = UiMode::operation_result(OperationResult::Error(tr_args(
+                    "settings.custom_theme_invalid",
+                    &[
+                        ("path", &path.display().to_string()),
+                        ("error", &e.to_string()),
+                    ],
+                )));
+            }
+        }
+    }
+
+    let theme_name = next.as_str().to_string();
+    save_settings_with(
+        |s| s.theme = theme_name.clone(),
+        "Failed to save theme to settings",
+        &tr_args("settings.theme_switched", &[("theme", next.label())]),
+    );
+}
+
+/// Cycle the UI language from Settings (Enter on "Change Language").
+///
+/// Takes effect on the next frame; text already stored in popups (e.g. an open error) keeps the
+/// language it was formatted in.
+pub fn handle_language_cycle() {
+    let next = language().next();
+    set_language(next);
+    let code = next.code().to_string();
+    save_settings_with(
+        |s| s.language = code.clone(),
+        "Failed to save language to settings",
+        &tr_args("settings.language_switched", &[("language", next.label())]),
+    );
+}
+
+/// Flip the automation kill switch from Settings (Enter on "Toggle Automation").
+///
+/// Applies immediately: rules stop (or start) firing on the next incoming trade DM.
+pub fn handle_automation_toggle(app: &mut AppState) {
+    let enabled = !app.automation.enabled;
+    app.automation.enabled = enabled;
+    save_settings_with(
+        |s| s.automation_enabled = enabled,
+        "Failed to save automation switch to settings",
+        &format!(
+            "Automation {} ({} rules loaded)",
+            if enabled { "enabled" } else { "disabled" },
+            app.automation.rules().len()
+        ),
+    );
+}
+
 /// Toggle User/Admin from Settings (Enter on "Switch Mode").
 pub fn handle_mode_switch(app: &mut AppState) {
     let new_role = match app.user_role {
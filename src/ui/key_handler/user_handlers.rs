@@ -1,5 +1,7 @@
 use crate::ui::key_handler::async_tasks::spawn_take_order_task;
+use crate::ui::order_form::has_fractional_fiat;
 use crate::ui::{AppState, FormState, Tab, TakeOrderState, UiMode, UserMode, UserRole, UserTab};
+use crate::util::order_utils::range_take_amount;
 use nostr_sdk::prelude::Client;
 use nostr_sdk::prelude::PublicKey;
 use sqlx::SqlitePool;
@@ -7,8 +9,13 @@ use tokio::sync::mpsc::UnboundedSender;
 
 /// Handle Enter key when creating an order.
 pub fn handle_enter_creating_order(app: &mut AppState, form: &FormState) {
-    // Show confirmation popup when Enter is pressed
+    // Show confirmation popup when Enter is pressed; the preview already flags
+    // a fractional fiat amount, so keep the form open instead.
     if let Tab::User(UserTab::CreateNewOrder) = app.active_tab {
+        if has_fractional_fiat(form) {
+            app.mode = UiMode::UserMode(UserMode::CreatingOrder(form.clone()));
+            return;
+        }
         app.mode = UiMode::UserMode(UserMode::ConfirmingOrder {
             form: form.clone(),
             selected_button: true, // default to YES
@@ -82,7 +89,7 @@ pub(crate) fn execute_take_order_action(
 
     // Parse amount if it's a range order
     let amount = if take_state_clone.is_range_order {
-        take_state_clone.amount_input.trim().parse::<i64>().ok()
+        range_take_amount(&take_state_clone)
     } else {
         None
     };
@@ -1,9 +1,9 @@
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
-use super::{helpers, KeyInputState, BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::{helpers, KeyInputState, Theme};
 
 pub fn render_key_input_popup(
     f: &mut ratatui::Frame,
@@ -12,6 +12,7 @@ pub fn render_key_input_popup(
     placeholder: &str,
     key_state: &KeyInputState,
     is_sensitive: bool,
+    theme: &Theme,
 ) {
     let area = f.area();
     let popup_width = 80;
@@ -25,7 +26,7 @@ pub fn render_key_input_popup(
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     f.render_widget(block, popup);
 
     let chunks = Layout::new(
@@ -47,7 +48,9 @@ pub fn render_key_input_popup(
         f.render_widget(
             Paragraph::new(Line::from(vec![Span::styled(
                 "⚠️  SENSITIVE DATA: Private keys are confidential!",
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                Style::default()
+                    .fg(theme.negative)
+                    .add_modifier(Modifier::BOLD),
             )]))
             .alignment(ratatui::layout::Alignment::Center),
             chunks[1],
@@ -59,7 +62,7 @@ pub fn render_key_input_popup(
         Paragraph::new(Line::from(vec![Span::styled(
             label,
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -75,11 +78,11 @@ pub fn render_key_input_popup(
 
     let input_style = if key_state.focused {
         Style::default()
-            .fg(PRIMARY_COLOR)
-            .bg(Color::Black)
+            .fg(theme.primary)
+            .bg(theme.input_bg)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme.text)
     };
 
     f.render_widget(
@@ -90,7 +93,7 @@ pub fn render_key_input_popup(
                 Block::default()
                     .borders(Borders::ALL)
                     .style(if key_state.focused {
-                        Style::default().fg(PRIMARY_COLOR)
+                        Style::default().fg(theme.primary)
                     } else {
                         Style::default()
                     }),
@@ -105,14 +108,14 @@ pub fn render_key_input_popup(
             Span::styled(
                 "Ctrl+Shift+V",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" or right-click), then press ", Style::default()),
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to submit", Style::default()),
@@ -122,7 +125,7 @@ pub fn render_key_input_popup(
     );
 
     // Esc help text
-    helpers::render_help_text(f, chunks[6], "Press ", "Esc", " to cancel");
+    helpers::render_help_text(f, chunks[6], "Press ", "Esc", " to cancel", theme);
 }
 
 #[cfg(test)]
@@ -152,6 +155,7 @@ mod tests {
 
     #[test]
     fn render_sensitive_shows_warning_and_chrome() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(100, 30);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
@@ -163,6 +167,7 @@ mod tests {
                     "paste your key here",
                     &key_state(""),
                     true,
+                    theme,
                 );
             })
             .unwrap();
@@ -176,6 +181,7 @@ mod tests {
 
     #[test]
     fn render_with_input_shows_typed_text() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(100, 30);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
@@ -187,6 +193,7 @@ mod tests {
                     "placeholder text",
                     &key_state("npub1abcxyz"),
                     false,
+                    theme,
                 );
             })
             .unwrap();
@@ -200,6 +207,7 @@ mod tests {
 
     #[test]
     fn render_empty_shows_placeholder() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(100, 30);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
@@ -211,6 +219,7 @@ mod tests {
                     "paste your key here",
                     &key_state(""),
                     false,
+                    theme,
                 );
             })
             .unwrap();
@@ -1,21 +1,23 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use super::{
-    helpers, InvoiceInputState, InvoiceNotificationActionSelection, MessageNotification,
-    BACKGROUND_COLOR, PRIMARY_COLOR,
+    helpers, InvoiceInputState, InvoiceNotificationActionSelection, MessageNotification, Theme,
 };
+use crate::i18n::{tr, tr_args};
+use crate::util::mostro_info::MostroInstanceInfo;
+use crate::util::trade_deadlines::popup_deadline;
 
 /// Renders the order ID header in a notification popup
-fn render_order_id_header(f: &mut ratatui::Frame, area: Rect, order_id_str: &str) {
+fn render_order_id_header(f: &mut ratatui::Frame, area: Rect, order_id_str: &str, theme: &Theme) {
     f.render_widget(
         Paragraph::new(Line::from(vec![Span::styled(
             order_id_str,
             Style::default()
-                .bg(BACKGROUND_COLOR)
-                .fg(PRIMARY_COLOR)
+                .bg(theme.background)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -24,11 +26,17 @@ fn render_order_id_header(f: &mut ratatui::Frame, area: Rect, order_id_str: &str
 }
 
 /// Renders the message preview text
-fn render_message_preview(f: &mut ratatui::Frame, area: Rect, preview: &str, use_white_text: bool) {
+fn render_message_preview(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    preview: &str,
+    use_white_text: bool,
+    theme: &Theme,
+) {
     let style = if use_white_text {
-        Style::default().bg(BACKGROUND_COLOR).fg(Color::White)
+        Style::default().bg(theme.background).fg(theme.text)
     } else {
-        Style::default().bg(BACKGROUND_COLOR)
+        Style::default().bg(theme.background)
     };
     f.render_widget(
         Paragraph::new(Line::from(vec![Span::styled(preview, style)]))
@@ -52,7 +60,12 @@ fn create_input_area(chunk: Rect) -> Rect {
 }
 
 /// Renders the invoice input field for AddInvoice
-fn render_invoice_input(f: &mut ratatui::Frame, area: Rect, invoice_state: &InvoiceInputState) {
+fn render_invoice_input(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    invoice_state: &InvoiceInputState,
+    theme: &Theme,
+) {
     let input_display = if invoice_state.invoice_input.is_empty() {
         "lnbc...".to_string()
     } else {
@@ -61,11 +74,11 @@ fn render_invoice_input(f: &mut ratatui::Frame, area: Rect, invoice_state: &Invo
 
     let input_style = if invoice_state.focused {
         Style::default()
-            .fg(PRIMARY_COLOR)
-            .bg(Color::Black)
+            .fg(theme.primary)
+            .bg(theme.input_bg)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(theme.text)
     };
 
     f.render_widget(
@@ -76,7 +89,7 @@ fn render_invoice_input(f: &mut ratatui::Frame, area: Rect, invoice_state: &Invo
                 Block::default()
                     .borders(Borders::ALL)
                     .style(if invoice_state.focused {
-                        Style::default().fg(PRIMARY_COLOR)
+                        Style::default().fg(theme.primary)
                     } else {
                         Style::default()
                     }),
@@ -91,14 +104,15 @@ fn render_invoice_display(
     area: Rect,
     invoice: Option<&String>,
     scroll_y: u16,
+    theme: &Theme,
 ) {
     let (invoice_text, text_color) = match invoice {
-        Some(inv) if !inv.is_empty() => (inv.clone(), Color::White),
+        Some(inv) if !inv.is_empty() => (inv.clone(), theme.text),
         Some(_) => (
             "⚠️  Invoice not available (empty)".to_string(),
-            Color::Yellow,
+            theme.warning,
         ),
-        None => ("⚠️  Invoice not available".to_string(), Color::Yellow),
+        None => ("⚠️  Invoice not available".to_string(), theme.warning),
     };
 
     f.render_widget(
@@ -109,7 +123,7 @@ fn render_invoice_display(
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .style(Style::default().fg(PRIMARY_COLOR)),
+                    .style(Style::default().fg(theme.primary)),
             ),
         area,
     );
@@ -121,6 +135,7 @@ fn render_add_bond_invoice(
     popup: Rect,
     notification: &MessageNotification,
     invoice_state: &InvoiceInputState,
+    theme: &Theme,
 ) {
     let chunks = Layout::new(
         Direction::Vertical,
@@ -140,10 +155,10 @@ fn render_add_bond_invoice(
     .split(popup);
 
     let order_id_str = helpers::format_order_id(notification.order_id);
-    render_order_id_header(f, chunks[1], &order_id_str);
-    render_message_preview(f, chunks[2], &notification.message_preview, false);
+    render_order_id_header(f, chunks[1], &order_id_str, theme);
+    render_message_preview(f, chunks[2], &notification.message_preview, false, theme);
     if let Some(body) = notification.body.as_deref() {
-        render_message_preview(f, chunks[3], body, true);
+        render_message_preview(f, chunks[3], body, true, theme);
     }
 
     let amt: i64 = notification.sat_amount.unwrap_or_default();
@@ -152,7 +167,7 @@ fn render_add_bond_invoice(
         Paragraph::new(Line::from(vec![Span::styled(
             input_label,
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -160,7 +175,7 @@ fn render_add_bond_invoice(
     );
 
     let input_area = create_input_area(chunks[5]);
-    render_invoice_input(f, input_area, invoice_state);
+    render_invoice_input(f, input_area, invoice_state, theme);
 
     helpers::render_yes_no_buttons(
         f,
@@ -171,6 +186,7 @@ fn render_add_bond_invoice(
         ),
         "Submit Invoice",
         "Cancel Order",
+        theme,
     );
 
     f.render_widget(
@@ -179,14 +195,14 @@ fn render_add_bond_invoice(
             Span::styled(
                 "Left/Right",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to select action, ", Style::default()),
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to confirm", Style::default()),
@@ -201,21 +217,21 @@ fn render_add_bond_invoice(
             Span::styled(
                 "Shift+Insert",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" / ", Style::default()),
             Span::styled(
                 "Ctrl+Shift+V",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("), ", Style::default()),
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to dismiss", Style::default()),
@@ -231,6 +247,7 @@ fn render_add_invoice(
     popup: Rect,
     notification: &MessageNotification,
     invoice_state: &InvoiceInputState,
+    theme: &Theme,
 ) {
     let chunks = Layout::new(
         Direction::Vertical,
@@ -238,7 +255,7 @@ fn render_add_invoice(
             Constraint::Length(1), // spacer
             Constraint::Length(1), // order id
             Constraint::Length(1), // message preview
-            Constraint::Length(1), // spacer
+            Constraint::Length(1), // body (PaymentFailed retry schedule) or spacer
             Constraint::Length(1), // label
             Constraint::Length(6), // invoice input field
             Constraint::Length(1), // spacer
@@ -250,16 +267,30 @@ fn render_add_invoice(
     .split(popup);
 
     let order_id_str = helpers::format_order_id(notification.order_id);
-    render_order_id_header(f, chunks[1], &order_id_str);
-    render_message_preview(f, chunks[2], &notification.message_preview, false);
+    render_order_id_header(f, chunks[1], &order_id_str, theme);
+    render_message_preview(f, chunks[2], &notification.message_preview, false, theme);
+    if let Some(body) = notification.body.as_deref() {
+        render_message_preview(f, chunks[3], body, true, theme);
+    }
 
+    let payout_failed = matches!(
+        notification.action,
+        mostro_core::prelude::Action::PaymentFailed
+    );
     let amt: i64 = notification.sat_amount.unwrap_or_default();
-    let input_label = format!("Paste your {} sats Lightning invoice:", amt);
+    let input_label = if payout_failed {
+        tr_args(
+            "payment_failed.invoice_prompt",
+            &[("amount", &amt.to_string())],
+        )
+    } else {
+        format!("Paste your {} sats Lightning invoice:", amt)
+    };
     f.render_widget(
         Paragraph::new(Line::from(vec![Span::styled(
             input_label,
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -267,7 +298,7 @@ fn render_add_invoice(
     );
 
     let input_area = create_input_area(chunks[5]);
-    render_invoice_input(f, input_area, invoice_state);
+    render_invoice_input(f, input_area, invoice_state, theme);
 
     helpers::render_yes_no_buttons(
         f,
@@ -277,7 +308,12 @@ fn render_add_invoice(
             InvoiceNotificationActionSelection::Primary
         ),
         "Submit Invoice",
-        "Cancel Order",
+        if payout_failed {
+            tr("payment_failed.wait_button")
+        } else {
+            "Cancel Order"
+        },
+        theme,
     );
 
     f.render_widget(
@@ -286,14 +322,14 @@ fn render_add_invoice(
             Span::styled(
                 "Left/Right",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to select action, ", Style::default()),
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to confirm", Style::default()),
@@ -308,21 +344,21 @@ fn render_add_invoice(
             Span::styled(
                 "Shift+Insert",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" / ", Style::default()),
             Span::styled(
                 "Ctrl+Shift+V",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled("), ", Style::default()),
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" dismiss", Style::default()),
@@ -338,6 +374,7 @@ fn render_pay_invoice(
     popup: Rect,
     notification: &MessageNotification,
     invoice_state: &InvoiceInputState,
+    theme: &Theme,
 ) {
     let chunks = Layout::new(
         Direction::Vertical,
@@ -357,8 +394,8 @@ fn render_pay_invoice(
     .split(popup);
 
     let order_id_str = helpers::format_order_id(notification.order_id);
-    render_order_id_header(f, chunks[1], &order_id_str);
-    render_message_preview(f, chunks[2], &notification.message_preview, true);
+    render_order_id_header(f, chunks[1], &order_id_str, theme);
+    render_message_preview(f, chunks[2], &notification.message_preview, true, theme);
 
     let amount_text = if let Some(amount) = notification.sat_amount {
         format!("Lightning invoice to pay ({} sats):", amount)
@@ -370,7 +407,7 @@ fn render_pay_invoice(
         Paragraph::new(Line::from(vec![Span::styled(
             amount_text,
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -383,6 +420,7 @@ fn render_pay_invoice(
         invoice_area,
         notification.invoice.as_ref(),
         invoice_state.scroll_y,
+        theme,
     );
 
     helpers::render_yes_no_buttons(
@@ -394,15 +432,16 @@ fn render_pay_invoice(
         ),
         "Acknowledge",
         "Cancel Order",
+        theme,
     );
 
     // Help text - first line
-    if invoice_state.copied_to_clipboard {
+    if let Some(backend) = invoice_state.copied_to_clipboard {
         f.render_widget(
             Paragraph::new(Line::from(vec![Span::styled(
-                "✓ Invoice copied to clipboard!",
+                format!("✓ Invoice copied to clipboard ({})!", backend.label()),
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(theme.positive)
                     .add_modifier(Modifier::BOLD),
             )]))
             .alignment(ratatui::layout::Alignment::Center),
@@ -415,21 +454,21 @@ fn render_pay_invoice(
                 Span::styled(
                     "C",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" to copy invoice to clipboard. ", Style::default()),
                 Span::styled(
                     "↑/↓",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" scroll, ", Style::default()),
                 Span::styled(
                     "Left/Right",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" select action", Style::default()),
@@ -446,14 +485,14 @@ fn render_pay_invoice(
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to confirm, ", Style::default()),
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to dismiss", Style::default()),
@@ -474,6 +513,7 @@ fn render_pay_bond_invoice(
     popup: Rect,
     notification: &MessageNotification,
     invoice_state: &InvoiceInputState,
+    theme: &Theme,
 ) {
     let chunks = Layout::new(
         Direction::Vertical,
@@ -494,14 +534,14 @@ fn render_pay_bond_invoice(
     .split(popup);
 
     let order_id_str = helpers::format_order_id(notification.order_id);
-    render_order_id_header(f, chunks[1], &order_id_str);
-    render_message_preview(f, chunks[2], &notification.message_preview, true);
+    render_order_id_header(f, chunks[1], &order_id_str, theme);
+    render_message_preview(f, chunks[2], &notification.message_preview, true, theme);
 
     f.render_widget(
         Paragraph::new(Line::from(vec![Span::styled(
             "Locked, not spent — refunded on normal completion",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.warning)
                 .add_modifier(Modifier::BOLD),
         )]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -524,7 +564,7 @@ fn render_pay_bond_invoice(
         Paragraph::new(Line::from(vec![Span::styled(
             amount_text,
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -537,6 +577,7 @@ fn render_pay_bond_invoice(
         invoice_area,
         notification.invoice.as_ref(),
         invoice_state.scroll_y,
+        theme,
     );
 
     helpers::render_yes_no_buttons(
@@ -548,14 +589,15 @@ fn render_pay_bond_invoice(
         ),
         "Acknowledge",
         "Cancel Order",
+        theme,
     );
 
-    if invoice_state.copied_to_clipboard {
+    if let Some(backend) = invoice_state.copied_to_clipboard {
         f.render_widget(
             Paragraph::new(Line::from(vec![Span::styled(
-                "✓ Invoice copied to clipboard!",
+                format!("✓ Invoice copied to clipboard ({})!", backend.label()),
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(theme.positive)
                     .add_modifier(Modifier::BOLD),
             )]))
             .alignment(ratatui::layout::Alignment::Center),
@@ -568,21 +610,21 @@ fn render_pay_bond_invoice(
                 Span::styled(
                     "C",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" to copy invoice to clipboard. ", Style::default()),
                 Span::styled(
                     "↑/↓",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" scroll, ", Style::default()),
                 Span::styled(
                     "Left/Right",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" select action", Style::default()),
@@ -598,14 +640,14 @@ fn render_pay_bond_invoice(
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to confirm, ", Style::default()),
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to dismiss", Style::default()),
@@ -634,6 +676,7 @@ fn render_waiting_phase_popup(
     popup: Rect,
     notification: &MessageNotification,
     invoice_state: &InvoiceInputState,
+    theme: &Theme,
 ) {
     let [inner] = Layout::new(Direction::Vertical, [Constraint::Min(0)])
         .margin(1)
@@ -654,9 +697,9 @@ fn render_waiting_phase_popup(
     .split(inner);
 
     let order_id_str = helpers::format_order_id(notification.order_id);
-    render_order_id_header(f, chunks[0], &order_id_str);
+    render_order_id_header(f, chunks[0], &order_id_str, theme);
 
-    render_message_preview(f, chunks[1], &notification.message_preview, true);
+    render_message_preview(f, chunks[1], &notification.message_preview, true, theme);
 
     let body_text = notification
         .body
@@ -664,7 +707,7 @@ fn render_waiting_phase_popup(
         .unwrap_or("Waiting for the counterparty. No action is required from you right now.");
     f.render_widget(
         Paragraph::new(body_text)
-            .style(Style::default().fg(Color::White))
+            .style(Style::default().fg(theme.text))
             .wrap(ratatui::widgets::Wrap { trim: true })
             .alignment(ratatui::layout::Alignment::Center),
         inset_horizontal(chunks[3], 2),
@@ -679,6 +722,7 @@ fn render_waiting_phase_popup(
         ),
         "Ok",
         "Cancel Order",
+        theme,
     );
 
     f.render_widget(
@@ -687,14 +731,14 @@ fn render_waiting_phase_popup(
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to confirm, ", Style::default()),
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to dismiss", Style::default()),
@@ -709,6 +753,7 @@ fn render_default_notification(
     f: &mut ratatui::Frame,
     popup: Rect,
     notification: &MessageNotification,
+    theme: &Theme,
 ) {
     let chunks = Layout::new(
         Direction::Vertical,
@@ -727,7 +772,7 @@ fn render_default_notification(
         Paragraph::new(Line::from(vec![Span::styled(
             order_id_str,
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -749,14 +794,14 @@ fn render_default_notification(
             Span::styled(
                 "Enter",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to view, ", Style::default()),
             Span::styled(
                 "Esc",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" to dismiss", Style::default()),
@@ -766,12 +811,48 @@ fn render_default_notification(
     );
 }
 
+/// Countdown for the popup's bottom border: time left to pay the hold/bond invoice or to send
+/// the buyer invoice. `None` for payout retries (Mostro keeps retrying, no hard deadline).
+fn deadline_border_title(
+    notification: &MessageNotification,
+    action: &mostro_core::prelude::Action,
+    mostro_info: Option<&MostroInstanceInfo>,
+    theme: &Theme,
+) -> Option<Line<'static>> {
+    if matches!(
+        notification.action,
+        mostro_core::prelude::Action::PaymentFailed
+    ) {
+        return None;
+    }
+    let deadline = popup_deadline(
+        action,
+        notification.timestamp,
+        notification.invoice.as_deref(),
+        mostro_info,
+    )?;
+    let (countdown, style) =
+        helpers::format_deadline(&deadline, chrono::Utc::now().timestamp(), theme);
+    Some(
+        Line::from(vec![
+            Span::styled(
+                format!(" ⏳ {}: ", deadline.kind.label()),
+                Style::default().fg(theme.secondary),
+            ),
+            Span::styled(format!("{countdown} "), style),
+        ])
+        .centered(),
+    )
+}
+
 /// Main function to render message notification popup
 pub fn render_message_notification(
     f: &mut ratatui::Frame,
     notification: &MessageNotification,
     action: mostro_core::prelude::Action,
     invoice_state: &InvoiceInputState,
+    mostro_info: Option<&MostroInstanceInfo>,
+    theme: &Theme,
 ) {
     let area = f.area();
     let (popup_width, popup_height) = match action {
@@ -796,6 +877,14 @@ pub fn render_message_notification(
     f.render_widget(Clear, popup);
 
     let title = match action {
+        mostro_core::prelude::Action::AddInvoice
+            if matches!(
+                notification.action,
+                mostro_core::prelude::Action::PaymentFailed
+            ) =>
+        {
+            tr("payment_failed.popup_title")
+        }
         mostro_core::prelude::Action::AddInvoice => "📝 Invoice Request",
         mostro_core::prelude::Action::AddBondInvoice => "⚔️ Bond Payout Invoice",
         mostro_core::prelude::Action::PayInvoice => "💳 Payment Request",
@@ -805,31 +894,34 @@ pub fn render_message_notification(
         _ => "📨 New Message",
     };
 
-    let block = Block::default()
+    let mut block = Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
+    if let Some(countdown) = deadline_border_title(notification, &action, mostro_info, theme) {
+        block = block.title_bottom(countdown);
+    }
     f.render_widget(block, popup);
 
     match action {
         mostro_core::prelude::Action::AddInvoice => {
-            render_add_invoice(f, popup, notification, invoice_state);
+            render_add_invoice(f, popup, notification, invoice_state, theme);
         }
         mostro_core::prelude::Action::AddBondInvoice => {
-            render_add_bond_invoice(f, popup, notification, invoice_state);
+            render_add_bond_invoice(f, popup, notification, invoice_state, theme);
         }
         mostro_core::prelude::Action::PayInvoice => {
-            render_pay_invoice(f, popup, notification, invoice_state);
+            render_pay_invoice(f, popup, notification, invoice_state, theme);
         }
         mostro_core::prelude::Action::PayBondInvoice => {
-            render_pay_bond_invoice(f, popup, notification, invoice_state);
+            render_pay_bond_invoice(f, popup, notification, invoice_state, theme);
         }
         mostro_core::prelude::Action::WaitingSellerToPay
         | mostro_core::prelude::Action::WaitingBuyerInvoice => {
-            render_waiting_phase_popup(f, popup, notification, invoice_state);
+            render_waiting_phase_popup(f, popup, notification, invoice_state, theme);
         }
         _ => {
-            render_default_notification(f, popup, notification);
+            render_default_notification(f, popup, notification, theme);
         }
     }
 }
@@ -1,12 +1,9 @@
-use ratatui::style::Color;
-
-pub const PRIMARY_COLOR: Color = Color::Rgb(177, 204, 51); // #b1cc33
-pub const BACKGROUND_COLOR: Color = Color::Rgb(29, 33, 44); // #1D212C
-
 pub mod admin_key_confirm;
 pub mod admin_state;
 pub(crate) mod app_state;
+pub mod backup_popup;
 pub(crate) mod chat;
+pub mod command_palette;
 pub mod constants;
 pub mod currencies;
 pub mod dispute_bond_slash_popup;
@@ -17,6 +14,8 @@ pub mod exit_confirm;
 pub mod generate_keys_popup;
 pub mod help_popup;
 pub mod helpers;
+pub mod hit_test;
+pub mod image_preview_popup;
 pub mod key_handler;
 pub mod key_input_popup;
 pub mod message_notification;
@@ -28,12 +27,18 @@ pub mod order_confirm;
 pub mod order_form;
 pub mod order_take;
 pub(crate) mod orders;
+pub mod profile_picker;
+pub mod retention_preview;
 pub mod save_attachment_popup;
+pub mod saved_search_alert;
 pub mod send_attachment_picker;
+pub mod settings_reload;
 pub mod startup_splash;
 pub mod state;
 pub mod status;
 pub mod tabs;
+pub mod terminal_graphics;
+pub mod theme;
 pub mod user_state;
 pub mod waiting;
 
@@ -45,9 +50,10 @@ pub use state::{
     AppState, BuyerInvoicePreference, ChatAttachment, ChatAttachmentType, ChatParty, ChatSender,
     DecodedChatMessage, DisputeChatMessage, DisputeFilter, FormState, InvoiceInputState,
     InvoiceNotificationActionSelection, KeyInputState, LnAddressVerifyResult, MessageNotification,
-    MessageViewState, MostroInfoFetchResult, OperationResult, OrderChatLastSeen,
+    MessageViewState, MostroInfoFetchResult, ObserverField, OperationResult, OrderChatLastSeen,
     OrderChatStaticHeader, OrderChatUpdate, OrderMessage, RatingOrderState, Tab, TakeOrderState,
     ThreeState, UiMode, UserChatChannel, UserChatSender, UserOrderChatMessage, UserRole, UserTab,
     ViewingMessageButtonSelection,
 };
+pub use theme::{Theme, ThemePreset};
 pub use user_state::UserMode;
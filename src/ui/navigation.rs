@@ -3,6 +3,8 @@ use std::str::FromStr;
 
 use ratatui::text::Line;
 
+use crate::i18n::tr;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum UserTab {
     Orders,
@@ -20,13 +22,13 @@ impl Display for UserTab {
             f,
             "{}",
             match self {
-                UserTab::Orders => "Orders",
-                UserTab::MyTrades => "Order Chat",
-                UserTab::Messages => "Messages",
-                UserTab::MostroInfo => "Mostro Info",
-                UserTab::Settings => "Settings",
-                UserTab::CreateNewOrder => "Create New Order",
-                UserTab::Exit => "Exit",
+                UserTab::Orders => tr("tabs.orders"),
+                UserTab::MyTrades => tr("tabs.my_trades"),
+                UserTab::Messages => tr("tabs.messages"),
+                UserTab::MostroInfo => tr("tabs.mostro_info"),
+                UserTab::Settings => tr("tabs.settings"),
+                UserTab::CreateNewOrder => tr("tabs.create_new_order"),
+                UserTab::Exit => tr("tabs.exit"),
             }
         )
     }
@@ -99,6 +101,7 @@ impl UserTab {
 pub enum AdminTab {
     DisputesPending,
     DisputesInProgress,
+    DisputeArchive,
     Observer,
     MostroInfo,
     Settings,
@@ -111,12 +114,13 @@ impl Display for AdminTab {
             f,
             "{}",
             match self {
-                AdminTab::DisputesPending => "Disputes Pending",
-                AdminTab::DisputesInProgress => "Disputes Management",
-                AdminTab::Observer => "Observer",
-                AdminTab::MostroInfo => "Mostro Info",
-                AdminTab::Settings => "Settings",
-                AdminTab::Exit => "Exit",
+                AdminTab::DisputesPending => tr("tabs.disputes_pending"),
+                AdminTab::DisputesInProgress => tr("tabs.disputes_management"),
+                AdminTab::DisputeArchive => tr("tabs.dispute_archive"),
+                AdminTab::Observer => tr("tabs.observer"),
+                AdminTab::MostroInfo => tr("tabs.mostro_info"),
+                AdminTab::Settings => tr("tabs.settings"),
+                AdminTab::Exit => tr("tabs.exit"),
             }
         )
     }
@@ -127,10 +131,11 @@ impl AdminTab {
         match index {
             0 => AdminTab::DisputesPending,
             1 => AdminTab::DisputesInProgress,
-            2 => AdminTab::Observer,
-            3 => AdminTab::MostroInfo,
-            4 => AdminTab::Settings,
-            5 => AdminTab::Exit,
+            2 => AdminTab::DisputeArchive,
+            3 => AdminTab::Observer,
+            4 => AdminTab::MostroInfo,
+            5 => AdminTab::Settings,
+            6 => AdminTab::Exit,
             _ => panic!("Invalid admin tab index: {}", index),
         }
     }
@@ -139,15 +144,16 @@ impl AdminTab {
         match self {
             AdminTab::DisputesPending => 0,
             AdminTab::DisputesInProgress => 1,
-            AdminTab::Observer => 2,
-            AdminTab::MostroInfo => 3,
-            AdminTab::Settings => 4,
-            AdminTab::Exit => 5,
+            AdminTab::DisputeArchive => 2,
+            AdminTab::Observer => 3,
+            AdminTab::MostroInfo => 4,
+            AdminTab::Settings => 5,
+            AdminTab::Exit => 6,
         }
     }
 
     pub fn count() -> usize {
-        6
+        7
     }
 
     pub fn first() -> Self {
@@ -162,7 +168,8 @@ impl AdminTab {
         match self {
             AdminTab::DisputesPending => AdminTab::DisputesPending,
             AdminTab::DisputesInProgress => AdminTab::DisputesPending,
-            AdminTab::Observer => AdminTab::DisputesInProgress,
+            AdminTab::DisputeArchive => AdminTab::DisputesInProgress,
+            AdminTab::Observer => AdminTab::DisputeArchive,
             AdminTab::MostroInfo => AdminTab::Observer,
             AdminTab::Settings => AdminTab::MostroInfo,
             AdminTab::Exit => AdminTab::Settings,
@@ -172,7 +179,8 @@ impl AdminTab {
     pub fn next(self) -> Self {
         match self {
             AdminTab::DisputesPending => AdminTab::DisputesInProgress,
-            AdminTab::DisputesInProgress => AdminTab::Observer,
+            AdminTab::DisputesInProgress => AdminTab::DisputeArchive,
+            AdminTab::DisputeArchive => AdminTab::Observer,
             AdminTab::Observer => AdminTab::MostroInfo,
             AdminTab::MostroInfo => AdminTab::Settings,
             AdminTab::Settings => AdminTab::Exit,
@@ -1,11 +1,11 @@
 use ratatui::layout::{Alignment, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 
-use crate::ui::{helpers, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::{helpers, Theme};
 
-pub fn render_offline_overlay(f: &mut ratatui::Frame, message: &str) {
+pub fn render_offline_overlay(f: &mut ratatui::Frame, message: &str, theme: &Theme) {
     let area = f.area();
     let width = 70u16.min(area.width.saturating_sub(2)).max(20);
     let height = 7u16.min(area.height.saturating_sub(2)).max(5);
@@ -16,7 +16,7 @@ pub fn render_offline_overlay(f: &mut ratatui::Frame, message: &str) {
     let block = Block::default()
         .title("⚠️ Offline")
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(Color::Yellow));
+        .style(Style::default().bg(theme.background).fg(theme.warning));
     f.render_widget(block, popup);
 
     let inner = Rect {
@@ -30,22 +30,22 @@ pub fn render_offline_overlay(f: &mut ratatui::Frame, message: &str) {
         Line::from(vec![Span::styled(
             message,
             Style::default()
-                .fg(Color::White)
-                .bg(BACKGROUND_COLOR)
+                .fg(theme.text)
+                .bg(theme.background)
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from(""),
         Line::from(vec![
-            Span::styled("Status: ", Style::default().fg(Color::Gray)),
+            Span::styled("Status: ", Style::default().fg(theme.secondary)),
             Span::styled(
                 "Retrying every 5s",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Tip: ", Style::default().fg(Color::Gray)),
+            Span::styled("Tip: ", Style::default().fg(theme.secondary)),
             Span::raw("restore internet; Mostrix will reconnect automatically."),
         ]),
     ]);
@@ -54,7 +54,7 @@ pub fn render_offline_overlay(f: &mut ratatui::Frame, message: &str) {
         Paragraph::new(text)
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true })
-            .style(Style::default().bg(BACKGROUND_COLOR)),
+            .style(Style::default().bg(theme.background)),
         inner,
     );
 }
@@ -78,10 +78,11 @@ mod tests {
 
     #[test]
     fn render_offline_overlay_shows_message_and_retry_hint() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_offline_overlay(f, "Relays unreachable right now"))
+            .draw(|f| render_offline_overlay(f, "Relays unreachable right now", theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Offline"));
@@ -1,11 +1,13 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
-use super::{OperationResult, BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::{OperationResult, Theme};
+use crate::i18n::{tr, tr_args};
 use crate::ui::helpers::create_centered_popup;
 use crate::ui::orders::OrderSuccess;
+use crate::util::clipboard::ClipboardBackend;
 
 /// Split on newlines, then wrap each paragraph at word boundaries.
 fn wrap_message_lines(message: &str, width: usize) -> Vec<Line<'static>> {
@@ -112,32 +114,33 @@ fn wrap_styled_fragments(fragments: &[(&str, Style)], width: usize) -> Vec<Line<
 /// visible on short terminals instead of being clipped.
 fn conversation_disclosure_lines(
     conv_hex: &str,
-    copied_to_clipboard: bool,
+    copied_to_clipboard: Option<ClipboardBackend>,
     inner_width: usize,
     compact: bool,
+    theme: &Theme,
 ) -> Vec<Line<'static>> {
     let mut lines = vec![Line::from(vec![Span::styled(
-        "Shared key (read-only grant for solvers):",
-        Style::default().fg(PRIMARY_COLOR),
+        tr("result.shared_key_label"),
+        Style::default().fg(theme.primary),
     )])];
     lines.extend(chunk_hex_lines(conv_hex, inner_width));
     lines.push(Line::from(""));
 
     lines.extend(styled_wrapped_lines(
-        "Disclose the Shared key only. Never share your signing key.",
+        tr("result.shared_key_warning"),
         inner_width,
-        Style::default().fg(Color::Yellow),
+        Style::default().fg(theme.warning),
     ));
     if !compact {
         lines.push(Line::from(""));
     }
 
-    if copied_to_clipboard {
+    if let Some(backend) = copied_to_clipboard {
         lines.extend(wrap_styled_fragments(
             &[(
-                "✓ Shared key copied to clipboard!",
+                &tr_args("result.shared_key_copied", &[("backend", backend.label())]),
                 Style::default()
-                    .fg(Color::Green)
+                    .fg(theme.positive)
                     .add_modifier(Modifier::BOLD),
             )],
             inner_width,
@@ -145,23 +148,20 @@ fn conversation_disclosure_lines(
     } else {
         lines.extend(wrap_styled_fragments(
             &[
-                ("Press", Style::default()),
+                (tr("result.press"), Style::default()),
                 (
                     "C",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
-                ("to copy the Shared key to clipboard.", Style::default()),
+                (tr("result.to_copy_shared_key"), Style::default()),
             ],
             inner_width,
         ));
     }
     lines.extend(wrap_styled_fragments(
-        &[(
-            "Press ESC or ENTER to close",
-            Style::default().fg(Color::DarkGray),
-        )],
+        &[(tr("result.close_hint"), Style::default().fg(theme.muted))],
         inner_width,
     ));
 
@@ -173,22 +173,25 @@ fn conversation_disclosure_lines(
 /// Returns the content lines and the popup height they need (content + borders).
 fn conversation_disclosure_layout(
     conv_hex: &str,
-    copied_to_clipboard: bool,
+    copied_to_clipboard: Option<ClipboardBackend>,
     inner_width: usize,
     max_height: u16,
+    theme: &Theme,
 ) -> (Vec<Line<'static>>, u16) {
-    let full = conversation_disclosure_lines(conv_hex, copied_to_clipboard, inner_width, false);
+    let full =
+        conversation_disclosure_lines(conv_hex, copied_to_clipboard, inner_width, false, theme);
     let full_height = full.len() as u16 + 2; // + top/bottom border
     if full_height <= max_height {
         return (full, full_height);
     }
 
-    let compact = conversation_disclosure_lines(conv_hex, copied_to_clipboard, inner_width, true);
+    let compact =
+        conversation_disclosure_lines(conv_hex, copied_to_clipboard, inner_width, true, theme);
     let compact_height = (compact.len() as u16 + 2).min(max_height);
     (compact, compact_height)
 }
 
-pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult) {
+pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult, theme: &Theme) {
     let area: Rect = f.area();
     let popup_width = 70u16.min(area.width.max(1));
     let inner_width = popup_width.saturating_sub(2).max(1) as usize;
@@ -202,6 +205,7 @@ pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult)
             *copied_to_clipboard,
             inner_width,
             area.height.max(1),
+            theme,
         ))
     } else {
         None
@@ -213,17 +217,26 @@ pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult)
         }
         OperationResult::PaymentRequestRequired { .. }
         | OperationResult::ObserverChatLoaded { .. }
-        | OperationResult::ObserverChatError { .. } => 8,
+        | OperationResult::ObserverChatAppended { .. }
+        | OperationResult::ObserverChatError { .. }
+        | OperationResult::ImagePreviewLoaded(_)
+        | OperationResult::ImagePreviewError { .. } => 8,
         OperationResult::Info(message) => info_popup_height(message, popup_width),
         OperationResult::Error(_)
         | OperationResult::InvoiceSubmitted { .. }
         | OperationResult::TradeClosed { .. }
         | OperationResult::OrderHistoryDeleted { .. }
         | OperationResult::MyTradesMakerBookChanged
+        | OperationResult::SavedSearchMatched(_)
+        | OperationResult::SettingsFileChanged(_)
         | OperationResult::OpenInvoicePopup { .. }
         | OperationResult::OrderChatAttachmentSent { .. }
         | OperationResult::OrderChatAttachmentSendFailed { .. }
-        | OperationResult::OrderChatAttachmentError { .. } => 8,
+        | OperationResult::OrderChatAttachmentReady(_)
+        | OperationResult::OrderChatAttachmentError { .. }
+        | OperationResult::RetentionPurged { .. }
+        | OperationResult::RetentionPreview(_)
+        | OperationResult::RestoreNeedsConfirmation(_) => 8,
     };
     // Clamp to the available area so the popup never exceeds narrow/short terminals.
     let popup = create_centered_popup(area, popup_width, popup_height);
@@ -246,9 +259,9 @@ pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult)
             ..
         }) => {
             let block = Block::default()
-                .title("✅ Order Created Successfully")
+                .title(tr("result.order_created"))
                 .borders(Borders::ALL)
-                .style(Style::default().bg(BACKGROUND_COLOR).fg(Color::Green));
+                .style(Style::default().bg(theme.background).fg(theme.positive));
 
             // Calculate inner area (excluding borders)
             let inner = block.inner(popup);
@@ -258,67 +271,70 @@ pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult)
 
             if let Some(id) = order_id {
                 lines.push(Line::from(vec![
-                    Span::styled("📋 Order ID: ", Style::default().fg(PRIMARY_COLOR)),
+                    Span::styled(tr("result.order_id"), Style::default().fg(theme.primary)),
                     Span::styled(id.to_string(), Style::default()),
                 ]));
             }
 
             if let Some(k) = kind {
                 lines.push(Line::from(vec![
-                    Span::styled("📈 Type: ", Style::default().fg(PRIMARY_COLOR)),
+                    Span::styled(tr("result.type"), Style::default().fg(theme.primary)),
                     Span::styled(format!("{:?}", k), Style::default()),
                 ]));
             }
 
             if *amount > 0 {
                 lines.push(Line::from(vec![
-                    Span::styled("💰 Amount: ", Style::default().fg(PRIMARY_COLOR)),
+                    Span::styled(tr("result.amount"), Style::default().fg(theme.primary)),
                     Span::styled(format!("{} sats", amount), Style::default()),
                 ]));
             } else {
                 lines.push(Line::from(vec![
-                    Span::styled("💰 Amount: ", Style::default().fg(PRIMARY_COLOR)),
-                    Span::styled("Market rate", Style::default()),
+                    Span::styled(tr("result.amount"), Style::default().fg(theme.primary)),
+                    Span::styled(tr("result.market_rate"), Style::default()),
                 ]));
             }
 
             if let (Some(min), Some(max)) = (min_amount, max_amount) {
                 lines.push(Line::from(vec![
-                    Span::styled("💵 Fiat Range: ", Style::default().fg(PRIMARY_COLOR)),
+                    Span::styled(tr("result.fiat_range"), Style::default().fg(theme.primary)),
                     Span::styled(format!("{}-{} {}", min, max, fiat_code), Style::default()),
                 ]));
             } else if *fiat_amount > 0 {
                 lines.push(Line::from(vec![
-                    Span::styled("💵 Fiat Amount: ", Style::default().fg(PRIMARY_COLOR)),
+                    Span::styled(tr("result.fiat_amount"), Style::default().fg(theme.primary)),
                     Span::styled(format!("{} {}", fiat_amount, fiat_code), Style::default()),
                 ]));
             }
 
             if !payment_method.is_empty() {
                 lines.push(Line::from(vec![
-                    Span::styled("💳 Payment Method: ", Style::default().fg(PRIMARY_COLOR)),
+                    Span::styled(
+                        tr("result.payment_method"),
+                        Style::default().fg(theme.primary),
+                    ),
                     Span::styled(payment_method.clone(), Style::default()),
                 ]));
             }
 
             if *premium != 0 {
                 lines.push(Line::from(vec![
-                    Span::styled("📈 Premium: ", Style::default().fg(PRIMARY_COLOR)),
+                    Span::styled(tr("result.premium"), Style::default().fg(theme.primary)),
                     Span::styled(format!("{}%", premium), Style::default()),
                 ]));
             }
 
             if let Some(s) = status {
                 lines.push(Line::from(vec![
-                    Span::styled("📊 Status: ", Style::default().fg(PRIMARY_COLOR)),
+                    Span::styled(tr("result.status"), Style::default().fg(theme.primary)),
                     Span::styled(format!("{:?}", s), Style::default()),
                 ]));
             }
 
             lines.push(Line::from(""));
             lines.push(Line::from(vec![Span::styled(
-                "Press ESC or ENTER to close",
-                Style::default().fg(Color::DarkGray),
+                tr("result.close_hint"),
+                Style::default().fg(theme.muted),
             )]));
 
             let content_height: u16 = lines.len().try_into().unwrap_or(inner.height);
@@ -338,9 +354,9 @@ pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult)
         }
         OperationResult::Error(error_msg) => {
             let block = Block::default()
-                .title("❌ Operation Failed")
+                .title(tr("result.failed"))
                 .borders(Borders::ALL)
-                .style(Style::default().bg(BACKGROUND_COLOR).fg(Color::Red));
+                .style(Style::default().bg(theme.background).fg(theme.negative));
 
             // Calculate inner area (excluding borders)
             let inner = block.inner(popup);
@@ -349,8 +365,8 @@ pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult)
             let mut lines = wrap_message_lines(error_msg, inner.width as usize);
             lines.push(Line::from(""));
             lines.push(Line::from(vec![Span::styled(
-                "Press ESC or ENTER to close",
-                Style::default().fg(Color::DarkGray),
+                tr("result.close_hint"),
+                Style::default().fg(theme.muted),
             )]));
 
             let paragraph = Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center);
@@ -361,9 +377,9 @@ pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult)
         | OperationResult::TradeClosed { message, .. }
         | OperationResult::OrderHistoryDeleted { message, .. } => {
             let block = Block::default()
-                .title("✅ Operation Successful")
+                .title(tr("result.success"))
                 .borders(Borders::ALL)
-                .style(Style::default().bg(BACKGROUND_COLOR).fg(Color::Green));
+                .style(Style::default().bg(theme.background).fg(theme.positive));
 
             // Calculate inner area (excluding borders)
             let inner = block.inner(popup);
@@ -372,8 +388,8 @@ pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult)
             let mut lines = wrap_message_lines(message, inner.width as usize);
             lines.push(Line::from(""));
             lines.push(Line::from(vec![Span::styled(
-                "Press ESC or ENTER to close",
-                Style::default().fg(Color::DarkGray),
+                tr("result.close_hint"),
+                Style::default().fg(theme.muted),
             )]));
 
             let paragraph = Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center);
@@ -381,9 +397,9 @@ pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult)
         }
         OperationResult::ConversationDisclosure { .. } => {
             let block = Block::default()
-                .title("✅ Operation Successful")
+                .title(tr("result.success"))
                 .borders(Borders::ALL)
-                .style(Style::default().bg(BACKGROUND_COLOR).fg(Color::Green));
+                .style(Style::default().bg(theme.background).fg(theme.positive));
 
             let inner = block.inner(popup);
             f.render_widget(block, popup);
@@ -397,29 +413,33 @@ pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult)
             let paragraph = Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center);
             f.render_widget(paragraph, inner);
         }
-        OperationResult::ObserverChatLoaded { .. } | OperationResult::ObserverChatError { .. } => {
+        OperationResult::ObserverChatLoaded { .. }
+        | OperationResult::ObserverChatAppended { .. }
+        | OperationResult::ObserverChatError { .. }
+        | OperationResult::ImagePreviewLoaded(_)
+        | OperationResult::ImagePreviewError { .. } => {
             // Handled directly in handle_operation_result, should not reach render
         }
         OperationResult::PaymentRequestRequired { .. } => {
             // This should not be displayed - it's converted to a notification in main.rs
             // But if it somehow reaches here, show a simple message
             let block = Block::default()
-                .title("💳 Payment Request")
+                .title(tr("result.payment_request"))
                 .borders(Borders::ALL)
-                .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+                .style(Style::default().bg(theme.background).fg(theme.primary));
 
             let inner = block.inner(popup);
             f.render_widget(block, popup);
 
             let lines = vec![
                 Line::from(vec![Span::styled(
-                    "Payment request received",
+                    tr("result.payment_request_received"),
                     Style::default(),
                 )]),
                 Line::from(""),
                 Line::from(vec![Span::styled(
-                    "Press ESC or ENTER to close",
-                    Style::default().fg(Color::DarkGray),
+                    tr("result.close_hint"),
+                    Style::default().fg(theme.muted),
                 )]),
             ];
 
@@ -427,10 +447,16 @@ pub fn render_operation_result(f: &mut ratatui::Frame, result: &OperationResult)
             f.render_widget(paragraph, inner);
         }
         OperationResult::MyTradesMakerBookChanged
+        | OperationResult::SavedSearchMatched(_)
+        | OperationResult::SettingsFileChanged(_)
         | OperationResult::OpenInvoicePopup { .. }
         | OperationResult::OrderChatAttachmentSent { .. }
         | OperationResult::OrderChatAttachmentSendFailed { .. }
-        | OperationResult::OrderChatAttachmentError { .. } => {}
+        | OperationResult::OrderChatAttachmentReady(_)
+        | OperationResult::OrderChatAttachmentError { .. }
+        | OperationResult::RetentionPurged { .. }
+        | OperationResult::RetentionPreview(_)
+        | OperationResult::RestoreNeedsConfirmation(_) => {}
     }
 }
 
@@ -458,7 +484,7 @@ mod tests {
         let backend = TestBackend::new(width, height);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_operation_result(f, result))
+            .draw(|f| render_operation_result(f, result, &Theme::dark()))
             .unwrap();
         terminal.backend().buffer().clone()
     }
@@ -467,7 +493,7 @@ mod tests {
     fn conversation_disclosure_shows_shared_key_and_copy_hint() {
         let result = OperationResult::ConversationDisclosure {
             conv_hex: "a".repeat(64),
-            copied_to_clipboard: false,
+            copied_to_clipboard: None,
         };
         let buf = render(&result);
         assert!(
@@ -500,12 +526,12 @@ mod tests {
     fn conversation_disclosure_shows_copied_confirmation() {
         let result = OperationResult::ConversationDisclosure {
             conv_hex: "a".repeat(64),
-            copied_to_clipboard: true,
+            copied_to_clipboard: Some(ClipboardBackend::Osc52),
         };
         let buf = render(&result);
         assert!(
-            buffer_contains(&buf, "Shared key copied to clipboard"),
-            "popup must confirm the Shared key was copied"
+            buffer_contains(&buf, "Shared key copied to clipboard (OSC 52)"),
+            "popup must confirm the Shared key was copied and name the backend"
         );
     }
 
@@ -521,7 +547,7 @@ mod tests {
         let conv_hex = "q".repeat(64);
         let result = OperationResult::ConversationDisclosure {
             conv_hex: conv_hex.clone(),
-            copied_to_clipboard: false,
+            copied_to_clipboard: None,
         };
         let (width, height) = (40, 12);
         let buf = render_at(&result, width, height);
@@ -566,7 +592,8 @@ mod tests {
 
     #[test]
     fn conversation_disclosure_layout_clamps_height_to_available_area() {
-        let (lines, height) = conversation_disclosure_layout(&"a".repeat(64), false, 38, 6);
+        let theme = &Theme::dark();
+        let (lines, height) = conversation_disclosure_layout(&"a".repeat(64), None, 38, 6, theme);
         assert!(
             height <= 6,
             "popup height must never exceed the available area"
@@ -579,8 +606,9 @@ mod tests {
 
     #[test]
     fn conversation_disclosure_layout_prefers_full_layout_when_it_fits() {
-        let (lines, height) = conversation_disclosure_layout(&"a".repeat(64), false, 38, 24);
-        let compact_lines = conversation_disclosure_lines(&"a".repeat(64), false, 38, true);
+        let theme = &Theme::dark();
+        let (lines, height) = conversation_disclosure_layout(&"a".repeat(64), None, 38, 24, theme);
+        let compact_lines = conversation_disclosure_lines(&"a".repeat(64), None, 38, true, theme);
         assert!(
             lines.len() > compact_lines.len(),
             "full layout (plenty of height) should keep the spacer line compact mode drops"
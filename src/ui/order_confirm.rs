@@ -1,12 +1,17 @@
 use ratatui::layout::{Constraint, Direction, Flex, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
-use super::{helpers, FormState, BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::{helpers, FormState, Theme};
 use crate::ui::currencies;
 
-pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_button: bool) {
+pub fn render_order_confirm(
+    f: &mut ratatui::Frame,
+    form: &FormState,
+    selected_button: bool,
+    theme: &Theme,
+) {
     let area = f.area();
     let popup_width = area.width.saturating_sub(area.width / 4);
     let popup_height = 20;
@@ -47,7 +52,7 @@ pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_b
     let block = Block::default()
         .title("📋 Order Confirmation")
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     f.render_widget(block, popup);
 
     // Title
@@ -69,7 +74,7 @@ pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_b
     f.render_widget(
         Paragraph::new(Line::from(vec![
             Span::raw("Order Type: "),
-            Span::styled(kind_str, Style::default().fg(PRIMARY_COLOR)),
+            Span::styled(kind_str, Style::default().fg(theme.primary)),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         inner_chunks[3],
@@ -81,7 +86,7 @@ pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_b
             Span::styled(
                 form.fiat_code.to_ascii_uppercase(),
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
@@ -93,7 +98,7 @@ pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_b
                         format!("  {name}")
                     }
                 },
-                Style::default().fg(Color::Gray),
+                Style::default().fg(theme.secondary),
             ),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -108,7 +113,7 @@ pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_b
     f.render_widget(
         Paragraph::new(Line::from(vec![
             Span::raw("Amount: "),
-            Span::styled(amount_str, Style::default().fg(PRIMARY_COLOR)),
+            Span::styled(amount_str, Style::default().fg(theme.primary)),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         inner_chunks[5],
@@ -125,7 +130,7 @@ pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_b
     f.render_widget(
         Paragraph::new(Line::from(vec![
             Span::raw("Fiat Amount: "),
-            Span::styled(fiat_str, Style::default().fg(PRIMARY_COLOR)),
+            Span::styled(fiat_str, Style::default().fg(theme.primary)),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         inner_chunks[6],
@@ -134,7 +139,7 @@ pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_b
     f.render_widget(
         Paragraph::new(Line::from(vec![
             Span::raw("Payment Method: "),
-            Span::styled(&form.payment_method, Style::default().fg(PRIMARY_COLOR)),
+            Span::styled(&form.payment_method, Style::default().fg(theme.primary)),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         inner_chunks[7],
@@ -145,7 +150,7 @@ pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_b
             Span::raw("Premium: "),
             Span::styled(
                 format!("{}%", form.premium),
-                Style::default().fg(PRIMARY_COLOR),
+                Style::default().fg(theme.primary),
             ),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -156,7 +161,7 @@ pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_b
         f.render_widget(
             Paragraph::new(Line::from(vec![
                 Span::raw("Invoice: "),
-                Span::styled(&form.invoice, Style::default().fg(PRIMARY_COLOR)),
+                Span::styled(&form.invoice, Style::default().fg(theme.primary)),
             ]))
             .alignment(ratatui::layout::Alignment::Center),
             inner_chunks[9],
@@ -172,14 +177,14 @@ pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_b
     f.render_widget(
         Paragraph::new(Line::from(vec![
             Span::raw("Expiration: "),
-            Span::styled(exp_str, Style::default().fg(PRIMARY_COLOR)),
+            Span::styled(exp_str, Style::default().fg(theme.primary)),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         inner_chunks[10],
     );
 
     // YES/NO buttons
-    helpers::render_yes_no_buttons(f, inner_chunks[11], selected_button, "✓ YES", "✗ NO");
+    helpers::render_yes_no_buttons(f, inner_chunks[11], selected_button, "✓ YES", "✗ NO", theme);
 
     // Help text: use Enter/Esc for confirmation
     helpers::render_help_text(
@@ -188,6 +193,7 @@ pub fn render_order_confirm(f: &mut ratatui::Frame, form: &FormState, selected_b
         "Press ",
         "Enter",
         " to confirm, Esc to cancel",
+        theme,
     );
 }
 
@@ -210,6 +216,7 @@ mod tests {
 
     #[test]
     fn render_buy_market_order_confirmation() {
+        let theme = &Theme::dark();
         let mut form = FormState::new_default_form();
         form.payment_method = "SEPA".to_string();
         form.fiat_amount = "100".to_string();
@@ -218,7 +225,7 @@ mod tests {
         let backend = TestBackend::new(100, 30);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_order_confirm(f, &form, true))
+            .draw(|f| render_order_confirm(f, &form, true, theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Order Confirmation"));
@@ -231,6 +238,7 @@ mod tests {
 
     #[test]
     fn render_sell_range_with_invoice() {
+        let theme = &Theme::dark();
         let mut form = FormState::new_default_form();
         form.kind = "sell".to_string();
         form.payment_method = "Cash".to_string();
@@ -243,7 +251,7 @@ mod tests {
         let backend = TestBackend::new(100, 30);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_order_confirm(f, &form, false))
+            .draw(|f| render_order_confirm(f, &form, false, theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Order Confirmation"));
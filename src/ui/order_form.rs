@@ -1,22 +1,22 @@
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{
     Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar,
     ScrollbarOrientation, ScrollbarState, Wrap,
 };
 
-use super::{FormState, BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::{FormState, Theme};
 use crate::ui::currencies::{filter_options, name_for, resolve_options};
 use crate::ui::orders::FormField;
+use crate::util::fiat::{group_thousands, FiatAmount};
+use crate::util::payment_methods::payment_methods;
 use crate::util::MostroInstanceInfo;
 
 /// Width of the label column inside the details panel.
 const LABEL_W: usize = 10;
 /// Column where the value strip starts (arrow/margin + label column + space).
 const VALUE_OFFSET: usize = LABEL_W + 2;
-/// Subtle fill for unfocused input strips (slightly lighter than the background).
-const FIELD_BG: Color = Color::Rgb(38, 44, 58);
 
 /// A single rendered field in the "Order details" panel.
 struct Row {
@@ -44,6 +44,7 @@ pub fn render_order_form(
     area: Rect,
     form: &FormState,
     info: Option<&MostroInstanceInfo>,
+    theme: &Theme,
 ) {
     let accepted: &[String] = info
         .map(|i| i.fiat_currencies_accepted.as_slice())
@@ -55,14 +56,14 @@ pub fn render_order_form(
         .title(Line::from(Span::styled(
             " ✨ Create New Order ",
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )))
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
     let inner = block.inner(area);
     f.render_widget(&block, area);
 
@@ -82,14 +83,20 @@ pub fn render_order_form(
     )
     .split(rows[0]);
 
-    let currency_row = render_details(f, top[0], form, accepted, min_amt, max_amt);
-    render_preview(f, top[1], form, accepted);
-    render_help(f, rows[1], form);
-    render_footer(f, rows[2]);
+    let (currency_row, payment_row) =
+        render_details(f, top[0], form, accepted, min_amt, max_amt, theme);
+    render_preview(f, top[1], form, accepted, theme);
+    render_help(f, rows[1], form, theme);
+    render_footer(f, rows[2], theme);
 
     if form.currency_picker.open {
         if let Some(anchor) = currency_row {
-            render_currency_dropdown(f, anchor, inner, form, accepted);
+            render_currency_dropdown(f, anchor, inner, form, accepted, theme);
+        }
+    }
+    if form.payment_picker.open {
+        if let Some(anchor) = payment_row {
+            render_payment_method_dropdown(f, anchor, inner, form, theme);
         }
     }
 }
@@ -103,8 +110,8 @@ enum Vis<'a> {
     Flex,
 }
 
-/// Render the "Order details" panel. Returns the Rect of the Currency row so the
-/// dropdown overlay can be anchored beneath it.
+/// Render the "Order details" panel. Returns the Rects of the Currency and Payment
+/// Method rows so their dropdown overlays can be anchored beneath them.
 fn render_details(
     f: &mut ratatui::Frame,
     area: Rect,
@@ -112,17 +119,18 @@ fn render_details(
     accepted: &[String],
     min_amt: Option<i64>,
     max_amt: Option<i64>,
-) -> Option<Rect> {
+    theme: &Theme,
+) -> (Option<Rect>, Option<Rect>) {
     let block = Block::default()
         .title(" Order details ")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
     let inner = block.inner(area);
     f.render_widget(&block, area);
 
-    let rows = build_rows(form);
+    let rows = build_rows(form, theme);
     let row_for = |field: FormField| rows.iter().find(|r| r.field == field).unwrap();
 
     let pricing: Vec<FormField> = if form.use_range {
@@ -185,6 +193,7 @@ fn render_details(
     let strip_width = (inner.width as usize).saturating_sub(VALUE_OFFSET + 1);
 
     let mut currency_row: Option<Rect> = None;
+    let mut payment_row: Option<Rect> = None;
     let mut cursor: Option<(Rect, usize, usize)> = None;
 
     for (item, chunk) in items.iter().zip(chunks.iter()) {
@@ -192,9 +201,9 @@ fn render_details(
             Vis::Flex | Vis::Spacer => {}
             Vis::Header(title, complete) => {
                 let head_color = if *complete {
-                    PRIMARY_COLOR
+                    theme.primary
                 } else {
-                    Color::Gray
+                    theme.secondary
                 };
                 let dashes = "─".repeat((chunk.width as usize).saturating_sub(title.len() + 3));
                 f.render_widget(
@@ -203,9 +212,9 @@ fn render_details(
                             format!(" {title} "),
                             Style::default().fg(head_color).add_modifier(Modifier::BOLD),
                         ),
-                        Span::styled(dashes, Style::default().fg(Color::DarkGray)),
+                        Span::styled(dashes, Style::default().fg(theme.muted)),
                     ]))
-                    .style(Style::default().bg(BACKGROUND_COLOR)),
+                    .style(Style::default().bg(theme.background)),
                     *chunk,
                 );
             }
@@ -216,11 +225,11 @@ fn render_details(
                         Span::styled(
                             text.clone(),
                             Style::default()
-                                .fg(Color::DarkGray)
+                                .fg(theme.muted)
                                 .add_modifier(Modifier::ITALIC),
                         ),
                     ]))
-                    .style(Style::default().bg(BACKGROUND_COLOR)),
+                    .style(Style::default().bg(theme.background)),
                     *chunk,
                 );
             }
@@ -229,9 +238,12 @@ fn render_details(
                 if row.field == FormField::Currency {
                     currency_row = Some(*chunk);
                 }
+                if row.field == FormField::PaymentMethod {
+                    payment_row = Some(*chunk);
+                }
                 f.render_widget(
-                    Paragraph::new(field_line(row, focused, strip_width, *status))
-                        .style(Style::default().bg(BACKGROUND_COLOR)),
+                    Paragraph::new(field_line(row, focused, strip_width, *status, theme))
+                        .style(Style::default().bg(theme.background)),
                     *chunk,
                 );
                 if focused && row.editable && !form.currency_picker.open {
@@ -246,22 +258,32 @@ fn render_details(
         f.set_cursor_position((x, chunk.y));
     }
 
-    currency_row
+    (currency_row, payment_row)
 }
 
 /// Build a single field line: focus arrow + `label` on the panel background,
 /// then the value on a tinted "input strip" (green when focused) padded to
 /// `strip_width`, with a trailing ✓/✗ status glyph.
-fn field_line(row: &Row, focused: bool, strip_width: usize, status: FieldStatus) -> Line<'static> {
+fn field_line(
+    row: &Row,
+    focused: bool,
+    strip_width: usize,
+    status: FieldStatus,
+    theme: &Theme,
+) -> Line<'static> {
     let arrow = if focused { "▸" } else { " " };
     let label_style = if focused {
         Style::default()
-            .fg(PRIMARY_COLOR)
+            .fg(theme.primary)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::Gray)
+        Style::default().fg(theme.secondary)
+    };
+    let strip_bg = if focused {
+        theme.primary
+    } else {
+        theme.field_bg
     };
-    let strip_bg = if focused { PRIMARY_COLOR } else { FIELD_BG };
 
     let mut spans = vec![Span::styled(
         format!("{arrow}{:<width$} ", row.label, width = LABEL_W),
@@ -273,7 +295,7 @@ fn field_line(row: &Row, focused: bool, strip_width: usize, status: FieldStatus)
         used += s.content.chars().count();
         let mut st = s.style.bg(strip_bg);
         if focused {
-            st = st.fg(Color::Black);
+            st = st.fg(theme.on_fill);
         }
         spans.push(Span::styled(s.content.clone(), st));
     }
@@ -287,11 +309,11 @@ fn field_line(row: &Row, focused: bool, strip_width: usize, status: FieldStatus)
     if let Some(g) = glyph {
         let ok = status == Some(true);
         let fg = if focused {
-            Color::Black
+            theme.on_fill
         } else if ok {
-            Color::Green
+            theme.positive
         } else {
-            Color::Red
+            theme.negative
         };
         spans.push(Span::styled(g, Style::default().fg(fg).bg(strip_bg)));
     }
@@ -299,14 +321,14 @@ fn field_line(row: &Row, focused: bool, strip_width: usize, status: FieldStatus)
     Line::from(spans)
 }
 
-fn build_rows(form: &FormState) -> Vec<Row> {
+fn build_rows(form: &FormState, theme: &Theme) -> Vec<Row> {
     let is_buy = form.kind.eq_ignore_ascii_case("buy");
 
     let type_val = {
         let (label, color) = if is_buy {
-            ("buy", Color::Green)
+            ("buy", theme.positive)
         } else {
-            ("sell", Color::Red)
+            ("sell", theme.negative)
         };
         Line::from(vec![
             Span::styled("● ", Style::default().fg(color)),
@@ -314,21 +336,21 @@ fn build_rows(form: &FormState) -> Vec<Row> {
                 label,
                 Style::default().fg(color).add_modifier(Modifier::BOLD),
             ),
-            Span::styled("   ⇄ Space", Style::default().fg(Color::DarkGray)),
+            Span::styled("   ⇄ Space", Style::default().fg(theme.muted)),
         ])
     };
 
     let currency_val = if form.currency_picker.open {
         Line::from(vec![
             Span::raw(form.currency_picker.filter.clone()),
-            Span::styled("▏", Style::default().fg(Color::DarkGray)),
+            Span::styled("▏", Style::default().fg(theme.muted)),
         ])
     } else {
         let trimmed = form.fiat_code.trim();
         if trimmed.is_empty() {
             Line::from(vec![
-                Span::styled("— none —", Style::default().fg(Color::DarkGray)),
-                Span::styled("   ▾ pick", Style::default().fg(Color::DarkGray)),
+                Span::styled("— none —", Style::default().fg(theme.muted)),
+                Span::styled("   ▾ pick", Style::default().fg(theme.muted)),
             ])
         } else {
             let code = trimmed.to_ascii_uppercase();
@@ -336,19 +358,16 @@ fn build_rows(form: &FormState) -> Vec<Row> {
             let mut spans = vec![Span::styled(
                 code,
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme.accent)
                     .add_modifier(Modifier::BOLD),
             )];
             if !name.is_empty() {
                 spans.push(Span::styled(
                     format!("  {name}"),
-                    Style::default().fg(Color::Gray),
+                    Style::default().fg(theme.secondary),
                 ));
             }
-            spans.push(Span::styled(
-                "   ▾ pick",
-                Style::default().fg(Color::DarkGray),
-            ));
+            spans.push(Span::styled("   ▾ pick", Style::default().fg(theme.muted)));
             Line::from(spans)
         }
     };
@@ -359,7 +378,7 @@ fn build_rows(form: &FormState) -> Vec<Row> {
         Line::from(Span::styled(
             "market",
             Style::default()
-                .fg(Color::DarkGray)
+                .fg(theme.muted)
                 .add_modifier(Modifier::ITALIC),
         ))
     } else {
@@ -394,9 +413,9 @@ fn build_rows(form: &FormState) -> Vec<Row> {
     ];
 
     let (tag, tag_color) = if form.use_range {
-        ("[Range] ", Color::Magenta)
+        ("[Range] ", theme.highlight)
     } else {
-        ("[Single] ", Color::Cyan)
+        ("[Single] ", theme.accent)
     };
     rows.push(Row {
         field: FormField::FiatAmount,
@@ -424,13 +443,29 @@ fn build_rows(form: &FormState) -> Vec<Row> {
         });
     }
 
+    let method_val = if form.payment_picker.open {
+        Line::from(vec![
+            Span::raw(form.payment_picker.filter.clone()),
+            Span::styled("▏", Style::default().fg(theme.muted)),
+        ])
+    } else if form.payment_method.trim().is_empty() {
+        Line::from(vec![
+            Span::styled("(any)", Style::default().fg(theme.muted)),
+            Span::styled("   ▾ pick", Style::default().fg(theme.muted)),
+        ])
+    } else {
+        Line::from(vec![
+            Span::raw(form.payment_method.replace(',', ", ")),
+            Span::styled("   ▾ pick", Style::default().fg(theme.muted)),
+        ])
+    };
     rows.push(Row {
         field: FormField::PaymentMethod,
         label: "Method",
-        value: dim_if_empty(&form.payment_method, "(any)"),
+        value: method_val,
         prefix_len: 0,
-        text_len: form.payment_method.len(),
-        editable: true,
+        text_len: 0,
+        editable: false,
     });
     rows.push(Row {
         field: FormField::Premium,
@@ -438,7 +473,7 @@ fn build_rows(form: &FormState) -> Vec<Row> {
         value: if form.focused == FormField::Premium {
             Line::from(form.premium.clone())
         } else {
-            premium_line(&form.premium)
+            premium_line(&form.premium, theme)
         },
         prefix_len: 0,
         text_len: form.premium.len(),
@@ -447,7 +482,7 @@ fn build_rows(form: &FormState) -> Vec<Row> {
     rows.push(Row {
         field: FormField::Invoice,
         label: "Invoice",
-        value: dim_if_empty(&form.invoice, "(optional)"),
+        value: dim_if_empty(&form.invoice, "(optional)", theme),
         prefix_len: 0,
         text_len: form.invoice.len(),
         editable: true,
@@ -458,7 +493,7 @@ fn build_rows(form: &FormState) -> Vec<Row> {
         value: if form.focused == FormField::ExpirationDays {
             Line::from(form.expiration_days.clone())
         } else {
-            expiry_line(&form.expiration_days)
+            expiry_line(&form.expiration_days, theme)
         },
         prefix_len: 0,
         text_len: form.expiration_days.len(),
@@ -468,17 +503,23 @@ fn build_rows(form: &FormState) -> Vec<Row> {
     rows
 }
 
-fn render_preview(f: &mut ratatui::Frame, area: Rect, form: &FormState, accepted: &[String]) {
+fn render_preview(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    form: &FormState,
+    accepted: &[String],
+    theme: &Theme,
+) {
     let block = Block::default()
         .title(" Live preview ")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
     let inner = block.inner(area);
     f.render_widget(&block, area);
 
-    let lines = build_preview_lines(form);
+    let lines = build_preview_lines(form, theme);
     let card_h = (lines.len() as u16 + 2).min(inner.height.saturating_sub(2));
 
     let split = Layout::new(
@@ -497,13 +538,13 @@ fn render_preview(f: &mut ratatui::Frame, area: Rect, form: &FormState, accepted
         .title(" Order ")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
     let card_inner = card.inner(split[0]);
     f.render_widget(card, split[0]);
     f.render_widget(
         Paragraph::new(lines)
-            .style(Style::default().fg(Color::White))
+            .style(Style::default().fg(theme.text))
             .wrap(Wrap { trim: true }),
         card_inner,
     );
@@ -511,30 +552,33 @@ fn render_preview(f: &mut ratatui::Frame, area: Rect, form: &FormState, accepted
     // Status dot beneath the card.
     let status = match validate(form, accepted) {
         PreviewStatus::Ready => Line::from(vec![
-            Span::styled("● ", Style::default().fg(Color::Green)),
-            Span::styled("ready to submit", Style::default().fg(Color::Green)),
+            Span::styled("● ", Style::default().fg(theme.positive)),
+            Span::styled("ready to submit", Style::default().fg(theme.positive)),
         ]),
         PreviewStatus::Missing(what) => Line::from(vec![
-            Span::styled("● ", Style::default().fg(Color::Yellow)),
-            Span::styled(format!("fill: {what}"), Style::default().fg(Color::Yellow)),
+            Span::styled("● ", Style::default().fg(theme.warning)),
+            Span::styled(format!("fill: {what}"), Style::default().fg(theme.warning)),
         ]),
         PreviewStatus::Invalid(why) => Line::from(vec![
-            Span::styled("● ", Style::default().fg(Color::Red)),
-            Span::styled(format!("invalid: {why}"), Style::default().fg(Color::Red)),
+            Span::styled("● ", Style::default().fg(theme.negative)),
+            Span::styled(
+                format!("invalid: {why}"),
+                Style::default().fg(theme.negative),
+            ),
         ]),
     };
     f.render_widget(
-        Paragraph::new(status).style(Style::default().bg(BACKGROUND_COLOR)),
+        Paragraph::new(status).style(Style::default().bg(theme.background)),
         split[2],
     );
 }
 
-fn build_preview_lines(form: &FormState) -> Vec<Line<'static>> {
+fn build_preview_lines(form: &FormState, theme: &Theme) -> Vec<Line<'static>> {
     let is_buy = form.kind.eq_ignore_ascii_case("buy");
     let (side, side_color) = if is_buy {
-        ("BUY", Color::Green)
+        ("BUY", theme.buy)
     } else {
-        ("SELL", Color::Red)
+        ("SELL", theme.sell)
     };
     let code = if form.fiat_code.trim().is_empty() {
         "—".to_string()
@@ -565,7 +609,7 @@ fn build_preview_lines(form: &FormState) -> Vec<Line<'static>> {
             Span::raw(sats),
         ]),
         Line::from(format!("for  {fiat}")),
-        premium_preview_line(&form.premium),
+        premium_preview_line(&form.premium, theme),
     ];
 
     let method = form.payment_method.trim();
@@ -578,10 +622,10 @@ fn build_preview_lines(form: &FormState) -> Vec<Line<'static>> {
     if let Some(price) = implied_price_per_btc(form) {
         lines.push(Line::from(""));
         lines.push(Line::from(vec![
-            Span::styled("≈ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("≈ ", Style::default().fg(theme.muted)),
             Span::styled(
                 format!("{}/BTC", group_thousands(&price.to_string())),
-                Style::default().fg(Color::Cyan),
+                Style::default().fg(theme.accent),
             ),
         ]));
     }
@@ -593,7 +637,7 @@ fn implied_price_per_btc(form: &FormState) -> Option<i64> {
         return None;
     }
     let sats = form.amount.trim().parse::<i64>().ok()?;
-    let fiat = form.fiat_amount.trim().parse::<f64>().ok()?;
+    let fiat = form_fiat(form, &form.fiat_amount)?.to_f64();
     if sats <= 0 || fiat <= 0.0 {
         return None;
     }
@@ -601,52 +645,54 @@ fn implied_price_per_btc(form: &FormState) -> Option<i64> {
     Some((fiat / btc).round() as i64)
 }
 
-fn render_help(f: &mut ratatui::Frame, area: Rect, form: &FormState) {
+fn render_help(f: &mut ratatui::Frame, area: Rect, form: &FormState, theme: &Theme) {
     let help_paragraph = Paragraph::new(build_field_help(form))
         .block(
             Block::default()
                 .title(" Field help ")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(PRIMARY_COLOR))
-                .style(Style::default().bg(BACKGROUND_COLOR)),
+                .border_style(Style::default().fg(theme.primary))
+                .style(Style::default().bg(theme.background)),
         )
-        .style(Style::default().fg(Color::Gray))
+        .style(Style::default().fg(theme.secondary))
         .wrap(Wrap { trim: true });
     f.render_widget(help_paragraph, area);
 }
 
-fn render_footer(f: &mut ratatui::Frame, area: Rect) {
+fn render_footer(f: &mut ratatui::Frame, area: Rect, theme: &Theme) {
     let hint = Paragraph::new(Line::from(vec![
         Span::styled(
             "Enter",
             Style::default()
-                .fg(Color::Green)
+                .fg(theme.positive)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" submit • "),
         Span::styled(
             "Tab",
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.warning)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" focus • "),
         Span::styled(
             "Space",
             Style::default()
-                .fg(Color::Magenta)
+                .fg(theme.highlight)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" toggle • "),
         Span::styled(
             "Esc",
-            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            Style::default()
+                .fg(theme.negative)
+                .add_modifier(Modifier::BOLD),
         ),
         Span::raw(" cancel"),
     ]))
     .alignment(Alignment::Center)
-    .style(Style::default().bg(BACKGROUND_COLOR));
+    .style(Style::default().bg(theme.background));
     f.render_widget(hint, area);
 }
 
@@ -656,6 +702,7 @@ fn render_currency_dropdown(
     bounds: Rect,
     form: &FormState,
     currencies: &[String],
+    theme: &Theme,
 ) {
     let options = resolve_options(currencies);
     let filtered = filter_options(&options, &form.currency_picker.filter);
@@ -691,8 +738,8 @@ fn render_currency_dropdown(
         .title(title)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
     let inner = block.inner(popup);
     f.render_widget(block, popup);
 
@@ -709,11 +756,8 @@ fn render_currency_dropdown(
             "  no match"
         };
         f.render_widget(
-            Paragraph::new(Span::styled(
-                empty_msg,
-                Style::default().fg(Color::DarkGray),
-            ))
-            .style(Style::default().bg(BACKGROUND_COLOR)),
+            Paragraph::new(Span::styled(empty_msg, Style::default().fg(theme.muted)))
+                .style(Style::default().bg(theme.background)),
             split[0],
         );
     } else {
@@ -735,11 +779,11 @@ fn render_currency_dropdown(
             .collect();
 
         let list = List::new(items)
-            .style(Style::default().fg(Color::White).bg(BACKGROUND_COLOR))
+            .style(Style::default().fg(theme.text).bg(theme.background))
             .highlight_style(
                 Style::default()
-                    .fg(Color::Black)
-                    .bg(PRIMARY_COLOR)
+                    .fg(theme.on_fill)
+                    .bg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol("› ");
@@ -759,9 +803,9 @@ fn render_currency_dropdown(
     f.render_widget(
         Paragraph::new(Span::styled(
             "type filter • ↑↓ move • Enter select • Esc close",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         ))
-        .style(Style::default().bg(BACKGROUND_COLOR)),
+        .style(Style::default().bg(theme.background)),
         split[1],
     );
 
@@ -770,6 +814,120 @@ fn render_currency_dropdown(
     f.set_cursor_position((caret_x, anchor.y));
 }
 
+fn render_payment_method_dropdown(
+    f: &mut ratatui::Frame,
+    anchor: Rect,
+    bounds: Rect,
+    form: &FormState,
+    theme: &Theme,
+) {
+    let catalog = payment_methods();
+    let options = catalog.suggestions(&form.fiat_code, &form.payment_picker.filter);
+    let selected = form
+        .payment_picker
+        .selected
+        .min(options.len().saturating_sub(1));
+
+    let x = anchor.x + VALUE_OFFSET as u16;
+    let max_width = (bounds.x + bounds.width).saturating_sub(x);
+    let width = 44u16.clamp(24, max_width.max(24)).min(max_width);
+    let content_rows = options.len().clamp(1, 8) as u16;
+    let height = content_rows + 3; // border (2) + hint (1)
+    let mut y = anchor.y + 1;
+    if y + height > bounds.y + bounds.height {
+        y = anchor.y.saturating_sub(height);
+    }
+    let popup = Rect {
+        x,
+        y,
+        width,
+        height: height.min(bounds.y + bounds.height - y),
+    };
+
+    f.render_widget(Clear, popup);
+
+    let code = form.fiat_code.trim().to_ascii_uppercase();
+    let block = Block::default()
+        .title(format!(" Payment methods for {code} ({}) ", options.len()))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let split = Layout::new(
+        Direction::Vertical,
+        [Constraint::Min(1), Constraint::Length(1)],
+    )
+    .split(inner);
+
+    if options.is_empty() {
+        let typed = catalog.canonical(&form.payment_picker.filter);
+        let empty_msg = if typed.is_empty() {
+            "  no suggestions — type a name".to_string()
+        } else {
+            format!("  no match — Enter adds \"{typed}\"")
+        };
+        f.render_widget(
+            Paragraph::new(Span::styled(empty_msg, Style::default().fg(theme.muted)))
+                .style(Style::default().bg(theme.background)),
+            split[0],
+        );
+    } else {
+        let items: Vec<ListItem> = options
+            .iter()
+            .map(|name| {
+                let chosen = catalog.list_contains(&form.payment_method, name);
+                let mark = if chosen { "[x] " } else { "[ ] " };
+                let style = if chosen {
+                    Style::default().add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(mark, Style::default().fg(theme.accent)),
+                    Span::styled(name.to_string(), style),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .style(Style::default().fg(theme.text).bg(theme.background))
+            .highlight_style(
+                Style::default()
+                    .fg(theme.on_fill)
+                    .bg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("› ");
+        let mut state = ListState::default().with_selected(Some(selected));
+        f.render_stateful_widget(list, split[0], &mut state);
+
+        if options.len() > split[0].height as usize {
+            let mut sb_state = ScrollbarState::new(options.len()).position(selected);
+            f.render_stateful_widget(
+                Scrollbar::default().orientation(ScrollbarOrientation::VerticalRight),
+                split[0],
+                &mut sb_state,
+            );
+        }
+    }
+
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            "type filter • Space toggle • Enter add • Esc close",
+            Style::default().fg(theme.muted),
+        ))
+        .style(Style::default().bg(theme.background)),
+        split[1],
+    );
+
+    let caret_x =
+        anchor.x + VALUE_OFFSET as u16 + form.payment_picker.filter.chars().count() as u16;
+    f.set_cursor_position((caret_x, anchor.y));
+}
+
 // ── validation & formatting helpers ──────────────────────────────────────────
 
 fn field_status(form: &FormState, field: FormField, accepted: &[String]) -> FieldStatus {
@@ -794,23 +952,21 @@ fn field_status(form: &FormState, field: FormField, accepted: &[String]) -> Fiel
                 Some(t.parse::<i64>().map(|n| n > 0).unwrap_or(false))
             }
         }
-        FormField::FiatAmount => {
-            let t = form.fiat_amount.trim();
-            if t.is_empty() {
-                Some(false)
-            } else {
-                Some(t.parse::<i64>().map(|n| n > 0).unwrap_or(false))
-            }
-        }
+        FormField::FiatAmount => Some(
+            form_fiat(form, &form.fiat_amount)
+                .is_some_and(|a| a.is_positive() && a.whole_units().is_some()),
+        ),
         FormField::FiatAmountMax => {
             if !form.use_range {
                 None
             } else {
                 match (
-                    form.fiat_amount.trim().parse::<i64>(),
-                    form.fiat_amount_max.trim().parse::<i64>(),
+                    form_fiat(form, &form.fiat_amount),
+                    form_fiat(form, &form.fiat_amount_max),
                 ) {
-                    (Ok(min), Ok(max)) if max > min => Some(true),
+                    (Some(min), Some(max)) if max > min && max.whole_units().is_some() => {
+                        Some(true)
+                    }
                     _ => Some(false),
                 }
             }
@@ -851,6 +1007,17 @@ fn limits_hint(min_amt: Option<i64>, max_amt: Option<i64>) -> Option<String> {
     }
 }
 
+/// A fiat field parsed with the form currency's decimals; `None` when empty or malformed.
+fn form_fiat(form: &FormState, raw: &str) -> Option<FiatAmount> {
+    FiatAmount::parse_for(raw, &form.fiat_code).ok()
+}
+
+/// Whether a fiat field holds a fractional amount, which `NewOrder` cannot carry.
+pub fn has_fractional_fiat(form: &FormState) -> bool {
+    let fractional = |raw: &str| form_fiat(form, raw).is_some_and(|a| a.whole_units().is_none());
+    fractional(&form.fiat_amount) || (form.use_range && fractional(&form.fiat_amount_max))
+}
+
 fn non_empty(s: &str, fallback: &'static str) -> String {
     let t = s.trim();
     if t.is_empty() {
@@ -860,32 +1027,12 @@ fn non_empty(s: &str, fallback: &'static str) -> String {
     }
 }
 
-fn group_thousands(raw: &str) -> String {
-    let trimmed = raw.trim();
-    let (sign, digits) = match trimmed.strip_prefix('-') {
-        Some(rest) => ("-", rest),
-        None => ("", trimmed),
-    };
-    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
-        return raw.to_string();
-    }
-    let mut out = String::new();
-    let n = digits.len();
-    for (i, ch) in digits.chars().enumerate() {
-        if i > 0 && (n - i) % 3 == 0 {
-            out.push(',');
-        }
-        out.push(ch);
-    }
-    format!("{sign}{out}")
-}
-
-fn dim_if_empty(value: &str, placeholder: &'static str) -> Line<'static> {
+fn dim_if_empty(value: &str, placeholder: &'static str, theme: &Theme) -> Line<'static> {
     if value.trim().is_empty() {
         Line::from(Span::styled(
             placeholder,
             Style::default()
-                .fg(Color::DarkGray)
+                .fg(theme.muted)
                 .add_modifier(Modifier::ITALIC),
         ))
     } else {
@@ -893,45 +1040,45 @@ fn dim_if_empty(value: &str, placeholder: &'static str) -> Line<'static> {
     }
 }
 
-fn premium_line(premium: &str) -> Line<'static> {
+fn premium_line(premium: &str, theme: &Theme) -> Line<'static> {
     match premium.trim().parse::<i64>() {
         Ok(0) => Line::from("0 %"),
         Ok(p) if p > 0 => Line::from(Span::styled(
             format!("+{p} %"),
-            Style::default().fg(Color::Green),
+            Style::default().fg(theme.positive),
         )),
         Ok(p) => Line::from(Span::styled(
             format!("{p} %"),
-            Style::default().fg(Color::Red),
+            Style::default().fg(theme.negative),
         )),
-        Err(_) => dim_if_empty(premium, "0 %"),
+        Err(_) => dim_if_empty(premium, "0 %", theme),
     }
 }
 
-fn expiry_line(days: &str) -> Line<'static> {
+fn expiry_line(days: &str, theme: &Theme) -> Line<'static> {
     match days.trim().parse::<i64>() {
         Ok(0) => Line::from(Span::styled(
             "0 (min 1 day)",
-            Style::default().fg(Color::Red),
+            Style::default().fg(theme.negative),
         )),
         Ok(1) => Line::from("1 day"),
         Ok(d) if d > 1 => Line::from(format!("{d} days")),
-        Ok(_) => Line::from(Span::styled("invalid", Style::default().fg(Color::Red))),
-        Err(_) => dim_if_empty(days, "1 day"),
+        Ok(_) => Line::from(Span::styled("invalid", Style::default().fg(theme.negative))),
+        Err(_) => dim_if_empty(days, "1 day", theme),
     }
 }
 
-fn premium_preview_line(premium: &str) -> Line<'static> {
+fn premium_preview_line(premium: &str, theme: &Theme) -> Line<'static> {
     match premium.trim().parse::<i64>() {
         Ok(0) => Line::from("@ no premium"),
         Ok(p) if p > 0 => Line::from(vec![
             Span::raw("@ "),
-            Span::styled(format!("+{p}%"), Style::default().fg(Color::Green)),
+            Span::styled(format!("+{p}%"), Style::default().fg(theme.positive)),
             Span::raw(" premium"),
         ]),
         Ok(p) => Line::from(vec![
             Span::raw("@ "),
-            Span::styled(format!("{p}%"), Style::default().fg(Color::Red)),
+            Span::styled(format!("{p}%"), Style::default().fg(theme.negative)),
             Span::raw(" discount"),
         ]),
         Err(_) => Line::from("@ ? premium"),
@@ -965,20 +1112,23 @@ fn validate(form: &FormState, accepted: &[String]) -> PreviewStatus {
     if form.fiat_amount.trim().is_empty() {
         return PreviewStatus::Missing("fiat amount".into());
     }
-    match form.fiat_amount.trim().parse::<i64>() {
-        Ok(n) if n > 0 => {}
-        Ok(_) => return PreviewStatus::Invalid("fiat amount".into()),
-        Err(_) => return PreviewStatus::Invalid("fiat amount".into()),
+    let fiat = match form_fiat(form, &form.fiat_amount) {
+        Some(fiat) if fiat.is_positive() => fiat,
+        _ => return PreviewStatus::Invalid("fiat amount".into()),
+    };
+    if fiat.whole_units().is_none() {
+        return PreviewStatus::Invalid("fiat amount (whole units only)".into());
     }
     if form.use_range {
-        match form.fiat_amount_max.trim().parse::<i64>() {
-            Ok(max) => {
-                let min = form.fiat_amount.trim().parse::<i64>().unwrap_or(0);
-                if max <= min {
-                    return PreviewStatus::Invalid("max must exceed min".into());
-                }
+        match form_fiat(form, &form.fiat_amount_max) {
+            Some(max) if max <= fiat => {
+                return PreviewStatus::Invalid("max must exceed min".into());
+            }
+            Some(max) if max.whole_units().is_none() => {
+                return PreviewStatus::Invalid("fiat max (whole units only)".into());
             }
-            Err(_) => return PreviewStatus::Missing("fiat max".into()),
+            Some(_) => {}
+            None => return PreviewStatus::Missing("fiat max".into()),
         }
     }
     if form.payment_method.trim().is_empty() {
@@ -1008,6 +1158,15 @@ fn build_field_help(form: &FormState) -> Vec<Line<'static>> {
             ),
         ];
     }
+    if form.payment_picker.open {
+        return vec![
+            Line::from("Payment methods"),
+            Line::from(
+                "Type to filter, ↑↓ to move, Space to toggle, Enter to add and close, \
+                 Esc to close. Unknown names (spaces allowed) are added as typed.",
+            ),
+        ];
+    }
     match form.focused {
         FormField::OrderType => vec![
             Line::from("Order Type"),
@@ -1023,7 +1182,10 @@ fn build_field_help(form: &FormState) -> Vec<Line<'static>> {
         ],
         FormField::FiatAmount => vec![
             Line::from("Fiat Amount"),
-            Line::from("Price in fiat. Space toggles a single amount or a range (e.g. 100-200)."),
+            Line::from(
+                "Price in fiat. Space toggles a single amount or a range (e.g. 100-200). \
+                 Decimals follow the currency (2 for EUR, 0 for JPY), but Mostro orders take whole units.",
+            ),
         ],
         FormField::FiatAmountMax if form.use_range => vec![
             Line::from("Fiat Amount (Max)"),
@@ -1031,7 +1193,9 @@ fn build_field_help(form: &FormState) -> Vec<Line<'static>> {
         ],
         FormField::PaymentMethod => vec![
             Line::from("Payment Method"),
-            Line::from("How you send/receive fiat. Spaces allowed (e.g. \"Bank transfer\")."),
+            Line::from(
+                "How you send/receive fiat. Enter or type to pick from the catalog; Space toggles, unknown names are added as typed.",
+            ),
         ],
         FormField::Premium => vec![
             Line::from("Premium (%)"),
@@ -1052,19 +1216,19 @@ fn build_field_help(form: &FormState) -> Vec<Line<'static>> {
     }
 }
 
-pub fn render_form_initializing(f: &mut ratatui::Frame, area: Rect) {
+pub fn render_form_initializing(f: &mut ratatui::Frame, area: Rect, theme: &Theme) {
     let block = Block::default()
         .title(Line::from(Span::styled(
             " ✨ Create New Order ",
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )))
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
     f.render_widget(block, area);
 }
 
@@ -1124,6 +1288,34 @@ mod tests {
         assert_eq!(field_status(&form, FormField::FiatAmount, &[]), Some(false));
     }
 
+    #[test]
+    fn validate_uses_currency_decimals_and_requires_whole_units() {
+        let mut form = ready_form();
+        form.fiat_code = "EUR".to_string();
+        form.fiat_amount = "12.505".to_string();
+        assert!(matches!(
+            validate(&form, &[]),
+            PreviewStatus::Invalid(ref s) if s == "fiat amount"
+        ));
+
+        form.fiat_amount = "12.50".to_string();
+        assert!(matches!(
+            validate(&form, &[]),
+            PreviewStatus::Invalid(ref s) if s.contains("whole units")
+        ));
+        assert_eq!(field_status(&form, FormField::FiatAmount, &[]), Some(false));
+
+        form.fiat_amount = "12.00".to_string();
+        assert!(matches!(validate(&form, &[]), PreviewStatus::Ready));
+
+        form.use_range = true;
+        form.fiat_amount_max = "12.0".to_string();
+        assert!(matches!(
+            validate(&form, &[]),
+            PreviewStatus::Invalid(ref s) if s.contains("max must exceed min")
+        ));
+    }
+
     #[test]
     fn validate_rejects_currency_outside_accepted_list() {
         let form = ready_form();
@@ -1,14 +1,15 @@
 use ratatui::layout::{Constraint, Direction, Flex, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::ui::helpers::format_premium;
+use crate::util::fiat::FiatAmount;
 
-use super::{TakeOrderState, BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::{hit_test, TakeOrderState, Theme};
 
 /// Renders the Take Order confirmation, using a compact layout on short terminals.
-pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
+pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState, theme: &Theme) {
     let area = f.area();
     let popup_width = area.width.saturating_sub(area.width / 4);
     // Adjust height based on whether it's a range order (needs input field and error)
@@ -41,12 +42,12 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
     let block = Block::default()
         .title("📥 Take Order")
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     let popup_inner = block.inner(popup);
     f.render_widget(block, popup);
 
     if compact {
-        render_compact_order_take(f, popup_inner, take_state);
+        render_compact_order_take(f, popup_inner, take_state, theme);
         return;
     }
 
@@ -99,7 +100,7 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
     f.render_widget(
         Paragraph::new(Line::from(vec![
             Span::raw("Order Type: "),
-            Span::styled(kind_str, Style::default().fg(PRIMARY_COLOR)),
+            Span::styled(kind_str, Style::default().fg(theme.primary)),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         inner_chunks[3],
@@ -110,7 +111,7 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
             Span::raw("Currency: "),
             Span::styled(
                 &take_state.order.fiat_code,
-                Style::default().fg(PRIMARY_COLOR),
+                Style::default().fg(theme.primary),
             ),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -118,20 +119,11 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
     );
 
     // Fiat amount - show range if applicable
-    let fiat_str = if take_state.is_range_order {
-        let min = take_state.order.min_amount.unwrap_or(0);
-        let max = take_state.order.max_amount.unwrap_or(0);
-        format!("{}-{} {}", min, max, take_state.order.fiat_code)
-    } else {
-        format!(
-            "{} {}",
-            take_state.order.fiat_amount, take_state.order.fiat_code
-        )
-    };
+    let fiat_str = take_fiat_text(take_state);
     f.render_widget(
         Paragraph::new(Line::from(vec![
             Span::raw("Fiat Amount: "),
-            Span::styled(fiat_str, Style::default().fg(PRIMARY_COLOR)),
+            Span::styled(fiat_str, Style::default().fg(theme.primary)),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         inner_chunks[5],
@@ -142,14 +134,14 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
             Span::raw("Payment Method: "),
             Span::styled(
                 &take_state.order.payment_method,
-                Style::default().fg(PRIMARY_COLOR),
+                Style::default().fg(theme.primary),
             ),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         inner_chunks[6],
     );
 
-    let (premium_text, premium_color) = format_premium(take_state.order.premium);
+    let (premium_text, premium_color) = format_premium(take_state.order.premium, theme);
     f.render_widget(
         Paragraph::new(Line::from(vec![
             Span::raw("Premium: "),
@@ -180,7 +172,7 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
                 Span::raw("Enter amount ("),
                 Span::styled(
                     format!("{}-{} {}", min, max, currency),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.warning),
                 ),
                 Span::raw("):"),
             ]))
@@ -197,11 +189,11 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
 
         // Determine border color based on validation
         let border_color = if take_state.validation_error.is_some() {
-            Color::Red
+            theme.negative
         } else if take_state.amount_input.is_empty() {
-            Color::Yellow
+            theme.warning
         } else {
-            Color::Green
+            theme.positive
         };
 
         // Create a smaller input box centered in the area
@@ -230,7 +222,7 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
             Paragraph::new(Line::from(vec![Span::styled(
                 &input_text,
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             )]))
             .alignment(ratatui::layout::Alignment::Center),
@@ -243,7 +235,9 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
             f.render_widget(
                 Paragraph::new(Line::from(vec![Span::styled(
                     format!("⚠️  {}", error_msg),
-                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    Style::default()
+                        .fg(theme.negative)
+                        .add_modifier(Modifier::BOLD),
                 )]))
                 .alignment(ratatui::layout::Alignment::Center),
                 error_chunk,
@@ -254,7 +248,7 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
 
     // YES/NO buttons - center them in the popup
     let button_area = inner_chunks[button_idx];
-    render_take_buttons(f, button_area, take_state.selected_button);
+    render_take_buttons(f, button_area, take_state.selected_button, theme);
 
     // Help text - comes after buttons and optional spacer
     let help_idx = if take_state.is_range_order {
@@ -270,14 +264,14 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
                 Span::styled(
                     "← →",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" to switch, ", Style::default()),
                 Span::styled(
                     "Enter",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" to confirm", Style::default()),
@@ -288,7 +282,12 @@ pub fn render_order_take(f: &mut ratatui::Frame, take_state: &TakeOrderState) {
     }
 }
 
-fn render_compact_order_take(f: &mut ratatui::Frame, area: Rect, take_state: &TakeOrderState) {
+fn render_compact_order_take(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    take_state: &TakeOrderState,
+    theme: &Theme,
+) {
     let [details_area, button_area] = Layout::vertical([
         Constraint::Min(0),
         Constraint::Length(3), // always reserve YES/NO controls
@@ -307,29 +306,17 @@ fn render_compact_order_take(f: &mut ratatui::Frame, area: Rect, take_state: &Ta
         constraints.push(Constraint::Length(1)); // compact amount input
     }
     let chunks = Layout::new(Direction::Vertical, constraints).split(details_area);
-    let fiat = if take_state.is_range_order {
-        format!(
-            "{}-{} {}",
-            take_state.order.min_amount.unwrap_or(0),
-            take_state.order.max_amount.unwrap_or(0),
-            take_state.order.fiat_code
-        )
-    } else {
-        format!(
-            "{} {}",
-            take_state.order.fiat_amount, take_state.order.fiat_code
-        )
-    };
+    let fiat = take_fiat_text(take_state);
     f.render_widget(
         Paragraph::new(Line::from(vec![
             Span::raw("Fiat: "),
-            Span::styled(fiat, Style::default().fg(PRIMARY_COLOR)),
+            Span::styled(fiat, Style::default().fg(theme.primary)),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         chunks[0],
     );
 
-    let (premium_text, premium_color) = format_premium(take_state.order.premium);
+    let (premium_text, premium_color) = format_premium(take_state.order.premium, theme);
     f.render_widget(
         Paragraph::new(Line::from(vec![
             Span::raw("Premium: "),
@@ -377,10 +364,24 @@ fn render_compact_order_take(f: &mut ratatui::Frame, area: Rect, take_state: &Ta
         );
     }
 
-    render_take_buttons(f, button_area, take_state.selected_button);
+    render_take_buttons(f, button_area, take_state.selected_button, theme);
 }
 
-fn render_take_buttons(f: &mut ratatui::Frame, area: Rect, selected_button: bool) {
+/// Fixed amount or `min-max` range with currency code, with the book's exact decimals.
+fn take_fiat_text(take_state: &TakeOrderState) -> String {
+    let fiat = take_state.order.fiat;
+    let code = &take_state.order.fiat_code;
+    if take_state.is_range_order {
+        let zero = FiatAmount::from_whole(0);
+        let min = fiat.min.unwrap_or(zero);
+        let max = fiat.max.unwrap_or(zero);
+        format!("{min}-{max} {code}")
+    } else {
+        format!("{} {code}", fiat.amount)
+    }
+}
+
+fn render_take_buttons(f: &mut ratatui::Frame, area: Rect, selected_button: bool, theme: &Theme) {
     let separator_width = 1;
     let button_width = ((area.width.saturating_sub(separator_width)) / 2).min(15);
     let total_button_width = (button_width * 2) + separator_width;
@@ -400,15 +401,16 @@ fn render_take_buttons(f: &mut ratatui::Frame, area: Rect, selected_button: bool
         ],
     )
     .split(centered_button_area);
+    hit_test::record_yes_no(button_chunks[0], button_chunks[2]);
 
     let yes_style = if selected_button {
         Style::default()
-            .bg(Color::Green)
-            .fg(Color::Black)
+            .bg(theme.positive)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
         Style::default()
-            .fg(Color::Green)
+            .fg(theme.positive)
             .add_modifier(Modifier::BOLD)
     };
     f.render_widget(
@@ -423,9 +425,9 @@ fn render_take_buttons(f: &mut ratatui::Frame, area: Rect, selected_button: bool
             "✓ YES",
             Style::default()
                 .fg(if selected_button {
-                    Color::Black
+                    theme.on_fill
                 } else {
-                    Color::Green
+                    theme.positive
                 })
                 .add_modifier(Modifier::BOLD),
         )]))
@@ -435,11 +437,13 @@ fn render_take_buttons(f: &mut ratatui::Frame, area: Rect, selected_button: bool
 
     let no_style = if !selected_button {
         Style::default()
-            .bg(Color::Red)
-            .fg(Color::Black)
+            .bg(theme.negative)
+            .fg(theme.on_fill)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        Style::default()
+            .fg(theme.negative)
+            .add_modifier(Modifier::BOLD)
     };
     f.render_widget(
         Block::default().borders(Borders::ALL).style(no_style),
@@ -453,9 +457,9 @@ fn render_take_buttons(f: &mut ratatui::Frame, area: Rect, selected_button: bool
             "✗ NO",
             Style::default()
                 .fg(if !selected_button {
-                    Color::Black
+                    theme.on_fill
                 } else {
-                    Color::Red
+                    theme.negative
                 })
                 .add_modifier(Modifier::BOLD),
         )]))
@@ -482,7 +486,12 @@ mod tests {
         flat.contains(needle)
     }
 
-    fn render_take_order(width: u16, height: u16, is_range_order: bool) -> ratatui::buffer::Buffer {
+    fn render_take_order(
+        width: u16,
+        height: u16,
+        is_range_order: bool,
+        theme: &Theme,
+    ) -> ratatui::buffer::Buffer {
         let backend = TestBackend::new(width, height);
         let mut terminal = Terminal::new(backend).unwrap();
         let take_state = TakeOrderState {
@@ -494,22 +503,24 @@ mod tests {
                 premium: -3,
                 payment_method: "SPEI".to_string(),
                 ..Default::default()
-            },
+            }
+            .into(),
             amount_input: String::new(),
             is_range_order,
             validation_error: None,
             selected_button: true,
         };
         terminal
-            .draw(|f| render_order_take(f, &take_state))
+            .draw(|f| render_order_take(f, &take_state, theme))
             .unwrap();
         terminal.backend().buffer().clone()
     }
 
     #[test]
     fn take_order_shows_premium_for_fixed_and_range_orders() {
+        let theme = &Theme::dark();
         for is_range_order in [false, true] {
-            let buf = render_take_order(100, 30, is_range_order);
+            let buf = render_take_order(100, 30, is_range_order, theme);
             assert!(buffer_contains(&buf, "Premium:"));
             assert!(buffer_contains(&buf, "-3%"));
             assert!(buffer_contains(&buf, "SPEI"));
@@ -520,8 +531,9 @@ mod tests {
 
     #[test]
     fn short_terminal_keeps_premium_and_actions_visible() {
+        let theme = &Theme::dark();
         for is_range_order in [false, true] {
-            let buf = render_take_order(60, 10, is_range_order);
+            let buf = render_take_order(60, 10, is_range_order, theme);
             assert!(buffer_contains(&buf, "Premium:"));
             assert!(buffer_contains(&buf, "-3%"));
             assert!(buffer_contains(&buf, "YES"));
@@ -4,12 +4,14 @@ use mostro_core::prelude::*;
 use nostr_sdk::prelude::*;
 use ratatui::style::{Color, Style};
 
+use crate::i18n::{tr, tr_args};
 use crate::ui::constants::{
     BUY_ORDER_FLOW_STEPS_MAKER, BUY_ORDER_FLOW_STEPS_TAKER, GENERIC_ORDER_FLOW_STEPS_TAKER,
     SELL_ORDER_FLOW_STEPS_MAKER, SELL_ORDER_FLOW_STEPS_TAKER,
     VIEW_MESSAGE_BUYER_TOOK_ORDER_PREVIEW, VIEW_MESSAGE_HOLD_INVOICE_PREVIEW,
 };
-use crate::ui::PRIMARY_COLOR;
+use crate::ui::Theme;
+use crate::util::fiat::BookOrder;
 
 pub use crate::ui::constants::StepLabel;
 
@@ -160,7 +162,12 @@ pub enum OperationResult {
     /// Observer chat loaded successfully from relays.
     ObserverChatLoaded {
         generation: u64,
-        messages: Vec<crate::ui::chat::DisputeChatMessage>,
+        chat: crate::util::chat_utils::ObserverChat,
+    },
+    /// New Observer messages from the live subscription.
+    ObserverChatAppended {
+        generation: u64,
+        chat: crate::util::chat_utils::ObserverChat,
     },
     /// Observer chat fetch failed.
     ObserverChatError {
@@ -177,8 +184,20 @@ pub enum OperationResult {
         deleted_order_ids: Vec<uuid::Uuid>,
         message: String,
     },
+    /// Scheduled retention pass removed these trades (no UI popup).
+    RetentionPurged {
+        deleted_order_ids: Vec<uuid::Uuid>,
+    },
+    /// Settings → Data Retention preview computed; opens the purge confirmation.
+    RetentionPreview(Box<crate::util::RetentionPlan>),
+    /// Backup verified and staged, but the live database is newer; asks before restoring.
+    RestoreNeedsConfirmation(Box<crate::util::backup::StagedRestore>),
     /// Rebuild [`crate::ui::AppState::my_trades_maker_book`] from SQLite (no UI popup).
     MyTradesMakerBookChanged,
+    /// A live book order matched a saved search; queued as an alert popup.
+    SavedSearchMatched(Box<crate::util::saved_searches::SavedSearchMatch>),
+    /// `settings.toml` changed on disk and validated; held until the user confirms the reload.
+    SettingsFileChanged(Box<crate::util::settings_reload::SettingsFileChange>),
     /// Open invoice / waiting popup from a synchronous execute reply (e.g. bond payout DM).
     OpenInvoicePopup {
         notification: MessageNotification,
@@ -195,6 +214,8 @@ pub enum OperationResult {
         order_id: String,
         error: String,
     },
+    /// Attachment read and preprocessed; opens the size/privacy confirmation popup.
+    OrderChatAttachmentReady(Box<crate::util::PendingOrderAttachment>),
     /// Blossom upload succeeded but order-chat DM failed; prepared payload kept for retry.
     OrderChatAttachmentSendFailed {
         prepared: crate::ui::helpers::PreparedOrderChatAttachment,
@@ -207,7 +228,15 @@ pub enum OperationResult {
     /// The signing key itself is never disclosed.
     ConversationDisclosure {
         conv_hex: String,
-        copied_to_clipboard: bool,
+        /// Backend that copied the Shared key (`None` until copied).
+        copied_to_clipboard: Option<crate::util::clipboard::ClipboardBackend>,
+    },
+    /// Chat image preview decoded; shown if the preview popup for that URL is still open.
+    ImagePreviewLoaded(std::sync::Arc<crate::util::image_preview::PreviewImage>),
+    /// Chat image preview failed (download, decrypt or decode).
+    ImagePreviewError {
+        blossom_url: String,
+        message: String,
     },
 }
 
@@ -298,20 +327,29 @@ pub struct CurrencyPicker {
     pub selected: usize, // index into the *filtered* option list
 }
 
+/// State for the payment method multi-select on the Payment Method field.
+#[derive(Clone, Debug, Default)]
+pub struct PaymentMethodPicker {
+    pub open: bool,      // whether the dropdown overlay is visible
+    pub filter: String,  // typed query used to narrow the suggestions
+    pub selected: usize, // index into the *filtered* suggestion list
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct FormState {
-    pub kind: String,                    // buy | sell
-    pub fiat_code: String,               // e.g. USD, EUR, ARS
-    pub fiat_amount: String,             // numeric (single amount or min for range)
-    pub fiat_amount_max: String,         // max amount for range (optional)
-    pub amount: String,                  // amount in sats (0 for market)
-    pub payment_method: String,          // comma separated
-    pub premium: String,                 // premium percentage
-    pub invoice: String,                 // optional invoice
-    pub expiration_days: String,         // expiration days (minimum 1)
-    pub focused: FormField,              // which field is focused
-    pub use_range: bool,                 // whether to use fiat range
-    pub currency_picker: CurrencyPicker, // searchable currency dropdown state
+    pub kind: String,                        // buy | sell
+    pub fiat_code: String,                   // e.g. USD, EUR, ARS
+    pub fiat_amount: String,                 // numeric (single amount or min for range)
+    pub fiat_amount_max: String,             // max amount for range (optional)
+    pub amount: String,                      // amount in sats (0 for market)
+    pub payment_method: String,              // comma separated
+    pub premium: String,                     // premium percentage
+    pub invoice: String,                     // optional invoice
+    pub expiration_days: String,             // expiration days (minimum 1)
+    pub focused: FormField,                  // which field is focused
+    pub use_range: bool,                     // whether to use fiat range
+    pub currency_picker: CurrencyPicker,     // searchable currency dropdown state
+    pub payment_picker: PaymentMethodPicker, // payment method multi-select state
 }
 
 impl FormState {
@@ -331,7 +369,7 @@ impl FormState {
 
 #[derive(Clone, Debug)]
 pub struct TakeOrderState {
-    pub order: SmallOrder,
+    pub order: BookOrder,
     pub amount_input: String, // For range orders: the amount user wants to take
     pub is_range_order: bool, // Whether this is a range order (has min/max)
     pub validation_error: Option<String>, // Error message if amount is invalid
@@ -420,7 +458,8 @@ pub fn invoice_popup_allowed_for_order_status(
                     | mostro_core::order::Status::WaitingMakerBond
             ) | None
         ),
-        Action::AddInvoice => matches!(
+        // `payment-failed` reuses the AddInvoice popup to submit a replacement payout invoice.
+        Action::AddInvoice | Action::PaymentFailed => matches!(
             order_status,
             Some(
                 mostro_core::order::Status::WaitingBuyerInvoice
@@ -458,6 +497,8 @@ pub fn local_user_must_act_on_invoice_popup(msg: &OrderMessage, popup_action: &A
             _ => false,
         },
         (_, Action::AddBondInvoice) => true,
+        // Mostro only sends `payment-failed` to the buyer whose payout failed.
+        (_, Action::PaymentFailed) => true,
         (None, Action::PayInvoice) => msg
             .buyer_invoice
             .as_ref()
@@ -575,7 +616,7 @@ pub struct InvoiceInputState {
     pub invoice_input: String,
     pub focused: bool,
     pub just_pasted: bool, // Flag to ignore Enter immediately after paste
-    pub copied_to_clipboard: bool, // Flag to show "Copied!" message
+    pub copied_to_clipboard: Option<crate::util::clipboard::ClipboardBackend>, // Backend for the "Copied!" message
     /// Vertical scroll offset for long invoice display (PayInvoice popup).
     pub scroll_y: u16,
     /// Selected action in AddInvoice/PayInvoice popup.
@@ -680,8 +721,8 @@ pub fn order_message_to_notification(msg: &OrderMessage) -> MessageNotification
         Action::FiatSentOk => "Fiat payment completed",
         Action::WaitingBuyerInvoice => "Waiting for Buyer to Add Invoice",
         Action::WaitingSellerToPay => "Waiting for Seller to Pay",
-        Action::HoldInvoicePaymentAccepted => VIEW_MESSAGE_HOLD_INVOICE_PREVIEW,
-        Action::BuyerTookOrder => VIEW_MESSAGE_BUYER_TOOK_ORDER_PREVIEW,
+        Action::HoldInvoicePaymentAccepted => tr(VIEW_MESSAGE_HOLD_INVOICE_PREVIEW),
+        Action::BuyerTookOrder => tr(VIEW_MESSAGE_BUYER_TOOK_ORDER_PREVIEW),
         Action::Cancel => "Cancel",
         Action::CooperativeCancelInitiatedByPeer => "Peer requested cooperative cancel",
         Action::Canceled => "Order canceled",
@@ -692,18 +733,20 @@ pub fn order_message_to_notification(msg: &OrderMessage) -> MessageNotification
         Action::Rate => "Rate Counterparty",
         Action::RateReceived | Action::PurchaseCompleted => "Rate Counterparty completed",
         Action::Release | Action::Released => "Release",
+        Action::PaymentFailed => tr("payment_failed.preview"),
+        Action::InvoiceUpdated => "Payout invoice updated",
+        Action::HoldInvoicePaymentCanceled => "Seller's hold invoice was canceled",
         _ => "Message",
     };
 
-    let body = if matches!(action, Action::AddBondInvoice) {
-        match inner_message_kind.payload.as_ref() {
-            Some(Payload::BondPayoutRequest(req)) => {
-                Some(bond_payout_notification_body(req.slashed_at))
-            }
-            _ => None,
+    let body = match (&action, inner_message_kind.payload.as_ref()) {
+        (Action::AddBondInvoice, Some(Payload::BondPayoutRequest(req))) => {
+            Some(bond_payout_notification_body(req.slashed_at))
         }
-    } else {
-        None
+        (Action::PaymentFailed, Some(Payload::PaymentFailed(info))) => {
+            Some(payment_failed_notification_body(info))
+        }
+        _ => None,
     };
     let solver_pubkey = match (&action, inner_message_kind.payload.as_ref()) {
         (Action::AdminTookDispute, Some(Payload::Peer(peer))) => Some(peer.pubkey.clone()),
@@ -734,6 +777,32 @@ fn bond_payout_notification_body(slashed_at: i64) -> String {
     format!("Slash recorded: {anchor}. Claim deadline = anchor + instance payout window.")
 }
 
+/// Retry schedule from a `payment-failed` payload, e.g. `Mostro retries 3 times, every 1 min.`
+pub fn payment_failed_notification_body(info: &PaymentFailedInfo) -> String {
+    let attempts = info.payment_attempts;
+    let interval = info.payment_retries_interval;
+    let every = if interval >= 60 && interval.is_multiple_of(60) {
+        tr_args(
+            "payment_failed.every_minutes",
+            &[("n", &(interval / 60).to_string())],
+        )
+    } else {
+        tr_args(
+            "payment_failed.every_seconds",
+            &[("n", &interval.to_string())],
+        )
+    };
+    let key = if attempts == 1 {
+        "payment_failed.retries_one"
+    } else {
+        "payment_failed.retries_other"
+    };
+    tr_args(
+        key,
+        &[("attempts", &attempts.to_string()), ("every", &every)],
+    )
+}
+
 /// Short, UI-friendly action label for the messages sidebar.
 pub fn message_action_compact_label(action: &Action) -> &'static str {
     match action {
@@ -759,6 +828,9 @@ pub fn message_action_compact_label(action: &Action) -> &'static str {
         Action::CooperativeCancelInitiatedByPeer => "Cooperative Cancel Initiated by Peer",
         Action::CooperativeCancelInitiatedByYou => "Cooperative Cancel Initiated by You",
         Action::NewOrder => "New Order Created",
+        Action::PaymentFailed => "Payout Failed",
+        Action::InvoiceUpdated => "Payout Invoice Updated",
+        Action::HoldInvoicePaymentCanceled => "Hold Invoice Canceled",
         _ => "Unknown Message",
     }
 }
@@ -806,6 +878,9 @@ pub fn message_action_emoji(action: &Action) -> &'static str {
         Action::RateReceived => "🌟",
         Action::CooperativeCancelInitiatedByPeer | Action::CooperativeCancelInitiatedByYou => "✋",
         Action::NewOrder => "🆕",
+        Action::PaymentFailed => "⚠️",
+        Action::InvoiceUpdated => "🧾",
+        Action::HoldInvoicePaymentCanceled => "❌",
         _ => "✉️",
     }
 }
@@ -834,25 +909,25 @@ pub fn message_action_emoji_for_message(msg: &OrderMessage) -> &'static str {
 
 /// Emoji + color badge summarizing the overall order status (header card, sidebar accents).
 /// `None` (status not yet hydrated) renders the same as an active/in-progress trade.
-pub fn order_status_badge(status: Option<Status>) -> (&'static str, Color) {
+pub fn order_status_badge(status: Option<Status>, theme: &Theme) -> (&'static str, Color) {
     match status {
-        Some(Status::Success) => ("✅", Color::Green),
-        Some(Status::SettledByAdmin) | Some(Status::CompletedByAdmin) => ("✅", Color::Green),
+        Some(Status::Success) => ("✅", theme.positive),
+        Some(Status::SettledByAdmin) | Some(Status::CompletedByAdmin) => ("✅", theme.positive),
         Some(Status::Canceled)
         | Some(Status::CanceledByAdmin)
-        | Some(Status::CooperativelyCanceled) => ("❌", Color::Red),
-        Some(Status::Expired) => ("⌛", Color::DarkGray),
-        Some(Status::Dispute) => ("⚖️", Color::Magenta),
-        Some(Status::FiatSent) => ("💸", PRIMARY_COLOR),
+        | Some(Status::CooperativelyCanceled) => ("❌", theme.negative),
+        Some(Status::Expired) => ("⌛", theme.muted),
+        Some(Status::Dispute) => ("⚖️", theme.highlight),
+        Some(Status::FiatSent) => ("💸", theme.primary),
         Some(Status::Pending)
         | Some(Status::WaitingPayment)
         | Some(Status::WaitingBuyerInvoice)
         | Some(Status::WaitingTakerBond)
-        | Some(Status::WaitingMakerBond) => ("⏳", Color::Yellow),
+        | Some(Status::WaitingMakerBond) => ("⏳", theme.warning),
         Some(Status::InProgress)
         | Some(Status::Active)
         | Some(Status::SettledHoldInvoice)
-        | None => ("💬", PRIMARY_COLOR),
+        | None => ("💬", theme.primary),
     }
 }
 
@@ -912,15 +987,15 @@ pub struct MessageStatusPresentation {
 /// Prefer persisted [`OrderMessage::order_status`], then fall back to the last
 /// DM action. Actionable invoice phases get explicit "do X" copy; waiting phases
 /// reuse [`waiting_phase_description`]; terminal/warning states omit Next.
-pub fn message_status_presentation(msg: &OrderMessage) -> MessageStatusPresentation {
+pub fn message_status_presentation(msg: &OrderMessage, theme: &Theme) -> MessageStatusPresentation {
     let action = msg.message.get_inner_message_kind().action.clone();
     if let Some(text) = message_timeline_warning_for_order_status(msg.order_status)
         .or_else(|| message_timeline_warning(&action))
     {
         let (emoji, color) = if text.contains("dispute") {
-            ("⚖️", Color::Magenta)
+            ("⚖️", theme.highlight)
         } else {
-            ("❌", Color::Red)
+            ("❌", theme.negative)
         };
         return MessageStatusPresentation {
             emoji,
@@ -936,14 +1011,14 @@ pub fn message_status_presentation(msg: &OrderMessage) -> MessageStatusPresentat
                 return MessageStatusPresentation {
                     emoji: "⭐",
                     title: "Trade completed",
-                    color: Color::Green,
+                    color: theme.positive,
                     next: Some("Rate your counterparty."),
                 };
             }
             return MessageStatusPresentation {
                 emoji: "✅",
                 title: "Trade completed",
-                color: Color::Green,
+                color: theme.positive,
                 next: None,
             };
         }
@@ -951,7 +1026,7 @@ pub fn message_status_presentation(msg: &OrderMessage) -> MessageStatusPresentat
             return MessageStatusPresentation {
                 emoji: "✅",
                 title: "Trade settled by admin",
-                color: Color::Green,
+                color: theme.positive,
                 next: None,
             };
         }
@@ -967,13 +1042,14 @@ pub fn message_status_presentation(msg: &OrderMessage) -> MessageStatusPresentat
             Action::Release | Action::FiatSentOk => ("🔓", "Release required"),
             Action::FiatSent | Action::HoldInvoicePaymentAccepted => ("💸", "Confirm fiat sent"),
             Action::CooperativeCancelInitiatedByPeer => ("⚠️", "Cancel requested"),
+            Action::PaymentFailed => ("⚠️", tr("payment_failed.status_title")),
             Action::Rate => ("⭐", "Rating required"),
             _ => ("👉", "Action required"),
         };
         return MessageStatusPresentation {
             emoji,
             title,
-            color: PRIMARY_COLOR,
+            color: theme.primary,
             next: Some(next),
         };
     }
@@ -981,7 +1057,7 @@ pub fn message_status_presentation(msg: &OrderMessage) -> MessageStatusPresentat
     MessageStatusPresentation {
         emoji: "✅",
         title: "Trade is on the normal path",
-        color: Color::Green,
+        color: theme.positive,
         next: Some(waiting_phase_description(msg)),
     }
 }
@@ -1039,6 +1115,10 @@ fn actionable_next_step(msg: &OrderMessage, action: &Action) -> Option<&'static
         Action::CooperativeCancelInitiatedByPeer => {
             Some("Your counterparty asked to cooperatively cancel — press Enter to review.")
         }
+        // Mostro keeps retrying on its own; Enter opens the replacement-invoice popup.
+        Action::PaymentFailed => {
+            Some("Mostro could not pay your invoice — press Enter to submit a new one.")
+        }
         Action::Rate => Some("Rate your counterparty."),
         _ => None,
     }
@@ -1231,9 +1311,13 @@ fn listing_step_from_status(kind: mostro_core::order::Kind, status: Status) -> O
             }
             // `WaitingTakerBond` / `WaitingMakerBond`: pre-active bond phases; treat like `Pending`.
             Status::WaitingPayment => Some(FlowStep::BuyFlowStep(StepLabelsBuy::StepSellerPayment)),
-            Status::WaitingBuyerInvoice | Status::SettledHoldInvoice => {
+            Status::WaitingBuyerInvoice => {
                 Some(FlowStep::BuyFlowStep(StepLabelsBuy::StepBuyerInvoice))
             }
+            // Seller released; the buyer payout is pending (or being retried after a failure).
+            Status::SettledHoldInvoice => {
+                Some(FlowStep::BuyFlowStep(StepLabelsBuy::StepReleaseSats))
+            }
             Status::InProgress | Status::Active => {
                 Some(FlowStep::BuyFlowStep(StepLabelsBuy::StepChatActiveOrder))
             }
@@ -1260,9 +1344,13 @@ fn listing_step_from_status(kind: mostro_core::order::Kind, status: Status) -> O
             Status::WaitingPayment => {
                 Some(FlowStep::SellFlowStep(StepLabelsSell::StepSellerPayment))
             }
-            Status::WaitingBuyerInvoice | Status::SettledHoldInvoice => {
+            Status::WaitingBuyerInvoice => {
                 Some(FlowStep::SellFlowStep(StepLabelsSell::StepBuyerInvoice))
             }
+            // Seller released; the buyer payout is pending (or being retried after a failure).
+            Status::SettledHoldInvoice => {
+                Some(FlowStep::SellFlowStep(StepLabelsSell::StepReleaseSats))
+            }
             Status::InProgress | Status::Active => {
                 Some(FlowStep::SellFlowStep(StepLabelsSell::StepChatActiveOrder))
             }
@@ -1435,6 +1523,7 @@ pub fn message_timeline_warning(action: &Action) -> Option<&'static str> {
     match action {
         Action::Canceled => Some("Trade canceled"),
         Action::AdminCanceled => Some("Trade canceled by admin"),
+        Action::HoldInvoicePaymentCanceled => Some("Trade canceled: hold invoice canceled"),
         Action::Dispute | Action::DisputeInitiatedByYou | Action::DisputeInitiatedByPeer => {
             Some("Trade in dispute state")
         }
@@ -1458,16 +1547,17 @@ pub fn message_timeline_warning_for_order_status(
 }
 
 /// Apply color coding to order kind cells (adapted for ratatui)
-pub fn apply_kind_color(kind: &mostro_core::order::Kind) -> Style {
+pub fn apply_kind_color(kind: &mostro_core::order::Kind, theme: &Theme) -> Style {
     match kind {
-        mostro_core::order::Kind::Buy => Style::default().fg(Color::Green),
-        mostro_core::order::Kind::Sell => Style::default().fg(Color::Red),
+        mostro_core::order::Kind::Buy => Style::default().fg(theme.buy),
+        mostro_core::order::Kind::Sell => Style::default().fg(theme.sell),
     }
 }
 
 #[cfg(test)]
 mod message_emoji_and_badge_tests {
     use super::*;
+    use crate::ui::Theme;
 
     #[test]
     fn add_invoice_and_pay_invoice_have_distinct_emoji() {
@@ -1570,62 +1660,71 @@ mod message_emoji_and_badge_tests {
 
     #[test]
     fn success_status_badge_is_green_check() {
+        let theme = &Theme::dark();
         assert_eq!(
-            order_status_badge(Some(Status::Success)),
+            order_status_badge(Some(Status::Success), theme),
             ("✅", Color::Green)
         );
     }
 
     #[test]
     fn cancel_variants_badge_as_red_cross() {
+        let theme = &Theme::dark();
         assert_eq!(
-            order_status_badge(Some(Status::Canceled)),
+            order_status_badge(Some(Status::Canceled), theme),
             ("❌", Color::Red)
         );
         assert_eq!(
-            order_status_badge(Some(Status::CanceledByAdmin)),
+            order_status_badge(Some(Status::CanceledByAdmin), theme),
             ("❌", Color::Red)
         );
         assert_eq!(
-            order_status_badge(Some(Status::CooperativelyCanceled)),
+            order_status_badge(Some(Status::CooperativelyCanceled), theme),
             ("❌", Color::Red)
         );
     }
 
     #[test]
     fn waiting_phases_badge_as_yellow_hourglass() {
+        let theme = &Theme::dark();
         assert_eq!(
-            order_status_badge(Some(Status::WaitingBuyerInvoice)),
+            order_status_badge(Some(Status::WaitingBuyerInvoice), theme),
             ("⏳", Color::Yellow)
         );
         assert_eq!(
-            order_status_badge(Some(Status::WaitingMakerBond)),
+            order_status_badge(Some(Status::WaitingMakerBond), theme),
             ("⏳", Color::Yellow)
         );
     }
 
     #[test]
     fn dispute_status_badges_as_magenta_scales() {
+        let theme = &Theme::dark();
         assert_eq!(
-            order_status_badge(Some(Status::Dispute)),
+            order_status_badge(Some(Status::Dispute), theme),
             ("⚖️", Color::Magenta)
         );
     }
 
     #[test]
     fn admin_settled_badge_is_green_check_not_dispute_scales() {
+        let theme = &Theme::dark();
         assert_eq!(
-            order_status_badge(Some(Status::SettledByAdmin)),
+            order_status_badge(Some(Status::SettledByAdmin), theme),
             ("✅", Color::Green)
         );
     }
 
     #[test]
     fn none_status_defaults_to_active_chat_badge() {
-        assert_eq!(order_status_badge(None), ("💬", PRIMARY_COLOR));
+        let theme = &Theme::dark();
+        assert_eq!(
+            order_status_badge(None, theme),
+            ("💬", Theme::dark().primary)
+        );
         assert_eq!(
-            order_status_badge(Some(Status::InProgress)),
-            ("💬", PRIMARY_COLOR)
+            order_status_badge(Some(Status::InProgress), theme),
+            ("💬", Theme::dark().primary)
         );
     }
 
@@ -1666,19 +1765,21 @@ mod message_emoji_and_badge_tests {
 
     #[test]
     fn status_presentation_buy_maker_add_invoice_is_actionable() {
+        let theme = &Theme::dark();
         let m = sample_msg(
             Action::AddInvoice,
             Some(mostro_core::order::Kind::Buy),
             Some(true),
             Some(Status::WaitingBuyerInvoice),
         );
-        let p = message_status_presentation(&m);
+        let p = message_status_presentation(&m, theme);
         assert_eq!(p.title, "Invoice required");
         assert_eq!(p.next, Some("Add your Lightning invoice to continue."));
     }
 
     #[test]
     fn status_presentation_hold_invoice_accepted_is_actionable() {
+        let theme = &Theme::dark();
         // Mostro reports the hold invoice paid; the Enter router opens the
         // fiat-confirmation popup, so STATUS must prompt the buyer to act rather
         // than fall through to the "no action required" waiting default.
@@ -1688,7 +1789,7 @@ mod message_emoji_and_badge_tests {
             Some(true),
             Some(Status::SettledHoldInvoice),
         );
-        let p = message_status_presentation(&m);
+        let p = message_status_presentation(&m, theme);
         assert_eq!(p.emoji, "💸");
         assert_eq!(p.title, "Confirm fiat sent");
         assert_eq!(
@@ -1699,6 +1800,7 @@ mod message_emoji_and_badge_tests {
 
     #[test]
     fn status_presentation_cooperative_cancel_peer_is_actionable() {
+        let theme = &Theme::dark();
         // Peer-initiated cooperative cancel: Enter opens a decision popup, so STATUS
         // must surface it rather than showing the generic waiting default.
         let m = sample_msg(
@@ -1707,7 +1809,7 @@ mod message_emoji_and_badge_tests {
             Some(true),
             Some(Status::Active),
         );
-        let p = message_status_presentation(&m);
+        let p = message_status_presentation(&m, theme);
         assert_eq!(p.emoji, "⚠️");
         assert_eq!(p.title, "Cancel requested");
         assert_eq!(
@@ -1718,6 +1820,7 @@ mod message_emoji_and_badge_tests {
 
     #[test]
     fn status_presentation_buyer_took_order_is_informational_waiting() {
+        let theme = &Theme::dark();
         // "Order taken" is a waiting phase (the actionable prompt arrives later), so
         // it should read as normal-path waiting with specific copy, not "must act".
         let m = sample_msg(
@@ -1726,7 +1829,7 @@ mod message_emoji_and_badge_tests {
             Some(true),
             Some(Status::Active),
         );
-        let p = message_status_presentation(&m);
+        let p = message_status_presentation(&m, theme);
         assert_eq!(p.title, "Trade is on the normal path");
         assert_eq!(
             p.next,
@@ -1736,13 +1839,14 @@ mod message_emoji_and_badge_tests {
 
     #[test]
     fn status_presentation_success_has_no_waiting_callout() {
+        let theme = &Theme::dark();
         let m = sample_msg(
             Action::Released,
             Some(mostro_core::order::Kind::Buy),
             Some(true),
             Some(Status::Success),
         );
-        let p = message_status_presentation(&m);
+        let p = message_status_presentation(&m, theme);
         assert_eq!(p.title, "Trade completed");
         assert!(p.next.is_none());
     }
@@ -2402,6 +2506,73 @@ mod invoice_popup_role_tests {
         ));
     }
 
+    #[test]
+    fn payment_failed_lands_on_release_column_and_reopens_invoice_popup() {
+        let theme = &Theme::dark();
+        let mut m = sample_order_message(
+            Action::PaymentFailed,
+            Some(mostro_core::order::Kind::Sell),
+            Some(false),
+            Some(Status::SettledHoldInvoice),
+        );
+        m.message = Message::new_order(
+            Some(uuid::Uuid::new_v4()),
+            None,
+            None,
+            Action::PaymentFailed,
+            Some(Payload::PaymentFailed(PaymentFailedInfo {
+                payment_attempts: 3,
+                payment_retries_interval: 60,
+            })),
+        );
+        assert_eq!(
+            message_trade_timeline_step(&m),
+            FlowStep::SellFlowStep(StepLabelsSell::StepReleaseSats)
+        );
+        assert!(invoice_popup_allowed_for_order_status(
+            &Action::PaymentFailed,
+            m.order_status
+        ));
+        assert!(local_user_must_act_on_invoice_popup(
+            &m,
+            &Action::PaymentFailed
+        ));
+
+        let notification = order_message_to_notification(&m);
+        assert_eq!(
+            notification.message_preview,
+            "Payment to your invoice failed"
+        );
+        assert_eq!(
+            notification.body.as_deref(),
+            Some("Mostro retries 3 times, every 1 min. Submit a new invoice or wait.")
+        );
+        assert_eq!(
+            message_status_presentation(&m, theme).title,
+            "Payout failed"
+        );
+    }
+
+    #[test]
+    fn payment_failed_body_falls_back_to_seconds() {
+        let body = payment_failed_notification_body(&PaymentFailedInfo {
+            payment_attempts: 1,
+            payment_retries_interval: 90,
+        });
+        assert_eq!(
+            body,
+            "Mostro retries 1 time, every 90 s. Submit a new invoice or wait."
+        );
+    }
+
+    #[test]
+    fn hold_invoice_payment_canceled_warns_in_the_timeline() {
+        assert_eq!(
+            message_timeline_warning(&Action::HoldInvoicePaymentCanceled),
+            Some("Trade canceled: hold invoice canceled")
+        );
+    }
+
     /// Unknown role must not be treated as maker for buy AddInvoice (would block maker popup).
     #[test]
     fn buy_maker_unknown_role_does_not_act_on_add_invoice_via_taker_default() {
@@ -0,0 +1,266 @@
+//! Startup profile switcher, shown before the database opens when named profiles exist
+//! (or with a bare `--profile`).
+
+use std::io::stdout;
+
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Alignment, Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Terminal;
+
+use crate::i18n::tr;
+use crate::profile::{validate_profile_name, DEFAULT_PROFILE};
+use crate::ui::helpers::create_centered_popup;
+use crate::ui::Theme;
+
+const PICKER_WIDTH: u16 = 60;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProfilePickerOutcome {
+    Pending,
+    Open(String),
+    Quit,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProfilePickerState {
+    /// `default` first, then the named profiles.
+    pub profiles: Vec<String>,
+    pub selected: usize,
+    /// Name being typed after `n`; `None` while browsing the list.
+    pub new_name: Option<String>,
+    pub error: Option<String>,
+}
+
+impl ProfilePickerState {
+    pub fn new(named: Vec<String>) -> Self {
+        let mut profiles = vec![DEFAULT_PROFILE.to_string()];
+        profiles.extend(named);
+        Self {
+            profiles,
+            selected: 0,
+            new_name: None,
+            error: None,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> ProfilePickerOutcome {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return ProfilePickerOutcome::Quit;
+        }
+        if let Some(name) = &mut self.new_name {
+            match key.code {
+                KeyCode::Esc => {
+                    self.new_name = None;
+                    self.error = None;
+                }
+                KeyCode::Backspace => {
+                    name.pop();
+                    self.error = None;
+                }
+                KeyCode::Char(c) => {
+                    name.push(c);
+                    self.error = None;
+                }
+                KeyCode::Enter => match validate_profile_name(name) {
+                    Ok(()) => return ProfilePickerOutcome::Open(name.clone()),
+                    Err(e) => self.error = Some(e.to_string()),
+                },
+                _ => {}
+            }
+            return ProfilePickerOutcome::Pending;
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return ProfilePickerOutcome::Quit,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1).min(self.profiles.len() - 1);
+            }
+            KeyCode::Char('n') => self.new_name = Some(String::new()),
+            KeyCode::Enter => {
+                return ProfilePickerOutcome::Open(self.profiles[self.selected].clone());
+            }
+            _ => {}
+        }
+        ProfilePickerOutcome::Pending
+    }
+}
+
+pub fn render_profile_picker(f: &mut ratatui::Frame, state: &ProfilePickerState, theme: &Theme) {
+    let height = state.profiles.len() as u16 + 9;
+    let popup = create_centered_popup(f.area(), PICKER_WIDTH, height);
+    f.render_widget(
+        Block::default().style(Style::default().bg(theme.background)),
+        f.area(),
+    );
+    f.render_widget(Clear, popup);
+    let block = Block::default()
+        .title(Span::styled(
+            tr("profile.picker_title"),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(theme.background));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let [body, input, hint] = Layout::vertical([
+        Constraint::Min(1),
+        Constraint::Length(3),
+        Constraint::Length(1),
+    ])
+    .areas(inner);
+
+    let mut lines = vec![Line::from("")];
+    for (idx, name) in state.profiles.iter().enumerate() {
+        let selected = idx == state.selected && state.new_name.is_none();
+        let style = if selected {
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        lines.push(Line::styled(
+            format!("{}{name}", if selected { "▶ " } else { "  " }),
+            style,
+        ));
+    }
+    f.render_widget(Paragraph::new(lines), body);
+
+    let input_lines = match &state.new_name {
+        Some(name) => vec![
+            Line::from(vec![
+                Span::styled(
+                    format!("{} ", tr("profile.new_name")),
+                    Style::default().fg(theme.primary),
+                ),
+                Span::styled(
+                    format!("{name}▏"),
+                    Style::default().fg(theme.primary).bg(theme.input_bg),
+                ),
+            ]),
+            Line::styled(
+                state.error.clone().unwrap_or_default(),
+                Style::default().fg(theme.negative),
+            ),
+        ],
+        None => vec![Line::styled(
+            tr("profile.new_hint"),
+            Style::default().fg(theme.muted),
+        )],
+    };
+    f.render_widget(Paragraph::new(input_lines).wrap(Wrap { trim: true }), input);
+    let hint_text = if state.new_name.is_some() {
+        tr("profile.create_hint")
+    } else {
+        tr("profile.picker_hint")
+    };
+    f.render_widget(
+        Paragraph::new(Line::styled(hint_text, Style::default().fg(theme.muted)))
+            .alignment(Alignment::Center),
+        hint,
+    );
+}
+
+/// Runs the picker on its own alternate screen; `None` when the user quits.
+///
+/// Drawn with the default palette: no profile (and so no `theme` setting) is loaded yet.
+pub fn run_profile_picker(named: Vec<String>) -> anyhow::Result<Option<String>> {
+    let theme = &Theme::default();
+    let mut state = ProfilePickerState::new(named);
+    enable_raw_mode()?;
+    execute!(stdout(), EnterAlternateScreen)?;
+    let result = (|| -> anyhow::Result<Option<String>> {
+        let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+        loop {
+            terminal.draw(|f| render_profile_picker(f, &state, theme))?;
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match state.handle_key(key) {
+                    ProfilePickerOutcome::Pending => {}
+                    ProfilePickerOutcome::Open(name) => return Ok(Some(name)),
+                    ProfilePickerOutcome::Quit => return Ok(None),
+                }
+            }
+        }
+    })();
+    execute!(stdout(), LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+
+    fn press(state: &mut ProfilePickerState, code: KeyCode) -> ProfilePickerOutcome {
+        state.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn picker_selects_existing_or_creates_new_profile() {
+        let mut state = ProfilePickerState::new(vec!["desk".to_string(), "solver".to_string()]);
+        press(&mut state, KeyCode::Down);
+        press(&mut state, KeyCode::Down);
+        press(&mut state, KeyCode::Down);
+        assert_eq!(
+            press(&mut state, KeyCode::Enter),
+            ProfilePickerOutcome::Open("solver".to_string())
+        );
+
+        press(&mut state, KeyCode::Char('n'));
+        for c in "Bad".chars() {
+            press(&mut state, KeyCode::Char(c));
+        }
+        assert_eq!(
+            press(&mut state, KeyCode::Enter),
+            ProfilePickerOutcome::Pending
+        );
+        assert!(state.error.is_some());
+        for _ in 0..3 {
+            press(&mut state, KeyCode::Backspace);
+        }
+        for c in "trader".chars() {
+            press(&mut state, KeyCode::Char(c));
+        }
+        assert_eq!(
+            press(&mut state, KeyCode::Enter),
+            ProfilePickerOutcome::Open("trader".to_string())
+        );
+
+        state.new_name = None;
+        assert_eq!(press(&mut state, KeyCode::Esc), ProfilePickerOutcome::Quit);
+    }
+
+    #[test]
+    fn picker_lists_default_first() {
+        let theme = &Theme::dark();
+        let state = ProfilePickerState::new(vec!["desk".to_string()]);
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal
+            .draw(|f| render_profile_picker(f, &state, theme))
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        let text: String = (0..buf.area.height)
+            .flat_map(|y| (0..buf.area.width).map(move |x| (x, y)))
+            .map(|pos| buf[pos].symbol().to_string())
+            .collect();
+        let default_at = text.find("▶ default").unwrap();
+        assert!(text[default_at..].contains("desk"));
+    }
+}
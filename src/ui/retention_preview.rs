@@ -0,0 +1,195 @@
+//! Settings → Data Retention: preview of what a purge removes, with Purge / Cancel buttons.
+
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::i18n::{tr, tr_args};
+use crate::ui::helpers::{create_centered_popup, render_yes_no_buttons};
+use crate::ui::send_attachment_picker::format_file_size;
+use crate::ui::Theme;
+use crate::util::{RetentionPlan, RetentionPolicy};
+
+const PREVIEW_POPUP_WIDTH: u16 = 76;
+/// Trades and files listed before collapsing the rest into "… and N more".
+const PREVIEW_MAX_ITEMS: usize = 8;
+
+fn days_label(days: u32) -> String {
+    if days == 0 {
+        tr("retention.keep").to_string()
+    } else {
+        tr_args("retention.days", &[("days", &days.to_string())])
+    }
+}
+
+fn policy_line(policy: &RetentionPolicy) -> String {
+    tr_args(
+        "retention.policy",
+        &[
+            ("trades", &days_label(policy.trade_days)),
+            ("disputed", &days_label(policy.disputed_trade_days)),
+            ("downloads", &days_label(policy.downloads_days)),
+        ],
+    )
+}
+
+/// Info popup text when a preview finds nothing to remove.
+pub fn empty_plan_message(policy: &RetentionPolicy) -> String {
+    if policy.is_active() {
+        format!("{}\n{}", tr("retention.nothing_due"), policy_line(policy))
+    } else {
+        tr("retention.all_off").to_string()
+    }
+}
+
+fn preview_lines(plan: &RetentionPlan, theme: &Theme) -> Vec<Line<'static>> {
+    let text = Style::default().fg(theme.text);
+    let muted = Style::default().fg(theme.muted);
+    let mut lines = vec![
+        Line::styled(policy_line(&plan.policy), muted),
+        Line::styled(
+            tr_args(
+                "retention.summary",
+                &[
+                    ("trades", &plan.orders.len().to_string()),
+                    ("disputed", &plan.disputed_count().to_string()),
+                    ("files", &plan.file_count().to_string()),
+                    ("size", &format_file_size(plan.total_bytes as usize)),
+                ],
+            ),
+            Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Line::from(""),
+    ];
+
+    let mut items: Vec<Line<'static>> = plan
+        .orders
+        .iter()
+        .map(|o| {
+            let id = o.order_id.to_string();
+            Line::from(vec![
+                Span::styled(format!("  {}  ", &id[..8]), text),
+                Span::styled(o.status.clone(), Style::default().fg(theme.primary)),
+                Span::styled(
+                    tr_args(
+                        "retention.order_row",
+                        &[
+                            ("age", &o.age_days.to_string()),
+                            ("files", &o.files.len().to_string()),
+                        ],
+                    ),
+                    muted,
+                ),
+            ])
+        })
+        .collect();
+    items.extend(plan.loose_files.iter().map(|path| {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Line::styled(format!("  {name}"), text)
+    }));
+    let hidden = items.len().saturating_sub(PREVIEW_MAX_ITEMS);
+    items.truncate(PREVIEW_MAX_ITEMS);
+    lines.extend(items);
+    if hidden > 0 {
+        lines.push(Line::styled(
+            tr_args("retention.more", &[("count", &hidden.to_string())]),
+            muted,
+        ));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::styled(tr("retention.question"), text));
+    lines
+}
+
+pub fn render_retention_preview(
+    f: &mut ratatui::Frame,
+    plan: &RetentionPlan,
+    selected_button: bool,
+    theme: &Theme,
+) {
+    let lines = preview_lines(plan, theme);
+    let popup = create_centered_popup(f.area(), PREVIEW_POPUP_WIDTH, lines.len() as u16 + 6);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Span::styled(
+            tr("retention.title"),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(theme.background));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let [body, buttons] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(3)]).areas(inner);
+    f.render_widget(Paragraph::new(lines), body);
+    render_yes_no_buttons(
+        f,
+        buttons,
+        selected_button,
+        tr("retention.purge"),
+        tr("retention.cancel"),
+        theme,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::retention::RetentionOrder;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use std::path::PathBuf;
+
+    #[test]
+    fn preview_lists_trades_files_and_collapses_the_rest() {
+        let theme = &Theme::dark();
+        let order_id = uuid::Uuid::new_v4();
+        let plan = RetentionPlan {
+            policy: RetentionPolicy {
+                trade_days: 30,
+                disputed_trade_days: 0,
+                downloads_days: 7,
+            },
+            orders: vec![RetentionOrder {
+                order_id,
+                status: "success".to_string(),
+                disputed: false,
+                age_days: 45,
+                files: vec![PathBuf::from("/tmp/orders_chat/x.txt")],
+            }],
+            loose_files: (0..10)
+                .map(|i| PathBuf::from(format!("/tmp/downloads/old_{i}.png")))
+                .collect(),
+            total_bytes: 2048,
+        };
+        let mut terminal = Terminal::new(TestBackend::new(90, 30)).unwrap();
+        terminal
+            .draw(|f| render_retention_preview(f, &plan, false, theme))
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        let text: String = (0..buf.area.height)
+            .map(|y| {
+                (0..buf.area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains(&order_id.to_string()[..8]));
+        assert!(text.contains("45 days"));
+        assert!(text.contains("old_0.png"));
+        assert!(!text.contains("old_9.png"));
+        assert!(text.contains("and 3 more"));
+        assert!(text.contains("11 file"));
+    }
+}
@@ -2,14 +2,15 @@
 //! User selects with Up/Down and presses Enter to save.
 
 use ratatui::layout::{Constraint, Flex, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 
+use crate::i18n::tr;
 use crate::ui::helpers::{
     get_order_attachment_messages, get_visible_attachment_messages, selected_filtered_dispute,
 };
-use crate::ui::{AppState, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::AppState;
 
 use super::chat::ChatAttachmentType;
 use super::constants::SAVE_ATTACHMENT_POPUP_HINT;
@@ -20,6 +21,7 @@ const TITLE: &str = "📎 Save attachment";
 /// Renders the Save Attachment popup with a selectable list.
 /// `selected_idx` is the index into the visible attachment list (clamped inside).
 pub fn render_save_attachment_popup(f: &mut ratatui::Frame, app: &AppState, selected_idx: usize) {
+    let theme = &app.theme;
     let Some(selected_dispute) = selected_filtered_dispute(app) else {
         return;
     };
@@ -51,11 +53,11 @@ pub fn render_save_attachment_popup(f: &mut ratatui::Frame, app: &AppState, sele
         .title(Span::styled(
             TITLE,
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         ))
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .style(Style::default().bg(theme.background));
     let inner = block.inner(popup);
     f.render_widget(block, popup);
 
@@ -80,9 +82,9 @@ pub fn render_save_attachment_popup(f: &mut ratatui::Frame, app: &AppState, sele
             };
             let text = format!("{} {}", icon, att.filename);
             let style = if global_i == selected_idx {
-                Style::default().fg(BACKGROUND_COLOR).bg(PRIMARY_COLOR)
+                Style::default().fg(theme.background).bg(theme.primary)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme.text)
             };
             Line::from(Span::styled(text, style))
         })
@@ -91,8 +93,8 @@ pub fn render_save_attachment_popup(f: &mut ratatui::Frame, app: &AppState, sele
     let mut all = content;
     all.push(Line::from(""));
     all.push(Line::from(Span::styled(
-        SAVE_ATTACHMENT_POPUP_HINT,
-        Style::default().fg(Color::DarkGray),
+        tr(SAVE_ATTACHMENT_POPUP_HINT),
+        Style::default().fg(theme.muted),
     )));
     let paragraph = Paragraph::new(all).wrap(Wrap { trim: true });
     f.render_widget(paragraph, inner);
@@ -105,6 +107,7 @@ pub fn render_observer_save_attachment_popup(
     app: &AppState,
     selected_idx: usize,
 ) {
+    let theme = &app.theme;
     let attachments: Vec<&super::chat::ChatAttachment> = app
         .observer_messages
         .iter()
@@ -135,11 +138,11 @@ pub fn render_observer_save_attachment_popup(
         .title(Span::styled(
             TITLE,
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         ))
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .style(Style::default().bg(theme.background));
     let inner = block.inner(popup);
     f.render_widget(block, popup);
 
@@ -161,9 +164,9 @@ pub fn render_observer_save_attachment_popup(
             };
             let text = format!("{} {}", icon, att.filename);
             let style = if global_i == selected_idx {
-                Style::default().fg(BACKGROUND_COLOR).bg(PRIMARY_COLOR)
+                Style::default().fg(theme.background).bg(theme.primary)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme.text)
             };
             Line::from(Span::styled(text, style))
         })
@@ -172,8 +175,8 @@ pub fn render_observer_save_attachment_popup(
     let mut all = content;
     all.push(Line::from(""));
     all.push(Line::from(Span::styled(
-        SAVE_ATTACHMENT_POPUP_HINT,
-        Style::default().fg(Color::DarkGray),
+        tr(SAVE_ATTACHMENT_POPUP_HINT),
+        Style::default().fg(theme.muted),
     )));
     let paragraph = Paragraph::new(all).wrap(Wrap { trim: true });
     f.render_widget(paragraph, inner);
@@ -186,7 +189,8 @@ pub fn render_user_save_attachment_popup(
     order_id: &str,
     selected_idx: usize,
 ) {
-    let list = get_order_attachment_messages(app, order_id);
+    let theme = &app.theme;
+    let list = get_order_attachment_messages(app, order_id, app.active_user_chat_channel);
     if list.is_empty() {
         return;
     }
@@ -212,11 +216,11 @@ pub fn render_user_save_attachment_popup(
         .title(Span::styled(
             TITLE,
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         ))
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .style(Style::default().bg(theme.background));
     let inner = block.inner(popup);
     f.render_widget(block, popup);
 
@@ -239,9 +243,9 @@ pub fn render_user_save_attachment_popup(
             };
             let text = format!("{} {}", icon, att.filename);
             let style = if global_i == selected_idx {
-                Style::default().fg(BACKGROUND_COLOR).bg(PRIMARY_COLOR)
+                Style::default().fg(theme.background).bg(theme.primary)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme.text)
             };
             Line::from(Span::styled(text, style))
         })
@@ -250,8 +254,8 @@ pub fn render_user_save_attachment_popup(
     let mut all = content;
     all.push(Line::from(""));
     all.push(Line::from(Span::styled(
-        SAVE_ATTACHMENT_POPUP_HINT,
-        Style::default().fg(Color::DarkGray),
+        tr(SAVE_ATTACHMENT_POPUP_HINT),
+        Style::default().fg(theme.muted),
     )));
     let paragraph = Paragraph::new(all).wrap(Wrap { trim: true });
     f.render_widget(paragraph, inner);
@@ -0,0 +1,228 @@
+//! Saved-search alert: a live book order matched `~/.mostrix/saved_searches.toml`.
+//!
+//! Alerts queue in [`AppState::saved_search_alerts`] and open one at a time once the UI is
+//! idle, so they never replace a form or confirmation the user is working in.
+
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use uuid::Uuid;
+
+use crate::i18n::{tr, tr_args};
+use crate::ui::helpers::{
+    create_centered_popup, format_premium, order_passes_currency_filter,
+    order_passes_payment_filter, render_yes_no_buttons,
+};
+use crate::ui::navigation::{Tab, UserTab};
+use crate::ui::{AppState, Theme, UiMode, UserMode};
+use crate::util::fiat::BookOrder;
+use crate::util::payment_methods::payment_methods;
+use crate::util::saved_searches::SavedSearchMatch;
+
+const ALERT_POPUP_WIDTH: u16 = 70;
+
+/// Open the next queued alert when the user is on a plain tab view.
+///
+/// Alerts for our own listings (My Trades maker rows) are dropped.
+pub fn show_next_saved_search_alert(app: &mut AppState) {
+    if !matches!(app.mode, UiMode::UserMode(UserMode::Normal)) {
+        return;
+    }
+    while let Some(found) = app.saved_search_alerts.pop_front() {
+        let own = found.order.id.is_some_and(|id| {
+            let id = id.to_string();
+            app.my_trades_maker_book
+                .iter()
+                .any(|row| row.order_id == id)
+        });
+        if !own {
+            app.mode = UiMode::SavedSearchAlert(Box::new(found), true);
+            return;
+        }
+    }
+}
+
+/// Select `order_id` on the Orders tab, clearing a payment filter that would hide it.
+///
+/// Returns a note for the user when the order cannot be shown as selected.
+pub fn jump_to_book_order(
+    app: &mut AppState,
+    orders: &[BookOrder],
+    order_id: Uuid,
+) -> Option<String> {
+    let Some(order) = orders.iter().find(|o| o.id == Some(order_id)) else {
+        return Some(tr("saved_search.gone").to_string());
+    };
+    app.active_tab = Tab::User(UserTab::Orders);
+    app.selected_order_id = Some(order_id);
+    if !order_passes_payment_filter(order, app.book_payment_filter.as_deref()) {
+        app.book_payment_filter = None;
+    }
+    if !order_passes_currency_filter(order, &app.currencies_filter) {
+        return Some(tr_args(
+            "saved_search.hidden_by_currency",
+            &[("code", &order.fiat_code)],
+        ));
+    }
+    None
+}
+
+fn order_summary(order: &BookOrder) -> String {
+    let fiat = order.fiat;
+    let amount = match (fiat.min, fiat.max) {
+        (Some(min), Some(max)) => format!("{}–{}", min.grouped(), max.grouped()),
+        _ => fiat.amount.grouped(),
+    };
+    let kind = order
+        .kind
+        .map(|k| k.to_string().to_uppercase())
+        .unwrap_or_else(|| "?".to_string());
+    format!(
+        "{kind} · {amount} {} · {}",
+        order.fiat_code.to_uppercase(),
+        payment_methods().normalize_list(&order.payment_method)
+    )
+}
+
+pub fn render_saved_search_alert(
+    f: &mut ratatui::Frame,
+    found: &SavedSearchMatch,
+    selected_button: bool,
+    theme: &Theme,
+) {
+    let text = Style::default().fg(theme.text);
+    let muted = Style::default().fg(theme.muted);
+    let (premium, premium_color) = format_premium(found.order.premium, theme);
+    let order_id = found.order.id.map(|id| id.to_string()).unwrap_or_default();
+    let lines = vec![
+        Line::styled(
+            tr_args(
+                "saved_search.matched",
+                &[("searches", &found.searches.join(", "))],
+            ),
+            Style::default()
+                .fg(theme.warning)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Line::from(""),
+        Line::styled(order_summary(&found.order), text),
+        Line::from(vec![
+            Span::styled(tr("saved_search.premium"), muted),
+            Span::styled(premium, Style::default().fg(premium_color)),
+        ]),
+        Line::styled(order_id, muted),
+    ];
+    let popup = create_centered_popup(f.area(), ALERT_POPUP_WIDTH, lines.len() as u16 + 6);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Span::styled(
+            tr("saved_search.title"),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(theme.background));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let [body, buttons] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(3)]).areas(inner);
+    f.render_widget(
+        Paragraph::new(lines).alignment(ratatui::layout::Alignment::Center),
+        body,
+    );
+    render_yes_no_buttons(
+        f,
+        buttons,
+        selected_button,
+        tr("saved_search.go_to_order"),
+        tr("saved_search.dismiss"),
+        theme,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::helpers::OrderChatListItem;
+    use crate::ui::UserRole;
+    use mostro_core::prelude::{Kind, SmallOrder, Status};
+
+    fn book_order(id: Uuid, fiat_code: &str, payment_method: &str) -> BookOrder {
+        BookOrder::whole(SmallOrder {
+            id: Some(id),
+            kind: Some(Kind::Sell),
+            status: Some(Status::Pending),
+            fiat_code: fiat_code.to_string(),
+            fiat_amount: 80_000,
+            payment_method: payment_method.to_string(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn queued_alerts_wait_for_idle_ui_and_skip_own_listings() {
+        let mut app = AppState::new(UserRole::User);
+        let own = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        app.my_trades_maker_book.push(OrderChatListItem {
+            order_id: own.to_string(),
+            status: Some(Status::Pending),
+            amount: None,
+            fiat: Some((80_000, "ARS".to_string())),
+            trade_index: Some(1),
+            payment_method: Some("MercadoPago".to_string()),
+            premium: Some(0),
+            buyer_trade_pubkey: None,
+            seller_trade_pubkey: None,
+            buyer_reputation: None,
+            seller_reputation: None,
+            solver_pubkey: None,
+            dispute_id: None,
+            phase_started_at: None,
+            payment_invoice: None,
+            created_at: None,
+            expires_at: None,
+        });
+        for id in [own, other] {
+            app.saved_search_alerts.push_back(SavedSearchMatch {
+                searches: vec!["ars".to_string()],
+                order: book_order(id, "ARS", "MercadoPago"),
+            });
+        }
+
+        app.mode = UiMode::ConfirmExit(false);
+        show_next_saved_search_alert(&mut app);
+        assert_eq!(app.saved_search_alerts.len(), 2);
+
+        app.mode = UiMode::UserMode(UserMode::Normal);
+        show_next_saved_search_alert(&mut app);
+        match &app.mode {
+            UiMode::SavedSearchAlert(found, true) => assert_eq!(found.order.id, Some(other)),
+            _ => panic!("expected the alert for the other maker's order"),
+        }
+        assert!(app.saved_search_alerts.is_empty());
+    }
+
+    #[test]
+    fn jump_selects_order_and_clears_hiding_payment_filter() {
+        let mut app = AppState::new(UserRole::User);
+        let id = Uuid::new_v4();
+        let orders = vec![
+            book_order(Uuid::new_v4(), "ARS", "Ualá"),
+            book_order(id, "ARS", "mercado pago"),
+        ];
+        app.book_payment_filter = Some("Ualá".to_string());
+        assert_eq!(jump_to_book_order(&mut app, &orders, id), None);
+        assert_eq!(app.active_tab, Tab::User(UserTab::Orders));
+        assert_eq!(app.selected_order_id, Some(id));
+        assert_eq!(app.book_payment_filter, None);
+
+        app.currencies_filter = vec!["USD".to_string()];
+        assert!(jump_to_book_order(&mut app, &orders, id).is_some_and(|note| note.contains("ARS")));
+        assert!(jump_to_book_order(&mut app, &orders, Uuid::new_v4()).is_some());
+    }
+}
@@ -3,15 +3,21 @@
 use std::path::PathBuf;
 
 use anyhow::Result;
-use ratatui::style::{Color, Modifier, Style};
-use ratatui::text::Span;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::FrameExt as _;
 use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph};
-use ratatui_explorer::{File, FileExplorer, FileExplorerBuilder, Theme};
+use ratatui_explorer::{File, FileExplorer, FileExplorerBuilder, Theme as ExplorerTheme};
 
+use crate::i18n::{tr, tr_args};
 use crate::ui::helpers::create_centered_popup;
-use crate::ui::{AppState, UiMode, UserMode, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::{AppState, Theme, UiMode, UserMode};
 use crate::util::attachment_extension_allowed;
+use crate::util::attachment_preprocess::PreprocessReport;
+use crate::util::PendingOrderAttachment;
+
+const CONFIRM_POPUP_WIDTH: u16 = 60;
 
 pub const SEND_ATTACHMENT_PICKER_HINT: &str =
     "Enter: Send file  |  Esc: Cancel  |  h/j/k/l: Navigate  |  Ctrl+H: hidden";
@@ -22,37 +28,37 @@ fn picker_start_dir() -> PathBuf {
         .unwrap_or_else(|| PathBuf::from("/"))
 }
 
-fn mostrix_explorer_theme() -> Theme {
-    Theme::default()
+fn mostrix_explorer_theme(theme: &Theme) -> ExplorerTheme {
+    ExplorerTheme::default()
         .add_default_title()
         .with_title_bottom(|fe| format!("[{} items]", fe.files().len()).into())
         .with_block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR)),
+                .style(Style::default().bg(theme.background).fg(theme.primary)),
         )
         .with_highlight_item_style(
             Style::default()
-                .fg(PRIMARY_COLOR)
-                .bg(BACKGROUND_COLOR)
+                .fg(theme.primary)
+                .bg(theme.background)
                 .add_modifier(Modifier::BOLD),
         )
         .with_highlight_dir_style(
             Style::default()
-                .fg(Color::Cyan)
-                .bg(BACKGROUND_COLOR)
+                .fg(theme.accent)
+                .bg(theme.background)
                 .add_modifier(Modifier::BOLD),
         )
         .with_highlight_symbol("> ")
 }
 
 /// Builds a file explorer filtered to attachment-allowed extensions (dirs always visible).
-pub fn build_send_attachment_explorer() -> Result<FileExplorer> {
+pub fn build_send_attachment_explorer(theme: &Theme) -> Result<FileExplorer> {
     let start = picker_start_dir();
     FileExplorerBuilder::default()
         .working_dir(&start)
-        .theme(mostrix_explorer_theme())
+        .theme(mostrix_explorer_theme(theme))
         .filter_map(|file| {
             if file.is_dir || attachment_extension_allowed(&file.path) {
                 Some(file)
@@ -71,7 +77,8 @@ pub fn explorer_selection_is_sendable_file(file: &File) -> bool {
 
 /// Opens the send-attachment picker for `order_id`.
 pub fn open_user_send_attachment_picker(app: &mut AppState, order_id: String) -> Result<()> {
-    let explorer = build_send_attachment_explorer()?;
+    let theme = &app.theme;
+    let explorer = build_send_attachment_explorer(theme)?;
     app.user_send_attachment_explorer = Some(explorer);
     app.mode = UiMode::UserSendAttachmentPicker(order_id);
     Ok(())
@@ -83,8 +90,130 @@ pub fn close_user_send_attachment_picker(app: &mut AppState) {
     app.mode = UiMode::UserMode(UserMode::Normal);
 }
 
+/// Drops a preprocessed attachment without uploading and releases the in-flight send guard.
+pub fn cancel_user_attachment_send(app: &mut AppState) {
+    app.sending_attachment_order_id = None;
+    app.mode = UiMode::UserMode(UserMode::Normal);
+}
+
+/// Human-readable byte size (`512 B`, `48.2 KB`, `3.1 MB`).
+pub fn format_file_size(bytes: usize) -> String {
+    const KB: f64 = 1024.0;
+    let b = bytes as f64;
+    if b < KB {
+        format!("{bytes} B")
+    } else if b < KB * KB {
+        format!("{:.1} KB", b / KB)
+    } else {
+        format!("{:.1} MB", b / (KB * KB))
+    }
+}
+
+/// Summary lines for the send confirmation: size, dimensions and removed metadata.
+fn confirm_summary_lines(
+    filename: &str,
+    report: &PreprocessReport,
+    theme: &Theme,
+) -> Vec<Line<'static>> {
+    let text = Style::default().fg(theme.text);
+    let good = Style::default().fg(theme.positive);
+    let mut lines = vec![Line::styled(
+        tr_args("attachment_confirm.file", &[("name", filename)]),
+        text,
+    )];
+    let before = format_file_size(report.original_size);
+    let after = format_file_size(report.final_size);
+    lines.push(Line::styled(
+        if report.original_size == report.final_size {
+            tr_args("attachment_confirm.size_unchanged", &[("size", &before)])
+        } else {
+            tr_args(
+                "attachment_confirm.size",
+                &[("before", &before), ("after", &after)],
+            )
+        },
+        text,
+    ));
+    if report.original_dimensions != report.final_dimensions {
+        let dims = |(w, h): (u32, u32)| format!("{w}×{h}");
+        lines.push(Line::styled(
+            tr_args(
+                "attachment_confirm.dimensions",
+                &[
+                    ("before", &dims(report.original_dimensions)),
+                    ("after", &dims(report.final_dimensions)),
+                ],
+            ),
+            text,
+        ));
+    }
+    lines.push(Line::default());
+    if !report.inspected {
+        lines.push(Line::styled(
+            tr("attachment_confirm.not_inspected"),
+            Style::default().fg(theme.warning),
+        ));
+        return lines;
+    }
+    let removed = report.removed;
+    if !removed.any() {
+        lines.push(Line::styled(tr("attachment_confirm.no_metadata"), good));
+        return lines;
+    }
+    lines.push(Line::styled(tr("attachment_confirm.removed_header"), text));
+    for (found, key) in [
+        (removed.location, "attachment_confirm.removed_location"),
+        (removed.device, "attachment_confirm.removed_device"),
+        (removed.exif, "attachment_confirm.removed_exif"),
+        (removed.xmp, "attachment_confirm.removed_xmp"),
+        (removed.icc_profile, "attachment_confirm.removed_icc"),
+        (removed.text, "attachment_confirm.removed_text"),
+    ] {
+        if found {
+            lines.push(Line::styled(format!("  ✓ {}", tr(key)), good));
+        }
+    }
+    lines
+}
+
+/// Renders the size/privacy summary shown before an attachment is uploaded.
+pub fn render_user_attachment_send_confirm(
+    f: &mut ratatui::Frame,
+    pending: &PendingOrderAttachment,
+    theme: &Theme,
+) {
+    let lines = confirm_summary_lines(&pending.attachment.filename, &pending.report, theme);
+    let area = f.area();
+    let popup = create_centered_popup(area, CONFIRM_POPUP_WIDTH, lines.len() as u16 + 4);
+    f.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .title(Span::styled(
+            tr("attachment_confirm.title"),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(theme.background));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let [body, footer] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(inner);
+    f.render_widget(Paragraph::new(lines), body);
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            tr("attachment_confirm.footer"),
+            Style::default().fg(theme.muted),
+        ))
+        .alignment(ratatui::layout::Alignment::Center),
+        footer,
+    );
+}
+
 /// Renders the file explorer modal when picker mode is active.
 pub fn render_user_send_attachment_picker(f: &mut ratatui::Frame, app: &AppState) {
+    let theme = &app.theme;
     let Some(explorer) = app.user_send_attachment_explorer.as_ref() else {
         return;
     };
@@ -107,7 +236,7 @@ pub fn render_user_send_attachment_picker(f: &mut ratatui::Frame, app: &AppState
         f.render_widget(
             Paragraph::new(Span::styled(
                 SEND_ATTACHMENT_PICKER_HINT,
-                Style::default().fg(Color::DarkGray).bg(BACKGROUND_COLOR),
+                Style::default().fg(theme.muted).bg(theme.background),
             )),
             hint_area,
         );
@@ -145,6 +274,13 @@ mod tests {
         let _ = fs::remove_dir_all(dir);
     }
 
+    #[test]
+    fn format_file_size_picks_unit() {
+        assert_eq!(format_file_size(900), "900 B");
+        assert_eq!(format_file_size(1536), "1.5 KB");
+        assert_eq!(format_file_size(3 * 1024 * 1024), "3.0 MB");
+    }
+
     #[test]
     fn explorer_selection_is_sendable_file_skips_parent_and_dirs() {
         let parent = File {
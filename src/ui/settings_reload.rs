@@ -0,0 +1,273 @@
+//! Reload confirmation for `settings.toml` edited outside the app.
+//!
+//! The watcher (`util/settings_reload.rs`) parks each validated change in
+//! [`AppState::pending_settings_change`]; the popup opens once the UI is idle and shows a
+//! per-key diff. Apply hands the change to the same runtime reload flags the Settings tab uses.
+
+use std::str::FromStr;
+
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::i18n::{set_language_from_setting, tr, tr_args};
+use crate::settings::mark_settings_applied;
+use crate::ui::helpers::{create_centered_popup, render_yes_no_buttons};
+use crate::ui::{AdminMode, AppState, Theme, UiMode, UserMode, UserRole};
+use crate::util::settings_reload::SettingsFileChange;
+
+const RELOAD_POPUP_WIDTH: u16 = 76;
+/// Changed keys listed before the rest is summarized.
+const MAX_LISTED_CHANGES: usize = 8;
+
+/// Open the pending reload confirmation when the user is on a plain tab view.
+pub fn show_pending_settings_change(app: &mut AppState) {
+    if !matches!(
+        app.mode,
+        UiMode::Normal | UiMode::UserMode(UserMode::Normal) | UiMode::AdminMode(AdminMode::Normal)
+    ) {
+        return;
+    }
+    if let Some(change) = app.pending_settings_change.take() {
+        app.mode = UiMode::ConfirmSettingsReload(Box::new(change), true);
+    }
+}
+
+/// Adopt an external settings change and flag the runtime reloads it needs.
+///
+/// Relays and the identity key rebuild the client (key reload); the Mostro pubkey, currency
+/// filters and admin key restart subscriptions. Returns the message for the result popup.
+pub fn apply_settings_file_change(app: &mut AppState, change: &SettingsFileChange) -> String {
+    let settings = &change.settings;
+    mark_settings_applied(settings);
+
+    if change.changed("user_mode") {
+        if let Ok(role) = UserRole::from_str(&settings.user_mode) {
+            if role != app.user_role {
+                app.switch_role(role);
+                if role == UserRole::Admin {
+                    app.pending_admin_disputes_reload = true;
+                }
+            }
+        }
+    }
+    if change.changed("theme") {
+        app.apply_theme_setting(&settings.theme);
+    }
+    if change.changed("language") {
+        set_language_from_setting(&settings.language);
+    }
+    if change.changed("automation_enabled") {
+        app.automation.enabled = settings.automation_enabled;
+    }
+    if change.changed("currencies_filter") {
+        app.currencies_filter = settings.currencies_filter.clone();
+    }
+    if change.changed("nsec_privkey") || change.changed("relays") {
+        app.pending_key_reload = true;
+    } else if change.changed("mostro_pubkey")
+        || change.changed("currencies_filter")
+        || change.changed("admin_privkey")
+    {
+        app.pending_fetch_scheduler_reload = true;
+    }
+
+    log::info!(
+        "Applied settings.toml changes: {}",
+        change
+            .changes
+            .iter()
+            .map(|c| c.key.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let restart = change.restart_required();
+    if restart.is_empty() {
+        tr("settings_reload.applied").to_string()
+    } else {
+        format!(
+            "{} {}",
+            tr("settings_reload.applied"),
+            tr_args(
+                "settings_reload.restart_required",
+                &[("keys", &restart.join(", "))]
+            )
+        )
+    }
+}
+
+fn truncate(value: &str, max: usize) -> String {
+    if value.chars().count() <= max {
+        return value.to_string();
+    }
+    let kept: String = value.chars().take(max.saturating_sub(1)).collect();
+    format!("{kept}…")
+}
+
+pub fn render_settings_reload(
+    f: &mut ratatui::Frame,
+    change: &SettingsFileChange,
+    selected_button: bool,
+    theme: &Theme,
+) {
+    let value_width = RELOAD_POPUP_WIDTH as usize - 8;
+    let mut lines = vec![
+        Line::styled(tr("settings_reload.intro"), Style::default().fg(theme.text)),
+        Line::from(""),
+    ];
+    for diff in change.changes.iter().take(MAX_LISTED_CHANGES) {
+        lines.push(Line::styled(
+            diff.key.clone(),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ));
+        lines.push(Line::from(vec![
+            Span::styled("  - ", Style::default().fg(theme.negative)),
+            Span::styled(
+                truncate(&diff.old, value_width),
+                Style::default().fg(theme.negative),
+            ),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("  + ", Style::default().fg(theme.positive)),
+            Span::styled(
+                truncate(&diff.new, value_width),
+                Style::default().fg(theme.positive),
+            ),
+        ]));
+    }
+    let hidden = change.changes.len().saturating_sub(MAX_LISTED_CHANGES);
+    if hidden > 0 {
+        lines.push(Line::styled(
+            tr_args("settings_reload.more", &[("count", &hidden.to_string())]),
+            Style::default().fg(theme.muted),
+        ));
+    }
+    let restart = change.restart_required();
+    if !restart.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::styled(
+            tr_args(
+                "settings_reload.restart_required",
+                &[("keys", &restart.join(", "))],
+            ),
+            Style::default().fg(theme.warning),
+        ));
+    }
+
+    let popup = create_centered_popup(f.area(), RELOAD_POPUP_WIDTH, lines.len() as u16 + 6);
+    f.render_widget(Clear, popup);
+    let block = Block::default()
+        .title(Span::styled(
+            tr("settings_reload.title"),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(theme.background));
+    let inner = block.inner(popup);
+    f.render_widget(block, popup);
+
+    let [body, buttons] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(3)]).areas(inner);
+    f.render_widget(Paragraph::new(lines), body);
+    render_yes_no_buttons(
+        f,
+        buttons,
+        selected_button,
+        tr("settings_reload.apply"),
+        tr("settings_reload.ignore"),
+        theme,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::Settings;
+    use crate::util::settings_reload::settings_diff;
+
+    fn change(old: &Settings, new: Settings) -> SettingsFileChange {
+        SettingsFileChange {
+            changes: settings_diff(old, &new),
+            settings: new,
+        }
+    }
+
+    #[test]
+    fn apply_flags_the_matching_runtime_reloads() {
+        let old = Settings::default();
+
+        let mut app = AppState::new(UserRole::User);
+        let filters = change(
+            &old,
+            Settings {
+                currencies_filter: vec!["ARS".to_string()],
+                ..old.clone()
+            },
+        );
+        let msg = apply_settings_file_change(&mut app, &filters);
+        assert!(app.pending_fetch_scheduler_reload);
+        assert!(!app.pending_key_reload);
+        assert_eq!(app.currencies_filter, vec!["ARS".to_string()]);
+        assert_eq!(msg, tr("settings_reload.applied"));
+
+        let mut app = AppState::new(UserRole::User);
+        let relays_and_mode = change(
+            &old,
+            Settings {
+                relays: vec!["wss://relay.example.com".to_string()],
+                user_mode: "admin".to_string(),
+                log_level: "debug".to_string(),
+                ..old.clone()
+            },
+        );
+        let msg = apply_settings_file_change(&mut app, &relays_and_mode);
+        assert!(app.pending_key_reload);
+        assert_eq!(app.user_role, UserRole::Admin);
+        assert!(app.pending_admin_disputes_reload);
+        assert!(msg.contains("log_level"));
+
+        // Attachment jobs read the applied settings when they are queued: no reload, no restart.
+        let mut app = AppState::new(UserRole::User);
+        let attachments = change(
+            &old,
+            Settings {
+                blossom_servers: vec!["https://blossom.example.com".to_string()],
+                blossom_mirror: !old.blossom_mirror,
+                attachment_max_image_side: 1024,
+                attachment_recompress_above_kb: 200,
+                ..old.clone()
+            },
+        );
+        assert_eq!(attachments.changes.len(), 4);
+        let msg = apply_settings_file_change(&mut app, &attachments);
+        assert!(attachments.restart_required().is_empty());
+        assert!(!app.pending_key_reload && !app.pending_fetch_scheduler_reload);
+        assert_eq!(msg, tr("settings_reload.applied"));
+    }
+
+    #[test]
+    fn pending_change_waits_for_idle_ui() {
+        let mut app = AppState::new(UserRole::User);
+        let old = Settings::default();
+        app.pending_settings_change = Some(change(
+            &old,
+            Settings {
+                theme: "light".to_string(),
+                ..old.clone()
+            },
+        ));
+        app.mode = UiMode::ConfirmExit(false);
+        show_pending_settings_change(&mut app);
+        assert!(app.pending_settings_change.is_some());
+
+        app.mode = UiMode::UserMode(UserMode::Normal);
+        show_pending_settings_change(&mut app);
+        assert!(matches!(app.mode, UiMode::ConfirmSettingsReload(_, true)));
+        assert!(app.pending_settings_change.is_none());
+    }
+}
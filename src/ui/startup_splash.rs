@@ -1,12 +1,12 @@
 use std::time::Instant;
 
 use ratatui::layout::{Alignment, Constraint, Flex, Layout};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Paragraph};
 
 use crate::ui::helpers::render_centered_lines;
-use crate::ui::{BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::Theme;
 
 pub const SPLASH_TICK_MS: u64 = 150;
 pub const SPLASH_DOT_CYCLE_MS: u64 = 400;
@@ -96,14 +96,14 @@ fn splash_fits_wordmark(terminal_width: u16) -> bool {
     terminal_width >= logo_line_width() as u16
 }
 
-fn fill_splash_background(f: &mut ratatui::Frame, area: ratatui::layout::Rect) {
+fn fill_splash_background(f: &mut ratatui::Frame, area: ratatui::layout::Rect, theme: &Theme) {
     f.render_widget(
-        Block::default().style(Style::default().bg(BACKGROUND_COLOR)),
+        Block::default().style(Style::default().bg(theme.background)),
         area,
     );
 }
 
-fn style_loading_line(line: &str) -> Vec<Span<'static>> {
+fn style_loading_line(line: &str, theme: &Theme) -> Vec<Span<'static>> {
     line.chars()
         .map(|c| {
             let highlight = matches!(
@@ -114,19 +114,19 @@ fn style_loading_line(line: &str) -> Vec<Span<'static>> {
                 Span::styled(
                     c.to_string(),
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 )
             } else {
-                Span::styled(c.to_string(), Style::default().fg(Color::White))
+                Span::styled(c.to_string(), Style::default().fg(theme.text))
             }
         })
         .collect()
 }
 
-fn render_compact_splash(f: &mut ratatui::Frame, dot_count: u8, phase: &str) {
+fn render_compact_splash(f: &mut ratatui::Frame, dot_count: u8, phase: &str, theme: &Theme) {
     let area = f.area();
-    fill_splash_background(f, area);
+    fill_splash_background(f, area, theme);
 
     let text = format!(
         "mostro is loading{}{}",
@@ -140,7 +140,7 @@ fn render_compact_splash(f: &mut ratatui::Frame, dot_count: u8, phase: &str) {
 
     let paragraph = Paragraph::new(text)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(theme.text));
 
     let [block] = Layout::vertical([Constraint::Min(1)])
         .flex(Flex::Center)
@@ -149,12 +149,12 @@ fn render_compact_splash(f: &mut ratatui::Frame, dot_count: u8, phase: &str) {
 }
 
 /// Full-screen startup splash with animated dots and optional phase subtitle.
-pub fn render_startup_splash(f: &mut ratatui::Frame, dot_count: u8, phase: &str) {
+pub fn render_startup_splash(f: &mut ratatui::Frame, dot_count: u8, phase: &str, theme: &Theme) {
     let area = f.area();
-    fill_splash_background(f, area);
+    fill_splash_background(f, area, theme);
 
     if !splash_fits_wordmark(area.width) {
-        render_compact_splash(f, dot_count, phase);
+        render_compact_splash(f, dot_count, phase, theme);
         return;
     }
 
@@ -180,10 +180,12 @@ pub fn render_startup_splash(f: &mut ratatui::Frame, dot_count: u8, phase: &str)
         .split(center_block)
     };
 
-    render_centered_lines(f, chunks[0], &art_lines, style_loading_line);
+    render_centered_lines(f, chunks[0], &art_lines, |line| {
+        style_loading_line(line, theme)
+    });
 
     if !phase.is_empty() && chunks.len() > 2 {
-        let phase_line = Line::from(Span::styled(phase, Style::default().fg(Color::Gray)));
+        let phase_line = Line::from(Span::styled(phase, Style::default().fg(theme.secondary)));
         f.render_widget(
             Paragraph::new(phase_line).alignment(Alignment::Center),
             chunks[2],
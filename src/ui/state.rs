@@ -3,8 +3,8 @@ pub use crate::ui::admin_state::AddSolverState;
 pub use crate::ui::app_state::{AppState, UiMode};
 pub use crate::ui::chat::{
     AdminChatLastSeen, AdminChatUpdate, ChatAttachment, ChatAttachmentType, ChatParty, ChatSender,
-    DecodedChatMessage, DisputeChatMessage, DisputeFilter, OrderChatLastSeen, OrderChatUpdate,
-    UserChatChannel, UserChatSender, UserOrderChatMessage,
+    DecodedChatMessage, DisputeChatMessage, DisputeFilter, ObserverField, OrderChatLastSeen,
+    OrderChatUpdate, UserChatChannel, UserChatSender, UserOrderChatMessage,
 };
 pub use crate::ui::navigation::{AdminTab, Tab, UserRole, UserTab};
 pub use crate::ui::orders::{
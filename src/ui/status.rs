@@ -1,15 +1,22 @@
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
+use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
 
-use super::{BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::Theme;
+use crate::i18n::tr_args;
 
+/// Draw the bottom status lines. `profile_label` (see [`crate::profile::active_profile_label`])
+/// and `proxy_label` (see [`crate::util::active_proxy`]) are shown as badges in front of the
+/// first line so it is obvious which identity is active and when traffic goes through a proxy.
 pub fn render_status_bar(
     f: &mut ratatui::Frame,
     area: Rect,
     lines: &[String],
     pending_notifications: usize,
+    profile_label: Option<&str>,
+    proxy_label: Option<&str>,
+    theme: &Theme,
 ) {
     // Clear the area first to avoid leftover text
     f.render_widget(Clear, area);
@@ -25,21 +32,42 @@ pub fn render_status_bar(
     // Build styled lines for the status bar
     let mut styled_lines: Vec<Line> = Vec::new();
     for (idx, line) in lines.iter().enumerate() {
-        let mut spans = vec![Span::styled(
+        let mut spans = Vec::new();
+        if idx == 0 {
+            if let Some(name) = profile_label {
+                spans.push(Span::styled(
+                    format!("{} ", tr_args("profile.badge", &[("name", name)])),
+                    Style::default()
+                        .bg(theme.background)
+                        .fg(theme.warning)
+                        .add_modifier(ratatui::style::Modifier::BOLD),
+                ));
+            }
+            if let Some(label) = proxy_label {
+                spans.push(Span::styled(
+                    format!("🧅 {label} "),
+                    Style::default()
+                        .bg(theme.background)
+                        .fg(theme.positive)
+                        .add_modifier(ratatui::style::Modifier::BOLD),
+                ));
+            }
+        }
+        spans.push(Span::styled(
             line.to_string(),
-            Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR),
-        )];
+            Style::default().bg(theme.background).fg(theme.primary),
+        ));
 
         // Add blinking notification indicator on the last line if there are pending notifications
         if idx == lines.len() - 1 && pending_notifications > 0 {
             let indicator_text = format!(" 🔔 {} new notification(s)", pending_notifications);
             let indicator_style = if blink_on {
                 Style::default()
-                    .bg(BACKGROUND_COLOR)
-                    .fg(Color::Yellow)
+                    .bg(theme.background)
+                    .fg(theme.warning)
                     .add_modifier(ratatui::style::Modifier::BOLD)
             } else {
-                Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR)
+                Style::default().bg(theme.background).fg(theme.primary)
             };
             spans.push(Span::styled(indicator_text, indicator_style));
         }
@@ -53,7 +81,7 @@ pub fn render_status_bar(
         .block(
             Block::default()
                 .borders(Borders::NONE)
-                .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR)),
+                .style(Style::default().bg(theme.background).fg(theme.primary)),
         );
     f.render_widget(bar, area);
 }
@@ -77,11 +105,12 @@ mod tests {
 
     #[test]
     fn render_status_bar_without_notifications() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(80, 5);
         let mut terminal = Terminal::new(backend).unwrap();
         let lines = vec!["Connected to relays".to_string()];
         terminal
-            .draw(|f| render_status_bar(f, f.area(), &lines, 0))
+            .draw(|f| render_status_bar(f, f.area(), &lines, 0, None, None, theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Connected to relays"));
@@ -90,14 +119,39 @@ mod tests {
 
     #[test]
     fn render_status_bar_with_pending_notifications() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(80, 5);
         let mut terminal = Terminal::new(backend).unwrap();
         let lines = vec!["Status line".to_string()];
         terminal
-            .draw(|f| render_status_bar(f, f.area(), &lines, 3))
+            .draw(|f| render_status_bar(f, f.area(), &lines, 3, None, None, theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Status line"));
         assert!(buffer_contains(buf, "new notification"));
     }
+
+    #[test]
+    fn render_status_bar_shows_profile_and_proxy_badges() {
+        let theme = &Theme::dark();
+        let backend = TestBackend::new(80, 5);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let lines = vec!["Relays".to_string()];
+        terminal
+            .draw(|f| {
+                render_status_bar(
+                    f,
+                    f.area(),
+                    &lines,
+                    0,
+                    Some("desk"),
+                    Some("SOCKS5 127.0.0.1:9050"),
+                    theme,
+                )
+            })
+            .unwrap();
+        let buf = terminal.backend().buffer();
+        assert!(buffer_contains(buf, "desk"));
+        assert!(buffer_contains(buf, "SOCKS5 127.0.0.1:9050"));
+    }
 }
@@ -0,0 +1,393 @@
+use std::str::FromStr;
+
+use mostro_core::prelude::*;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table, TableState, Wrap};
+
+use crate::i18n::{tr, tr_args};
+use crate::models::AdminDispute;
+use crate::ui::helpers::{format_local_timestamp, render_table_list_scrollbar};
+use crate::ui::hit_test::{self, HitTarget};
+use crate::ui::{AppState, Theme};
+use crate::util::dispute_archive::{bond_choice, party_history, ArchiveQuery, PartyRole};
+
+/// Below this width the results table and the detail pane are stacked instead of side by side.
+const ARCHIVE_SPLIT_WIDTH: u16 = 110;
+/// Other disputes listed per party in the detail pane.
+const HISTORY_ROWS: usize = 8;
+
+/// Number of rows the current search shows (0 while the query does not parse).
+pub fn archive_result_count(app: &AppState) -> usize {
+    ArchiveQuery::parse(&app.archive_query)
+        .map(|q| q.search(&app.admin_disputes_in_progress).len())
+        .unwrap_or(0)
+}
+
+fn outcome_label(dispute: &AdminDispute) -> String {
+    match dispute.status.as_deref().map(DisputeStatus::from_str) {
+        Some(Ok(DisputeStatus::Settled)) => tr("archive.settled").to_string(),
+        Some(Ok(DisputeStatus::SellerRefunded)) => tr("archive.seller_refunded").to_string(),
+        Some(Ok(DisputeStatus::Released)) => tr("archive.released").to_string(),
+        _ => dispute.status.clone().unwrap_or_default(),
+    }
+}
+
+fn bond_label(dispute: &AdminDispute) -> String {
+    match bond_choice(dispute) {
+        Some(choice) => tr_args("archive.bond", &[("choice", choice.label())]),
+        None => tr("archive.bond_unknown").to_string(),
+    }
+}
+
+fn taken_day(dispute: &AdminDispute) -> String {
+    format_local_timestamp(dispute.taken_at, "%Y-%m-%d").unwrap_or_else(|| "-".to_string())
+}
+
+/// Render the admin Dispute Archive: search box, finalized results and the selected
+/// dispute's outcome plus each party's history with this solver.
+pub fn render_dispute_archive_tab(f: &mut ratatui::Frame, area: Rect, app: &mut AppState) {
+    let theme = &app.theme;
+    let chunks = Layout::new(
+        Direction::Vertical,
+        [
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ],
+    )
+    .split(area);
+
+    let (query, error) = match ArchiveQuery::parse(&app.archive_query) {
+        Ok(query) => (query, None),
+        Err(e) => (ArchiveQuery::default(), Some(e.to_string())),
+    };
+    let results = if error.is_some() {
+        Vec::new()
+    } else {
+        query.search(&app.admin_disputes_in_progress)
+    };
+
+    let search_title = match &error {
+        Some(e) => Span::styled(
+            format!("{} – {e}", tr("archive.search")),
+            Style::default()
+                .fg(theme.negative)
+                .add_modifier(Modifier::BOLD),
+        ),
+        None => Span::styled(
+            format!(
+                "{} – {}",
+                tr("archive.search"),
+                tr_args("archive.results", &[("count", &results.len().to_string())])
+            ),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ),
+    };
+    let search_line = if app.archive_query.is_empty() {
+        Line::from(Span::styled(
+            tr("archive.placeholder"),
+            Style::default().fg(theme.muted),
+        ))
+    } else {
+        Line::from(Span::styled(
+            format!("{}▏", app.archive_query),
+            Style::default().fg(theme.text),
+        ))
+    };
+    f.render_widget(
+        Paragraph::new(search_line).block(
+            Block::default()
+                .title(search_title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.primary))
+                .style(Style::default().bg(theme.background)),
+        ),
+        chunks[0],
+    );
+
+    let split = Layout::new(
+        if chunks[1].width >= ARCHIVE_SPLIT_WIDTH {
+            Direction::Horizontal
+        } else {
+            Direction::Vertical
+        },
+        [Constraint::Percentage(45), Constraint::Percentage(55)],
+    )
+    .split(chunks[1]);
+
+    if results.is_empty() {
+        app.archive_selected = 0;
+        f.render_widget(
+            Paragraph::new(Span::styled(
+                tr("archive.empty"),
+                Style::default().fg(theme.warning),
+            ))
+            .block(archive_block(tr("archive.title"), theme)),
+            chunks[1],
+        );
+    } else {
+        app.archive_selected = app.archive_selected.min(results.len() - 1);
+        render_results_table(
+            f,
+            split[0],
+            &results,
+            app.archive_selected,
+            &mut app.archive_table_state,
+            theme,
+        );
+        let selected = results[app.archive_selected];
+        let lines = detail_lines(selected, &app.admin_disputes_in_progress, theme);
+        f.render_widget(
+            Paragraph::new(lines)
+                .wrap(Wrap { trim: false })
+                .block(archive_block(tr("archive.details"), theme)),
+            split[1],
+        );
+    }
+
+    f.render_widget(
+        Paragraph::new(Span::styled(
+            tr("archive.footer"),
+            Style::default().fg(theme.muted),
+        )),
+        chunks[2],
+    );
+}
+
+fn archive_block<'a>(title: &'a str, theme: &Theme) -> Block<'a> {
+    Block::default()
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background))
+}
+
+fn render_results_table(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    results: &[&AdminDispute],
+    selected: usize,
+    table_state: &mut TableState,
+    theme: &Theme,
+) {
+    let rows: Vec<Row> = results
+        .iter()
+        .map(|d| {
+            let short_id: String = d.id.chars().take(8).collect();
+            Row::new(vec![
+                Cell::from(short_id),
+                Cell::from(taken_day(d)),
+                Cell::from(d.fiat_code.clone()),
+                Cell::from(outcome_label(d)),
+            ])
+        })
+        .collect();
+    let bold = Style::default().add_modifier(Modifier::BOLD);
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(9),
+            Constraint::Length(11),
+            Constraint::Length(5),
+            Constraint::Min(10),
+        ],
+    )
+    .header(Row::new(vec![
+        Cell::from(tr("archive.col_order")).style(bold),
+        Cell::from(tr("archive.col_taken")).style(bold),
+        Cell::from(tr("archive.col_fiat")).style(bold),
+        Cell::from(tr("archive.col_outcome")).style(bold),
+    ]))
+    .block(archive_block(tr("archive.title"), theme))
+    .row_highlight_style(
+        Style::default()
+            .bg(theme.primary)
+            .fg(theme.on_fill)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    table_state.select(Some(selected));
+    f.render_stateful_widget(table, area, table_state);
+    hit_test::record(area, HitTarget::List);
+
+    let visible_rows = area.height.saturating_sub(3) as usize;
+    render_table_list_scrollbar(
+        f,
+        area,
+        results.len(),
+        visible_rows,
+        1,
+        table_state.offset(),
+    );
+}
+
+fn detail_lines<'a>(
+    dispute: &'a AdminDispute,
+    all: &'a [AdminDispute],
+    theme: &Theme,
+) -> Vec<Line<'a>> {
+    let label = Style::default().fg(theme.secondary);
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Order: ", label),
+            Span::raw(dispute.id.as_str()),
+        ]),
+        Line::from(vec![
+            Span::styled("Dispute: ", label),
+            Span::raw(dispute.dispute_id.as_str()),
+        ]),
+        Line::from(format!(
+            "{} {} · {} sats · {}",
+            dispute.fiat_amount, dispute.fiat_code, dispute.amount, dispute.payment_method
+        )),
+        Line::styled(
+            format!("{} · {}", outcome_label(dispute), bond_label(dispute)),
+            Style::default()
+                .fg(theme.positive)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+
+    for (role, pubkey) in [
+        (PartyRole::Buyer, dispute.buyer_pubkey.as_deref()),
+        (PartyRole::Seller, dispute.seller_pubkey.as_deref()),
+    ] {
+        let Some(pubkey) = pubkey else { continue };
+        let history = party_history(all, pubkey);
+        let title = match role {
+            PartyRole::Buyer => tr("archive.buyer"),
+            PartyRole::Seller => tr("archive.seller"),
+        };
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{title}: "),
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(pubkey),
+        ]));
+        let rating = history
+            .iter()
+            .find(|p| p.dispute.id == dispute.id)
+            .and_then(|p| p.rating);
+        lines.push(Line::from(match rating {
+            Some(info) => tr_args(
+                "archive.rating",
+                &[
+                    ("rating", &format!("{:.1}", info.rating)),
+                    ("reviews", &info.reviews.to_string()),
+                    ("days", &info.operating_days.to_string()),
+                ],
+            ),
+            None => tr("archive.no_rating").to_string(),
+        }));
+
+        let others: Vec<_> = history
+            .iter()
+            .filter(|p| p.dispute.id != dispute.id)
+            .collect();
+        lines.push(Line::styled(
+            tr_args("archive.history", &[("count", &others.len().to_string())]),
+            if others.is_empty() {
+                label
+            } else {
+                Style::default().fg(theme.warning)
+            },
+        ));
+        for past in others.into_iter().take(HISTORY_ROWS) {
+            let short_id: String = past.dispute.id.chars().take(8).collect();
+            let mut row = format!(
+                "  {} · {short_id} · {}",
+                taken_day(past.dispute),
+                match past.role {
+                    PartyRole::Buyer => tr("archive.as_buyer"),
+                    PartyRole::Seller => tr("archive.as_seller"),
+                }
+            );
+            if past.initiated {
+                row.push_str(&format!(" · {}", tr("archive.opened")));
+            }
+            row.push_str(&format!(
+                " · {} · {}",
+                outcome_label(past.dispute),
+                bond_label(past.dispute)
+            ));
+            lines.push(Line::from(row));
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{AdminTab, Tab, UserRole};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn buffer_text(buf: &ratatui::buffer::Buffer) -> String {
+        let mut flat = String::new();
+        for y in 0..buf.area.height {
+            for x in 0..buf.area.width {
+                flat.push_str(buf[(x, y)].symbol());
+            }
+            flat.push('\n');
+        }
+        flat
+    }
+
+    fn dispute(id: &str, status: &str, seller: &str, taken_at: i64) -> AdminDispute {
+        AdminDispute {
+            id: id.to_string(),
+            dispute_id: format!("{id}-dispute"),
+            status: Some(status.to_string()),
+            initiator_pubkey: "buyer-key".to_string(),
+            buyer_pubkey: Some("buyer-key".to_string()),
+            seller_pubkey: Some(seller.to_string()),
+            fiat_code: "USD".to_string(),
+            taken_at,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn archive_lists_finalized_disputes_and_party_history() {
+        let mut app = AppState::new(UserRole::Admin);
+        app.active_tab = Tab::Admin(AdminTab::DisputeArchive);
+        app.admin_disputes_in_progress = vec![
+            dispute("aaaa1111", "settled", "seller-one", 10),
+            dispute("bbbb2222", "seller-refunded", "seller-two", 20),
+            dispute("cccc3333", "in-progress", "seller-one", 30),
+        ];
+        assert_eq!(archive_result_count(&app), 2);
+
+        app.archive_query = "seller-one".to_string();
+        let mut terminal = Terminal::new(TestBackend::new(140, 30)).unwrap();
+        terminal
+            .draw(|f| render_dispute_archive_tab(f, f.area(), &mut app))
+            .unwrap();
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(text.contains("aaaa1111"));
+        assert!(text.contains(&tr_args("archive.results", &[("count", "1")])));
+        assert!(text.contains(tr("archive.settled")));
+        // The buyer shows up in two other disputes, the seller in the one still in progress.
+        assert!(text.contains(&tr_args("archive.history", &[("count", "2")])));
+        assert!(text.contains(&tr_args("archive.history", &[("count", "1")])));
+
+        app.archive_query = "from:2026-99-01".to_string();
+        assert_eq!(archive_result_count(&app), 0);
+    }
+}
@@ -1,21 +1,23 @@
 //! Admin disputes-in-progress UI. The Ctrl+H help overlay is styled in [`crate::ui::help_popup`].
 
 use ratatui::layout::{Constraint, Direction, Layout, Rect, Size};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{
     Block, BorderType, Borders, HighlightSpacing, List, ListItem, ListState, Paragraph,
 };
 use tui_scrollview::{ScrollView, ScrollbarVisibility};
 
+use crate::i18n::tr;
 use crate::ui::constants::*;
 use crate::ui::helpers::{
     build_chat_scrollview_content, count_visible_attachments, dispute_status_color,
     format_local_timestamp, format_user_rating, get_filtered_disputes, get_selected_chat_message,
     render_table_list_scrollbar,
 };
+use crate::ui::hit_test::{self, ChatScroll, HitTarget};
 use crate::ui::ChatParty;
-use crate::ui::{AdminMode, AppState, DisputeFilter, UiMode, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::{AdminMode, AppState, DisputeFilter, UiMode};
 
 fn should_auto_scroll_chat(
     tracker: Option<&(String, ChatParty, usize)>,
@@ -50,6 +52,7 @@ fn truncate_dispute_id_label(display_id: &str, max_chars: usize) -> String {
 /// This shows a sidebar with active disputes and a detailed view with chat interface
 /// Can filter between InProgress and Finalized disputes
 pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut AppState) {
+    let theme = &app.theme;
     let chunks = Layout::new(
         Direction::Horizontal,
         [Constraint::Percentage(20), Constraint::Percentage(80)],
@@ -75,8 +78,8 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
         .title(sidebar_title)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
 
     if filtered_disputes.is_empty() {
         let empty_msg = match app.dispute_filter {
@@ -103,19 +106,20 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
                 let truncated_id = truncate_dispute_id_label(&d.dispute_id, id_budget);
                 ListItem::new(Line::from(Span::styled(
                     format!("{truncated_id}  {status}"),
-                    Style::default().fg(dispute_status_color(d.status.as_deref())),
+                    Style::default().fg(dispute_status_color(d.status.as_deref(), theme)),
                 )))
             })
             .collect();
 
         let list = List::new(items)
             .block(disputes_block)
-            .highlight_style(Style::default().bg(PRIMARY_COLOR).fg(Color::Black))
+            .highlight_style(Style::default().bg(theme.primary).fg(theme.on_fill))
             .highlight_symbol("▶ ")
             .highlight_spacing(HighlightSpacing::Always);
 
         let mut list_state = ListState::default().with_selected(Some(valid_selected_idx));
         f.render_stateful_widget(list, sidebar_area, &mut list_state);
+        hit_test::record(sidebar_area, HitTarget::List);
 
         let visible_rows = sidebar_area.height.saturating_sub(2) as usize;
         render_table_list_scrollbar(
@@ -321,77 +325,78 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
         // Build header lines - expand for finalized disputes
         let mut header_lines = vec![
             Line::from(vec![
-                Span::styled("Order ID: ", Style::default().fg(Color::Gray)),
+                Span::styled("Order ID: ", Style::default().fg(theme.secondary)),
                 Span::styled(
                     &selected_dispute.id,
-                    Style::default()
-                        .fg(Color::White)
-                        .add_modifier(Modifier::BOLD),
+                    Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
                 ),
                 Span::raw("  "),
-                Span::styled("Dispute ID: ", Style::default().fg(Color::Gray)),
+                Span::styled("Dispute ID: ", Style::default().fg(theme.secondary)),
                 Span::styled(
                     &selected_dispute.dispute_id,
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(theme.accent)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw("  "),
-                Span::styled("Type: ", Style::default().fg(Color::Gray)),
+                Span::styled("Type: ", Style::default().fg(theme.secondary)),
                 Span::styled(
                     selected_dispute.kind.as_deref().unwrap_or("Unknown"),
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw("  "),
-                Span::styled("Status: ", Style::default().fg(Color::Gray)),
+                Span::styled("Status: ", Style::default().fg(theme.secondary)),
                 Span::styled(
                     selected_dispute.status.as_deref().unwrap_or("Unknown"),
                     Style::default()
-                        .fg(dispute_status_color(selected_dispute.status.as_deref()))
+                        .fg(dispute_status_color(
+                            selected_dispute.status.as_deref(),
+                            theme,
+                        ))
                         .add_modifier(Modifier::BOLD),
                 ),
             ]),
             Line::from(vec![
                 Span::styled(
                     format!("Initiator: {} ", initiator_role),
-                    Style::default().fg(Color::Gray),
+                    Style::default().fg(theme.secondary),
                 ),
-                Span::styled(&initiator_pubkey_display, Style::default().fg(Color::Cyan)),
+                Span::styled(&initiator_pubkey_display, Style::default().fg(theme.accent)),
                 Span::raw("  "),
-                Span::styled("Created: ", Style::default().fg(Color::Gray)),
-                Span::styled(&created_str, Style::default().fg(Color::Yellow)),
+                Span::styled("Created: ", Style::default().fg(theme.secondary)),
+                Span::styled(&created_str, Style::default().fg(theme.warning)),
             ]),
             Line::from(vec![
-                Span::styled("Amount: ", Style::default().fg(Color::Gray)),
+                Span::styled("Amount: ", Style::default().fg(theme.secondary)),
                 Span::styled(
                     format!("{} sats", selected_dispute.amount),
                     Style::default()
-                        .fg(Color::Green)
+                        .fg(theme.positive)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw("  "),
-                Span::styled("Fiat: ", Style::default().fg(Color::Gray)),
+                Span::styled("Fiat: ", Style::default().fg(theme.secondary)),
                 Span::styled(
                     format!(
                         "{} {}",
                         selected_dispute.fiat_amount, selected_dispute.fiat_code
                     ),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.warning),
                 ),
                 Span::raw("  |  "),
-                Span::styled("Privacy: ", Style::default().fg(Color::Gray)),
-                Span::styled(&buyer_label, Style::default().fg(Color::White)),
+                Span::styled("Privacy: ", Style::default().fg(theme.secondary)),
+                Span::styled(&buyer_label, Style::default().fg(theme.text)),
                 Span::raw("  "),
-                Span::styled(&seller_label, Style::default().fg(Color::White)),
+                Span::styled(&seller_label, Style::default().fg(theme.text)),
             ]),
             Line::from(vec![
-                Span::styled("Buyer Rating: ", Style::default().fg(Color::Gray)),
-                Span::styled(&buyer_rating, Style::default().fg(Color::Yellow)),
+                Span::styled("Buyer Rating: ", Style::default().fg(theme.secondary)),
+                Span::styled(&buyer_rating, Style::default().fg(theme.warning)),
                 Span::raw("  |  "),
-                Span::styled("Seller Rating: ", Style::default().fg(Color::Gray)),
-                Span::styled(&seller_rating, Style::default().fg(Color::Yellow)),
+                Span::styled("Seller Rating: ", Style::default().fg(theme.secondary)),
+                Span::styled(&seller_rating, Style::default().fg(theme.warning)),
             ]),
         ];
 
@@ -402,43 +407,43 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
                 "━━━ FINALIZATION DETAILS ━━━",
                 Style::default()
                     .add_modifier(Modifier::BOLD)
-                    .fg(PRIMARY_COLOR),
+                    .fg(theme.primary),
             )]));
             header_lines.push(Line::from(""));
             header_lines.push(Line::from(vec![
-                Span::styled("Taken At: ", Style::default().fg(Color::Gray)),
-                Span::styled(&taken_str, Style::default().fg(Color::Yellow)),
+                Span::styled("Taken At: ", Style::default().fg(theme.secondary)),
+                Span::styled(&taken_str, Style::default().fg(theme.warning)),
             ]));
             header_lines.push(Line::from(vec![
-                Span::styled("Payment Method: ", Style::default().fg(Color::Gray)),
+                Span::styled("Payment Method: ", Style::default().fg(theme.secondary)),
                 Span::styled(
                     &selected_dispute.payment_method,
-                    Style::default().fg(Color::White),
+                    Style::default().fg(theme.text),
                 ),
             ]));
             header_lines.push(Line::from(vec![
-                Span::styled("Premium: ", Style::default().fg(Color::Gray)),
+                Span::styled("Premium: ", Style::default().fg(theme.secondary)),
                 Span::styled(
                     format!("{}%", selected_dispute.premium),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.warning),
                 ),
                 Span::raw("  |  "),
-                Span::styled("Fee: ", Style::default().fg(Color::Gray)),
+                Span::styled("Fee: ", Style::default().fg(theme.secondary)),
                 Span::styled(
                     format!("{} sats", selected_dispute.fee),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.warning),
                 ),
                 Span::raw("  |  "),
-                Span::styled("Routing Fee: ", Style::default().fg(Color::Gray)),
+                Span::styled("Routing Fee: ", Style::default().fg(theme.secondary)),
                 Span::styled(
                     format!("{} sats", selected_dispute.routing_fee),
-                    Style::default().fg(Color::Yellow),
+                    Style::default().fg(theme.warning),
                 ),
             ]));
             if let Some(ref order_previous_status) = selected_dispute.order_previous_status {
                 header_lines.push(Line::from(vec![
-                    Span::styled("Previous Status: ", Style::default().fg(Color::Gray)),
-                    Span::styled(order_previous_status, Style::default().fg(Color::White)),
+                    Span::styled("Previous Status: ", Style::default().fg(theme.secondary)),
+                    Span::styled(order_previous_status, Style::default().fg(theme.text)),
                 ]));
             }
             if let Some(ref buyer_invoice) = selected_dispute.buyer_invoice {
@@ -449,8 +454,8 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
                         buyer_invoice.clone()
                     };
                     header_lines.push(Line::from(vec![
-                        Span::styled("Buyer Invoice: ", Style::default().fg(Color::Gray)),
-                        Span::styled(invoice_display, Style::default().fg(Color::Cyan)),
+                        Span::styled("Buyer Invoice: ", Style::default().fg(theme.secondary)),
+                        Span::styled(invoice_display, Style::default().fg(theme.accent)),
                     ]));
                 }
             }
@@ -468,13 +473,13 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
                     .title(Span::styled(
                         header_title,
                         Style::default()
-                            .fg(PRIMARY_COLOR)
+                            .fg(theme.primary)
                             .add_modifier(Modifier::BOLD),
                     ))
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(PRIMARY_COLOR))
-                    .style(Style::default().bg(BACKGROUND_COLOR)),
+                    .border_style(Style::default().fg(theme.primary))
+                    .style(Style::default().bg(theme.background)),
             )
             .alignment(ratatui::layout::Alignment::Left);
         f.render_widget(header, main_chunks[0]);
@@ -484,19 +489,19 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
             // Party Tabs
             let buyer_style = if app.active_chat_party == ChatParty::Buyer {
                 Style::default()
-                    .bg(Color::Green)
-                    .fg(Color::Black)
+                    .bg(theme.positive)
+                    .fg(theme.on_fill)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.positive)
             };
             let seller_style = if app.active_chat_party == ChatParty::Seller {
                 Style::default()
-                    .bg(Color::Red)
-                    .fg(Color::Black)
+                    .bg(theme.negative)
+                    .fg(theme.on_fill)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Red)
+                Style::default().fg(theme.negative)
             };
 
             let party_tabs_area = main_chunks[1];
@@ -569,6 +574,7 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
                 app.active_chat_party,
                 content_width,
                 Some(max_content_width),
+                theme,
             );
 
             let visible_count = content.line_start_per_message.len();
@@ -619,8 +625,8 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
                 .title(chat_title)
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(PRIMARY_COLOR))
-                .style(Style::default().bg(BACKGROUND_COLOR));
+                .border_style(Style::default().fg(theme.primary))
+                .style(Style::default().bg(theme.background));
             let inner_area = chat_block.inner(chat_area);
             f.render_widget(chat_block, chat_area);
 
@@ -637,6 +643,7 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
                 inner_area,
                 &mut app.admin_chat_scrollview_state,
             );
+            hit_test::record(inner_area, HitTarget::Chat(ChatScroll::Dispute));
 
             // Input Area
             // Check if we're in ManagingDispute mode (input is active)
@@ -646,10 +653,10 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
 
             let input_style = if is_input_focused && is_input_enabled {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.warning)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Gray)
+                Style::default().fg(theme.secondary)
             };
 
             let input_title = if is_input_focused && is_input_enabled {
@@ -662,10 +669,10 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
 
             let input_border_style = if is_input_focused && is_input_enabled {
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Gray)
+                Style::default().fg(theme.secondary)
             };
 
             let input = Paragraph::new(app.admin_chat_input.as_str())
@@ -683,15 +690,15 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
 
         // Footer (width-aware: minimal on narrow, 1 or 2 lines when wide; always include Ctrl+H)
         let filter_hint = match app.dispute_filter {
-            DisputeFilter::InProgress => FILTER_VIEW_FINALIZED,
-            DisputeFilter::Finalized => FILTER_VIEW_IN_PROGRESS,
+            DisputeFilter::InProgress => tr(FILTER_VIEW_FINALIZED),
+            DisputeFilter::Finalized => tr(FILTER_VIEW_IN_PROGRESS),
         };
         let has_selected_attachment = !is_finalized
             && get_selected_chat_message(app, &selected_dispute.dispute_id)
                 .and_then(|m| m.attachment.as_ref())
                 .is_some();
         let ctrl_s_hint = if has_selected_attachment {
-            FOOTER_CTRL_S_SAVE_FILE
+            tr(FOOTER_CTRL_S_SAVE_FILE)
         } else {
             ""
         };
@@ -701,26 +708,34 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
 
         // When wide (>=90) and not finalized, use two lines to avoid overflow
         let (footer_line1, footer_line2) = if footer_width < 50 {
-            (HELP_KEY.to_string(), None)
+            (tr(HELP_KEY).to_string(), None)
         } else if footer_width < 90 {
             let one = if is_finalized {
-                format!("{} | {} | {}", HELP_KEY, filter_hint, FOOTER_UP_DOWN_SELECT)
+                format!(
+                    "{} | {} | {}",
+                    tr(HELP_KEY),
+                    filter_hint,
+                    tr(FOOTER_UP_DOWN_SELECT)
+                )
             } else {
                 let is_input_focused =
                     matches!(app.mode, UiMode::AdminMode(AdminMode::ManagingDispute));
                 let short = if is_input_focused && app.admin_chat_input_enabled {
                     format!(
                         "{} | {} | {} | {} | {}",
-                        HELP_KEY,
-                        FOOTER_ENTER_SEND,
-                        FOOTER_TAB_PARTY,
-                        FOOTER_SHIFT_F_RESOLVE,
+                        tr(HELP_KEY),
+                        tr(FOOTER_ENTER_SEND),
+                        tr(FOOTER_TAB_PARTY),
+                        tr(FOOTER_SHIFT_F_RESOLVE),
                         filter_hint
                     )
                 } else {
                     format!(
                         "{} | {} | {} | {}",
-                        HELP_KEY, FOOTER_TAB_PARTY, FOOTER_SHIFT_F_RESOLVE, filter_hint
+                        tr(HELP_KEY),
+                        tr(FOOTER_TAB_PARTY),
+                        tr(FOOTER_SHIFT_F_RESOLVE),
+                        filter_hint
                     )
                 };
                 format!("{}{}", short, ctrl_s_hint)
@@ -730,7 +745,9 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
             (
                 format!(
                     "{} | {} | {}",
-                    HELP_KEY, filter_hint, FOOTER_UP_DOWN_SELECT_DISPUTE
+                    tr(HELP_KEY),
+                    filter_hint,
+                    tr(FOOTER_UP_DOWN_SELECT_DISPUTE)
                 ),
                 None,
             )
@@ -743,18 +760,18 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
                     (
                         format!(
                             "{} | {} | {} | {} | {} | {}",
-                            HELP_KEY,
-                            FOOTER_TAB_SWITCH_PARTY,
-                            FOOTER_ENTER_SEND,
-                            FOOTER_SHIFT_I_DISABLE,
-                            FOOTER_SHIFT_F_RESOLVE,
+                            tr(HELP_KEY),
+                            tr(FOOTER_TAB_SWITCH_PARTY),
+                            tr(FOOTER_ENTER_SEND),
+                            tr(FOOTER_SHIFT_I_DISABLE),
+                            tr(FOOTER_SHIFT_F_RESOLVE),
                             filter_hint
                         ),
                         format!(
                             "{} | {} | {}{}",
-                            FOOTER_PGUP_PGDN_SCROLL,
-                            FOOTER_END_BOTTOM,
-                            FOOTER_UP_DOWN_SELECT_DISPUTE,
+                            tr(FOOTER_PGUP_PGDN_SCROLL),
+                            tr(FOOTER_END_BOTTOM),
+                            tr(FOOTER_UP_DOWN_SELECT_DISPUTE),
                             ctrl_s_hint
                         ),
                     )
@@ -762,19 +779,19 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
                     (
                         format!(
                             "{} | {} | {} | {} | {}{}",
-                            HELP_KEY,
-                            FOOTER_TAB_SWITCH_PARTY,
-                            FOOTER_SHIFT_I_ENABLE,
-                            FOOTER_SHIFT_F_RESOLVE,
+                            tr(HELP_KEY),
+                            tr(FOOTER_TAB_SWITCH_PARTY),
+                            tr(FOOTER_SHIFT_I_ENABLE),
+                            tr(FOOTER_SHIFT_F_RESOLVE),
                             filter_hint,
                             ctrl_s_hint
                         ),
                         format!(
                             "{} | {} | {} | {}",
-                            FOOTER_PGUP_PGDN_SCROLL,
-                            FOOTER_NAV_CHAT,
-                            FOOTER_END_BOTTOM,
-                            FOOTER_UP_DOWN_SELECT_DISPUTE
+                            tr(FOOTER_PGUP_PGDN_SCROLL),
+                            tr(FOOTER_NAV_CHAT),
+                            tr(FOOTER_END_BOTTOM),
+                            tr(FOOTER_UP_DOWN_SELECT_DISPUTE)
                         ),
                     )
                 }
@@ -782,20 +799,32 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
                 (
                     format!(
                         "{} | {} | {} | {} | {}",
-                        HELP_KEY,
-                        FOOTER_TAB_SWITCH_PARTY,
-                        FOOTER_SHIFT_F_RESOLVE,
+                        tr(HELP_KEY),
+                        tr(FOOTER_TAB_SWITCH_PARTY),
+                        tr(FOOTER_SHIFT_F_RESOLVE),
                         filter_hint,
-                        FOOTER_UP_DOWN_SELECT_DISPUTE
+                        tr(FOOTER_UP_DOWN_SELECT_DISPUTE)
                     ),
                     format!(
                         "{} | {}{}",
-                        FOOTER_PGUP_PGDN_SCROLL_CHAT, FOOTER_END_BOTTOM, ctrl_s_hint
+                        tr(FOOTER_PGUP_PGDN_SCROLL_CHAT),
+                        tr(FOOTER_END_BOTTOM),
+                        ctrl_s_hint
                     ),
                 )
             };
             (line1, Some(line2))
         };
+        // Parked Observer session (Ctrl+G from the Observer tab): offer the way back.
+        let (footer_line1, footer_line2) = if app.observer_parked && footer_width >= 50 {
+            let hint = tr(FOOTER_CTRL_G_BACK_OBSERVER);
+            match footer_line2 {
+                Some(line2) => (footer_line1, Some(format!("{line2}{hint}"))),
+                None => (format!("{footer_line1}{hint}"), None),
+            }
+        } else {
+            (footer_line1, footer_line2)
+        };
 
         match (!is_finalized, app.attachment_toast.as_ref()) {
             (true, Some((toast_msg, _))) => {
@@ -807,7 +836,7 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
                 .split(footer_area);
                 let (toast_area, footer_areas) = (chunks[0], &chunks[1..]);
                 f.render_widget(
-                    Paragraph::new(toast_msg.as_str()).style(Style::default().fg(Color::Yellow)),
+                    Paragraph::new(toast_msg.as_str()).style(Style::default().fg(theme.warning)),
                     toast_area,
                 );
                 f.render_widget(Paragraph::new(footer_line1.as_str()), footer_areas[0]);
@@ -835,8 +864,8 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
         let outer_block = Block::default()
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(PRIMARY_COLOR))
-            .style(Style::default().bg(BACKGROUND_COLOR));
+            .border_style(Style::default().fg(theme.primary))
+            .style(Style::default().bg(theme.background));
         let inner_area = outer_block.inner(main_area);
         f.render_widget(outer_block, main_area);
 
@@ -857,16 +886,18 @@ pub fn render_disputes_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut
 
         // Render footer with key hints (width-aware)
         let filter_hint = match app.dispute_filter {
-            DisputeFilter::InProgress => FILTER_VIEW_FINALIZED,
-            DisputeFilter::Finalized => FILTER_VIEW_IN_PROGRESS,
+            DisputeFilter::InProgress => tr(FILTER_VIEW_FINALIZED),
+            DisputeFilter::Finalized => tr(FILTER_VIEW_IN_PROGRESS),
         };
         let footer_width = inner_chunks[1].width;
         let footer_text = if footer_width < 50 {
-            HELP_KEY.to_string()
+            tr(HELP_KEY).to_string()
         } else {
             format!(
                 "{} | {} | {}",
-                HELP_KEY, filter_hint, FOOTER_UP_DOWN_SELECT_DISPUTE
+                tr(HELP_KEY),
+                filter_hint,
+                tr(FOOTER_UP_DOWN_SELECT_DISPUTE)
             )
         };
         let footer = Paragraph::new(footer_text);
@@ -2,7 +2,7 @@ use std::sync::{Arc, Mutex};
 
 use mostro_core::prelude::*;
 use ratatui::layout::Constraint;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::Span;
 use ratatui::widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table};
 
@@ -10,7 +10,8 @@ use crate::ui::helpers::{
     format_local_timestamp, get_initiated_disputes, render_table_list_scrollbar,
     selected_pending_display_idx,
 };
-use crate::ui::{AppState, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::hit_test::{self, HitTarget};
+use crate::ui::AppState;
 
 /// Render the Disputes Pending table (admin mode only).
 ///
@@ -24,6 +25,7 @@ pub fn render_disputes_tab(
     disputes: &Arc<Mutex<Vec<Dispute>>>,
     app: &mut AppState,
 ) {
+    let theme = &app.theme;
     let disputes_lock = match disputes.lock() {
         Ok(g) => g,
         Err(e) => {
@@ -32,15 +34,15 @@ pub fn render_disputes_tab(
             ));
             let paragraph = Paragraph::new(Span::styled(
                 "❌ Internal error. Please restart Mostrix.",
-                Style::default().fg(Color::Red),
+                Style::default().fg(theme.negative),
             ))
             .block(
                 Block::default()
                     .title("Disputes Pending")
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(PRIMARY_COLOR))
-                    .style(Style::default().bg(BACKGROUND_COLOR)),
+                    .border_style(Style::default().fg(theme.primary))
+                    .style(Style::default().bg(theme.background)),
             );
             f.render_widget(paragraph, area);
             return;
@@ -54,15 +56,15 @@ pub fn render_disputes_tab(
     if initiated.is_empty() {
         let paragraph = Paragraph::new(Span::styled(
             "📭 No disputes found",
-            Style::default().fg(Color::Yellow),
+            Style::default().fg(theme.warning),
         ))
         .block(
             Block::default()
                 .title("Disputes Pending")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(PRIMARY_COLOR))
-                .style(Style::default().bg(BACKGROUND_COLOR)),
+                .border_style(Style::default().fg(theme.primary))
+                .style(Style::default().bg(theme.background)),
         );
         f.render_widget(paragraph, area);
         return;
@@ -121,13 +123,13 @@ pub fn render_disputes_tab(
                 .title("Disputes Pending")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(PRIMARY_COLOR))
-                .style(Style::default().bg(BACKGROUND_COLOR)),
+                .border_style(Style::default().fg(theme.primary))
+                .style(Style::default().bg(theme.background)),
         )
         .row_highlight_style(
             Style::default()
-                .bg(PRIMARY_COLOR)
-                .fg(Color::Black)
+                .bg(theme.primary)
+                .fg(theme.on_fill)
                 .add_modifier(Modifier::BOLD),
         );
 
@@ -152,6 +154,7 @@ pub fn render_disputes_tab(
     // Persistent TableState keeps ↑ scroll smooth (same as Orders tab).
     app.disputes_table_state.select(Some(valid_selected_idx));
     f.render_stateful_widget(table, area, &mut app.disputes_table_state);
+    hit_test::record(area, HitTarget::List);
 
     let header_rows = u16::from(show_header);
     let visible_rows = area.height.saturating_sub(2 + header_rows) as usize;
@@ -10,12 +10,14 @@ use ratatui::widgets::{
 use mostro_core::prelude::{Payload, SmallOrder};
 
 use crate::ui::helpers;
+use crate::ui::hit_test::{self, HitTarget};
 use crate::ui::orders::{
     listing_timeline_labels, message_action_compact_label_for_message,
     message_action_emoji_for_message, message_order_kind_label, message_status_presentation,
     message_trade_timeline_step, order_status_badge, FlowStep, StepLabel,
 };
-use crate::ui::{OrderMessage, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::{OrderMessage, Theme};
+use crate::util::fiat::{group_thousands, OrderFiat};
 
 /// Renders the order-message list and the selected trade timeline.
 pub fn render_messages_tab(
@@ -23,18 +25,19 @@ pub fn render_messages_tab(
     area: Rect,
     messages: &[OrderMessage],
     selected_idx: usize,
+    theme: &Theme,
 ) {
     let block = Block::default()
-        .title(sidebar_title(messages))
+        .title(sidebar_title(messages, theme))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
 
     f.render_widget(block.clone(), area);
     let inner = block.inner(area);
 
     if messages.is_empty() {
-        render_empty_state(f, inner);
+        render_empty_state(f, inner, theme);
         return;
     }
 
@@ -54,10 +57,10 @@ pub fn render_messages_tab(
     .split(columns[0]);
 
     let separator_width = left_chunks[0].width as usize;
-    let items = build_sidebar_items(messages, selected_idx, separator_width);
+    let items = build_sidebar_items(messages, selected_idx, separator_width, theme);
 
     let list = List::new(items)
-        .highlight_style(Style::default().bg(PRIMARY_COLOR).fg(Color::Black))
+        .highlight_style(Style::default().bg(theme.primary).fg(theme.on_fill))
         .highlight_symbol("▶ ")
         .highlight_spacing(ratatui::widgets::HighlightSpacing::Always);
 
@@ -66,36 +69,37 @@ pub fn render_messages_tab(
         left_chunks[0],
         &mut ratatui::widgets::ListState::default().with_selected(Some(selected_idx)),
     );
+    hit_test::record(left_chunks[0], HitTarget::List);
 
     let footer = Paragraph::new(Line::from(vec![
-        Span::styled("↑↓", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" move · ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Enter", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" open · ", Style::default().fg(Color::DarkGray)),
-        Span::styled("Ctrl+H", Style::default().fg(PRIMARY_COLOR)),
-        Span::styled(" help", Style::default().fg(Color::DarkGray)),
+        Span::styled("↑↓", Style::default().fg(theme.primary)),
+        Span::styled(" move · ", Style::default().fg(theme.muted)),
+        Span::styled("Enter", Style::default().fg(theme.primary)),
+        Span::styled(" open · ", Style::default().fg(theme.muted)),
+        Span::styled("Ctrl+H", Style::default().fg(theme.primary)),
+        Span::styled(" help", Style::default().fg(theme.muted)),
     ]))
     .alignment(ratatui::layout::Alignment::Center);
     f.render_widget(footer, left_chunks[1]);
 
-    render_message_timeline_panel(f, columns[1], selected_msg);
+    render_message_timeline_panel(f, columns[1], selected_msg, theme);
 }
 
 /// Sidebar title with total trade count and, when present, an unread badge.
-fn sidebar_title(messages: &[OrderMessage]) -> Line<'static> {
+fn sidebar_title(messages: &[OrderMessage], theme: &Theme) -> Line<'static> {
     let unread = messages.iter().filter(|m| !m.read).count();
     let mut spans = vec![Span::styled(
         format!(" 📨 My Trades ({}) ", messages.len()),
         Style::default()
-            .fg(PRIMARY_COLOR)
+            .fg(theme.primary)
             .add_modifier(Modifier::BOLD),
     )];
     if unread > 0 {
-        spans.push(Span::styled("· ", Style::default().fg(Color::DarkGray)));
+        spans.push(Span::styled("· ", Style::default().fg(theme.muted)));
         spans.push(Span::styled(
             format!("● {unread} new "),
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.warning)
                 .add_modifier(Modifier::BOLD),
         ));
     }
@@ -103,11 +107,11 @@ fn sidebar_title(messages: &[OrderMessage]) -> Line<'static> {
 }
 
 /// Emoji + color for the order-kind dot shown on each sidebar row.
-fn kind_dot(kind_label: &str) -> (&'static str, Color) {
+fn kind_dot(kind_label: &str, theme: &Theme) -> (&'static str, Color) {
     match kind_label {
-        "BUY" => ("🟢", Color::Green),
-        "SELL" => ("🔴", Color::Red),
-        _ => ("⚪", Color::DarkGray),
+        "BUY" => ("🟢", theme.buy),
+        "SELL" => ("🔴", theme.sell),
+        _ => ("⚪", theme.muted),
     }
 }
 
@@ -116,6 +120,7 @@ fn build_sidebar_items(
     messages: &[OrderMessage],
     selected_idx: usize,
     separator_width: usize,
+    theme: &Theme,
 ) -> Vec<ListItem<'static>> {
     let last_idx = messages.len().saturating_sub(1);
     messages
@@ -125,19 +130,17 @@ fn build_sidebar_items(
             let is_selected = idx == selected_idx;
             let base_style = if is_selected {
                 Style::default()
-                    .bg(PRIMARY_COLOR)
-                    .fg(Color::Black)
+                    .bg(theme.primary)
+                    .fg(theme.on_fill)
                     .add_modifier(Modifier::BOLD)
             } else if !msg.read {
-                Style::default()
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Gray)
+                Style::default().fg(theme.secondary)
             };
 
             let kind_label = message_order_kind_label(msg);
-            let (dot, kind_color) = kind_dot(kind_label);
+            let (dot, kind_color) = kind_dot(kind_label, theme);
             let kind_style = if is_selected {
                 base_style
             } else {
@@ -164,11 +167,8 @@ fn build_sidebar_items(
                 Span::styled(time, base_style),
             ];
             if !msg.read {
-                line3_spans.push(Span::styled(
-                    " · unread ",
-                    Style::default().fg(Color::DarkGray),
-                ));
-                line3_spans.push(Span::styled("●", Style::default().fg(Color::Yellow)));
+                line3_spans.push(Span::styled(" · unread ", Style::default().fg(theme.muted)));
+                line3_spans.push(Span::styled("●", Style::default().fg(theme.warning)));
             }
             let line3 = Line::from(line3_spans);
 
@@ -176,7 +176,7 @@ fn build_sidebar_items(
             if idx != last_idx {
                 lines.push(Line::from(Span::styled(
                     "─".repeat(separator_width.max(1)),
-                    Style::default().fg(Color::DarkGray),
+                    Style::default().fg(theme.muted),
                 )));
             }
 
@@ -188,7 +188,7 @@ fn build_sidebar_items(
 /// Empty state: a centered mailbox glyph over a short "no trades yet" message
 /// pointing at the Orders tab. On narrow/short panels the art is dropped so the
 /// text stays readable (readability over decoration).
-fn render_empty_state(f: &mut ratatui::Frame, area: Rect) {
+fn render_empty_state(f: &mut ratatui::Frame, area: Rect, theme: &Theme) {
     let art = helpers::MAILBOX_EMPTY_ART;
     let art_w = art.iter().map(|l| l.chars().count()).max().unwrap_or(0) as u16;
 
@@ -196,18 +196,16 @@ fn render_empty_state(f: &mut ratatui::Frame, area: Rect) {
     let text_lines = vec![
         Line::from(Span::styled(
             "Your mailbox is empty",
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
         )),
         Line::from(Span::styled(
             "No trades yet — messages from your orders will appear here.",
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         )),
         Line::from(Span::styled(
             "Go to the Orders tab to create or take an order",
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )),
     ];
@@ -229,7 +227,7 @@ fn render_empty_state(f: &mut ratatui::Frame, area: Rect) {
             width: area.width,
             height: art.len() as u16,
         };
-        helpers::render_centered_lines(f, art_area, art, style_mailbox_art);
+        helpers::render_centered_lines(f, art_area, art, |line| style_mailbox_art(line, theme));
         y = y.saturating_add(art.len() as u16 + 1);
     }
 
@@ -247,14 +245,19 @@ fn render_empty_state(f: &mut ratatui::Frame, area: Rect) {
     );
 }
 
-fn style_mailbox_art(line: &str) -> Vec<Span<'static>> {
+fn style_mailbox_art(line: &str, theme: &Theme) -> Vec<Span<'static>> {
     vec![Span::styled(
         line.to_string(),
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     )]
 }
 
-fn render_message_timeline_panel(f: &mut ratatui::Frame, area: Rect, selected_msg: &OrderMessage) {
+fn render_message_timeline_panel(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    selected_msg: &OrderMessage,
+    theme: &Theme,
+) {
     // Panel order mirrors the target mockup: header, progress stepper, TRADE
     // snapshot (fills the freed space), then the STATUS banner at the bottom.
     // Heights shrink on short terminals so TRADE keeps a usable receipt (Hermeme:
@@ -272,7 +275,7 @@ fn render_message_timeline_panel(f: &mut ratatui::Frame, area: Rect, selected_ms
     )
     .split(area);
 
-    render_header_card(f, right_chunks[0], selected_msg);
+    render_header_card(f, right_chunks[0], selected_msg, theme);
 
     let step_labels = listing_timeline_labels(selected_msg);
     render_trade_stepper(
@@ -280,10 +283,11 @@ fn render_message_timeline_panel(f: &mut ratatui::Frame, area: Rect, selected_ms
         right_chunks[1],
         message_trade_timeline_step(selected_msg),
         &step_labels,
+        theme,
     );
 
-    render_trade_snapshot_card(f, right_chunks[2], selected_msg);
-    render_status_card(f, right_chunks[3], selected_msg);
+    render_trade_snapshot_card(f, right_chunks[2], selected_msg, theme);
+    render_status_card(f, right_chunks[3], selected_msg, theme);
 }
 
 /// Allocate header / progress / status heights so TRADE retains a usable minimum
@@ -320,22 +324,22 @@ fn right_panel_heights(area_height: u16, narrow: bool) -> (u16, u16, u16) {
 /// STATUS banner: a one-line status (normal path / canceled / dispute) and, when
 /// appropriate, a `👉 Next:` callout. Replaces the old "State" box and the
 /// redundant numbered timeline. Text wraps, so it stays readable on narrow panels.
-fn render_status_card(f: &mut ratatui::Frame, area: Rect, msg: &OrderMessage) {
+fn render_status_card(f: &mut ratatui::Frame, area: Rect, msg: &OrderMessage, theme: &Theme) {
     let block = Block::default()
         .title(Span::styled(
             " STATUS ",
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         ))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .padding(Padding::horizontal(1))
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     let inner = block.inner(area);
     f.render_widget(&block, area);
 
-    let presentation = message_status_presentation(msg);
+    let presentation = message_status_presentation(msg, theme);
     let mut lines = vec![Line::from(vec![
         Span::styled(
             format!("{} ", presentation.emoji),
@@ -353,10 +357,10 @@ fn render_status_card(f: &mut ratatui::Frame, area: Rect, msg: &OrderMessage) {
             Span::styled(
                 "👉 Next: ",
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme.accent)
                     .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(next.to_string(), Style::default().fg(Color::Gray)),
+            Span::styled(next.to_string(), Style::default().fg(theme.secondary)),
         ]));
     }
     f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), inner);
@@ -364,27 +368,25 @@ fn render_status_card(f: &mut ratatui::Frame, area: Rect, msg: &OrderMessage) {
 
 /// Header card: order id + kind badge + maker/taker role chip, plus the absolute
 /// and relative last-update time.
-fn render_header_card(f: &mut ratatui::Frame, area: Rect, msg: &OrderMessage) {
+fn render_header_card(f: &mut ratatui::Frame, area: Rect, msg: &OrderMessage, theme: &Theme) {
     let block = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .padding(Padding::horizontal(1))
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     let inner = block.inner(area);
     f.render_widget(&block, area);
 
     let kind_label = message_order_kind_label(msg);
-    let (dot, kind_color) = kind_dot(kind_label);
+    let (dot, kind_color) = kind_dot(kind_label, theme);
     let (role_emoji, role_label) = role_chip(msg.is_mine);
-    let (status_emoji, status_color) = order_status_badge(msg.order_status);
+    let (status_emoji, status_color) = order_status_badge(msg.order_status, theme);
 
     let line1 = Line::from(vec![
-        Span::styled("🧾 Order ", Style::default().fg(Color::Gray)),
+        Span::styled("🧾 Order ", Style::default().fg(theme.secondary)),
         Span::styled(
             helpers::short_order_id(msg.order_id),
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
         ),
         Span::raw("   "),
         Span::styled(
@@ -394,7 +396,7 @@ fn render_header_card(f: &mut ratatui::Frame, area: Rect, msg: &OrderMessage) {
         Span::raw("   "),
         Span::styled(
             format!("{role_emoji} {role_label}"),
-            Style::default().fg(Color::Cyan),
+            Style::default().fg(theme.accent),
         ),
         Span::raw("   "),
         Span::styled(status_emoji.to_string(), Style::default().fg(status_color)),
@@ -405,7 +407,7 @@ fn render_header_card(f: &mut ratatui::Frame, area: Rect, msg: &OrderMessage) {
     let relative = helpers::relative_time_compact(msg.timestamp);
     let line2 = Line::from(Span::styled(
         format!("Last update: {absolute} ({relative})"),
-        Style::default().fg(Color::DarkGray),
+        Style::default().fg(theme.muted),
     ));
 
     f.render_widget(Paragraph::new(vec![line1, line2]), inner);
@@ -415,36 +417,54 @@ fn render_header_card(f: &mut ratatui::Frame, area: Rect, msg: &OrderMessage) {
 /// method, trade index, role). Values gracefully fall back to `—`. Renders two
 /// columns when there is room, and stacks into a single column on narrow panels
 /// so nothing is squeezed off-screen.
-fn render_trade_snapshot_card(f: &mut ratatui::Frame, area: Rect, msg: &OrderMessage) {
+fn render_trade_snapshot_card(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    msg: &OrderMessage,
+    theme: &Theme,
+) {
     let block = Block::default()
         .title(Span::styled(
             " TRADE ",
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         ))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     let inner = block.inner(area);
     f.render_widget(&block, area);
 
     let order = trade_order_snapshot(msg);
 
-    let (premium, premium_color) = premium_display(order);
+    let (premium, premium_color) = premium_display(order, theme);
     let (role_emoji, role_label) = role_chip(msg.is_mine);
-    let white = Style::default().fg(Color::White);
-
-    let fiat = snapshot_field("💰", "Fiat", fiat_display(order), white);
-    let sats = snapshot_field("⚡", "Sats", sats_display(order, msg.sat_amount), white);
-    let trade_idx = snapshot_field("🔑", "Trade idx", msg.trade_index.to_string(), white);
-    let premium = snapshot_field("📈", "Premium", premium, Style::default().fg(premium_color));
-    let method = snapshot_field("🧾", "Method", method_display(order), white);
+    let white = Style::default().fg(theme.text);
+
+    let fiat = snapshot_field("💰", "Fiat", fiat_display(order), white, theme);
+    let sats = snapshot_field(
+        "⚡",
+        "Sats",
+        sats_display(order, msg.sat_amount),
+        white,
+        theme,
+    );
+    let trade_idx = snapshot_field("🔑", "Trade idx", msg.trade_index.to_string(), white, theme);
+    let premium = snapshot_field(
+        "📈",
+        "Premium",
+        premium,
+        Style::default().fg(premium_color),
+        theme,
+    );
+    let method = snapshot_field("🧾", "Method", method_display(order), white, theme);
     let role = snapshot_field(
         role_emoji,
         "Role",
         role_label.to_string(),
-        Style::default().fg(Color::Cyan),
+        Style::default().fg(theme.accent),
+        theme,
     );
 
     if use_two_column_trade(inner.width) {
@@ -481,10 +501,16 @@ fn trade_order_snapshot(msg: &OrderMessage) -> Option<&SmallOrder> {
 /// One `emoji label value` row inside the TRADE snapshot card. The leading emoji
 /// stays at the start of the row (kept out of the aligned label/value columns) so
 /// double-width glyphs never break alignment.
-fn snapshot_field(emoji: &str, label: &str, value: String, value_style: Style) -> Line<'static> {
+fn snapshot_field(
+    emoji: &str,
+    label: &str,
+    value: String,
+    value_style: Style,
+    theme: &Theme,
+) -> Line<'static> {
     Line::from(vec![
-        Span::styled(format!(" {emoji} "), Style::default().fg(Color::Gray)),
-        Span::styled(format!("{label:<10}"), Style::default().fg(Color::DarkGray)),
+        Span::styled(format!(" {emoji} "), Style::default().fg(theme.secondary)),
+        Span::styled(format!("{label:<10}"), Style::default().fg(theme.muted)),
         Span::styled(value, value_style),
     ])
 }
@@ -504,9 +530,12 @@ fn fiat_display(order: Option<&SmallOrder>) -> String {
         return "—".to_string();
     };
     let code = order.fiat_code.trim().to_ascii_uppercase();
-    let value = match (order.min_amount, order.max_amount) {
-        (Some(min), Some(max)) if min > 0 && max > 0 => format!("{min}–{max}"),
-        _ if order.fiat_amount > 0 => order.fiat_amount.to_string(),
+    let fiat = OrderFiat::whole(order);
+    let value = match (fiat.min, fiat.max) {
+        (Some(min), Some(max)) if min.is_positive() && max.is_positive() => {
+            format!("{}–{}", min.grouped(), max.grouped())
+        }
+        _ if fiat.amount.is_positive() => fiat.amount.grouped(),
         _ => String::new(),
     };
     match (value.is_empty(), code.is_empty()) {
@@ -533,10 +562,10 @@ fn sats_display(order: Option<&SmallOrder>, sat_amount: Option<i64>) -> String {
 }
 
 /// Premium string + color: `+p%` green, `p%` red, `0%` gray, `—` when absent.
-fn premium_display(order: Option<&SmallOrder>) -> (String, Color) {
+fn premium_display(order: Option<&SmallOrder>, theme: &Theme) -> (String, Color) {
     match order {
-        None => ("—".to_string(), Color::DarkGray),
-        Some(order) => helpers::format_premium(order.premium),
+        None => ("—".to_string(), theme.muted),
+        Some(order) => helpers::format_premium(order.premium, theme),
     }
 }
 
@@ -550,27 +579,6 @@ fn method_display(order: Option<&SmallOrder>) -> String {
     }
 }
 
-/// Group an integer string into thousands (e.g. `142857` → `142,857`).
-fn group_thousands(raw: &str) -> String {
-    let trimmed = raw.trim();
-    let (sign, digits) = match trimmed.strip_prefix('-') {
-        Some(rest) => ("-", rest),
-        None => ("", trimmed),
-    };
-    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
-        return raw.to_string();
-    }
-    let mut out = String::new();
-    let n = digits.len();
-    for (i, ch) in digits.chars().enumerate() {
-        if i > 0 && (n - i) % 3 == 0 {
-            out.push(',');
-        }
-        out.push(ch);
-    }
-    format!("{sign}{out}")
-}
-
 /// Compact progress stepper: a single-line colored glyph track
 /// (`✔──✔──◉──○──○──○`) with the step labels underneath, plus a `LineGauge`
 /// showing `Step N of 6`. Render-only; `FlowStep`/label logic is unchanged.
@@ -579,6 +587,7 @@ fn render_trade_stepper(
     area: Rect,
     current_step: FlowStep,
     steps: &[StepLabel; 6],
+    theme: &Theme,
 ) {
     let current = current_step.step_number();
 
@@ -586,36 +595,34 @@ fn render_trade_stepper(
         .title(Span::styled(
             " PROGRESS ",
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         ))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     let inner = block.inner(area);
     f.render_widget(&block, area);
 
     if use_full_progress(inner.width) && inner.height >= 3 {
-        render_progress_full(f, inner, current, steps);
+        render_progress_full(f, inner, current, steps, theme);
     } else {
-        render_progress_compact(f, inner, current, steps);
+        render_progress_compact(f, inner, current, steps, theme);
     }
 }
 
 /// Progress gauge widget (`Step N of 6` + `▰▱` bar) shared by both layouts.
-fn progress_gauge(current: usize, total: usize) -> LineGauge<'static> {
+fn progress_gauge(current: usize, total: usize, theme: &Theme) -> LineGauge<'static> {
     let ratio = (current as f64 / total as f64).clamp(0.0, 1.0);
     LineGauge::default()
         .filled_symbol("▰")
         .unfilled_symbol("▱")
-        .filled_style(Style::default().fg(PRIMARY_COLOR))
-        .unfilled_style(Style::default().fg(Color::DarkGray))
+        .filled_style(Style::default().fg(theme.primary))
+        .unfilled_style(Style::default().fg(theme.muted))
         .ratio(ratio)
         .label(Span::styled(
             format!("Step {current} of {total} "),
-            Style::default()
-                .fg(Color::White)
-                .add_modifier(Modifier::BOLD),
+            Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
         ))
 }
 
@@ -627,10 +634,11 @@ fn render_glyph_columns(
     current: usize,
     steps: &[StepLabel; 6],
     with_labels: bool,
+    theme: &Theme,
 ) {
     let step_columns = Layout::new(Direction::Horizontal, [Constraint::Ratio(1, 6); 6]).split(area);
     for (idx, step_label) in steps.iter().enumerate() {
-        let (glyph, style) = step_glyph(idx + 1, current);
+        let (glyph, style) = step_glyph(idx + 1, current, theme);
         let width = step_columns[idx].width as usize;
         let mut lines = vec![glyph_cell_line(
             width,
@@ -638,14 +646,15 @@ fn render_glyph_columns(
             style,
             idx == 0,
             idx == steps.len() - 1,
+            theme,
         )];
         if with_labels {
             lines.push(Line::from(Span::styled(
-                center_in(step_label.top, width),
+                center_in(step_label.top_text(), width),
                 style,
             )));
             lines.push(Line::from(Span::styled(
-                center_in(step_label.bottom, width),
+                center_in(step_label.bottom_text(), width),
                 style,
             )));
         }
@@ -659,19 +668,20 @@ fn render_progress_full(
     inner: Rect,
     current: usize,
     steps: &[StepLabel; 6],
+    theme: &Theme,
 ) {
     let halves = Layout::new(
         Direction::Horizontal,
         [Constraint::Min(0), Constraint::Length(20)],
     )
     .split(inner);
-    render_glyph_columns(f, halves[0], current, steps, true);
+    render_glyph_columns(f, halves[0], current, steps, true, theme);
     // Gauge on the top row of its column, aligned with the glyph track.
     let gauge_area = Rect {
         height: 1,
         ..halves[1]
     };
-    f.render_widget(progress_gauge(current, steps.len()), gauge_area);
+    f.render_widget(progress_gauge(current, steps.len(), theme), gauge_area);
 }
 
 /// Narrow layout: drop the six per-step labels (unreadable when cramped) in
@@ -682,6 +692,7 @@ fn render_progress_compact(
     inner: Rect,
     current: usize,
     steps: &[StepLabel; 6],
+    theme: &Theme,
 ) {
     let rows = Layout::new(
         Direction::Vertical,
@@ -693,8 +704,8 @@ fn render_progress_compact(
     )
     .split(inner);
 
-    render_glyph_columns(f, rows[0], current, steps, false);
-    f.render_widget(progress_gauge(current, steps.len()), rows[1]);
+    render_glyph_columns(f, rows[0], current, steps, false, theme);
+    f.render_widget(progress_gauge(current, steps.len(), theme), rows[1]);
 
     let current_label = steps
         .get(current.saturating_sub(1))
@@ -704,7 +715,7 @@ fn render_progress_compact(
         Paragraph::new(Line::from(Span::styled(
             format!("▸ {current_label}"),
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )))
         .alignment(ratatui::layout::Alignment::Center),
@@ -725,23 +736,23 @@ fn use_full_progress(inner_width: u16) -> bool {
 
 /// Glyph + style for one step relative to the current step: done (`✔`, green),
 /// current (`◉`, primary), or upcoming (`○`, dim).
-fn step_glyph(step_number: usize, current: usize) -> (&'static str, Style) {
+fn step_glyph(step_number: usize, current: usize, theme: &Theme) -> (&'static str, Style) {
     if step_number < current {
         (
             "✔",
             Style::default()
-                .fg(Color::Green)
+                .fg(theme.positive)
                 .add_modifier(Modifier::BOLD),
         )
     } else if step_number == current {
         (
             "◉",
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )
     } else {
-        ("○", Style::default().fg(Color::DarkGray))
+        ("○", Style::default().fg(theme.muted))
     }
 }
 
@@ -754,6 +765,7 @@ fn glyph_cell_line(
     glyph_style: Style,
     is_first: bool,
     is_last: bool,
+    theme: &Theme,
 ) -> Line<'static> {
     if width == 0 {
         return Line::default();
@@ -761,7 +773,7 @@ fn glyph_cell_line(
     let mid = width / 2;
     let left_n = mid;
     let right_n = width - mid - 1;
-    let dash = Style::default().fg(Color::DarkGray);
+    let dash = Style::default().fg(theme.muted);
     let left = if is_first {
         " ".repeat(left_n)
     } else {
@@ -823,35 +835,39 @@ mod sidebar_tests {
 
     #[test]
     fn kind_dot_maps_buy_sell_and_unknown_to_distinct_glyphs() {
-        assert_eq!(kind_dot("BUY"), ("🟢", Color::Green));
-        assert_eq!(kind_dot("SELL"), ("🔴", Color::Red));
-        assert_eq!(kind_dot("N/A"), ("⚪", Color::DarkGray));
+        let theme = &Theme::dark();
+        assert_eq!(kind_dot("BUY", theme), ("🟢", Color::Green));
+        assert_eq!(kind_dot("SELL", theme), ("🔴", Color::Red));
+        assert_eq!(kind_dot("N/A", theme), ("⚪", Color::DarkGray));
     }
 
     #[test]
     fn sidebar_title_omits_unread_badge_when_all_read() {
+        let theme = &Theme::dark();
         let messages = vec![sample_message(true), sample_message(true)];
-        let text = spans_text(&sidebar_title(&messages));
+        let text = spans_text(&sidebar_title(&messages, theme));
         assert!(text.contains("My Trades (2)"));
         assert!(!text.contains("new"));
     }
 
     #[test]
     fn sidebar_title_shows_unread_count_badge() {
+        let theme = &Theme::dark();
         let messages = vec![
             sample_message(false),
             sample_message(true),
             sample_message(false),
         ];
-        let text = spans_text(&sidebar_title(&messages));
+        let text = spans_text(&sidebar_title(&messages, theme));
         assert!(text.contains("My Trades (3)"));
         assert!(text.contains("● 2 new"));
     }
 
     #[test]
     fn build_sidebar_items_adds_separator_between_rows_but_not_after_last() {
+        let theme = &Theme::dark();
         let messages = vec![sample_message(false), sample_message(true)];
-        let items = build_sidebar_items(&messages, 0, 20);
+        let items = build_sidebar_items(&messages, 0, 20, theme);
         assert_eq!(items.len(), 2);
         // Non-last row: kind/id + action + time + separator = 4 lines.
         assert_eq!(items[0].height(), 4);
@@ -915,14 +931,27 @@ mod trade_snapshot_tests {
 
     #[test]
     fn premium_display_signs_and_colors() {
+        let theme = &Theme::dark();
         let mut o = order(100, None, None);
         o.premium = 2;
-        assert_eq!(premium_display(Some(&o)), ("+2%".to_string(), Color::Green));
+        assert_eq!(
+            premium_display(Some(&o), theme),
+            ("+2%".to_string(), Color::Green)
+        );
         o.premium = -3;
-        assert_eq!(premium_display(Some(&o)), ("-3%".to_string(), Color::Red));
+        assert_eq!(
+            premium_display(Some(&o), theme),
+            ("-3%".to_string(), Color::Red)
+        );
         o.premium = 0;
-        assert_eq!(premium_display(Some(&o)), ("0%".to_string(), Color::Gray));
-        assert_eq!(premium_display(None), ("—".to_string(), Color::DarkGray));
+        assert_eq!(
+            premium_display(Some(&o), theme),
+            ("0%".to_string(), Color::Gray)
+        );
+        assert_eq!(
+            premium_display(None, theme),
+            ("—".to_string(), Color::DarkGray)
+        );
     }
 
     #[test]
@@ -1007,24 +1036,31 @@ mod trade_snapshot_tests {
 
     #[test]
     fn status_presentation_waiting_pay_invoice_unknown_role_still_has_next() {
+        let theme = &Theme::dark();
         use mostro_core::prelude::Action;
         // Unknown role defaults to taker for buy PayInvoice → actionable.
         let mut m = message_with(Action::PayInvoice, Some(Status::WaitingPayment));
         m.order_kind = Some(mostro_core::order::Kind::Buy);
-        let p = message_status_presentation(&m);
+        let p = message_status_presentation(&m, theme);
         assert_eq!(p.next, Some("Pay the hold invoice to continue."));
     }
 
     #[test]
     fn status_presentation_flags_canceled_and_dispute_without_next() {
+        let theme = &Theme::dark();
         use mostro_core::prelude::Action;
-        let p =
-            message_status_presentation(&message_with(Action::Canceled, Some(Status::Canceled)));
+        let p = message_status_presentation(
+            &message_with(Action::Canceled, Some(Status::Canceled)),
+            theme,
+        );
         assert_eq!(p.emoji, "❌");
         assert_eq!(p.color, Color::Red);
         assert!(p.next.is_none());
 
-        let p = message_status_presentation(&message_with(Action::Dispute, Some(Status::Dispute)));
+        let p = message_status_presentation(
+            &message_with(Action::Dispute, Some(Status::Dispute)),
+            theme,
+        );
         assert_eq!(p.emoji, "⚖️");
         assert_eq!(p.color, Color::Magenta);
         assert!(p.next.is_none());
@@ -1101,12 +1137,13 @@ mod layout_and_render_tests {
 
     #[test]
     fn render_messages_tab_shows_trade_fields_on_80x18() {
+        let theme = &Theme::dark();
         // 80x18 ≈ Messages content area on an 80x24 terminal (tabs+status reserved).
         let backend = TestBackend::new(80, 18);
         let mut terminal = Terminal::new(backend).unwrap();
         let messages = vec![rich_message()];
         terminal
-            .draw(|f| render_messages_tab(f, f.area(), &messages, 0))
+            .draw(|f| render_messages_tab(f, f.area(), &messages, 0, theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "TRADE"), "missing TRADE title");
@@ -1117,11 +1154,12 @@ mod layout_and_render_tests {
 
     #[test]
     fn render_messages_tab_shows_trade_on_60x18_narrow() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(60, 18);
         let mut terminal = Terminal::new(backend).unwrap();
         let messages = vec![rich_message()];
         terminal
-            .draw(|f| render_messages_tab(f, f.area(), &messages, 0))
+            .draw(|f| render_messages_tab(f, f.area(), &messages, 0, theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "TRADE"));
@@ -1135,11 +1173,12 @@ mod stepper_tests {
 
     #[test]
     fn step_glyph_marks_done_current_and_upcoming() {
+        let theme = &Theme::dark();
         // current step = 3
-        assert_eq!(step_glyph(1, 3).0, "✔");
-        assert_eq!(step_glyph(2, 3).0, "✔");
-        assert_eq!(step_glyph(3, 3).0, "◉");
-        assert_eq!(step_glyph(4, 3).0, "○");
+        assert_eq!(step_glyph(1, 3, theme).0, "✔");
+        assert_eq!(step_glyph(2, 3, theme).0, "✔");
+        assert_eq!(step_glyph(3, 3, theme).0, "◉");
+        assert_eq!(step_glyph(4, 3, theme).0, "○");
     }
 
     #[test]
@@ -1166,13 +1205,14 @@ mod stepper_tests {
 
     #[test]
     fn glyph_cell_line_blanks_outer_edges_of_first_and_last() {
-        let (glyph, style) = step_glyph(1, 1);
-        let first = glyph_cell_line(5, glyph, style, true, false);
+        let theme = &Theme::dark();
+        let (glyph, style) = step_glyph(1, 1, theme);
+        let first = glyph_cell_line(5, glyph, style, true, false, theme);
         let first_text: String = first.spans.iter().map(|s| s.content.as_ref()).collect();
         // First cell: no connector to the left of the glyph, dashes to the right.
         assert_eq!(first_text, "  ◉──");
 
-        let last = glyph_cell_line(5, glyph, style, false, true);
+        let last = glyph_cell_line(5, glyph, style, false, true, theme);
         let last_text: String = last.spans.iter().map(|s| s.content.as_ref()).collect();
         // Last cell: dashes to the left, blank to the right.
         assert_eq!(last_text, "──◉  ");
@@ -1,3 +1,4 @@
+pub mod dispute_archive_tab;
 pub mod disputes_in_progress_tab;
 pub mod disputes_tab;
 pub mod message_flow_tab;
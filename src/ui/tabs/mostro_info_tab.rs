@@ -1,20 +1,21 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Wrap};
 
-use crate::ui::{AppState, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::{AppState, Theme};
 use crate::util::{
     format_instance_info_age, transport_from_instance, MostroInstanceInfo, Transport,
 };
 
 pub fn render_mostro_info_tab(f: &mut ratatui::Frame, area: Rect, app: &AppState) {
+    let theme = &app.theme;
     let block = Block::default()
         .title("🧌 Mostro Instance Info")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
 
     let inner = block.inner(area);
     f.render_widget(block, area);
@@ -41,23 +42,28 @@ pub fn render_mostro_info_tab(f: &mut ratatui::Frame, area: Rect, app: &AppState
             f.render_widget(message, chunks[0]);
         }
         Some(info) => {
-            render_info_details(f, chunks[0], info);
+            render_info_details(f, chunks[0], info, theme);
         }
     }
 }
 
-fn render_info_details(f: &mut ratatui::Frame, area: Rect, info: &MostroInstanceInfo) {
-    let lines = build_info_lines(info);
+fn render_info_details(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    info: &MostroInstanceInfo,
+    theme: &Theme,
+) {
+    let lines = build_info_lines(info, theme);
     let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
         Block::default()
             .borders(Borders::NONE)
-            .style(Style::default().bg(BACKGROUND_COLOR)),
+            .style(Style::default().bg(theme.background)),
     );
 
     f.render_widget(paragraph, area);
 }
 
-fn build_info_lines(info: &MostroInstanceInfo) -> Vec<Line<'static>> {
+fn build_info_lines(info: &MostroInstanceInfo, theme: &Theme) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
 
     if let Some(ts) = &info.last_updated {
@@ -71,14 +77,14 @@ fn build_info_lines(info: &MostroInstanceInfo) -> Vec<Line<'static>> {
         if info.is_stale() {
             lines.push(Line::from(Span::styled(
                 "⚠ This data is older than 7 days and may be outdated",
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.warning),
             )));
         }
         lines.push(Line::default());
     }
 
     // Mostro daemon section
-    lines.push(section_title("Mostro daemon"));
+    lines.push(section_title("Mostro daemon", theme));
     push_kv(
         &mut lines,
         "Version",
@@ -145,7 +151,7 @@ fn build_info_lines(info: &MostroInstanceInfo) -> Vec<Line<'static>> {
     lines.push(Line::default());
 
     // Lightning node section
-    lines.push(section_title("Lightning node"));
+    lines.push(section_title("Lightning node", theme));
     push_kv(
         &mut lines,
         "Alias",
@@ -175,7 +181,7 @@ fn build_info_lines(info: &MostroInstanceInfo) -> Vec<Line<'static>> {
     lines.push(Line::default());
 
     // Fiat currencies section
-    lines.push(section_title("Fiat currencies"));
+    lines.push(section_title("Fiat currencies", theme));
     if info.fiat_currencies_accepted.is_empty() {
         lines.push(Line::from(Span::raw("All currencies are accepted.")));
     } else {
@@ -199,11 +205,11 @@ fn transport_display_label(transport: Transport) -> &'static str {
     }
 }
 
-fn section_title(title: &str) -> Line<'static> {
+fn section_title(title: &str, theme: &Theme) -> Line<'static> {
     Line::from(vec![Span::styled(
         title.to_string(),
         Style::default()
-            .fg(PRIMARY_COLOR)
+            .fg(theme.primary)
             .add_modifier(Modifier::BOLD),
     )])
 }
@@ -1,22 +1,24 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect, Size};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, Paragraph, Wrap};
 use tui_scrollview::{ScrollView, ScrollbarVisibility};
 
 use crate::ui::helpers::build_observer_scrollview_content;
-use crate::ui::{AppState, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::hit_test::{self, ChatScroll, HitTarget};
+use crate::ui::{AppState, ObserverField};
 
 /// Below this width the full field labels and footer (the longer footer line
-/// needs ~104 columns) no longer fit; fall back to the abbreviated compact
+/// needs ~100 columns) no longer fit; fall back to the abbreviated compact
 /// labels/footer instead of silently clipping keyboard shortcuts.
 const OBSERVER_NARROW_WIDTH: u16 = 60;
 
 pub fn render_observer_tab(f: &mut ratatui::Frame, area: Rect, app: &mut AppState) {
+    let theme = &app.theme;
     let compact = area.height < 16 || area.width < OBSERVER_NARROW_WIDTH;
-    // Compact footer is 3 short lines (vs. 2 long ones) so shortcuts stay
-    // readable instead of being cut off; field row stays 3 rows either way.
-    let input_height = if compact { 6 } else { 5 };
+    // Footer is 3 lines either way (short labels when compact) so shortcuts stay
+    // readable instead of being cut off; field row stays 3 rows.
+    let input_height = 6;
     // Borders consume 2 rows. Compact: 1 inner row (status/error). Full: 2 inner rows.
     let header_height = if compact { 3 } else { 4 };
     let chunks = Layout::new(
@@ -34,32 +36,49 @@ pub fn render_observer_tab(f: &mut ratatui::Frame, area: Rect, app: &mut AppStat
         Line::from(vec![
             Span::styled(
                 "Error: ",
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                Style::default()
+                    .fg(theme.negative)
+                    .add_modifier(Modifier::BOLD),
             ),
-            Span::styled(err.as_str(), Style::default().fg(Color::Red)),
+            Span::styled(err.as_str(), Style::default().fg(theme.negative)),
         ])
-    } else if !app.observer_messages.is_empty() {
-        Line::from(vec![
-            Span::styled("Status: ", Style::default().fg(Color::Gray)),
+    } else if !app.observer_messages.is_empty() || app.observer_is_live() {
+        let state = if app.observer_is_live() {
+            "Live"
+        } else {
+            "Loaded"
+        };
+        let mut spans = vec![
+            Span::styled("Status: ", Style::default().fg(theme.secondary)),
             Span::styled(
-                format!("Loaded {} message(s)", app.observer_messages.len()),
-                Style::default().fg(Color::Green),
+                format!("{state} · {} message(s)", app.observer_messages.len()),
+                Style::default().fg(theme.positive),
             ),
-        ])
+        ];
+        if let Some(dispute) = app.observer_linked_dispute() {
+            let short_id: String = dispute.dispute_id.chars().take(8).collect();
+            spans.push(Span::styled(
+                format!("  Linked dispute {short_id} (Ctrl+G)"),
+                Style::default()
+                    .fg(theme.primary)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        Line::from(spans)
     } else if app.observer_loading {
         Line::from(vec![
-            Span::styled("Status: ", Style::default().fg(Color::Gray)),
+            Span::styled("Status: ", Style::default().fg(theme.secondary)),
             Span::styled(
                 "Fetching messages from relays...",
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(theme.warning),
             ),
         ])
     } else {
         Line::from(vec![
-            Span::styled("Status: ", Style::default().fg(Color::Gray)),
+            Span::styled("Status: ", Style::default().fg(theme.secondary)),
             Span::styled(
                 "Paste Shared key and press Enter to load chat",
-                Style::default().fg(Color::Gray),
+                Style::default().fg(theme.secondary),
             ),
         ])
     };
@@ -72,7 +91,7 @@ pub fn render_observer_tab(f: &mut ratatui::Frame, area: Rect, app: &mut AppStat
                 Span::styled(
                     "Observer Mode",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::raw("  –  paste Shared key (read-only). Never paste a signing key."),
@@ -86,13 +105,13 @@ pub fn render_observer_tab(f: &mut ratatui::Frame, area: Rect, app: &mut AppStat
             .title(Span::styled(
                 "🔍 Observer",
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ))
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(PRIMARY_COLOR))
-            .style(Style::default().bg(BACKGROUND_COLOR)),
+            .border_style(Style::default().fg(theme.primary))
+            .style(Style::default().bg(theme.background)),
     );
     f.render_widget(header, chunks[0]);
 
@@ -101,8 +120,8 @@ pub fn render_observer_tab(f: &mut ratatui::Frame, area: Rect, app: &mut AppStat
         .title("Chat messages")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
     let chat_area = chunks[1];
     let inner_area = chat_block.inner(chat_area);
     f.render_widget(chat_block, chat_area);
@@ -115,7 +134,7 @@ pub fn render_observer_tab(f: &mut ratatui::Frame, area: Rect, app: &mut AppStat
         };
         let paragraph = Paragraph::new(Line::from(Span::styled(
             hint,
-            Style::default().fg(Color::Gray),
+            Style::default().fg(theme.secondary),
         )));
         f.render_widget(paragraph, inner_area);
     } else {
@@ -127,6 +146,7 @@ pub fn render_observer_tab(f: &mut ratatui::Frame, area: Rect, app: &mut AppStat
             &app.observer_messages,
             viewport_width,
             Some(max_content_width),
+            theme,
         );
 
         // Auto-scroll to bottom only when new messages arrive; preserve manual scroll otherwise.
@@ -157,21 +177,32 @@ pub fn render_observer_tab(f: &mut ratatui::Frame, area: Rect, app: &mut AppStat
             content_rect,
         );
         f.render_stateful_widget(scroll_view, inner_area, &mut app.observer_scrollview_state);
+        hit_test::record(inner_area, HitTarget::Chat(ChatScroll::Observer));
     }
 
-    // Shared key input + footer
-    let footer_height = if compact { 3 } else { 2 };
+    // Shared key + Sign pubkey inputs side by side, then the footer
     let input_chunks = Layout::new(
         Direction::Vertical,
-        [Constraint::Length(3), Constraint::Length(footer_height)],
+        [Constraint::Length(3), Constraint::Length(3)],
     )
     .split(chunks[2]);
+    let field_chunks = Layout::new(
+        Direction::Horizontal,
+        [Constraint::Percentage(60), Constraint::Percentage(40)],
+    )
+    .split(input_chunks[0]);
 
-    let focused_border = Style::default()
-        .fg(PRIMARY_COLOR)
-        .add_modifier(Modifier::BOLD);
+    let border_for = |field: ObserverField| {
+        if app.observer_focus == field {
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.secondary)
+        }
+    };
     let title_style = Style::default()
-        .fg(PRIMARY_COLOR)
+        .fg(theme.primary)
         .add_modifier(Modifier::BOLD);
 
     let conv_title = if compact {
@@ -185,13 +216,27 @@ pub fn render_observer_tab(f: &mut ratatui::Frame, area: Rect, app: &mut AppStat
             .title(Span::styled(conv_title, title_style))
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(focused_border),
+            .border_style(border_for(ObserverField::SharedKey)),
+    );
+    f.render_widget(conv_input, field_chunks[0]);
+
+    let sign_title = if compact {
+        "Sign pubkey"
+    } else {
+        "Sign pubkey (optional)"
+    };
+    let sign_input = Paragraph::new(app.observer_sign_pubkey_input.as_str()).block(
+        Block::default()
+            .title(Span::styled(sign_title, title_style))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(border_for(ObserverField::SignPubkey)),
     );
-    f.render_widget(conv_input, input_chunks[0]);
+    f.render_widget(sign_input, field_chunks[1]);
 
     let footer_text = if compact {
         // Shortened so shortcuts stay visible instead of clipping on narrow terminals.
-        "Ctrl+H:Help  Paste\n\
+        "Ctrl+H:Help  Paste  Tab:Field\n\
 Enter:Load  Esc:Clear  Ctrl+C:All\n\
 Ctrl+S:Save  \u{2191}\u{2193}/PgUp/PgDn:Scroll"
             .to_string()
@@ -202,8 +247,9 @@ Ctrl+S:Save  \u{2191}\u{2193}/PgUp/PgDn:Scroll"
             "Ctrl+V / Ctrl+Shift+V / middle-click"
         };
         format!(
-            "Ctrl+H: Help | Paste ({paste_hint})\n\
-Enter: Load chat | Esc: Clear error | Ctrl+C: Clear all | Ctrl+S: Save attachment | ↑↓/PgUp/PgDn: Scroll"
+            "Ctrl+H: Help | Paste ({paste_hint}) | Tab: Switch field\n\
+Enter: Load chat and follow live | Esc: Clear error | Ctrl+C: Clear all | Ctrl+G: Linked dispute\n\
+Ctrl+S: Save attachment | ↑↓/PgUp/PgDn: Scroll"
         )
     };
     let footer = Paragraph::new(footer_text);
@@ -249,8 +295,8 @@ mod tests {
             "missing read-only grant copy"
         );
         assert!(
-            !buffer_contains(buf, "Signer pubkey"),
-            "Signer pubkey input box should be removed"
+            buffer_contains(buf, "Sign pubkey (optional)"),
+            "missing optional Sign pubkey locator"
         );
     }
 
@@ -1,13 +1,14 @@
 //! My Trades / order chat UI. Ctrl+H and Shift+H help overlays are styled in [`crate::ui::help_popup`].
 
 use ratatui::layout::{Constraint, Direction, Layout, Rect, Size};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Wrap};
 use tui_scrollview::{ScrollView, ScrollbarVisibility};
 use unicode_segmentation::UnicodeSegmentation;
 use uuid::Uuid;
 
+use crate::i18n::tr;
 use crate::ui::constants::{
     FOOTER_CTRL_O_SEND_FILE, FOOTER_CTRL_SHIFT_O_RETRY, FOOTER_CTRL_S_SAVE_FILE,
     FOOTER_MYTRADES_END_BOTTOM, FOOTER_MYTRADES_ENTER_SEND, FOOTER_MYTRADES_PGUP_PGDN_SCROLL_CHAT,
@@ -17,12 +18,14 @@ use crate::ui::constants::{
     FOOTER_MYTRADES_SHIFT_V_RATE, FOOTER_MYTRADES_TAB_CHAT, FOOTER_SENDING_ATTACHMENT, HELP_KEY,
 };
 use crate::ui::helpers::{
-    active_order_chat_list_snapshot, count_order_attachments, format_local_timestamp,
-    format_user_rating,
+    active_order_chat_list_snapshot, count_order_attachments, format_deadline,
+    format_local_timestamp, format_user_rating,
 };
+use crate::ui::hit_test::{self, ChatScroll, HitTarget};
+use crate::ui::Theme;
 use crate::ui::UserOrderChatMessage;
 use crate::ui::{AppState, UserChatChannel, UserChatSender};
-use crate::ui::{BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::util::fiat::FiatAmount;
 
 /// `Order ID: …` for the sidebar — same style as disputes; shows the full id when it fits the column.
 fn sidebar_order_list_label(order_id: &str, inner_width: u16) -> String {
@@ -46,6 +49,7 @@ fn build_order_chat_content(
     messages: &[UserOrderChatMessage],
     content_width: u16,
     channel: UserChatChannel,
+    theme: &Theme,
 ) -> (Vec<Line<'static>>, u16, Vec<usize>) {
     fn wrap_text_to_lines(content: &str, max_width: u16) -> Vec<String> {
         if max_width == 0 {
@@ -111,11 +115,11 @@ fn build_order_chat_content(
             },
         };
         let color = match sender {
-            UserChatSender::You => Color::Cyan,
-            UserChatSender::Peer => Color::Green,
+            UserChatSender::You => theme.party_you,
+            UserChatSender::Peer => theme.party_peer,
         };
         let content_color = if msg.attachment.is_some() {
-            Color::Yellow
+            theme.warning
         } else {
             color
         };
@@ -146,7 +150,7 @@ fn build_order_chat_content(
     if lines.is_empty() {
         lines.push(Line::from(Span::styled(
             "No messages yet. Start the conversation!",
-            Style::default().fg(Color::Gray),
+            Style::default().fg(theme.secondary),
         )));
     }
     (lines, content_width.max(1), starts)
@@ -174,6 +178,7 @@ fn trailing_order_chat_input(input: &str, visible_width: u16) -> String {
 }
 
 pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut AppState) {
+    let theme = &app.theme;
     let active_orders = active_order_chat_list_snapshot(app);
 
     let chunks = Layout::new(
@@ -195,8 +200,8 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
         .title("Orders In Progress")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
     if active_orders.is_empty() {
         f.render_widget(
             Paragraph::new("No active orders yet")
@@ -215,30 +220,49 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
                     .title("Order Chat")
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(PRIMARY_COLOR))
-                    .style(Style::default().bg(BACKGROUND_COLOR)),
+                    .border_style(Style::default().fg(theme.primary))
+                    .style(Style::default().bg(theme.background)),
             ),
             empty_main_chunks[0],
         );
-        f.render_widget(Paragraph::new(HELP_KEY), empty_main_chunks[1]);
+        f.render_widget(Paragraph::new(tr(HELP_KEY)), empty_main_chunks[1]);
         return;
     }
 
-    let sidebar_text_width = sidebar_block.inner(sidebar_area).width.max(1);
+    let sidebar_inner = sidebar_block.inner(sidebar_area);
+    let sidebar_text_width = sidebar_inner.width.max(1);
     let items: Vec<ListItem> = active_orders
         .iter()
         .enumerate()
         .map(|(idx, row)| {
             let style = if idx == selected_idx {
-                Style::default().bg(PRIMARY_COLOR).fg(Color::Black)
+                Style::default().bg(theme.primary).fg(theme.on_fill)
             } else {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme.text)
             };
             let label = sidebar_order_list_label(&row.order_id, sidebar_text_width);
             ListItem::new(Line::from(Span::styled(label, style)))
         })
         .collect();
     f.render_widget(List::new(items).block(sidebar_block), sidebar_area);
+    hit_test::record(sidebar_area, HitTarget::List);
+    for (slot, row) in active_orders
+        .iter()
+        .take(sidebar_inner.height as usize)
+        .enumerate()
+    {
+        if let Ok(id) = Uuid::parse_str(&row.order_id) {
+            hit_test::record(
+                Rect::new(
+                    sidebar_inner.x,
+                    sidebar_inner.y + slot as u16,
+                    sidebar_inner.width,
+                    1,
+                ),
+                HitTarget::MyTradesRow(id),
+            );
+        }
+    }
 
     let selected = &active_orders[selected_idx];
     let input_height: u16 = 3;
@@ -291,12 +315,34 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
         .unwrap_or_else(|| "Unknown".to_string());
     let amount_line = match (selected.amount, &selected.fiat) {
         (Some(sats), Some((fiat_amount, fiat_code))) => {
+            let fiat_amount = FiatAmount::from_whole(*fiat_amount);
             format!("{sats} sats | {fiat_amount} {fiat_code}")
         }
         (Some(sats), None) => format!("{sats} sats"),
         _ => "amount N/A".to_string(),
     };
 
+    let mut amount_spans = vec![
+        Span::styled("Amount: ", Style::default().fg(theme.secondary)),
+        Span::styled(
+            amount_line,
+            Style::default()
+                .fg(theme.positive)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    // Live countdown for the current time-bounded step (book expiry, hold invoice, buyer invoice,
+    // bond); redrawn every tick so it escalates color as the deadline nears.
+    if let Some(deadline) = selected.deadline(app.mostro_info.as_ref()) {
+        let (countdown, style) = format_deadline(&deadline, chrono::Utc::now().timestamp(), theme);
+        amount_spans.push(Span::raw("  "));
+        amount_spans.push(Span::styled(
+            format!("⏳ {}: ", deadline.kind.label()),
+            Style::default().fg(theme.secondary),
+        ));
+        amount_spans.push(Span::styled(countdown, style));
+    }
+
     // TODO(My Trades header): Wire "Privacy:", "Buyer -", "Seller -" from trade privacy / full-privacy
     // signals once available on DM payloads or local `orders` (see dispute UI + `Order::is_full_privacy_order`).
     // Omit that row until then — avoid static "Unknown" placeholders.
@@ -310,11 +356,11 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
         .or_else(|| static_h.and_then(|header| header.dispute_id.as_deref()));
     let context_line = if let Some(dispute_id) = dispute_id {
         Line::from(vec![
-            Span::styled("Dispute ID: ", Style::default().fg(Color::Gray)),
+            Span::styled("Dispute ID: ", Style::default().fg(theme.secondary)),
             Span::styled(
                 dispute_id.to_string(),
                 Style::default()
-                    .fg(Color::Magenta)
+                    .fg(theme.highlight)
                     .add_modifier(Modifier::BOLD),
             ),
         ])
@@ -322,57 +368,47 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
         Line::from(vec![
             Span::styled(
                 format!("Initiator: {initiator_role} "),
-                Style::default().fg(Color::Gray),
+                Style::default().fg(theme.secondary),
             ),
-            Span::styled(initiator_pubkey_display, Style::default().fg(Color::Cyan)),
+            Span::styled(initiator_pubkey_display, Style::default().fg(theme.accent)),
             Span::raw("  "),
-            Span::styled("Created: ", Style::default().fg(Color::Gray)),
-            Span::styled(created_str, Style::default().fg(Color::Yellow)),
+            Span::styled("Created: ", Style::default().fg(theme.secondary)),
+            Span::styled(created_str, Style::default().fg(theme.warning)),
         ])
     };
     let mut header_lines: Vec<Line> = vec![
         Line::from(vec![
-            Span::styled("Order ID: ", Style::default().fg(Color::Gray)),
+            Span::styled("Order ID: ", Style::default().fg(theme.secondary)),
             Span::styled(
                 order_id_display,
-                Style::default()
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
             ),
             Span::raw("  "),
-            Span::styled("Trade ID: ", Style::default().fg(Color::Gray)),
+            Span::styled("Trade ID: ", Style::default().fg(theme.secondary)),
             Span::styled(
                 trade_id,
                 Style::default()
-                    .fg(Color::Cyan)
+                    .fg(theme.accent)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw("  "),
-            Span::styled("Type: ", Style::default().fg(Color::Gray)),
+            Span::styled("Type: ", Style::default().fg(theme.secondary)),
             Span::styled(
                 order_kind,
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::raw("  "),
-            Span::styled("Status: ", Style::default().fg(Color::Gray)),
+            Span::styled("Status: ", Style::default().fg(theme.secondary)),
             Span::styled(status_label, Style::default().add_modifier(Modifier::BOLD)),
         ]),
         context_line,
-        Line::from(vec![
-            Span::styled("Amount: ", Style::default().fg(Color::Gray)),
-            Span::styled(
-                amount_line,
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]),
+        Line::from(amount_spans),
     ];
 
-    let gray = Style::default().fg(Color::Gray);
-    let yellow = Style::default().fg(Color::Yellow);
+    let gray = Style::default().fg(theme.secondary);
+    let yellow = Style::default().fg(theme.warning);
     let mut payment_row: Vec<Span> = Vec::new();
     let mut any_rating = false;
     if let Some(ref info) = selected.buyer_reputation {
@@ -394,14 +430,15 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
     payment_row.push(Span::styled("Payment: ", gray));
     payment_row.push(Span::styled(
         payment_method.to_string(),
-        Style::default().fg(Color::White),
+        Style::default().fg(theme.text),
     ));
     payment_row.push(Span::raw("  "));
     payment_row.push(Span::styled("Premium: ", gray));
     payment_row.push(Span::styled(premium_text, yellow));
     header_lines.push(Line::from(payment_row));
 
-    let header_height = header_lines.len() as u16;
+    // +2 for the block borders, otherwise the Amount / Payment rows are clipped.
+    let header_height = (header_lines.len() as u16).saturating_add(2);
 
     let spare_below_header_input = main_area
         .height
@@ -424,34 +461,30 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
     let footer_height =
         footer_height.saturating_add(if app.attachment_toast.is_some() { 1 } else { 0 });
 
-    let file_count = if active_channel == UserChatChannel::Peer {
-        count_order_attachments(app, &selected.order_id)
-    } else {
-        0
-    };
+    let file_count = count_order_attachments(app, &selected.order_id, active_channel);
     let mut attach_hints = if active_channel == UserChatChannel::Peer {
-        FOOTER_CTRL_O_SEND_FILE.to_string()
+        tr(FOOTER_CTRL_O_SEND_FILE).to_string()
     } else {
         String::new()
     };
     if file_count > 0 {
-        attach_hints.push_str(FOOTER_CTRL_S_SAVE_FILE);
+        attach_hints.push_str(tr(FOOTER_CTRL_S_SAVE_FILE));
     }
     if active_channel == UserChatChannel::Peer
         && app
             .pending_order_attachment_sends
             .contains_key(&selected.order_id)
     {
-        attach_hints.push_str(FOOTER_CTRL_SHIFT_O_RETRY);
+        attach_hints.push_str(tr(FOOTER_CTRL_SHIFT_O_RETRY));
     }
     if active_channel == UserChatChannel::Peer
         && app.sending_attachment_order_id.as_deref() == Some(selected.order_id.as_str())
     {
-        attach_hints.push_str(FOOTER_SENDING_ATTACHMENT);
+        attach_hints.push_str(tr(FOOTER_SENDING_ATTACHMENT));
     }
     if solver_available {
         attach_hints.push_str(" | ");
-        attach_hints.push_str(FOOTER_MYTRADES_TAB_CHAT);
+        attach_hints.push_str(tr(FOOTER_MYTRADES_TAB_CHAT));
     }
     let attach_hints = attach_hints.as_str();
 
@@ -471,13 +504,13 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
                 .title(Span::styled(
                     "📋 Order Info",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(PRIMARY_COLOR))
-                .style(Style::default().bg(BACKGROUND_COLOR)),
+                .border_style(Style::default().fg(theme.primary))
+                .style(Style::default().bg(theme.background)),
         ),
         main_chunks[0],
     );
@@ -506,15 +539,15 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
         .title(chat_title)
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
     let chat_inner = chat_block.inner(chat_area);
     f.render_widget(chat_block, chat_area);
 
     // Match disputes/observer chat: content width reserves one column for the vertical scrollbar.
     let content_width = chat_inner.width.saturating_sub(1).max(1);
     let (chat_lines, _, line_starts) =
-        build_order_chat_content(&chat_messages, content_width, active_channel);
+        build_order_chat_content(&chat_messages, content_width, active_channel, theme);
     app.order_chat_line_starts = line_starts;
     let content_height = chat_lines.len().min(u16::MAX as usize) as u16;
 
@@ -548,6 +581,7 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
         chat_inner,
         &mut app.order_chat_scrollview_state,
     );
+    hit_test::record(chat_inner, HitTarget::Chat(ChatScroll::Order));
 
     let input_active = app.mode.user_my_trades_interactive() && app.order_chat_input_enabled;
     let input_block = Block::default()
@@ -558,7 +592,7 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
         })
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR));
+        .border_style(Style::default().fg(theme.primary));
     let visible_input = trailing_order_chat_input(
         &app.order_chat_input,
         input_block.inner(main_chunks[2]).width,
@@ -567,9 +601,9 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
         Paragraph::new(visible_input)
             .wrap(Wrap { trim: false })
             .style(if input_active {
-                Style::default().fg(Color::White)
+                Style::default().fg(theme.text)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(theme.muted)
             })
             .block(input_block),
         main_chunks[2],
@@ -591,30 +625,30 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
     let hint_lines = base_footer_lines;
 
     let footer_body: Text<'static> = if footer_width < 50 {
-        Text::raw(format!("{HELP_KEY}{attach_hints}"))
+        Text::raw(format!("{}{attach_hints}", tr(HELP_KEY)))
     } else if hint_lines >= 3 {
         if app.order_chat_input_enabled {
             Text::from(vec![
                 Line::from(format!(
                     "{} | {} | {} | {}",
-                    HELP_KEY,
-                    FOOTER_MYTRADES_SELECT_ORDER,
-                    FOOTER_MYTRADES_ENTER_SEND,
-                    FOOTER_MYTRADES_SHIFT_I_DISABLE,
+                    tr(HELP_KEY),
+                    tr(FOOTER_MYTRADES_SELECT_ORDER),
+                    tr(FOOTER_MYTRADES_ENTER_SEND),
+                    tr(FOOTER_MYTRADES_SHIFT_I_DISABLE),
                 )),
                 Line::from(format!(
                     "{} | {} | {} | {}",
-                    FOOTER_MYTRADES_SHIFT_C_CANCEL,
-                    FOOTER_MYTRADES_SHIFT_D_DISPUTE,
-                    FOOTER_MYTRADES_SHIFT_F_FIAT_SENT,
-                    FOOTER_MYTRADES_SHIFT_R_RELEASE,
+                    tr(FOOTER_MYTRADES_SHIFT_C_CANCEL),
+                    tr(FOOTER_MYTRADES_SHIFT_D_DISPUTE),
+                    tr(FOOTER_MYTRADES_SHIFT_F_FIAT_SENT),
+                    tr(FOOTER_MYTRADES_SHIFT_R_RELEASE),
                 )),
                 Line::from(format!(
                     "{} | {} | {} | {}{}",
-                    FOOTER_MYTRADES_PGUP_PGDN_SCROLL_CHAT,
-                    FOOTER_MYTRADES_END_BOTTOM,
-                    FOOTER_MYTRADES_SHIFT_V_RATE,
-                    FOOTER_MYTRADES_SHIFT_K_KCONV,
+                    tr(FOOTER_MYTRADES_PGUP_PGDN_SCROLL_CHAT),
+                    tr(FOOTER_MYTRADES_END_BOTTOM),
+                    tr(FOOTER_MYTRADES_SHIFT_V_RATE),
+                    tr(FOOTER_MYTRADES_SHIFT_K_KCONV),
                     attach_hints,
                 )),
             ])
@@ -622,21 +656,23 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
             Text::from(vec![
                 Line::from(format!(
                     "{} | {} | {}",
-                    HELP_KEY, FOOTER_MYTRADES_SELECT_ORDER, FOOTER_MYTRADES_SHIFT_I_ENABLE,
+                    tr(HELP_KEY),
+                    tr(FOOTER_MYTRADES_SELECT_ORDER),
+                    tr(FOOTER_MYTRADES_SHIFT_I_ENABLE),
                 )),
                 Line::from(format!(
                     "{} | {} | {} | {}",
-                    FOOTER_MYTRADES_SHIFT_C_CANCEL,
-                    FOOTER_MYTRADES_SHIFT_D_DISPUTE,
-                    FOOTER_MYTRADES_SHIFT_F_FIAT_SENT,
-                    FOOTER_MYTRADES_SHIFT_R_RELEASE,
+                    tr(FOOTER_MYTRADES_SHIFT_C_CANCEL),
+                    tr(FOOTER_MYTRADES_SHIFT_D_DISPUTE),
+                    tr(FOOTER_MYTRADES_SHIFT_F_FIAT_SENT),
+                    tr(FOOTER_MYTRADES_SHIFT_R_RELEASE),
                 )),
                 Line::from(format!(
                     "{} | {} | {} | {}{}",
-                    FOOTER_MYTRADES_PGUP_PGDN_SCROLL_CHAT,
-                    FOOTER_MYTRADES_END_BOTTOM,
-                    FOOTER_MYTRADES_SHIFT_V_RATE,
-                    FOOTER_MYTRADES_SHIFT_K_KCONV,
+                    tr(FOOTER_MYTRADES_PGUP_PGDN_SCROLL_CHAT),
+                    tr(FOOTER_MYTRADES_END_BOTTOM),
+                    tr(FOOTER_MYTRADES_SHIFT_V_RATE),
+                    tr(FOOTER_MYTRADES_SHIFT_K_KCONV),
                     attach_hints,
                 )),
             ])
@@ -646,18 +682,18 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
             Text::from(vec![
                 Line::from(format!(
                     "{} | {} | {} | {}",
-                    HELP_KEY,
-                    FOOTER_MYTRADES_SELECT_ORDER,
-                    FOOTER_MYTRADES_ENTER_SEND,
-                    FOOTER_MYTRADES_SHIFT_I_DISABLE,
+                    tr(HELP_KEY),
+                    tr(FOOTER_MYTRADES_SELECT_ORDER),
+                    tr(FOOTER_MYTRADES_ENTER_SEND),
+                    tr(FOOTER_MYTRADES_SHIFT_I_DISABLE),
                 )),
                 Line::from(format!(
                     "{} | {} | {} | {} | {}{}",
-                    FOOTER_MYTRADES_SHIFT_C_CANCEL,
-                    FOOTER_MYTRADES_SHIFT_D_DISPUTE,
-                    FOOTER_MYTRADES_SHIFT_F_FIAT_SENT,
-                    FOOTER_MYTRADES_SHIFT_R_RELEASE,
-                    FOOTER_MYTRADES_SHIFT_V_RATE,
+                    tr(FOOTER_MYTRADES_SHIFT_C_CANCEL),
+                    tr(FOOTER_MYTRADES_SHIFT_D_DISPUTE),
+                    tr(FOOTER_MYTRADES_SHIFT_F_FIAT_SENT),
+                    tr(FOOTER_MYTRADES_SHIFT_R_RELEASE),
+                    tr(FOOTER_MYTRADES_SHIFT_V_RATE),
                     attach_hints,
                 )),
             ])
@@ -665,18 +701,18 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
             Text::from(vec![
                 Line::from(format!(
                     "{} | {} | {} | {} | {}",
-                    HELP_KEY,
-                    FOOTER_MYTRADES_SELECT_ORDER,
-                    FOOTER_MYTRADES_SHIFT_I_ENABLE,
-                    FOOTER_MYTRADES_SHIFT_C_CANCEL,
-                    FOOTER_MYTRADES_SHIFT_D_DISPUTE,
+                    tr(HELP_KEY),
+                    tr(FOOTER_MYTRADES_SELECT_ORDER),
+                    tr(FOOTER_MYTRADES_SHIFT_I_ENABLE),
+                    tr(FOOTER_MYTRADES_SHIFT_C_CANCEL),
+                    tr(FOOTER_MYTRADES_SHIFT_D_DISPUTE),
                 )),
                 Line::from(format!(
                     "{} | {} | {} | {}{}",
-                    FOOTER_MYTRADES_SHIFT_F_FIAT_SENT,
-                    FOOTER_MYTRADES_PGUP_PGDN_SCROLL_CHAT,
-                    FOOTER_MYTRADES_SHIFT_R_RELEASE,
-                    FOOTER_MYTRADES_SHIFT_V_RATE,
+                    tr(FOOTER_MYTRADES_SHIFT_F_FIAT_SENT),
+                    tr(FOOTER_MYTRADES_PGUP_PGDN_SCROLL_CHAT),
+                    tr(FOOTER_MYTRADES_SHIFT_R_RELEASE),
+                    tr(FOOTER_MYTRADES_SHIFT_V_RATE),
                     attach_hints,
                 )),
             ])
@@ -685,21 +721,21 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
         let base = if app.order_chat_input_enabled {
             format!(
                 "{} | {} | {} | {} | {} | {}",
-                HELP_KEY,
-                FOOTER_MYTRADES_SELECT_ORDER,
-                FOOTER_MYTRADES_ENTER_SEND,
-                FOOTER_MYTRADES_SHIFT_I_DISABLE,
-                FOOTER_MYTRADES_SHIFT_C_CANCEL,
-                FOOTER_MYTRADES_SHIFT_D_DISPUTE
+                tr(HELP_KEY),
+                tr(FOOTER_MYTRADES_SELECT_ORDER),
+                tr(FOOTER_MYTRADES_ENTER_SEND),
+                tr(FOOTER_MYTRADES_SHIFT_I_DISABLE),
+                tr(FOOTER_MYTRADES_SHIFT_C_CANCEL),
+                tr(FOOTER_MYTRADES_SHIFT_D_DISPUTE)
             )
         } else {
             format!(
                 "{} | {} | {} | {} | {}",
-                HELP_KEY,
-                FOOTER_MYTRADES_SELECT_ORDER,
-                FOOTER_MYTRADES_SHIFT_I_ENABLE,
-                FOOTER_MYTRADES_SHIFT_C_CANCEL,
-                FOOTER_MYTRADES_SHIFT_D_DISPUTE
+                tr(HELP_KEY),
+                tr(FOOTER_MYTRADES_SELECT_ORDER),
+                tr(FOOTER_MYTRADES_SHIFT_I_ENABLE),
+                tr(FOOTER_MYTRADES_SHIFT_C_CANCEL),
+                tr(FOOTER_MYTRADES_SHIFT_D_DISPUTE)
             )
         };
         Text::raw(format!("{base}{attach_hints}"))
@@ -713,7 +749,7 @@ pub fn render_order_in_progress(f: &mut ratatui::Frame, area: Rect, app: &mut Ap
         .split(footer_area);
         let (toast_msg, _) = app.attachment_toast.as_ref().unwrap();
         f.render_widget(
-            Paragraph::new(toast_msg.as_str()).style(Style::default().fg(Color::Yellow)),
+            Paragraph::new(toast_msg.as_str()).style(Style::default().fg(theme.warning)),
             chunks[0],
         );
         f.render_widget(
@@ -839,6 +875,10 @@ mod tests {
             seller_reputation: None,
             solver_pubkey: None,
             dispute_id: None,
+            phase_started_at: None,
+            payment_invoice: None,
+            created_at: None,
+            expires_at: None,
         });
         app.order_chat_input = format!(
             "hidden-prefix-that-should-scroll-away-{}-visible-suffix",
@@ -893,6 +933,10 @@ mod tests {
             seller_reputation: None,
             solver_pubkey: Some("solver-pubkey".to_string()),
             dispute_id: None,
+            phase_started_at: None,
+            payment_invoice: None,
+            created_at: None,
+            expires_at: None,
         });
         app.user_dispute_chats.insert(
             order_id,
@@ -937,6 +981,10 @@ mod tests {
             seller_reputation: None,
             solver_pubkey: None,
             dispute_id: None,
+            phase_started_at: None,
+            payment_invoice: None,
+            created_at: None,
+            expires_at: None,
         });
 
         let backend = TestBackend::new(120, 24);
@@ -952,6 +1000,40 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn render_header_shows_pending_order_expiry_countdown() {
+        let mut app = AppState::new(UserRole::User);
+        app.mode = UiMode::UserMode(UserMode::Normal);
+        app.my_trades_maker_book.push(OrderChatListItem {
+            order_id: Uuid::nil().to_string(),
+            status: Some(Status::Pending),
+            amount: Some(1000),
+            fiat: Some((10, "USD".to_string())),
+            trade_index: Some(1),
+            payment_method: Some("cash".to_string()),
+            premium: Some(0),
+            buyer_trade_pubkey: None,
+            seller_trade_pubkey: None,
+            buyer_reputation: None,
+            seller_reputation: None,
+            solver_pubkey: None,
+            dispute_id: None,
+            phase_started_at: None,
+            payment_invoice: None,
+            created_at: None,
+            expires_at: Some(chrono::Utc::now().timestamp() + 3 * 3_600 + 630),
+        });
+
+        let backend = TestBackend::new(120, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| render_order_in_progress(frame, frame.area(), &mut app))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+
+        assert!(buffer_contains(buffer, "Expires in: 3h 10m"));
+    }
+
     #[test]
     fn tab_switches_to_solver_chat_only_after_assignment() {
         let mut app = AppState::new(UserRole::User);
@@ -970,6 +1052,10 @@ mod tests {
             seller_reputation: None,
             solver_pubkey: None,
             dispute_id: None,
+            phase_started_at: None,
+            payment_invoice: None,
+            created_at: None,
+            expires_at: None,
         });
 
         handle_tab_navigation(KeyCode::Tab, &mut app);
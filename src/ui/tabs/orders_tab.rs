@@ -1,16 +1,20 @@
 use std::sync::{Arc, Mutex};
 
-use mostro_core::prelude::*;
 use ratatui::layout::{Constraint, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::Span;
 use ratatui::widgets::{Block, BorderType, Borders, Cell, Paragraph, Row, Table};
 
 use crate::ui::helpers::{
-    format_local_timestamp, format_premium, get_filtered_book_orders, render_table_list_scrollbar,
-    selected_book_display_idx,
+    format_deadline, format_local_timestamp, format_premium, get_filtered_book_orders,
+    render_table_list_scrollbar, selected_book_display_idx,
 };
-use crate::ui::{apply_kind_color, AppState, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::hit_test::{self, HitTarget};
+use crate::ui::{apply_kind_color, AppState, Theme};
+use crate::util::fiat::BookOrder;
+use crate::util::order_expiry_deadline;
+use crate::util::order_utils::book_is_stale;
+use crate::util::payment_methods::payment_methods;
 
 /// Renders the available orders table, with fewer columns when terminal width is limited.
 ///
@@ -20,12 +24,16 @@ use crate::ui::{apply_kind_color, AppState, BACKGROUND_COLOR, PRIMARY_COLOR};
 /// projection (`helpers/order_selection.rs`) so highlight and Enter stay aligned.
 /// Vertical scrollbar uses [`render_table_list_scrollbar`] (offset + data-row track).
 /// On short terminals (`height < 4`) the header is dropped so a data row remains.
+/// Each visible row is recorded for mouse hit-testing by order id.
+/// The wide layout adds a live "Expires" countdown (order `expires_at`, else `created_at` plus the
+/// instance `expiration_hours`) that turns yellow, then bold red, as the order nears expiry.
 pub fn render_orders_tab(
     f: &mut ratatui::Frame,
     area: Rect,
-    orders: &Arc<Mutex<Vec<SmallOrder>>>,
+    orders: &Arc<Mutex<Vec<BookOrder>>>,
     app: &mut AppState,
 ) {
+    let theme = &app.theme;
     let orders_lock = match orders.lock() {
         Ok(g) => g,
         Err(e) => {
@@ -34,51 +42,70 @@ pub fn render_orders_tab(
             ));
             let paragraph = Paragraph::new(Span::styled(
                 "❌ Internal error. Please restart Mostrix.",
-                Style::default().fg(Color::Red),
+                Style::default().fg(theme.negative),
             ))
             .block(
                 Block::default()
                     .title("Orders")
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
-                    .border_style(Style::default().fg(PRIMARY_COLOR))
-                    .style(Style::default().bg(BACKGROUND_COLOR)),
+                    .border_style(Style::default().fg(theme.primary))
+                    .style(Style::default().bg(theme.background)),
             );
             f.render_widget(paragraph, area);
             return;
         }
     };
 
+    let stale = book_is_stale();
     if orders_lock.is_empty() {
-        let paragraph = Paragraph::new(Span::styled(
-            "📭 No offers found with requested parameters…",
-            Style::default().fg(Color::Red),
-        ))
+        let paragraph = Paragraph::new(if stale {
+            Span::styled(
+                "⏳ Syncing the order book with relays…",
+                Style::default().fg(theme.muted),
+            )
+        } else {
+            Span::styled(
+                "📭 No offers found with requested parameters…",
+                Style::default().fg(theme.negative),
+            )
+        })
         .block(
             Block::default()
                 .title("Orders")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(PRIMARY_COLOR))
-                .style(Style::default().bg(BACKGROUND_COLOR)),
+                .border_style(Style::default().fg(theme.primary))
+                .style(Style::default().bg(theme.background)),
         );
         f.render_widget(paragraph, area);
         return;
     }
 
-    let filtered = get_filtered_book_orders(&orders_lock, &app.currencies_filter);
+    let filtered = get_filtered_book_orders(
+        &orders_lock,
+        &app.currencies_filter,
+        app.book_payment_filter.as_deref(),
+    );
+    let mut title = match &app.book_payment_filter {
+        Some(method) => format!("Orders · 💳 {method}"),
+        None => "Orders".to_string(),
+    };
+    if stale {
+        title.push_str(" · ⏳ cached, stale until synced");
+    }
     if filtered.is_empty() {
         let paragraph = Paragraph::new(Span::styled(
-            "📭 No offers match the current currency filter…",
-            Style::default().fg(Color::Yellow),
+            "📭 No offers match the current filters…",
+            Style::default().fg(theme.warning),
         ))
         .block(
             Block::default()
-                .title("Orders")
+                .title(title)
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(PRIMARY_COLOR))
-                .style(Style::default().bg(BACKGROUND_COLOR)),
+                .border_style(Style::default().fg(theme.primary))
+                .style(Style::default().bg(theme.background)),
         );
         f.render_widget(paragraph, area);
         return;
@@ -104,14 +131,16 @@ pub fn render_orders_tab(
             "± Premium",
             "💳 Payment Method",
             "📅 Created",
+            "⏳ Expires",
         ]
     };
+    let now = chrono::Utc::now().timestamp();
 
     let rows: Vec<Row> = filtered
         .iter()
         .map(|(_orig, order)| {
             let kind_cell = if let Some(k) = &order.kind {
-                Cell::from(k.to_string()).style(apply_kind_color(k))
+                Cell::from(k.to_string()).style(apply_kind_color(k, theme))
             } else {
                 Cell::from("BUY/SELL")
             };
@@ -137,10 +166,11 @@ pub fn render_orders_tab(
 
             let fiat_code_cell = Cell::from(order.fiat_code.clone());
 
-            let fiat_amount_text = if order.min_amount.is_none() && order.max_amount.is_none() {
-                order.fiat_amount.to_string()
+            let fiat = order.fiat;
+            let fiat_amount_text = if fiat.min.is_none() && fiat.max.is_none() {
+                fiat.amount.to_string()
             } else {
-                match (order.min_amount, order.max_amount) {
+                match (fiat.min, fiat.max) {
                     (Some(min), Some(max)) => format!("{}-{}", min, max),
                     (Some(min), None) => format!("{}-?", min),
                     (None, Some(max)) => format!("?-{}", max),
@@ -149,8 +179,9 @@ pub fn render_orders_tab(
             };
             let fiat_amount_cell = Cell::from(fiat_amount_text.clone());
 
-            let payment_method_cell = Cell::from(order.payment_method.clone());
-            let premium_cell = premium_cell(order.premium);
+            let payment_method_cell =
+                Cell::from(payment_methods().normalize_list(&order.payment_method));
+            let premium_cell = premium_cell(order.premium, theme);
 
             // Missing created_at must not fall back to epoch (unwrap_or(0)); propagate None.
             let date_cell = Cell::from(
@@ -160,6 +191,18 @@ pub fn render_orders_tab(
                     .unwrap_or_else(|| "Invalid date".to_string()),
             );
 
+            let expires_cell = match order_expiry_deadline(
+                order.expires_at,
+                order.created_at,
+                app.mostro_info.as_ref(),
+            ) {
+                Some(deadline) => {
+                    let (text, style) = format_deadline(&deadline, now, theme);
+                    Cell::from(text).style(style)
+                }
+                None => Cell::from("—"),
+            };
+
             if compact {
                 Row::new(vec![
                     kind_cell,
@@ -178,6 +221,7 @@ pub fn render_orders_tab(
                     premium_cell,
                     payment_method_cell,
                     date_cell,
+                    expires_cell,
                 ])
             }
         })
@@ -201,19 +245,20 @@ pub fn render_orders_tab(
             Constraint::Max(10),
             Constraint::Min(15),
             Constraint::Max(18),
+            Constraint::Max(10),
         ]
     };
 
     let row_count = rows.len();
     let mut table = Table::new(rows, widths)
-        .row_highlight_style(Style::default().bg(PRIMARY_COLOR).fg(Color::Black))
+        .row_highlight_style(Style::default().bg(theme.primary).fg(theme.on_fill))
         .block(
             Block::default()
-                .title("Orders")
+                .title(title)
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(PRIMARY_COLOR))
-                .style(Style::default().bg(BACKGROUND_COLOR)),
+                .border_style(Style::default().fg(theme.primary))
+                .style(Style::default().bg(theme.background)),
         );
 
     if show_header {
@@ -237,10 +282,30 @@ pub fn render_orders_tab(
         header_rows,
         app.orders_table_state.offset(),
     );
+
+    hit_test::record(area, HitTarget::List);
+    let first_row_y = area.y + 1 + header_rows;
+    let visible = filtered
+        .iter()
+        .skip(app.orders_table_state.offset())
+        .take(visible_rows);
+    for (slot, (_, order)) in visible.enumerate() {
+        if let Some(id) = order.id {
+            hit_test::record(
+                Rect::new(
+                    area.x + 1,
+                    first_row_y + slot as u16,
+                    area.width.saturating_sub(2),
+                    1,
+                ),
+                HitTarget::OrderRow(id),
+            );
+        }
+    }
 }
 
-fn premium_cell(premium: i64) -> Cell<'static> {
-    let (text, color) = format_premium(premium);
+fn premium_cell(premium: i64, theme: &Theme) -> Cell<'static> {
+    let (text, color) = format_premium(premium, theme);
     Cell::from(text).style(Style::default().fg(color))
 }
 
@@ -252,6 +317,7 @@ mod tests {
     use uuid::Uuid;
 
     use crate::ui::UserRole;
+    use mostro_core::prelude::SmallOrder;
 
     fn buffer_contains(buf: &ratatui::buffer::Buffer, needle: &str) -> bool {
         let mut flat = String::new();
@@ -264,8 +330,8 @@ mod tests {
         flat.contains(needle)
     }
 
-    fn sample_order(payment_method: &str, premium: i64) -> SmallOrder {
-        SmallOrder {
+    fn sample_order(payment_method: &str, premium: i64) -> BookOrder {
+        BookOrder::whole(SmallOrder {
             id: Some(Uuid::new_v4()),
             kind: Some(mostro_core::order::Kind::Buy),
             fiat_code: "USD".to_string(),
@@ -274,7 +340,7 @@ mod tests {
             premium,
             payment_method: payment_method.to_string(),
             ..Default::default()
-        }
+        })
     }
 
     fn render_at_width(width: u16, premium: i64) -> ratatui::buffer::Buffer {
@@ -306,6 +372,48 @@ mod tests {
         assert!(!buffer_contains(&buf, "Created"));
     }
 
+    #[test]
+    fn wide_orders_table_shows_expiry_countdown() {
+        let backend = TestBackend::new(150, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut order = sample_order("SEPA", 0);
+        order.order.expires_at = Some(chrono::Utc::now().timestamp() + 2 * 3_600 + 630);
+        let orders = Arc::new(Mutex::new(vec![order]));
+        let mut app = AppState::new(UserRole::User);
+        terminal
+            .draw(|f| render_orders_tab(f, f.area(), &orders, &mut app))
+            .unwrap();
+        let buf = terminal.backend().buffer().clone();
+        assert!(buffer_contains(&buf, "Expires"));
+        assert!(buffer_contains(&buf, "2h 10m"));
+    }
+
+    /// Scrolled tables must record row regions for the rows actually on screen.
+    #[test]
+    fn row_regions_follow_scroll_offset() {
+        let book: Vec<BookOrder> = (0..30)
+            .map(|i| sample_order(&format!("PAY-{i:02}"), 0))
+            .collect();
+        let last = book.last().and_then(|o| o.id).unwrap();
+        let orders = Arc::new(Mutex::new(book));
+        let mut app = AppState::new(UserRole::User);
+        app.selected_order_id = Some(last);
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 10)).unwrap();
+        hit_test::begin_frame();
+        terminal
+            .draw(|f| render_orders_tab(f, f.area(), &orders, &mut app))
+            .unwrap();
+        let map = hit_test::finish_frame(&app.mode);
+
+        // Selected last row sits on the bottom body line (above the border).
+        let buf = terminal.backend().buffer();
+        let row_text: String = (0..60).map(|x| buf[(x, 8)].symbol().to_string()).collect();
+        assert!(row_text.contains("PAY-29"), "bottom row is {row_text:?}");
+        assert_eq!(map.target_at(5, 8), Some(HitTarget::OrderRow(last)));
+        assert_eq!(map.target_at(5, 9), Some(HitTarget::List));
+    }
+
     /// When more orders exist than table body rows, selecting a late row must
     /// scroll the stateful table so that marker is visible.
     #[test]
@@ -384,7 +492,8 @@ mod tests {
                 amount: 50_000,
                 payment_method: "PAY-USD".to_string(),
                 ..Default::default()
-            },
+            }
+            .into(),
             SmallOrder {
                 id: Some(eur_id),
                 kind: Some(mostro_core::order::Kind::Sell),
@@ -393,7 +502,8 @@ mod tests {
                 amount: 60_000,
                 payment_method: "PAY-EUR".to_string(),
                 ..Default::default()
-            },
+            }
+            .into(),
         ]));
         let mut app = AppState::new(UserRole::User);
         app.selected_order_id = Some(usd_id);
@@ -1,9 +1,10 @@
 use ratatui::layout::{Constraint, Direction, Flex, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, Borders, List, ListItem, Paragraph};
 
-use crate::ui::{UserRole, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::i18n::{language, tr};
+use crate::ui::{Theme, ThemePreset, UserRole};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SettingsMenuAction {
@@ -14,13 +15,20 @@ pub enum SettingsMenuAction {
     ClearBuyerLnAddress,
     AddCurrencyFilter,
     ClearCurrencyFilters,
+    ChangeTheme,
+    ChangeLanguage,
+    ToggleAutomation,
+    DataRetention,
+    CreateBackup,
+    RestoreBackup,
     ViewSeedWords,
     AddDisputeSolver,
     ChangeAdminKey,
     GenerateNewKeys,
 }
 
-type SettingsMenuRow = (SettingsMenuAction, &'static str);
+/// Action plus the catalog key of its list label (`settings.*`).
+pub(crate) type SettingsMenuRow = (SettingsMenuAction, &'static str);
 
 /// Single source of truth for Admin Settings rows (action + list label).
 ///
@@ -29,54 +37,94 @@ type SettingsMenuRow = (SettingsMenuAction, &'static str);
 /// set via **Change Admin Key** — generating a fresh keypair would overwrite
 /// `admin_privkey` with a key the daemon rejects.
 #[allow(clippy::redundant_static_lifetimes)]
-const ADMIN_SETTINGS: [SettingsMenuRow; 8] = [
-    (SettingsMenuAction::SwitchMode, "Switch Mode (User ↔ Admin)"),
+const ADMIN_SETTINGS: [SettingsMenuRow; 12] = [
+    (SettingsMenuAction::SwitchMode, "settings.switch_mode"),
     (
         SettingsMenuAction::ChangeMostroPubkey,
-        "Change Mostro Pubkey",
+        "settings.change_mostro_pubkey",
+    ),
+    (SettingsMenuAction::AddRelay, "settings.add_relay"),
+    (
+        SettingsMenuAction::AddCurrencyFilter,
+        "settings.add_currency_filter",
     ),
-    (SettingsMenuAction::AddRelay, "Add Nostr Relay"),
-    (SettingsMenuAction::AddCurrencyFilter, "Add Currency Filter"),
     (
         SettingsMenuAction::ClearCurrencyFilters,
-        "Clear Currency Filters",
+        "settings.clear_currency_filters",
+    ),
+    (SettingsMenuAction::ChangeTheme, "settings.change_theme"),
+    (
+        SettingsMenuAction::ChangeLanguage,
+        "settings.change_language",
+    ),
+    (SettingsMenuAction::CreateBackup, "settings.create_backup"),
+    (SettingsMenuAction::RestoreBackup, "settings.restore_backup"),
+    (
+        SettingsMenuAction::ViewSeedWords,
+        "settings.view_seed_words",
+    ),
+    (
+        SettingsMenuAction::AddDisputeSolver,
+        "settings.add_dispute_solver",
+    ),
+    (
+        SettingsMenuAction::ChangeAdminKey,
+        "settings.change_admin_key",
     ),
-    (SettingsMenuAction::ViewSeedWords, "View Seed Words"),
-    (SettingsMenuAction::AddDisputeSolver, "Add Dispute Solver"),
-    (SettingsMenuAction::ChangeAdminKey, "Change Admin Key"),
 ];
 
 /// Single source of truth for User Settings rows (action + list label).
 #[allow(clippy::redundant_static_lifetimes)]
-const USER_SETTINGS: [SettingsMenuRow; 9] = [
-    (SettingsMenuAction::SwitchMode, "Switch Mode (User ↔ Admin)"),
+const USER_SETTINGS: [SettingsMenuRow; 15] = [
+    (SettingsMenuAction::SwitchMode, "settings.switch_mode"),
     (
         SettingsMenuAction::ChangeMostroPubkey,
-        "Change Mostro Pubkey",
+        "settings.change_mostro_pubkey",
     ),
-    (SettingsMenuAction::AddRelay, "Add Nostr Relay"),
+    (SettingsMenuAction::AddRelay, "settings.add_relay"),
     (
         SettingsMenuAction::SetBuyerLnAddress,
-        "Set Lightning Address (buyer)",
+        "settings.set_ln_address",
     ),
     (
         SettingsMenuAction::ClearBuyerLnAddress,
-        "Clear Lightning Address",
+        "settings.clear_ln_address",
+    ),
+    (
+        SettingsMenuAction::AddCurrencyFilter,
+        "settings.add_currency_filter",
     ),
-    (SettingsMenuAction::AddCurrencyFilter, "Add Currency Filter"),
     (
         SettingsMenuAction::ClearCurrencyFilters,
-        "Clear Currency Filters",
+        "settings.clear_currency_filters",
+    ),
+    (SettingsMenuAction::ChangeTheme, "settings.change_theme"),
+    (
+        SettingsMenuAction::ChangeLanguage,
+        "settings.change_language",
+    ),
+    (
+        SettingsMenuAction::ToggleAutomation,
+        "settings.toggle_automation",
+    ),
+    (SettingsMenuAction::DataRetention, "settings.data_retention"),
+    (SettingsMenuAction::CreateBackup, "settings.create_backup"),
+    (SettingsMenuAction::RestoreBackup, "settings.restore_backup"),
+    (
+        SettingsMenuAction::ViewSeedWords,
+        "settings.view_seed_words",
+    ),
+    (
+        SettingsMenuAction::GenerateNewKeys,
+        "settings.generate_new_keys",
     ),
-    (SettingsMenuAction::ViewSeedWords, "View Seed Words"),
-    (SettingsMenuAction::GenerateNewKeys, "Generate New Keys"),
 ];
 
 pub const ADMIN_SETTINGS_OPTIONS_COUNT: usize = ADMIN_SETTINGS.len();
 
 pub const USER_SETTINGS_OPTIONS_COUNT: usize = USER_SETTINGS.len();
 
-fn settings_rows(role: UserRole) -> &'static [SettingsMenuRow] {
+pub(crate) fn settings_menu_rows(role: UserRole) -> &'static [SettingsMenuRow] {
     match role {
         UserRole::Admin => &ADMIN_SETTINGS,
         UserRole::User => &USER_SETTINGS,
@@ -84,7 +132,9 @@ fn settings_rows(role: UserRole) -> &'static [SettingsMenuRow] {
 }
 
 pub fn settings_action_for_index(user_role: UserRole, idx: usize) -> Option<SettingsMenuAction> {
-    settings_rows(user_role).get(idx).map(|(action, _)| *action)
+    settings_menu_rows(user_role)
+        .get(idx)
+        .map(|(action, _)| *action)
 }
 
 /// Package version from `Cargo.toml` (`CARGO_PKG_VERSION`).
@@ -92,7 +142,8 @@ pub const MOSTRIX_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Render the Settings tab UI
 ///
-/// Displays settings options based on user role (User or Admin).
+/// Displays settings options based on user role (User or Admin). User mode also shows the
+/// automation kill switch state next to the theme and language.
 /// The options list is centered when terminal width allows, otherwise uses full width
 /// to prevent text clipping on narrow terminals.
 pub fn render_settings_tab(
@@ -100,13 +151,16 @@ pub fn render_settings_tab(
     area: Rect,
     user_role: UserRole,
     selected_option: usize,
+    theme_preset: ThemePreset,
+    automation_enabled: bool,
+    theme: &Theme,
 ) {
     let block = Block::default()
-        .title("⚙️  Settings")
+        .title(tr("settings.title"))
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
 
     let inner_area = block.inner(area);
     f.render_widget(block, area);
@@ -141,20 +195,49 @@ pub fn render_settings_tab(
     };
 
     // Current mode display
+    let mut mode_line = vec![
+        Span::styled(tr("settings.current_mode"), Style::default()),
+        Span::styled(
+            match user_role {
+                UserRole::User => tr("settings.mode_user"),
+                UserRole::Admin => tr("settings.mode_admin"),
+            },
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(tr("settings.theme_label"), Style::default()),
+        Span::styled(
+            theme_preset.label(),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(tr("settings.language_label"), Style::default()),
+        Span::styled(
+            language().label(),
+            Style::default()
+                .fg(theme.primary)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ];
+    if user_role == UserRole::User {
+        let (state, color) = if automation_enabled {
+            (tr("settings.automation_on"), theme.warning)
+        } else {
+            (tr("settings.automation_off"), theme.muted)
+        };
+        mode_line.push(Span::styled(
+            tr("settings.automation_label"),
+            Style::default(),
+        ));
+        mode_line.push(Span::styled(
+            state,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ));
+    }
     f.render_widget(
-        Paragraph::new(Line::from(vec![
-            Span::styled("Current Mode: ", Style::default()),
-            Span::styled(
-                match user_role {
-                    UserRole::User => "User",
-                    UserRole::Admin => "Admin",
-                },
-                Style::default()
-                    .fg(PRIMARY_COLOR)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ]))
-        .alignment(ratatui::layout::Alignment::Center),
+        Paragraph::new(Line::from(mode_line)).alignment(ratatui::layout::Alignment::Center),
         chunks[1],
     );
 
@@ -164,13 +247,13 @@ pub fn render_settings_tab(
                 Span::styled(
                     "Mostrix ",
                     Style::default()
-                        .fg(Color::DarkGray)
+                        .fg(theme.muted)
                         .add_modifier(Modifier::ITALIC),
                 ),
                 Span::styled(
                     format!("v{MOSTRIX_VERSION}"),
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
             ]))
@@ -182,19 +265,19 @@ pub fn render_settings_tab(
         (chunks[3], chunks[4])
     };
 
-    let rows = settings_rows(user_role);
+    let rows = settings_menu_rows(user_role);
     let list_items: Vec<ListItem> = rows
         .iter()
         .enumerate()
         .map(|(i, (_, label))| {
             let style = if i == selected_option {
                 Style::default()
-                    .fg(PRIMARY_COLOR)
+                    .fg(theme.primary)
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
             };
-            ListItem::new(Line::from(Span::styled(*label, style)))
+            ListItem::new(Line::from(Span::styled(tr(label), style)))
         })
         .collect();
 
@@ -221,12 +304,12 @@ pub fn render_settings_tab(
         .block(
             Block::default()
                 .borders(Borders::NONE)
-                .style(Style::default().bg(BACKGROUND_COLOR)),
+                .style(Style::default().bg(theme.background)),
         )
         .highlight_style(
             Style::default()
-                .fg(Color::White)
-                .bg(PRIMARY_COLOR)
+                .fg(theme.text)
+                .bg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         );
 
@@ -235,12 +318,21 @@ pub fn render_settings_tab(
     // Footer hint
     f.render_widget(
         Paragraph::new(Line::from(vec![
-            Span::styled("↑/↓", Style::default().fg(PRIMARY_COLOR)),
-            Span::styled(" navigate · ", Style::default().fg(Color::White)),
-            Span::styled("Enter", Style::default().fg(PRIMARY_COLOR)),
-            Span::styled(" select · ", Style::default().fg(Color::White)),
-            Span::styled("Shift+H", Style::default().fg(PRIMARY_COLOR)),
-            Span::styled(" all options", Style::default().fg(Color::White)),
+            Span::styled("↑/↓", Style::default().fg(theme.primary)),
+            Span::styled(
+                tr("settings.footer_navigate"),
+                Style::default().fg(theme.text),
+            ),
+            Span::styled("Enter", Style::default().fg(theme.primary)),
+            Span::styled(
+                tr("settings.footer_select"),
+                Style::default().fg(theme.text),
+            ),
+            Span::styled("Shift+H", Style::default().fg(theme.primary)),
+            Span::styled(
+                tr("settings.footer_all_options"),
+                Style::default().fg(theme.text),
+            ),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
         footer_chunk,
@@ -266,15 +358,15 @@ mod tests {
 
     #[test]
     fn admin_settings_omit_generate_new_keys() {
-        assert_eq!(ADMIN_SETTINGS_OPTIONS_COUNT, 8);
+        assert_eq!(ADMIN_SETTINGS_OPTIONS_COUNT, 12);
         assert!(ADMIN_SETTINGS
             .iter()
             .all(|(action, _)| *action != SettingsMenuAction::GenerateNewKeys));
         assert!(matches!(
-            settings_action_for_index(UserRole::Admin, 7),
+            settings_action_for_index(UserRole::Admin, 11),
             Some(SettingsMenuAction::ChangeAdminKey)
         ));
-        assert!(settings_action_for_index(UserRole::Admin, 8).is_none());
+        assert!(settings_action_for_index(UserRole::Admin, 12).is_none());
     }
 
     #[test]
@@ -290,11 +382,20 @@ mod tests {
 
     #[test]
     fn render_shows_mostrix_version_on_tall_terminal() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
             .draw(|f| {
-                render_settings_tab(f, f.area(), UserRole::User, 0);
+                render_settings_tab(
+                    f,
+                    f.area(),
+                    UserRole::User,
+                    0,
+                    ThemePreset::Dark,
+                    false,
+                    theme,
+                );
             })
             .unwrap();
         let buf = terminal.backend().buffer();
@@ -304,13 +405,53 @@ mod tests {
         assert!(buffer_contains(buf, "navigate"));
     }
 
+    #[test]
+    fn render_shows_automation_kill_switch_for_users_only() {
+        let theme = &Theme::dark();
+        let render = |role: UserRole, enabled: bool| {
+            let backend = TestBackend::new(120, 24);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal
+                .draw(|f| {
+                    render_settings_tab(f, f.area(), role, 0, ThemePreset::Dark, enabled, theme)
+                })
+                .unwrap();
+            terminal.backend().buffer().clone()
+        };
+        assert!(buffer_contains(
+            &render(UserRole::User, true),
+            "Automation: ON"
+        ));
+        assert!(buffer_contains(
+            &render(UserRole::User, false),
+            "Automation: OFF"
+        ));
+        assert!(buffer_contains(
+            &render(UserRole::User, false),
+            "Toggle Automation"
+        ));
+        assert!(!buffer_contains(
+            &render(UserRole::Admin, true),
+            "Automation"
+        ));
+    }
+
     #[test]
     fn render_hides_version_on_short_terminal() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(80, 6);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
             .draw(|f| {
-                render_settings_tab(f, f.area(), UserRole::User, 0);
+                render_settings_tab(
+                    f,
+                    f.area(),
+                    UserRole::User,
+                    0,
+                    ThemePreset::Dark,
+                    false,
+                    theme,
+                );
             })
             .unwrap();
         let buf = terminal.backend().buffer();
@@ -318,4 +459,46 @@ mod tests {
         assert!(!buffer_contains(buf, "Mostrix"));
         assert!(buffer_contains(buf, "Current Mode"));
     }
+
+    #[test]
+    fn render_uses_given_theme_for_every_builtin_preset() {
+        let presets = [
+            ThemePreset::Dark,
+            ThemePreset::Light,
+            ThemePreset::HighContrast,
+            ThemePreset::ColorBlindSafe,
+        ];
+        let mut rendered_text: Option<String> = None;
+        for preset in presets {
+            let palette = Theme::builtin(preset);
+            let backend = TestBackend::new(80, 24);
+            let mut terminal = Terminal::new(backend).unwrap();
+            terminal
+                .draw(|f| {
+                    render_settings_tab(f, f.area(), UserRole::User, 0, preset, false, &palette);
+                })
+                .unwrap();
+            let buf = terminal.backend().buffer();
+
+            let corner = &buf[(0, 0)];
+            assert_eq!(corner.fg, palette.primary, "{preset:?} border");
+            assert_eq!(corner.bg, palette.background, "{preset:?} background");
+            assert!(buffer_contains(buf, preset.label()));
+
+            // Layout and copy are palette-independent; only the centered mode/theme row differs.
+            let text: String = (0..buf.area.height)
+                .map(|y| {
+                    (0..buf.area.width)
+                        .map(|x| buf[(x, y)].symbol())
+                        .collect::<String>()
+                })
+                .filter(|row| !row.contains("Theme: "))
+                .collect::<Vec<_>>()
+                .join("\n");
+            match &rendered_text {
+                Some(first) => assert_eq!(first, &text, "{preset:?} layout"),
+                None => rendered_text = Some(text),
+            }
+        }
+    }
 }
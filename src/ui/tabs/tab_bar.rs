@@ -1,13 +1,21 @@
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, Tabs};
 
-use crate::ui::{Tab, UserRole, BACKGROUND_COLOR, PRIMARY_COLOR};
+use crate::ui::hit_test::{self, HitTarget};
+use crate::ui::{Tab, Theme, UserRole};
 
-pub fn render_tabs(f: &mut ratatui::Frame, area: Rect, active_tab: Tab, role: UserRole) {
+pub fn render_tabs(
+    f: &mut ratatui::Frame,
+    area: Rect,
+    active_tab: Tab,
+    role: UserRole,
+    theme: &Theme,
+) {
     let titles = Tab::get_titles(role);
     let tab_titles: Vec<Line> = titles.iter().map(|t| Line::from(t.as_str())).collect();
+    record_tab_regions(area, &tab_titles, role);
 
     let tabs = Tabs::new(tab_titles)
         .select(active_tab.as_index())
@@ -16,12 +24,76 @@ pub fn render_tabs(f: &mut ratatui::Frame, area: Rect, active_tab: Tab, role: Us
             // visually distinct from the green rounded content frames below.
             Block::default()
                 .borders(Borders::ALL)
-                .style(Style::default().bg(BACKGROUND_COLOR).fg(Color::White)),
+                .style(Style::default().bg(theme.background).fg(theme.text)),
         )
         .highlight_style(
             Style::default()
-                .fg(PRIMARY_COLOR)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         );
     f.render_widget(tabs, area);
 }
+
+/// Record one click region per title, mirroring the `Tabs` layout: one padding
+/// cell on each side of the title and a one-cell divider between titles.
+fn record_tab_regions(area: Rect, titles: &[Line], role: UserRole) {
+    let inner = Block::default().borders(Borders::ALL).inner(area);
+    if inner.is_empty() {
+        return;
+    }
+    let mut x = inner.x;
+    for (idx, title) in titles.iter().enumerate() {
+        if x >= inner.right() {
+            break;
+        }
+        let width = (title.width() as u16).saturating_add(2);
+        let width = width.min(inner.right() - x);
+        hit_test::record(
+            Rect::new(x, area.y, width, area.height),
+            HitTarget::Tab(Tab::from_index(idx, role)),
+        );
+        x = x.saturating_add(width).saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_tabs;
+    use crate::ui::hit_test::{self, HitTarget};
+    use crate::ui::{Tab, Theme, UiMode, UserRole, UserTab};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn tab_regions_line_up_with_rendered_titles() {
+        let theme = &Theme::dark();
+        let mut terminal = Terminal::new(TestBackend::new(160, 3)).expect("terminal");
+        hit_test::begin_frame();
+        terminal
+            .draw(|f| {
+                render_tabs(
+                    f,
+                    f.area(),
+                    Tab::User(UserTab::Orders),
+                    UserRole::User,
+                    theme,
+                )
+            })
+            .expect("draw");
+        let map = hit_test::finish_frame(&UiMode::Normal);
+
+        let buf = terminal.backend().buffer();
+        for (idx, title) in Tab::get_titles(UserRole::User).iter().enumerate() {
+            let target = HitTarget::Tab(Tab::from_index(idx, UserRole::User));
+            let under_region: String = (0..buf.area.width)
+                .filter(|&x| map.target_at(x, 1) == Some(target))
+                .map(|x| buf[(x, 1)].symbol().to_string())
+                .collect();
+            let word = title.split_whitespace().last().expect("non-empty title");
+            assert!(
+                under_region.contains(word),
+                "region of {title:?} covers {under_region:?}"
+            );
+        }
+    }
+}
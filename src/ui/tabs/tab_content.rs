@@ -1,23 +1,22 @@
 use mostro_core::prelude::*;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph, Wrap};
 
 use crate::ui::{
     helpers::{self, render_centered_lines},
-    MessageViewState, RatingOrderState, ThreeState, ViewingMessageButtonSelection,
-    BACKGROUND_COLOR, PRIMARY_COLOR,
+    MessageViewState, RatingOrderState, Theme, ThreeState, ViewingMessageButtonSelection,
 };
 
-pub fn render_coming_soon(f: &mut ratatui::Frame, area: Rect, title: &str) {
+pub fn render_coming_soon(f: &mut ratatui::Frame, area: Rect, title: &str, theme: &Theme) {
     let paragraph = Paragraph::new(Span::raw("Coming soon")).block(
         Block::default()
             .title(title)
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(PRIMARY_COLOR))
-            .style(Style::default().bg(BACKGROUND_COLOR)),
+            .border_style(Style::default().fg(theme.primary))
+            .style(Style::default().bg(theme.background)),
     );
     f.render_widget(paragraph, area);
 }
@@ -39,7 +38,7 @@ fn get_mostro_logo() -> Vec<&'static str> {
     ]
 }
 
-fn style_exit_logo_line(line: &str) -> Vec<Span<'static>> {
+fn style_exit_logo_line(line: &str, theme: &Theme) -> Vec<Span<'static>> {
     if line.contains('█') {
         line.chars()
             .map(|c| {
@@ -47,7 +46,7 @@ fn style_exit_logo_line(line: &str) -> Vec<Span<'static>> {
                     Span::styled(
                         c.to_string(),
                         Style::default()
-                            .fg(PRIMARY_COLOR)
+                            .fg(theme.primary)
                             .add_modifier(Modifier::BOLD),
                     )
                 } else {
@@ -62,7 +61,7 @@ fn style_exit_logo_line(line: &str) -> Vec<Span<'static>> {
                     Span::styled(
                         c.to_string(),
                         Style::default()
-                            .fg(PRIMARY_COLOR)
+                            .fg(theme.primary)
                             .add_modifier(Modifier::BOLD),
                     )
                 } else {
@@ -76,7 +75,7 @@ fn style_exit_logo_line(line: &str) -> Vec<Span<'static>> {
 }
 
 /// Renders the Exit tab content with ASCII art logo
-pub fn render_exit_tab(f: &mut ratatui::Frame, area: Rect) {
+pub fn render_exit_tab(f: &mut ratatui::Frame, area: Rect, theme: &Theme) {
     let logo_lines = get_mostro_logo();
 
     f.render_widget(
@@ -84,8 +83,8 @@ pub fn render_exit_tab(f: &mut ratatui::Frame, area: Rect) {
             .title("Exit")
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(PRIMARY_COLOR))
-            .style(Style::default().bg(BACKGROUND_COLOR)),
+            .border_style(Style::default().fg(theme.primary))
+            .style(Style::default().bg(theme.background)),
         area,
     );
 
@@ -93,15 +92,17 @@ pub fn render_exit_tab(f: &mut ratatui::Frame, area: Rect) {
         .title("Exit")
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR))
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background))
         .inner(area);
 
     let line_refs: Vec<&str> = logo_lines.to_vec();
-    render_centered_lines(f, inner_area, &line_refs, style_exit_logo_line);
+    render_centered_lines(f, inner_area, &line_refs, |line| {
+        style_exit_logo_line(line, theme)
+    });
 }
 
-pub fn render_message_view(f: &mut ratatui::Frame, view_state: &MessageViewState) {
+pub fn render_message_view(f: &mut ratatui::Frame, view_state: &MessageViewState, theme: &Theme) {
     let area = f.area();
     let popup_width = area.width.saturating_sub(area.width / 4);
 
@@ -203,8 +204,8 @@ pub fn render_message_view(f: &mut ratatui::Frame, view_state: &MessageViewState
     let block = Block::default()
         .title("📨 Message")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
     f.render_widget(block, popup);
 
     // Order ID
@@ -213,8 +214,8 @@ pub fn render_message_view(f: &mut ratatui::Frame, view_state: &MessageViewState
         Paragraph::new(Line::from(vec![Span::styled(
             order_id_str,
             Style::default()
-                .bg(BACKGROUND_COLOR)
-                .fg(PRIMARY_COLOR)
+                .bg(theme.background)
+                .fg(theme.primary)
                 .add_modifier(Modifier::BOLD),
         )]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -222,7 +223,7 @@ pub fn render_message_view(f: &mut ratatui::Frame, view_state: &MessageViewState
     );
 
     // Message content (multi-line Text so `\n` in the string becomes real line breaks in ratatui)
-    let body_style = Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR);
+    let body_style = Style::default().bg(theme.background).fg(theme.primary);
     let message_paragraph = if multiline_message_body {
         let lines: Vec<Line> = view_state
             .message_content
@@ -234,7 +235,7 @@ pub fn render_message_view(f: &mut ratatui::Frame, view_state: &MessageViewState
             .block(
                 Block::default()
                     .padding(Padding::horizontal(1))
-                    .style(Style::default().bg(BACKGROUND_COLOR)),
+                    .style(Style::default().bg(theme.background)),
             )
             .wrap(Wrap { trim: true })
     } else {
@@ -261,6 +262,7 @@ pub fn render_message_view(f: &mut ratatui::Frame, view_state: &MessageViewState
                 "✓ YES",
                 "✗ NO",
                 "CANCEL",
+                theme,
             );
             f.render_widget(
                 Paragraph::new(Line::from(vec![
@@ -268,21 +270,21 @@ pub fn render_message_view(f: &mut ratatui::Frame, view_state: &MessageViewState
                     Span::styled(
                         "Left/Right",
                         Style::default()
-                            .fg(PRIMARY_COLOR)
+                            .fg(theme.primary)
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(" to cycle YES / NO / CANCEL, ", Style::default()),
                     Span::styled(
                         "Enter",
                         Style::default()
-                            .fg(PRIMARY_COLOR)
+                            .fg(theme.primary)
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(" to confirm, ", Style::default()),
                     Span::styled(
                         "Esc",
                         Style::default()
-                            .fg(PRIMARY_COLOR)
+                            .fg(theme.primary)
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(" to dismiss", Style::default()),
@@ -302,28 +304,35 @@ pub fn render_message_view(f: &mut ratatui::Frame, view_state: &MessageViewState
             } else {
                 ("✓ YES", "✗ NO")
             };
-            helpers::render_yes_no_buttons(f, button_area, yes_selected, yes_label, no_label);
+            helpers::render_yes_no_buttons(
+                f,
+                button_area,
+                yes_selected,
+                yes_label,
+                no_label,
+                theme,
+            );
             f.render_widget(
                 Paragraph::new(Line::from(vec![
                     Span::styled("Use ", Style::default()),
                     Span::styled(
                         "Left/Right",
                         Style::default()
-                            .fg(PRIMARY_COLOR)
+                            .fg(theme.primary)
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(" to select, ", Style::default()),
                     Span::styled(
                         "Enter",
                         Style::default()
-                            .fg(PRIMARY_COLOR)
+                            .fg(theme.primary)
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(" to confirm, ", Style::default()),
                     Span::styled(
                         "Esc",
                         Style::default()
-                            .fg(PRIMARY_COLOR)
+                            .fg(theme.primary)
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::styled(" to dismiss", Style::default()),
@@ -340,14 +349,14 @@ pub fn render_message_view(f: &mut ratatui::Frame, view_state: &MessageViewState
                 Span::styled(
                     "Esc",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" or ", Style::default()),
                 Span::styled(
                     "Return",
                     Style::default()
-                        .fg(PRIMARY_COLOR)
+                        .fg(theme.primary)
                         .add_modifier(Modifier::BOLD),
                 ),
                 Span::styled(" to exit", Style::default()),
@@ -359,7 +368,7 @@ pub fn render_message_view(f: &mut ratatui::Frame, view_state: &MessageViewState
 }
 
 /// Popup to choose a 1..=5 star rating before sending `RateUser` to Mostro.
-pub fn render_rating_order(f: &mut ratatui::Frame, state: &RatingOrderState) {
+pub fn render_rating_order(f: &mut ratatui::Frame, state: &RatingOrderState, theme: &Theme) {
     let area = f.area();
     let popup_width = area.width.saturating_sub(area.width / 4);
     let popup = helpers::create_centered_popup(area, popup_width, 14);
@@ -367,8 +376,8 @@ pub fn render_rating_order(f: &mut ratatui::Frame, state: &RatingOrderState) {
     let block = Block::default()
         .title("Rate counterparty")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(PRIMARY_COLOR))
-        .style(Style::default().bg(BACKGROUND_COLOR));
+        .border_style(Style::default().fg(theme.primary))
+        .style(Style::default().bg(theme.background));
     f.render_widget(block.clone(), popup);
     let inner = block.inner(popup);
     let chunks = Layout::new(
@@ -406,7 +415,7 @@ pub fn render_rating_order(f: &mut ratatui::Frame, state: &RatingOrderState) {
         Paragraph::new(Line::from(vec![Span::styled(
             stars.trim_end(),
             Style::default()
-                .fg(Color::Yellow)
+                .fg(theme.warning)
                 .add_modifier(Modifier::BOLD),
         )]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -424,13 +433,13 @@ pub fn render_rating_order(f: &mut ratatui::Frame, state: &RatingOrderState) {
 
     f.render_widget(
         Paragraph::new(Line::from(vec![
-            Span::styled("Left/Right ", Style::default().fg(PRIMARY_COLOR)),
+            Span::styled("Left/Right ", Style::default().fg(theme.primary)),
             Span::raw("or "),
-            Span::styled("+/- ", Style::default().fg(PRIMARY_COLOR)),
+            Span::styled("+/- ", Style::default().fg(theme.primary)),
             Span::raw("adjust  "),
-            Span::styled("Enter ", Style::default().fg(PRIMARY_COLOR)),
+            Span::styled("Enter ", Style::default().fg(theme.primary)),
             Span::raw("submit  "),
-            Span::styled("Esc ", Style::default().fg(PRIMARY_COLOR)),
+            Span::styled("Esc ", Style::default().fg(theme.primary)),
             Span::raw("cancel"),
         ]))
         .alignment(ratatui::layout::Alignment::Center),
@@ -0,0 +1,447 @@
+//! Terminal image output for chat previews.
+//!
+//! Half blocks (`▀`, top pixel as foreground, bottom pixel as background) go through the ratatui
+//! buffer like any widget and work everywhere. Kitty and Sixel images are escape sequences the
+//! buffer cannot hold, so the popup only records a [`GraphicsPlacement`] while drawing and the
+//! main loop writes it after the frame with [`flush_graphics_overlay`].
+
+use std::fmt::Write as _;
+use std::io::{self, Cursor, Write};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use crossterm::cursor::{MoveTo, RestorePosition, SavePosition};
+use crossterm::queue;
+use image::imageops::{resize, thumbnail, FilterType};
+use image::{ImageFormat, Rgba, RgbaImage};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::util::image_preview::PreviewImage;
+
+/// Cell size assumed when the terminal does not report its pixel size.
+const FALLBACK_CELL_PX: (u16, u16) = (8, 16);
+/// Kitty graphics payloads are sent in base64 chunks of at most this many bytes.
+const KITTY_CHUNK: usize = 4096;
+/// Alpha below this is treated as transparent.
+const ALPHA_CUTOFF: u8 = 128;
+
+/// How preview images reach the terminal (`image_preview` in settings.toml).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    /// Unicode half blocks with true colour; works in any terminal.
+    #[default]
+    HalfBlocks,
+    /// Kitty graphics protocol (kitty, WezTerm, Ghostty, Konsole).
+    Kitty,
+    /// DEC Sixel (foot, mlterm, xterm -ti vt340, Contour, iTerm2, WezTerm).
+    Sixel,
+}
+
+impl GraphicsProtocol {
+    /// Parse the setting: `auto` (or empty) detects from the environment.
+    pub fn from_setting(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "auto" => Ok(Self::detect()),
+            "halfblocks" => Ok(Self::HalfBlocks),
+            "kitty" => Ok(Self::Kitty),
+            "sixel" => Ok(Self::Sixel),
+            other => Err(anyhow!(
+                "image_preview must be \"auto\", \"halfblocks\", \"kitty\" or \"sixel\", got \"{other}\""
+            )),
+        }
+    }
+
+    /// Best protocol for the running terminal, from `TERM`, `TERM_PROGRAM` and friends.
+    pub fn detect() -> Self {
+        let var = |name: &str| std::env::var(name).unwrap_or_default();
+        Self::detect_from(
+            &var("TERM"),
+            &var("TERM_PROGRAM"),
+            std::env::var_os("KITTY_WINDOW_ID").is_some(),
+            std::env::var_os("TMUX").is_some(),
+        )
+    }
+
+    fn detect_from(term: &str, term_program: &str, kitty_window: bool, in_tmux: bool) -> Self {
+        // tmux swallows graphics escapes unless passthrough is configured; stay safe.
+        if in_tmux {
+            return Self::HalfBlocks;
+        }
+        let term = term.to_ascii_lowercase();
+        let program = term_program.to_ascii_lowercase();
+        if kitty_window
+            || term.contains("kitty")
+            || term.contains("ghostty")
+            || matches!(program.as_str(), "wezterm" | "ghostty")
+        {
+            return Self::Kitty;
+        }
+        if term.contains("sixel")
+            || term.starts_with("foot")
+            || term.starts_with("mlterm")
+            || term.starts_with("contour")
+            || program == "iterm.app"
+        {
+            return Self::Sixel;
+        }
+        Self::HalfBlocks
+    }
+}
+
+/// Image to write over `area` after the frame (Kitty / Sixel only).
+#[derive(Clone, Debug)]
+pub struct GraphicsPlacement {
+    pub area: Rect,
+    pub image: Arc<PreviewImage>,
+}
+
+/// What the terminal currently shows on top of the ratatui frame.
+#[derive(Clone, Debug, Default)]
+pub struct GraphicsOverlay {
+    /// Set by the preview popup during the current draw; cleared at the start of every frame.
+    pub pending: Option<GraphicsPlacement>,
+    /// Area and image id last written to the terminal.
+    shown: Option<(Rect, u64)>,
+}
+
+impl GraphicsOverlay {
+    pub fn begin_frame(&mut self) {
+        self.pending = None;
+    }
+}
+
+/// Write the pending placement if it changed since the last frame and remove the old one.
+///
+/// Returns `true` when a Sixel image must be erased: the caller clears the terminal, redraws the
+/// frame and calls this again (Sixel pixels are only removed by repainting the cells under them).
+pub fn flush_graphics_overlay<W: Write>(
+    out: &mut W,
+    overlay: &mut GraphicsOverlay,
+    protocol: GraphicsProtocol,
+) -> io::Result<bool> {
+    let wanted = overlay
+        .pending
+        .as_ref()
+        .map(|placement| (placement.area, placement.image.id));
+    if wanted == overlay.shown {
+        return Ok(false);
+    }
+    if overlay.shown.take().is_some() {
+        match protocol {
+            GraphicsProtocol::Kitty => write!(out, "\x1b_Ga=d,d=A,q=2\x1b\\")?,
+            GraphicsProtocol::Sixel => return Ok(true),
+            GraphicsProtocol::HalfBlocks => {}
+        }
+    }
+    if let Some(placement) = overlay.pending.as_ref() {
+        let cell_px = cell_pixel_size();
+        let (x, y, sequence) = match protocol {
+            GraphicsProtocol::Kitty => {
+                let (x, y, img) = fit_pixels(&placement.image.image, placement.area, cell_px);
+                (x, y, kitty_sequence(&img).map_err(io::Error::other)?)
+            }
+            GraphicsProtocol::Sixel => {
+                let (x, y, img) = fit_pixels(&placement.image.image, placement.area, cell_px);
+                (x, y, sixel_sequence(&img))
+            }
+            GraphicsProtocol::HalfBlocks => (0, 0, String::new()),
+        };
+        if !sequence.is_empty() {
+            queue!(out, SavePosition, MoveTo(x, y))?;
+            out.write_all(sequence.as_bytes())?;
+            queue!(out, RestorePosition)?;
+        }
+        overlay.shown = wanted;
+    }
+    out.flush()?;
+    Ok(false)
+}
+
+/// Pixel size of one terminal cell, from `TIOCGWINSZ` when the terminal reports it.
+fn cell_pixel_size() -> (u16, u16) {
+    match crossterm::terminal::window_size() {
+        Ok(size) if size.width > 0 && size.height > 0 && size.columns > 0 && size.rows > 0 => (
+            (size.width / size.columns).max(1),
+            (size.height / size.rows).max(1),
+        ),
+        _ => FALLBACK_CELL_PX,
+    }
+}
+
+/// Largest `(width, height)` with the image's aspect ratio that fits in `max_w` × `max_h`.
+fn fit_dimensions(width: u32, height: u32, max_w: u32, max_h: u32) -> (u32, u32) {
+    if width == 0 || height == 0 || max_w == 0 || max_h == 0 {
+        return (0, 0);
+    }
+    let scale = (max_w as f64 / width as f64).min(max_h as f64 / height as f64);
+    (
+        ((width as f64 * scale).round() as u32).clamp(1, max_w),
+        ((height as f64 * scale).round() as u32).clamp(1, max_h),
+    )
+}
+
+/// Area-average when shrinking (fast, no aliasing); smooth when enlarging small screenshots.
+fn scale_to(image: &RgbaImage, width: u32, height: u32) -> RgbaImage {
+    if width <= image.width() && height <= image.height() {
+        thumbnail(image, width, height)
+    } else {
+        resize(image, width, height, FilterType::Triangle)
+    }
+}
+
+/// Scale the image to fit `area` at `cell_px` and return the top-left cell that centres it.
+fn fit_pixels(image: &RgbaImage, area: Rect, cell_px: (u16, u16)) -> (u16, u16, RgbaImage) {
+    let (cw, ch) = (u32::from(cell_px.0), u32::from(cell_px.1));
+    let (w, h) = fit_dimensions(
+        image.width(),
+        image.height(),
+        u32::from(area.width) * cw,
+        u32::from(area.height) * ch,
+    );
+    let cols = w.div_ceil(cw) as u16;
+    let rows = h.div_ceil(ch) as u16;
+    let x = area.x + area.width.saturating_sub(cols) / 2;
+    let y = area.y + area.height.saturating_sub(rows) / 2;
+    (x, y, scale_to(image, w.max(1), h.max(1)))
+}
+
+/// Draw the image into the buffer with `▀` cells, centred in `area`. `background` fills
+/// transparent pixels.
+pub fn render_half_blocks(image: &RgbaImage, area: Rect, buf: &mut Buffer, background: Color) {
+    let (w, h) = fit_dimensions(
+        image.width(),
+        image.height(),
+        u32::from(area.width),
+        u32::from(area.height) * 2,
+    );
+    if w == 0 || h == 0 {
+        return;
+    }
+    let scaled = scale_to(image, w, h);
+    let cols = w as u16;
+    let rows = h.div_ceil(2) as u16;
+    let x0 = area.x + area.width.saturating_sub(cols) / 2;
+    let y0 = area.y + area.height.saturating_sub(rows) / 2;
+    let color_at = |x: u32, y: u32| {
+        if y >= scaled.height() {
+            return background;
+        }
+        let Rgba([r, g, b, a]) = *scaled.get_pixel(x, y);
+        if a < ALPHA_CUTOFF {
+            background
+        } else {
+            Color::Rgb(r, g, b)
+        }
+    };
+    for row in 0..rows {
+        for col in 0..cols {
+            if let Some(cell) = buf.cell_mut((x0 + col, y0 + row)) {
+                cell.set_symbol("▀")
+                    .set_fg(color_at(u32::from(col), u32::from(row) * 2))
+                    .set_bg(color_at(u32::from(col), u32::from(row) * 2 + 1));
+            }
+        }
+    }
+}
+
+/// Kitty graphics: transmit-and-display a PNG at the cursor, without moving the cursor.
+fn kitty_sequence(image: &RgbaImage) -> Result<String> {
+    let mut png = Cursor::new(Vec::new());
+    image
+        .write_to(&mut png, ImageFormat::Png)
+        .map_err(|e| anyhow!("PNG encode failed: {e}"))?;
+    let payload = BASE64.encode(png.into_inner());
+    let chunks: Vec<&str> = payload
+        .as_bytes()
+        .chunks(KITTY_CHUNK)
+        .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+        .collect();
+    let mut out = String::with_capacity(payload.len() + chunks.len() * 16);
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 < chunks.len());
+        if i == 0 {
+            let _ = write!(out, "\x1b_Ga=T,f=100,C=1,q=2,m={more};{chunk}\x1b\\");
+        } else {
+            let _ = write!(out, "\x1b_Gm={more};{chunk}\x1b\\");
+        }
+    }
+    Ok(out)
+}
+
+/// Index into the 6×6×6 colour cube used as the Sixel palette, or `None` when transparent.
+fn sixel_color_index(pixel: &Rgba<u8>) -> Option<usize> {
+    let Rgba([r, g, b, a]) = *pixel;
+    if a < ALPHA_CUTOFF {
+        return None;
+    }
+    let level = |c: u8| (usize::from(c) * 5 + 127) / 255;
+    Some(level(r) * 36 + level(g) * 6 + level(b))
+}
+
+/// Append one Sixel run, using `!n` repeats for runs longer than three.
+fn push_sixel_run(out: &mut String, sixel: u8, count: usize) {
+    let c = char::from(63 + sixel);
+    if count > 3 {
+        let _ = write!(out, "!{count}{c}");
+    } else {
+        out.extend(std::iter::repeat_n(c, count));
+    }
+}
+
+/// DEC Sixel with a fixed 216-colour palette; transparent pixels leave the cells untouched.
+fn sixel_sequence(image: &RgbaImage) -> String {
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let mut out = String::new();
+    let _ = write!(out, "\x1bP0;1;0q\"1;1;{width};{height}");
+    for idx in 0..216 {
+        let (r, g, b) = (idx / 36, (idx / 6) % 6, idx % 6);
+        let _ = write!(out, "#{idx};2;{};{};{}", r * 20, g * 20, b * 20);
+    }
+    let mut band: Vec<Option<Vec<u8>>> = vec![None; 216];
+    for top in (0..height).step_by(6) {
+        band.iter_mut().for_each(|row| *row = None);
+        for dy in 0..6.min(height - top) {
+            for x in 0..width {
+                if let Some(idx) = sixel_color_index(image.get_pixel(x as u32, (top + dy) as u32)) {
+                    band[idx].get_or_insert_with(|| vec![0; width])[x] |= 1 << dy;
+                }
+            }
+        }
+        let mut first = true;
+        for (idx, row) in band.iter().enumerate() {
+            let Some(row) = row else { continue };
+            if !first {
+                out.push('$');
+            }
+            first = false;
+            let _ = write!(out, "#{idx}");
+            let mut run = (row[0], 0usize);
+            for &bits in row {
+                if bits == run.0 {
+                    run.1 += 1;
+                } else {
+                    push_sixel_run(&mut out, run.0, run.1);
+                    run = (bits, 1);
+                }
+            }
+            push_sixel_run(&mut out, run.0, run.1);
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preview(image: RgbaImage) -> Arc<PreviewImage> {
+        Arc::new(PreviewImage {
+            id: 7,
+            filename: "receipt.png".into(),
+            blossom_url: "blossom://example/abc".into(),
+            image,
+        })
+    }
+
+    #[test]
+    fn detection_prefers_kitty_then_sixel_and_avoids_tmux() {
+        use GraphicsProtocol::*;
+        assert_eq!(
+            GraphicsProtocol::detect_from("xterm-kitty", "", false, false),
+            Kitty
+        );
+        assert_eq!(
+            GraphicsProtocol::detect_from("xterm-256color", "WezTerm", false, false),
+            Kitty
+        );
+        assert_eq!(
+            GraphicsProtocol::detect_from("foot", "", false, false),
+            Sixel
+        );
+        assert_eq!(
+            GraphicsProtocol::detect_from("xterm-kitty", "", true, true),
+            HalfBlocks
+        );
+        assert_eq!(
+            GraphicsProtocol::detect_from("xterm-256color", "", false, false),
+            HalfBlocks
+        );
+        assert_eq!(GraphicsProtocol::from_setting("SIXEL").unwrap(), Sixel);
+        assert!(GraphicsProtocol::from_setting("ascii").is_err());
+    }
+
+    #[test]
+    fn fit_keeps_aspect_ratio() {
+        assert_eq!(fit_dimensions(200, 100, 40, 40), (40, 20));
+        assert_eq!(fit_dimensions(100, 400, 80, 40), (10, 40));
+        assert_eq!(fit_dimensions(0, 10, 5, 5), (0, 0));
+    }
+
+    #[test]
+    fn half_blocks_pack_two_pixels_per_cell() {
+        let mut img = RgbaImage::from_pixel(2, 2, Rgba([255, 0, 0, 255]));
+        img.put_pixel(0, 1, Rgba([0, 0, 255, 255]));
+        img.put_pixel(1, 1, Rgba([0, 0, 255, 255]));
+        let area = Rect::new(0, 0, 2, 1);
+        let mut buf = Buffer::empty(area);
+        render_half_blocks(&img, area, &mut buf, Color::Reset);
+        let cell = &buf[(0, 0)];
+        assert_eq!(cell.symbol(), "▀");
+        assert_eq!(cell.fg, Color::Rgb(255, 0, 0));
+        assert_eq!(cell.bg, Color::Rgb(0, 0, 255));
+    }
+
+    #[test]
+    fn sixel_encodes_runs_and_bands() {
+        let img = RgbaImage::from_pixel(8, 6, Rgba([255, 255, 255, 255]));
+        let seq = sixel_sequence(&img);
+        assert!(seq.starts_with("\x1bP0;1;0q\"1;1;8;6"));
+        // White is cube index 215; six full rows give sixel `~` repeated 8 times.
+        assert!(seq.contains("#215!8~-"));
+        assert!(seq.ends_with("\x1b\\"));
+    }
+
+    #[test]
+    fn kitty_output_is_chunked_and_overlay_is_written_once() {
+        let seq = kitty_sequence(&RgbaImage::from_pixel(4, 4, Rgba([1, 2, 3, 255]))).unwrap();
+        assert!(seq.starts_with("\x1b_Ga=T,f=100,C=1,q=2,m=0;"));
+
+        let mut overlay = GraphicsOverlay {
+            pending: Some(GraphicsPlacement {
+                area: Rect::new(2, 2, 10, 5),
+                image: preview(RgbaImage::from_pixel(4, 4, Rgba([1, 2, 3, 255]))),
+            }),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        assert!(!flush_graphics_overlay(&mut out, &mut overlay, GraphicsProtocol::Kitty).unwrap());
+        assert!(String::from_utf8_lossy(&out).contains("\x1b_Ga=T"));
+
+        // Same placement next frame: nothing re-sent.
+        let mut again = Vec::new();
+        flush_graphics_overlay(&mut again, &mut overlay, GraphicsProtocol::Kitty).unwrap();
+        assert!(again.is_empty());
+
+        // Popup closed: Kitty deletes the placement; Sixel asks for a repaint.
+        overlay.begin_frame();
+        let mut closed = Vec::new();
+        flush_graphics_overlay(&mut closed, &mut overlay, GraphicsProtocol::Kitty).unwrap();
+        assert!(String::from_utf8_lossy(&closed).contains("a=d"));
+
+        overlay.pending = Some(GraphicsPlacement {
+            area: Rect::new(0, 0, 4, 2),
+            image: preview(RgbaImage::from_pixel(4, 4, Rgba([1, 2, 3, 255]))),
+        });
+        flush_graphics_overlay(&mut Vec::new(), &mut overlay, GraphicsProtocol::Sixel).unwrap();
+        overlay.begin_frame();
+        assert!(
+            flush_graphics_overlay(&mut Vec::new(), &mut overlay, GraphicsProtocol::Sixel).unwrap()
+        );
+    }
+}
@@ -0,0 +1,542 @@
+//! Color themes for the TUI.
+//!
+//! Render functions take the palette as a `theme: &Theme` argument. [`crate::ui::ui_draw`] passes
+//! [`AppState::theme`](crate::ui::AppState) down from the top of each frame; renderers that already
+//! receive the `AppState` read it from there. The startup splash (drawn before `AppState` exists)
+//! resolves the palette from `settings.toml` itself, and the profile picker uses the default.
+
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// File name of the optional user palette, next to `settings.toml` in `~/.mostrix/`.
+pub const USER_THEME_FILE_NAME: &str = "theme.toml";
+
+/// Built-in palettes selectable from Settings (`theme` in `settings.toml`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemePreset {
+    /// Original Mostrix palette (lime on dark slate).
+    #[default]
+    Dark,
+    /// Dark text on a light background for light terminal profiles.
+    Light,
+    /// Pure black/white with bright accents.
+    HighContrast,
+    /// Okabe–Ito palette: buy/sell and buyer/seller cues are blue vs orange instead of green vs red.
+    ColorBlindSafe,
+    /// `~/.mostrix/theme.toml` applied on top of its `base` preset.
+    Custom,
+}
+
+impl ThemePreset {
+    /// Cycle order used by Settings → Change Theme.
+    pub const ALL: [ThemePreset; 5] = [
+        ThemePreset::Dark,
+        ThemePreset::Light,
+        ThemePreset::HighContrast,
+        ThemePreset::ColorBlindSafe,
+        ThemePreset::Custom,
+    ];
+
+    /// Value stored in `settings.toml`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ThemePreset::Dark => "dark",
+            ThemePreset::Light => "light",
+            ThemePreset::HighContrast => "high-contrast",
+            ThemePreset::ColorBlindSafe => "color-blind",
+            ThemePreset::Custom => "custom",
+        }
+    }
+
+    /// Human label for the Settings tab.
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemePreset::Dark => "Dark",
+            ThemePreset::Light => "Light",
+            ThemePreset::HighContrast => "High contrast",
+            ThemePreset::ColorBlindSafe => "Color-blind safe",
+            ThemePreset::Custom => "Custom (theme.toml)",
+        }
+    }
+
+    /// Next preset in [`Self::ALL`], wrapping around.
+    pub fn next(self) -> ThemePreset {
+        let idx = Self::ALL.iter().position(|p| *p == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+}
+
+impl FromStr for ThemePreset {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "dark" => Ok(ThemePreset::Dark),
+            "light" => Ok(ThemePreset::Light),
+            "high-contrast" | "high_contrast" => Ok(ThemePreset::HighContrast),
+            "color-blind" | "color_blind" | "colorblind" => Ok(ThemePreset::ColorBlindSafe),
+            "custom" => Ok(ThemePreset::Custom),
+            other => Err(anyhow::anyhow!("Unknown theme '{}'", other)),
+        }
+    }
+}
+
+/// Semantic palette used by every widget.
+///
+/// Slots are named by role rather than hue so presets can remap them freely (e.g. `buy` is
+/// green in [`ThemePreset::Dark`] but blue in [`ThemePreset::ColorBlindSafe`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Brand accent: borders, focused rows, highlighted labels.
+    pub primary: Color,
+    /// Panel background.
+    pub background: Color,
+    /// Background of unfocused form fields (New Order form strips).
+    pub field_bg: Color,
+    /// Background of text inputs inside popups.
+    pub input_bg: Color,
+    /// Default foreground text.
+    pub text: Color,
+    /// Secondary labels and neutral values.
+    pub secondary: Color,
+    /// Hints, placeholders and de-emphasized text.
+    pub muted: Color,
+    /// Foreground drawn on top of filled buttons and highlighted rows.
+    pub on_fill: Color,
+    /// Success, confirm buttons, positive premiums.
+    pub positive: Color,
+    /// Errors, cancel buttons, negative premiums.
+    pub negative: Color,
+    /// Warnings, pending states, attachment lines.
+    pub warning: Color,
+    /// Informational accent (ids, pubkeys, keys).
+    pub accent: Color,
+    /// Secondary accent (disputes, range markers).
+    pub highlight: Color,
+    /// Buy-side orders.
+    pub buy: Color,
+    /// Sell-side orders.
+    pub sell: Color,
+    /// Admin/solver messages in chats.
+    pub party_admin: Color,
+    /// Buyer messages in dispute chats.
+    pub party_buyer: Color,
+    /// Seller messages in dispute chats.
+    pub party_seller: Color,
+    /// Own messages in My Trades chats.
+    pub party_you: Color,
+    /// Counterparty (peer or solver) messages in My Trades chats.
+    pub party_peer: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// Original hard-coded Mostrix palette.
+    pub const fn dark() -> Self {
+        Self {
+            primary: Color::Rgb(177, 204, 51),  // #b1cc33
+            background: Color::Rgb(29, 33, 44), // #1D212C
+            field_bg: Color::Rgb(38, 44, 58),   // #262C3A
+            input_bg: Color::Black,
+            text: Color::White,
+            secondary: Color::Gray,
+            muted: Color::DarkGray,
+            on_fill: Color::Black,
+            positive: Color::Green,
+            negative: Color::Red,
+            warning: Color::Yellow,
+            accent: Color::Cyan,
+            highlight: Color::Magenta,
+            buy: Color::Green,
+            sell: Color::Red,
+            party_admin: Color::Cyan,
+            party_buyer: Color::Green,
+            party_seller: Color::Magenta,
+            party_you: Color::Cyan,
+            party_peer: Color::Green,
+        }
+    }
+
+    /// Dark text on an off-white background.
+    pub const fn light() -> Self {
+        Self {
+            primary: Color::Rgb(74, 110, 0),        // #4A6E00
+            background: Color::Rgb(250, 250, 245),  // #FAFAF5
+            field_bg: Color::Rgb(230, 232, 222),    // #E6E8DE
+            input_bg: Color::Rgb(238, 238, 238),    // #EEEEEE
+            text: Color::Rgb(28, 28, 28),           // #1C1C1C
+            secondary: Color::Rgb(80, 80, 80),      // #505050
+            muted: Color::Rgb(120, 120, 120),       // #787878
+            on_fill: Color::Rgb(255, 255, 255),     // #FFFFFF
+            positive: Color::Rgb(0, 122, 51),       // #007A33
+            negative: Color::Rgb(185, 28, 28),      // #B91C1C
+            warning: Color::Rgb(154, 103, 0),       // #9A6700
+            accent: Color::Rgb(0, 95, 153),         // #005F99
+            highlight: Color::Rgb(134, 25, 143),    // #86198F
+            buy: Color::Rgb(0, 122, 51),            // #007A33
+            sell: Color::Rgb(185, 28, 28),          // #B91C1C
+            party_admin: Color::Rgb(0, 95, 153),    // #005F99
+            party_buyer: Color::Rgb(0, 122, 51),    // #007A33
+            party_seller: Color::Rgb(134, 25, 143), // #86198F
+            party_you: Color::Rgb(0, 95, 153),      // #005F99
+            party_peer: Color::Rgb(0, 122, 51),     // #007A33
+        }
+    }
+
+    /// Black background, white text, bright accents; no mid-grey text.
+    pub const fn high_contrast() -> Self {
+        Self {
+            primary: Color::Yellow,
+            background: Color::Black,
+            field_bg: Color::Rgb(40, 40, 40), // #282828
+            input_bg: Color::Black,
+            text: Color::White,
+            secondary: Color::White,
+            muted: Color::Gray,
+            on_fill: Color::Black,
+            positive: Color::LightGreen,
+            negative: Color::LightRed,
+            warning: Color::Yellow,
+            accent: Color::LightCyan,
+            highlight: Color::LightMagenta,
+            buy: Color::LightGreen,
+            sell: Color::LightRed,
+            party_admin: Color::LightCyan,
+            party_buyer: Color::LightGreen,
+            party_seller: Color::LightMagenta,
+            party_you: Color::LightCyan,
+            party_peer: Color::White,
+        }
+    }
+
+    /// Okabe–Ito hues: blue/orange replace green/red for every two-sided cue.
+    pub const fn color_blind_safe() -> Self {
+        Self {
+            primary: Color::Rgb(86, 180, 233),  // #56B4E9 sky blue
+            background: Color::Rgb(29, 33, 44), // #1D212C
+            field_bg: Color::Rgb(38, 44, 58),   // #262C3A
+            input_bg: Color::Black,
+            text: Color::White,
+            secondary: Color::Gray,
+            muted: Color::DarkGray,
+            on_fill: Color::Black,
+            positive: Color::Rgb(0, 114, 178),    // #0072B2 blue
+            negative: Color::Rgb(230, 159, 0),    // #E69F00 orange
+            warning: Color::Rgb(240, 228, 66),    // #F0E442 yellow
+            accent: Color::Rgb(86, 180, 233),     // #56B4E9 sky blue
+            highlight: Color::Rgb(204, 121, 167), // #CC79A7 reddish purple
+            buy: Color::Rgb(0, 114, 178),         // #0072B2 blue
+            sell: Color::Rgb(230, 159, 0),        // #E69F00 orange
+            party_admin: Color::White,
+            party_buyer: Color::Rgb(0, 114, 178), // #0072B2 blue
+            party_seller: Color::Rgb(230, 159, 0), // #E69F00 orange
+            party_you: Color::Rgb(86, 180, 233),  // #56B4E9 sky blue
+            party_peer: Color::Rgb(230, 159, 0),  // #E69F00 orange
+        }
+    }
+
+    /// Palette for a built-in preset. [`ThemePreset::Custom`] falls back to dark here; use
+    /// [`Theme::for_preset_name`] to load `theme.toml`.
+    pub const fn builtin(preset: ThemePreset) -> Self {
+        match preset {
+            ThemePreset::Dark | ThemePreset::Custom => Self::dark(),
+            ThemePreset::Light => Self::light(),
+            ThemePreset::HighContrast => Self::high_contrast(),
+            ThemePreset::ColorBlindSafe => Self::color_blind_safe(),
+        }
+    }
+
+    /// Resolve the `theme` setting into a palette, loading the user file for `custom`.
+    ///
+    /// Unknown names and unreadable or invalid `theme.toml` files fall back to the dark palette
+    /// with a warning, so a bad theme never prevents Mostrix from starting.
+    pub fn for_preset_name(name: &str) -> Self {
+        let preset = match ThemePreset::from_str(name) {
+            Ok(preset) => preset,
+            Err(e) => {
+                log::warn!("{}; using the dark theme", e);
+                return Self::dark();
+            }
+        };
+        if preset != ThemePreset::Custom {
+            return Self::builtin(preset);
+        }
+        let Some(path) = user_theme_path() else {
+            log::warn!("Could not resolve home directory for theme.toml; using the dark theme");
+            return Self::dark();
+        };
+        match Self::from_user_file(&path) {
+            Ok(theme) => theme,
+            Err(e) => {
+                log::warn!(
+                    "Failed to load {}: {}; using the dark theme",
+                    path.display(),
+                    e
+                );
+                Self::dark()
+            }
+        }
+    }
+
+    /// Load a user palette file (see [`UserThemeFile`]).
+    pub fn from_user_file(path: &Path) -> Result<Self, anyhow::Error> {
+        let raw = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("could not read theme file: {}", e))?;
+        Self::from_user_toml(&raw)
+    }
+
+    /// Parse a user palette: `base` preset plus per-slot overrides.
+    pub fn from_user_toml(raw: &str) -> Result<Self, anyhow::Error> {
+        let file: UserThemeFile =
+            toml::from_str(raw).map_err(|e| anyhow::anyhow!("theme.toml malformed: {}", e))?;
+        file.into_theme()
+    }
+}
+
+/// On-disk shape of `~/.mostrix/theme.toml`.
+///
+/// Colors accept ratatui names (`"red"`, `"lightcyan"`), `#RRGGBB` hex, or 0–255 indices.
+/// Omitted slots keep the `base` preset's value.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserThemeFile {
+    pub base: Option<String>,
+    pub primary: Option<String>,
+    pub background: Option<String>,
+    pub field_bg: Option<String>,
+    pub input_bg: Option<String>,
+    pub text: Option<String>,
+    pub secondary: Option<String>,
+    pub muted: Option<String>,
+    pub on_fill: Option<String>,
+    pub positive: Option<String>,
+    pub negative: Option<String>,
+    pub warning: Option<String>,
+    pub accent: Option<String>,
+    pub highlight: Option<String>,
+    pub buy: Option<String>,
+    pub sell: Option<String>,
+    pub party_admin: Option<String>,
+    pub party_buyer: Option<String>,
+    pub party_seller: Option<String>,
+    pub party_you: Option<String>,
+    pub party_peer: Option<String>,
+}
+
+impl UserThemeFile {
+    fn into_theme(self) -> Result<Theme, anyhow::Error> {
+        let base = match self.base.as_deref() {
+            Some(name) => ThemePreset::from_str(name)?,
+            None => ThemePreset::Dark,
+        };
+        if base == ThemePreset::Custom {
+            anyhow::bail!("theme.toml base cannot be 'custom'");
+        }
+        let mut theme = Theme::builtin(base);
+        let overrides: [(&Option<String>, &mut Color, &str); 20] = [
+            (&self.primary, &mut theme.primary, "primary"),
+            (&self.background, &mut theme.background, "background"),
+            (&self.field_bg, &mut theme.field_bg, "field_bg"),
+            (&self.input_bg, &mut theme.input_bg, "input_bg"),
+            (&self.text, &mut theme.text, "text"),
+            (&self.secondary, &mut theme.secondary, "secondary"),
+            (&self.muted, &mut theme.muted, "muted"),
+            (&self.on_fill, &mut theme.on_fill, "on_fill"),
+            (&self.positive, &mut theme.positive, "positive"),
+            (&self.negative, &mut theme.negative, "negative"),
+            (&self.warning, &mut theme.warning, "warning"),
+            (&self.accent, &mut theme.accent, "accent"),
+            (&self.highlight, &mut theme.highlight, "highlight"),
+            (&self.buy, &mut theme.buy, "buy"),
+            (&self.sell, &mut theme.sell, "sell"),
+            (&self.party_admin, &mut theme.party_admin, "party_admin"),
+            (&self.party_buyer, &mut theme.party_buyer, "party_buyer"),
+            (&self.party_seller, &mut theme.party_seller, "party_seller"),
+            (&self.party_you, &mut theme.party_you, "party_you"),
+            (&self.party_peer, &mut theme.party_peer, "party_peer"),
+        ];
+        for (value, slot, name) in overrides {
+            if let Some(raw) = value {
+                *slot = Color::from_str(raw.trim())
+                    .map_err(|_| anyhow::anyhow!("invalid color {:?} for '{}'", raw, name))?;
+            }
+        }
+        Ok(theme)
+    }
+}
+
+/// `~/.mostrix/theme.toml`, or `None` when the home directory is unknown.
+pub fn user_theme_path() -> Option<PathBuf> {
+    Some(crate::settings::data_dir()?.join(USER_THEME_FILE_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{ui_draw, AppState, Tab, UserRole, UserTab};
+    use crate::util::fiat::BookOrder;
+    use mostro_core::prelude::{Kind, SmallOrder};
+    use ratatui::backend::TestBackend;
+    use ratatui::buffer::Buffer;
+    use ratatui::Terminal;
+    use std::sync::{Arc, Mutex};
+
+    const STATUS: &str = "Connected to relays";
+
+    fn find_text(buf: &Buffer, needle: &str) -> Vec<(u16, u16)> {
+        let mut found = Vec::new();
+        for y in 0..buf.area.height {
+            let row: Vec<&str> = (0..buf.area.width).map(|x| buf[(x, y)].symbol()).collect();
+            for x in 0..row.len() {
+                if row[x..].concat().starts_with(needle) {
+                    found.push((x as u16, y));
+                }
+            }
+        }
+        found
+    }
+
+    /// The Orders tab (two buy and two sell listings) with a status bar, drawn in `theme`.
+    fn render_book_with_status_bar(theme: Theme) -> Buffer {
+        let mut app = AppState::new(UserRole::User);
+        app.theme = theme;
+        app.active_tab = Tab::User(UserTab::Orders);
+        let book: Vec<BookOrder> = [Kind::Buy, Kind::Sell, Kind::Buy, Kind::Sell]
+            .into_iter()
+            .map(|kind| {
+                BookOrder::whole(SmallOrder {
+                    id: Some(uuid::Uuid::new_v4()),
+                    kind: Some(kind),
+                    fiat_code: "EUR".to_string(),
+                    fiat_amount: 100,
+                    payment_method: "SEPA".to_string(),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        let orders = Arc::new(Mutex::new(book));
+        let disputes = Arc::new(Mutex::new(Vec::new()));
+        let status = [STATUS.to_string()];
+        let mut terminal = Terminal::new(TestBackend::new(140, 24)).expect("terminal");
+        terminal
+            .draw(|f| ui_draw(f, &mut app, &orders, &disputes, Some(&status)))
+            .expect("draw");
+        terminal.backend().buffer().clone()
+    }
+
+    /// Kind cells, the selected row, the panel background and the status bar use `theme`.
+    fn assert_book_and_status_bar_styles(theme: Theme) {
+        let buf = render_book_with_status_bar(theme);
+        for (label, color) in [("buy", theme.buy), ("sell", theme.sell)] {
+            let cells = find_text(&buf, label);
+            assert!(
+                cells
+                    .iter()
+                    .any(|&(x, y)| buf[(x, y)].fg == color && buf[(x, y)].bg == theme.background),
+                "{label} cell"
+            );
+        }
+        assert!(
+            buf.content()
+                .iter()
+                .any(|cell| cell.bg == theme.primary && cell.fg == theme.on_fill),
+            "selected book row"
+        );
+        let (x, y) = find_text(&buf, STATUS)[0];
+        assert_eq!(buf[(x, y)].fg, theme.primary, "status bar text");
+        assert_eq!(buf[(x, y)].bg, theme.background, "status bar background");
+    }
+
+    #[test]
+    fn dark_preset_styles_order_book_and_status_bar() {
+        assert_book_and_status_bar_styles(Theme::dark());
+    }
+
+    #[test]
+    fn light_preset_styles_order_book_and_status_bar() {
+        assert_book_and_status_bar_styles(Theme::light());
+    }
+
+    #[test]
+    fn high_contrast_preset_styles_order_book_and_status_bar() {
+        assert_book_and_status_bar_styles(Theme::high_contrast());
+    }
+
+    #[test]
+    fn color_blind_preset_styles_order_book_and_status_bar() {
+        assert_book_and_status_bar_styles(Theme::color_blind_safe());
+    }
+
+    #[test]
+    fn custom_preset_styles_order_book_and_status_bar() {
+        let theme =
+            Theme::from_user_toml("base = \"light\"\nbuy = \"#0072B2\"\nprimary = \"magenta\"")
+                .unwrap();
+        assert_book_and_status_bar_styles(theme);
+    }
+
+    #[test]
+    fn preset_names_round_trip() {
+        for preset in ThemePreset::ALL {
+            assert_eq!(ThemePreset::from_str(preset.as_str()).unwrap(), preset);
+        }
+        assert_eq!(ThemePreset::from_str("").unwrap(), ThemePreset::Dark);
+        assert!(ThemePreset::from_str("solarized").is_err());
+    }
+
+    #[test]
+    fn next_cycles_through_every_preset() {
+        let mut preset = ThemePreset::Dark;
+        for _ in 0..ThemePreset::ALL.len() {
+            preset = preset.next();
+        }
+        assert_eq!(preset, ThemePreset::Dark);
+    }
+
+    #[test]
+    fn color_blind_preset_avoids_red_green_pairs() {
+        let t = Theme::color_blind_safe();
+        for (a, b) in [
+            (t.buy, t.sell),
+            (t.party_buyer, t.party_seller),
+            (t.positive, t.negative),
+        ] {
+            assert_ne!(a, b);
+            for c in [a, b] {
+                assert!(!matches!(
+                    c,
+                    Color::Red | Color::Green | Color::LightRed | Color::LightGreen
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn user_toml_overrides_base_preset() {
+        let theme = Theme::from_user_toml(
+            r##"
+base = "light"
+buy = "#0072B2"
+sell = "lightred"
+"##,
+        )
+        .unwrap();
+        assert_eq!(theme.buy, Color::Rgb(0, 114, 178));
+        assert_eq!(theme.sell, Color::LightRed);
+        assert_eq!(theme.background, Theme::light().background);
+    }
+
+    #[test]
+    fn user_toml_rejects_unknown_keys_and_bad_colors() {
+        assert!(Theme::from_user_toml("prmary = \"red\"").is_err());
+        assert!(Theme::from_user_toml("primary = \"not-a-color\"").is_err());
+        assert!(Theme::from_user_toml("base = \"custom\"").is_err());
+    }
+}
@@ -3,13 +3,14 @@ use ratatui::style::Style;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
-use super::{BACKGROUND_COLOR, PRIMARY_COLOR};
+use super::Theme;
+use crate::i18n::tr;
 
-pub fn render_waiting(f: &mut ratatui::Frame) {
-    render_waiting_with_message(f, "Sending order and waiting for confirmation...");
+pub fn render_waiting(f: &mut ratatui::Frame, theme: &Theme) {
+    render_waiting_with_message(f, tr("waiting.sending_order"), theme);
 }
 
-pub fn render_waiting_with_message(f: &mut ratatui::Frame, message: &str) {
+pub fn render_waiting_with_message(f: &mut ratatui::Frame, message: &str, theme: &Theme) {
     let area = f.area();
     let popup_width = 50;
     let popup_height = 7;
@@ -28,9 +29,9 @@ pub fn render_waiting_with_message(f: &mut ratatui::Frame, message: &str) {
     f.render_widget(Clear, popup);
 
     let block = Block::default()
-        .title("⏳ Waiting for Mostro")
+        .title(tr("waiting.title"))
         .borders(Borders::ALL)
-        .style(Style::default().bg(BACKGROUND_COLOR).fg(PRIMARY_COLOR));
+        .style(Style::default().bg(theme.background).fg(theme.primary));
     f.render_widget(block, popup);
 
     let inner_chunks = Layout::new(
@@ -60,7 +61,7 @@ pub fn render_waiting_with_message(f: &mut ratatui::Frame, message: &str) {
     f.render_widget(
         Paragraph::new(Line::from(vec![Span::styled(
             format!("{}", spinner_char),
-            Style::default().fg(PRIMARY_COLOR),
+            Style::default().fg(theme.primary),
         )]))
         .alignment(ratatui::layout::Alignment::Center),
         inner_chunks[2],
@@ -88,7 +89,9 @@ mod tests {
     fn render_waiting_shows_title_and_default_message() {
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
-        terminal.draw(render_waiting).unwrap();
+        terminal
+            .draw(|f| render_waiting(f, &Theme::dark()))
+            .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Waiting for Mostro"));
         assert!(buffer_contains(
@@ -99,10 +102,11 @@ mod tests {
 
     #[test]
     fn render_waiting_with_custom_message() {
+        let theme = &Theme::dark();
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
         terminal
-            .draw(|f| render_waiting_with_message(f, "Custom wait message"))
+            .draw(|f| render_waiting_with_message(f, "Custom wait message", theme))
             .unwrap();
         let buf = terminal.backend().buffer();
         assert!(buffer_contains(buf, "Waiting for Mostro"));
@@ -0,0 +1,541 @@
+//! Privacy preprocessing for outgoing image attachments: strip EXIF/XMP/ICC/text metadata and
+//! optionally downscale or recompress before encrypt + Blossom upload.
+//!
+//! Stripping is lossless (metadata segments/chunks are dropped, pixels untouched). Pixels are only
+//! re-encoded when the image must be rotated (EXIF orientation would be lost), downscaled, or
+//! recompressed; the encoders used here write no metadata at all.
+
+use std::io::Cursor;
+
+use anyhow::{anyhow, Result};
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::imageops::FilterType;
+use image::metadata::Orientation;
+use image::DynamicImage;
+
+use crate::settings::Settings;
+use crate::util::file_validation::{AttachmentFileClass, ValidatedAttachment};
+
+/// JPEG quality used when an image has to be re-encoded.
+pub const ATTACHMENT_JPEG_QUALITY: u8 = 85;
+
+const PNG_SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+const EXIF_HEADER: &[u8] = b"Exif\0\0";
+const XMP_HEADER: &[u8] = b"http://ns.adobe.com/xap/1.0/";
+
+/// EXIF IFD0 tags that identify the device or point at GPS data.
+const EXIF_TAG_MAKE: u16 = 0x010F;
+const EXIF_TAG_MODEL: u16 = 0x0110;
+const EXIF_TAG_GPS_IFD: u16 = 0x8825;
+
+/// Limits applied to image attachments, from `settings.toml`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AttachmentPreprocessOptions {
+    /// Longest side in pixels; larger images are downscaled. 0 = keep dimensions.
+    pub max_image_side: u32,
+    /// JPEGs larger than this (bytes, after stripping) are re-encoded. 0 = never.
+    pub recompress_above: usize,
+}
+
+impl AttachmentPreprocessOptions {
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            max_image_side: settings.attachment_max_image_side,
+            recompress_above: settings.attachment_recompress_above_kb as usize * 1024,
+        }
+    }
+}
+
+/// Which kinds of metadata were found (and removed) in the original file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RemovedMetadata {
+    pub exif: bool,
+    /// EXIF GPS block (location where the photo was taken).
+    pub location: bool,
+    /// EXIF camera make / model.
+    pub device: bool,
+    pub xmp: bool,
+    pub icc_profile: bool,
+    /// JPEG comments and PNG text / timestamp chunks.
+    pub text: bool,
+}
+
+impl RemovedMetadata {
+    pub fn any(&self) -> bool {
+        self.exif || self.location || self.device || self.xmp || self.icc_profile || self.text
+    }
+}
+
+/// Before/after summary shown in the send confirmation popup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreprocessReport {
+    pub original_size: usize,
+    pub final_size: usize,
+    /// Pixel dimensions before/after (0×0 for non-images).
+    pub original_dimensions: (u32, u32),
+    pub final_dimensions: (u32, u32),
+    /// False for videos and documents, which are sent unchanged.
+    pub inspected: bool,
+    pub removed: RemovedMetadata,
+    /// Pixels were decoded and re-encoded (rotation, downscale or recompression).
+    pub reencoded: bool,
+}
+
+/// Strip metadata from an image attachment and apply the configured size limits.
+///
+/// Non-images are returned unchanged. If re-encoding fails the losslessly stripped bytes are used.
+/// A JPEG whose segments cannot be walked is an error: it is never sent unstripped.
+pub fn preprocess_attachment(
+    mut attachment: ValidatedAttachment,
+    options: AttachmentPreprocessOptions,
+) -> Result<(ValidatedAttachment, PreprocessReport)> {
+    let original_dimensions = (attachment.image_width, attachment.image_height);
+    let mut report = PreprocessReport {
+        original_size: attachment.data.len(),
+        final_size: attachment.data.len(),
+        original_dimensions,
+        final_dimensions: original_dimensions,
+        inspected: attachment.file_class == AttachmentFileClass::Image,
+        removed: RemovedMetadata::default(),
+        reencoded: false,
+    };
+    if !report.inspected {
+        return Ok((attachment, report));
+    }
+
+    let is_jpeg = attachment.mime_type == "image/jpeg";
+    let (stripped, removed, exif) = if is_jpeg {
+        strip_jpeg_metadata(&attachment.data).map_err(|e| {
+            anyhow!(
+                "{}: {e}; not sending it with its metadata",
+                attachment.filename
+            )
+        })?
+    } else {
+        strip_png_metadata(&attachment.data)
+    };
+    report.removed = removed;
+
+    let orientation = exif
+        .as_deref()
+        .and_then(Orientation::from_exif_chunk)
+        .filter(|o| *o != Orientation::NoTransforms);
+    let (width, height) = original_dimensions;
+    let downscale = options.max_image_side > 0 && width.max(height) > options.max_image_side;
+    let recompress =
+        is_jpeg && options.recompress_above > 0 && stripped.len() > options.recompress_above;
+
+    attachment.data = stripped;
+    if orientation.is_some() || downscale || recompress {
+        match reencode(
+            &attachment.data,
+            is_jpeg,
+            orientation,
+            options.max_image_side,
+        ) {
+            // Keep the lossless strip when re-encoding only for size did not help.
+            Ok((bytes, _))
+                if !downscale && orientation.is_none() && bytes.len() >= attachment.data.len() => {}
+            Ok((bytes, (w, h))) => {
+                attachment.data = bytes;
+                attachment.image_width = w;
+                attachment.image_height = h;
+                report.reencoded = true;
+            }
+            Err(e) => log::warn!(
+                "Attachment re-encode failed for {}: {e}; sending metadata-stripped original",
+                attachment.filename
+            ),
+        }
+    }
+
+    report.final_size = attachment.data.len();
+    report.final_dimensions = (attachment.image_width, attachment.image_height);
+    Ok((attachment, report))
+}
+
+/// Decode, apply orientation, fit within `max_side` (0 = no limit) and encode without metadata.
+fn reencode(
+    data: &[u8],
+    is_jpeg: bool,
+    orientation: Option<Orientation>,
+    max_side: u32,
+) -> Result<(Vec<u8>, (u32, u32))> {
+    let mut image =
+        image::load_from_memory(data).map_err(|e| anyhow!("image decode failed: {e}"))?;
+    if let Some(orientation) = orientation {
+        image.apply_orientation(orientation);
+    }
+    if max_side > 0 && image.width().max(image.height()) > max_side {
+        image = image.resize(max_side, max_side, FilterType::Lanczos3);
+    }
+    let mut out = Cursor::new(Vec::new());
+    if is_jpeg {
+        let rgb = DynamicImage::ImageRgb8(image.to_rgb8());
+        rgb.write_with_encoder(JpegEncoder::new_with_quality(
+            &mut out,
+            ATTACHMENT_JPEG_QUALITY,
+        ))
+    } else {
+        image.write_with_encoder(PngEncoder::new(&mut out))
+    }
+    .map_err(|e| anyhow!("image encode failed: {e}"))?;
+    Ok((out.into_inner(), (image.width(), image.height())))
+}
+
+/// Drop APP1 (EXIF/XMP), APP2 (ICC), APP3–APP13, APP15 and COM segments.
+/// APP0 (JFIF) and APP14 (Adobe colour transform) are kept because decoders rely on them.
+/// Returns the stripped bytes, what was removed, and the EXIF TIFF block (for orientation).
+///
+/// A bad segment length or a missing marker before the image data is an error: the bytes after
+/// it cannot be inspected, and copying them as-is could pass an EXIF block through.
+fn strip_jpeg_metadata(data: &[u8]) -> Result<(Vec<u8>, RemovedMetadata, Option<Vec<u8>>)> {
+    let mut removed = RemovedMetadata::default();
+    let mut exif = None;
+    if data.len() < 4 || data[0] != 0xFF || data[1] != 0xD8 {
+        return Ok((data.to_vec(), removed, exif));
+    }
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(&data[..2]);
+    let mut i = 2usize;
+    loop {
+        if i + 2 > data.len() {
+            return Err(anyhow!("JPEG ends before its image data"));
+        }
+        if data[i] != 0xFF {
+            return Err(anyhow!("JPEG marker expected at byte {i}"));
+        }
+        let marker = data[i + 1];
+        // Fill byte before a marker.
+        if marker == 0xFF {
+            i += 1;
+            continue;
+        }
+        // Start of scan: entropy-coded data follows up to EOI, copy everything.
+        if marker == 0xDA || marker == 0xD9 {
+            break;
+        }
+        if i + 4 > data.len() {
+            return Err(anyhow!("JPEG segment at byte {i} is truncated"));
+        }
+        let seg_len = u16::from_be_bytes([data[i + 2], data[i + 3]]) as usize;
+        if seg_len < 2 || i + 2 + seg_len > data.len() {
+            return Err(anyhow!(
+                "JPEG segment at byte {i} has an invalid length ({seg_len})"
+            ));
+        }
+        let payload = &data[i + 4..i + 2 + seg_len];
+        let drop = match marker {
+            0xE1 => {
+                if let Some(tiff) = payload.strip_prefix(EXIF_HEADER) {
+                    removed.exif = true;
+                    let (location, device) = scan_exif_ifd0(tiff);
+                    removed.location |= location;
+                    removed.device |= device;
+                    exif = Some(tiff.to_vec());
+                } else if payload.starts_with(XMP_HEADER) {
+                    removed.xmp = true;
+                } else {
+                    removed.text = true;
+                }
+                true
+            }
+            0xE2 => {
+                removed.icc_profile = true;
+                true
+            }
+            0xE3..=0xED | 0xEF => {
+                removed.text = true;
+                true
+            }
+            0xFE => {
+                removed.text = true;
+                true
+            }
+            _ => false,
+        };
+        if !drop {
+            out.extend_from_slice(&data[i..i + 2 + seg_len]);
+        }
+        i += 2 + seg_len;
+    }
+    out.extend_from_slice(&data[i..]);
+    Ok((out, removed, exif))
+}
+
+/// Drop ancillary PNG chunks that carry metadata (`eXIf`, `iCCP`, `tEXt`, `zTXt`, `iTXt`, `tIME`).
+fn strip_png_metadata(data: &[u8]) -> (Vec<u8>, RemovedMetadata, Option<Vec<u8>>) {
+    let mut removed = RemovedMetadata::default();
+    let mut exif = None;
+    if !data.starts_with(PNG_SIGNATURE) {
+        return (data.to_vec(), removed, exif);
+    }
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(PNG_SIGNATURE);
+    let mut i = PNG_SIGNATURE.len();
+    while i + 12 <= data.len() {
+        let len = u32::from_be_bytes([data[i], data[i + 1], data[i + 2], data[i + 3]]) as usize;
+        let end = match (i + 12).checked_add(len) {
+            Some(end) if end <= data.len() => end,
+            _ => break,
+        };
+        let kind = &data[i + 4..i + 8];
+        let payload = &data[i + 8..i + 8 + len];
+        let drop = match kind {
+            b"eXIf" => {
+                removed.exif = true;
+                let (location, device) = scan_exif_ifd0(payload);
+                removed.location |= location;
+                removed.device |= device;
+                exif = Some(payload.to_vec());
+                true
+            }
+            b"iCCP" => {
+                removed.icc_profile = true;
+                true
+            }
+            b"iTXt" if payload.starts_with(b"XML:com.adobe.xmp\0") => {
+                removed.xmp = true;
+                true
+            }
+            b"tEXt" | b"zTXt" | b"iTXt" | b"tIME" => {
+                removed.text = true;
+                true
+            }
+            _ => false,
+        };
+        if !drop {
+            out.extend_from_slice(&data[i..end]);
+        }
+        i = end;
+        if kind == b"IEND" {
+            break;
+        }
+    }
+    out.extend_from_slice(&data[i..]);
+    (out, removed, exif)
+}
+
+/// Look for GPS and device tags in the first IFD of a TIFF-structured EXIF block.
+fn scan_exif_ifd0(tiff: &[u8]) -> (bool, bool) {
+    let read_u16 = |at: usize, little: bool| -> Option<u16> {
+        let b = tiff.get(at..at + 2)?;
+        Some(if little {
+            u16::from_le_bytes([b[0], b[1]])
+        } else {
+            u16::from_be_bytes([b[0], b[1]])
+        })
+    };
+    let little = match tiff.get(..4) {
+        Some([0x49, 0x49, 42, 0]) => true,
+        Some([0x4D, 0x4D, 0, 42]) => false,
+        _ => return (false, false),
+    };
+    let Some(offset) = tiff.get(4..8).map(|b| {
+        let b = [b[0], b[1], b[2], b[3]];
+        (if little {
+            u32::from_le_bytes(b)
+        } else {
+            u32::from_be_bytes(b)
+        }) as usize
+    }) else {
+        return (false, false);
+    };
+    let Some(count) = read_u16(offset, little) else {
+        return (false, false);
+    };
+    let (mut location, mut device) = (false, false);
+    for entry in 0..count as usize {
+        match read_u16(offset + 2 + entry * 12, little) {
+            Some(EXIF_TAG_GPS_IFD) => location = true,
+            Some(EXIF_TAG_MAKE | EXIF_TAG_MODEL) => device = true,
+            Some(_) => {}
+            None => break,
+        }
+    }
+    (location, device)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, Rgb, RgbImage};
+
+    /// Little-endian TIFF block with Make, Orientation and a GPS IFD pointer in IFD0.
+    fn exif_tiff(orientation: u16) -> Vec<u8> {
+        let mut t = vec![0x49, 0x49, 42, 0, 8, 0, 0, 0, 3, 0];
+        for (tag, kind, value) in [
+            (EXIF_TAG_MAKE, 2u16, 0u32),
+            (0x0112, 3, orientation as u32),
+            (EXIF_TAG_GPS_IFD, 4, 0),
+        ] {
+            t.extend_from_slice(&tag.to_le_bytes());
+            t.extend_from_slice(&kind.to_le_bytes());
+            t.extend_from_slice(&1u32.to_le_bytes());
+            t.extend_from_slice(&value.to_le_bytes());
+        }
+        t.extend_from_slice(&[0, 0, 0, 0]);
+        t
+    }
+
+    fn segment(marker: u8, payload: &[u8]) -> Vec<u8> {
+        let mut s = vec![0xFF, marker];
+        s.extend_from_slice(&((payload.len() + 2) as u16).to_be_bytes());
+        s.extend_from_slice(payload);
+        s
+    }
+
+    /// Real JPEG of `w`×`h` with EXIF, XMP, ICC and a comment spliced in after SOI.
+    fn jpeg_with_metadata(w: u32, h: u32, orientation: u16) -> Vec<u8> {
+        let img = RgbImage::from_pixel(w, h, Rgb([200, 30, 30]));
+        let mut plain = Cursor::new(Vec::new());
+        img.write_to(&mut plain, ImageFormat::Jpeg).unwrap();
+        let plain = plain.into_inner();
+        let mut exif = EXIF_HEADER.to_vec();
+        exif.extend_from_slice(&exif_tiff(orientation));
+        let mut xmp = XMP_HEADER.to_vec();
+        xmp.extend_from_slice(b"\0<x:xmpmeta/>");
+        let mut out = plain[..2].to_vec();
+        out.extend(segment(0xE1, &exif));
+        out.extend(segment(0xE1, &xmp));
+        out.extend(segment(0xE2, b"ICC_PROFILE\0\x01\x01data"));
+        out.extend(segment(0xFE, b"taken at home"));
+        out.extend_from_slice(&plain[2..]);
+        out
+    }
+
+    fn validated(data: Vec<u8>, mime: &str, w: u32, h: u32) -> ValidatedAttachment {
+        ValidatedAttachment {
+            data,
+            filename: "receipt".into(),
+            mime_type: mime.into(),
+            file_class: AttachmentFileClass::Image,
+            image_width: w,
+            image_height: h,
+        }
+    }
+
+    const NO_LIMITS: AttachmentPreprocessOptions = AttachmentPreprocessOptions {
+        max_image_side: 0,
+        recompress_above: 0,
+    };
+
+    #[test]
+    fn jpeg_metadata_is_stripped_losslessly() {
+        let data = jpeg_with_metadata(16, 8, 1);
+        let (out, report) =
+            preprocess_attachment(validated(data, "image/jpeg", 16, 8), NO_LIMITS).unwrap();
+        assert_eq!(
+            report.removed,
+            RemovedMetadata {
+                exif: true,
+                location: true,
+                device: true,
+                xmp: true,
+                icc_profile: true,
+                text: true,
+            }
+        );
+        assert!(!report.reencoded);
+        assert!(report.final_size < report.original_size);
+        assert!(!out.data.windows(4).any(|w| w == b"Exif"));
+        assert!(!out.data.windows(9).any(|w| w == b"taken at "));
+        assert_eq!(image::load_from_memory(&out.data).unwrap().width(), 16);
+    }
+
+    #[test]
+    fn corrupt_jpeg_segment_is_refused_rather_than_sent_unstripped() {
+        let clean = jpeg_with_metadata(16, 8, 1);
+        let mut exif = EXIF_HEADER.to_vec();
+        exif.extend_from_slice(&exif_tiff(1));
+        let mut truncated = clean[..2].to_vec();
+        // COM segment claiming more bytes than the file holds, then an EXIF block.
+        truncated.extend_from_slice(&[0xFF, 0xFE, 0xFF, 0xF0, b'x']);
+        truncated.extend(segment(0xE1, &exif));
+        let mut too_short = clean[..2].to_vec();
+        too_short.extend_from_slice(&[0xFF, 0xFE, 0x00, 0x01]);
+        too_short.extend(segment(0xE1, &exif));
+        too_short.extend_from_slice(&clean[2..]);
+
+        for data in [truncated, too_short] {
+            let err = preprocess_attachment(validated(data, "image/jpeg", 16, 8), NO_LIMITS)
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("invalid length"), "{err}");
+        }
+    }
+
+    #[test]
+    fn orientation_is_applied_before_exif_is_dropped() {
+        // 6 = rotate 90° clockwise: a 16×8 sensor image displays as 8×16.
+        let data = jpeg_with_metadata(16, 8, 6);
+        let (out, report) =
+            preprocess_attachment(validated(data, "image/jpeg", 16, 8), NO_LIMITS).unwrap();
+        assert!(report.reencoded);
+        assert_eq!(report.final_dimensions, (8, 16));
+        assert_eq!((out.image_width, out.image_height), (8, 16));
+        assert!(!out.data.windows(4).any(|w| w == b"Exif"));
+    }
+
+    #[test]
+    fn large_images_are_downscaled() {
+        let img = RgbImage::from_pixel(300, 100, Rgb([1, 2, 3]));
+        let mut png = Cursor::new(Vec::new());
+        img.write_to(&mut png, ImageFormat::Png).unwrap();
+        let options = AttachmentPreprocessOptions {
+            max_image_side: 150,
+            recompress_above: 0,
+        };
+        let (out, report) =
+            preprocess_attachment(validated(png.into_inner(), "image/png", 300, 100), options)
+                .unwrap();
+        assert!(report.reencoded);
+        assert_eq!(report.original_dimensions, (300, 100));
+        assert_eq!(report.final_dimensions, (150, 50));
+        let decoded = image::load_from_memory(&out.data).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (150, 50));
+    }
+
+    #[test]
+    fn png_text_and_exif_chunks_are_removed() {
+        let img = RgbImage::from_pixel(4, 4, Rgb([9, 9, 9]));
+        let mut png = Cursor::new(Vec::new());
+        img.write_to(&mut png, ImageFormat::Png).unwrap();
+        let png = png.into_inner();
+        let chunk = |kind: &[u8], payload: &[u8]| {
+            let mut c = (payload.len() as u32).to_be_bytes().to_vec();
+            c.extend_from_slice(kind);
+            c.extend_from_slice(payload);
+            c.extend_from_slice(&[0, 0, 0, 0]); // CRC is not checked by the stripper
+            c
+        };
+        // Insert after IHDR (8-byte signature + 25-byte IHDR chunk).
+        let mut data = png[..33].to_vec();
+        data.extend(chunk(b"eXIf", &exif_tiff(1)));
+        data.extend(chunk(b"tEXt", b"Author\0someone"));
+        data.extend_from_slice(&png[33..]);
+
+        let (out, report) =
+            preprocess_attachment(validated(data, "image/png", 4, 4), NO_LIMITS).unwrap();
+        assert!(report.removed.exif && report.removed.location && report.removed.text);
+        assert!(!report.removed.icc_profile);
+        assert_eq!(out.data, png);
+    }
+
+    #[test]
+    fn documents_are_sent_unchanged() {
+        let doc = ValidatedAttachment {
+            data: b"%PDF-1.7".to_vec(),
+            filename: "statement.pdf".into(),
+            mime_type: "application/pdf".into(),
+            file_class: AttachmentFileClass::Document,
+            image_width: 0,
+            image_height: 0,
+        };
+        let (out, report) = preprocess_attachment(doc, NO_LIMITS).unwrap();
+        assert!(!report.inspected);
+        assert_eq!(out.data, b"%PDF-1.7");
+        assert_eq!(report.original_size, report.final_size);
+    }
+}
@@ -0,0 +1,702 @@
+//! Rule-based automation for unattended trading.
+//!
+//! Rules live in `~/.mostrix/automation.toml` and are matched against incoming trade DMs
+//! ([`OrderMessage`]). A matching rule issues one of the existing order actions
+//! (`execute_add_invoice`, `execute_send_msg`, `execute_rate_user`) in the background.
+//! Every firing is appended to `~/.mostrix/automation_audit.log` with its outcome.
+//!
+//! Nothing runs unless `automation_enabled = true` in settings.toml (the Settings kill switch).
+//!
+//! ```toml
+//! [[rules]]
+//! name = "payout-to-ln-address"
+//! on = "add-invoice"
+//! do = "submit-ln-address"
+//!
+//! [[rules]]
+//! name = "accept-stale-cancels"
+//! on = "cooperative-cancel-initiated-by-peer"
+//! do = "accept-cooperative-cancel"
+//! min_order_age_hours = 12
+//!
+//! [[rules]]
+//! name = "rate-after-purchase"
+//! on = "purchase-completed"
+//! do = "rate"
+//! rating = 5
+//! ```
+
+use std::collections::HashSet;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use mostro_core::prelude::{Action, MAX_RATING, MIN_RATING};
+use nostr_sdk::prelude::{Client, PublicKey};
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use tokio::sync::mpsc::UnboundedSender;
+use uuid::Uuid;
+
+use crate::ui::{AppState, MessageNotification, OperationResult, OrderMessage, UiMode, UserMode};
+use crate::util::mostro_info::MostroInstanceInfo;
+use crate::util::order_utils::{execute_add_invoice, execute_rate_user, execute_send_msg};
+
+pub const AUTOMATION_RULES_FILE_NAME: &str = "automation.toml";
+pub const AUTOMATION_AUDIT_FILE_NAME: &str = "automation_audit.log";
+
+/// What a rule does when it matches (`do = "..."`).
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum AutomationActionKind {
+    /// Answer `add-invoice` with the saved `ln_address` from settings.toml.
+    SubmitLnAddress,
+    /// Answer `cooperative-cancel-initiated-by-peer` with our own `cancel`.
+    AcceptCooperativeCancel,
+    /// Rate the counterparty (`rating`, default 5 stars).
+    Rate,
+}
+
+impl AutomationActionKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AutomationActionKind::SubmitLnAddress => "submit-ln-address",
+            AutomationActionKind::AcceptCooperativeCancel => "accept-cooperative-cancel",
+            AutomationActionKind::Rate => "rate",
+        }
+    }
+}
+
+/// One `[[rules]]` entry of `automation.toml`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct AutomationRule {
+    pub name: String,
+    /// Incoming Mostro action that triggers the rule (kebab-case, e.g. `add-invoice`).
+    pub on: Action,
+    #[serde(rename = "do")]
+    pub action: AutomationActionKind,
+    /// Only fire for this order.
+    #[serde(default)]
+    pub order_id: Option<Uuid>,
+    /// Only fire when the order was created at least this many hours ago.
+    #[serde(default)]
+    pub min_order_age_hours: Option<u64>,
+    /// Stars for `do = "rate"`.
+    #[serde(default)]
+    pub rating: Option<u8>,
+    #[serde(default = "default_rule_enabled")]
+    pub enabled: bool,
+}
+
+fn default_rule_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct AutomationFile {
+    #[serde(default)]
+    rules: Vec<AutomationRule>,
+}
+
+/// Resolved command for one rule firing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AutomationCommand {
+    SubmitLnAddress { expected_sats: Option<i64> },
+    AcceptCooperativeCancel,
+    Rate(u8),
+}
+
+/// A rule that matched an incoming message and should run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AutomationFiring {
+    pub rule: String,
+    pub order_id: Uuid,
+    pub trigger: Action,
+    pub command: AutomationCommand,
+}
+
+impl AutomationFiring {
+    fn action_kind(&self) -> AutomationActionKind {
+        match self.command {
+            AutomationCommand::SubmitLnAddress { .. } => AutomationActionKind::SubmitLnAddress,
+            AutomationCommand::AcceptCooperativeCancel => {
+                AutomationActionKind::AcceptCooperativeCancel
+            }
+            AutomationCommand::Rate(_) => AutomationActionKind::Rate,
+        }
+    }
+}
+
+/// Loaded rules plus the global kill switch.
+#[derive(Debug, Default)]
+pub struct AutomationEngine {
+    /// Kill switch (`automation_enabled` in settings.toml); no rule fires while `false`.
+    pub enabled: bool,
+    rules: Vec<AutomationRule>,
+    /// `(rule, order, trigger)` already fired; Mostro may resend the same prompt.
+    /// Seeded from the audit log so a restart does not fire a rule again.
+    fired: HashSet<(String, Uuid, String)>,
+}
+
+impl AutomationEngine {
+    /// Parse and validate `automation.toml` contents.
+    pub fn from_toml(raw: &str) -> Result<Self> {
+        let file: AutomationFile =
+            toml::from_str(raw).map_err(|e| anyhow::anyhow!("automation.toml malformed: {}", e))?;
+        let mut names = HashSet::new();
+        for rule in &file.rules {
+            validate_rule(rule)?;
+            if !names.insert(rule.name.as_str()) {
+                anyhow::bail!("duplicate automation rule name '{}'", rule.name);
+            }
+        }
+        Ok(Self {
+            enabled: false,
+            rules: file.rules,
+            fired: HashSet::new(),
+        })
+    }
+
+    /// Load `~/.mostrix/automation.toml`; a missing file means no rules.
+    ///
+    /// Firings already recorded in the audit log count as fired.
+    pub fn load_from_disk() -> Result<Self> {
+        let Some(path) = automation_rules_path() else {
+            return Ok(Self::default());
+        };
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow::anyhow!("could not read {}: {}", path.display(), e))?;
+        let mut engine = Self::from_toml(&raw)?;
+        if let Some(audit_path) = automation_audit_path() {
+            engine.fired = load_fired_keys(&audit_path);
+        }
+        Ok(engine)
+    }
+
+    pub fn rules(&self) -> &[AutomationRule] {
+        &self.rules
+    }
+
+    /// Rules matching `msg`, each fired at most once per order and trigger.
+    ///
+    /// `order_created_at` comes from the DM order snapshot or the static My Trades header.
+    pub fn evaluate(
+        &mut self,
+        msg: &OrderMessage,
+        order_created_at: Option<i64>,
+        now: i64,
+    ) -> Vec<AutomationFiring> {
+        if !self.enabled {
+            return Vec::new();
+        }
+        let Some(order_id) = msg.order_id else {
+            return Vec::new();
+        };
+        let trigger = msg.message.get_inner_message_kind().action.clone();
+        let mut firings = Vec::new();
+        for rule in self.rules.iter().filter(|r| r.enabled && r.on == trigger) {
+            if rule.order_id.is_some_and(|id| id != order_id) {
+                continue;
+            }
+            if let Some(hours) = rule.min_order_age_hours {
+                let min_age = i64::try_from(hours.saturating_mul(3_600)).unwrap_or(i64::MAX);
+                match order_created_at {
+                    Some(created) if now - created >= min_age => {}
+                    _ => continue,
+                }
+            }
+            let key = (rule.name.clone(), order_id, trigger.to_string());
+            if !self.fired.insert(key) {
+                continue;
+            }
+            let command = match rule.action {
+                AutomationActionKind::SubmitLnAddress => AutomationCommand::SubmitLnAddress {
+                    expected_sats: msg.sat_amount,
+                },
+                AutomationActionKind::AcceptCooperativeCancel => {
+                    AutomationCommand::AcceptCooperativeCancel
+                }
+                AutomationActionKind::Rate => {
+                    AutomationCommand::Rate(rule.rating.unwrap_or(MAX_RATING))
+                }
+            };
+            firings.push(AutomationFiring {
+                rule: rule.name.clone(),
+                order_id,
+                trigger: trigger.clone(),
+                command,
+            });
+        }
+        firings
+    }
+}
+
+/// Evaluate rules for the trade DM behind `notification` (the row just stored in
+/// `app.messages`). Order age uses the DM order snapshot, else the My Trades static header.
+pub fn automation_firings_for_notification(
+    app: &mut AppState,
+    notification: &MessageNotification,
+) -> Vec<AutomationFiring> {
+    if !app.automation.enabled {
+        return Vec::new();
+    }
+    let Some(order_id) = notification.order_id else {
+        return Vec::new();
+    };
+    let msg = match app.messages.lock() {
+        Ok(guard) => guard
+            .iter()
+            .find(|m| {
+                m.order_id == Some(order_id)
+                    && m.message.get_inner_message_kind().action == notification.action
+            })
+            .cloned(),
+        Err(e) => {
+            crate::util::request_fatal_restart(format!(
+                "Mostrix encountered an internal error (poisoned messages lock: {e}). Please restart the app."
+            ));
+            return Vec::new();
+        }
+    };
+    // Row not replaced (stale replay): nothing new to react to.
+    let Some(msg) = msg else {
+        return Vec::new();
+    };
+    let created_at = msg
+        .order_snapshot
+        .as_ref()
+        .and_then(|o| o.created_at)
+        .or_else(|| {
+            app.order_chat_static
+                .get(&order_id)
+                .and_then(|h| h.created_at)
+        });
+    app.automation
+        .evaluate(&msg, created_at, chrono::Utc::now().timestamp())
+}
+
+/// After a rule answered a prompt, close the popup it would otherwise leave open.
+pub fn dismiss_prompt_answered_by_automation(app: &mut AppState, order_id: Uuid) {
+    let answered = match &app.mode {
+        UiMode::NewMessageNotification(notification, _, _)
+        | UiMode::ConfirmSavedLnAddressForInvoice(notification, _) => {
+            notification.order_id == Some(order_id)
+        }
+        _ => false,
+    };
+    if answered {
+        app.mode = UiMode::UserMode(UserMode::Normal);
+    }
+}
+
+fn validate_rule(rule: &AutomationRule) -> Result<()> {
+    if rule.name.trim().is_empty() {
+        anyhow::bail!("automation rule without a name");
+    }
+    match rule.action {
+        AutomationActionKind::SubmitLnAddress if rule.on != Action::AddInvoice => {
+            anyhow::bail!(
+                "rule '{}': submit-ln-address only answers on = \"add-invoice\"",
+                rule.name
+            );
+        }
+        AutomationActionKind::AcceptCooperativeCancel
+            if rule.on != Action::CooperativeCancelInitiatedByPeer =>
+        {
+            anyhow::bail!(
+                "rule '{}': accept-cooperative-cancel only answers on = \"cooperative-cancel-initiated-by-peer\"",
+                rule.name
+            );
+        }
+        AutomationActionKind::Rate => {
+            if let Some(rating) = rule.rating {
+                if !(MIN_RATING..=MAX_RATING).contains(&rating) {
+                    anyhow::bail!(
+                        "rule '{}': rating must be between {} and {}",
+                        rule.name,
+                        MIN_RATING,
+                        MAX_RATING
+                    );
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn mostrix_dir_file(file_name: &str) -> Option<PathBuf> {
+    Some(crate::settings::data_dir()?.join(file_name))
+}
+
+/// `~/.mostrix/automation.toml`, or `None` when the home directory is unknown.
+pub fn automation_rules_path() -> Option<PathBuf> {
+    mostrix_dir_file(AUTOMATION_RULES_FILE_NAME)
+}
+
+/// `~/.mostrix/automation_audit.log`, or `None` when the home directory is unknown.
+pub fn automation_audit_path() -> Option<PathBuf> {
+    mostrix_dir_file(AUTOMATION_AUDIT_FILE_NAME)
+}
+
+/// One audit line: UTC time, rule, order, trigger, action and outcome.
+///
+/// The rule name is written as a JSON string, so quotes or newlines in it cannot break the line.
+pub fn format_audit_line(
+    firing: &AutomationFiring,
+    outcome: &Result<(), String>,
+    at: chrono::DateTime<chrono::Utc>,
+) -> String {
+    let result = match outcome {
+        Ok(()) => "ok".to_string(),
+        Err(e) => format!("error: {}", e.replace('\n', " ")),
+    };
+    let rule = serde_json::to_string(&firing.rule).unwrap_or_default();
+    format!(
+        "{} rule={} order={} trigger={} action={} result={}",
+        at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        rule,
+        firing.order_id,
+        firing.trigger,
+        firing.action_kind().as_str(),
+        result
+    )
+}
+
+/// `(rule, order, trigger)` of a line written by [`format_audit_line`].
+pub fn parse_audit_line(line: &str) -> Option<(String, Uuid, String)> {
+    let (_, rest) = line.split_once(" rule=")?;
+    let mut names = serde_json::Deserializer::from_str(rest).into_iter::<String>();
+    let rule = names.next()?.ok()?;
+    let rest = rest[names.byte_offset()..].strip_prefix(" order=")?;
+    let (order, rest) = rest.split_once(" trigger=")?;
+    let (trigger, _) = rest.split_once(" action=")?;
+    Some((
+        rule.to_string(),
+        Uuid::parse_str(order).ok()?,
+        trigger.to_string(),
+    ))
+}
+
+/// Keys of every firing in the audit log; a missing or unreadable log yields none.
+pub fn load_fired_keys(path: &Path) -> HashSet<(String, Uuid, String)> {
+    match std::fs::read_to_string(path) {
+        Ok(raw) => raw.lines().filter_map(parse_audit_line).collect(),
+        Err(e) => {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                log::error!("Failed to read automation audit log: {}", e);
+            }
+            HashSet::new()
+        }
+    }
+}
+
+/// Append one line to the audit log, creating it on first use.
+pub fn append_audit_line(path: &Path, line: &str) -> std::io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    writeln!(file, "{line}")
+}
+
+fn audit(firing: &AutomationFiring, outcome: &Result<(), String>) {
+    let line = format_audit_line(firing, outcome, chrono::Utc::now());
+    log::info!("automation: {}", line);
+    let Some(path) = automation_audit_path() else {
+        return;
+    };
+    if let Err(e) = append_audit_line(&path, &line) {
+        log::error!("Failed to write automation audit log: {}", e);
+    }
+}
+
+/// Everything a background firing needs to talk to Mostro.
+#[derive(Clone)]
+pub struct AutomationContext {
+    pub pool: SqlitePool,
+    pub client: Client,
+    pub mostro_pubkey: PublicKey,
+    pub mostro_info: Option<MostroInstanceInfo>,
+    /// Saved buyer Lightning address (`ln_address` in settings.toml).
+    pub ln_address: String,
+    pub order_result_tx: UnboundedSender<OperationResult>,
+}
+
+async fn run_command(firing: &AutomationFiring, ctx: &AutomationContext) -> Result<()> {
+    let mostro_info = ctx.mostro_info.as_ref();
+    match &firing.command {
+        AutomationCommand::SubmitLnAddress { expected_sats } => {
+            let ln_address = ctx.ln_address.trim();
+            if ln_address.is_empty() {
+                anyhow::bail!("ln_address is not set in settings.toml");
+            }
+            execute_add_invoice(
+                &firing.order_id,
+                ln_address,
+                *expected_sats,
+                &ctx.pool,
+                &ctx.client,
+                ctx.mostro_pubkey,
+                mostro_info,
+            )
+            .await
+        }
+        AutomationCommand::AcceptCooperativeCancel => {
+            execute_send_msg(
+                &firing.order_id,
+                Action::Cancel,
+                &ctx.pool,
+                &ctx.client,
+                ctx.mostro_pubkey,
+                mostro_info,
+            )
+            .await
+        }
+        AutomationCommand::Rate(rating) => {
+            execute_rate_user(
+                &firing.order_id,
+                *rating,
+                &ctx.pool,
+                &ctx.client,
+                ctx.mostro_pubkey,
+                mostro_info,
+            )
+            .await
+        }
+    }
+}
+
+/// Run a firing in the background and audit its outcome. Failures also surface as an error
+/// popup so an operator returning to the terminal sees them.
+pub fn spawn_automation_firing(firing: AutomationFiring, ctx: AutomationContext) {
+    tokio::spawn(async move {
+        let outcome = run_command(&firing, &ctx).await.map_err(|e| e.to_string());
+        audit(&firing, &outcome);
+        if let Err(e) = outcome {
+            let _ = ctx.order_result_tx.send(OperationResult::Error(format!(
+                "Automation rule '{}' failed for order {}: {}",
+                firing.rule, firing.order_id, e
+            )));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mostro_core::prelude::Message;
+    use nostr_sdk::prelude::Keys;
+
+    const RULES: &str = r#"
+[[rules]]
+name = "payout"
+on = "add-invoice"
+do = "submit-ln-address"
+
+[[rules]]
+name = "stale-cancel"
+on = "cooperative-cancel-initiated-by-peer"
+do = "accept-cooperative-cancel"
+min_order_age_hours = 2
+
+[[rules]]
+name = "rate"
+on = "purchase-completed"
+do = "rate"
+"#;
+
+    fn message(order_id: Uuid, action: Action) -> OrderMessage {
+        OrderMessage {
+            message: Message::new_order(Some(order_id), None, Some(1), action, None),
+            timestamp: 1_000,
+            sender: Keys::generate().public_key(),
+            order_id: Some(order_id),
+            trade_index: 1,
+            sat_amount: Some(21_000),
+            buyer_invoice: None,
+            order_kind: None,
+            is_mine: Some(true),
+            order_status: None,
+            order_snapshot: None,
+            read: false,
+            auto_popup_shown: false,
+        }
+    }
+
+    fn enabled_engine() -> AutomationEngine {
+        let mut engine = AutomationEngine::from_toml(RULES).expect("rules parse");
+        engine.enabled = true;
+        engine
+    }
+
+    #[test]
+    fn kill_switch_blocks_every_rule() {
+        let mut engine = enabled_engine();
+        engine.enabled = false;
+        let msg = message(Uuid::new_v4(), Action::AddInvoice);
+        assert!(engine.evaluate(&msg, None, 0).is_empty());
+    }
+
+    #[test]
+    fn add_invoice_submits_ln_address_once_per_order() {
+        let mut engine = enabled_engine();
+        let order_id = Uuid::new_v4();
+        let msg = message(order_id, Action::AddInvoice);
+        let firings = engine.evaluate(&msg, None, 0);
+        assert_eq!(
+            firings,
+            vec![AutomationFiring {
+                rule: "payout".to_string(),
+                order_id,
+                trigger: Action::AddInvoice,
+                command: AutomationCommand::SubmitLnAddress {
+                    expected_sats: Some(21_000)
+                },
+            }]
+        );
+        assert!(engine.evaluate(&msg, None, 0).is_empty());
+    }
+
+    #[test]
+    fn cooperative_cancel_waits_for_order_age() {
+        let mut engine = enabled_engine();
+        let msg = message(Uuid::new_v4(), Action::CooperativeCancelInitiatedByPeer);
+        assert!(engine.evaluate(&msg, Some(0), 3_599).is_empty());
+        assert!(engine.evaluate(&msg, None, 10_000).is_empty());
+        let firings = engine.evaluate(&msg, Some(0), 7_200);
+        assert_eq!(firings.len(), 1);
+        assert_eq!(
+            firings[0].command,
+            AutomationCommand::AcceptCooperativeCancel
+        );
+    }
+
+    #[test]
+    fn rate_defaults_to_five_stars() {
+        let mut engine = enabled_engine();
+        let msg = message(Uuid::new_v4(), Action::PurchaseCompleted);
+        let firings = engine.evaluate(&msg, None, 0);
+        assert_eq!(firings[0].command, AutomationCommand::Rate(5));
+    }
+
+    #[test]
+    fn order_scoped_rule_ignores_other_orders() {
+        let order_id = Uuid::new_v4();
+        let raw = format!(
+            "[[rules]]\nname = \"one\"\non = \"add-invoice\"\ndo = \"submit-ln-address\"\norder_id = \"{order_id}\"\n"
+        );
+        let mut engine = AutomationEngine::from_toml(&raw).unwrap();
+        engine.enabled = true;
+        assert!(engine
+            .evaluate(&message(Uuid::new_v4(), Action::AddInvoice), None, 0)
+            .is_empty());
+        assert_eq!(
+            engine
+                .evaluate(&message(order_id, Action::AddInvoice), None, 0)
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        let mismatched =
+            "[[rules]]\nname = \"x\"\non = \"pay-invoice\"\ndo = \"submit-ln-address\"\n";
+        assert!(AutomationEngine::from_toml(mismatched).is_err());
+        let bad_rating = "[[rules]]\nname = \"x\"\non = \"rate\"\ndo = \"rate\"\nrating = 9\n";
+        assert!(AutomationEngine::from_toml(bad_rating).is_err());
+        let duplicate =
+            format!("{RULES}\n[[rules]]\nname = \"rate\"\non = \"rate\"\ndo = \"rate\"\n");
+        assert!(AutomationEngine::from_toml(&duplicate).is_err());
+    }
+
+    #[test]
+    fn audit_line_records_rule_order_and_outcome() {
+        let order_id = Uuid::nil();
+        let firing = AutomationFiring {
+            rule: "payout".to_string(),
+            order_id,
+            trigger: Action::AddInvoice,
+            command: AutomationCommand::SubmitLnAddress {
+                expected_sats: None,
+            },
+        };
+        let at = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        assert_eq!(
+            format_audit_line(&firing, &Err("timeout\nwaiting".to_string()), at),
+            format!(
+                "2023-11-14T22:13:20Z rule=\"payout\" order={order_id} trigger=AddInvoice action=submit-ln-address result=error: timeout waiting"
+            )
+        );
+
+        let dir = std::env::temp_dir().join(format!("mostrix-audit-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(AUTOMATION_AUDIT_FILE_NAME);
+        append_audit_line(&path, "first").unwrap();
+        append_audit_line(&path, "second").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "first\nsecond\n");
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn audit_line_round_trips_rule_names_with_quotes() {
+        let order_id = Uuid::new_v4();
+        let rule = r#"pay "fast" order=x\ trigger=y"#;
+        let firing = AutomationFiring {
+            rule: rule.to_string(),
+            order_id,
+            trigger: Action::Rate,
+            command: AutomationCommand::Rate(5),
+        };
+        let at = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let line = format_audit_line(&firing, &Ok(()), at);
+        assert!(line
+            .starts_with(r#"2023-11-14T22:13:20Z rule="pay \"fast\" order=x\\ trigger=y" order="#));
+        assert_eq!(
+            parse_audit_line(&line),
+            Some((rule.to_string(), order_id, "Rate".to_string()))
+        );
+    }
+
+    #[test]
+    fn audit_log_keeps_rules_fired_across_restarts() {
+        let order_id = Uuid::new_v4();
+        let mut engine = AutomationEngine::from_toml(RULES).unwrap();
+        engine.enabled = true;
+        let firings = engine.evaluate(&message(order_id, Action::AddInvoice), None, 0);
+        assert_eq!(firings.len(), 1);
+
+        let dir = std::env::temp_dir().join(format!("mostrix-audit-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(AUTOMATION_AUDIT_FILE_NAME);
+        let at = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let line = format_audit_line(&firings[0], &Err("relay down".to_string()), at);
+        append_audit_line(&path, &line).unwrap();
+        append_audit_line(&path, "not an audit line").unwrap();
+
+        let mut restarted = AutomationEngine::from_toml(RULES).unwrap();
+        restarted.enabled = true;
+        restarted.fired = load_fired_keys(&path);
+        assert_eq!(restarted.fired.len(), 1);
+        assert!(restarted
+            .evaluate(&message(order_id, Action::AddInvoice), None, 0)
+            .is_empty());
+        assert_eq!(
+            restarted
+                .evaluate(&message(Uuid::new_v4(), Action::AddInvoice), None, 0)
+                .len(),
+            1
+        );
+        assert!(load_fired_keys(&dir.join("missing.log")).is_empty());
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
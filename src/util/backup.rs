@@ -0,0 +1,749 @@
+//! Encrypted backup and restore of the `~/.mostrix` data directory.
+//!
+//! An archive is a single file: a plain header (magic, format, KDF iterations, salt) followed by
+//! a ChaCha20-Poly1305 blob that also authenticates the header. The key is derived from the
+//! passphrase with PBKDF2-HMAC-SHA256. The decrypted payload is a JSON manifest (path, size and
+//! SHA-256 of every file) followed by the file contents in manifest order.
+//!
+//! Restore never touches the open database: the archive is verified and unpacked into
+//! `~/.mostrix/restore_pending`, and the next startup moves the current files aside to
+//! `pre_restore_<timestamp>` before putting the restored ones in place.
+//!
+//! Each profile is backed up on its own: the `profiles/` folder of the default profile is
+//! neither archived nor moved aside by a restore.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use chacha20poly1305::aead::{Aead, Generate, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use tokio::sync::mpsc::UnboundedSender;
+use zeroize::Zeroizing;
+
+use crate::profile::PROFILES_DIR;
+use crate::ui::OperationResult;
+use crate::util::local_api::LOCAL_API_DIR_NAME;
+use crate::util::retention::shred_file;
+
+const BACKUP_MAGIC: &[u8; 8] = b"MOSTRIXB";
+const BACKUP_FORMAT: u8 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+/// Magic, format byte, iteration count (u32 LE) and salt; authenticated as associated data.
+const HEADER_LEN: usize = BACKUP_MAGIC.len() + 1 + 4 + SALT_LEN;
+
+/// PBKDF2 rounds for new archives (OWASP 2023 figure for HMAC-SHA256).
+pub const BACKUP_KDF_ITERATIONS: u32 = 600_000;
+/// Upper bound accepted when reading, so a crafted header cannot stall the app.
+const MAX_KDF_ITERATIONS: u32 = 10_000_000;
+
+pub const BACKUP_FILE_EXTENSION: &str = "mxbak";
+pub const MIN_PASSPHRASE_CHARS: usize = 8;
+
+const DB_FILE_NAME: &str = "mostrix.db";
+/// Consistent copy of the live database taken with `VACUUM INTO` while a backup runs.
+const SNAPSHOT_FILE_NAME: &str = "backup_snapshot.db";
+const RESTORE_DIR: &str = "restore_pending";
+const RESTORE_FILES_DIR: &str = "files";
+const RESTORE_MANIFEST: &str = "manifest.json";
+/// Present once the user accepted the staged restore; startup ignores staging without it.
+const RESTORE_READY_MARKER: &str = "ready";
+const PRE_RESTORE_PREFIX: &str = "pre_restore_";
+/// Live files never archived: the database (archived as a snapshot), its journals and the
+/// local API socket directory.
+const SKIPPED_FILE_NAMES: [&str; 6] = [
+    DB_FILE_NAME,
+    "mostrix.db-wal",
+    "mostrix.db-shm",
+    "mostrix.db-journal",
+    LOCAL_API_DIR_NAME,
+    SNAPSHOT_FILE_NAME,
+];
+
+/// How far a database has progressed: newest trade key index and latest trade activity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DbStamp {
+    pub last_trade_index: i64,
+    pub latest_activity: i64,
+}
+
+impl DbStamp {
+    pub async fn read(pool: &SqlitePool) -> Result<Self> {
+        let last_trade_index: Option<i64> =
+            sqlx::query_scalar("SELECT MAX(last_trade_index) FROM users")
+                .fetch_one(pool)
+                .await?;
+        let latest_activity: Option<i64> =
+            sqlx::query_scalar("SELECT MAX(COALESCE(last_seen_dm_ts, created_at)) FROM orders")
+                .fetch_one(pool)
+                .await?;
+        Ok(Self {
+            last_trade_index: last_trade_index.unwrap_or(0),
+            latest_activity: latest_activity.unwrap_or(0),
+        })
+    }
+
+    /// True when this database holds trades or trade keys that `other` does not.
+    pub fn is_newer_than(&self, other: &DbStamp) -> bool {
+        self.last_trade_index > other.last_trade_index
+            || self.latest_activity > other.latest_activity
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupEntry {
+    /// Relative to `~/.mostrix`, `/`-separated.
+    pub path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format: u8,
+    pub app_version: String,
+    pub created_at: i64,
+    pub db: DbStamp,
+    pub files: Vec<BackupEntry>,
+}
+
+impl BackupManifest {
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|f| f.size).sum()
+    }
+}
+
+/// Result of a written backup.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BackupSummary {
+    pub path: PathBuf,
+    pub files: usize,
+    pub bytes: u64,
+}
+
+/// A verified archive unpacked into `restore_pending`, waiting for a restart.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StagedRestore {
+    pub archive: PathBuf,
+    pub created_at: i64,
+    pub files: usize,
+    pub backup_db: DbStamp,
+    pub current_db: DbStamp,
+}
+
+impl StagedRestore {
+    /// The live database has trades the backup lacks; restoring needs explicit confirmation.
+    pub fn needs_confirmation(&self) -> bool {
+        self.current_db.is_newer_than(&self.backup_db)
+    }
+}
+
+/// What startup did with a staged restore.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppliedRestore {
+    pub created_at: i64,
+    pub files: usize,
+    /// Where the replaced data was moved.
+    pub previous_data: PathBuf,
+}
+
+/// Data directory of the active profile (`~/.mostrix` for the default one).
+pub fn backup_data_dir() -> Option<PathBuf> {
+    crate::settings::data_dir()
+}
+
+/// `~/mostrix-backup-[<profile>-]<timestamp>.mxbak`, outside the data directory so it is never
+/// archived.
+pub fn default_backup_path(now: chrono::DateTime<chrono::Local>) -> Option<PathBuf> {
+    let profile = crate::profile::active_profile()
+        .map(|name| format!("{name}-"))
+        .unwrap_or_default();
+    Some(crate::settings::home_dir()?.join(format!(
+        "mostrix-backup-{profile}{}.{BACKUP_FILE_EXTENSION}",
+        now.format("%Y%m%d-%H%M%S")
+    )))
+}
+
+/// Expands a leading `~/` in a path typed by the user.
+pub fn expand_user_path(input: &str) -> PathBuf {
+    let input = input.trim();
+    match (input.strip_prefix("~/"), crate::settings::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(input),
+    }
+}
+
+/// PBKDF2-HMAC-SHA256 with a 32-byte output.
+fn derive_backup_key(passphrase: &str, salt: &[u8], iterations: u32) -> Zeroizing<[u8; 32]> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, iterations, key.as_mut_slice());
+    key
+}
+
+fn header(iterations: u32, salt: &[u8; SALT_LEN]) -> Vec<u8> {
+    let mut header = Vec::with_capacity(HEADER_LEN);
+    header.extend_from_slice(BACKUP_MAGIC);
+    header.push(BACKUP_FORMAT);
+    header.extend_from_slice(&iterations.to_le_bytes());
+    header.extend_from_slice(salt);
+    header
+}
+
+/// Encrypts `payload` into a complete archive: `[header][nonce:12][ciphertext][tag:16]`.
+pub fn encrypt_archive(payload: &[u8], passphrase: &str, iterations: u32) -> Result<Vec<u8>> {
+    let salt = <[u8; SALT_LEN]>::generate();
+    let key = derive_backup_key(passphrase, &salt, iterations);
+    let cipher =
+        ChaCha20Poly1305::new_from_slice(key.as_slice()).map_err(|e| anyhow!("key init: {e}"))?;
+    let nonce = Nonce::generate();
+    let mut archive = header(iterations, &salt);
+    let ciphertext = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: payload,
+                aad: &archive,
+            },
+        )
+        .map_err(|e| anyhow!("encrypt failed: {e}"))?;
+    archive.extend_from_slice(nonce.as_slice());
+    archive.extend_from_slice(&ciphertext);
+    Ok(archive)
+}
+
+/// Checks the header and decrypts an archive; fails on a wrong passphrase or any tampering.
+pub fn decrypt_archive(archive: &[u8], passphrase: &str) -> Result<Zeroizing<Vec<u8>>> {
+    if archive.len() < HEADER_LEN + NONCE_LEN + 16 || !archive.starts_with(BACKUP_MAGIC) {
+        bail!("Not a Mostrix backup file");
+    }
+    let (header, body) = archive.split_at(HEADER_LEN);
+    let format = header[BACKUP_MAGIC.len()];
+    if format != BACKUP_FORMAT {
+        bail!("Unsupported backup format {format}; update Mostrix to restore it");
+    }
+    let iterations_at = BACKUP_MAGIC.len() + 1;
+    let iterations = u32::from_le_bytes(
+        header[iterations_at..iterations_at + 4]
+            .try_into()
+            .expect("4-byte slice"),
+    );
+    if iterations == 0 || iterations > MAX_KDF_ITERATIONS {
+        bail!("Backup header is corrupted (KDF iterations {iterations})");
+    }
+    let salt = &header[iterations_at + 4..];
+    let key = derive_backup_key(passphrase, salt, iterations);
+    let cipher =
+        ChaCha20Poly1305::new_from_slice(key.as_slice()).map_err(|e| anyhow!("key init: {e}"))?;
+    let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+    let nonce: &Nonce = nonce
+        .try_into()
+        .map_err(|_| anyhow!("invalid nonce length"))?;
+    let payload = cipher
+        .decrypt(
+            nonce,
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| anyhow!("Wrong passphrase or damaged backup file"))?;
+    Ok(Zeroizing::new(payload))
+}
+
+/// Lays out `[manifest length: u32 LE][manifest JSON][file bytes…]`.
+fn build_payload(manifest: &BackupManifest, contents: &[Vec<u8>]) -> Result<Zeroizing<Vec<u8>>> {
+    let manifest_json = serde_json::to_vec(manifest)?;
+    let total = 4 + manifest_json.len() + contents.iter().map(Vec::len).sum::<usize>();
+    let mut payload = Zeroizing::new(Vec::with_capacity(total));
+    payload.extend_from_slice(&(manifest_json.len() as u32).to_le_bytes());
+    payload.extend_from_slice(&manifest_json);
+    for content in contents {
+        payload.extend_from_slice(content);
+    }
+    Ok(payload)
+}
+
+/// Only plain relative paths may come out of an archive.
+fn is_safe_relative_path(path: &str) -> bool {
+    !path.is_empty()
+        && Path::new(path)
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+}
+
+/// Splits a decrypted payload and checks every file against the manifest.
+pub fn parse_payload(payload: &[u8]) -> Result<(BackupManifest, Vec<&[u8]>)> {
+    let len_bytes: [u8; 4] = payload
+        .get(..4)
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| anyhow!("Backup payload is truncated"))?;
+    let manifest_end = 4 + u32::from_le_bytes(len_bytes) as usize;
+    let manifest: BackupManifest = serde_json::from_slice(
+        payload
+            .get(4..manifest_end)
+            .ok_or_else(|| anyhow!("Backup manifest is truncated"))?,
+    )
+    .context("Backup manifest is invalid")?;
+    if manifest.format != BACKUP_FORMAT {
+        bail!("Unsupported backup format {}", manifest.format);
+    }
+
+    let mut offset = manifest_end;
+    let mut contents = Vec::with_capacity(manifest.files.len());
+    for entry in &manifest.files {
+        if !is_safe_relative_path(&entry.path) {
+            bail!("Backup contains an unsafe path: {}", entry.path);
+        }
+        let end = offset
+            .checked_add(entry.size as usize)
+            .filter(|end| *end <= payload.len())
+            .ok_or_else(|| anyhow!("Backup is missing data for {}", entry.path))?;
+        let content = &payload[offset..end];
+        if hex::encode(Sha256::digest(content)) != entry.sha256 {
+            bail!("Integrity check failed for {}", entry.path);
+        }
+        contents.push(content);
+        offset = end;
+    }
+    if offset != payload.len() {
+        bail!("Backup has unexpected trailing data");
+    }
+    if !manifest.files.iter().any(|f| f.path == DB_FILE_NAME) {
+        bail!("Backup does not contain {DB_FILE_NAME}");
+    }
+    Ok((manifest, contents))
+}
+
+fn is_skipped_top_level(name: &str) -> bool {
+    SKIPPED_FILE_NAMES.contains(&name)
+        || name == RESTORE_DIR
+        || name == PROFILES_DIR
+        || name.starts_with(PRE_RESTORE_PREFIX)
+}
+
+/// Every regular file under `dir` (symlinks skipped), as `/`-separated relative paths.
+fn collect_files(root: &Path, dir: &Path, out: &mut Vec<(String, PathBuf)>) -> Result<()> {
+    for entry in fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+        let entry = entry?;
+        let path = entry.path();
+        let file_type = entry.file_type()?;
+        let relative = path
+            .strip_prefix(root)
+            .expect("walk stays under root")
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if dir == root && is_skipped_top_level(&relative) {
+            continue;
+        }
+        if file_type.is_dir() {
+            collect_files(root, &path, out)?;
+        } else if file_type.is_file()
+            && path.extension().and_then(|e| e.to_str()) != Some(BACKUP_FILE_EXTENSION)
+        {
+            out.push((relative, path));
+        }
+    }
+    Ok(())
+}
+
+/// Creates `path` readable by the owner only (Unix) and writes `data`.
+fn write_private(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    file.write_all(data)?;
+    file.sync_all()
+}
+
+/// Archives `data_dir` (database snapshot included) into `out`, encrypted with `passphrase`.
+pub async fn create_backup(
+    pool: &SqlitePool,
+    data_dir: &Path,
+    out: &Path,
+    passphrase: &str,
+    iterations: u32,
+) -> Result<BackupSummary> {
+    if out.exists() {
+        bail!("{} already exists", out.display());
+    }
+    let snapshot = data_dir.join(SNAPSHOT_FILE_NAME);
+    if snapshot.exists() {
+        shred_file(&snapshot)?;
+    }
+    sqlx::query("VACUUM INTO ?")
+        .bind(snapshot.to_string_lossy().into_owned())
+        .execute(pool)
+        .await
+        .context("Failed to snapshot the database")?;
+    let db = DbStamp::read(pool).await?;
+    let db_bytes = fs::read(&snapshot);
+    if let Err(e) = shred_file(&snapshot) {
+        log::warn!("Backup: could not remove {}: {e}", snapshot.display());
+    }
+
+    let files = vec![(DB_FILE_NAME.to_string(), db_bytes?)];
+
+    // Reading the tree, hashing and the key derivation are CPU/disk bound: keep them off the
+    // async workers.
+    let data_dir = data_dir.to_path_buf();
+    let out = out.to_path_buf();
+    let passphrase = Zeroizing::new(passphrase.to_string());
+    tokio::task::spawn_blocking(move || {
+        write_backup_archive(&data_dir, &out, files, db, &passphrase, iterations)
+    })
+    .await
+    .map_err(|e| anyhow!("backup task failed: {e}"))?
+}
+
+fn write_backup_archive(
+    data_dir: &Path,
+    out: &Path,
+    mut files: Vec<(String, Vec<u8>)>,
+    db: DbStamp,
+    passphrase: &str,
+    iterations: u32,
+) -> Result<BackupSummary> {
+    let mut on_disk = Vec::new();
+    collect_files(data_dir, data_dir, &mut on_disk)?;
+    on_disk.sort();
+    for (relative, path) in on_disk {
+        let content = fs::read(&path).with_context(|| format!("reading {}", path.display()))?;
+        files.push((relative, content));
+    }
+
+    let manifest = BackupManifest {
+        format: BACKUP_FORMAT,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: chrono::Utc::now().timestamp(),
+        db,
+        files: files
+            .iter()
+            .map(|(path, content)| BackupEntry {
+                path: path.clone(),
+                size: content.len() as u64,
+                sha256: hex::encode(Sha256::digest(content)),
+            })
+            .collect(),
+    };
+    let contents: Vec<Vec<u8>> = files.into_iter().map(|(_, c)| c).collect();
+    let payload = build_payload(&manifest, &contents)?;
+    let archive = encrypt_archive(&payload, passphrase, iterations)?;
+
+    // Write next to the target and rename, so a failed write never leaves a partial archive.
+    let partial = out.with_extension(format!("{BACKUP_FILE_EXTENSION}.partial"));
+    write_private(&partial, &archive).with_context(|| format!("writing {}", partial.display()))?;
+    fs::rename(&partial, out)?;
+    Ok(BackupSummary {
+        path: out.to_path_buf(),
+        files: manifest.files.len(),
+        bytes: manifest.total_bytes(),
+    })
+}
+
+/// Verifies `archive` and unpacks it into `data_dir/restore_pending`.
+///
+/// The staged restore is marked ready right away unless the live database is newer than the
+/// backup; then [`confirm_staged_restore`] must be called first.
+pub async fn stage_restore(
+    pool: &SqlitePool,
+    data_dir: &Path,
+    archive: &Path,
+    passphrase: &str,
+) -> Result<StagedRestore> {
+    let staging_dir = data_dir.to_path_buf();
+    let archive_path = archive.to_path_buf();
+    let passphrase = Zeroizing::new(passphrase.to_string());
+    let manifest = tokio::task::spawn_blocking(move || {
+        unpack_archive(&staging_dir, &archive_path, &passphrase)
+    })
+    .await
+    .map_err(|e| anyhow!("restore task failed: {e}"))??;
+
+    let staged = StagedRestore {
+        archive: archive.to_path_buf(),
+        created_at: manifest.created_at,
+        files: manifest.files.len(),
+        backup_db: manifest.db,
+        current_db: DbStamp::read(pool).await?,
+    };
+    if !staged.needs_confirmation() {
+        confirm_staged_restore(data_dir)?;
+    }
+    Ok(staged)
+}
+
+/// Decrypts `archive` and writes its files and manifest under `data_dir/restore_pending`.
+fn unpack_archive(data_dir: &Path, archive: &Path, passphrase: &str) -> Result<BackupManifest> {
+    let bytes = fs::read(archive).with_context(|| format!("reading {}", archive.display()))?;
+    let payload = decrypt_archive(&bytes, passphrase)?;
+    let (manifest, contents) = parse_payload(&payload)?;
+
+    let staging = data_dir.join(RESTORE_DIR);
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    let files_dir = staging.join(RESTORE_FILES_DIR);
+    for (entry, content) in manifest.files.iter().zip(contents) {
+        let target = files_dir.join(&entry.path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write_private(&target, content).with_context(|| format!("staging {}", target.display()))?;
+    }
+    write_private(
+        &staging.join(RESTORE_MANIFEST),
+        &serde_json::to_vec_pretty(&manifest)?,
+    )?;
+    Ok(manifest)
+}
+
+/// Accepts the staged restore; it is applied on the next startup.
+pub fn confirm_staged_restore(data_dir: &Path) -> Result<()> {
+    let staging = data_dir.join(RESTORE_DIR);
+    if !staging.join(RESTORE_MANIFEST).exists() {
+        bail!("No staged restore found");
+    }
+    write_private(&staging.join(RESTORE_READY_MARKER), b"")?;
+    Ok(())
+}
+
+/// Drops a staged restore the user declined.
+pub fn discard_staged_restore(data_dir: &Path) -> Result<()> {
+    let staging = data_dir.join(RESTORE_DIR);
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    Ok(())
+}
+
+/// Startup hook, run before the database opens: swaps in a confirmed staged restore.
+///
+/// Everything currently in `data_dir` is moved to `pre_restore_<timestamp>` first, so nothing is
+/// lost if the backup turns out to be the wrong one. Unconfirmed staging is discarded.
+pub fn apply_pending_restore(data_dir: &Path) -> Result<Option<AppliedRestore>> {
+    let staging = data_dir.join(RESTORE_DIR);
+    if !staging.exists() {
+        return Ok(None);
+    }
+    if !staging.join(RESTORE_READY_MARKER).exists() {
+        fs::remove_dir_all(&staging)?;
+        return Ok(None);
+    }
+    let manifest: BackupManifest =
+        serde_json::from_slice(&fs::read(staging.join(RESTORE_MANIFEST))?)
+            .context("Staged restore manifest is invalid")?;
+
+    let previous_data = data_dir.join(format!(
+        "{PRE_RESTORE_PREFIX}{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    fs::create_dir_all(&previous_data)?;
+    for entry in fs::read_dir(data_dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name_str = name.to_string_lossy();
+        if name_str == RESTORE_DIR
+            || name_str == PROFILES_DIR
+            || name_str.starts_with(PRE_RESTORE_PREFIX)
+            || name_str == LOCAL_API_DIR_NAME
+        {
+            continue;
+        }
+        fs::rename(entry.path(), previous_data.join(&name)).with_context(|| {
+            format!(
+                "moving {} aside (restore stopped; data is in {} and {})",
+                entry.path().display(),
+                previous_data.display(),
+                staging.display()
+            )
+        })?;
+    }
+    for entry in fs::read_dir(staging.join(RESTORE_FILES_DIR))? {
+        let entry = entry?;
+        fs::rename(entry.path(), data_dir.join(entry.file_name())).with_context(|| {
+            format!(
+                "moving restored {} into place (previous data is in {})",
+                entry.path().display(),
+                previous_data.display()
+            )
+        })?;
+    }
+    fs::remove_dir_all(&staging)?;
+    Ok(Some(AppliedRestore {
+        created_at: manifest.created_at,
+        files: manifest.files.len(),
+        previous_data,
+    }))
+}
+
+fn format_timestamp(ts: i64) -> String {
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map(|dt| {
+            dt.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|| ts.to_string())
+}
+
+impl BackupSummary {
+    pub fn message(&self) -> String {
+        format!(
+            "Backup written to {} ({} files, {} KB). Keep the passphrase safe: without it the backup cannot be restored.",
+            self.path.display(),
+            self.files,
+            self.bytes.div_ceil(1024)
+        )
+    }
+}
+
+impl StagedRestore {
+    pub fn backup_date(&self) -> String {
+        format_timestamp(self.created_at)
+    }
+
+    pub fn message(&self) -> String {
+        format!(
+            "Backup from {} verified ({} files). Restart Mostrix to finish the restore.",
+            self.backup_date(),
+            self.files
+        )
+    }
+}
+
+impl AppliedRestore {
+    pub fn message(&self) -> String {
+        format!(
+            "Restored the backup from {} ({} files). Previous data was moved to {}.",
+            format_timestamp(self.created_at),
+            self.files,
+            self.previous_data.display()
+        )
+    }
+}
+
+/// Writes a backup in the background; reports `Info` with the summary or `Error`.
+pub fn spawn_create_backup_task(
+    pool: SqlitePool,
+    out: PathBuf,
+    passphrase: Zeroizing<String>,
+    order_result_tx: UnboundedSender<OperationResult>,
+) {
+    tokio::spawn(async move {
+        let Some(data_dir) = backup_data_dir() else {
+            let _ = order_result_tx.send(OperationResult::Error(
+                "Cannot locate the ~/.mostrix data directory.".to_string(),
+            ));
+            return;
+        };
+        let result =
+            match create_backup(&pool, &data_dir, &out, &passphrase, BACKUP_KDF_ITERATIONS).await {
+                Ok(summary) => OperationResult::Info(summary.message()),
+                Err(e) => OperationResult::Error(format!("Backup failed: {e:#}")),
+            };
+        let _ = order_result_tx.send(result);
+    });
+}
+
+/// Verifies and stages a restore in the background. Reports
+/// [`OperationResult::RestoreNeedsConfirmation`] when the live database is newer.
+pub fn spawn_stage_restore_task(
+    pool: SqlitePool,
+    archive: PathBuf,
+    passphrase: Zeroizing<String>,
+    order_result_tx: UnboundedSender<OperationResult>,
+) {
+    tokio::spawn(async move {
+        let Some(data_dir) = backup_data_dir() else {
+            let _ = order_result_tx.send(OperationResult::Error(
+                "Cannot locate the ~/.mostrix data directory.".to_string(),
+            ));
+            return;
+        };
+        let result = match stage_restore(&pool, &data_dir, &archive, &passphrase).await {
+            Ok(staged) if staged.needs_confirmation() => {
+                OperationResult::RestoreNeedsConfirmation(Box::new(staged))
+            }
+            Ok(staged) => OperationResult::Info(staged.message()),
+            Err(e) => {
+                let _ = discard_staged_restore(&data_dir);
+                OperationResult::Error(format!("Restore failed: {e:#}"))
+            }
+        };
+        let _ = order_result_tx.send(result);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pbkdf2_matches_known_vectors() {
+        assert_eq!(
+            hex::encode(*derive_backup_key("password", b"salt", 1)),
+            "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b"
+        );
+        assert_eq!(
+            hex::encode(*derive_backup_key("password", b"salt", 2)),
+            "ae4d0c95af6b46d32d0adff928f06dd02a303f8ef3c251dfd6e2d85a95474c43"
+        );
+    }
+
+    #[test]
+    fn archive_rejects_wrong_passphrase_and_tampering() {
+        let archive = encrypt_archive(b"payload", "correct horse", 10).unwrap();
+        assert_eq!(
+            decrypt_archive(&archive, "correct horse")
+                .unwrap()
+                .as_slice(),
+            b"payload"
+        );
+        assert!(decrypt_archive(&archive, "wrong horse").is_err());
+
+        // Lowering the iteration count in the header breaks authentication.
+        let mut tampered = archive.clone();
+        tampered[BACKUP_MAGIC.len() + 1] ^= 1;
+        assert!(decrypt_archive(&tampered, "correct horse").is_err());
+        assert!(decrypt_archive(b"not a backup", "correct horse").is_err());
+    }
+
+    #[test]
+    fn payload_rejects_unsafe_paths_and_bad_hashes() {
+        let entry = |path: &str, content: &[u8]| BackupEntry {
+            path: path.to_string(),
+            size: content.len() as u64,
+            sha256: hex::encode(Sha256::digest(content)),
+        };
+        let manifest = |files| BackupManifest {
+            format: BACKUP_FORMAT,
+            app_version: "test".to_string(),
+            created_at: 0,
+            db: DbStamp::default(),
+            files,
+        };
+
+        let ok = manifest(vec![entry(DB_FILE_NAME, b"db")]);
+        let payload = build_payload(&ok, &[b"db".to_vec()]).unwrap();
+        assert_eq!(parse_payload(&payload).unwrap().1, vec![b"db".as_slice()]);
+
+        let escape = manifest(vec![entry(DB_FILE_NAME, b"db"), entry("../x", b"x")]);
+        let payload = build_payload(&escape, &[b"db".to_vec(), b"x".to_vec()]).unwrap();
+        assert!(parse_payload(&payload).is_err());
+
+        let payload = build_payload(&ok, &[b"dB".to_vec()]).unwrap();
+        assert!(parse_payload(&payload).is_err());
+    }
+}
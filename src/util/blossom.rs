@@ -12,10 +12,12 @@ use nostr_sdk::prelude::{EventBuilder, FinalizeEvent, Keys, Kind, PublicKey, Tag
 use reqwest::{header::CONTENT_LENGTH, Client};
 use sha2::{Digest, Sha256};
 use std::path::PathBuf;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::ui::{ChatAttachment, OperationResult};
+use crate::util::blossom_health::BlossomHealth;
+use crate::util::proxy::http_client;
 
 /// NIP-24242 Blossom upload authorization event kind.
 const BLOSSOM_AUTH_KIND: Kind = Kind::Custom(24242);
@@ -36,6 +38,9 @@ pub const DEFAULT_BLOSSOM_SERVERS: &[&str] = &[
 /// Upload timeout (seconds).
 const BLOSSOM_UPLOAD_TIMEOUT_SECS: u64 = 300;
 
+/// Delete request timeout (seconds).
+const BLOSSOM_DELETE_TIMEOUT_SECS: u64 = 30;
+
 /// Derives the 32-byte shared decryption key from our (admin) private key and the sender's public key.
 /// Mirror of mostro-cli's derive_shared_key: they use (trade_sk, admin_pubkey); we use (admin_sk, sender_pubkey).
 pub fn derive_shared_key(admin_keys: &Keys, sender_pubkey: &PublicKey) -> Result<[u8; 32]> {
@@ -168,17 +173,28 @@ pub fn sha256_hex(data: &[u8]) -> String {
     hex::encode(hash)
 }
 
-fn normalize_blossom_server_base(server: &str) -> String {
+/// Trims whitespace and trailing slashes so health stats and upload records key on one form.
+pub fn normalize_blossom_server_base(server: &str) -> String {
     server.trim().trim_end_matches('/').to_string()
 }
 
 /// Builds a signed NIP-24242 authorization event for Blossom upload.
 /// Must use the same identity that publishes the corresponding chat message (order trade key).
 pub(crate) fn blossom_upload_auth_header(blob_hash_hex: &str, keys: &Keys) -> Result<String> {
+    blossom_auth_header("upload", blob_hash_hex, keys)
+}
+
+/// Builds a signed NIP-24242 authorization event for deleting a blob (BUD-02).
+/// Servers only accept it from the key that uploaded the blob.
+pub(crate) fn blossom_delete_auth_header(blob_hash_hex: &str, keys: &Keys) -> Result<String> {
+    blossom_auth_header("delete", blob_hash_hex, keys)
+}
+
+fn blossom_auth_header(verb: &str, blob_hash_hex: &str, keys: &Keys) -> Result<String> {
     let now = Timestamp::now().as_secs();
     let expiration = (now + 3600).to_string();
     let tags = vec![
-        Tag::parse(["t", "upload"]).map_err(|e| anyhow!("auth tag t: {}", e))?,
+        Tag::parse(["t", verb]).map_err(|e| anyhow!("auth tag t: {}", e))?,
         Tag::parse(["x", blob_hash_hex]).map_err(|e| anyhow!("auth tag x: {}", e))?,
         Tag::parse(["expiration", expiration.as_str()])
             .map_err(|e| anyhow!("auth tag expiration: {}", e))?,
@@ -221,27 +237,100 @@ pub async fn upload_blob(
     Ok(format!("{base}/{hash_hex}"))
 }
 
-/// Tries each server in order until one accepts the upload.
+/// Where an uploaded blob lives: the URL shared in chat plus every server holding a copy.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UploadedBlobLocations {
+    /// URL of the first successful upload (sent in the chat message).
+    pub url: String,
+    /// SHA-256 of the encrypted blob.
+    pub hash: String,
+    /// Normalized server bases holding the blob, primary first then the mirror.
+    pub servers: Vec<String>,
+}
+
+/// Tries servers best-health first until one accepts the upload; with `mirror`, keeps going
+/// until a second server also holds a copy. Every attempt updates `health`.
 pub async fn upload_blob_with_retry(
     http: &Client,
     servers: &[String],
     blob: &[u8],
     auth_keys: &Keys,
-) -> Result<String> {
+    mirror: bool,
+    health: &mut BlossomHealth,
+) -> Result<UploadedBlobLocations> {
     if servers.is_empty() {
         return Err(anyhow!("no Blossom servers configured"));
     }
+    let mut uploaded: Option<UploadedBlobLocations> = None;
     let mut last_err = anyhow!("no upload attempt");
-    for server in servers {
-        match upload_blob(http, server, blob, auth_keys).await {
-            Ok(url) => return Ok(url),
+    for server in health.rank(servers) {
+        let started = Instant::now();
+        match upload_blob(http, &server, blob, auth_keys).await {
+            Ok(url) => {
+                health.record_success(&server, started.elapsed());
+                match uploaded.as_mut() {
+                    None => {
+                        uploaded = Some(UploadedBlobLocations {
+                            url,
+                            hash: sha256_hex(blob),
+                            servers: vec![server],
+                        });
+                        if !mirror {
+                            break;
+                        }
+                    }
+                    Some(primary) => {
+                        primary.servers.push(server);
+                        break;
+                    }
+                }
+            }
             Err(e) => {
+                health.record_failure(&server);
                 log::warn!("Blossom upload failed for {}: {}", server, e);
                 last_err = e;
             }
         }
     }
-    Err(last_err)
+    match uploaded {
+        Some(locations) => {
+            if mirror && locations.servers.len() < 2 {
+                log::warn!(
+                    "Blossom mirror: no second server accepted {}; only {} holds it",
+                    locations.hash,
+                    locations.servers[0]
+                );
+            }
+            Ok(locations)
+        }
+        None => Err(last_err),
+    }
+}
+
+/// Deletes one blob from a Blossom server (BUD-02 `DELETE /<sha256>`), signed by the uploader.
+/// A 404 counts as success: the blob is already gone.
+pub async fn delete_blob(
+    http: &Client,
+    server_base: &str,
+    blob_hash_hex: &str,
+    auth_keys: &Keys,
+) -> Result<()> {
+    let base = normalize_blossom_server_base(server_base);
+    let auth = blossom_delete_auth_header(blob_hash_hex, auth_keys)?;
+    let res = http
+        .delete(format!("{base}/{blob_hash_hex}"))
+        .header("Authorization", auth)
+        .header("User-Agent", "Mostrix/0.2")
+        .timeout(Duration::from_secs(BLOSSOM_DELETE_TIMEOUT_SECS))
+        .send()
+        .await
+        .map_err(|e| anyhow!("Blossom delete failed: {}", e))?;
+    let status = res.status();
+    if status.is_success() || status == reqwest::StatusCode::NOT_FOUND {
+        return Ok(());
+    }
+    let body = res.text().await.unwrap_or_default();
+    Err(anyhow!("Blossom delete returned {status}: {body}"))
 }
 
 /// Sanitizes a filename to avoid path traversal: only [a-zA-Z0-9_.-] allowed.
@@ -272,7 +361,7 @@ pub async fn save_attachment_to_disk(
     decryption_key: Option<Vec<u8>>,
 ) -> Result<PathBuf> {
     let url = blossom_url_to_https(blossom_url.trim())?;
-    let client = Client::new();
+    let client = http_client()?;
     let blob = fetch_blob(&client, &url, 0, BLOSSOM_MAX_BLOB_SIZE).await?;
     let bytes = match &decryption_key {
         Some(key) => decrypt_blob(key, &blob)?,
@@ -284,8 +373,9 @@ pub async fn save_attachment_to_disk(
     } else {
         format!("{}.enc", sanitized)
     };
-    let home = dirs::home_dir().ok_or_else(|| anyhow!("No home directory"))?;
-    let dir = home.join(".mostrix").join("downloads");
+    let dir = crate::settings::data_dir()
+        .ok_or_else(|| anyhow!("No home directory"))?
+        .join("downloads");
     std::fs::create_dir_all(&dir).map_err(|e| anyhow!("Create downloads dir: {}", e))?;
     let path = dir.join(format!("{}_{}", dispute_id, final_name));
     std::fs::write(&path, &bytes).map_err(|e| anyhow!("Write file: {}", e))?;
@@ -367,4 +457,19 @@ mod tests {
         let event = Event::from_json(json_str).expect("event json");
         assert_eq!(event.pubkey, keys.public_key());
     }
+
+    #[test]
+    fn delete_auth_event_is_scoped_to_delete_and_hash() {
+        use nostr_sdk::prelude::Event;
+
+        let keys = Keys::generate();
+        let header = blossom_delete_auth_header("cafe", &keys).expect("auth header");
+        let json = BASE64
+            .decode(header.strip_prefix("Nostr ").expect("Nostr prefix"))
+            .expect("base64");
+        let event = Event::from_json(std::str::from_utf8(&json).unwrap()).expect("event json");
+        let tags: Vec<Vec<String>> = event.tags.iter().map(|t| t.clone().to_vec()).collect();
+        assert!(tags.contains(&vec!["t".to_string(), "delete".to_string()]));
+        assert!(tags.contains(&vec!["x".to_string(), "cafe".to_string()]));
+    }
 }
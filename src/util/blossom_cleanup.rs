@@ -0,0 +1,285 @@
+//! Blobs we uploaded to Blossom (`~/.mostrix/blossom_uploads.json`) and the cleanup job that
+//! deletes them once their trade reaches a terminal status.
+//!
+//! Delete requests are signed with the order's trade key, the same key that authorized the
+//! upload. Records are only written under [`REGISTRY_LOCK`] and re-read before each write, so an
+//! upload finishing while a cleanup pass runs is not lost.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Result};
+use mostro_core::prelude::Status;
+use nostr_sdk::prelude::{Keys, SecretKey};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tokio::time::{interval, Duration};
+
+use crate::models::Order;
+use crate::util::blossom::{delete_blob, UploadedBlobLocations};
+use crate::util::order_utils::is_terminal_trade_status;
+use crate::util::proxy::http_client;
+
+const BLOSSOM_UPLOADS_FILE_NAME: &str = "blossom_uploads.json";
+
+/// How often the background job looks for finished trades.
+const BLOSSOM_CLEANUP_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// Servers that keep refusing a delete are given up on after this many passes.
+const MAX_DELETE_ATTEMPTS: u32 = 5;
+
+/// Serializes read-modify-write of the uploads file between upload tasks and cleanup passes.
+static REGISTRY_LOCK: Mutex<()> = Mutex::new(());
+
+/// One encrypted attachment we uploaded for an order chat.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadedBlob {
+    pub order_id: String,
+    pub hash: String,
+    /// Server bases still holding the blob (removed as deletes succeed).
+    pub servers: Vec<String>,
+    pub uploaded_at: i64,
+    #[serde(default)]
+    pub delete_attempts: u32,
+}
+
+/// Counts from one cleanup pass.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlossomCleanupSummary {
+    /// Blob copies deleted (one per server).
+    pub deleted: usize,
+    /// Delete requests that failed and will be retried.
+    pub failed: usize,
+    /// Blobs kept because their trade is still running.
+    pub waiting: usize,
+}
+
+/// `~/.mostrix/blossom_uploads.json`, or `None` when the home directory is unknown.
+pub fn blossom_uploads_path() -> Option<PathBuf> {
+    Some(blossom_uploads_path_in(&crate::settings::data_dir()?))
+}
+
+/// The uploads file inside `data_dir`.
+pub fn blossom_uploads_path_in(data_dir: &Path) -> PathBuf {
+    data_dir.join(BLOSSOM_UPLOADS_FILE_NAME)
+}
+
+pub fn load_uploaded_blobs(path: &Path) -> Vec<UploadedBlob> {
+    match std::fs::read_to_string(path) {
+        Ok(raw) => serde_json::from_str(&raw).unwrap_or_else(|e| {
+            log::warn!("Ignoring invalid {}: {e}", path.display());
+            Vec::new()
+        }),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Order ids that still have blobs waiting for a delete.
+pub fn orders_with_uploaded_blobs(path: &Path) -> HashSet<String> {
+    let _guard = REGISTRY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    load_uploaded_blobs(path)
+        .into_iter()
+        .map(|b| b.order_id)
+        .collect()
+}
+
+fn save_uploaded_blobs(path: &Path, blobs: &[UploadedBlob]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(blobs)?;
+    std::fs::write(path, json).map_err(|e| anyhow!("write {}: {e}", path.display()))
+}
+
+/// Remember an upload so the cleanup job can delete it after the trade.
+pub fn record_uploaded_blob(path: &Path, order_id: &str, locations: &UploadedBlobLocations) {
+    let _guard = REGISTRY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut blobs = load_uploaded_blobs(path);
+    blobs.push(UploadedBlob {
+        order_id: order_id.to_string(),
+        hash: locations.hash.clone(),
+        servers: locations.servers.clone(),
+        uploaded_at: chrono::Utc::now().timestamp(),
+        delete_attempts: 0,
+    });
+    if let Err(e) = save_uploaded_blobs(path, &blobs) {
+        log::warn!("Could not record Blossom upload {}: {e}", locations.hash);
+    }
+}
+
+/// [`record_uploaded_blob`] into `~/.mostrix/blossom_uploads.json`.
+pub fn record_uploaded_blob_default(order_id: &str, locations: &UploadedBlobLocations) {
+    match blossom_uploads_path() {
+        Some(path) => record_uploaded_blob(&path, order_id, locations),
+        None => log::warn!(
+            "No home directory; Blossom upload {} not tracked",
+            locations.hash
+        ),
+    }
+}
+
+/// Outcome for one recorded blob after a pass.
+enum BlobOutcome {
+    /// Trade still running (or status unknown): keep untouched.
+    Waiting,
+    /// Delete attempted; `remaining` servers still hold the blob.
+    Attempted { remaining: Vec<String> },
+    /// Nothing more can be done (all deleted, order gone, or gave up).
+    Done,
+}
+
+fn order_trade_keys(order: &Order) -> Option<Keys> {
+    let sk = SecretKey::from_str(order.trade_keys.as_deref()?).ok()?;
+    Some(Keys::new(sk))
+}
+
+/// `true` when the order row is gone, as opposed to a failed lookup.
+fn is_row_not_found(e: &anyhow::Error) -> bool {
+    matches!(
+        e.downcast_ref::<sqlx::Error>(),
+        Some(sqlx::Error::RowNotFound)
+    )
+}
+
+/// One cleanup pass: delete every recorded blob whose order is in a terminal status.
+pub async fn run_blossom_cleanup(
+    pool: &SqlitePool,
+    http: &Client,
+    registry_path: &Path,
+) -> BlossomCleanupSummary {
+    let snapshot = {
+        let _guard = REGISTRY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        load_uploaded_blobs(registry_path)
+    };
+    let mut summary = BlossomCleanupSummary::default();
+    let mut outcomes = Vec::with_capacity(snapshot.len());
+
+    for blob in &snapshot {
+        let order = match Order::get_by_id(pool, &blob.order_id).await {
+            Ok(order) => order,
+            Err(e) if !is_row_not_found(&e) => {
+                // DB busy or unreadable: keep the record and retry on the next pass.
+                log::warn!(
+                    "Blossom cleanup: could not load order {}: {e}",
+                    blob.order_id
+                );
+                summary.waiting += 1;
+                outcomes.push(BlobOutcome::Waiting);
+                continue;
+            }
+            Err(_) => {
+                // Deleted from history: the trade key that could sign the delete is gone too.
+                log::warn!(
+                    "Blossom cleanup: order {} no longer stored; blob {} stays on {} (dropping record)",
+                    blob.order_id,
+                    blob.hash,
+                    blob.servers.join(", ")
+                );
+                outcomes.push(BlobOutcome::Done);
+                continue;
+            }
+        };
+        let terminal = order
+            .status
+            .as_deref()
+            .and_then(|s| Status::from_str(s).ok())
+            .is_some_and(is_terminal_trade_status);
+        let Some(keys) = order_trade_keys(&order).filter(|_| terminal) else {
+            summary.waiting += 1;
+            outcomes.push(BlobOutcome::Waiting);
+            continue;
+        };
+
+        let mut remaining = Vec::new();
+        for server in &blob.servers {
+            match delete_blob(http, server, &blob.hash, &keys).await {
+                Ok(()) => summary.deleted += 1,
+                Err(e) => {
+                    log::warn!(
+                        "Blossom cleanup: delete {} on {server} failed: {e}",
+                        blob.hash
+                    );
+                    summary.failed += 1;
+                    remaining.push(server.clone());
+                }
+            }
+        }
+        outcomes.push(if remaining.is_empty() {
+            BlobOutcome::Done
+        } else {
+            BlobOutcome::Attempted { remaining }
+        });
+    }
+
+    let _guard = REGISTRY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    // Re-read: uploads recorded during the pass are kept as-is.
+    let mut current = load_uploaded_blobs(registry_path);
+    for (blob, outcome) in snapshot.iter().zip(outcomes) {
+        let Some(pos) = current
+            .iter()
+            .position(|b| b.hash == blob.hash && b.order_id == blob.order_id)
+        else {
+            continue;
+        };
+        match outcome {
+            BlobOutcome::Waiting => {}
+            BlobOutcome::Done => {
+                current.remove(pos);
+            }
+            BlobOutcome::Attempted { remaining } => {
+                let entry = &mut current[pos];
+                entry.delete_attempts += 1;
+                if entry.delete_attempts >= MAX_DELETE_ATTEMPTS {
+                    log::warn!(
+                        "Blossom cleanup: giving up on {} after {} attempts ({})",
+                        entry.hash,
+                        entry.delete_attempts,
+                        remaining.join(", ")
+                    );
+                    current.remove(pos);
+                } else {
+                    entry.servers = remaining;
+                }
+            }
+        }
+    }
+    if let Err(e) = save_uploaded_blobs(registry_path, &current) {
+        log::warn!("Blossom cleanup: could not update upload records: {e}");
+    }
+    summary
+}
+
+/// Background task: a cleanup pass at startup and then every ten minutes.
+pub fn spawn_blossom_cleanup_task(pool: SqlitePool) {
+    tokio::spawn(async move {
+        let Some(path) = blossom_uploads_path() else {
+            return;
+        };
+        let mut ticker = interval(BLOSSOM_CLEANUP_INTERVAL);
+        loop {
+            ticker.tick().await;
+            if !path.exists() {
+                continue;
+            }
+            let http = match http_client() {
+                Ok(http) => http,
+                Err(e) => {
+                    log::warn!("Blossom cleanup: no HTTP client: {e}");
+                    continue;
+                }
+            };
+            let summary = run_blossom_cleanup(&pool, &http, &path).await;
+            if summary.deleted > 0 || summary.failed > 0 {
+                log::info!(
+                    "Blossom cleanup: {} deleted, {} failed, {} waiting for trades to finish",
+                    summary.deleted,
+                    summary.failed,
+                    summary.waiting
+                );
+            }
+        }
+    });
+}
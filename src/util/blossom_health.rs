@@ -0,0 +1,185 @@
+//! Blossom server health: upload latency and failure counts, persisted in
+//! `~/.mostrix/blossom_health.json` and used to order upload attempts.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::util::blossom::normalize_blossom_server_base;
+
+const BLOSSOM_HEALTH_FILE_NAME: &str = "blossom_health.json";
+
+/// Latency assumed for servers we never uploaded to: known-good fast servers go first,
+/// untried ones still beat slow or failing ones.
+const UNKNOWN_LATENCY_MS: u64 = 5_000;
+
+/// Per-server counters. Latency is an exponential moving average of successful uploads.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServerHealth {
+    pub successes: u32,
+    pub failures: u32,
+    /// Failures since the last success; the main ranking key.
+    pub consecutive_failures: u32,
+    pub avg_latency_ms: Option<u64>,
+    /// Unix seconds of the last failure.
+    pub last_failure_at: Option<i64>,
+}
+
+/// Health table keyed by normalized server base URL.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BlossomHealth {
+    #[serde(default)]
+    pub servers: HashMap<String, ServerHealth>,
+    /// File the table was loaded from and is saved to (`None` = in memory only).
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+/// `~/.mostrix/blossom_health.json`, or `None` when the home directory is unknown.
+pub fn blossom_health_path() -> Option<PathBuf> {
+    Some(crate::settings::data_dir()?.join(BLOSSOM_HEALTH_FILE_NAME))
+}
+
+impl BlossomHealth {
+    /// Load `~/.mostrix/blossom_health.json`; a missing or unreadable file starts fresh.
+    pub fn load() -> Self {
+        match blossom_health_path() {
+            Some(path) => Self::load_from(&path),
+            None => Self::default(),
+        }
+    }
+
+    pub fn load_from(path: &Path) -> Self {
+        let mut health = match std::fs::read_to_string(path) {
+            Ok(raw) => serde_json::from_str::<Self>(&raw).unwrap_or_else(|e| {
+                log::warn!("Ignoring invalid {}: {e}", path.display());
+                Self::default()
+            }),
+            Err(_) => Self::default(),
+        };
+        health.path = Some(path.to_path_buf());
+        health
+    }
+
+    /// Write back to the file this table was loaded from (no-op for in-memory tables).
+    pub fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).map_err(|e| anyhow!("write {}: {e}", path.display()))
+    }
+
+    pub fn get(&self, server: &str) -> Option<&ServerHealth> {
+        self.servers.get(&normalize_blossom_server_base(server))
+    }
+
+    pub fn record_success(&mut self, server: &str, latency: Duration) {
+        let entry = self
+            .servers
+            .entry(normalize_blossom_server_base(server))
+            .or_default();
+        let ms = latency.as_millis().min(u64::MAX as u128) as u64;
+        entry.successes = entry.successes.saturating_add(1);
+        entry.consecutive_failures = 0;
+        entry.avg_latency_ms = Some(match entry.avg_latency_ms {
+            Some(avg) => (avg * 3 + ms) / 4,
+            None => ms,
+        });
+    }
+
+    pub fn record_failure(&mut self, server: &str) {
+        let entry = self
+            .servers
+            .entry(normalize_blossom_server_base(server))
+            .or_default();
+        entry.failures = entry.failures.saturating_add(1);
+        entry.consecutive_failures = entry.consecutive_failures.saturating_add(1);
+        entry.last_failure_at = Some(chrono::Utc::now().timestamp());
+    }
+
+    /// `servers` (normalized, duplicates dropped) ordered healthiest first: fewest consecutive
+    /// failures, then lowest latency. Ties keep the configured order.
+    pub fn rank(&self, servers: &[String]) -> Vec<String> {
+        let mut ranked: Vec<String> = Vec::with_capacity(servers.len());
+        for server in servers {
+            let base = normalize_blossom_server_base(server);
+            if !base.is_empty() && !ranked.contains(&base) {
+                ranked.push(base);
+            }
+        }
+        ranked.sort_by_key(|server| match self.servers.get(server) {
+            Some(h) => (
+                h.consecutive_failures,
+                h.avg_latency_ms.unwrap_or(UNKNOWN_LATENCY_MS),
+            ),
+            None => (0, UNKNOWN_LATENCY_MS),
+        });
+        ranked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn servers(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn rank_prefers_fast_working_servers_and_demotes_failing_ones() {
+        let mut health = BlossomHealth::default();
+        health.record_success("https://slow.example", Duration::from_millis(4_000));
+        health.record_success("https://fast.example/", Duration::from_millis(200));
+        health.record_failure("https://down.example");
+
+        let ranked = health.rank(&servers(&[
+            "https://down.example",
+            "https://new.example",
+            "https://slow.example",
+            "https://fast.example",
+        ]));
+        assert_eq!(
+            ranked,
+            servers(&[
+                "https://fast.example",
+                "https://slow.example",
+                "https://new.example",
+                "https://down.example",
+            ])
+        );
+    }
+
+    #[test]
+    fn success_resets_failure_streak_and_averages_latency() {
+        let mut health = BlossomHealth::default();
+        health.record_failure("https://a.example");
+        health.record_failure("https://a.example");
+        health.record_success("https://a.example", Duration::from_millis(1_000));
+        health.record_success("https://a.example", Duration::from_millis(200));
+        let h = health.get("https://a.example").unwrap();
+        assert_eq!((h.successes, h.failures, h.consecutive_failures), (2, 2, 0));
+        assert_eq!(h.avg_latency_ms, Some(800));
+        assert!(h.last_failure_at.is_some());
+    }
+
+    #[test]
+    fn health_persists_across_loads() {
+        let dir = std::env::temp_dir().join(format!("mostrix_health_{}", uuid::Uuid::new_v4()));
+        let path = dir.join(BLOSSOM_HEALTH_FILE_NAME);
+        let mut health = BlossomHealth::load_from(&path);
+        health.record_failure("https://x.example");
+        health.save().unwrap();
+
+        let reloaded = BlossomHealth::load_from(&path);
+        assert_eq!(reloaded.get("https://x.example").unwrap().failures, 1);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 use anyhow::Result;
+use futures::StreamExt;
 use mostro_core::chat::{
     chat_filter, giftwrap_chat_filter, unwrap_chat_message, unwrap_giftwrap_chat_message,
     wrap_chat_message, SharedKey,
@@ -13,7 +14,7 @@ use nostr_sdk::prelude::*;
 use crate::models::{AdminDispute, Order};
 use crate::ui::{
     AdminChatLastSeen, AdminChatUpdate, ChatParty, ChatSender, DecodedChatMessage,
-    DisputeChatMessage,
+    DisputeChatMessage, UserChatChannel,
 };
 use crate::util::dm_utils::FETCH_EVENTS_TIMEOUT;
 use crate::util::mostro_info::MostroInstanceInfo;
@@ -244,6 +245,25 @@ pub fn order_chat_decryption_key_bytes(order: &Order) -> Option<Vec<u8>> {
         .map(|k| k.secret_key().to_secret_bytes().to_vec())
 }
 
+/// Attachment key for either user chat channel: peer (see [`order_chat_decryption_key_bytes`])
+/// or the dispute chat with the solver (stored dispute key, else ECDH with the solver pubkey).
+pub fn user_chat_decryption_key_bytes(order: &Order, channel: UserChatChannel) -> Option<Vec<u8>> {
+    if channel == UserChatChannel::Peer {
+        return order_chat_decryption_key_bytes(order);
+    }
+    if let Some(keys) = order
+        .dispute_chat_shared_key_hex
+        .as_deref()
+        .and_then(keys_from_shared_hex)
+    {
+        return Some(keys.secret_key().to_secret_bytes().to_vec());
+    }
+    let trade_sk = SecretKey::from_str(order.trade_keys.as_deref()?).ok()?;
+    let solver_pk = PublicKey::parse(order.solver_pubkey.as_deref()?).ok()?;
+    derive_shared_keys(Some(&Keys::new(trade_sk)), Some(&solver_pk))
+        .map(|k| k.secret_key().to_secret_bytes().to_vec())
+}
+
 /// Resolve the order-chat counterparty pubkey and the ECDH shared-key hex.
 ///
 /// This is only possible once `SmallOrder` includes both `buyer_trade_pubkey` and
@@ -649,6 +669,71 @@ pub(crate) fn unwrap_observer_chat_event(
     })
 }
 
+/// Observer messages plus the inner signers that wrote them (used to link the chat to a dispute).
+#[derive(Debug, Clone, Default)]
+pub struct ObserverChat {
+    pub messages: Vec<DisputeChatMessage>,
+    pub participants: Vec<PublicKey>,
+}
+
+impl ObserverChat {
+    fn push(&mut self, signer: PublicKey, message: DisputeChatMessage) {
+        if !self.participants.contains(&signer) {
+            self.participants.push(signer);
+        }
+        self.messages.push(message);
+    }
+}
+
+/// Decode one Observer event, keeping only inner signers listed in `known_roles`.
+fn decode_observer_event(
+    conv: &Keys,
+    sign_pubkey: Option<&PublicKey>,
+    event: &Event,
+    known_roles: &HashMap<PublicKey, ChatSender>,
+) -> Option<(PublicKey, DisputeChatMessage)> {
+    use crate::ui::helpers::try_parse_attachment_message;
+
+    let allowed: Vec<PublicKey> = known_roles.keys().copied().collect();
+    let msg = match unwrap_observer_chat_event(conv, sign_pubkey, event, &allowed) {
+        Ok(msg) => msg,
+        Err(e) => {
+            log::debug!("observer: skipped event {}: {e}", event.id);
+            return None;
+        }
+    };
+    let Some(sender) = known_roles.get(&msg.sender).copied() else {
+        log::warn!("observer: dropping chat message from unknown inner signer");
+        return None;
+    };
+    let (content, attachment) = match try_parse_attachment_message(&msg.content) {
+        Some((att, display)) => (display, Some(att)),
+        None => (msg.content, None),
+    };
+    Some((
+        msg.sender,
+        DisputeChatMessage {
+            sender,
+            content,
+            timestamp: msg.timestamp,
+            target_party: None,
+            attachment,
+        },
+    ))
+}
+
+fn observer_conv_keys(
+    conv_key_hex: &str,
+    known_roles: &HashMap<PublicKey, ChatSender>,
+) -> Result<Keys> {
+    if known_roles.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Cannot verify observer chat signers without admin keys or taken-dispute party pubkeys"
+        ));
+    }
+    keys_from_shared_hex(conv_key_hex).ok_or_else(|| anyhow::anyhow!("Invalid K_conv hex"))
+}
+
 /// Fetch chat messages for the Observer tab using disclosed `K_conv` hex.
 ///
 /// Optional `sign_pubkey` (`pub(K_sign)`) uses an `authors` filter; without it
@@ -661,18 +746,8 @@ pub async fn fetch_observer_chat(
     conv_key_hex: &str,
     sign_pubkey: Option<PublicKey>,
     known_roles: &HashMap<PublicKey, ChatSender>,
-) -> Result<Vec<DisputeChatMessage>> {
-    use crate::ui::helpers::try_parse_attachment_message;
-
-    if known_roles.is_empty() {
-        return Err(anyhow::anyhow!(
-            "Cannot verify observer chat signers without admin keys or taken-dispute party pubkeys"
-        ));
-    }
-
-    let conv =
-        keys_from_shared_hex(conv_key_hex).ok_or_else(|| anyhow::anyhow!("Invalid K_conv hex"))?;
-    let allowed: Vec<PublicKey> = known_roles.keys().copied().collect();
+) -> Result<ObserverChat> {
+    let conv = observer_conv_keys(conv_key_hex, known_roles)?;
 
     let local_now = Timestamp::now().as_secs() as i64;
     let seven_days_secs: i64 = 7 * 24 * 60 * 60;
@@ -686,35 +761,93 @@ pub async fn fetch_observer_chat(
         .await
         .map_err(|e| anyhow::anyhow!("Failed to fetch observer chat events: {e}"))?;
 
-    let mut messages = Vec::new();
+    let mut chat = ObserverChat::default();
     for wrapped in events.iter() {
-        match unwrap_observer_chat_event(&conv, sign_pubkey.as_ref(), wrapped, &allowed) {
-            Ok(msg) => {
-                let Some(sender) = known_roles.get(&msg.sender).copied() else {
-                    log::warn!("observer: dropping chat message from unknown inner signer");
-                    continue;
-                };
+        if let Some((signer, msg)) =
+            decode_observer_event(&conv, sign_pubkey.as_ref(), wrapped, known_roles)
+        {
+            chat.push(signer, msg);
+        }
+    }
+    chat.messages.sort_by_key(|m| m.timestamp);
+    Ok(chat)
+}
 
-                let (msg_content, attachment) = match try_parse_attachment_message(&msg.content) {
-                    Some((att, display)) => (display, Some(att)),
-                    None => (msg.content, None),
+/// Live Observer subscription: same filter as [`fetch_observer_chat`] from `since` on, each
+/// decoded message handed to `emit` as it arrives.
+///
+/// Runs until `stop` fires or its sender is dropped, then unsubscribes. Messages at the
+/// overlap with the initial fetch may be emitted twice; the caller deduplicates.
+pub async fn stream_observer_chat(
+    client: &Client,
+    conv_key_hex: &str,
+    sign_pubkey: Option<PublicKey>,
+    known_roles: &HashMap<PublicKey, ChatSender>,
+    since: Timestamp,
+    mut stop: tokio::sync::oneshot::Receiver<()>,
+    mut emit: impl FnMut(ObserverChat),
+) -> Result<()> {
+    let conv = observer_conv_keys(conv_key_hex, known_roles)?;
+    let mut notifications = client.notifications();
+    let filter = observer_kind14_filter(conv.public_key(), sign_pubkey, since);
+    let subscription_id = client
+        .subscribe(filter)
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to subscribe to observer chat: {e}"))?
+        .value;
+
+    loop {
+        tokio::select! {
+            _ = &mut stop => break,
+            notification = notifications.next() => {
+                let Some(notification) = notification else {
+                    log::debug!("observer: notification stream ended");
+                    break;
                 };
-
-                messages.push(DisputeChatMessage {
-                    sender,
-                    content: msg_content,
-                    timestamp: msg.timestamp,
-                    target_party: None,
-                    attachment,
-                });
-            }
-            Err(e) => {
-                log::debug!("observer: skipped event {}: {e}", wrapped.id);
+                let ClientNotification::Event { subscription_id: sub, event, .. } = notification
+                else {
+                    continue;
+                };
+                if sub != subscription_id {
+                    continue;
+                }
+                if let Some((signer, msg)) =
+                    decode_observer_event(&conv, sign_pubkey.as_ref(), &event, known_roles)
+                {
+                    let mut chat = ObserverChat::default();
+                    chat.push(signer, msg);
+                    emit(chat);
+                }
             }
         }
     }
-    messages.sort_by_key(|m| m.timestamp);
-    Ok(messages)
+    if let Err(e) = client.unsubscribe(&subscription_id).await {
+        log::debug!("observer: unsubscribe failed: {e}");
+    }
+    Ok(())
+}
+
+/// Taken dispute whose buyer/seller trade keys wrote the observed chat.
+///
+/// Prefers the dispute matching the most participants, then an in-progress one, then the most
+/// recently taken.
+pub fn observer_linked_dispute<'a>(
+    disputes: &'a [AdminDispute],
+    participants: &[PublicKey],
+) -> Option<&'a AdminDispute> {
+    disputes
+        .iter()
+        .filter_map(|d| {
+            let matched = [d.buyer_pubkey.as_deref(), d.seller_pubkey.as_deref()]
+                .into_iter()
+                .flatten()
+                .filter_map(parse_chat_pubkey)
+                .filter(|pk| participants.contains(pk))
+                .count();
+            (matched > 0).then_some((matched, !d.is_finalized(), d.taken_at, d))
+        })
+        .max_by_key(|(matched, open, taken_at, _)| (*matched, *open, *taken_at))
+        .map(|(_, _, _, d)| d)
 }
 
 /// Send one user order chat message using shared-key wrapping.
@@ -735,6 +868,56 @@ pub async fn send_user_order_chat_message_via_shared_key(
     .await
 }
 
+/// Send a user chat message for `order_id` on the peer or solver channel.
+///
+/// Keys come from the local order row: the stored shared key when present, else ECDH with the
+/// counterparty (peer) or solver trade pubkey.
+pub async fn send_user_order_chat(
+    pool: &sqlx::SqlitePool,
+    client: &Client,
+    order_id: &str,
+    channel: UserChatChannel,
+    content: &str,
+    mostro_instance: Option<&MostroInstanceInfo>,
+) -> Result<()> {
+    let order = Order::get_by_id(pool, order_id).await?;
+    let trade_keys = order
+        .trade_keys
+        .as_deref()
+        .and_then(|h| SecretKey::from_str(h).ok())
+        .map(Keys::new)
+        .ok_or_else(|| anyhow::anyhow!("Missing trade keys for order {order_id}"))?;
+    let shared_keys = match channel {
+        UserChatChannel::Peer => order
+            .order_chat_shared_key_hex
+            .as_deref()
+            .and_then(keys_from_shared_hex)
+            .or_else(|| {
+                let cp = order.counterparty_pubkey.as_deref()?;
+                let pk = PublicKey::parse(cp).ok()?;
+                derive_shared_keys(Some(&trade_keys), Some(&pk))
+            }),
+        UserChatChannel::Solver => order
+            .dispute_chat_shared_key_hex
+            .as_deref()
+            .and_then(keys_from_shared_hex)
+            .or_else(|| {
+                let solver = order.solver_pubkey.as_deref()?;
+                let pk = PublicKey::parse(solver).ok()?;
+                derive_shared_keys(Some(&trade_keys), Some(&pk))
+            }),
+    }
+    .ok_or_else(|| anyhow::anyhow!("No {channel} chat key for order {order_id} yet"))?;
+    send_user_order_chat_message_via_shared_key(
+        client,
+        &trade_keys,
+        &shared_keys,
+        content,
+        mostro_instance,
+    )
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1031,6 +1214,33 @@ mod tests {
         assert!(!roles.contains_key(&Keys::generate().public_key()));
     }
 
+    #[test]
+    fn observer_linked_dispute_prefers_most_matched_open_dispute() {
+        let buyer = Keys::generate().public_key();
+        let seller = Keys::generate().public_key();
+        let dispute = |id: &str, status: &str, with_seller: bool, taken_at: i64| AdminDispute {
+            dispute_id: id.to_string(),
+            status: Some(status.to_string()),
+            buyer_pubkey: Some(buyer.to_hex()),
+            seller_pubkey: with_seller.then(|| seller.to_hex()),
+            taken_at,
+            ..Default::default()
+        };
+        let disputes = vec![
+            dispute("buyer-only", "in-progress", false, 30),
+            dispute("settled", "settled", true, 20),
+            dispute("open", "in-progress", true, 10),
+        ];
+
+        let linked = |participants: &[PublicKey]| {
+            observer_linked_dispute(&disputes, participants).map(|d| d.dispute_id.as_str())
+        };
+        assert_eq!(linked(&[buyer, seller]), Some("open"));
+        assert_eq!(linked(&[buyer]), Some("buyer-only"));
+        assert_eq!(linked(&[Keys::generate().public_key()]), None);
+        assert_eq!(linked(&[]), None);
+    }
+
     #[test]
     fn chat_keys_from_shared_hex_matches_derive() {
         let a = Keys::generate();
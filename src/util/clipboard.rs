@@ -0,0 +1,144 @@
+//! Clipboard backend selection and the OSC 52 fallback for SSH / headless tmux sessions.
+//!
+//! The local backend (`arboard`, in `ui/key_handler`) needs a display server. OSC 52 asks the
+//! terminal emulator to set its clipboard instead, so it works over SSH; inside tmux the
+//! sequence is wrapped in a DCS passthrough. The terminal gives no acknowledgement, so an
+//! OSC 52 copy is reported as sent, not verified. Reading the clipboard over OSC 52 is not
+//! supported: the terminal's own paste (bracketed paste) covers that.
+
+use std::io::Write;
+
+use anyhow::Result;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+
+/// Largest payload sent over OSC 52; many terminals drop longer sequences.
+const OSC52_MAX_ENCODED_LEN: usize = 100_000;
+
+/// `clipboard` setting: which backend copies use.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClipboardMode {
+    /// Local clipboard, falling back to OSC 52 when it is unavailable.
+    #[default]
+    Auto,
+    /// Local clipboard only.
+    Local,
+    /// Always OSC 52 (e.g. SSH with a forwarded but useless display).
+    Osc52,
+}
+
+impl ClipboardMode {
+    pub fn from_setting(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "auto" => Ok(Self::Auto),
+            "local" => Ok(Self::Local),
+            "osc52" => Ok(Self::Osc52),
+            other => {
+                anyhow::bail!("clipboard must be \"auto\", \"local\" or \"osc52\", got \"{other}\"")
+            }
+        }
+    }
+}
+
+/// Current `clipboard` setting from the applied settings (`auto` when invalid).
+pub fn clipboard_mode() -> ClipboardMode {
+    let value = crate::settings::applied_settings()
+        .map(|s| s.clipboard)
+        .unwrap_or_default();
+    ClipboardMode::from_setting(&value).unwrap_or_else(|e| {
+        log::warn!("{e}; using auto");
+        ClipboardMode::Auto
+    })
+}
+
+/// Backend that handled a copy, shown next to the "copied" confirmation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClipboardBackend {
+    /// `arboard` (X11 / Wayland / macOS / Windows clipboard).
+    Local,
+    /// OSC 52 escape sequence written to the terminal.
+    Osc52,
+    /// OSC 52 wrapped in a tmux DCS passthrough.
+    Osc52Tmux,
+}
+
+impl ClipboardBackend {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Osc52 => "OSC 52",
+            Self::Osc52Tmux => "OSC 52 via tmux",
+        }
+    }
+}
+
+/// OSC 52 "set clipboard" sequence for `text`; `tmux` wraps it for passthrough
+/// (every ESC doubled inside `ESC P tmux; … ESC \`).
+pub fn osc52_sequence(text: &str, tmux: bool) -> String {
+    let osc = format!("\x1b]52;c;{}\x07", BASE64.encode(text));
+    if tmux {
+        format!("\x1bPtmux;{}\x1b\\", osc.replace('\x1b', "\x1b\x1b"))
+    } else {
+        osc
+    }
+}
+
+/// Whether Mostrix runs inside tmux (sequences need passthrough).
+fn inside_tmux() -> bool {
+    std::env::var_os("TMUX").is_some_and(|v| !v.is_empty())
+}
+
+/// Ask the terminal to copy `text` with OSC 52.
+///
+/// Inside tmux both forms are sent: tmux handles the plain sequence itself with
+/// `set -g set-clipboard on`, and forwards the passthrough with `set -g allow-passthrough on`
+/// (tmux 3.3+).
+pub fn copy_osc52(text: &str) -> Result<ClipboardBackend> {
+    let tmux = inside_tmux();
+    let mut sequence = osc52_sequence(text, false);
+    if sequence.len() > OSC52_MAX_ENCODED_LEN {
+        anyhow::bail!("text too long for OSC 52 ({} bytes)", text.len());
+    }
+    if tmux {
+        sequence.push_str(&osc52_sequence(text, true));
+    }
+    let mut out = std::io::stdout().lock();
+    out.write_all(sequence.as_bytes())?;
+    out.flush()?;
+    Ok(if tmux {
+        ClipboardBackend::Osc52Tmux
+    } else {
+        ClipboardBackend::Osc52
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn osc52_sequence_encodes_and_wraps_for_tmux() {
+        assert_eq!(osc52_sequence("lnbc1", false), "\x1b]52;c;bG5iYzE=\x07");
+        assert_eq!(
+            osc52_sequence("lnbc1", true),
+            "\x1bPtmux;\x1b\x1b]52;c;bG5iYzE=\x07\x1b\\"
+        );
+    }
+
+    #[test]
+    fn clipboard_setting_parses_known_modes() {
+        assert_eq!(
+            ClipboardMode::from_setting("").unwrap(),
+            ClipboardMode::Auto
+        );
+        assert_eq!(
+            ClipboardMode::from_setting("OSC52").unwrap(),
+            ClipboardMode::Osc52
+        );
+        assert_eq!(
+            ClipboardMode::from_setting("local").unwrap(),
+            ClipboardMode::Local
+        );
+        assert!(ClipboardMode::from_setting("xclip").is_err());
+    }
+}
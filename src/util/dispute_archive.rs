@@ -0,0 +1,275 @@
+//! Dispute archive: search over finalized `admin_disputes` rows and per-party history.
+//!
+//! The query is typed into the admin "Dispute Archive" tab. Plain words match (as substrings)
+//! the order id, dispute id and buyer/seller/initiator pubkeys; an `npub1…` is converted to hex
+//! first. Keyed filters narrow the result further:
+//!
+//! - `fiat:USD` — exact fiat code (case-insensitive)
+//! - `pm:revolut` — payment method substring
+//! - `from:2026-01-01` / `to:2026-03-31` — inclusive range on the dispute creation day (local time)
+
+use anyhow::Result;
+use chrono::{Local, NaiveDate, TimeZone};
+use mostro_core::prelude::UserInfo;
+use nostr_sdk::prelude::*;
+
+use crate::models::AdminDispute;
+use crate::util::order_utils::BondSlashChoice;
+
+/// Parsed archive search; the default (empty) query matches every finalized dispute.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArchiveQuery {
+    /// Lowercase id / pubkey fragments; all must match.
+    pub terms: Vec<String>,
+    pub fiat_code: Option<String>,
+    pub payment_method: Option<String>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+impl ArchiveQuery {
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut query = Self::default();
+        for token in input.split_whitespace() {
+            let (key, value) = token.split_once(':').unwrap_or(("", token));
+            match key.to_ascii_lowercase().as_str() {
+                "fiat" => query.fiat_code = Some(value.to_ascii_uppercase()),
+                "pm" => query.payment_method = Some(value.to_lowercase()),
+                "from" => query.from = Some(parse_day(value)?),
+                "to" => query.to = Some(parse_day(value)?),
+                _ => query.terms.push(normalize_term(token)),
+            }
+        }
+        if let (Some(from), Some(to)) = (query.from, query.to) {
+            if from > to {
+                anyhow::bail!("from:{from} is after to:{to}");
+            }
+        }
+        Ok(query)
+    }
+
+    /// `true` when `dispute` is finalized and passes every term and filter.
+    pub fn matches(&self, dispute: &AdminDispute) -> bool {
+        if !dispute.is_finalized() {
+            return false;
+        }
+        if let Some(fiat) = &self.fiat_code {
+            if !dispute.fiat_code.eq_ignore_ascii_case(fiat) {
+                return false;
+            }
+        }
+        if let Some(pm) = &self.payment_method {
+            if !dispute.payment_method.to_lowercase().contains(pm) {
+                return false;
+            }
+        }
+        if self.from.is_some() || self.to.is_some() {
+            let Some(day) = Local
+                .timestamp_opt(dispute.created_at, 0)
+                .single()
+                .map(|dt| dt.date_naive())
+            else {
+                return false;
+            };
+            if self.from.is_some_and(|from| day < from) || self.to.is_some_and(|to| day > to) {
+                return false;
+            }
+        }
+        self.terms.iter().all(|term| {
+            [
+                Some(dispute.id.as_str()),
+                Some(dispute.dispute_id.as_str()),
+                Some(dispute.initiator_pubkey.as_str()),
+                dispute.buyer_pubkey.as_deref(),
+                dispute.seller_pubkey.as_deref(),
+            ]
+            .into_iter()
+            .flatten()
+            .any(|field| field.to_lowercase().contains(term.as_str()))
+        })
+    }
+
+    /// Matching finalized disputes, most recently taken first.
+    pub fn search<'a>(&self, disputes: &'a [AdminDispute]) -> Vec<&'a AdminDispute> {
+        let mut results: Vec<&AdminDispute> = disputes.iter().filter(|d| self.matches(d)).collect();
+        results.sort_by_key(|d| std::cmp::Reverse(d.taken_at));
+        results
+    }
+}
+
+fn parse_day(value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Invalid date '{value}', use YYYY-MM-DD"))
+}
+
+/// Lowercase hex for `npub1…` terms so they match the stored keys; other terms are lowercased.
+fn normalize_term(token: &str) -> String {
+    if token.starts_with("npub1") {
+        if let Ok(pk) = PublicKey::from_bech32(token) {
+            return pk.to_hex();
+        }
+    }
+    token.to_lowercase()
+}
+
+/// Bond slash choice recorded when this client finalized the dispute, if any.
+pub fn bond_choice(dispute: &AdminDispute) -> Option<BondSlashChoice> {
+    dispute
+        .bond_resolution
+        .as_deref()
+        .and_then(BondSlashChoice::from_stored)
+}
+
+/// Side a party took in a past dispute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartyRole {
+    Buyer,
+    Seller,
+}
+
+/// One past dispute involving a given party pubkey.
+#[derive(Debug, Clone)]
+pub struct PartyDispute<'a> {
+    pub dispute: &'a AdminDispute,
+    pub role: PartyRole,
+    /// The party opened this dispute.
+    pub initiated: bool,
+    /// The party's reputation as reported by Mostro when the dispute was taken.
+    pub rating: Option<&'a UserInfo>,
+}
+
+/// Every dispute this solver handled with `pubkey` as buyer or seller (finalized or not),
+/// most recently taken first.
+pub fn party_history<'a>(disputes: &'a [AdminDispute], pubkey: &str) -> Vec<PartyDispute<'a>> {
+    let mut history: Vec<PartyDispute> = disputes
+        .iter()
+        .filter_map(|dispute| {
+            let role = if dispute.buyer_pubkey.as_deref() == Some(pubkey) {
+                PartyRole::Buyer
+            } else if dispute.seller_pubkey.as_deref() == Some(pubkey) {
+                PartyRole::Seller
+            } else {
+                return None;
+            };
+            let initiated = dispute.initiator_pubkey == pubkey;
+            let rating = if initiated {
+                dispute.initiator_info_data.as_ref()
+            } else {
+                dispute.counterpart_info_data.as_ref()
+            };
+            Some(PartyDispute {
+                dispute,
+                role,
+                initiated,
+                rating,
+            })
+        })
+        .collect();
+    history.sort_by_key(|p| std::cmp::Reverse(p.dispute.taken_at));
+    history
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUYER: &str = "aa11";
+    const SELLER: &str = "bb22";
+
+    fn dispute(id: &str, status: &str, taken_at: i64) -> AdminDispute {
+        AdminDispute {
+            id: id.to_string(),
+            dispute_id: format!("d-{id}"),
+            status: Some(status.to_string()),
+            initiator_pubkey: BUYER.to_string(),
+            buyer_pubkey: Some(BUYER.to_string()),
+            seller_pubkey: Some(SELLER.to_string()),
+            initiator_info_data: Some(UserInfo {
+                rating: 4.5,
+                reviews: 10,
+                operating_days: 30,
+            }),
+            fiat_code: "USD".to_string(),
+            payment_method: "Revolut,SEPA".to_string(),
+            created_at: local_ts(2026, 3, 10),
+            taken_at,
+            ..Default::default()
+        }
+    }
+
+    fn local_ts(y: i32, m: u32, d: u32) -> i64 {
+        Local
+            .with_ymd_and_hms(y, m, d, 12, 0, 0)
+            .single()
+            .unwrap()
+            .timestamp()
+    }
+
+    #[test]
+    fn query_filters_finalized_disputes() {
+        let mut eur = dispute("order-2", "seller-refunded", 20);
+        eur.fiat_code = "EUR".to_string();
+        eur.created_at = local_ts(2026, 5, 1);
+        let disputes = vec![
+            dispute("order-1", "settled", 10),
+            eur,
+            dispute("order-3", "in-progress", 30),
+        ];
+
+        let ids = |q: &str| -> Vec<String> {
+            ArchiveQuery::parse(q)
+                .unwrap()
+                .search(&disputes)
+                .iter()
+                .map(|d| d.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(""), vec!["order-2", "order-1"]);
+        assert_eq!(ids("fiat:eur"), vec!["order-2"]);
+        assert_eq!(ids("ORDER-1"), vec!["order-1"]);
+        assert_eq!(ids("d-order-2 bb2"), vec!["order-2"]);
+        assert_eq!(ids("pm:sepa to:2026-04-01"), vec!["order-1"]);
+        assert_eq!(ids("from:2026-05-01 to:2026-05-01"), vec!["order-2"]);
+        assert!(ids("pm:zelle").is_empty());
+
+        assert!(ArchiveQuery::parse("from:2026-13-01").is_err());
+        assert!(ArchiveQuery::parse("from:2026-05-02 to:2026-05-01").is_err());
+    }
+
+    #[test]
+    fn npub_terms_match_hex_pubkeys() {
+        let keys = Keys::generate();
+        let mut d = dispute("order-1", "settled", 10);
+        d.seller_pubkey = Some(keys.public_key().to_hex());
+        let query = ArchiveQuery::parse(&keys.public_key().to_bech32().unwrap()).unwrap();
+        assert!(query.matches(&d));
+    }
+
+    #[test]
+    fn party_history_reports_role_rating_and_bond() {
+        let mut settled = dispute("order-1", "settled", 10);
+        settled.bond_resolution = Some(BondSlashChoice::SlashSeller.as_str().to_string());
+        let mut other = dispute("order-2", "settled", 20);
+        other.buyer_pubkey = Some("cc33".to_string());
+        other.initiator_pubkey = "cc33".to_string();
+        let disputes = vec![settled, other, dispute("order-3", "in-progress", 30)];
+
+        let buyer = party_history(&disputes, BUYER);
+        assert_eq!(buyer.len(), 2);
+        assert_eq!(buyer[0].dispute.id, "order-3");
+        assert!(buyer[1].initiated);
+        assert_eq!(buyer[1].role, PartyRole::Buyer);
+        assert_eq!(buyer[1].rating.map(|r| r.reviews), Some(10));
+        assert_eq!(
+            bond_choice(buyer[1].dispute),
+            Some(BondSlashChoice::SlashSeller)
+        );
+
+        let seller = party_history(&disputes, SELLER);
+        assert_eq!(seller.len(), 3);
+        assert!(seller
+            .iter()
+            .all(|p| p.role == PartyRole::Seller && !p.initiated && p.rating.is_none()));
+        assert_eq!(bond_choice(seller[0].dispute), None);
+    }
+}
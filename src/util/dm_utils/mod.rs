@@ -11,7 +11,10 @@ pub use notifications_ch_mng::{
     apply_saved_ln_address_invoice_choice, handle_message_notification, present_add_invoice_popup,
 };
 pub use order_ch_mng::handle_operation_result;
-pub use order_result_tx::{set_order_result_tx, try_notify_my_trades_maker_book_changed};
+pub use order_result_tx::{
+    set_order_result_tx, try_notify_my_trades_maker_book_changed, try_notify_saved_search_match,
+    try_notify_settings_file_changed, try_notify_settings_file_invalid,
+};
 
 use anyhow::Result;
 use mostro_core::prelude::*;
@@ -41,8 +44,8 @@ use crate::util::mostro_info::{
     nostr_pow_for_protocol_dm, transport_from_instance, MostroInstanceInfo,
 };
 use crate::util::order_utils::{
-    inferred_status_from_trade_action, map_action_to_status, should_apply_status_transition,
-    should_strictly_advance_status,
+    inferred_status_from_trade_action, map_action_to_status, payment_failure_reopens_trade,
+    should_apply_status_transition, should_strictly_advance_status,
 };
 use futures::StreamExt;
 use std::collections::BTreeSet;
@@ -207,7 +210,10 @@ fn trade_message_should_untrack_order_chat(message: &Message) -> bool {
     let kind = message.get_inner_message_kind();
     if matches!(
         &kind.action,
-        Action::AdminCanceled | Action::Canceled | Action::CooperativeCancelAccepted
+        Action::AdminCanceled
+            | Action::Canceled
+            | Action::CooperativeCancelAccepted
+            | Action::HoldInvoicePaymentCanceled
     ) {
         return true;
     }
@@ -270,7 +276,10 @@ fn trade_message_is_terminal(message: &Message) -> bool {
     let kind = message.get_inner_message_kind();
     if matches!(
         &kind.action,
-        Action::AdminCanceled | Action::Canceled | Action::CooperativeCancelAccepted
+        Action::AdminCanceled
+            | Action::Canceled
+            | Action::CooperativeCancelAccepted
+            | Action::HoldInvoicePaymentCanceled
     ) {
         return true;
     }
@@ -1015,9 +1024,16 @@ async fn handle_trade_dm_for_order(
     );
 
     let baseline_status = status_from_db.or(prior_order_status);
+    // A payout failure may follow a locally inferred `Success` (buyer `released`), but a stale
+    // replay must not undo a newer `purchase-completed`.
+    let payout_retry_reopens = existing_message_data.as_ref().is_none_or(|prior| {
+        timestamp >= prior.timestamp && !matches!(prior.action, Action::PurchaseCompleted)
+    });
     let should_accept_candidate = status_candidate
         .map(|candidate| {
             should_apply_status_transition(baseline_status, candidate, effective_order_kind)
+                || (payout_retry_reopens
+                    && payment_failure_reopens_trade(baseline_status, candidate, &action))
         })
         .unwrap_or(false);
     let effective_order_status = if should_accept_candidate {
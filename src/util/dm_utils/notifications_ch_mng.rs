@@ -85,7 +85,7 @@ fn invoice_state_for_add_invoice(invoice_input: String, focused: bool) -> Invoic
         invoice_input,
         focused,
         just_pasted: false,
-        copied_to_clipboard: false,
+        copied_to_clipboard: None,
         scroll_y: 0,
         action_selection: InvoiceNotificationActionSelection::Primary,
     }
@@ -190,7 +190,7 @@ fn invoice_popup_mode(
                 invoice_input: String::new(),
                 focused: false,
                 just_pasted: false,
-                copied_to_clipboard: false,
+                copied_to_clipboard: None,
                 scroll_y: 0,
                 action_selection: InvoiceNotificationActionSelection::Primary,
             };
@@ -280,19 +280,25 @@ pub fn handle_message_notification(notification: MessageNotification, app: &mut
         }
     }
 
-    // Only show popup automatically for PayInvoice / PayBondInvoice / AddInvoice,
+    // Only show popup automatically for PayInvoice / PayBondInvoice / AddInvoice / PaymentFailed,
     // and only if we haven't already shown it for this message.
     match notification.action {
         Action::PayInvoice
         | Action::PayBondInvoice
         | Action::AddInvoice
-        | Action::AddBondInvoice => {
+        | Action::AddBondInvoice
+        | Action::PaymentFailed => {
             let should_show_popup = check_if_popup_should_be_shown(&notification, app);
             if !should_show_popup {
                 return;
             }
 
-            if matches!(notification.action, Action::AddInvoice) {
+            // PaymentFailed keeps its action on the notification (retry schedule body, "Wait"
+            // button) but uses the AddInvoice popup, so a saved Lightning address is re-resolved.
+            if matches!(
+                notification.action,
+                Action::AddInvoice | Action::PaymentFailed
+            ) {
                 app.mode =
                     present_add_invoice_popup(&mut app.buyer_invoice_preference, notification);
             } else if matches!(notification.action, Action::AddBondInvoice) {
@@ -310,7 +316,7 @@ pub fn handle_message_notification(notification: MessageNotification, app: &mut
                     invoice_input: String::new(),
                     focused: false,
                     just_pasted: false,
-                    copied_to_clipboard: false,
+                    copied_to_clipboard: None,
                     scroll_y: 0,
                     action_selection: InvoiceNotificationActionSelection::Primary,
                 };
@@ -6,7 +6,7 @@ use crate::ui::orders::{
 };
 use crate::ui::{
     AppState, InvoiceInputState, InvoiceNotificationActionSelection, MessageNotification,
-    OperationResult, UiMode, UserMode,
+    OperationResult, UiMode, UserMode, UserRole,
 };
 use mostro_core::prelude::Action;
 use uuid::Uuid;
@@ -270,7 +270,7 @@ pub fn handle_operation_result(mut result: OperationResult, app: &mut AppState)
             invoice_input: String::new(),
             focused: false,
             just_pasted: false,
-            copied_to_clipboard: false,
+            copied_to_clipboard: None,
             scroll_y: 0,
             action_selection: InvoiceNotificationActionSelection::Primary,
         };
@@ -309,18 +309,71 @@ pub fn handle_operation_result(mut result: OperationResult, app: &mut AppState)
         }
     }
 
-    // Handle observer chat results directly (don't show popup)
+    // Handle observer chat, image preview and retention results directly (don't show popup)
     match result {
-        OperationResult::ObserverChatLoaded {
-            generation,
-            messages,
+        OperationResult::OrderChatAttachmentReady(pending) => {
+            // Dropped if the send was cancelled meanwhile.
+            if app.sending_attachment_order_id.as_deref() == Some(pending.order_id.as_str()) {
+                app.mode = UiMode::UserAttachmentSendConfirm(pending);
+            }
+            return;
+        }
+        OperationResult::ImagePreviewLoaded(image) => {
+            crate::ui::image_preview_popup::apply_image_preview_result(app, Ok(image));
+            return;
+        }
+        OperationResult::RetentionPurged { deleted_order_ids } => {
+            remove_many_orders_from_messages_tab(app, &deleted_order_ids);
+            return;
+        }
+        OperationResult::RetentionPreview(plan) => {
+            app.mode = if plan.is_empty() {
+                UiMode::operation_result(OperationResult::Info(
+                    crate::ui::retention_preview::empty_plan_message(&plan.policy),
+                ))
+            } else {
+                UiMode::RetentionPreview(plan, false)
+            };
+            return;
+        }
+        OperationResult::SavedSearchMatched(found) => {
+            if app.user_role == UserRole::User {
+                app.saved_search_alerts.push_back(*found);
+            }
+            return;
+        }
+        OperationResult::SettingsFileChanged(change) => {
+            app.pending_settings_change = Some(*change);
+            return;
+        }
+        OperationResult::RestoreNeedsConfirmation(staged) => {
+            app.mode = UiMode::ConfirmRestoreBackup(staged, false);
+            return;
+        }
+        OperationResult::ImagePreviewError {
+            blossom_url,
+            message,
         } => {
+            crate::ui::image_preview_popup::apply_image_preview_result(
+                app,
+                Err((blossom_url, message)),
+            );
+            return;
+        }
+        OperationResult::ObserverChatLoaded { generation, chat } => {
             if generation != app.observer_fetch_generation {
                 return;
             }
             app.observer_loading = false;
             app.observer_error = None;
-            app.observer_messages = messages;
+            app.observer_messages.clear();
+            app.append_observer_chat(chat);
+            return;
+        }
+        OperationResult::ObserverChatAppended { generation, chat } => {
+            if generation == app.observer_fetch_generation {
+                app.append_observer_chat(chat);
+            }
             return;
         }
         OperationResult::ObserverChatError {
@@ -518,7 +571,8 @@ mod tests {
                 id: Some(order_id),
                 kind: Some(mostro_core::order::Kind::Sell),
                 ..Default::default()
-            },
+            }
+            .into(),
             amount_input: "100".to_string(),
             is_range_order: true,
             validation_error: None,
@@ -579,6 +633,7 @@ mod tests {
     #[test]
     fn stale_observer_chat_loaded_does_not_replace_newer_or_cleared_state() {
         use crate::ui::chat::{ChatSender, DisputeChatMessage};
+        use crate::util::chat_utils::ObserverChat;
 
         let dummy = |content: &str| DisputeChatMessage {
             sender: ChatSender::Buyer,
@@ -594,7 +649,10 @@ mod tests {
         handle_operation_result(
             OperationResult::ObserverChatLoaded {
                 generation: gen_a,
-                messages: vec![dummy("from-a")],
+                chat: ObserverChat {
+                    messages: vec![dummy("from-a")],
+                    participants: Vec::new(),
+                },
             },
             &mut app,
         );
@@ -607,7 +665,10 @@ mod tests {
         handle_operation_result(
             OperationResult::ObserverChatLoaded {
                 generation: gen_a,
-                messages: vec![dummy("from-a")],
+                chat: ObserverChat {
+                    messages: vec![dummy("from-a")],
+                    participants: Vec::new(),
+                },
             },
             &mut app,
         );
@@ -620,7 +681,10 @@ mod tests {
         handle_operation_result(
             OperationResult::ObserverChatLoaded {
                 generation: gen_b,
-                messages: vec![dummy("from-b")],
+                chat: ObserverChat {
+                    messages: vec![dummy("from-b")],
+                    participants: Vec::new(),
+                },
             },
             &mut app,
         );
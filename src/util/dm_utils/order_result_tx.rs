@@ -3,6 +3,8 @@ use std::sync::Mutex;
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::ui::OperationResult;
+use crate::util::saved_searches::SavedSearchMatch;
+use crate::util::settings_reload::SettingsFileChange;
 
 static ORDER_RESULT_TX: Mutex<Option<UnboundedSender<OperationResult>>> = Mutex::new(None);
 
@@ -26,3 +28,35 @@ pub fn try_notify_my_trades_maker_book_changed() {
         let _ = tx.send(OperationResult::MyTradesMakerBookChanged);
     }
 }
+
+/// Report a live book order that matched a saved search (alert popup in the UI thread).
+pub fn try_notify_saved_search_match(found: SavedSearchMatch) {
+    let Ok(guard) = ORDER_RESULT_TX.lock() else {
+        return;
+    };
+    if let Some(tx) = guard.as_ref() {
+        let _ = tx.send(OperationResult::SavedSearchMatched(Box::new(found)));
+    }
+}
+
+/// Report an external edit of `settings.toml` (reload confirmation in the UI thread).
+pub fn try_notify_settings_file_changed(change: SettingsFileChange) {
+    let Ok(guard) = ORDER_RESULT_TX.lock() else {
+        return;
+    };
+    if let Some(tx) = guard.as_ref() {
+        let _ = tx.send(OperationResult::SettingsFileChanged(Box::new(change)));
+    }
+}
+
+/// Report an external edit of `settings.toml` that failed validation and was not applied.
+pub fn try_notify_settings_file_invalid(error: String) {
+    let Ok(guard) = ORDER_RESULT_TX.lock() else {
+        return;
+    };
+    if let Some(tx) = guard.as_ref() {
+        let _ = tx.send(OperationResult::Error(format!(
+            "settings.toml changed on disk but was not applied: {error}"
+        )));
+    }
+}
@@ -0,0 +1,371 @@
+//! Decimal fiat amounts.
+//!
+//! Order events may carry decimal `fa` tags ("12.50" EUR, "3.250" KWD), but `SmallOrder`
+//! keeps fiat amounts as whole `i64` units. [`FiatAmount`] holds the exact value in minor
+//! units. `book_order_from_tags` fills the `SmallOrder` fields with whole units (amount and
+//! max rounded down, min rounded up, so they describe what can still be traded) and keeps the
+//! exact values next to them in a [`BookOrder`].
+//!
+//! Mostro messages (`NewOrder`, the `Amount` payload of a range take) carry whole units, so
+//! a fractional amount typed into the order form or the take popup is flagged and not sent.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Deref;
+
+use mostro_core::prelude::SmallOrder;
+
+use crate::ui::currencies::minor_units;
+
+/// Most fractional digits accepted from a `fa` tag (keeps `minor` far from overflow).
+const MAX_TAG_DECIMALS: u8 = 8;
+
+/// A non-negative fiat amount: `minor / 10^decimals`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FiatAmount {
+    minor: i64,
+    decimals: u8,
+}
+
+impl FiatAmount {
+    pub fn from_whole(units: i64) -> Self {
+        Self {
+            minor: units,
+            decimals: 0,
+        }
+    }
+
+    /// Parse user input for `code`: digits with an optional `.` and at most the currency's
+    /// minor units (2 for EUR, 0 for JPY, 3 for KWD).
+    pub fn parse_for(raw: &str, code: &str) -> Result<Self, String> {
+        let max_decimals = minor_units(code);
+        let code = code.trim().to_ascii_uppercase();
+        match parse_decimal(raw, max_decimals) {
+            Some(amount) => Ok(amount),
+            None if parse_decimal(raw, MAX_TAG_DECIMALS).is_none() => {
+                Err("Invalid number format".to_string())
+            }
+            None if max_decimals == 0 => Err(format!("{code} amounts have no decimals")),
+            None => Err(format!(
+                "{code} amounts have at most {max_decimals} decimals"
+            )),
+        }
+    }
+
+    /// Parse a `fa` tag value for `code`, padded to the currency's minor units
+    /// ("12.5" EUR shows as `12.50`). Extra published precision is kept.
+    pub fn parse_tag(raw: &str, code: &str) -> Option<Self> {
+        let amount = parse_decimal(raw, MAX_TAG_DECIMALS)?;
+        let decimals = minor_units(code).max(amount.decimals);
+        Some(Self {
+            minor: amount
+                .minor
+                .checked_mul(pow10(decimals - amount.decimals))?,
+            decimals,
+        })
+    }
+
+    /// The amount in whole units, or `None` when it has a fractional part.
+    pub fn whole_units(&self) -> Option<i64> {
+        let scale = pow10(self.decimals);
+        (self.minor % scale == 0).then_some(self.minor / scale)
+    }
+
+    pub fn floor_units(&self) -> i64 {
+        self.minor / pow10(self.decimals)
+    }
+
+    pub fn ceil_units(&self) -> i64 {
+        let scale = pow10(self.decimals);
+        self.minor / scale + i64::from(self.minor % scale != 0)
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.minor > 0
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.minor as f64 / pow10(self.decimals) as f64
+    }
+
+    /// `Display` with thousands separators on the whole part (`1,234.50`).
+    pub fn grouped(&self) -> String {
+        group_thousands(&self.to_string())
+    }
+}
+
+impl PartialEq for FiatAmount {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for FiatAmount {}
+
+impl PartialOrd for FiatAmount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FiatAmount {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = i128::from(self.minor) * i128::from(pow10(other.decimals));
+        let rhs = i128::from(other.minor) * i128::from(pow10(self.decimals));
+        lhs.cmp(&rhs)
+    }
+}
+
+/// Whole amounts print without decimals (`100`); others with all their digits (`12.50`).
+impl fmt::Display for FiatAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.whole_units() {
+            Some(units) => write!(f, "{units}"),
+            None => {
+                let scale = pow10(self.decimals);
+                let width = usize::from(self.decimals);
+                write!(
+                    f,
+                    "{}.{:0width$}",
+                    self.minor / scale,
+                    self.minor % scale,
+                    width = width
+                )
+            }
+        }
+    }
+}
+
+/// Fixed amount and range bounds of one order.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OrderFiat {
+    pub amount: FiatAmount,
+    pub min: Option<FiatAmount>,
+    pub max: Option<FiatAmount>,
+}
+
+impl OrderFiat {
+    /// `fa` tag values for `code`: one value is a fixed amount, two are a min–max range.
+    /// Unparseable values become `0` / no bound, as before decimals were supported.
+    pub fn from_tag(values: &[String], code: &str) -> Self {
+        let parse = |raw: &String| FiatAmount::parse_tag(raw, code);
+        match values {
+            [min, max, ..] => Self {
+                amount: FiatAmount::from_whole(0),
+                min: parse(min),
+                max: parse(max),
+            },
+            [amount] => Self {
+                amount: parse(amount).unwrap_or(FiatAmount::from_whole(0)),
+                min: None,
+                max: None,
+            },
+            [] => Self::from_whole(0, None, None),
+        }
+    }
+
+    fn from_whole(amount: i64, min: Option<i64>, max: Option<i64>) -> Self {
+        Self {
+            amount: FiatAmount::from_whole(amount),
+            min: min.map(FiatAmount::from_whole),
+            max: max.map(FiatAmount::from_whole),
+        }
+    }
+
+    /// The whole-unit amounts of `order` (a DM snapshot or a trade row).
+    pub fn whole(order: &SmallOrder) -> Self {
+        Self::from_whole(order.fiat_amount, order.min_amount, order.max_amount)
+    }
+
+    /// Whole-unit `(fiat_amount, min_amount, max_amount)` for `SmallOrder`.
+    pub fn whole_fields(&self) -> (i64, Option<i64>, Option<i64>) {
+        (
+            self.amount.floor_units(),
+            self.min.as_ref().map(FiatAmount::ceil_units),
+            self.max.as_ref().map(FiatAmount::floor_units),
+        )
+    }
+}
+
+/// A book listing: the `SmallOrder` with whole-unit fields plus the exact `fa` amounts.
+#[derive(Clone, Debug, Default)]
+pub struct BookOrder {
+    pub order: SmallOrder,
+    pub fiat: OrderFiat,
+}
+
+impl BookOrder {
+    /// A listing whose amounts are the order's whole-unit fields.
+    pub fn whole(order: SmallOrder) -> Self {
+        Self {
+            fiat: OrderFiat::whole(&order),
+            order,
+        }
+    }
+}
+
+impl From<SmallOrder> for BookOrder {
+    fn from(order: SmallOrder) -> Self {
+        Self::whole(order)
+    }
+}
+
+impl Deref for BookOrder {
+    type Target = SmallOrder;
+
+    fn deref(&self) -> &SmallOrder {
+        &self.order
+    }
+}
+
+/// Group the whole part of a number string into thousands (`142857.5` → `142,857.5`).
+/// Anything that is not a plain number is returned unchanged.
+pub fn group_thousands(raw: &str) -> String {
+    let trimmed = raw.trim();
+    let (sign, number) = match trimmed.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", trimmed),
+    };
+    let (digits, fraction) = match number.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (number, None),
+    };
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if digits.is_empty() || !is_digits(digits) || !fraction.is_none_or(is_digits) {
+        return raw.to_string();
+    }
+    let mut out = String::from(sign);
+    let n = digits.len();
+    for (i, ch) in digits.chars().enumerate() {
+        if i > 0 && (n - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(ch);
+    }
+    if let Some(fraction) = fraction {
+        out.push('.');
+        out.push_str(fraction);
+    }
+    out
+}
+
+fn parse_decimal(raw: &str, max_decimals: u8) -> Option<FiatAmount> {
+    let raw = raw.trim();
+    let (whole, fraction) = match raw.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (raw, ""),
+    };
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty())
+        || !is_digits(whole)
+        || !is_digits(fraction)
+        || fraction.len() > usize::from(max_decimals)
+    {
+        return None;
+    }
+    let decimals = fraction.len() as u8;
+    let digits = format!("{whole}{fraction}");
+    Some(FiatAmount {
+        minor: digits.parse().ok()?,
+        decimals,
+    })
+}
+
+fn pow10(decimals: u8) -> i64 {
+    10_i64.pow(u32::from(decimals))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_for_respects_currency_minor_units() {
+        assert_eq!(
+            FiatAmount::parse_for("12.50", "eur").unwrap().to_string(),
+            "12.50"
+        );
+        assert_eq!(
+            FiatAmount::parse_for("3.125", "KWD").unwrap().to_string(),
+            "3.125"
+        );
+        assert_eq!(
+            FiatAmount::parse_for(" 100 ", "JPY").unwrap().to_string(),
+            "100"
+        );
+        assert_eq!(
+            FiatAmount::parse_for(".5", "USD").unwrap().to_string(),
+            "0.5"
+        );
+        assert_eq!(
+            FiatAmount::parse_for("12.505", "EUR").unwrap_err(),
+            "EUR amounts have at most 2 decimals"
+        );
+        assert_eq!(
+            FiatAmount::parse_for("100.5", "jpy").unwrap_err(),
+            "JPY amounts have no decimals"
+        );
+        for bad in ["", ".", "1.2.3", "-5", "1e3", "12,50"] {
+            assert_eq!(
+                FiatAmount::parse_for(bad, "EUR").unwrap_err(),
+                "Invalid number format",
+                "{bad:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn amounts_compare_across_precisions_and_round_to_whole_units() {
+        let a = FiatAmount::parse_for("12.5", "EUR").unwrap();
+        let b = FiatAmount::parse_for("12.50", "EUR").unwrap();
+        assert_eq!(a, b);
+        assert!(a > FiatAmount::from_whole(12));
+        assert!(a < FiatAmount::from_whole(13));
+        assert_eq!((a.floor_units(), a.ceil_units()), (12, 13));
+        assert_eq!(a.whole_units(), None);
+        assert_eq!(
+            FiatAmount::parse_tag("40.00", "EUR").unwrap().whole_units(),
+            Some(40)
+        );
+    }
+
+    #[test]
+    fn tag_values_become_whole_fields_and_exact_amounts() {
+        let tag = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let fixed = OrderFiat::from_tag(&tag(&["12.5"]), "EUR");
+        assert_eq!(fixed.amount.to_string(), "12.50");
+        assert_eq!(fixed.whole_fields(), (12, None, None));
+
+        let range = OrderFiat::from_tag(&tag(&["10.25", "99.90"]), "USD");
+        assert_eq!(range.whole_fields(), (0, Some(11), Some(99)));
+
+        let order = SmallOrder {
+            fiat_code: "USD".to_string(),
+            min_amount: Some(11),
+            max_amount: Some(99),
+            ..Default::default()
+        };
+        assert_eq!(OrderFiat::whole(&order).min.unwrap().to_string(), "11");
+        let listing = BookOrder { order, fiat: range };
+        assert_eq!(listing.fiat.min.unwrap().to_string(), "10.25");
+        assert_eq!(listing.min_amount, Some(11));
+
+        // A DM snapshot of the taken trade carries the whole amount it was taken for.
+        let taken = BookOrder::whole(SmallOrder {
+            fiat_amount: 40,
+            ..Default::default()
+        });
+        assert_eq!(taken.fiat.amount.to_string(), "40");
+    }
+
+    #[test]
+    fn group_thousands_handles_decimals_and_passes_through_text() {
+        assert_eq!(group_thousands("142857"), "142,857");
+        assert_eq!(group_thousands("1234567.890"), "1,234,567.890");
+        assert_eq!(group_thousands("-1000"), "-1,000");
+        assert_eq!(group_thousands("999"), "999");
+        assert_eq!(group_thousands("market"), "market");
+        assert_eq!(group_thousands("1.2.3"), "1.2.3");
+    }
+}
@@ -49,7 +49,9 @@ pub const MOSTRO_LIST_FETCH_EVENT_LIMIT: usize = 500;
 /// Build a fetch filter for Mostro list snapshots: events authored by `pubkey`, a given custom
 /// `kind`, and at most [`MOSTRO_LIST_FETCH_EVENT_LIMIT`] results.
 ///
-/// There is **no** `since` time window; relay ordering decides which events fall inside the limit.
+/// There is **no** `since` time window here; relay ordering decides which events fall inside the
+/// limit. The order book adds one and pages past the limit in
+/// [`crate::util::order_utils::fetch_mostro_order_events_since`].
 pub fn create_mostro_list_fetch_filter(kind: u16, pubkey: PublicKey) -> Result<Filter> {
     Ok(Filter::new()
         .author(pubkey)
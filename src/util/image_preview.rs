@@ -0,0 +1,139 @@
+//! Image attachment previews: download the Blossom blob, decrypt it and decode a capped RGBA image.
+//! Rendering lives in `ui::image_preview_popup` / `ui::terminal_graphics`.
+
+use std::fmt;
+use std::io::Cursor;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use image::{ImageReader, Limits, RgbaImage};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::ui::{ChatAttachment, OperationResult};
+use crate::util::blossom::{blossom_url_to_https, decrypt_blob, fetch_blob};
+use crate::util::proxy::http_client;
+
+/// Largest blob downloaded for a preview (receipts and screenshots are far below this).
+pub const PREVIEW_MAX_BLOB_SIZE: usize = 10 * 1024 * 1024;
+/// Longest side of the decoded preview; larger images are downscaled once after decoding.
+pub const PREVIEW_MAX_SIDE: u32 = 1024;
+/// Decoder limits: refuse pathological dimensions before allocating the pixel buffer.
+const PREVIEW_MAX_DECODE_SIDE: u32 = 8192;
+const PREVIEW_MAX_DECODE_ALLOC: u64 = 256 * 1024 * 1024;
+
+static NEXT_PREVIEW_ID: AtomicU64 = AtomicU64::new(1);
+
+/// A decoded, size-capped image ready to render.
+#[derive(Clone)]
+pub struct PreviewImage {
+    /// Unique per load, so the terminal graphics overlay knows when to re-send pixels.
+    pub id: u64,
+    pub filename: String,
+    pub blossom_url: String,
+    pub image: RgbaImage,
+}
+
+impl fmt::Debug for PreviewImage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreviewImage")
+            .field("id", &self.id)
+            .field("filename", &self.filename)
+            .field("width", &self.image.width())
+            .field("height", &self.image.height())
+            .finish()
+    }
+}
+
+/// Decode PNG/JPEG/GIF/WebP bytes and downscale so neither side exceeds [`PREVIEW_MAX_SIDE`].
+pub fn decode_preview_image(bytes: &[u8]) -> Result<RgbaImage> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(PREVIEW_MAX_DECODE_SIDE);
+    limits.max_image_height = Some(PREVIEW_MAX_DECODE_SIDE);
+    limits.max_alloc = Some(PREVIEW_MAX_DECODE_ALLOC);
+
+    let mut reader = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| anyhow!("Unrecognized image data: {e}"))?;
+    reader.limits(limits);
+    let decoded = reader
+        .decode()
+        .map_err(|e| anyhow!("Image decode failed: {e}"))?;
+    let capped = if decoded.width() > PREVIEW_MAX_SIDE || decoded.height() > PREVIEW_MAX_SIDE {
+        decoded.thumbnail(PREVIEW_MAX_SIDE, PREVIEW_MAX_SIDE)
+    } else {
+        decoded
+    };
+    Ok(capped.to_rgba8())
+}
+
+/// Fetch, decrypt and decode an image attachment. The attachment must carry its decryption key.
+pub async fn load_preview_image(attachment: &ChatAttachment) -> Result<PreviewImage> {
+    let key = attachment
+        .decryption_key
+        .as_deref()
+        .ok_or_else(|| anyhow!("No decryption key for {}", attachment.filename))?;
+    let url = blossom_url_to_https(&attachment.blossom_url)?;
+    let client = http_client()?;
+    let blob = fetch_blob(&client, &url, 0, PREVIEW_MAX_BLOB_SIZE).await?;
+    let bytes = decrypt_blob(key, &blob)?;
+    let image = decode_preview_image(&bytes)?;
+    Ok(PreviewImage {
+        id: NEXT_PREVIEW_ID.fetch_add(1, Ordering::Relaxed),
+        filename: attachment.filename.clone(),
+        blossom_url: attachment.blossom_url.clone(),
+        image,
+    })
+}
+
+/// Load the preview in the background and report `ImagePreviewLoaded` / `ImagePreviewError`.
+pub async fn send_image_preview(
+    attachment: ChatAttachment,
+    order_result_tx: UnboundedSender<OperationResult>,
+) {
+    let result = match load_preview_image(&attachment).await {
+        Ok(image) => OperationResult::ImagePreviewLoaded(Arc::new(image)),
+        Err(e) => OperationResult::ImagePreviewError {
+            blossom_url: attachment.blossom_url,
+            message: e.to_string(),
+        },
+    };
+    let _ = order_result_tx.send(result);
+}
+
+/// Spawns [`send_image_preview`] for an attachment whose key is already resolved.
+pub fn spawn_image_preview(
+    attachment: ChatAttachment,
+    order_result_tx: UnboundedSender<OperationResult>,
+) {
+    tokio::spawn(send_image_preview(attachment, order_result_tx));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, Rgba};
+
+    fn png_bytes(width: u32, height: u32) -> Vec<u8> {
+        let img = RgbaImage::from_pixel(width, height, Rgba([10, 200, 30, 255]));
+        let mut out = Cursor::new(Vec::new());
+        img.write_to(&mut out, ImageFormat::Png).unwrap();
+        out.into_inner()
+    }
+
+    #[test]
+    fn decode_keeps_small_images_and_caps_large_ones() {
+        let small = decode_preview_image(&png_bytes(40, 20)).unwrap();
+        assert_eq!(small.dimensions(), (40, 20));
+        assert_eq!(small.get_pixel(0, 0), &Rgba([10, 200, 30, 255]));
+
+        let large = decode_preview_image(&png_bytes(3000, 1500)).unwrap();
+        assert_eq!(large.width(), PREVIEW_MAX_SIDE);
+        assert!(large.height() <= PREVIEW_MAX_SIDE / 2 + 1);
+    }
+
+    #[test]
+    fn decode_rejects_non_images() {
+        assert!(decode_preview_image(b"%PDF-1.7 not an image").is_err());
+    }
+}
@@ -1,40 +1,128 @@
 //! LNURL-pay metadata checks for Lightning addresses (and raw `lnurl1…` URLs).
+//!
+//! Buyer payout input pasted from a wallet may also arrive wrapped in a `lightning:` URI or a
+//! BIP-21 `bitcoin:` URI carrying a `lightning=` parameter; [`parse_payout_invoice_input`]
+//! unwraps those before the string reaches Mostro.
 
 use anyhow::Context;
+use lightning_invoice::Bolt11Invoice;
 use lnurl::lightning_address::LightningAddress;
 use lnurl::lnurl::LnUrl;
+use reqwest::Url;
 use serde_json::Value;
 use std::str::FromStr;
 
+use crate::util::proxy::http_client_builder;
+
 const LNURL_HTTP_TIMEOUT_SECS: u64 = 12;
 
-/// Resolve the HTTP URL that returns LNURL-pay metadata JSON (`tag: payRequest`).
-fn resolve_lnurlp_metadata_url(address: &str) -> Result<String, anyhow::Error> {
-    let trimmed = address.trim();
-    if trimmed.to_lowercase().starts_with("lnurl") {
+/// Destination the buyer pasted into an invoice popup, after URI unwrapping.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayoutInvoiceInput {
+    /// Raw BOLT11 payment request.
+    Bolt11(String),
+    /// `user@domain` Lightning address (LUD-16).
+    LightningAddress(String),
+    /// Bech32 `lnurl1…` string, decoded to its LNURL-pay HTTP URL (LUD-01/06).
+    Lnurl(String),
+}
+
+/// Sendable range and callback advertised by an LNURL-pay endpoint (`tag: payRequest`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LnurlPayParams {
+    pub callback: String,
+    pub min_sendable_msat: u64,
+    pub max_sendable_msat: u64,
+}
+
+/// Strip a case-insensitive `lightning:` scheme (with or without `//`).
+fn strip_lightning_scheme(input: &str) -> &str {
+    let trimmed = input.trim();
+    let prefix = "lightning:";
+    match trimmed.get(..prefix.len()) {
+        Some(head) if head.eq_ignore_ascii_case(prefix) => trimmed
+            .get(prefix.len()..)
+            .unwrap_or_default()
+            .trim_start_matches("//")
+            .trim(),
+        _ => trimmed,
+    }
+}
+
+/// Extract the `lightning=` query parameter from a BIP-21 `bitcoin:` URI.
+fn bip21_lightning_param(input: &str) -> Result<Option<String>, anyhow::Error> {
+    let trimmed = input.trim();
+    let prefix = "bitcoin:";
+    if !trimmed
+        .get(..prefix.len())
+        .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+    {
+        return Ok(None);
+    }
+    let url = Url::parse(trimmed).map_err(|_| anyhow::anyhow!("Invalid bitcoin: URI"))?;
+    let lightning = url
+        .query_pairs()
+        .find(|(k, _)| k.eq_ignore_ascii_case("lightning"))
+        .map(|(_, v)| v.into_owned());
+    match lightning {
+        Some(v) if !v.trim().is_empty() => Ok(Some(v)),
+        _ => anyhow::bail!("bitcoin: URI has no lightning= parameter"),
+    }
+}
+
+/// Classify buyer payout input, unwrapping `lightning:` and BIP-21 URIs first.
+///
+/// BOLT11 strings are returned as-is (expiry and signature are checked by the caller); Lightning
+/// addresses and LNURLs still need [`resolve_payout_payment_request`] before sending.
+pub fn parse_payout_invoice_input(input: &str) -> Result<PayoutInvoiceInput, anyhow::Error> {
+    let unwrapped = match bip21_lightning_param(input)? {
+        Some(param) => param,
+        None => input.trim().to_string(),
+    };
+    let candidate = strip_lightning_scheme(&unwrapped);
+    if candidate.is_empty() {
+        anyhow::bail!("Invoice cannot be empty");
+    }
+
+    if candidate.to_lowercase().starts_with("lnurl1") {
         let lnurl =
-            LnUrl::decode(trimmed.to_string()).map_err(|_| anyhow::anyhow!("Invalid LNURL"))?;
-        Ok(lnurl.url)
-    } else {
-        let la = LightningAddress::from_str(trimmed)
-            .map_err(|_| anyhow::anyhow!("Invalid Lightning address format"))?;
-        Ok(la.lnurlp_url())
+            LnUrl::decode(candidate.to_string()).map_err(|_| anyhow::anyhow!("Invalid LNURL"))?;
+        return Ok(PayoutInvoiceInput::Lnurl(lnurl.url));
     }
+    if LightningAddress::from_str(candidate).is_ok() {
+        return Ok(PayoutInvoiceInput::LightningAddress(candidate.to_string()));
+    }
+    Ok(PayoutInvoiceInput::Bolt11(candidate.to_string()))
 }
 
-/// GET the LNURL-pay metadata URL and ensure JSON declares `tag: "payRequest"` (same idea as Mostro `ln_exists`).
-pub async fn ln_address_pay_request_reachable(address: &str) -> Result<(), anyhow::Error> {
-    let url = resolve_lnurlp_metadata_url(address)?;
+/// Resolve the HTTP URL that returns LNURL-pay metadata JSON (`tag: payRequest`).
+fn resolve_lnurlp_metadata_url(address: &str) -> Result<String, anyhow::Error> {
+    match parse_payout_invoice_input(address)? {
+        PayoutInvoiceInput::Lnurl(url) => Ok(url),
+        PayoutInvoiceInput::LightningAddress(addr) => {
+            let la = LightningAddress::from_str(&addr)
+                .map_err(|_| anyhow::anyhow!("Invalid Lightning address format"))?;
+            Ok(la.lnurlp_url())
+        }
+        PayoutInvoiceInput::Bolt11(_) => {
+            anyhow::bail!("Invalid Lightning address format")
+        }
+    }
+}
 
-    let client = reqwest::Client::builder()
+fn lnurl_http_client() -> Result<reqwest::Client, anyhow::Error> {
+    http_client_builder()?
         .timeout(std::time::Duration::from_secs(LNURL_HTTP_TIMEOUT_SECS))
         .user_agent(concat!("mostrix/", env!("CARGO_PKG_VERSION")))
         .redirect(reqwest::redirect::Policy::limited(8))
         .build()
-        .context("build HTTP client")?;
+        .context("build HTTP client")
+}
 
+/// GET a LNURL endpoint and parse the JSON body, surfacing LUD-06 `status: ERROR` reasons.
+async fn get_lnurl_json(client: &reqwest::Client, url: &str) -> Result<Value, anyhow::Error> {
     let response = client
-        .get(&url)
+        .get(url)
         .send()
         .await
         .with_context(|| format!("GET {}", url))?;
@@ -44,23 +132,308 @@ pub async fn ln_address_pay_request_reachable(address: &str) -> Result<(), anyho
     }
 
     let body = response.text().await.context("read LNURL metadata body")?;
-
     let value: Value = serde_json::from_str(&body).context("LNURL metadata is not valid JSON")?;
 
+    if value
+        .get("status")
+        .and_then(|s| s.as_str())
+        .is_some_and(|s| s.eq_ignore_ascii_case("ERROR"))
+    {
+        let reason = value
+            .get("reason")
+            .and_then(|r| r.as_str())
+            .unwrap_or("no reason given");
+        anyhow::bail!("LNURL service error: {}", reason);
+    }
+    Ok(value)
+}
+
+fn pay_params_from_metadata(value: &Value) -> Result<LnurlPayParams, anyhow::Error> {
     match value.get("tag").and_then(|t| t.as_str()) {
-        Some("payRequest") => Ok(()),
+        Some("payRequest") => {}
         Some(other) => anyhow::bail!("unexpected LNURL tag {:?} (expected payRequest)", other),
         None => anyhow::bail!("LNURL metadata missing tag"),
     }
+    let callback = value
+        .get("callback")
+        .and_then(|c| c.as_str())
+        .filter(|c| !c.trim().is_empty())
+        .ok_or_else(|| anyhow::anyhow!("LNURL metadata missing callback"))?;
+    let min_sendable_msat = value
+        .get("minSendable")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| anyhow::anyhow!("LNURL metadata missing minSendable"))?;
+    let max_sendable_msat = value
+        .get("maxSendable")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| anyhow::anyhow!("LNURL metadata missing maxSendable"))?;
+    Ok(LnurlPayParams {
+        callback: callback.to_string(),
+        min_sendable_msat,
+        max_sendable_msat,
+    })
+}
+
+async fn fetch_pay_params_from_url(
+    client: &reqwest::Client,
+    url: &str,
+) -> Result<LnurlPayParams, anyhow::Error> {
+    let value = get_lnurl_json(client, url).await?;
+    pay_params_from_metadata(&value)
+}
+
+/// GET the LNURL-pay metadata URL and ensure JSON declares `tag: "payRequest"` (same idea as Mostro `ln_exists`).
+pub async fn ln_address_pay_request_reachable(address: &str) -> Result<(), anyhow::Error> {
+    let url = resolve_lnurlp_metadata_url(address)?;
+    let client = lnurl_http_client()?;
+    fetch_pay_params_from_url(&client, &url).await.map(|_| ())
+}
+
+/// Ensure `amount_sats` fits the endpoint's `[minSendable, maxSendable]` window (millisatoshis).
+pub fn check_sendable_range(
+    params: &LnurlPayParams,
+    amount_sats: i64,
+) -> Result<(), anyhow::Error> {
+    let amount_msat = u64::try_from(amount_sats)
+        .ok()
+        .and_then(|s| s.checked_mul(1000))
+        .ok_or_else(|| anyhow::anyhow!("Invalid trade amount: {} sats", amount_sats))?;
+    if amount_msat < params.min_sendable_msat {
+        anyhow::bail!(
+            "Trade amount {} sats is below the wallet minimum of {} sats",
+            amount_sats,
+            params.min_sendable_msat.div_ceil(1000)
+        );
+    }
+    if amount_msat > params.max_sendable_msat {
+        anyhow::bail!(
+            "Trade amount {} sats is above the wallet maximum of {} sats",
+            amount_sats,
+            params.max_sendable_msat / 1000
+        );
+    }
+    Ok(())
+}
+
+/// Ask the LNURL-pay callback for a BOLT11 invoice of exactly `amount_sats` (LUD-06 step 5).
+async fn request_lnurl_invoice(
+    client: &reqwest::Client,
+    params: &LnurlPayParams,
+    amount_sats: i64,
+) -> Result<String, anyhow::Error> {
+    let amount_msat = (amount_sats as u64) * 1000;
+    let mut callback =
+        Url::parse(&params.callback).map_err(|_| anyhow::anyhow!("Invalid LNURL callback URL"))?;
+    callback
+        .query_pairs_mut()
+        .append_pair("amount", &amount_msat.to_string());
+
+    let value = get_lnurl_json(client, callback.as_str()).await?;
+    let pr = value
+        .get("pr")
+        .and_then(|p| p.as_str())
+        .ok_or_else(|| anyhow::anyhow!("LNURL callback returned no invoice"))?;
+
+    let invoice = Bolt11Invoice::from_str(pr)
+        .map_err(|_| anyhow::anyhow!("LNURL returned invalid invoice"))?;
+    if invoice.amount_milli_satoshis() != Some(amount_msat) {
+        anyhow::bail!(
+            "LNURL invoice amount {:?} msat does not match requested {} msat",
+            invoice.amount_milli_satoshis(),
+            amount_msat
+        );
+    }
+    Ok(pr.to_string())
+}
+
+/// Turn a non-BOLT11 payout destination into the string sent in `Payload::PaymentRequest`.
+///
+/// Lightning addresses (including LNURLs that encode a `/.well-known/lnurlp/` URL) are checked
+/// against the pay endpoint and forwarded as `user@domain`, which Mostro resolves itself. Other
+/// LNURLs are resolved here into a BOLT11 invoice for `amount_sats`, so they need a known amount.
+/// BOLT11 input is returned unchanged.
+pub async fn resolve_payout_payment_request(
+    input: &PayoutInvoiceInput,
+    amount_sats: Option<i64>,
+) -> Result<String, anyhow::Error> {
+    let amount_sats = amount_sats.filter(|a| *a > 0);
+    let client = lnurl_http_client()?;
+    match input {
+        PayoutInvoiceInput::Bolt11(invoice) => Ok(invoice.clone()),
+        PayoutInvoiceInput::LightningAddress(address) => {
+            let url = resolve_lnurlp_metadata_url(address)?;
+            let params = fetch_pay_params_from_url(&client, &url).await?;
+            if let Some(amount) = amount_sats {
+                check_sendable_range(&params, amount)?;
+            }
+            Ok(address.clone())
+        }
+        PayoutInvoiceInput::Lnurl(url) => {
+            let params = fetch_pay_params_from_url(&client, url).await?;
+            if let Some(amount) = amount_sats {
+                check_sendable_range(&params, amount)?;
+            }
+            if let Some(address) = LnUrl::from_url(url.clone()).lightning_address() {
+                return Ok(address.to_string());
+            }
+            let amount = amount_sats.ok_or_else(|| {
+                anyhow::anyhow!("LNURL needs a known trade amount; paste a BOLT11 invoice instead")
+            })?;
+            request_lnurl_invoice(&client, &params, amount).await
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
 
     #[test]
     fn resolves_well_known_lnurlp_from_lightning_address() {
         let url = resolve_lnurlp_metadata_url("user@example.com").unwrap();
         assert_eq!(url, "https://example.com/.well-known/lnurlp/user");
     }
+
+    #[test]
+    fn parses_lightning_uri_and_bip21_wrappers() {
+        assert_eq!(
+            parse_payout_invoice_input("LIGHTNING:user@example.com").unwrap(),
+            PayoutInvoiceInput::LightningAddress("user@example.com".to_string())
+        );
+        let lnurl = LnUrl::from_url("https://example.com/pay/abc".to_string()).encode();
+        assert_eq!(
+            parse_payout_invoice_input(&format!("lightning:{}", lnurl.to_uppercase())).unwrap(),
+            PayoutInvoiceInput::Lnurl("https://example.com/pay/abc".to_string())
+        );
+        assert_eq!(
+            parse_payout_invoice_input(&format!(
+                "bitcoin:bc1qexample?amount=0.0001&lightning={}",
+                lnurl
+            ))
+            .unwrap(),
+            PayoutInvoiceInput::Lnurl("https://example.com/pay/abc".to_string())
+        );
+        assert_eq!(
+            parse_payout_invoice_input("lightning:lnbc1xyz").unwrap(),
+            PayoutInvoiceInput::Bolt11("lnbc1xyz".to_string())
+        );
+        assert!(parse_payout_invoice_input("bitcoin:bc1qexample?amount=1").is_err());
+    }
+
+    #[test]
+    fn non_ascii_input_does_not_split_a_char() {
+        // Byte 8 / 10 falls inside a multi-byte char for both inputs.
+        for input in ["€€€€", "ñandú-pay…", "lightnin€:x", "bitcoi€:x"] {
+            assert_eq!(
+                parse_payout_invoice_input(input).unwrap(),
+                PayoutInvoiceInput::Bolt11(input.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn sendable_range_is_checked_in_msat() {
+        let params = LnurlPayParams {
+            callback: "https://example.com/cb".to_string(),
+            min_sendable_msat: 1_000_000,
+            max_sendable_msat: 50_000_000,
+        };
+        assert!(check_sendable_range(&params, 1_000).is_ok());
+        assert!(check_sendable_range(&params, 50_000).is_ok());
+        assert!(check_sendable_range(&params, 999).is_err());
+        assert!(check_sendable_range(&params, 50_001).is_err());
+    }
+
+    /// Minimal LNURL stand-in: answers every GET with the same JSON `body`.
+    async fn spawn_lnurl_stand_in(body: String) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 2048];
+                let _ = socket.read(&mut buf).await;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        format!("http://{}", addr)
+    }
+
+    fn install_crypto_provider() {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+    }
+
+    #[tokio::test]
+    async fn lnurl_pay_rejects_amount_outside_sendable_range() {
+        install_crypto_provider();
+        let base = spawn_lnurl_stand_in(
+            r#"{"tag":"payRequest","callback":"http://127.0.0.1:1/cb","minSendable":1000000,"maxSendable":2000000,"metadata":"[]"}"#
+                .to_string(),
+        )
+        .await;
+        let input =
+            parse_payout_invoice_input(&LnUrl::from_url(format!("{}/lnurlp/abc", base)).encode())
+                .unwrap();
+
+        let err = resolve_payout_payment_request(&input, Some(5_000))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("above the wallet maximum"));
+
+        let err = resolve_payout_payment_request(&input, Some(10))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("below the wallet minimum"));
+    }
+
+    #[tokio::test]
+    async fn lnurl_pay_without_amount_requires_bolt11() {
+        install_crypto_provider();
+        let base = spawn_lnurl_stand_in(
+            r#"{"tag":"payRequest","callback":"http://127.0.0.1:1/cb","minSendable":1000,"maxSendable":2000000000,"metadata":"[]"}"#
+                .to_string(),
+        )
+        .await;
+        let input = PayoutInvoiceInput::Lnurl(format!("{}/lnurlp/abc", base));
+        let err = resolve_payout_payment_request(&input, None)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("known trade amount"));
+    }
+
+    #[tokio::test]
+    async fn lnurl_service_error_is_surfaced() {
+        install_crypto_provider();
+        let base =
+            spawn_lnurl_stand_in(r#"{"status":"ERROR","reason":"user not found"}"#.to_string())
+                .await;
+        let input = PayoutInvoiceInput::Lnurl(format!("{}/lnurlp/abc", base));
+        let err = resolve_payout_payment_request(&input, Some(1_000))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("user not found"));
+    }
+
+    #[tokio::test]
+    async fn lnurl_callback_invalid_invoice_is_rejected() {
+        install_crypto_provider();
+        let callback_base =
+            spawn_lnurl_stand_in(r#"{"pr":"lnbcnotaninvoice","routes":[]}"#.to_string()).await;
+        let base = spawn_lnurl_stand_in(format!(
+            r#"{{"tag":"payRequest","callback":"{}/cb?id=1","minSendable":1000,"maxSendable":2000000000,"metadata":"[]"}}"#,
+            callback_base
+        ))
+        .await;
+        let input = PayoutInvoiceInput::Lnurl(format!("{}/lnurlp/abc", base));
+        let err = resolve_payout_payment_request(&input, Some(1_000))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid invoice"));
+    }
 }
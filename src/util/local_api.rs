@@ -0,0 +1,1225 @@
+//! Local JSON-RPC API served by the running client on a Unix domain socket.
+//!
+//! Dashboards and bots talk to this instance instead of opening a second one on the same
+//! SQLite file and trade-index counter. The socket is `~/.mostrix/api/mostrix.sock` (mode `0600`,
+//! in a `0700` directory) and only exists while `local_api` in settings.toml is `"read"` or `"read-write"`.
+//!
+//! Requests are JSON-RPC 2.0 objects, one per line; every answer is one line. Calls are handed
+//! to the main loop as [`LocalApiCall`]s, so reads see the state the UI draws and writes run the
+//! same tasks as the keyboard flows, reporting through the same [`OperationResult`] channel.
+//!
+//! | Method               | Access     | Params                                                    |
+//! |----------------------|------------|-----------------------------------------------------------|
+//! | `get_orders`         | read       | –                                                         |
+//! | `get_my_trades`      | read       | –                                                         |
+//! | `get_messages`       | read       | –                                                         |
+//! | `get_instance_info`  | read       | –                                                         |
+//! | `get_network_status` | read       | –                                                         |
+//! | `subscribe_events`   | read       | – (streams `{"method":"event",...}` lines for new DMs)    |
+//! | `new_order`          | read-write | `kind`, `fiat_code`, `fiat_amount`, `payment_method`, ... |
+//! | `take_order`         | read-write | `order_id`, `amount?`, `invoice?`                         |
+//! | `send_chat`          | read-write | `order_id`, `message`, `channel?` (`peer` / `solver`)     |
+//! | `trade_action`       | read-write | `order_id`, `action`, `rating?`, `invoice?`               |
+//!
+//! ```text
+//! {"jsonrpc":"2.0","id":1,"method":"trade_action","params":{"order_id":"…","action":"fiat-sent"}}
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use mostro_core::prelude::{Action, SmallOrder, Status};
+use nostr_sdk::prelude::{Client, PublicKey};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::sync::{broadcast, oneshot};
+use uuid::Uuid;
+
+use crate::ui::helpers::active_order_chat_list_snapshot;
+use crate::ui::key_handler::{persist_local_user_chat_message, OrderChatTarget};
+use crate::ui::{
+    AppState, FormState, MessageNotification, OperationResult, TakeOrderState, UserChatChannel,
+    UserChatSender, UserOrderChatMessage,
+};
+use crate::util::chat_utils::send_user_order_chat;
+use crate::util::db_utils::update_order_status;
+use crate::util::dm_utils::OrderDmSubscriptionCmd;
+use crate::util::fiat::BookOrder;
+use crate::util::mostro_info::MostroInstanceInfo;
+use crate::util::order_utils::{
+    execute_add_invoice, execute_dispute, execute_rate_user, execute_send_msg, range_take_amount,
+    send_new_order, take_order, validate_range_amount,
+};
+
+pub const LOCAL_API_SOCKET_FILE_NAME: &str = "mostrix.sock";
+/// Owner-only directory under `~/.mostrix` that holds the socket.
+pub const LOCAL_API_DIR_NAME: &str = "api";
+/// Events buffered per `subscribe_events` connection before a slow reader starts missing some.
+pub const LOCAL_API_EVENT_CAPACITY: usize = 256;
+/// Longest request line accepted; a longer one gets an error and the connection is closed.
+pub const MAX_REQUEST_BYTES: usize = 64 * 1024;
+
+/// JSON-RPC 2.0 error codes.
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+/// Write method called while `local_api = "read"`.
+pub const FORBIDDEN: i64 = -32001;
+
+/// `local_api` setting: who may do what over the socket.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocalApiAccess {
+    /// No socket.
+    Off,
+    /// Read methods and `subscribe_events` only.
+    Read,
+    /// Read methods plus orders, takes, chat and trade actions.
+    ReadWrite,
+}
+
+impl LocalApiAccess {
+    pub fn from_setting(value: &str) -> Result<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "" | "off" => Ok(Self::Off),
+            "read" => Ok(Self::Read),
+            "read-write" => Ok(Self::ReadWrite),
+            other => anyhow::bail!(
+                "local_api must be \"off\", \"read\" or \"read-write\", got \"{other}\""
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LocalApiMethod {
+    GetOrders,
+    GetMyTrades,
+    GetMessages,
+    GetInstanceInfo,
+    GetNetworkStatus,
+    SubscribeEvents,
+    NewOrder,
+    TakeOrder,
+    SendChat,
+    TradeAction,
+}
+
+impl LocalApiMethod {
+    pub const ALL: [LocalApiMethod; 10] = [
+        LocalApiMethod::GetOrders,
+        LocalApiMethod::GetMyTrades,
+        LocalApiMethod::GetMessages,
+        LocalApiMethod::GetInstanceInfo,
+        LocalApiMethod::GetNetworkStatus,
+        LocalApiMethod::SubscribeEvents,
+        LocalApiMethod::NewOrder,
+        LocalApiMethod::TakeOrder,
+        LocalApiMethod::SendChat,
+        LocalApiMethod::TradeAction,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LocalApiMethod::GetOrders => "get_orders",
+            LocalApiMethod::GetMyTrades => "get_my_trades",
+            LocalApiMethod::GetMessages => "get_messages",
+            LocalApiMethod::GetInstanceInfo => "get_instance_info",
+            LocalApiMethod::GetNetworkStatus => "get_network_status",
+            LocalApiMethod::SubscribeEvents => "subscribe_events",
+            LocalApiMethod::NewOrder => "new_order",
+            LocalApiMethod::TakeOrder => "take_order",
+            LocalApiMethod::SendChat => "send_chat",
+            LocalApiMethod::TradeAction => "trade_action",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|m| m.as_str() == name)
+    }
+
+    /// Methods that talk to Mostro or the counterparty on the user's behalf.
+    pub fn is_write(self) -> bool {
+        matches!(
+            self,
+            LocalApiMethod::NewOrder
+                | LocalApiMethod::TakeOrder
+                | LocalApiMethod::SendChat
+                | LocalApiMethod::TradeAction
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(INVALID_PARAMS, message)
+    }
+
+    fn internal(message: impl Into<String>) -> Self {
+        Self::new(INTERNAL_ERROR, message)
+    }
+}
+
+pub type RpcOutcome = std::result::Result<Value, RpcError>;
+
+#[derive(Deserialize)]
+struct RpcRequest {
+    #[serde(default)]
+    jsonrpc: Option<String>,
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// A request line that passed parsing and the access check.
+#[derive(Debug)]
+pub struct ParsedRequest {
+    pub id: Value,
+    pub method: LocalApiMethod,
+    pub params: Value,
+}
+
+/// Parse one request line and check it against `access`. Errors carry the request id (or
+/// `null`) so the caller can still answer.
+pub fn parse_request(
+    line: &str,
+    access: LocalApiAccess,
+) -> std::result::Result<ParsedRequest, (Value, RpcError)> {
+    let value: Value = serde_json::from_str(line)
+        .map_err(|e| (Value::Null, RpcError::new(PARSE_ERROR, e.to_string())))?;
+    let id = value.get("id").cloned().unwrap_or(Value::Null);
+    let request: RpcRequest = serde_json::from_value(value)
+        .map_err(|e| (id.clone(), RpcError::new(INVALID_REQUEST, e.to_string())))?;
+    if request.jsonrpc.as_deref().is_some_and(|v| v != "2.0") {
+        return Err((
+            request.id,
+            RpcError::new(INVALID_REQUEST, "jsonrpc must be \"2.0\""),
+        ));
+    }
+    let Some(method) = LocalApiMethod::from_name(&request.method) else {
+        return Err((
+            request.id,
+            RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Unknown method: {}", request.method),
+            ),
+        ));
+    };
+    if method.is_write() && access != LocalApiAccess::ReadWrite {
+        return Err((
+            request.id,
+            RpcError::new(
+                FORBIDDEN,
+                format!(
+                    "{} needs local_api = \"read-write\" in settings.toml",
+                    method.as_str()
+                ),
+            ),
+        ));
+    }
+    Ok(ParsedRequest {
+        id: request.id,
+        method,
+        params: request.params,
+    })
+}
+
+/// One JSON-RPC response line (without the trailing newline).
+pub fn response_line(id: &Value, outcome: &RpcOutcome) -> String {
+    let response = match outcome {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": e.code, "message": e.message },
+        }),
+    };
+    response.to_string()
+}
+
+/// `subscribe_events` notification line for a new trade DM.
+pub fn notification_event_line(notification: &MessageNotification) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "method": "event",
+        "params": {
+            "type": "trade-dm",
+            "order_id": notification.order_id,
+            "action": notification.action,
+            "timestamp": notification.timestamp,
+            "sat_amount": notification.sat_amount,
+            "invoice": notification.invoice,
+            "message": notification.message_preview,
+        },
+    })
+    .to_string()
+}
+
+/// Answer sent to the caller for a write call; errors become JSON-RPC errors.
+pub fn operation_result_json(result: &OperationResult) -> RpcOutcome {
+    match result {
+        OperationResult::Error(e) => Err(RpcError::internal(e.clone())),
+        OperationResult::Success(s) => Ok(json!({
+            "result": "success",
+            "order_id": s.order_id,
+            "kind": s.kind,
+            "status": s.status,
+            "amount": s.amount,
+            "fiat_code": s.fiat_code,
+            "fiat_amount": s.fiat_amount,
+            "min_amount": s.min_amount,
+            "max_amount": s.max_amount,
+            "trade_index": s.trade_index,
+        })),
+        OperationResult::PaymentRequestRequired {
+            order,
+            invoice,
+            sat_amount,
+            action,
+            ..
+        } => Ok(json!({
+            "result": "payment-required",
+            "order_id": order.id,
+            "action": action,
+            "invoice": invoice,
+            "sat_amount": sat_amount,
+        })),
+        OperationResult::OpenInvoicePopup { notification, .. } => Ok(json!({
+            "result": "invoice-requested",
+            "order_id": notification.order_id,
+            "action": notification.action,
+            "invoice": notification.invoice,
+            "sat_amount": notification.sat_amount,
+        })),
+        OperationResult::TradeClosed { order_id, message } => Ok(json!({
+            "result": "trade-closed",
+            "order_id": order_id,
+            "message": message,
+        })),
+        OperationResult::Info(message) | OperationResult::InvoiceSubmitted { message, .. } => {
+            Ok(json!({ "result": "info", "message": message }))
+        }
+        _ => Ok(json!({ "result": "ok" })),
+    }
+}
+
+/// One request forwarded from a socket connection to the main loop.
+pub struct LocalApiCall {
+    pub method: LocalApiMethod,
+    pub params: Value,
+    pub reply: oneshot::Sender<RpcOutcome>,
+}
+
+/// `~/.mostrix/api/mostrix.sock`, or `None` when the home directory is unknown.
+pub fn local_api_socket_path() -> Option<PathBuf> {
+    Some(
+        crate::settings::data_dir()?
+            .join(LOCAL_API_DIR_NAME)
+            .join(LOCAL_API_SOCKET_FILE_NAME),
+    )
+}
+
+/// Bind the socket at `path` and serve connections until the returned task is aborted.
+///
+/// The parent of `path` is a directory of its own and is made `0700`, so nobody else can reach
+/// the socket between `bind` and its `chmod 0600`.
+///
+/// A leftover socket from a crashed run is replaced; one that still accepts connections
+/// belongs to another running client and is an error.
+#[cfg(unix)]
+pub async fn start_local_api_server(
+    path: &Path,
+    access: LocalApiAccess,
+    call_tx: UnboundedSender<LocalApiCall>,
+    events_tx: broadcast::Sender<String>,
+) -> Result<tokio::task::JoinHandle<()>> {
+    use std::os::unix::fs::PermissionsExt;
+    use tokio::net::{UnixListener, UnixStream};
+
+    if path.exists() {
+        if UnixStream::connect(path).await.is_ok() {
+            anyhow::bail!("{} is in use by another Mostrix", path.display());
+        }
+        std::fs::remove_file(path)?;
+    }
+    if let Some(parent) = path.parent() {
+        use std::os::unix::fs::DirBuilderExt;
+        std::fs::DirBuilder::new()
+            .recursive(true)
+            .mode(0o700)
+            .create(parent)?;
+        // `DirBuilder::mode` only applies to directories it creates; tighten an existing one.
+        std::fs::set_permissions(parent, std::fs::Permissions::from_mode(0o700))?;
+    }
+    let listener = UnixListener::bind(path)?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    log::info!("Local API listening on {} ({:?})", path.display(), access);
+
+    Ok(tokio::spawn(async move {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    tokio::spawn(serve_connection(
+                        stream,
+                        access,
+                        call_tx.clone(),
+                        events_tx.clone(),
+                    ));
+                }
+                Err(e) => log::warn!("Local API accept failed: {}", e),
+            }
+        }
+    }))
+}
+
+#[cfg(not(unix))]
+pub async fn start_local_api_server(
+    _path: &Path,
+    _access: LocalApiAccess,
+    _call_tx: UnboundedSender<LocalApiCall>,
+    _events_tx: broadcast::Sender<String>,
+) -> Result<tokio::task::JoinHandle<()>> {
+    anyhow::bail!("the local API needs Unix domain sockets")
+}
+
+/// Next request line without its line ending, or `None` at end of stream.
+///
+/// A line longer than [`MAX_REQUEST_BYTES`] (or not UTF-8) is an `InvalidData` error, so a
+/// client cannot make the server buffer without bound.
+#[cfg(unix)]
+async fn read_request_line<R>(reader: &mut R) -> std::io::Result<Option<String>>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt};
+
+    let mut buf = Vec::new();
+    let limit = MAX_REQUEST_BYTES as u64 + 1;
+    if (&mut *reader)
+        .take(limit)
+        .read_until(b'\n', &mut buf)
+        .await?
+        == 0
+    {
+        return Ok(None);
+    }
+    if buf.last() == Some(&b'\n') {
+        buf.pop();
+        if buf.last() == Some(&b'\r') {
+            buf.pop();
+        }
+    } else if buf.len() > MAX_REQUEST_BYTES {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("request exceeds {MAX_REQUEST_BYTES} bytes"),
+        ));
+    }
+    String::from_utf8(buf)
+        .map(Some)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+}
+
+/// Serve one client: requests are answered concurrently, in completion order (match on `id`).
+#[cfg(unix)]
+async fn serve_connection(
+    stream: tokio::net::UnixStream,
+    access: LocalApiAccess,
+    call_tx: UnboundedSender<LocalApiCall>,
+    events_tx: broadcast::Sender<String>,
+) {
+    use tokio::io::{AsyncWriteExt, BufReader};
+
+    let (read_half, mut write_half) = stream.into_split();
+    let (out_tx, mut out_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+    tokio::spawn(async move {
+        while let Some(mut line) = out_rx.recv().await {
+            line.push('\n');
+            if write_half.write_all(line.as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut subscription: Option<tokio::task::JoinHandle<()>> = None;
+    let mut reader = BufReader::new(read_half);
+    loop {
+        let line = match read_request_line(&mut reader).await {
+            Ok(Some(line)) => line,
+            Ok(None) => break,
+            Err(e) => {
+                if e.kind() == std::io::ErrorKind::InvalidData {
+                    let error = RpcError::new(INVALID_REQUEST, e.to_string());
+                    let _ = out_tx.send(response_line(&Value::Null, &Err(error)));
+                }
+                break;
+            }
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let request = match parse_request(&line, access) {
+            Ok(request) => request,
+            Err((id, e)) => {
+                let _ = out_tx.send(response_line(&id, &Err(e)));
+                continue;
+            }
+        };
+        if request.method == LocalApiMethod::SubscribeEvents {
+            if subscription.is_none() {
+                let mut events_rx = events_tx.subscribe();
+                let events_out = out_tx.clone();
+                subscription = Some(tokio::spawn(async move {
+                    loop {
+                        match events_rx.recv().await {
+                            Ok(event) => {
+                                if events_out.send(event).is_err() {
+                                    break;
+                                }
+                            }
+                            Err(broadcast::error::RecvError::Lagged(missed)) => {
+                                log::warn!("Local API subscriber missed {} events", missed);
+                            }
+                            Err(broadcast::error::RecvError::Closed) => break,
+                        }
+                    }
+                }));
+            }
+            let _ = out_tx.send(response_line(
+                &request.id,
+                &Ok(json!({ "subscribed": true })),
+            ));
+            continue;
+        }
+        let call_tx = call_tx.clone();
+        let out_tx = out_tx.clone();
+        tokio::spawn(async move {
+            let (reply, reply_rx) = oneshot::channel();
+            let call = LocalApiCall {
+                method: request.method,
+                params: request.params,
+                reply,
+            };
+            let outcome = if call_tx.send(call).is_err() {
+                Err(RpcError::internal("Mostrix is shutting down"))
+            } else {
+                reply_rx
+                    .await
+                    .unwrap_or_else(|_| Err(RpcError::internal("Request was dropped")))
+            };
+            let _ = out_tx.send(response_line(&request.id, &outcome));
+        });
+    }
+    if let Some(subscription) = subscription {
+        subscription.abort();
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Main-loop side
+// ---------------------------------------------------------------------------
+
+/// Handles write calls need beyond [`AppState`].
+#[derive(Clone)]
+pub struct LocalApiContext {
+    pub pool: SqlitePool,
+    pub client: Client,
+    pub mostro_pubkey: PublicKey,
+    pub order_result_tx: UnboundedSender<OperationResult>,
+    pub dm_subscription_tx: UnboundedSender<OrderDmSubscriptionCmd>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NewOrderParams {
+    kind: String,
+    fiat_code: String,
+    fiat_amount: i64,
+    #[serde(default)]
+    fiat_amount_max: Option<i64>,
+    /// Sats; `0` or absent = market price.
+    #[serde(default)]
+    amount: i64,
+    payment_method: String,
+    #[serde(default)]
+    premium: i64,
+    #[serde(default)]
+    invoice: Option<String>,
+    #[serde(default = "default_expiration_days")]
+    expiration_days: u32,
+}
+
+fn default_expiration_days() -> u32 {
+    1
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TakeOrderParams {
+    order_id: Uuid,
+    /// Fiat amount for range orders.
+    #[serde(default)]
+    amount: Option<i64>,
+    #[serde(default)]
+    invoice: Option<String>,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum ChatChannelParam {
+    #[default]
+    Peer,
+    Solver,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SendChatParams {
+    order_id: Uuid,
+    message: String,
+    #[serde(default)]
+    channel: ChatChannelParam,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+enum TradeActionKind {
+    FiatSent,
+    Release,
+    Cancel,
+    Dispute,
+    Rate,
+    AddInvoice,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TradeActionParams {
+    order_id: Uuid,
+    action: TradeActionKind,
+    #[serde(default)]
+    rating: Option<u8>,
+    #[serde(default)]
+    invoice: Option<String>,
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> std::result::Result<T, RpcError> {
+    let params = if params.is_null() { json!({}) } else { params };
+    serde_json::from_value(params).map_err(|e| RpcError::invalid_params(e.to_string()))
+}
+
+fn new_order_form(params: NewOrderParams) -> std::result::Result<FormState, RpcError> {
+    let kind = params.kind.trim().to_lowercase();
+    if kind != "buy" && kind != "sell" {
+        return Err(RpcError::invalid_params("kind must be \"buy\" or \"sell\""));
+    }
+    if params.fiat_amount <= 0 {
+        return Err(RpcError::invalid_params("fiat_amount must be positive"));
+    }
+    if params.payment_method.trim().is_empty() {
+        return Err(RpcError::invalid_params("payment_method is required"));
+    }
+    if params
+        .fiat_amount_max
+        .is_some_and(|max| max <= params.fiat_amount)
+    {
+        return Err(RpcError::invalid_params(
+            "fiat_amount_max must be above fiat_amount",
+        ));
+    }
+    let mut form = FormState::new_default_form();
+    form.kind = kind;
+    form.fiat_code = params.fiat_code.trim().to_uppercase();
+    form.fiat_amount = params.fiat_amount.to_string();
+    form.use_range = params.fiat_amount_max.is_some();
+    form.fiat_amount_max = params
+        .fiat_amount_max
+        .map(|max| max.to_string())
+        .unwrap_or_default();
+    form.amount = params.amount.to_string();
+    form.payment_method = params.payment_method.trim().to_string();
+    form.premium = params.premium.to_string();
+    form.invoice = params.invoice.unwrap_or_default();
+    form.expiration_days = params.expiration_days.max(1).to_string();
+    Ok(form)
+}
+
+fn take_order_state(
+    orders: &Arc<Mutex<Vec<BookOrder>>>,
+    params: &TakeOrderParams,
+) -> std::result::Result<TakeOrderState, RpcError> {
+    let order = orders
+        .lock()
+        .map_err(|e| RpcError::internal(format!("Orders lock poisoned: {e}")))?
+        .iter()
+        .find(|o| o.id == Some(params.order_id))
+        .cloned()
+        .ok_or_else(|| {
+            RpcError::invalid_params(format!("Order {} is not on the book", params.order_id))
+        })?;
+    let is_range_order = order.min_amount.is_some() || order.max_amount.is_some();
+    let mut take_state = TakeOrderState {
+        order,
+        amount_input: params.amount.map(|a| a.to_string()).unwrap_or_default(),
+        is_range_order,
+        validation_error: None,
+        selected_button: true,
+    };
+    if is_range_order {
+        if take_state.amount_input.is_empty() {
+            return Err(RpcError::invalid_params(
+                "amount is required for range orders",
+            ));
+        }
+        validate_range_amount(&mut take_state);
+        if let Some(e) = take_state.validation_error.take() {
+            return Err(RpcError::invalid_params(e));
+        }
+    }
+    Ok(take_state)
+}
+
+fn orders_json(orders: &Arc<Mutex<Vec<BookOrder>>>) -> RpcOutcome {
+    let orders = orders
+        .lock()
+        .map_err(|e| RpcError::internal(format!("Orders lock poisoned: {e}")))?;
+    let orders: Vec<&SmallOrder> = orders.iter().map(|o| &o.order).collect();
+    serde_json::to_value(orders).map_err(|e| RpcError::internal(e.to_string()))
+}
+
+fn my_trades_json(app: &AppState) -> Value {
+    let now = chrono::Utc::now().timestamp();
+    let info = app.mostro_info.as_ref();
+    active_order_chat_list_snapshot(app)
+        .iter()
+        .map(|row| {
+            let deadline = row.deadline(info).map(|d| {
+                json!({
+                    "kind": d.kind.as_str(),
+                    "label": d.kind.label(),
+                    "at": d.deadline,
+                    "remaining_secs": d.remaining_secs(now),
+                })
+            });
+            json!({
+                "order_id": row.order_id,
+                "status": row.status,
+                "amount": row.amount,
+                "fiat_amount": row.fiat.as_ref().map(|(amount, _)| amount),
+                "fiat_code": row.fiat.as_ref().map(|(_, code)| code),
+                "payment_method": row.payment_method,
+                "premium": row.premium,
+                "trade_index": row.trade_index,
+                "dispute_id": row.dispute_id,
+                "created_at": row.created_at,
+                "deadline": deadline,
+            })
+        })
+        .collect()
+}
+
+fn messages_json(app: &AppState) -> RpcOutcome {
+    let messages = app
+        .messages
+        .lock()
+        .map_err(|e| RpcError::internal(format!("Messages lock poisoned: {e}")))?;
+    Ok(messages
+        .iter()
+        .map(|m| {
+            json!({
+                "order_id": m.order_id,
+                "action": m.message.get_inner_message_kind().action,
+                "timestamp": m.timestamp,
+                "trade_index": m.trade_index,
+                "sat_amount": m.sat_amount,
+                "order_kind": m.order_kind,
+                "order_status": m.order_status,
+                "is_mine": m.is_mine,
+                "read": m.read,
+            })
+        })
+        .collect())
+}
+
+fn instance_info_json(info: Option<&MostroInstanceInfo>) -> Value {
+    let Some(info) = info else {
+        return Value::Null;
+    };
+    json!({
+        "last_updated": info.last_updated.map(|t| t.as_secs()),
+        "mostro_version": info.mostro_version,
+        "max_order_amount": info.max_order_amount,
+        "min_order_amount": info.min_order_amount,
+        "expiration_hours": info.expiration_hours,
+        "expiration_seconds": info.expiration_seconds,
+        "fiat_currencies_accepted": info.fiat_currencies_accepted,
+        "fee": info.fee,
+        "pow": info.pow,
+        "protocol_version": info.protocol_version,
+        "bond_enabled": info.bond_enabled,
+        "hold_invoice_expiration_window": info.hold_invoice_expiration_window,
+        "invoice_expiration_window": info.invoice_expiration_window,
+        "lnd_node_alias": info.lnd_node_alias,
+        "lnd_node_pubkey": info.lnd_node_pubkey,
+        "lnd_networks": info.lnd_networks,
+    })
+}
+
+/// Answer one [`LocalApiCall`]. Reads reply immediately from `app`; writes validate here and
+/// then run in the background like their keyboard counterparts.
+pub fn handle_local_api_call(
+    app: &mut AppState,
+    orders: &Arc<Mutex<Vec<BookOrder>>>,
+    call: LocalApiCall,
+    ctx: &LocalApiContext,
+) {
+    let LocalApiCall {
+        method,
+        params,
+        reply,
+    } = call;
+    let outcome = match method {
+        LocalApiMethod::GetOrders => orders_json(orders),
+        LocalApiMethod::GetMyTrades => Ok(my_trades_json(app)),
+        LocalApiMethod::GetMessages => messages_json(app),
+        LocalApiMethod::GetInstanceInfo => Ok(instance_info_json(app.mostro_info.as_ref())),
+        LocalApiMethod::GetNetworkStatus => {
+            spawn_network_status(app, ctx, reply);
+            return;
+        }
+        // Served by the connection itself; never forwarded.
+        LocalApiMethod::SubscribeEvents => Err(RpcError::new(
+            INVALID_REQUEST,
+            "subscribe_events is handled per connection",
+        )),
+        LocalApiMethod::NewOrder => match parse_params(params).and_then(new_order_form) {
+            Ok(form) => {
+                let ctx_task = ctx.clone();
+                let mostro_info = app.mostro_info.clone();
+                spawn_write(ctx, reply, async move {
+                    send_new_order(
+                        &ctx_task.pool,
+                        &ctx_task.client,
+                        ctx_task.mostro_pubkey,
+                        form,
+                        Some(&ctx_task.dm_subscription_tx),
+                        mostro_info.as_ref(),
+                    )
+                    .await
+                });
+                return;
+            }
+            Err(e) => Err(e),
+        },
+        LocalApiMethod::TakeOrder => {
+            match parse_params::<TakeOrderParams>(params)
+                .and_then(|p| take_order_state(orders, &p).map(|state| (state, p.invoice)))
+            {
+                Ok((take_state, invoice)) => {
+                    let ctx_task = ctx.clone();
+                    let mostro_info = app.mostro_info.clone();
+                    let amount = take_state
+                        .is_range_order
+                        .then(|| range_take_amount(&take_state))
+                        .flatten();
+                    spawn_write(ctx, reply, async move {
+                        take_order(
+                            &ctx_task.pool,
+                            &ctx_task.client,
+                            ctx_task.mostro_pubkey,
+                            &take_state.order,
+                            amount,
+                            invoice,
+                            Some(&ctx_task.dm_subscription_tx),
+                            mostro_info.as_ref(),
+                        )
+                        .await
+                    });
+                    return;
+                }
+                Err(e) => Err(e),
+            }
+        }
+        LocalApiMethod::SendChat => match parse_params::<SendChatParams>(params) {
+            Ok(p) => {
+                send_chat(app, ctx, p, reply);
+                return;
+            }
+            Err(e) => Err(e),
+        },
+        LocalApiMethod::TradeAction => match parse_params::<TradeActionParams>(params) {
+            Ok(p) => {
+                trade_action(app, ctx, p, reply);
+                return;
+            }
+            Err(e) => Err(e),
+        },
+    };
+    let _ = reply.send(outcome);
+}
+
+/// Run a write in the background; its [`OperationResult`] answers the caller and then goes to
+/// the UI channel exactly as the keyboard flow would send it.
+fn spawn_write<F>(ctx: &LocalApiContext, reply: oneshot::Sender<RpcOutcome>, task: F)
+where
+    F: std::future::Future<Output = Result<OperationResult>> + Send + 'static,
+{
+    let order_result_tx = ctx.order_result_tx.clone();
+    tokio::spawn(async move {
+        let result = task.await.unwrap_or_else(|e| {
+            log::error!("Local API call failed: {}", e);
+            OperationResult::Error(e.to_string())
+        });
+        let _ = reply.send(operation_result_json(&result));
+        let _ = order_result_tx.send(result);
+    });
+}
+
+fn spawn_network_status(app: &AppState, ctx: &LocalApiContext, reply: oneshot::Sender<RpcOutcome>) {
+    let client = ctx.client.clone();
+    let offline = app.offline_overlay_message.clone();
+    let transport = format!("{:?}", app.transport);
+    let mostro_pubkey = ctx.mostro_pubkey.to_hex();
+    tokio::spawn(async move {
+        let relays: Vec<Value> = client
+            .relays()
+            .await
+            .iter()
+            .map(|(url, relay)| json!({ "url": url.to_string(), "status": relay.status().to_string() }))
+            .collect();
+        let _ = reply.send(Ok(json!({
+            "online": offline.is_none(),
+            "offline_message": offline,
+            "mostro_pubkey": mostro_pubkey,
+            "transport": transport,
+            "relays": relays,
+        })));
+    });
+}
+
+fn send_chat(
+    app: &mut AppState,
+    ctx: &LocalApiContext,
+    params: SendChatParams,
+    reply: oneshot::Sender<RpcOutcome>,
+) {
+    let content = params.message.trim().to_string();
+    if content.is_empty() {
+        let _ = reply.send(Err(RpcError::invalid_params("message is empty")));
+        return;
+    }
+    let target = OrderChatTarget {
+        order_id: params.order_id.to_string(),
+        channel: match params.channel {
+            ChatChannelParam::Peer => UserChatChannel::Peer,
+            ChatChannelParam::Solver => UserChatChannel::Solver,
+        },
+    };
+    let local_msg = UserOrderChatMessage {
+        sender: UserChatSender::You,
+        content: content.clone(),
+        timestamp: chrono::Utc::now().timestamp(),
+        attachment: None,
+    };
+    // Same order as the chat input: store locally first, never send what could not be saved.
+    if !persist_local_user_chat_message(app, &target, local_msg) {
+        let _ = reply.send(Err(RpcError::internal(
+            "Failed to save the chat message locally; it was not sent",
+        )));
+        return;
+    }
+    let pool = ctx.pool.clone();
+    let client = ctx.client.clone();
+    let mostro_info = app.mostro_info.clone();
+    tokio::spawn(async move {
+        let outcome = send_user_order_chat(
+            &pool,
+            &client,
+            &target.order_id,
+            target.channel,
+            &content,
+            mostro_info.as_ref(),
+        )
+        .await;
+        let _ = reply.send(match outcome {
+            Ok(()) => Ok(json!({ "result": "sent" })),
+            Err(e) => {
+                log::warn!("Failed to send user {} chat: {}", target.channel, e);
+                Err(RpcError::internal(e.to_string()))
+            }
+        });
+    });
+}
+
+fn trade_action(
+    app: &AppState,
+    ctx: &LocalApiContext,
+    params: TradeActionParams,
+    reply: oneshot::Sender<RpcOutcome>,
+) {
+    let TradeActionParams {
+        order_id,
+        action,
+        rating,
+        invoice,
+    } = params;
+    if action == TradeActionKind::Rate && rating.is_none() {
+        let _ = reply.send(Err(RpcError::invalid_params("rate needs rating")));
+        return;
+    }
+    let invoice = invoice.map(|i| i.trim().to_string()).unwrap_or_default();
+    if action == TradeActionKind::AddInvoice && invoice.is_empty() {
+        let _ = reply.send(Err(RpcError::invalid_params(
+            "add-invoice needs invoice (BOLT11 or Lightning address)",
+        )));
+        return;
+    }
+    // A Lightning address is resolved for the amount of the pending add-invoice request.
+    let expected_sats = app.messages.lock().ok().and_then(|messages| {
+        messages
+            .iter()
+            .find(|m| m.order_id == Some(order_id))
+            .and_then(|m| m.sat_amount)
+    });
+    let ctx_task = ctx.clone();
+    let mostro_info = app.mostro_info.clone();
+    spawn_write(ctx, reply, async move {
+        let ctx = ctx_task;
+        let mostro_info = mostro_info.as_ref();
+        let send = |mostro_action: Action| {
+            execute_send_msg(
+                &order_id,
+                mostro_action,
+                &ctx.pool,
+                &ctx.client,
+                ctx.mostro_pubkey,
+                mostro_info,
+            )
+        };
+        match action {
+            TradeActionKind::FiatSent => send(Action::FiatSent).await,
+            TradeActionKind::Release => send(Action::Release).await,
+            TradeActionKind::Cancel => send(Action::Cancel).await,
+            TradeActionKind::Dispute => {
+                let dispute_id = execute_dispute(
+                    &order_id,
+                    &ctx.pool,
+                    &ctx.client,
+                    ctx.mostro_pubkey,
+                    mostro_info,
+                )
+                .await?;
+                if let Err(e) =
+                    update_order_status(&ctx.pool, &order_id.to_string(), Status::Dispute).await
+                {
+                    log::warn!("Failed to save Dispute status for order {order_id}: {e}");
+                }
+                if let Err(e) = crate::models::Order::update_dispute_id(
+                    &ctx.pool,
+                    &order_id.to_string(),
+                    &dispute_id.to_string(),
+                )
+                .await
+                {
+                    log::warn!("Failed to save dispute id for order {order_id}: {e}");
+                }
+                return Ok(OperationResult::Info(format!(
+                    "Dispute opened. Dispute id: {dispute_id} — give it to the solver."
+                )));
+            }
+            TradeActionKind::Rate => {
+                execute_rate_user(
+                    &order_id,
+                    rating.unwrap_or_default(),
+                    &ctx.pool,
+                    &ctx.client,
+                    ctx.mostro_pubkey,
+                    mostro_info,
+                )
+                .await?;
+                return Ok(OperationResult::Info(
+                    "Rating sent successfully".to_string(),
+                ));
+            }
+            TradeActionKind::AddInvoice => {
+                execute_add_invoice(
+                    &order_id,
+                    &invoice,
+                    expected_sats,
+                    &ctx.pool,
+                    &ctx.client,
+                    ctx.mostro_pubkey,
+                    mostro_info,
+                )
+                .await?;
+                return Ok(OperationResult::InvoiceSubmitted {
+                    message: "Invoice sent successfully".to_string(),
+                    remember_buyer_saved_ln_address_for_order: None,
+                });
+            }
+        }?;
+        Ok(OperationResult::Info(
+            "Message sent successfully".to_string(),
+        ))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_setting_parses_and_rejects_unknown_values() {
+        assert_eq!(
+            LocalApiAccess::from_setting("").unwrap(),
+            LocalApiAccess::Off
+        );
+        assert_eq!(
+            LocalApiAccess::from_setting("Read").unwrap(),
+            LocalApiAccess::Read
+        );
+        assert_eq!(
+            LocalApiAccess::from_setting("read-write").unwrap(),
+            LocalApiAccess::ReadWrite
+        );
+        assert!(LocalApiAccess::from_setting("write").is_err());
+    }
+
+    #[test]
+    fn read_only_access_refuses_write_methods() {
+        let line = r#"{"jsonrpc":"2.0","id":7,"method":"take_order","params":{}}"#;
+        let (id, err) = parse_request(line, LocalApiAccess::Read).unwrap_err();
+        assert_eq!(id, json!(7));
+        assert_eq!(err.code, FORBIDDEN);
+
+        let request = parse_request(line, LocalApiAccess::ReadWrite).unwrap();
+        assert_eq!(request.method, LocalApiMethod::TakeOrder);
+
+        let line = r#"{"jsonrpc":"2.0","id":"a","method":"get_orders"}"#;
+        let request = parse_request(line, LocalApiAccess::Read).unwrap();
+        assert_eq!(request.method, LocalApiMethod::GetOrders);
+        assert_eq!(request.id, json!("a"));
+    }
+
+    #[test]
+    fn malformed_requests_map_to_json_rpc_errors() {
+        let (id, err) = parse_request("{not json", LocalApiAccess::ReadWrite).unwrap_err();
+        assert_eq!((id, err.code), (Value::Null, PARSE_ERROR));
+
+        let (id, err) = parse_request(r#"{"id":3}"#, LocalApiAccess::ReadWrite).unwrap_err();
+        assert_eq!((id, err.code), (json!(3), INVALID_REQUEST));
+
+        let (_, err) =
+            parse_request(r#"{"id":3,"method":"drop_tables"}"#, LocalApiAccess::Read).unwrap_err();
+        assert_eq!(err.code, METHOD_NOT_FOUND);
+
+        let line = response_line(&json!(3), &Err(err));
+        let value: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["error"]["code"], json!(METHOD_NOT_FOUND));
+    }
+
+    #[test]
+    fn new_order_params_fill_the_order_form() {
+        let params: NewOrderParams = parse_params(json!({
+            "kind": "Sell",
+            "fiat_code": "ars",
+            "fiat_amount": 1000,
+            "fiat_amount_max": 5000,
+            "payment_method": "MercadoPago",
+        }))
+        .unwrap();
+        let form = new_order_form(params).unwrap();
+        assert_eq!(form.kind, "sell");
+        assert_eq!(form.fiat_code, "ARS");
+        assert!(form.use_range);
+        assert_eq!(form.fiat_amount_max, "5000");
+        assert_eq!(form.amount, "0");
+        assert_eq!(form.expiration_days, "1");
+
+        let err = parse_params::<NewOrderParams>(json!({ "kind": "buy", "fiat_cod": "USD" }))
+            .err()
+            .unwrap();
+        assert_eq!(err.code, INVALID_PARAMS);
+    }
+
+    #[test]
+    fn operation_results_become_answers() {
+        let ok = operation_result_json(&OperationResult::Info("sent".to_string())).unwrap();
+        assert_eq!(ok, json!({ "result": "info", "message": "sent" }));
+        let err = operation_result_json(&OperationResult::Error("boom".to_string())).unwrap_err();
+        assert_eq!(err.code, INTERNAL_ERROR);
+        assert_eq!(err.message, "boom");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn request_lines_are_capped() {
+        let mut input = b"{\"id\":1}\r\n\n".to_vec();
+        input.extend(std::iter::repeat_n(b'x', MAX_REQUEST_BYTES));
+        input.push(b'\n');
+        input.extend(std::iter::repeat_n(b'x', MAX_REQUEST_BYTES + 1));
+        input.extend(b"\n{}\n");
+        let mut reader = tokio::io::BufReader::new(input.as_slice());
+
+        assert_eq!(
+            read_request_line(&mut reader).await.unwrap().as_deref(),
+            Some("{\"id\":1}")
+        );
+        assert_eq!(
+            read_request_line(&mut reader).await.unwrap().as_deref(),
+            Some("")
+        );
+        assert_eq!(
+            read_request_line(&mut reader)
+                .await
+                .unwrap()
+                .map(|l| l.len()),
+            Some(MAX_REQUEST_BYTES)
+        );
+        let err = read_request_line(&mut reader).await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn socket_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir()
+            .join(format!("mostrix-api-{}", Uuid::new_v4()))
+            .join(LOCAL_API_DIR_NAME);
+        let path = dir.join(LOCAL_API_SOCKET_FILE_NAME);
+        // A directory left world-readable by an earlier run is tightened too.
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let (call_tx, _call_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (events_tx, _) = broadcast::channel(1);
+        let server = start_local_api_server(&path, LocalApiAccess::Read, call_tx, events_tx)
+            .await
+            .unwrap();
+        let mode = |p: &Path| std::fs::metadata(p).unwrap().permissions().mode() & 0o777;
+        assert_eq!(mode(&dir), 0o700);
+        assert_eq!(mode(&path), 0o600);
+        server.abort();
+        let _ = std::fs::remove_dir_all(dir.parent().unwrap());
+    }
+}
@@ -1,25 +1,47 @@
+pub mod attachment_preprocess;
+pub mod automation;
+pub mod backup;
 pub mod blossom;
+pub mod blossom_cleanup;
+pub mod blossom_health;
 pub mod chat_listener;
 pub mod chat_security;
 pub mod chat_utils;
+pub mod clipboard;
 pub mod db_utils;
+pub mod dispute_archive;
 pub mod dm_utils;
 pub mod fatal;
+pub mod fiat;
 pub mod file_validation;
 pub mod filters;
+pub mod image_preview;
 pub mod ln_address;
+pub mod local_api;
 pub mod mostro_info;
 pub mod network;
 pub mod order_utils;
+pub mod payment_methods;
+pub mod proxy;
+pub mod retention;
+pub mod saved_searches;
 pub mod send_attachment;
+pub mod settings_reload;
+pub mod trade_deadlines;
 pub mod types;
 
 // Re-export commonly used items
 pub use crate::ui::helpers::PreparedOrderChatAttachment;
+pub use automation::{
+    automation_firings_for_notification, dismiss_prompt_answered_by_automation,
+    spawn_automation_firing, AutomationContext, AutomationEngine, AutomationFiring,
+};
 pub use blossom::{
     blossom_url_to_https, decrypt_blob, encrypt_blob, fetch_blob, save_attachment_to_disk,
     spawn_save_attachment, upload_blob_with_retry, BLOSSOM_MAX_BLOB_SIZE, DEFAULT_BLOSSOM_SERVERS,
 };
+pub use blossom_cleanup::{run_blossom_cleanup, spawn_blossom_cleanup_task};
+pub use blossom_health::BlossomHealth;
 pub use chat_listener::{
     listen_for_chat_messages, set_chat_router_cmd_tx, track_dispute_chat, track_order_chat,
     track_user_dispute_chat, untrack_dispute_chat, untrack_dispute_chat_parties,
@@ -46,6 +68,10 @@ pub use filters::{
     create_filter, create_mostro_list_fetch_filter, filter_giftwrap_to_recipient,
     filter_protocol_dm_from_mostro, MOSTRO_LIST_FETCH_EVENT_LIMIT,
 };
+pub use local_api::{
+    handle_local_api_call, local_api_socket_path, notification_event_line, start_local_api_server,
+    LocalApiAccess, LocalApiCall, LocalApiContext, LOCAL_API_EVENT_CAPACITY,
+};
 pub use mostro_core::prelude::{unwrap_incoming, wrap_message_with, Transport};
 pub use mostro_info::{
     fetch_mostro_instance_info, fetch_mostro_instance_info_from_settings, format_instance_info_age,
@@ -54,8 +80,18 @@ pub use mostro_info::{
 };
 pub use network::{any_relay_reachable, connect_client_safely};
 pub use order_utils::{fetch_events_list, get_disputes, get_orders, send_new_order, take_order};
+pub use proxy::{active_proxy, http_client, http_client_builder, init_proxy, nostr_client_builder};
+pub use retention::{
+    spawn_retention_preview_task, spawn_retention_purge_task, spawn_retention_task, RetentionPlan,
+    RetentionPolicy,
+};
 pub use send_attachment::{
     blossom_servers_from_settings, send_prepared_order_chat_attachment,
-    spawn_send_order_chat_attachment, SendOrderAttachmentJob,
+    spawn_send_order_chat_attachment, BlossomUploadOptions, PendingOrderAttachment,
+    SendOrderAttachmentJob,
+};
+pub use trade_deadlines::{
+    format_countdown, order_expiry_deadline, popup_deadline, trade_step_deadline, DeadlineKind,
+    DeadlineUrgency, TradeDeadline,
 };
 pub use types::{get_cant_do_description, Event, ListKind};
@@ -2,10 +2,12 @@ use std::time::Duration;
 
 use futures::FutureExt;
 use nostr_sdk::prelude::Client;
-use tokio::net::TcpStream;
-use tokio::time::timeout;
+
+use crate::util::proxy::{active_proxy, connect_tcp};
 
 const RELAY_CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+/// Building a Tor circuit takes longer than a direct TCP handshake.
+const PROXIED_RELAY_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 
 fn relay_host_port(relay: &str) -> Option<(String, u16)> {
     let relay = relay.trim();
@@ -36,15 +38,18 @@ fn relay_host_port(relay: &str) -> Option<(String, u16)> {
 ///
 /// Returns `true` if at least one configured relay host:port accepts a TCP connection
 /// within a short timeout. This avoids calling `nostr-sdk` connect paths that may panic
-/// when the machine has no network.
+/// when the machine has no network. With a `proxy` configured the probe goes through it,
+/// so it never reveals our address to the relay.
 pub async fn any_relay_reachable(relays: &[String]) -> bool {
+    let limit = match active_proxy() {
+        Some(_) => PROXIED_RELAY_CONNECT_TIMEOUT,
+        None => RELAY_CONNECT_TIMEOUT,
+    };
     for relay in relays {
         let Some((host, port)) = relay_host_port(relay) else {
             continue;
         };
-        let addr = format!("{host}:{port}");
-        let attempt = timeout(RELAY_CONNECT_TIMEOUT, TcpStream::connect(addr)).await;
-        if matches!(attempt, Ok(Ok(_))) {
+        if connect_tcp(&host, port, limit).await.is_ok() {
             return true;
         }
     }
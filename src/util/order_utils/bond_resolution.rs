@@ -49,6 +49,21 @@ impl BondSlashChoice {
         }
     }
 
+    /// Stable name stored in `admin_disputes.bond_resolution`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::SlashBuyer => "slash-buyer",
+            Self::SlashSeller => "slash-seller",
+            Self::SlashBoth => "slash-both",
+        }
+    }
+
+    /// Inverse of [`Self::as_str`]; `None` for unknown values.
+    pub fn from_stored(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == value)
+    }
+
     /// Returns `true` if the seller's bond should be slashed.
     pub fn slash_seller(self) -> bool {
         matches!(self, Self::SlashSeller | Self::SlashBoth)
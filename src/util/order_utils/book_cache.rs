@@ -0,0 +1,265 @@
+//! Persistent order book cache: the latest kind 38383 event per pending order.
+//!
+//! The fetch scheduler renders the cached book as soon as it starts (flagged stale until the
+//! first relay sync lands), then keeps it current with `since`-based incremental fetches and a
+//! periodic full resync. Raw events are stored, not [`SmallOrder`] rows, so decimal `fa` tags
+//! survive a restart. Orders that leave `pending` or pass their expiry are deleted.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use anyhow::Result;
+use mostro_core::prelude::{SmallOrder, Status};
+use nostr_sdk::prelude::{Event, PublicKey};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use super::helper::aggregate_latest_orders_by_id;
+use crate::util::fiat::BookOrder;
+
+/// `true` while the Orders tab shows cached data the relays have not confirmed yet.
+static BOOK_STALE: AtomicBool = AtomicBool::new(false);
+
+/// Whether the in-memory book still comes from the local cache only.
+pub fn book_is_stale() -> bool {
+    BOOK_STALE.load(Ordering::Relaxed)
+}
+
+pub(super) fn set_book_stale(stale: bool) {
+    BOOK_STALE.store(stale, Ordering::Relaxed);
+}
+
+/// Whether `order` is past its book expiry at `now` (orders without `expires_at` never are).
+fn is_expired(order: &SmallOrder, now: i64) -> bool {
+    order.expires_at.is_some_and(|expires_at| expires_at <= now)
+}
+
+/// Pending, unexpired orders of `latest` (the book's view of a relay or cache snapshot).
+pub(super) fn pending_book(latest: HashMap<Uuid, BookOrder>, now: i64) -> HashMap<Uuid, BookOrder> {
+    latest
+        .into_iter()
+        .filter(|(_, o)| o.status == Some(Status::Pending) && !is_expired(o, now))
+        .collect()
+}
+
+/// Apply an incremental relay snapshot to `book`: newer pending versions replace older ones,
+/// any other status removes the order. Expired orders are dropped.
+pub(super) fn merge_into_book(
+    book: &mut HashMap<Uuid, BookOrder>,
+    latest: HashMap<Uuid, BookOrder>,
+    now: i64,
+) {
+    for (id, order) in latest {
+        let newer = book
+            .get(&id)
+            .is_none_or(|existing| order.created_at >= existing.created_at);
+        if !newer {
+            continue;
+        }
+        if order.status == Some(Status::Pending) {
+            book.insert(id, order);
+        } else {
+            book.remove(&id);
+        }
+    }
+    book.retain(|_, o| !is_expired(o, now));
+}
+
+/// Cached pending book for `mostro_pubkey` and the newest cached event time.
+pub async fn load_cached_book(
+    pool: &SqlitePool,
+    mostro_pubkey: PublicKey,
+    now: i64,
+) -> Result<(HashMap<Uuid, BookOrder>, Option<i64>)> {
+    let rows: Vec<(String,)> =
+        sqlx::query_as("SELECT event_json FROM book_cache WHERE mostro_pubkey = ?")
+            .bind(mostro_pubkey.to_hex())
+            .fetch_all(pool)
+            .await?;
+    let events: BTreeSet<Event> = rows
+        .iter()
+        .filter_map(|(json,)| match Event::from_json(json) {
+            Ok(event) => Some(event),
+            Err(e) => {
+                log::warn!("[book_cache] skipping unreadable cached event: {}", e);
+                None
+            }
+        })
+        .collect();
+    let newest = events.iter().map(|e| e.created_at.as_secs() as i64).max();
+    Ok((
+        pending_book(aggregate_latest_orders_by_id(&events), now),
+        newest,
+    ))
+}
+
+/// Persist a relay snapshot. A `full` snapshot replaces the cache for `mostro_pubkey`;
+/// otherwise each order's row is upserted (pending) or deleted (any other status).
+/// Expired rows are pruned either way.
+pub async fn store_book_events(
+    pool: &SqlitePool,
+    mostro_pubkey: PublicKey,
+    events: &BTreeSet<Event>,
+    full: bool,
+    now: i64,
+) -> Result<()> {
+    let latest = aggregate_latest_orders_by_id(events);
+    let latest_events: HashMap<Uuid, &Event> = events
+        .iter()
+        .filter_map(|e| Some((Uuid::parse_str(&e.tags.identifier()?).ok()?, e)))
+        .fold(HashMap::new(), |mut acc, (id, e)| {
+            let keep = acc
+                .get(&id)
+                .is_none_or(|kept: &&Event| e.created_at > kept.created_at);
+            if keep {
+                acc.insert(id, e);
+            }
+            acc
+        });
+    let pubkey = mostro_pubkey.to_hex();
+
+    let mut tx = pool.begin().await?;
+    if full {
+        sqlx::query("DELETE FROM book_cache WHERE mostro_pubkey = ?")
+            .bind(&pubkey)
+            .execute(&mut *tx)
+            .await?;
+    }
+    for (id, order) in &latest {
+        let order_id = id.to_string();
+        let created_at = order.created_at.unwrap_or(0);
+        let pending = order.status == Some(Status::Pending) && !is_expired(order, now);
+        match latest_events.get(id) {
+            Some(event) if pending => {
+                sqlx::query(
+                    r#"
+                    INSERT INTO book_cache
+                        (mostro_pubkey, order_id, created_at, expires_at, event_json)
+                    VALUES (?, ?, ?, ?, ?)
+                    ON CONFLICT(mostro_pubkey, order_id) DO UPDATE SET
+                        created_at = excluded.created_at,
+                        expires_at = excluded.expires_at,
+                        event_json = excluded.event_json
+                    WHERE excluded.created_at >= book_cache.created_at
+                    "#,
+                )
+                .bind(&pubkey)
+                .bind(&order_id)
+                .bind(created_at)
+                .bind(order.expires_at)
+                .bind(event.as_json())
+                .execute(&mut *tx)
+                .await?;
+            }
+            _ => {
+                sqlx::query(
+                    "DELETE FROM book_cache WHERE mostro_pubkey = ? AND order_id = ? AND created_at <= ?",
+                )
+                .bind(&pubkey)
+                .bind(&order_id)
+                .bind(created_at)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+    }
+    sqlx::query("DELETE FROM book_cache WHERE expires_at IS NOT NULL AND expires_at <= ?")
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nostr_sdk::prelude::*;
+
+    async fn cache_pool() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        crate::db::create_book_cache_table(&pool).await.unwrap();
+        pool
+    }
+
+    fn order_event(keys: &Keys, id: Uuid, status: &str, fa: &str, created_at: u64) -> Event {
+        let tag = |kind: &str, value: &str| Tag::custom(kind, vec![value.to_string()]);
+        EventBuilder::new(Kind::Custom(38383), "")
+            .tags([
+                Tag::identifier(id.to_string()),
+                tag("k", "sell"),
+                tag("f", "EUR"),
+                tag("s", status),
+                tag("amt", "0"),
+                tag("fa", fa),
+                tag("pm", "SEPA"),
+                tag("premium", "1"),
+            ])
+            .custom_created_at(Timestamp::from(created_at))
+            .finalize(keys)
+            .expect("sign event")
+    }
+
+    #[tokio::test]
+    async fn cache_keeps_latest_pending_events_and_prunes_the_rest() {
+        let pool = cache_pool().await;
+        let keys = Keys::generate();
+        let (kept, taken) = (Uuid::new_v4(), Uuid::new_v4());
+        let first: BTreeSet<Event> = [
+            order_event(&keys, kept, "pending", "12.5", 100),
+            order_event(&keys, taken, "pending", "30", 100),
+        ]
+        .into_iter()
+        .collect();
+        store_book_events(&pool, keys.public_key(), &first, true, 150)
+            .await
+            .unwrap();
+
+        let update: BTreeSet<Event> = [order_event(&keys, taken, "in-progress", "30", 200)]
+            .into_iter()
+            .collect();
+        store_book_events(&pool, keys.public_key(), &update, false, 250)
+            .await
+            .unwrap();
+
+        let (book, newest) = load_cached_book(&pool, keys.public_key(), 300)
+            .await
+            .unwrap();
+        assert_eq!(newest, Some(100));
+        assert_eq!(book.len(), 1);
+        let order = &book[&kept];
+        assert_eq!(order.fiat_amount, 12);
+        assert_eq!(order.fiat.amount.to_string(), "12.50");
+        let (other_instance, _) = load_cached_book(&pool, Keys::generate().public_key(), 300)
+            .await
+            .unwrap();
+        assert!(other_instance.is_empty());
+    }
+
+    #[test]
+    fn merge_replaces_newer_versions_and_drops_closed_or_expired_orders() {
+        let pending = |id: Uuid, created_at: i64, expires_at: Option<i64>| {
+            BookOrder::whole(SmallOrder {
+                id: Some(id),
+                status: Some(Status::Pending),
+                created_at: Some(created_at),
+                expires_at,
+                ..Default::default()
+            })
+        };
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut book: HashMap<Uuid, BookOrder> = [
+            (a, pending(a, 100, None)),
+            (b, pending(b, 100, None)),
+            (c, pending(c, 100, Some(150))),
+        ]
+        .into_iter()
+        .collect();
+        let mut canceled = pending(b, 200, None);
+        canceled.order.status = Some(Status::Canceled);
+        let stale_a = pending(a, 50, None);
+        merge_into_book(&mut book, [(a, stale_a), (b, canceled)].into(), 160);
+        assert_eq!(book.len(), 1);
+        assert_eq!(book[&a].created_at, Some(100));
+    }
+}
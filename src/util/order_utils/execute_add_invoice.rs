@@ -1,7 +1,6 @@
 // Execute add invoice / add bond payout invoice functionality
 use anyhow::Result;
 use lightning_invoice::Bolt11Invoice as Invoice;
-use lnurl::lightning_address::LightningAddress;
 use mostro_core::prelude::*;
 use nostr_sdk::prelude::*;
 use std::str::FromStr;
@@ -11,6 +10,9 @@ use crate::models::Order;
 use crate::ui::orders::{order_message_to_notification, OperationResult, OrderMessage};
 use crate::util::db_utils::{save_order, update_order_status};
 use crate::util::dm_utils::{parse_dm_events, send_dm, wait_for_dm, FETCH_EVENTS_TIMEOUT};
+use crate::util::ln_address::{
+    parse_payout_invoice_input, resolve_payout_payment_request, PayoutInvoiceInput,
+};
 use crate::util::mostro_info::MostroInstanceInfo;
 use crate::util::order_utils::helper::{
     build_order_chat_static_header, handle_mostro_response, inferred_status_from_trade_action,
@@ -32,21 +34,25 @@ fn is_valid_invoice(payment_request: &str) -> Result<Invoice, anyhow::Error> {
     Ok(invoice)
 }
 
-async fn payment_request_payload_for_invoice(invoice: &str) -> Result<Option<Payload>> {
-    let ln_addr = LightningAddress::from_str(invoice.trim());
-    if ln_addr.is_ok() {
-        crate::util::ln_address::ln_address_pay_request_reachable(invoice.trim())
-            .await
-            .map_err(|e| anyhow::anyhow!("Lightning address not verified: {}", e))?;
-        Ok(Some(Payload::PaymentRequest(
-            None,
-            invoice.trim().to_string(),
-            None,
-        )))
-    } else {
-        match is_valid_invoice(invoice) {
+/// Build the `PaymentRequest` payload from popup input (BOLT11, Lightning address, LNURL or a
+/// `lightning:` / BIP-21 URI wrapping one). `expected_sats` is the amount shown in the popup and
+/// is checked against the LNURL-pay sendable range when known.
+async fn payment_request_payload_for_invoice(
+    invoice: &str,
+    expected_sats: Option<i64>,
+) -> Result<Option<Payload>> {
+    let input = parse_payout_invoice_input(invoice)
+        .map_err(|e| anyhow::anyhow!("Invalid invoice: {}", e))?;
+    match input {
+        PayoutInvoiceInput::Bolt11(bolt11) => match is_valid_invoice(&bolt11) {
             Ok(i) => Ok(Some(Payload::PaymentRequest(None, i.to_string(), None))),
             Err(e) => Err(anyhow::anyhow!("Invalid invoice: {}", e)),
+        },
+        PayoutInvoiceInput::LightningAddress(_) | PayoutInvoiceInput::Lnurl(_) => {
+            let payment_request = resolve_payout_payment_request(&input, expected_sats)
+                .await
+                .map_err(|e| anyhow::anyhow!("Lightning address not verified: {}", e))?;
+            Ok(Some(Payload::PaymentRequest(None, payment_request, None)))
         }
     }
 }
@@ -284,9 +290,11 @@ async fn operation_result_from_bond_invoice_reply(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn execute_payment_request_reply(
     order_id: &Uuid,
     invoice: &str,
+    expected_sats: Option<i64>,
     action: Action,
     pool: &sqlx::sqlite::SqlitePool,
     client: &Client,
@@ -299,7 +307,7 @@ async fn execute_payment_request_reply(
         .clone()
         .ok_or(anyhow::anyhow!("Missing trade keys"))?;
     let order_trade_keys = Keys::parse(&trade_keys)?;
-    let payload = payment_request_payload_for_invoice(invoice).await?;
+    let payload = payment_request_payload_for_invoice(invoice, expected_sats).await?;
 
     let request_id = Uuid::new_v4().as_u128() as u64;
     let message = Message::new_order(
@@ -338,7 +346,10 @@ async fn execute_payment_request_reply(
     let inner_message = handle_mostro_response(response_message, request_id)?;
 
     match inner_message.action {
-        Action::WaitingSellerToPay | Action::HoldInvoicePaymentAccepted => Ok(()),
+        // `InvoiceUpdated`: replacement invoice after `PaymentFailed` (order stays settled-hold-invoice).
+        Action::WaitingSellerToPay
+        | Action::HoldInvoicePaymentAccepted
+        | Action::InvoiceUpdated => Ok(()),
         _ => Err(anyhow::anyhow!(
             "Unexpected action: {:?}",
             inner_message.action
@@ -349,6 +360,7 @@ async fn execute_payment_request_reply(
 async fn execute_bond_payment_request_reply(
     order_id: &Uuid,
     invoice: &str,
+    expected_sats: Option<i64>,
     pool: &sqlx::sqlite::SqlitePool,
     client: &Client,
     mostro_pubkey: PublicKey,
@@ -360,7 +372,7 @@ async fn execute_bond_payment_request_reply(
         .clone()
         .ok_or(anyhow::anyhow!("Missing trade keys"))?;
     let order_trade_keys = Keys::parse(&trade_keys)?;
-    let payload = payment_request_payload_for_invoice(invoice).await?;
+    let payload = payment_request_payload_for_invoice(invoice, expected_sats).await?;
 
     let request_id = Uuid::new_v4().as_u128() as u64;
     let message = Message::new_order(
@@ -424,6 +436,7 @@ async fn execute_bond_payment_request_reply(
 pub async fn execute_add_invoice(
     order_id: &Uuid,
     invoice: &str,
+    expected_sats: Option<i64>,
     pool: &sqlx::sqlite::SqlitePool,
     client: &Client,
     mostro_pubkey: PublicKey,
@@ -432,6 +445,7 @@ pub async fn execute_add_invoice(
     execute_payment_request_reply(
         order_id,
         invoice,
+        expected_sats,
         Action::AddInvoice,
         pool,
         client,
@@ -444,6 +458,7 @@ pub async fn execute_add_invoice(
 pub async fn execute_add_bond_invoice(
     order_id: &Uuid,
     invoice: &str,
+    expected_sats: Option<i64>,
     pool: &sqlx::sqlite::SqlitePool,
     client: &Client,
     mostro_pubkey: PublicKey,
@@ -452,6 +467,7 @@ pub async fn execute_add_bond_invoice(
     execute_bond_payment_request_reply(
         order_id,
         invoice,
+        expected_sats,
         pool,
         client,
         mostro_pubkey,
@@ -122,6 +122,10 @@ pub async fn execute_finalize_dispute(
     } else {
         AdminDispute::set_status_settled(pool, &dispute.id).await?;
     }
+    // A cooperative cancel closed the order before our message, so no bonds were resolved.
+    if !cooperatively_canceled {
+        AdminDispute::set_bond_resolution(pool, &dispute.id, bond).await?;
+    }
 
     // Dispute left InProgress: drop buyer/seller shared-key chat subscriptions.
     untrack_dispute_chat_parties(&dispute_id_str);
@@ -1,4 +1,4 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashMap};
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
@@ -10,11 +10,17 @@ use tokio::time::{interval_at, Duration, Instant};
 
 use crate::settings::Settings;
 use crate::util::catch_unwind_request_fatal_restart;
+use crate::util::dm_utils::try_notify_saved_search_match;
+use crate::util::fiat::BookOrder;
+use crate::util::saved_searches::match_live_order;
 use sqlx::SqlitePool;
 
+use super::book_cache::{
+    load_cached_book, merge_into_book, pending_book, set_book_stale, store_book_events,
+};
 use super::get_disputes;
 use super::helper::{
-    aggregate_latest_orders_by_id, fetch_mostro_order_events, pending_orders_for_book,
+    aggregate_latest_orders_by_id, fetch_mostro_order_events_since, pending_orders_for_book,
 };
 use super::relay_dispute_db_reconcile::{
     reconcile_one_admin_dispute_if_terminal, reconcile_terminal_admin_disputes_from_relay,
@@ -28,7 +34,7 @@ use super::relay_order_db_reconcile::{
 /// Result of starting the fetch scheduler
 /// Contains shared state for orders and disputes that are periodically updated
 pub struct FetchSchedulerResult {
-    pub orders: Arc<Mutex<Vec<SmallOrder>>>,
+    pub orders: Arc<Mutex<Vec<BookOrder>>>,
     pub disputes: Arc<Mutex<Vec<Dispute>>>,
     /// Background task for periodic order fetches; abort and call [`spawn_fetch_scheduler_loops`]
     /// after a soft client reload so polls use the new session.
@@ -39,8 +45,36 @@ pub struct FetchSchedulerResult {
 
 // Semaphore to prevent multiple chat messages from being processed at the same time
 const RECONCILIATION_INTERVAL_SECS: u64 = 30;
+/// Polls between full order book resyncs (10 minutes); other polls only fetch events
+/// published since the previous one.
+const FULL_BOOK_RESYNC_TICKS: u32 = 20;
+/// Incremental fetches reach back this far past the previous sync (relay clock skew).
+const BOOK_SYNC_OVERLAP_SECS: i64 = 120;
+
+/// Replace the shared book with the currency-filtered pending orders of `book`.
+///
+/// Returns `false` after requesting a fatal restart (poisoned lock).
+fn publish_book(
+    orders: &Arc<Mutex<Vec<BookOrder>>>,
+    book: &HashMap<Uuid, BookOrder>,
+    currencies: Vec<String>,
+) -> bool {
+    let fetched_orders = pending_orders_for_book(book, Some(currencies));
+    let mut orders_lock = match orders.lock() {
+        Ok(g) => g,
+        Err(e) => {
+            crate::util::request_fatal_restart(format!(
+                "Mostrix encountered an internal error while reconciling orders (poisoned orders lock: {e}). Please restart the app."
+            ));
+            return false;
+        }
+    };
+    orders_lock.clear();
+    orders_lock.extend(fetched_orders);
+    true
+}
 
-fn apply_live_order_update(orders: &Arc<Mutex<Vec<SmallOrder>>>, order: SmallOrder) {
+fn apply_live_order_update(orders: &Arc<Mutex<Vec<BookOrder>>>, order: BookOrder) {
     let Some(order_id) = order.id else {
         return;
     };
@@ -63,6 +97,7 @@ fn apply_live_order_update(orders: &Arc<Mutex<Vec<SmallOrder>>>, order: SmallOrd
         return;
     }
 
+    let saved_search_match = match_live_order(&order);
     if let Some(existing) = orders_lock
         .iter_mut()
         .find(|existing| existing.id == Some(order_id))
@@ -81,6 +116,15 @@ fn apply_live_order_update(orders: &Arc<Mutex<Vec<SmallOrder>>>, order: SmallOrd
         order_id,
         orders_lock.len()
     );
+    drop(orders_lock);
+    if let Some(found) = saved_search_match {
+        log::info!(
+            "[orders_live] order_id={} matched saved search(es) {:?}",
+            order_id,
+            found.searches
+        );
+        try_notify_saved_search_match(found);
+    }
 }
 
 fn apply_live_dispute_update_inner(disputes: &mut Vec<Dispute>, dispute: Dispute) {
@@ -136,7 +180,7 @@ pub fn start_fetch_scheduler(
     settings: &Settings,
     pool: SqlitePool,
 ) -> FetchSchedulerResult {
-    let orders: Arc<Mutex<Vec<SmallOrder>>> = Arc::new(Mutex::new(Vec::new()));
+    let orders: Arc<Mutex<Vec<BookOrder>>> = Arc::new(Mutex::new(Vec::new()));
     let disputes: Arc<Mutex<Vec<Dispute>>> = Arc::new(Mutex::new(Vec::new()));
 
     let (order_task, dispute_task) = spawn_fetch_scheduler_loops(
@@ -163,7 +207,7 @@ pub fn start_fetch_scheduler(
 pub fn spawn_fetch_scheduler_loops(
     client: Client,
     current_mostro_pubkey: Arc<Mutex<PublicKey>>,
-    orders: Arc<Mutex<Vec<SmallOrder>>>,
+    orders: Arc<Mutex<Vec<BookOrder>>>,
     disputes: Arc<Mutex<Vec<Dispute>>>,
     settings: &Settings,
     pool: SqlitePool,
@@ -203,7 +247,35 @@ pub fn spawn_fetch_scheduler_loops(
                 }
             }
 
-            // Reconcile from relay every 30s (immediate first poll, then periodic).
+            // Render the cached book right away; it stays flagged stale until a relay sync lands.
+            set_book_stale(true);
+            let mut book: HashMap<Uuid, BookOrder> = HashMap::new();
+            let mut synced_until: Option<i64> = None;
+            let mut ticks_since_full_sync: u32 = 0;
+            match load_cached_book(
+                &pool_for_orders,
+                mostro_pubkey_for_order_subscribe,
+                Timestamp::now().as_secs() as i64,
+            )
+            .await
+            {
+                Ok((cached, newest)) => {
+                    log::debug!("[book_cache] loaded cached pending orders count={}", cached.len());
+                    book = cached;
+                    synced_until = newest;
+                    if !publish_book(
+                        &orders_clone,
+                        &book,
+                        reloaded_settings.currencies_filter.clone(),
+                    ) {
+                        return;
+                    }
+                }
+                Err(e) => log::warn!("[book_cache] failed to load cached order book: {}", e),
+            }
+
+            // Sync from relay every 30s (immediate first poll, then periodic): `since` the last
+            // sync, with a full resync every FULL_BOOK_RESYNC_TICKS polls.
             let mut refresh_interval = interval_at(
                 Instant::now(),
                 Duration::from_secs(RECONCILIATION_INTERVAL_SECS),
@@ -229,13 +301,28 @@ pub fn spawn_fetch_scheduler_loops(
                             }
                         };
 
-                        match fetch_mostro_order_events(
+                        let full = synced_until.is_none()
+                            || ticks_since_full_sync >= FULL_BOOK_RESYNC_TICKS;
+                        let since = synced_until
+                            .filter(|_| !full)
+                            .map(|t| Timestamp::from((t - BOOK_SYNC_OVERLAP_SECS).max(0) as u64));
+                        let fetch_started = Timestamp::now().as_secs() as i64;
+                        match fetch_mostro_order_events_since(
                             &client_for_orders,
                             mostro_pubkey_for_orders,
+                            since,
                         )
                         .await
                         {
-                            Ok(events) => {
+                            Ok((events, complete)) => {
+                                // A capped full resync is only a partial snapshot: merge it
+                                // rather than dropping cached orders it did not reach.
+                                let replace = full && complete;
+                                if full && !complete {
+                                    log::warn!(
+                                        "[orders_reconcile] full sync hit the page cap; merging into the cached book"
+                                    );
+                                }
                                 let latest_map = aggregate_latest_orders_by_id(&events);
                                 if let Err(e) = reconcile_terminal_order_statuses_from_relay(
                                     &pool_for_orders,
@@ -248,22 +335,35 @@ pub fn spawn_fetch_scheduler_loops(
                                         e
                                     );
                                 }
-                                let fetched_orders =
-                                    pending_orders_for_book(&latest_map, Some(currencies));
-                                let mut orders_lock = match orders_clone.lock() {
-                                    Ok(g) => g,
-                                    Err(e) => {
-                                        crate::util::request_fatal_restart(format!(
-                                            "Mostrix encountered an internal error while reconciling orders (poisoned orders lock: {e}). Please restart the app."
-                                        ));
-                                        return;
-                                    }
-                                };
-                                orders_lock.clear();
-                                orders_lock.extend(fetched_orders);
+                                if let Err(e) = store_book_events(
+                                    &pool_for_orders,
+                                    mostro_pubkey_for_orders,
+                                    &events,
+                                    replace,
+                                    fetch_started,
+                                )
+                                .await
+                                {
+                                    log::warn!("[book_cache] failed to store order events: {}", e);
+                                }
+                                if replace {
+                                    book = pending_book(latest_map, fetch_started);
+                                } else {
+                                    merge_into_book(&mut book, latest_map, fetch_started);
+                                }
+                                if full {
+                                    ticks_since_full_sync = 0;
+                                }
+                                synced_until = Some(fetch_started);
+                                if !publish_book(&orders_clone, &book, currencies) {
+                                    return;
+                                }
+                                set_book_stale(false);
                                 log::debug!(
-                                    "[orders_reconcile] refreshed pending orders count={}",
-                                    orders_lock.len()
+                                    "[orders_reconcile] {} sync: events={} pending={}",
+                                    if full { "full" } else { "incremental" },
+                                    events.len(),
+                                    book.len()
                                 );
                             }
                             Err(e) => log::warn!(
@@ -271,6 +371,7 @@ pub fn spawn_fetch_scheduler_loops(
                                 e
                             ),
                         }
+                        ticks_since_full_sync += 1;
 
                         if let Err(e) = run_targeted_relay_order_db_reconcile_tick(
                             &client_for_orders,
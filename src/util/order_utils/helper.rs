@@ -9,7 +9,8 @@ use uuid::Uuid;
 use crate::ui::state::{OperationResult, OrderChatStaticHeader, OrderSuccess, TakeOrderState};
 use crate::util::db_utils::save_order;
 use crate::util::dm_utils::FETCH_EVENTS_TIMEOUT;
-use crate::util::filters::create_filter;
+use crate::util::fiat::{BookOrder, FiatAmount, OrderFiat};
+use crate::util::filters::{create_filter, MOSTRO_LIST_FETCH_EVENT_LIMIT};
 use crate::util::types::{get_cant_do_description, Event, ListKind};
 use crate::util::OrderDmSubscriptionCmd;
 use sqlx::SqlitePool;
@@ -19,9 +20,21 @@ use tokio::sync::mpsc::UnboundedSender;
 /// Nostr events from relays (distinct from [`Event`] in `util::types`).
 type NostrEvents = BTreeSet<nostr_sdk::prelude::Event>;
 
+/// Page cap for [`fetch_mostro_order_events_since`] (20 × 500 events).
+const MAX_ORDER_FETCH_PAGES: usize = 20;
+
 /// Parse order from nostr tags
+///
+/// Decimal `fa` values are rounded to whole units; [`book_order_from_tags`] keeps them.
 pub fn order_from_tags(tags: Tags) -> Result<SmallOrder> {
+    Ok(book_order_from_tags(tags)?.order)
+}
+
+/// Parse a book listing from nostr tags: whole-unit `SmallOrder` fields plus the exact
+/// decimal `fa` amounts.
+pub fn book_order_from_tags(tags: Tags) -> Result<BookOrder> {
     let mut order = SmallOrder::default();
+    let mut fiat_tag: Vec<String> = Vec::new();
 
     for tag in tags {
         let t = tag.to_vec(); // Vec<String>
@@ -50,16 +63,9 @@ pub fn order_from_tags(tags: Tags) -> Result<SmallOrder> {
             "amt" => {
                 order.amount = v.parse::<i64>().unwrap_or(0);
             }
+            // Resolved after the loop: precision depends on the `f` tag.
             "fa" => {
-                if v.contains('.') {
-                    continue;
-                }
-                if let Some(max_str) = values.get(1) {
-                    order.min_amount = v.parse::<i64>().ok();
-                    order.max_amount = max_str.parse::<i64>().ok();
-                } else {
-                    order.fiat_amount = v.parse::<i64>().unwrap_or(0);
-                }
+                fiat_tag = values.to_vec();
             }
             "pm" => {
                 order.payment_method = values.join(",");
@@ -67,11 +73,23 @@ pub fn order_from_tags(tags: Tags) -> Result<SmallOrder> {
             "premium" => {
                 order.premium = v.parse::<i64>().unwrap_or(0);
             }
+            "expires_at" => {
+                order.expires_at = v.parse::<i64>().ok().or(order.expires_at);
+            }
+            // NIP-40 expiration; only used when Mostro did not send `expires_at`.
+            "expiration" => {
+                order.expires_at = order.expires_at.or(v.parse::<i64>().ok());
+            }
             _ => {}
         }
     }
 
-    Ok(order)
+    if fiat_tag.is_empty() {
+        return Ok(BookOrder::whole(order));
+    }
+    let fiat = OrderFiat::from_tag(&fiat_tag, &order.fiat_code);
+    (order.fiat_amount, order.min_amount, order.max_amount) = fiat.whole_fields();
+    Ok(BookOrder { order, fiat })
 }
 
 /// Infer `Status` from the message `action` when there is no `SmallOrder` payload
@@ -84,7 +102,12 @@ pub fn inferred_status_from_trade_action(action: &Action) -> Option<Status> {
         Action::WaitingSellerToPay | Action::PayInvoice => Some(Status::WaitingPayment),
         Action::PayBondInvoice => Some(Status::WaitingTakerBond),
         Action::AdminCanceled => Some(Status::CanceledByAdmin),
+        // The seller's hold invoice was canceled (e.g. it expired before settlement).
+        Action::HoldInvoicePaymentCanceled => Some(Status::Canceled),
         Action::FiatSentOk => Some(Status::FiatSent),
+        // Hold invoice settled but paying the buyer invoice failed; Mostro keeps the order in
+        // `settled-hold-invoice` while it retries or waits for a new invoice.
+        Action::PaymentFailed | Action::InvoiceUpdated => Some(Status::SettledHoldInvoice),
         // Release ACK to seller (`hold-invoice-payment-settled`), buyer `released` /
         // `purchase-completed`: trade complete from Mostro's view → Success / Rate column.
         Action::Release
@@ -123,14 +146,16 @@ fn status_phase_rank_for_actor(
             Some(mostro_core::order::Kind::Sell) => Some(2),
             None => None,
         },
-        Status::WaitingBuyerInvoice | Status::SettledHoldInvoice => match kind {
+        Status::WaitingBuyerInvoice => match kind {
             Some(mostro_core::order::Kind::Buy) => Some(2),
             Some(mostro_core::order::Kind::Sell) => Some(1),
             None => None,
         },
         Status::InProgress | Status::Active => Some(3),
         Status::FiatSent => Some(4),
-        Status::Success => Some(5),
+        // Released but the buyer payout is still pending (retries / new invoice).
+        Status::SettledHoldInvoice => Some(5),
+        Status::Success => Some(6),
         _ => None,
     }
 }
@@ -202,6 +227,24 @@ pub fn should_apply_status_transition(
     }
 }
 
+/// Whether a payout-failure DM may pull a locally completed trade back to `settled-hold-invoice`.
+///
+/// The buyer's `released` DM is mapped to [`Status::Success`] before Mostro has actually paid the
+/// buyer invoice. If that payment then fails, `payment-failed` / `invoice-updated` / a fresh
+/// `add-invoice` must reopen the trade instead of being dropped by the sticky terminal rule.
+pub fn payment_failure_reopens_trade(
+    current: Option<Status>,
+    candidate: Status,
+    action: &Action,
+) -> bool {
+    current == Some(Status::Success)
+        && candidate == Status::SettledHoldInvoice
+        && matches!(
+            action,
+            Action::PaymentFailed | Action::InvoiceUpdated | Action::AddInvoice
+        )
+}
+
 /// Like [`should_apply_status_transition`], but never treats **equal** status as an advance.
 ///
 /// Use when an **older** Nostr `timestamp` must not replace the Messages row unless the payload
@@ -220,43 +263,49 @@ pub fn should_strictly_advance_status(
 }
 
 /// Validates the range amount input against min/max limits.
+///
+/// Decimals are accepted up to the currency's minor units and compared exactly,
+/// but Mostro's `Amount` payload is whole units, so a fractional amount is rejected.
 pub fn validate_range_amount(take_state: &mut TakeOrderState) {
     if take_state.amount_input.is_empty() {
         take_state.validation_error = None;
         return;
     }
 
-    let amount = match take_state.amount_input.parse::<f64>() {
-        Ok(val) if val.is_finite() => val,
-        Ok(_) => {
-            take_state.validation_error = Some("Invalid number format".to_string());
-            return;
-        }
-        Err(_) => {
-            take_state.validation_error = Some("Invalid number format".to_string());
+    let fiat = take_state.order.fiat_code.clone();
+    let amount = match FiatAmount::parse_for(&take_state.amount_input, &fiat) {
+        Ok(amount) => amount,
+        Err(e) => {
+            take_state.validation_error = Some(e);
             return;
         }
     };
 
-    let min_opt = take_state.order.min_amount.map(|m| m as f64);
-    let max_opt = take_state.order.max_amount.map(|m| m as f64);
-
-    let below_min = min_opt.is_some_and(|min| amount < min);
-    let above_max = max_opt.is_some_and(|max| amount > max);
+    let range = take_state.order.fiat;
+    let below_min = range.min.is_some_and(|min| amount < min);
+    let above_max = range.max.is_some_and(|max| amount > max);
 
-    if below_min || above_max {
-        let fiat = &take_state.order.fiat_code;
-        take_state.validation_error = Some(match (min_opt, max_opt) {
+    take_state.validation_error = if below_min || above_max {
+        Some(match (range.min, range.max) {
             (Some(min), Some(max)) => {
                 format!("Amount must be between {} and {} {}", min, max, fiat)
             }
             (Some(min), None) => format!("Amount must be at least {} {}", min, fiat),
             (None, Some(max)) => format!("Amount must be at most {} {}", max, fiat),
             (None, None) => "Amount is outside allowed range".to_string(),
-        });
+        })
+    } else if amount.whole_units().is_none() {
+        Some(format!("Mostro takes whole {} amounts only", fiat))
     } else {
-        take_state.validation_error = None;
-    }
+        None
+    };
+}
+
+/// Whole-unit fiat amount to send when taking a range order (`None` when not valid).
+pub fn range_take_amount(take_state: &TakeOrderState) -> Option<i64> {
+    FiatAmount::parse_for(&take_state.amount_input, &take_state.order.fiat_code)
+        .ok()?
+        .whole_units()
 }
 
 /// Parse dispute from nostr tags.
@@ -346,14 +395,14 @@ pub fn parse_disputes_events(events: NostrEvents) -> Vec<Dispute> {
     disputes_list
 }
 
-/// Latest [`SmallOrder`] per order id from Mostro nostr order events (newest event wins).
+/// Latest listing per order id from Mostro nostr order events (newest event wins).
 ///
 /// Does not apply currency, status, or kind filters — use [`parse_orders_events`] for that.
-pub fn aggregate_latest_orders_by_id(events: &NostrEvents) -> HashMap<Uuid, SmallOrder> {
-    let mut latest_by_id: HashMap<Uuid, SmallOrder> = HashMap::new();
+pub fn aggregate_latest_orders_by_id(events: &NostrEvents) -> HashMap<Uuid, BookOrder> {
+    let mut latest_by_id: HashMap<Uuid, BookOrder> = HashMap::new();
 
     for event in events.iter() {
-        let mut order = match order_from_tags(event.tags.clone()) {
+        let mut order = match book_order_from_tags(event.tags.clone()) {
             Ok(o) => o,
             Err(e) => {
                 log::error!("{e:?}");
@@ -371,7 +420,7 @@ pub fn aggregate_latest_orders_by_id(events: &NostrEvents) -> HashMap<Uuid, Smal
             log::info!("Order kind is none");
             continue;
         }
-        order.created_at = Some(event.created_at.as_secs() as i64);
+        order.order.created_at = Some(event.created_at.as_secs() as i64);
         latest_by_id
             .entry(order_id)
             .and_modify(|existing| {
@@ -393,10 +442,10 @@ pub fn parse_orders_events(
     currencies: Option<Vec<String>>,
     status: Option<Status>,
     kind: Option<mostro_core::order::Kind>,
-) -> Vec<SmallOrder> {
+) -> Vec<BookOrder> {
     let latest_by_id = aggregate_latest_orders_by_id(&events);
 
-    let mut requested: Vec<SmallOrder> = latest_by_id
+    let mut requested: Vec<BookOrder> = latest_by_id
         .into_values()
         .filter(|o| status.map(|s| o.status == Some(s)).unwrap_or(true))
         .filter(|o| {
@@ -423,21 +472,93 @@ pub fn parse_orders_events(
 /// Events are filtered client-side to include only those authored by `mostro_pubkey`, since relay-side
 /// author filtering cannot be trusted.
 ///
-/// Relay queries are capped (see [`crate::util::filters::MOSTRO_LIST_FETCH_EVENT_LIMIT`]); very old
-/// order updates may not be included in the snapshot.
+/// Full snapshot: pages past [`crate::util::filters::MOSTRO_LIST_FETCH_EVENT_LIMIT`] with
+/// [`fetch_mostro_order_events_since`].
 pub async fn fetch_mostro_order_events(
     client: &Client,
     mostro_pubkey: PublicKey,
 ) -> Result<NostrEvents> {
-    let filters = create_filter(ListKind::Orders, mostro_pubkey, None)?;
-    let events = client
-        .fetch_events(filters)
-        .timeout(FETCH_EVENTS_TIMEOUT)
-        .await?;
-    Ok(events
-        .into_iter()
-        .filter(|e| e.pubkey == mostro_pubkey)
-        .collect())
+    Ok(fetch_mostro_order_events_since(client, mostro_pubkey, None)
+        .await?
+        .0)
+}
+
+/// Mostro order events published at or after `since` (all of them for `None`), and whether
+/// paging reached the end before [`MAX_ORDER_FETCH_PAGES`].
+///
+/// Relays return at most [`crate::util::filters::MOSTRO_LIST_FETCH_EVENT_LIMIT`] events per
+/// query; see [`page_order_events`] for how full pages are followed.
+pub async fn fetch_mostro_order_events_since(
+    client: &Client,
+    mostro_pubkey: PublicKey,
+    since: Option<Timestamp>,
+) -> Result<(NostrEvents, bool)> {
+    let (mut all, complete) = page_order_events(
+        MOSTRO_LIST_FETCH_EVENT_LIMIT,
+        MAX_ORDER_FETCH_PAGES,
+        |until| async move {
+            let mut filter = create_filter(ListKind::Orders, mostro_pubkey, None)?;
+            if let Some(since) = since {
+                filter = filter.since(since);
+            }
+            if let Some(until) = until {
+                filter = filter.until(until);
+            }
+            Ok(client
+                .fetch_events(filter)
+                .timeout(FETCH_EVENTS_TIMEOUT)
+                .await?
+                .into_iter()
+                .collect())
+        },
+    )
+    .await?;
+    all.retain(|e| e.pubkey == mostro_pubkey);
+    Ok((all, complete))
+}
+
+/// Collect pages from `fetch_page(until)` until one comes back short of `limit`.
+///
+/// Each next page ends at the oldest event seen (`until` is inclusive), so events sharing the
+/// boundary second are kept; the set dedupes them by event id. A full page with nothing new
+/// means that second alone holds more than `limit` events, so paging steps one second past it.
+/// Returns `false` alongside the events when `max_pages` ran out first.
+async fn page_order_events<F, Fut>(
+    limit: usize,
+    max_pages: usize,
+    mut fetch_page: F,
+) -> Result<(NostrEvents, bool)>
+where
+    F: FnMut(Option<Timestamp>) -> Fut,
+    Fut: std::future::Future<Output = Result<NostrEvents>>,
+{
+    let mut all = NostrEvents::new();
+    let mut until: Option<Timestamp> = None;
+    for _ in 0..max_pages {
+        let page = fetch_page(until).await?;
+        let full = page.len() >= limit;
+        let Some(oldest) = page.iter().map(|e| e.created_at).min() else {
+            return Ok((all, true));
+        };
+        let known = all.len();
+        all.extend(page);
+        if !full {
+            return Ok((all, true));
+        }
+        if all.len() > known {
+            until = Some(oldest);
+        } else if oldest.as_secs() == 0 {
+            return Ok((all, true));
+        } else {
+            log::warn!(
+                "[orders] more than {} order events at {}; some may be missing",
+                limit,
+                oldest.as_secs()
+            );
+            until = Some(Timestamp::from(oldest.as_secs() - 1));
+        }
+    }
+    Ok((all, false))
 }
 
 /// Pending listings for the public order book from an aggregated relay snapshot.
@@ -445,10 +566,10 @@ pub async fn fetch_mostro_order_events(
 /// Applies the same currency rules as [`parse_orders_events`] when `status` is pending-only:
 /// empty `currencies` list means no filter; `None` means no filter.
 pub fn pending_orders_for_book(
-    latest: &HashMap<Uuid, SmallOrder>,
+    latest: &HashMap<Uuid, BookOrder>,
     currencies: Option<Vec<String>>,
-) -> Vec<SmallOrder> {
-    let mut requested: Vec<SmallOrder> = latest
+) -> Vec<BookOrder> {
+    let mut requested: Vec<BookOrder> = latest
         .values()
         .filter(|o| {
             o.status == Some(Status::Pending)
@@ -477,7 +598,10 @@ pub async fn fetch_events_list(
         ListKind::Orders => {
             let fetched_events = fetch_mostro_order_events(client, mostro_pubkey).await?;
             let orders = parse_orders_events(fetched_events, currencies, status, kind);
-            Ok(orders.into_iter().map(Event::SmallOrder).collect())
+            Ok(orders
+                .into_iter()
+                .map(|listing| Event::SmallOrder(listing.order))
+                .collect())
         }
         ListKind::Disputes => {
             let filters = create_filter(list_kind, mostro_pubkey, None)?;
@@ -504,12 +628,12 @@ pub async fn get_orders(
     currencies: Option<Vec<String>>,
 ) -> Result<Vec<SmallOrder>> {
     let fetched_events = fetch_mostro_order_events(client, mostro_pubkey).await?;
-    Ok(parse_orders_events(
-        fetched_events,
-        currencies,
-        status,
-        None,
-    ))
+    Ok(
+        parse_orders_events(fetched_events, currencies, status, None)
+            .into_iter()
+            .map(|listing| listing.order)
+            .collect(),
+    )
 }
 
 /// Fetch disputes from the Mostro network
@@ -798,11 +922,14 @@ pub(super) fn handle_mostro_response(
 #[cfg(test)]
 mod tests {
     use super::{
-        admin_finalize_ack, dispute_from_tags, handle_mostro_response,
-        inferred_status_from_trade_action, is_terminal_trade_status, parse_disputes_events,
-        should_apply_status_transition, should_strictly_advance_status, AdminFinalizeAck,
+        admin_finalize_ack, book_order_from_tags, dispute_from_tags, handle_mostro_response,
+        inferred_status_from_trade_action, is_terminal_trade_status, order_from_tags,
+        page_order_events, parse_disputes_events, payment_failure_reopens_trade, range_take_amount,
+        should_apply_status_transition, should_strictly_advance_status, validate_range_amount,
+        AdminFinalizeAck,
     };
     use crate::models::TERMINAL_ORDER_HISTORY_STATUSES;
+    use crate::ui::state::TakeOrderState;
     use mostro_core::prelude::{Action, DisputeStatus, Message, Status, NOSTR_DISPUTE_EVENT_KIND};
     use nostr_sdk::prelude::*;
     use std::collections::BTreeSet;
@@ -844,6 +971,92 @@ mod tests {
         assert_eq!(dispute.created_at, 1_700_000_100);
     }
 
+    #[test]
+    fn order_from_tags_keeps_decimal_fiat_amounts() {
+        let id = Uuid::new_v4();
+        let tags = Tags::from_list(vec![
+            Tag::identifier(id.to_string()),
+            Tag::custom("fa", vec!["10.5".to_string(), "99.90".to_string()]),
+            Tag::custom("f", vec!["EUR".to_string()]),
+        ]);
+        let order = book_order_from_tags(tags).unwrap();
+        assert_eq!(
+            (order.fiat_amount, order.min_amount, order.max_amount),
+            (0, Some(11), Some(99))
+        );
+        let fiat = order.fiat;
+        assert_eq!(fiat.min.unwrap().to_string(), "10.50");
+        assert_eq!(fiat.max.unwrap().to_string(), "99.90");
+
+        let tags = Tags::from_list(vec![
+            Tag::custom("f", vec!["KWD".to_string()]),
+            Tag::custom("fa", vec!["12.345".to_string()]),
+        ]);
+        assert_eq!(order_from_tags(tags).unwrap().fiat_amount, 12);
+    }
+
+    #[test]
+    fn validate_range_amount_compares_decimals_and_requires_whole_units() {
+        let id = Uuid::new_v4();
+        let tags = Tags::from_list(vec![
+            Tag::identifier(id.to_string()),
+            Tag::custom("f", vec!["EUR".to_string()]),
+            Tag::custom("fa", vec!["10.50".to_string(), "20".to_string()]),
+        ]);
+        let mut take_state = TakeOrderState {
+            order: book_order_from_tags(tags).unwrap(),
+            amount_input: "10.25".to_string(),
+            is_range_order: true,
+            validation_error: None,
+            selected_button: true,
+        };
+        let mut check = |input: &str| {
+            take_state.amount_input = input.to_string();
+            validate_range_amount(&mut take_state);
+            (
+                take_state.validation_error.clone(),
+                range_take_amount(&take_state),
+            )
+        };
+
+        assert_eq!(
+            check("10.25").0.as_deref(),
+            Some("Amount must be between 10.50 and 20 EUR")
+        );
+        assert_eq!(
+            check("12.5").0.as_deref(),
+            Some("Mostro takes whole EUR amounts only")
+        );
+        assert_eq!(
+            check("12.505").0.as_deref(),
+            Some("EUR amounts have at most 2 decimals")
+        );
+        assert_eq!(check("abc").0.as_deref(), Some("Invalid number format"));
+        assert_eq!(check("11"), (None, Some(11)));
+        assert_eq!(check("15.00"), (None, Some(15)));
+    }
+
+    #[test]
+    fn order_from_tags_reads_expires_at_and_falls_back_to_nip40_expiration() {
+        let tags = Tags::from_list(vec![
+            Tag::custom("expiration", vec!["1700090000".to_string()]),
+            Tag::custom("expires_at", vec!["1700086400".to_string()]),
+        ]);
+        assert_eq!(
+            order_from_tags(tags).unwrap().expires_at,
+            Some(1_700_086_400)
+        );
+
+        let tags = Tags::from_list(vec![Tag::custom(
+            "expiration",
+            vec!["1700090000".to_string()],
+        )]);
+        assert_eq!(
+            order_from_tags(tags).unwrap().expires_at,
+            Some(1_700_090_000)
+        );
+    }
+
     #[test]
     fn dispute_from_tags_ignores_invalid_or_non_positive_created_at() {
         let id = Uuid::new_v4();
@@ -927,6 +1140,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn payment_failure_actions_map_to_settled_hold_invoice_after_fiat_sent() {
+        for action in [Action::PaymentFailed, Action::InvoiceUpdated] {
+            assert_eq!(
+                inferred_status_from_trade_action(&action),
+                Some(Status::SettledHoldInvoice)
+            );
+        }
+        assert_eq!(
+            inferred_status_from_trade_action(&Action::HoldInvoicePaymentCanceled),
+            Some(Status::Canceled)
+        );
+        for kind in [
+            mostro_core::order::Kind::Buy,
+            mostro_core::order::Kind::Sell,
+        ] {
+            assert!(should_apply_status_transition(
+                Some(Status::FiatSent),
+                Status::SettledHoldInvoice,
+                Some(kind)
+            ));
+            assert!(!should_apply_status_transition(
+                Some(Status::SettledHoldInvoice),
+                Status::WaitingBuyerInvoice,
+                Some(kind)
+            ));
+        }
+    }
+
+    #[test]
+    fn payment_failure_reopens_locally_completed_trade_only() {
+        assert!(payment_failure_reopens_trade(
+            Some(Status::Success),
+            Status::SettledHoldInvoice,
+            &Action::PaymentFailed
+        ));
+        assert!(!payment_failure_reopens_trade(
+            Some(Status::Success),
+            Status::SettledHoldInvoice,
+            &Action::HoldInvoicePaymentSettled
+        ));
+        assert!(!payment_failure_reopens_trade(
+            Some(Status::Canceled),
+            Status::SettledHoldInvoice,
+            &Action::PaymentFailed
+        ));
+    }
+
     #[test]
     fn terminal_order_history_statuses_match_is_terminal_trade_status() {
         let terminal_variants = [
@@ -1087,4 +1348,55 @@ mod tests {
             .expect_err("mismatched request_id must be rejected");
         assert!(err.to_string().contains("Mismatched request_id"));
     }
+
+    /// Relay stand-in: the newest `limit` events at or before `until`.
+    fn relay_page(events: &[Event], limit: usize, until: Option<Timestamp>) -> BTreeSet<Event> {
+        let mut matching: Vec<&Event> = events
+            .iter()
+            .filter(|e| until.is_none_or(|until| e.created_at <= until))
+            .collect();
+        matching.sort_by_key(|e| (std::cmp::Reverse(e.created_at), e.id));
+        matching.into_iter().take(limit).cloned().collect()
+    }
+
+    fn note_at(keys: &Keys, created_at: u64, content: &str) -> Event {
+        EventBuilder::new(Kind::Custom(38383), content)
+            .custom_created_at(Timestamp::from(created_at))
+            .finalize(keys)
+            .expect("sign event")
+    }
+
+    #[tokio::test]
+    async fn paging_keeps_boundary_second_and_steps_past_a_full_one() {
+        let keys = Keys::generate();
+        let mut events = vec![note_at(&keys, 110, "newest")];
+        events.extend((0..4).map(|i| note_at(&keys, 100, &i.to_string())));
+        events.extend([note_at(&keys, 90, "older"), note_at(&keys, 80, "oldest")]);
+
+        let (all, complete) = page_order_events(3, 10, |until| {
+            let page = relay_page(&events, 3, until);
+            async move { Ok(page) }
+        })
+        .await
+        .unwrap();
+        assert!(complete);
+        for content in ["newest", "older", "oldest"] {
+            assert!(all.iter().any(|e| e.content == content), "{content}");
+        }
+        assert!(all.iter().filter(|e| e.created_at.as_secs() == 100).count() >= 3);
+    }
+
+    #[tokio::test]
+    async fn paging_reports_an_incomplete_snapshot_at_the_page_cap() {
+        let keys = Keys::generate();
+        let events: Vec<Event> = (0..6u64).map(|t| note_at(&keys, 100 + t, "")).collect();
+        let (all, complete) = page_order_events(2, 2, |until| {
+            let page = relay_page(&events, 2, until);
+            async move { Ok(page) }
+        })
+        .await
+        .unwrap();
+        assert!(!complete);
+        assert_eq!(all.len(), 3);
+    }
 }
@@ -1,5 +1,6 @@
 // Order utilities module
 mod bond_resolution;
+mod book_cache;
 mod execute_add_invoice;
 mod execute_admin_add_solver;
 mod execute_admin_cancel;
@@ -16,6 +17,7 @@ mod take_order;
 
 // Re-export public functions
 pub use bond_resolution::BondSlashChoice;
+pub use book_cache::{book_is_stale, load_cached_book, store_book_events};
 pub use execute_add_invoice::{execute_add_bond_invoice, execute_add_invoice};
 pub use execute_admin_add_solver::execute_admin_add_solver;
 pub use execute_admin_cancel::execute_admin_cancel;
@@ -26,12 +28,14 @@ pub use execute_take_dispute::execute_take_dispute;
 pub use fetch_scheduler::{
     spawn_fetch_scheduler_loops, start_fetch_scheduler, FetchSchedulerResult,
 };
+pub(crate) use helper::is_terminal_trade_status;
 pub use helper::{
-    aggregate_latest_orders_by_id, dispute_from_tags, fetch_events_list, fetch_mostro_order_events,
-    get_disputes, get_orders, inferred_status_from_trade_action, map_action_to_status,
-    order_from_tags, parse_disputes_events, parse_orders_events, pending_orders_for_book,
-    should_apply_status_transition, should_strictly_advance_status, validate_range_amount,
-    AdminFinalizeAck,
+    aggregate_latest_orders_by_id, book_order_from_tags, dispute_from_tags, fetch_events_list,
+    fetch_mostro_order_events, fetch_mostro_order_events_since, get_disputes, get_orders,
+    inferred_status_from_trade_action, map_action_to_status, order_from_tags,
+    parse_disputes_events, parse_orders_events, payment_failure_reopens_trade,
+    pending_orders_for_book, range_take_amount, should_apply_status_transition,
+    should_strictly_advance_status, validate_range_amount, AdminFinalizeAck,
 };
 pub use relay_dispute_db_reconcile::{
     apply_terminal_relay_statuses_to_admin_disputes, reconcile_one_admin_dispute_if_terminal,
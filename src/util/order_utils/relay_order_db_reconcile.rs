@@ -10,6 +10,7 @@ use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
 use crate::models::Order;
+use crate::util::fiat::BookOrder;
 
 use super::helper::{
     aggregate_latest_orders_by_id, fetch_mostro_order_events, fetch_small_order_by_id_from_relay,
@@ -22,7 +23,7 @@ pub const TARGETED_RELAY_RECONCILE_MAX_PER_TICK: usize = 5;
 /// Fetch latest order snapshots from relays and apply [`reconcile_one_order_if_terminal`] for each entry.
 pub async fn reconcile_terminal_order_statuses_from_relay(
     pool: &SqlitePool,
-    relay_latest: &HashMap<Uuid, SmallOrder>,
+    relay_latest: &HashMap<Uuid, BookOrder>,
 ) -> Result<()> {
     for relay_order in relay_latest.values() {
         reconcile_one_order_if_terminal(pool, relay_order).await;
@@ -209,7 +210,7 @@ mod tests {
             .await
             .unwrap();
 
-        let mut relay_latest: HashMap<Uuid, SmallOrder> = HashMap::new();
+        let mut relay_latest: HashMap<Uuid, BookOrder> = HashMap::new();
         relay_latest.insert(
             oid,
             SmallOrder {
@@ -222,7 +223,8 @@ mod tests {
                 payment_method: "sepa".to_string(),
                 premium: 0,
                 ..Default::default()
-            },
+            }
+            .into(),
         );
 
         reconcile_terminal_order_statuses_from_relay(&pool, &relay_latest)
@@ -271,7 +273,7 @@ mod tests {
         .unwrap();
 
         let oid = Uuid::new_v4();
-        let mut relay_latest: HashMap<Uuid, SmallOrder> = HashMap::new();
+        let mut relay_latest: HashMap<Uuid, BookOrder> = HashMap::new();
         relay_latest.insert(
             oid,
             SmallOrder {
@@ -284,7 +286,8 @@ mod tests {
                 payment_method: "x".to_string(),
                 premium: 0,
                 ..Default::default()
-            },
+            }
+            .into(),
         );
 
         reconcile_terminal_order_statuses_from_relay(&pool, &relay_latest)
@@ -9,10 +9,13 @@ use crate::models::User;
 use crate::ui::FormState;
 use crate::util::db_utils::save_order;
 use crate::util::dm_utils::{parse_dm_events, send_dm, wait_for_dm, FETCH_EVENTS_TIMEOUT};
+use crate::util::fiat::FiatAmount;
 use crate::util::mostro_info::MostroInstanceInfo;
 use crate::util::order_utils::helper::{
     create_order_result_success, handle_mostro_response, payment_request_operation_result,
 };
+use crate::util::payment_methods::payment_methods;
+use crate::util::proxy::http_client;
 use crate::util::OrderDmSubscriptionCmd;
 use sqlx::SqlitePool;
 use tokio::sync::mpsc::UnboundedSender;
@@ -40,10 +43,12 @@ pub async fn send_new_order(
 
     let amount: i64 = form.amount.trim().parse().unwrap_or(0);
 
-    // Check if fiat currency is available on Yadio if amount is 0
-    if amount == 0 {
+    // Check if fiat currency is available on Yadio if amount is 0 (demo mode stays offline)
+    if amount == 0 && !crate::demo::is_demo_mode() {
         let api_req_string = "https://api.yadio.io/currencies".to_string();
-        let fiat_list_check = reqwest::get(api_req_string)
+        let fiat_list_check = http_client()?
+            .get(api_req_string)
+            .send()
             .await?
             .json::<HashMap<String, String>>()
             .await?
@@ -66,18 +71,28 @@ pub async fn send_new_order(
         }
     };
 
-    // Handle fiat amount (single or range)
+    // Handle fiat amount (single or range); `NewOrder` carries whole fiat units.
+    let whole_fiat = |raw: &str| -> Result<i64> {
+        let amount = FiatAmount::parse_for(raw, &fiat_code).map_err(|e| anyhow::anyhow!(e))?;
+        amount.whole_units().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Mostro orders take whole {} amounts; {} has a fractional part",
+                fiat_code,
+                amount
+            )
+        })
+    };
     let (fiat_amount, min_amount, max_amount) =
         if form.use_range && !form.fiat_amount_max.trim().is_empty() {
-            let min: i64 = form.fiat_amount.trim().parse().unwrap_or(0);
-            let max: i64 = form.fiat_amount_max.trim().parse().unwrap_or(0);
+            let min = whole_fiat(&form.fiat_amount)?;
+            let max = whole_fiat(&form.fiat_amount_max)?;
             (0, Some(min), Some(max))
         } else {
-            let fiat: i64 = form.fiat_amount.trim().parse().unwrap_or(0);
-            (fiat, None, None)
+            (whole_fiat(&form.fiat_amount)?, None, None)
         };
 
-    let payment_method = form.payment_method.trim().to_string();
+    // Publish catalog spellings so the book does not fill up with "zelle " / "ZELLE" variants.
+    let payment_method = payment_methods().normalize_list(&form.payment_method);
     let premium: i64 = form.premium.trim().parse().unwrap_or(0);
     let invoice = if form.invoice.trim().is_empty() {
         None
@@ -0,0 +1,395 @@
+//! Payment method catalog: canonical names, spelling aliases and per-currency suggestions.
+//!
+//! Order events carry free-text `pm` tags, so the book mixes "Zelle", "zelle " and "ZELLE".
+//! Names are compared on a folded key (lowercase, accents stripped, only letters and digits),
+//! so those variants — and "Mercado Pago" vs "MercadoPago" — resolve to one canonical entry.
+//! Real aliases ("TransferWise" → "Wise") are listed per method.
+//!
+//! The built-in list can be extended from `~/.mostrix/payment_methods.toml`. An entry whose
+//! name (or alias) matches a built-in method adds currencies and aliases to it; any other
+//! entry is a new method. Methods without `currencies` are suggested for every fiat code.
+//!
+//! ```toml
+//! [[methods]]
+//! name = "Zelle"
+//! aliases = ["zelle pay"]
+//!
+//! [[methods]]
+//! name = "Sinpe Móvil"
+//! currencies = ["CRC"]
+//! aliases = ["sinpe"]
+//! ```
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use anyhow::Result;
+use serde::Deserialize;
+
+pub const PAYMENT_METHODS_FILE_NAME: &str = "payment_methods.toml";
+
+static CATALOG: OnceLock<PaymentMethodCatalog> = OnceLock::new();
+
+/// One catalog entry.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct PaymentMethod {
+    pub name: String,
+    /// Fiat codes this method is suggested for; empty = all.
+    #[serde(default)]
+    pub currencies: Vec<String>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl PaymentMethod {
+    fn serves(&self, fiat_code: &str) -> bool {
+        self.currencies
+            .iter()
+            .any(|c| c.eq_ignore_ascii_case(fiat_code))
+    }
+
+    fn keys(&self) -> impl Iterator<Item = String> + '_ {
+        std::iter::once(&self.name)
+            .chain(self.aliases.iter())
+            .map(|n| method_key(n))
+    }
+}
+
+struct BuiltinMethod {
+    name: &'static str,
+    currencies: &'static [&'static str],
+    aliases: &'static [&'static str],
+}
+
+const fn method(
+    name: &'static str,
+    currencies: &'static [&'static str],
+    aliases: &'static [&'static str],
+) -> BuiltinMethod {
+    BuiltinMethod {
+        name,
+        currencies,
+        aliases,
+    }
+}
+
+/// Methods commonly seen on Mostro books. Spelling variants that fold to the same key need
+/// no alias.
+const BUILTIN_METHODS: &[BuiltinMethod] = &[
+    method(
+        "Bank Transfer",
+        &[],
+        &[
+            "bank",
+            "wire",
+            "wire transfer",
+            "transferencia bancaria",
+            "transferencia",
+            "transferência bancária",
+        ],
+    ),
+    method("Cash", &[], &["cash in person", "efectivo", "dinheiro"]),
+    method("PayPal", &[], &[]),
+    method("Revolut", &[], &[]),
+    method("Wise", &[], &["transferwise"]),
+    method("Zelle", &["USD"], &[]),
+    method("Cash App", &["USD"], &["square cash"]),
+    method("Venmo", &["USD"], &[]),
+    method("Strike", &["USD"], &[]),
+    method("SEPA", &["EUR"], &["sepa transfer", "sepa instant"]),
+    method("Bizum", &["EUR"], &[]),
+    method("N26", &["EUR"], &[]),
+    method("Faster Payments", &["GBP"], &["fps", "uk bank transfer"]),
+    method("Monzo", &["GBP"], &[]),
+    method(
+        "Interac e-Transfer",
+        &["CAD"],
+        &["interac", "e-transfer", "etransfer"],
+    ),
+    method("PayID", &["AUD"], &["osko"]),
+    method("MercadoPago", &["ARS", "MXN", "UYU", "CLP"], &[]),
+    method("Ualá", &["ARS"], &[]),
+    method("Brubank", &["ARS"], &[]),
+    method("PIX", &["BRL"], &[]),
+    method("SPEI", &["MXN"], &[]),
+    method("OXXO", &["MXN"], &[]),
+    method("Pago Móvil", &["VES"], &[]),
+    method("Zinli", &["VES", "USD"], &[]),
+    method("Nequi", &["COP"], &[]),
+    method("Daviplata", &["COP"], &[]),
+    method("Bancolombia", &["COP"], &[]),
+    method("Yape", &["PEN"], &[]),
+    method("Plin", &["PEN"], &[]),
+    method("Transfermóvil", &["CUP"], &[]),
+    method("EnZona", &["CUP"], &[]),
+    method("UPI", &["INR"], &[]),
+    method("IMPS", &["INR"], &[]),
+    method("M-Pesa", &["KES", "TZS"], &[]),
+];
+
+#[derive(Debug, Default, Deserialize)]
+struct PaymentMethodsFile {
+    #[serde(default)]
+    methods: Vec<PaymentMethod>,
+}
+
+/// Built-in methods plus the user's `payment_methods.toml` additions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentMethodCatalog {
+    methods: Vec<PaymentMethod>,
+}
+
+impl PaymentMethodCatalog {
+    pub fn builtin() -> Self {
+        let methods = BUILTIN_METHODS
+            .iter()
+            .map(|m| PaymentMethod {
+                name: m.name.to_string(),
+                currencies: m.currencies.iter().map(|c| c.to_string()).collect(),
+                aliases: m.aliases.iter().map(|a| a.to_string()).collect(),
+            })
+            .collect();
+        Self { methods }
+    }
+
+    /// Built-in catalog extended with `payment_methods.toml` contents.
+    pub fn from_toml(raw: &str) -> Result<Self> {
+        let file: PaymentMethodsFile = toml::from_str(raw)
+            .map_err(|e| anyhow::anyhow!("payment_methods.toml malformed: {}", e))?;
+        let mut catalog = Self::builtin();
+        for entry in file.methods {
+            catalog.merge(entry)?;
+        }
+        Ok(catalog)
+    }
+
+    /// Load `~/.mostrix/payment_methods.toml`; a missing file means the built-in list.
+    pub fn load_from_disk() -> Result<Self> {
+        let Some(path) = payment_methods_path() else {
+            return Ok(Self::builtin());
+        };
+        if !path.exists() {
+            return Ok(Self::builtin());
+        }
+        let raw = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow::anyhow!("could not read {}: {}", path.display(), e))?;
+        Self::from_toml(&raw)
+    }
+
+    fn merge(&mut self, entry: PaymentMethod) -> Result<()> {
+        let name = collapse_whitespace(&entry.name);
+        if method_key(&name).is_empty() {
+            anyhow::bail!("payment method name cannot be empty");
+        }
+        let currencies = entry.currencies.iter().map(|c| c.trim().to_uppercase());
+        let key = method_key(&name);
+        let existing = self.methods.iter_mut().find(|m| m.keys().any(|k| k == key));
+        let target = match existing {
+            Some(method) => method,
+            None => {
+                self.methods.push(PaymentMethod {
+                    name,
+                    currencies: Vec::new(),
+                    aliases: Vec::new(),
+                });
+                self.methods.last_mut().expect("just pushed")
+            }
+        };
+        for code in currencies.filter(|c| !c.is_empty()) {
+            if !target.serves(&code) {
+                target.currencies.push(code);
+            }
+        }
+        for alias in entry.aliases {
+            if !method_key(&alias).is_empty() && !target.keys().any(|k| k == method_key(&alias)) {
+                target.aliases.push(alias);
+            }
+        }
+        Ok(())
+    }
+
+    fn lookup(&self, raw: &str) -> Option<&PaymentMethod> {
+        let key = method_key(raw);
+        if key.is_empty() {
+            return None;
+        }
+        self.methods.iter().find(|m| m.keys().any(|k| k == key))
+    }
+
+    /// Canonical catalog name for `raw`, or `raw` trimmed with whitespace collapsed.
+    pub fn canonical(&self, raw: &str) -> String {
+        match self.lookup(raw) {
+            Some(method) => method.name.clone(),
+            None => collapse_whitespace(raw),
+        }
+    }
+
+    /// Canonicalize a comma-separated `pm` list, dropping blanks and duplicates.
+    pub fn normalize_list(&self, raw: &str) -> String {
+        let mut names: Vec<String> = Vec::new();
+        for part in raw.split(',') {
+            let name = self.canonical(part);
+            if !name.is_empty() && !names.iter().any(|n| method_key(n) == method_key(&name)) {
+                names.push(name);
+            }
+        }
+        names.join(",")
+    }
+
+    /// `true` when the `pm` list `raw` includes `method` (any spelling or alias).
+    pub fn list_contains(&self, raw: &str, method: &str) -> bool {
+        let wanted = method_key(&self.canonical(method));
+        raw.split(',')
+            .any(|part| method_key(&self.canonical(part)) == wanted)
+    }
+
+    /// Picker options for `fiat_code` whose name or an alias contains `query` (folded):
+    /// methods for that currency first, then the ones offered everywhere.
+    pub fn suggestions(&self, fiat_code: &str, query: &str) -> Vec<&str> {
+        let query = method_key(query);
+        let hits = |m: &&PaymentMethod| query.is_empty() || m.keys().any(|k| k.contains(&query));
+        let local = self.methods.iter().filter(|m| m.serves(fiat_code.trim()));
+        let global = self.methods.iter().filter(|m| m.currencies.is_empty());
+        local
+            .chain(global)
+            .filter(hits)
+            .map(|m| m.name.as_str())
+            .collect()
+    }
+}
+
+/// Install the catalog used by the order form and the book (first call wins).
+pub fn init_payment_methods(catalog: PaymentMethodCatalog) {
+    let _ = CATALOG.set(catalog);
+}
+
+/// The installed catalog, or the built-in list when none was installed.
+pub fn payment_methods() -> &'static PaymentMethodCatalog {
+    CATALOG.get_or_init(PaymentMethodCatalog::builtin)
+}
+
+/// `~/.mostrix/payment_methods.toml`, or `None` when the home directory is unknown.
+pub fn payment_methods_path() -> Option<PathBuf> {
+    Some(crate::settings::data_dir()?.join(PAYMENT_METHODS_FILE_NAME))
+}
+
+/// Add `name` to the comma-separated `list`, or remove it when already listed.
+pub fn toggle_in_list(list: &str, name: &str) -> String {
+    let key = method_key(name);
+    let mut names: Vec<String> = list
+        .split(',')
+        .map(collapse_whitespace)
+        .filter(|n| !n.is_empty())
+        .collect();
+    let before = names.len();
+    names.retain(|n| method_key(n) != key);
+    if names.len() == before && !key.is_empty() {
+        names.push(collapse_whitespace(name));
+    }
+    names.join(",")
+}
+
+/// Comparison key: lowercase letters and digits with common Latin accents folded.
+pub fn method_key(raw: &str) -> String {
+    raw.chars()
+        .flat_map(char::to_lowercase)
+        .map(fold_accent)
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+fn fold_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'ñ' => 'n',
+        'ç' => 'c',
+        other => other,
+    }
+}
+
+fn collapse_whitespace(raw: &str) -> String {
+    raw.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spelling_variants_and_aliases_resolve_to_canonical_names() {
+        let catalog = PaymentMethodCatalog::builtin();
+        assert_eq!(catalog.canonical("zelle "), "Zelle");
+        assert_eq!(catalog.canonical("ZELLE"), "Zelle");
+        assert_eq!(catalog.canonical("Mercado Pago"), "MercadoPago");
+        assert_eq!(catalog.canonical("pago movil"), "Pago Móvil");
+        assert_eq!(catalog.canonical("TransferWise"), "Wise");
+        assert_eq!(catalog.canonical("  my   local bank "), "my local bank");
+
+        assert_eq!(
+            catalog.normalize_list("zelle, ZELLE ,cashapp,,Strike"),
+            "Zelle,Cash App,Strike"
+        );
+        assert!(catalog.list_contains("Revolut,zelle", "Zelle"));
+        assert!(!catalog.list_contains("Revolut", "Zelle"));
+    }
+
+    #[test]
+    fn suggestions_put_currency_methods_before_global_ones() {
+        let catalog = PaymentMethodCatalog::builtin();
+        let usd = catalog.suggestions("usd", "");
+        assert_eq!(usd.first(), Some(&"Zelle"));
+        assert!(usd.contains(&"Bank Transfer"));
+        assert!(!usd.contains(&"PIX"));
+        assert_eq!(catalog.suggestions("BRL", "pi"), vec!["PIX"]);
+        assert_eq!(
+            catalog.suggestions("USD", "transfer"),
+            vec!["Bank Transfer", "Wise"]
+        );
+    }
+
+    #[test]
+    fn toml_extends_builtin_methods_and_adds_new_ones() {
+        let catalog = PaymentMethodCatalog::from_toml(
+            r#"
+[[methods]]
+name = "zelle"
+currencies = ["usd", "PAB"]
+aliases = ["zelle pay"]
+
+[[methods]]
+name = "Sinpe Móvil"
+currencies = ["CRC"]
+aliases = ["sinpe"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(catalog.canonical("Zelle Pay"), "Zelle");
+        assert_eq!(
+            catalog.suggestions("PAB", ""),
+            vec![
+                "Zelle",
+                "Bank Transfer",
+                "Cash",
+                "PayPal",
+                "Revolut",
+                "Wise"
+            ]
+        );
+        assert_eq!(catalog.canonical("SINPE"), "Sinpe Móvil");
+        assert_eq!(catalog.suggestions("CRC", "sin"), vec!["Sinpe Móvil"]);
+
+        assert!(PaymentMethodCatalog::from_toml("[[methods]]\nname = \" \"").is_err());
+        assert!(PaymentMethodCatalog::from_toml("methods = 3").is_err());
+    }
+
+    #[test]
+    fn toggle_adds_and_removes_by_folded_name() {
+        assert_eq!(toggle_in_list("", "Zelle"), "Zelle");
+        assert_eq!(toggle_in_list("Zelle", "PayPal"), "Zelle,PayPal");
+        assert_eq!(toggle_in_list("zelle ,PayPal", "Zelle"), "PayPal");
+    }
+}
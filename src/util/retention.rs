@@ -0,0 +1,459 @@
+//! Retention policy: purge finished trades, their chat transcripts and saved attachments after a
+//! configurable number of days.
+//!
+//! A pass first builds a [`RetentionPlan`] (also shown as a preview from Settings), then removes
+//! the order rows and overwrites each file with zeros before unlinking it. On copy-on-write or
+//! flash storage the overwrite is best effort; the unlink is what the app relies on.
+//!
+//! Trades with attachments still listed in `blossom_uploads.json` are left for a later pass: the
+//! row holds the trade key that signs the Blossom delete, so it goes only after the cleanup job.
+
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use anyhow::Result;
+use sqlx::SqlitePool;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::time::{interval, Duration};
+use uuid::Uuid;
+
+use crate::models::Order;
+use crate::settings::{load_settings_from_disk, Settings};
+use crate::ui::OperationResult;
+use crate::util::blossom_cleanup::{blossom_uploads_path_in, orders_with_uploaded_blobs};
+
+/// Per-order transcript folders under `~/.mostrix` (files named `<order_id>.*`).
+const ORDER_CHAT_DIRS: [&str; 2] = ["orders_chat", "user_disputes_chat"];
+/// Saved attachments (`<order_or_dispute_id>_<filename>`).
+const DOWNLOADS_DIR: &str = "downloads";
+
+/// How often the background pass runs (first run right after startup).
+const RETENTION_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Statuses only reached through a dispute resolution.
+const ADMIN_RESOLVED_STATUSES: [&str; 3] = [
+    "settled-by-admin",
+    "canceled-by-admin",
+    "completed-by-admin",
+];
+
+/// Days to keep each kind of data; `0` keeps it forever.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    pub trade_days: u32,
+    pub disputed_trade_days: u32,
+    pub downloads_days: u32,
+}
+
+impl RetentionPolicy {
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            trade_days: settings.retention_trade_days,
+            disputed_trade_days: settings.retention_disputed_trade_days,
+            downloads_days: settings.retention_downloads_days,
+        }
+    }
+
+    /// True when at least one rule purges something.
+    pub fn is_active(&self) -> bool {
+        self.trade_days > 0 || self.disputed_trade_days > 0 || self.downloads_days > 0
+    }
+}
+
+/// One finished trade due for removal, with the files that belong to it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetentionOrder {
+    pub order_id: Uuid,
+    pub status: String,
+    pub disputed: bool,
+    /// Days since the last trade activity.
+    pub age_days: i64,
+    pub files: Vec<PathBuf>,
+}
+
+/// Everything one retention pass would remove.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RetentionPlan {
+    pub policy: RetentionPolicy,
+    pub orders: Vec<RetentionOrder>,
+    /// Old downloads and transcripts of orders no longer in the database.
+    pub loose_files: Vec<PathBuf>,
+    pub total_bytes: u64,
+}
+
+impl RetentionPlan {
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty() && self.loose_files.is_empty()
+    }
+
+    pub fn file_count(&self) -> usize {
+        self.orders.iter().map(|o| o.files.len()).sum::<usize>() + self.loose_files.len()
+    }
+
+    pub fn disputed_count(&self) -> usize {
+        self.orders.iter().filter(|o| o.disputed).count()
+    }
+}
+
+/// What a pass actually removed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RetentionOutcome {
+    pub deleted_order_ids: Vec<Uuid>,
+    pub files_removed: usize,
+    pub bytes_removed: u64,
+    pub failures: usize,
+}
+
+impl RetentionOutcome {
+    pub fn summary(&self) -> String {
+        let mut message = format!(
+            "Retention: removed {} finished trade(s) and {} file(s) ({} KB).",
+            self.deleted_order_ids.len(),
+            self.files_removed,
+            self.bytes_removed.div_ceil(1024)
+        );
+        if self.failures > 0 {
+            message.push_str(&format!(" {} item(s) could not be removed.", self.failures));
+        }
+        message
+    }
+}
+
+/// Data directory of the active profile, where transcripts and downloads live.
+pub fn retention_data_dir() -> Option<PathBuf> {
+    crate::settings::data_dir()
+}
+
+fn is_disputed(order: &Order, status: &str) -> bool {
+    order.dispute_id.as_deref().is_some_and(|d| !d.is_empty())
+        || ADMIN_RESOLVED_STATUSES.contains(&status)
+}
+
+fn file_age_days(path: &Path, now: SystemTime) -> Option<i64> {
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+    let age = now.duration_since(modified).ok()?;
+    Some(age.as_secs() as i64 / SECONDS_PER_DAY)
+}
+
+fn dir_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file())
+        .collect();
+    files.sort();
+    files
+}
+
+fn file_name(path: &Path) -> &str {
+    path.file_name().and_then(|n| n.to_str()).unwrap_or("")
+}
+
+/// Chat transcript and download files that belong to `order_id`.
+fn files_for_order(data_dir: &Path, order_id: &str) -> Vec<PathBuf> {
+    let chat_prefix = format!("{order_id}.");
+    let download_prefix = format!("{order_id}_");
+    let mut files = Vec::new();
+    for dir in ORDER_CHAT_DIRS {
+        files.extend(
+            dir_files(&data_dir.join(dir))
+                .into_iter()
+                .filter(|p| file_name(p).starts_with(&chat_prefix)),
+        );
+    }
+    files.extend(
+        dir_files(&data_dir.join(DOWNLOADS_DIR))
+            .into_iter()
+            .filter(|p| file_name(p).starts_with(&download_prefix)),
+    );
+    files
+}
+
+/// Builds the list of trades and files the policy would remove at `now` (Unix seconds).
+pub async fn plan_retention(
+    pool: &SqlitePool,
+    policy: RetentionPolicy,
+    data_dir: &Path,
+    now: i64,
+) -> Result<RetentionPlan> {
+    let mut plan = RetentionPlan {
+        policy,
+        ..Default::default()
+    };
+    let mut claimed: HashSet<PathBuf> = HashSet::new();
+    let with_blobs = orders_with_uploaded_blobs(&blossom_uploads_path_in(data_dir));
+
+    for order in Order::get_terminal_history_orders(pool).await? {
+        let Some(order_id) = order.id.as_deref().and_then(|id| Uuid::parse_str(id).ok()) else {
+            continue;
+        };
+        if with_blobs.contains(&order_id.to_string()) {
+            continue;
+        }
+        let status = order.status.clone().unwrap_or_default().to_lowercase();
+        let disputed = is_disputed(&order, &status);
+        let keep_days = if disputed {
+            policy.disputed_trade_days
+        } else {
+            policy.trade_days
+        };
+        // Rows without any timestamp have an unknown age and are never purged automatically.
+        let Some(last_activity) = order.last_seen_dm_ts.or(order.created_at) else {
+            continue;
+        };
+        let age_days = (now - last_activity) / SECONDS_PER_DAY;
+        if keep_days == 0 || age_days < i64::from(keep_days) {
+            continue;
+        }
+        let files = files_for_order(data_dir, &order_id.to_string());
+        claimed.extend(files.iter().cloned());
+        plan.orders.push(RetentionOrder {
+            order_id,
+            status,
+            disputed,
+            age_days,
+            files,
+        });
+    }
+
+    let now_time = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(now.max(0) as u64);
+
+    // Transcripts left behind by orders deleted from history (manually or by an earlier pass).
+    if policy.trade_days > 0 {
+        let known: HashSet<String> = Order::list_all_ids(pool).await?.into_iter().collect();
+        for dir in ORDER_CHAT_DIRS {
+            for path in dir_files(&data_dir.join(dir)) {
+                let Some(id) = file_name(&path).split('.').next() else {
+                    continue;
+                };
+                if Uuid::parse_str(id).is_err() || known.contains(id) || claimed.contains(&path) {
+                    continue;
+                }
+                if file_age_days(&path, now_time).is_some_and(|d| d >= policy.trade_days.into()) {
+                    claimed.insert(path.clone());
+                    plan.loose_files.push(path);
+                }
+            }
+        }
+    }
+
+    if policy.downloads_days > 0 {
+        for path in dir_files(&data_dir.join(DOWNLOADS_DIR)) {
+            if claimed.contains(&path) {
+                continue;
+            }
+            if file_age_days(&path, now_time).is_some_and(|d| d >= policy.downloads_days.into()) {
+                plan.loose_files.push(path);
+            }
+        }
+    }
+
+    plan.total_bytes = plan
+        .orders
+        .iter()
+        .flat_map(|o| o.files.iter())
+        .chain(plan.loose_files.iter())
+        .filter_map(|p| fs::metadata(p).ok())
+        .map(|m| m.len())
+        .sum();
+    Ok(plan)
+}
+
+/// Overwrites a file with zeros, flushes it to disk, then removes it. Returns its former size.
+pub fn shred_file(path: &Path) -> io::Result<u64> {
+    let len = fs::metadata(path)?.len();
+    {
+        let mut file = OpenOptions::new().write(true).open(path)?;
+        let zeros = [0u8; 64 * 1024];
+        let mut remaining = len;
+        while remaining > 0 {
+            let n = remaining.min(zeros.len() as u64) as usize;
+            file.write_all(&zeros[..n])?;
+            remaining -= n as u64;
+        }
+        file.sync_all()?;
+    }
+    fs::remove_file(path)?;
+    Ok(len)
+}
+
+fn shred_into(outcome: &mut RetentionOutcome, path: &Path) {
+    match shred_file(path) {
+        Ok(bytes) => {
+            outcome.files_removed += 1;
+            outcome.bytes_removed += bytes;
+        }
+        // Already gone since the plan was built: nothing left to protect.
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => {
+            log::warn!("Retention: could not remove {}: {e}", path.display());
+            outcome.failures += 1;
+        }
+    }
+}
+
+/// Removes what `plan` lists. Order rows are deleted first (still only when terminal); a trade's
+/// files are only shredded once its row is gone. A trade that recorded a Blossom upload after
+/// the plan was built is skipped.
+pub async fn execute_retention(
+    pool: &SqlitePool,
+    plan: &RetentionPlan,
+    data_dir: &Path,
+) -> RetentionOutcome {
+    let mut outcome = RetentionOutcome::default();
+    let with_blobs = orders_with_uploaded_blobs(&blossom_uploads_path_in(data_dir));
+    for order in &plan.orders {
+        if with_blobs.contains(&order.order_id.to_string()) {
+            log::info!(
+                "Retention: order {} still has Blossom uploads to delete; skipped",
+                order.order_id
+            );
+            continue;
+        }
+        match Order::delete_terminal_order_by_id(pool, &order.order_id.to_string()).await {
+            Ok(affected) if affected > 0 => {
+                outcome.deleted_order_ids.push(order.order_id);
+                for path in &order.files {
+                    shred_into(&mut outcome, path);
+                }
+            }
+            Ok(_) => {
+                log::info!(
+                    "Retention: order {} is no longer terminal or already removed; skipped",
+                    order.order_id
+                );
+            }
+            Err(e) => {
+                log::warn!("Retention: could not delete order {}: {e}", order.order_id);
+                outcome.failures += 1;
+            }
+        }
+    }
+    for path in &plan.loose_files {
+        shred_into(&mut outcome, path);
+    }
+    outcome
+}
+
+/// Background preview for the Settings popup; reports [`OperationResult::RetentionPreview`].
+pub fn spawn_retention_preview_task(
+    pool: SqlitePool,
+    policy: RetentionPolicy,
+    order_result_tx: UnboundedSender<OperationResult>,
+) {
+    tokio::spawn(async move {
+        let Some(data_dir) = retention_data_dir() else {
+            let _ = order_result_tx.send(OperationResult::Error(
+                "Cannot locate the ~/.mostrix data directory.".to_string(),
+            ));
+            return;
+        };
+        let now = chrono::Utc::now().timestamp();
+        let result = match plan_retention(&pool, policy, &data_dir, now).await {
+            Ok(plan) => OperationResult::RetentionPreview(Box::new(plan)),
+            Err(e) => OperationResult::Error(format!("Failed to build retention preview: {e}")),
+        };
+        let _ = order_result_tx.send(result);
+    });
+}
+
+/// Runs a confirmed purge from the preview popup; reports [`OperationResult::OrderHistoryDeleted`].
+pub fn spawn_retention_purge_task(
+    pool: SqlitePool,
+    plan: RetentionPlan,
+    order_result_tx: UnboundedSender<OperationResult>,
+) {
+    tokio::spawn(async move {
+        let Some(data_dir) = retention_data_dir() else {
+            let _ = order_result_tx.send(OperationResult::Error(
+                "Cannot locate the ~/.mostrix data directory.".to_string(),
+            ));
+            return;
+        };
+        let outcome = execute_retention(&pool, &plan, &data_dir).await;
+        let _ = order_result_tx.send(OperationResult::OrderHistoryDeleted {
+            message: outcome.summary(),
+            deleted_order_ids: outcome.deleted_order_ids,
+        });
+    });
+}
+
+/// Scheduled purge: right after startup, then every six hours. Settings are re-read from disk on
+/// each pass so edited rules apply without a restart.
+pub fn spawn_retention_task(pool: SqlitePool, order_result_tx: UnboundedSender<OperationResult>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(RETENTION_INTERVAL);
+        loop {
+            ticker.tick().await;
+            let policy = match load_settings_from_disk() {
+                Ok(settings) => RetentionPolicy::from_settings(&settings),
+                Err(e) => {
+                    log::warn!("Retention: could not read settings: {e}");
+                    continue;
+                }
+            };
+            if !policy.is_active() {
+                continue;
+            }
+            let Some(data_dir) = retention_data_dir() else {
+                return;
+            };
+            let now = chrono::Utc::now().timestamp();
+            let plan = match plan_retention(&pool, policy, &data_dir, now).await {
+                Ok(plan) => plan,
+                Err(e) => {
+                    log::warn!("Retention: planning failed: {e}");
+                    continue;
+                }
+            };
+            if plan.is_empty() {
+                continue;
+            }
+            let outcome = execute_retention(&pool, &plan, &data_dir).await;
+            log::info!("{}", outcome.summary());
+            if !outcome.deleted_order_ids.is_empty() {
+                let _ = order_result_tx.send(OperationResult::RetentionPurged {
+                    deleted_order_ids: outcome.deleted_order_ids,
+                });
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shred_file_zeroes_and_removes() {
+        let dir = std::env::temp_dir().join(format!("mostrix_shred_{}", Uuid::new_v4()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("secret.txt");
+        fs::write(&path, vec![b'x'; 70 * 1024]).unwrap();
+        assert_eq!(shred_file(&path).unwrap(), 70 * 1024);
+        assert!(!path.exists());
+        assert_eq!(
+            shred_file(&path).unwrap_err().kind(),
+            io::ErrorKind::NotFound
+        );
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn policy_is_inactive_when_every_rule_keeps_forever() {
+        assert!(!RetentionPolicy::default().is_active());
+        assert!(RetentionPolicy {
+            downloads_days: 30,
+            ..Default::default()
+        }
+        .is_active());
+    }
+}
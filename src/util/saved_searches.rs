@@ -0,0 +1,305 @@
+//! Saved searches: criteria over live book orders that raise an in-app alert on a match.
+//!
+//! Searches live in `~/.mostrix/saved_searches.toml`. Each pending order that arrives on the
+//! live kind 38383 subscription is checked against them (`apply_live_order_update` in the
+//! fetch scheduler); the first match for an order is reported to the UI, and that order never
+//! alerts again for the rest of the session. Every criterion is optional.
+//!
+//! `kind` is the order as listed in the book: `sell` means the maker sells sats (you buy).
+//! Amounts are fiat in the order's currency; a range order matches when its min–max range
+//! overlaps `min_amount`–`max_amount`. `payment_method` goes through the payment method
+//! catalog, so aliases and spelling variants match.
+//!
+//! ```toml
+//! [[searches]]
+//! name = "ars-mercadopago"
+//! kind = "sell"
+//! fiat_code = "ARS"
+//! min_amount = 50000
+//! payment_method = "MercadoPago"
+//! max_premium = 2
+//! ```
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+use anyhow::Result;
+use mostro_core::prelude::{Kind, Status};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::util::fiat::BookOrder;
+use crate::util::payment_methods::payment_methods;
+
+pub const SAVED_SEARCHES_FILE_NAME: &str = "saved_searches.toml";
+
+static SAVED_SEARCHES: OnceLock<Mutex<SavedSearches>> = OnceLock::new();
+
+/// One `[[searches]]` entry of `saved_searches.toml`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SavedSearch {
+    pub name: String,
+    #[serde(default)]
+    pub kind: Option<Kind>,
+    #[serde(default)]
+    pub fiat_code: Option<String>,
+    #[serde(default)]
+    pub min_amount: Option<f64>,
+    #[serde(default)]
+    pub max_amount: Option<f64>,
+    #[serde(default)]
+    pub payment_method: Option<String>,
+    /// Premium bounds in percent (`max_premium = 2` means at most +2%).
+    #[serde(default)]
+    pub min_premium: Option<i64>,
+    #[serde(default)]
+    pub max_premium: Option<i64>,
+    #[serde(default = "default_search_enabled")]
+    pub enabled: bool,
+}
+
+fn default_search_enabled() -> bool {
+    true
+}
+
+impl SavedSearch {
+    pub fn matches(&self, order: &BookOrder) -> bool {
+        if self.kind.is_some_and(|kind| order.kind != Some(kind)) {
+            return false;
+        }
+        if self
+            .fiat_code
+            .as_deref()
+            .is_some_and(|code| !code.eq_ignore_ascii_case(&order.fiat_code))
+        {
+            return false;
+        }
+        if self
+            .payment_method
+            .as_deref()
+            .is_some_and(|method| !payment_methods().list_contains(&order.payment_method, method))
+        {
+            return false;
+        }
+        if self.min_premium.is_some_and(|min| order.premium < min)
+            || self.max_premium.is_some_and(|max| order.premium > max)
+        {
+            return false;
+        }
+        if self.min_amount.is_none() && self.max_amount.is_none() {
+            return true;
+        }
+        let fiat = order.fiat;
+        let (low, high) = match (fiat.min, fiat.max) {
+            (Some(min), Some(max)) => (min.to_f64(), max.to_f64()),
+            _ => (fiat.amount.to_f64(), fiat.amount.to_f64()),
+        };
+        self.min_amount.is_none_or(|min| high >= min)
+            && self.max_amount.is_none_or(|max| low <= max)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SavedSearchesFile {
+    #[serde(default)]
+    searches: Vec<SavedSearch>,
+}
+
+/// A live book order that matched one or more saved searches.
+#[derive(Clone, Debug)]
+pub struct SavedSearchMatch {
+    pub searches: Vec<String>,
+    pub order: BookOrder,
+}
+
+/// Loaded searches plus the orders that already alerted.
+#[derive(Debug, Default)]
+pub struct SavedSearches {
+    searches: Vec<SavedSearch>,
+    alerted: HashSet<Uuid>,
+}
+
+impl SavedSearches {
+    /// Parse and validate `saved_searches.toml` contents.
+    pub fn from_toml(raw: &str) -> Result<Self> {
+        let file: SavedSearchesFile = toml::from_str(raw)
+            .map_err(|e| anyhow::anyhow!("saved_searches.toml malformed: {}", e))?;
+        let mut names = HashSet::new();
+        for search in &file.searches {
+            validate_search(search)?;
+            if !names.insert(search.name.as_str()) {
+                anyhow::bail!("duplicate saved search name '{}'", search.name);
+            }
+        }
+        Ok(Self {
+            searches: file.searches,
+            alerted: HashSet::new(),
+        })
+    }
+
+    /// Load `~/.mostrix/saved_searches.toml`; a missing file means no searches.
+    pub fn load_from_disk() -> Result<Self> {
+        let Some(path) = saved_searches_path() else {
+            return Ok(Self::default());
+        };
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let raw = std::fs::read_to_string(&path)
+            .map_err(|e| anyhow::anyhow!("could not read {}: {}", path.display(), e))?;
+        Self::from_toml(&raw)
+    }
+
+    pub fn searches(&self) -> &[SavedSearch] {
+        &self.searches
+    }
+
+    /// Searches matching pending `order`, at most once per order id.
+    pub fn evaluate(&mut self, order: &BookOrder) -> Option<SavedSearchMatch> {
+        let order_id = order.id?;
+        if order.status != Some(Status::Pending) || self.alerted.contains(&order_id) {
+            return None;
+        }
+        let searches: Vec<String> = self
+            .searches
+            .iter()
+            .filter(|s| s.enabled && s.matches(order))
+            .map(|s| s.name.clone())
+            .collect();
+        if searches.is_empty() {
+            return None;
+        }
+        self.alerted.insert(order_id);
+        Some(SavedSearchMatch {
+            searches,
+            order: order.clone(),
+        })
+    }
+}
+
+fn validate_search(search: &SavedSearch) -> Result<()> {
+    if search.name.trim().is_empty() {
+        anyhow::bail!("saved search name must not be empty");
+    }
+    if let (Some(min), Some(max)) = (search.min_amount, search.max_amount) {
+        if min > max {
+            anyhow::bail!(
+                "saved search '{}': min_amount is above max_amount",
+                search.name
+            );
+        }
+    }
+    if let (Some(min), Some(max)) = (search.min_premium, search.max_premium) {
+        if min > max {
+            anyhow::bail!(
+                "saved search '{}': min_premium is above max_premium",
+                search.name
+            );
+        }
+    }
+    if search
+        .payment_method
+        .as_deref()
+        .is_some_and(|m| m.trim().is_empty())
+    {
+        anyhow::bail!("saved search '{}': payment_method is empty", search.name);
+    }
+    Ok(())
+}
+
+/// Install the searches evaluated for live book orders (first call wins).
+pub fn init_saved_searches(searches: SavedSearches) {
+    let _ = SAVED_SEARCHES.set(Mutex::new(searches));
+}
+
+/// Check a live book order against the installed searches.
+pub fn match_live_order(order: &BookOrder) -> Option<SavedSearchMatch> {
+    let mut searches = SAVED_SEARCHES.get()?.lock().ok()?;
+    searches.evaluate(order)
+}
+
+/// `~/.mostrix/saved_searches.toml`, or `None` when the home directory is unknown.
+pub fn saved_searches_path() -> Option<PathBuf> {
+    Some(crate::settings::data_dir()?.join(SAVED_SEARCHES_FILE_NAME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mostro_core::prelude::SmallOrder;
+
+    fn order(kind: Kind, fiat_code: &str, amount: i64, range: Option<(i64, i64)>) -> BookOrder {
+        BookOrder::whole(SmallOrder {
+            id: Some(Uuid::new_v4()),
+            kind: Some(kind),
+            status: Some(Status::Pending),
+            fiat_code: fiat_code.to_string(),
+            fiat_amount: amount,
+            min_amount: range.map(|r| r.0),
+            max_amount: range.map(|r| r.1),
+            payment_method: "mercado pago,efectivo".to_string(),
+            premium: 1,
+            ..Default::default()
+        })
+    }
+
+    const ARS_SEARCH: &str = r#"
+        [[searches]]
+        name = "ars-mercadopago"
+        kind = "sell"
+        fiat_code = "ars"
+        min_amount = 50000
+        payment_method = "MercadoPago"
+        max_premium = 2
+    "#;
+
+    #[test]
+    fn matches_every_criterion_and_alerts_once_per_order() {
+        let mut searches = SavedSearches::from_toml(ARS_SEARCH).unwrap();
+        let hit = order(Kind::Sell, "ARS", 80_000, None);
+        let found = searches.evaluate(&hit).expect("order matches");
+        assert_eq!(found.searches, vec!["ars-mercadopago".to_string()]);
+        assert!(searches.evaluate(&hit).is_none());
+
+        assert!(searches
+            .evaluate(&order(Kind::Buy, "ARS", 80_000, None))
+            .is_none());
+        assert!(searches
+            .evaluate(&order(Kind::Sell, "USD", 80_000, None))
+            .is_none());
+        assert!(searches
+            .evaluate(&order(Kind::Sell, "ARS", 20_000, None))
+            .is_none());
+        let mut pricey = order(Kind::Sell, "ARS", 80_000, None);
+        pricey.order.premium = 5;
+        assert!(searches.evaluate(&pricey).is_none());
+        let mut other_method = order(Kind::Sell, "ARS", 80_000, None);
+        other_method.order.payment_method = "Ualá".to_string();
+        assert!(searches.evaluate(&other_method).is_none());
+    }
+
+    #[test]
+    fn range_orders_match_when_ranges_overlap() {
+        let mut searches = SavedSearches::from_toml(ARS_SEARCH).unwrap();
+        assert!(searches
+            .evaluate(&order(Kind::Sell, "ARS", 0, Some((10_000, 60_000))))
+            .is_some());
+        assert!(searches
+            .evaluate(&order(Kind::Sell, "ARS", 0, Some((10_000, 40_000))))
+            .is_none());
+    }
+
+    #[test]
+    fn rejects_inverted_bounds_and_duplicate_names() {
+        assert!(SavedSearches::from_toml(
+            "[[searches]]\nname = \"x\"\nmin_amount = 10\nmax_amount = 5\n"
+        )
+        .is_err());
+        assert!(SavedSearches::from_toml(
+            "[[searches]]\nname = \"x\"\n\n[[searches]]\nname = \"x\"\n"
+        )
+        .is_err());
+        assert!(SavedSearches::from_toml("").unwrap().searches().is_empty());
+    }
+}
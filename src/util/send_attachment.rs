@@ -1,6 +1,6 @@
-//! Send encrypted order-chat attachments (encrypt → Blossom → kind-14 chat DM).
+//! Send encrypted order-chat attachments (preprocess → confirm → encrypt → Blossom → kind-14 chat DM).
 
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 
 use anyhow::{anyhow, Result};
 use nostr_sdk::prelude::PublicKey;
@@ -17,15 +17,21 @@ use crate::ui::helpers::{
     PreparedOrderChatAttachment,
 };
 use crate::ui::{OperationResult, UserChatSender, UserOrderChatMessage};
+use crate::util::attachment_preprocess::{
+    preprocess_attachment, AttachmentPreprocessOptions, PreprocessReport,
+};
 use crate::util::blossom::{
     encrypt_blob, upload_blob_with_retry, BLOSSOM_MAX_BLOB_SIZE, DEFAULT_BLOSSOM_SERVERS,
 };
+use crate::util::blossom_cleanup::record_uploaded_blob_default;
+use crate::util::blossom_health::BlossomHealth;
 use crate::util::chat_utils::{
     keys_from_shared_hex, order_chat_decryption_key_bytes,
     send_user_order_chat_message_via_shared_key,
 };
 use crate::util::file_validation::validate_attachment_file;
 use crate::util::file_validation::{AttachmentFileClass, ValidatedAttachment};
+use crate::util::proxy::http_client;
 use crate::util::MostroInstanceInfo;
 
 /// Retries chat DM send after a successful Blossom upload (no re-upload).
@@ -35,12 +41,22 @@ const CHAT_SEND_RETRY_DELAY: Duration = Duration::from_secs(2);
 /// Work queued on `send_order_attachment_tx`.
 #[derive(Clone, Debug)]
 pub enum SendOrderAttachmentJob {
-    /// Read file, encrypt, upload, then send DM.
+    /// Read, validate and strip/downscale the file; the UI confirms before anything is uploaded.
     FromPath { order_id: String, path: PathBuf },
+    /// Encrypt, upload, then send DM for a file the user confirmed.
+    Confirmed(Box<PendingOrderAttachment>),
     /// Re-send DM for a blob already uploaded (after upload-success / send-failure).
     RetryPrepared(PreparedOrderChatAttachment),
 }
 
+/// Preprocessed file waiting for the user's confirmation (size and privacy summary popup).
+#[derive(Clone, Debug)]
+pub struct PendingOrderAttachment {
+    pub order_id: String,
+    pub attachment: ValidatedAttachment,
+    pub report: PreprocessReport,
+}
+
 /// Result of a full upload+send attempt.
 enum SendAttachmentAttempt {
     Sent(UserOrderChatMessage, String),
@@ -59,6 +75,23 @@ pub fn blossom_servers_from_settings(settings: &Settings) -> Vec<String> {
     }
 }
 
+/// Where and how attachments are uploaded.
+#[derive(Clone, Debug)]
+pub struct BlossomUploadOptions {
+    pub servers: Vec<String>,
+    /// Keep a copy on a second server.
+    pub mirror: bool,
+}
+
+impl BlossomUploadOptions {
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            servers: blossom_servers_from_settings(settings),
+            mirror: settings.blossom_mirror,
+        }
+    }
+}
+
 fn file_type_label(class: AttachmentFileClass) -> &'static str {
     match class {
         AttachmentFileClass::Image => "image",
@@ -189,16 +222,34 @@ pub async fn send_prepared_order_chat_attachment(
     Ok((local_message_from_prepared(prepared), info))
 }
 
+/// Reads, validates and preprocesses a file off the async runtime (image decode can be slow).
+async fn prepare_order_chat_attachment(
+    order_id: String,
+    path: PathBuf,
+    options: AttachmentPreprocessOptions,
+) -> Result<PendingOrderAttachment> {
+    tokio::task::spawn_blocking(move || {
+        let validated = validate_attachment_file(&path)?;
+        let (attachment, report) = preprocess_attachment(validated, options)?;
+        Ok(PendingOrderAttachment {
+            order_id,
+            attachment,
+            report,
+        })
+    })
+    .await
+    .map_err(|e| anyhow!("attachment preprocessing task failed: {e}"))?
+}
+
 /// Encrypts, uploads, sends attachment JSON over order chat.
-async fn send_order_chat_attachment_from_path(
+async fn send_confirmed_order_chat_attachment(
     client: &Client,
     pool: &SqlitePool,
     order_id: &str,
-    path: &Path,
-    blossom_servers: &[String],
+    validated: &ValidatedAttachment,
+    blossom: &BlossomUploadOptions,
     mostro_info: Option<&MostroInstanceInfo>,
 ) -> Result<SendAttachmentAttempt> {
-    let validated = validate_attachment_file(path)?;
     let (order, keys) = load_order_chat_keys(pool, order_id).await?;
 
     let key_vec = order_chat_decryption_key_bytes(&order)
@@ -216,10 +267,24 @@ async fn send_order_chat_attachment_from_path(
         ));
     }
 
-    let http = reqwest::Client::new();
-    let blossom_url =
-        upload_blob_with_retry(&http, blossom_servers, &encrypted_blob, &keys.trade_keys).await?;
-    let outbound = build_outbound_payload(&validated, blossom_url.clone(), &encrypted_blob)?;
+    let http = http_client()?;
+    let mut health = BlossomHealth::load();
+    let uploaded = upload_blob_with_retry(
+        &http,
+        &blossom.servers,
+        &encrypted_blob,
+        &keys.trade_keys,
+        blossom.mirror,
+        &mut health,
+    )
+    .await;
+    if let Err(e) = health.save() {
+        log::warn!("Could not save Blossom server health: {e}");
+    }
+    let locations = uploaded?;
+    record_uploaded_blob_default(order_id, &locations);
+    let blossom_url = locations.url;
+    let outbound = build_outbound_payload(validated, blossom_url.clone(), &encrypted_blob)?;
 
     let prepared = PreparedOrderChatAttachment {
         order_id: order_id.to_string(),
@@ -252,19 +317,32 @@ pub fn spawn_send_order_chat_attachment(
     job: SendOrderAttachmentJob,
     client: Client,
     pool: SqlitePool,
-    blossom_servers: Vec<String>,
+    blossom: BlossomUploadOptions,
+    preprocess: AttachmentPreprocessOptions,
     mostro_info: Option<MostroInstanceInfo>,
     order_result_tx: UnboundedSender<OperationResult>,
 ) {
     tokio::spawn(async move {
         match job {
             SendOrderAttachmentJob::FromPath { order_id, path } => {
-                match send_order_chat_attachment_from_path(
+                let result =
+                    match prepare_order_chat_attachment(order_id.clone(), path, preprocess).await {
+                        Ok(pending) => OperationResult::OrderChatAttachmentReady(Box::new(pending)),
+                        Err(e) => OperationResult::OrderChatAttachmentError {
+                            order_id,
+                            error: e.to_string(),
+                        },
+                    };
+                let _ = order_result_tx.send(result);
+            }
+            SendOrderAttachmentJob::Confirmed(pending) => {
+                let order_id = pending.order_id.clone();
+                match send_confirmed_order_chat_attachment(
                     &client,
                     &pool,
                     &order_id,
-                    &path,
-                    &blossom_servers,
+                    &pending.attachment,
+                    &blossom,
                     mostro_info.as_ref(),
                 )
                 .await
@@ -0,0 +1,231 @@
+//! Hot reload of `settings.toml` edited outside the app (by hand or by config management).
+//!
+//! A background task polls the active settings file. Each new version is validated and diffed
+//! against the settings the session runs with; the UI asks before applying it through the
+//! regular runtime reload paths (`ui/settings_reload.rs`). In-app saves merge over such edits
+//! instead of overwriting them (see [`crate::settings::prepare_settings_write`]).
+
+use anyhow::Result;
+use nostr_sdk::prelude::Keys;
+use std::str::FromStr;
+use tokio::time::{interval, Duration};
+
+use crate::settings::{applied_settings, poll_settings_file, Settings};
+use crate::ui::key_handler::{validate_mostro_pubkey, validate_relay};
+use crate::ui::UserRole;
+use crate::util::catch_unwind_request_fatal_restart;
+use crate::util::clipboard::ClipboardMode;
+use crate::util::dm_utils::{try_notify_settings_file_changed, try_notify_settings_file_invalid};
+
+/// How often the settings file is checked for changes.
+const SETTINGS_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Keys whose values are never shown in the diff.
+const SECRET_KEYS: [&str; 2] = ["nsec_privkey", "admin_privkey"];
+
+/// Keys only read at startup; a change is saved but needs a restart.
+const RESTART_KEYS: [&str; 5] = [
+    "log_level",
+    "proxy",
+    "local_api",
+    "image_preview",
+    "blossom_delete_after_trade",
+];
+
+/// One changed top-level key, values rendered as TOML (secrets masked).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SettingDiff {
+    pub key: String,
+    pub old: String,
+    pub new: String,
+}
+
+/// A validated `settings.toml` that differs from the applied settings.
+#[derive(Clone, Debug)]
+pub struct SettingsFileChange {
+    pub settings: Settings,
+    pub changes: Vec<SettingDiff>,
+}
+
+impl SettingsFileChange {
+    pub fn changed(&self, key: &str) -> bool {
+        self.changes.iter().any(|c| c.key == key)
+    }
+
+    /// Changed keys that only take effect after a restart.
+    pub fn restart_required(&self) -> Vec<&str> {
+        self.changes
+            .iter()
+            .map(|c| c.key.as_str())
+            .filter(|key| RESTART_KEYS.contains(key))
+            .collect()
+    }
+}
+
+fn render_value(key: &str, value: Option<&toml::Value>) -> String {
+    match value {
+        None => "(unset)".to_string(),
+        Some(toml::Value::String(s)) if SECRET_KEYS.contains(&key) && !s.is_empty() => {
+            "\"••••••\"".to_string()
+        }
+        Some(value) => value.to_string(),
+    }
+}
+
+/// Top-level keys that differ between `old` and `new`, sorted by key.
+pub fn settings_diff(old: &Settings, new: &Settings) -> Vec<SettingDiff> {
+    let (Ok(old), Ok(new)) = (toml::Table::try_from(old), toml::Table::try_from(new)) else {
+        return Vec::new();
+    };
+    new.iter()
+        .filter(|(key, value)| old.get(*key) != Some(*value))
+        .map(|(key, value)| SettingDiff {
+            key: key.clone(),
+            old: render_value(key, old.get(key)),
+            new: render_value(key, Some(value)),
+        })
+        .collect()
+}
+
+/// Reject settings the runtime reload paths cannot apply.
+pub fn validate_settings(settings: &Settings) -> Result<()> {
+    validate_mostro_pubkey(&settings.mostro_pubkey)
+        .map_err(|e| anyhow::anyhow!("mostro_pubkey: {e}"))?;
+    settings
+        .nsec_privkey
+        .parse::<Keys>()
+        .map_err(|e| anyhow::anyhow!("nsec_privkey: {e}"))?;
+    if !settings.admin_privkey.trim().is_empty() {
+        settings
+            .admin_privkey
+            .trim()
+            .parse::<Keys>()
+            .map_err(|e| anyhow::anyhow!("admin_privkey: {e}"))?;
+    }
+    if settings.relays.iter().all(|r| r.trim().is_empty()) {
+        anyhow::bail!("relays: at least one relay is required");
+    }
+    for relay in settings.relays.iter().filter(|r| !r.trim().is_empty()) {
+        validate_relay(relay).map_err(|e| anyhow::anyhow!("relays: {e} ({relay})"))?;
+    }
+    UserRole::from_str(&settings.user_mode).map_err(|e| anyhow::anyhow!("user_mode: {e}"))?;
+    crate::i18n::Language::from_str(&settings.language)
+        .map_err(|e| anyhow::anyhow!("language: {e}"))?;
+    ClipboardMode::from_setting(&settings.clipboard)
+        .map_err(|e| anyhow::anyhow!("clipboard: {e}"))?;
+    Ok(())
+}
+
+/// Check the settings file once: `None` when unchanged or identical to the applied settings.
+fn check_settings_file() -> Option<Result<SettingsFileChange>> {
+    let settings = match poll_settings_file()? {
+        Ok(settings) => settings,
+        Err(e) => return Some(Err(e)),
+    };
+    if let Err(e) = validate_settings(&settings) {
+        return Some(Err(e));
+    }
+    let changes = settings_diff(&applied_settings()?, &settings);
+    if changes.is_empty() {
+        return None;
+    }
+    Some(Ok(SettingsFileChange { settings, changes }))
+}
+
+/// Poll `settings.toml` and report external edits to the UI thread.
+pub fn spawn_settings_file_watcher() {
+    tokio::spawn(async move {
+        catch_unwind_request_fatal_restart("settings file watcher", async move {
+            let mut ticker = interval(SETTINGS_POLL_INTERVAL);
+            loop {
+                ticker.tick().await;
+                match check_settings_file() {
+                    None => {}
+                    Some(Ok(change)) => {
+                        log::info!(
+                            "settings.toml changed on disk ({} keys)",
+                            change.changes.len()
+                        );
+                        try_notify_settings_file_changed(change);
+                    }
+                    Some(Err(e)) => {
+                        log::warn!("settings.toml changed on disk but is invalid: {}", e);
+                        try_notify_settings_file_invalid(e.to_string());
+                    }
+                }
+            }
+        })
+        .await;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_settings() -> Settings {
+        Settings {
+            mostro_pubkey: crate::settings::MOSTRO_STAGING_PUBKEY.to_string(),
+            nsec_privkey: Keys::generate().secret_key().to_secret_hex(),
+            relays: vec!["wss://relay.mostro.network".to_string()],
+            ..Settings::default()
+        }
+    }
+
+    #[test]
+    fn diff_lists_changed_keys_and_masks_secrets() {
+        let old = valid_settings();
+        let new = Settings {
+            nsec_privkey: Keys::generate().secret_key().to_secret_hex(),
+            currencies_filter: vec!["ARS".to_string()],
+            log_level: "debug".to_string(),
+            ..old.clone()
+        };
+        let change = SettingsFileChange {
+            changes: settings_diff(&old, &new),
+            settings: new,
+        };
+        let keys: Vec<&str> = change.changes.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["currencies_filter", "log_level", "nsec_privkey"]);
+        assert_eq!(change.changes[0].old, "[]");
+        assert_eq!(change.changes[0].new, "[\"ARS\"]");
+        assert_eq!(change.changes[2].new, "\"••••••\"");
+        assert!(change.changed("currencies_filter"));
+        assert_eq!(change.restart_required(), vec!["log_level"]);
+        assert!(settings_diff(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn validation_rejects_what_reload_cannot_apply() {
+        assert!(validate_settings(&valid_settings()).is_ok());
+        let broken = [
+            Settings {
+                mostro_pubkey: "not-a-key".to_string(),
+                ..valid_settings()
+            },
+            Settings {
+                relays: vec!["https://relay.example.com".to_string()],
+                ..valid_settings()
+            },
+            Settings {
+                relays: Vec::new(),
+                ..valid_settings()
+            },
+            Settings {
+                user_mode: "root".to_string(),
+                ..valid_settings()
+            },
+            Settings {
+                admin_privkey: "nsec1nope".to_string(),
+                ..valid_settings()
+            },
+            Settings {
+                clipboard: "x11".to_string(),
+                ..valid_settings()
+            },
+        ];
+        for settings in broken {
+            assert!(validate_settings(&settings).is_err());
+        }
+    }
+}
@@ -0,0 +1,295 @@
+//! Per-trade deadlines derived from Mostro instance info (kind 38385) and order/invoice data.
+//!
+//! Mostro enforces several time windows that the client only learns indirectly:
+//! - `expiration_hours`: how long a pending order stays on the book (or the order's own
+//!   `expires_at`).
+//! - `expiration_seconds`: how long each waiting step (hold invoice, buyer invoice, bond) may take
+//!   before Mostro cancels the take and republishes the order.
+//! - `hold_invoice_expiration_window`: lifetime of the seller hold invoice.
+//! - `invoice_expiration_window`: how long the buyer has to send a payout invoice.
+//!
+//! Phase start times come from the DM that opened the step. When a BOLT11 invoice is at hand,
+//! its own expiry is used too, since the wallet cannot pay it after that.
+
+use std::str::FromStr;
+
+use lightning_invoice::Bolt11Invoice;
+use mostro_core::prelude::{Action, Status};
+
+use crate::i18n::tr;
+use crate::util::mostro_info::MostroInstanceInfo;
+
+/// Fallback thresholds (seconds) when the full window length is unknown.
+const WARNING_SECS: i64 = 300;
+const CRITICAL_SECS: i64 = 60;
+
+/// Which time-bounded step a [`TradeDeadline`] belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeadlineKind {
+    /// Pending order leaves the book.
+    OrderExpiry,
+    /// Seller must pay the hold invoice.
+    HoldInvoicePayment,
+    /// Buyer must send a payout invoice.
+    BuyerInvoice,
+    /// Anti-abuse bond must be paid.
+    BondPayment,
+}
+
+impl DeadlineKind {
+    /// Stable identifier (local API).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DeadlineKind::OrderExpiry => "order_expiry",
+            DeadlineKind::HoldInvoicePayment => "hold_invoice_payment",
+            DeadlineKind::BuyerInvoice => "buyer_invoice",
+            DeadlineKind::BondPayment => "bond_payment",
+        }
+    }
+
+    /// Short label shown in front of the countdown.
+    pub fn label(self) -> &'static str {
+        match self {
+            DeadlineKind::OrderExpiry => tr("deadline.order_expiry"),
+            DeadlineKind::HoldInvoicePayment => tr("deadline.hold_invoice_payment"),
+            DeadlineKind::BuyerInvoice => tr("deadline.buyer_invoice"),
+            DeadlineKind::BondPayment => tr("deadline.bond_payment"),
+        }
+    }
+}
+
+/// How close a deadline is; drives color and emphasis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DeadlineUrgency {
+    Normal,
+    Warning,
+    Critical,
+    Expired,
+}
+
+/// A deadline as a Unix timestamp, with the full window length when known.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TradeDeadline {
+    pub kind: DeadlineKind,
+    pub deadline: i64,
+    pub window_secs: Option<i64>,
+}
+
+impl TradeDeadline {
+    pub fn remaining_secs(&self, now: i64) -> i64 {
+        self.deadline - now
+    }
+
+    /// `Warning` under 25% of the window (or 5 min), `Critical` under 10% (or 1 min).
+    pub fn urgency(&self, now: i64) -> DeadlineUrgency {
+        let remaining = self.remaining_secs(now);
+        if remaining <= 0 {
+            return DeadlineUrgency::Expired;
+        }
+        let (warning, critical) = match self.window_secs.filter(|w| *w > 0) {
+            Some(window) => (window / 4, window / 10),
+            None => (WARNING_SECS, CRITICAL_SECS),
+        };
+        if remaining <= critical {
+            DeadlineUrgency::Critical
+        } else if remaining <= warning {
+            DeadlineUrgency::Warning
+        } else {
+            DeadlineUrgency::Normal
+        }
+    }
+}
+
+/// `1d 04h`, `2h 05m`, `04:59`, or `expired`.
+pub fn format_countdown(remaining_secs: i64) -> String {
+    if remaining_secs <= 0 {
+        return "expired".to_string();
+    }
+    let days = remaining_secs / 86_400;
+    let hours = (remaining_secs % 86_400) / 3_600;
+    let minutes = (remaining_secs % 3_600) / 60;
+    let seconds = remaining_secs % 60;
+    if days > 0 {
+        format!("{days}d {hours:02}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes:02}m")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+/// Expiry of a BOLT11 invoice as a Unix timestamp, if it decodes.
+pub fn bolt11_expires_at(invoice: &str) -> Option<i64> {
+    let invoice = Bolt11Invoice::from_str(invoice.trim()).ok()?;
+    invoice
+        .expires_at()
+        .and_then(|d| i64::try_from(d.as_secs()).ok())
+}
+
+fn secs(value: Option<u64>) -> Option<i64> {
+    value.and_then(|v| i64::try_from(v).ok()).filter(|v| *v > 0)
+}
+
+/// When a pending order leaves the book: its `expires_at`, else `created_at + expiration_hours`.
+pub fn order_expiry_deadline(
+    expires_at: Option<i64>,
+    created_at: Option<i64>,
+    info: Option<&MostroInstanceInfo>,
+) -> Option<TradeDeadline> {
+    let window = secs(info.and_then(|i| i.expiration_hours)).and_then(|h| h.checked_mul(3_600));
+    let deadline = expires_at
+        .filter(|ts| *ts > 0)
+        .or_else(|| Some(created_at? + window?))?;
+    Some(TradeDeadline {
+        kind: DeadlineKind::OrderExpiry,
+        deadline,
+        window_secs: window,
+    })
+}
+
+/// Deadline of the waiting step implied by `status`, counted from `phase_started_at`.
+///
+/// `payment_invoice` is the hold or bond invoice when the local user has it; its BOLT11 expiry
+/// caps the step deadline. Without it, the instance's hold invoice or buyer invoice window does. Returns `None` for statuses without a Mostro-enforced window.
+pub fn trade_step_deadline(
+    status: Status,
+    phase_started_at: Option<i64>,
+    payment_invoice: Option<&str>,
+    info: Option<&MostroInstanceInfo>,
+) -> Option<TradeDeadline> {
+    let kind = match status {
+        Status::WaitingPayment => DeadlineKind::HoldInvoicePayment,
+        Status::WaitingBuyerInvoice => DeadlineKind::BuyerInvoice,
+        Status::WaitingTakerBond | Status::WaitingMakerBond => DeadlineKind::BondPayment,
+        _ => return None,
+    };
+    let step_window = secs(info.and_then(|i| i.expiration_seconds));
+    let invoice_window = match kind {
+        DeadlineKind::HoldInvoicePayment => {
+            secs(info.and_then(|i| i.hold_invoice_expiration_window))
+        }
+        DeadlineKind::BuyerInvoice => secs(info.and_then(|i| i.invoice_expiration_window)),
+        _ => None,
+    };
+    let step_deadline = phase_started_at.zip(step_window).map(|(t, w)| t + w);
+    let invoice_deadline = payment_invoice
+        .and_then(bolt11_expires_at)
+        .or_else(|| phase_started_at.zip(invoice_window).map(|(t, w)| t + w));
+    let deadline = match (step_deadline, invoice_deadline) {
+        (Some(a), Some(b)) => a.min(b),
+        (a, b) => a.or(b)?,
+    };
+    let window_secs = phase_started_at
+        .map(|t| deadline - t)
+        .filter(|w| *w > 0)
+        .or(step_window);
+    Some(TradeDeadline {
+        kind,
+        deadline,
+        window_secs,
+    })
+}
+
+/// Deadline for an invoice popup, keyed by the popup action (`PayInvoice`, `AddInvoice`, ...).
+pub fn popup_deadline(
+    action: &Action,
+    phase_started_at: i64,
+    invoice: Option<&str>,
+    info: Option<&MostroInstanceInfo>,
+) -> Option<TradeDeadline> {
+    let status = match action {
+        Action::PayInvoice | Action::WaitingSellerToPay => Status::WaitingPayment,
+        Action::AddInvoice | Action::WaitingBuyerInvoice => Status::WaitingBuyerInvoice,
+        Action::PayBondInvoice => Status::WaitingTakerBond,
+        _ => return None,
+    };
+    let invoice = match action {
+        Action::PayInvoice | Action::PayBondInvoice => invoice,
+        _ => None,
+    };
+    trade_step_deadline(status, Some(phase_started_at), invoice, info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info() -> MostroInstanceInfo {
+        MostroInstanceInfo {
+            expiration_hours: Some(24),
+            expiration_seconds: Some(900),
+            hold_invoice_expiration_window: Some(300),
+            invoice_expiration_window: Some(600),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn format_countdown_picks_units() {
+        assert_eq!(format_countdown(0), "expired");
+        assert_eq!(format_countdown(299), "04:59");
+        assert_eq!(format_countdown(7_500), "2h 05m");
+        assert_eq!(format_countdown(100_800), "1d 04h");
+    }
+
+    #[test]
+    fn order_expiry_prefers_expires_at_and_falls_back_to_expiration_hours() {
+        let info = info();
+        let d = order_expiry_deadline(Some(5_000), Some(1_000), Some(&info)).unwrap();
+        assert_eq!(d.deadline, 5_000);
+        let d = order_expiry_deadline(None, Some(1_000), Some(&info)).unwrap();
+        assert_eq!(d.deadline, 1_000 + 24 * 3_600);
+        assert!(order_expiry_deadline(None, Some(1_000), None).is_none());
+    }
+
+    #[test]
+    fn invoice_steps_use_the_shorter_window() {
+        let info = info();
+        let d =
+            trade_step_deadline(Status::WaitingPayment, Some(1_000), None, Some(&info)).unwrap();
+        assert_eq!(d.kind, DeadlineKind::HoldInvoicePayment);
+        assert_eq!(d.deadline, 1_300);
+        assert_eq!(d.window_secs, Some(300));
+
+        let d = trade_step_deadline(Status::WaitingBuyerInvoice, Some(1_000), None, Some(&info))
+            .unwrap();
+        assert_eq!(d.kind, DeadlineKind::BuyerInvoice);
+        assert_eq!(d.deadline, 1_600);
+
+        let no_invoice_window = MostroInstanceInfo {
+            invoice_expiration_window: None,
+            ..info.clone()
+        };
+        let d = trade_step_deadline(
+            Status::WaitingBuyerInvoice,
+            Some(1_000),
+            None,
+            Some(&no_invoice_window),
+        )
+        .unwrap();
+        assert_eq!(d.deadline, 1_900);
+        assert!(trade_step_deadline(Status::Active, Some(1_000), None, Some(&info)).is_none());
+    }
+
+    #[test]
+    fn urgency_escalates_with_the_window() {
+        let d = TradeDeadline {
+            kind: DeadlineKind::BuyerInvoice,
+            deadline: 1_000,
+            window_secs: Some(1_000),
+        };
+        assert_eq!(d.urgency(0), DeadlineUrgency::Normal);
+        assert_eq!(d.urgency(800), DeadlineUrgency::Warning);
+        assert_eq!(d.urgency(950), DeadlineUrgency::Critical);
+        assert_eq!(d.urgency(1_000), DeadlineUrgency::Expired);
+    }
+
+    #[test]
+    fn popup_deadline_reads_bond_invoice_expiry() {
+        let invoice = crate::demo::demo_invoice(1_000, "bond");
+        let expiry = bolt11_expires_at(&invoice).expect("demo invoice decodes");
+        let d = popup_deadline(&Action::PayBondInvoice, 0, Some(&invoice), None).unwrap();
+        assert_eq!(d.kind, DeadlineKind::BondPayment);
+        assert_eq!(d.deadline, expiry);
+    }
+}
@@ -1,4 +1,5 @@
 // Common types and enums used across nostr utilities
+use crate::i18n::tr;
 use mostro_core::prelude::*;
 use nostr_sdk::prelude::*;
 
@@ -18,73 +19,45 @@ pub enum Event {
     MessageTuple(Box<(Message, u64, PublicKey)>),
 }
 
-/// Convert CantDoReason to user-friendly description
+/// Convert CantDoReason to user-friendly description in the active UI language
+/// (`cant_do.*` in the message catalogs).
 pub fn get_cant_do_description(reason: &CantDoReason) -> String {
-    match reason {
-        CantDoReason::InvalidSignature => "Invalid signature - authentication failed".to_string(),
-        CantDoReason::InvalidTradeIndex => "Invalid trade index - please try again".to_string(),
-        CantDoReason::InvalidAmount => "Invalid amount - check your order values".to_string(),
-        CantDoReason::InvalidInvoice => {
-            "Invalid invoice - please provide a valid lightning invoice".to_string()
-        }
-        CantDoReason::InvalidPaymentRequest => "Invalid payment request".to_string(),
-        CantDoReason::InvalidPeer => "Invalid peer information".to_string(),
-        CantDoReason::InvalidRating => "Invalid rating value".to_string(),
-        CantDoReason::InvalidTextMessage => "Invalid text message".to_string(),
-        CantDoReason::InvalidOrderKind => {
-            "Invalid order kind - must be 'buy' or 'sell'".to_string()
-        }
-        CantDoReason::InvalidOrderStatus => "Invalid order status".to_string(),
-        CantDoReason::InvalidPubkey => "Invalid public key".to_string(),
-        CantDoReason::InvalidParameters => {
-            "Invalid parameters - check your order details".to_string()
-        }
-        CantDoReason::InvalidPayload => {
-            "Invalid payload - check bond slash choices or message format".to_string()
-        }
-        CantDoReason::OrderAlreadyCanceled => "Order is already canceled".to_string(),
-        CantDoReason::CantCreateUser => "Cannot create user - please contact support".to_string(),
-        CantDoReason::IsNotYourOrder => "This is not your order".to_string(),
-        CantDoReason::NotAllowedByStatus => {
-            "Action not allowed - order status prevents this operation".to_string()
-        }
-        CantDoReason::OutOfRangeFiatAmount => "Fiat amount is out of acceptable range".to_string(),
-        CantDoReason::OutOfRangeSatsAmount => {
-            "Satoshis amount is out of acceptable range".to_string()
-        }
-        CantDoReason::IsNotYourDispute => "This is not your dispute".to_string(),
-        CantDoReason::DisputeTakenByAdmin => {
-            "Dispute has been taken over by an administrator".to_string()
-        }
-        CantDoReason::DisputeCreationError => "Cannot create dispute for this order".to_string(),
-        CantDoReason::NotFound => "Resource not found".to_string(),
-        CantDoReason::InvalidDisputeStatus => "Invalid dispute status".to_string(),
-        CantDoReason::InvalidAction => "Invalid action for current state".to_string(),
-        CantDoReason::PendingOrderExists => {
-            "You already have a pending order - please complete or cancel it first".to_string()
-        }
-        CantDoReason::InvalidFiatCurrency => {
-            "Invalid fiat currency - currency not supported or specify a fixed rate".to_string()
-        }
-        CantDoReason::TooManyRequests => {
-            "Too many requests - please wait and try again".to_string()
-        }
-        CantDoReason::NotAuthorized => "Not authorized to perform this action".to_string(),
-        CantDoReason::InvalidCashuToken => {
-            "Invalid Cashu token — check the escrow token and try again".to_string()
-        }
-        CantDoReason::CashuMintUnavailable => {
-            "Cashu mint is unavailable — try again later".to_string()
-        }
-        CantDoReason::InvalidMintUrl => "Invalid Cashu mint URL".to_string(),
-        CantDoReason::CashuEscrowNotLocked => {
-            "Cashu escrow is not locked — complete escrow setup first".to_string()
-        }
-        CantDoReason::CashuSignatureMissing => {
-            "Cashu signature missing from the request".to_string()
-        }
-        CantDoReason::PriceTooStale => {
-            "Price quote is too stale — refresh the rate and try again".to_string()
-        }
-    }
+    let key = match reason {
+        CantDoReason::InvalidSignature => "cant_do.invalid_signature",
+        CantDoReason::InvalidTradeIndex => "cant_do.invalid_trade_index",
+        CantDoReason::InvalidAmount => "cant_do.invalid_amount",
+        CantDoReason::InvalidInvoice => "cant_do.invalid_invoice",
+        CantDoReason::InvalidPaymentRequest => "cant_do.invalid_payment_request",
+        CantDoReason::InvalidPeer => "cant_do.invalid_peer",
+        CantDoReason::InvalidRating => "cant_do.invalid_rating",
+        CantDoReason::InvalidTextMessage => "cant_do.invalid_text_message",
+        CantDoReason::InvalidOrderKind => "cant_do.invalid_order_kind",
+        CantDoReason::InvalidOrderStatus => "cant_do.invalid_order_status",
+        CantDoReason::InvalidPubkey => "cant_do.invalid_pubkey",
+        CantDoReason::InvalidParameters => "cant_do.invalid_parameters",
+        CantDoReason::InvalidPayload => "cant_do.invalid_payload",
+        CantDoReason::OrderAlreadyCanceled => "cant_do.order_already_canceled",
+        CantDoReason::CantCreateUser => "cant_do.cant_create_user",
+        CantDoReason::IsNotYourOrder => "cant_do.is_not_your_order",
+        CantDoReason::NotAllowedByStatus => "cant_do.not_allowed_by_status",
+        CantDoReason::OutOfRangeFiatAmount => "cant_do.out_of_range_fiat_amount",
+        CantDoReason::OutOfRangeSatsAmount => "cant_do.out_of_range_sats_amount",
+        CantDoReason::IsNotYourDispute => "cant_do.is_not_your_dispute",
+        CantDoReason::DisputeTakenByAdmin => "cant_do.dispute_taken_by_admin",
+        CantDoReason::DisputeCreationError => "cant_do.dispute_creation_error",
+        CantDoReason::NotFound => "cant_do.not_found",
+        CantDoReason::InvalidDisputeStatus => "cant_do.invalid_dispute_status",
+        CantDoReason::InvalidAction => "cant_do.invalid_action",
+        CantDoReason::PendingOrderExists => "cant_do.pending_order_exists",
+        CantDoReason::InvalidFiatCurrency => "cant_do.invalid_fiat_currency",
+        CantDoReason::TooManyRequests => "cant_do.too_many_requests",
+        CantDoReason::NotAuthorized => "cant_do.not_authorized",
+        CantDoReason::InvalidCashuToken => "cant_do.invalid_cashu_token",
+        CantDoReason::CashuMintUnavailable => "cant_do.cashu_mint_unavailable",
+        CantDoReason::InvalidMintUrl => "cant_do.invalid_mint_url",
+        CantDoReason::CashuEscrowNotLocked => "cant_do.cashu_escrow_not_locked",
+        CantDoReason::CashuSignatureMissing => "cant_do.cashu_signature_missing",
+        CantDoReason::PriceTooStale => "cant_do.price_too_stale",
+    };
+    tr(key).to_string()
 }
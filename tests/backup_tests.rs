@@ -0,0 +1,188 @@
+// Encrypted backup, staged restore and startup apply against a temp data directory
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use mostrix::util::backup::{
+    apply_pending_restore, confirm_staged_restore, create_backup, stage_restore,
+};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+const PASSPHRASE: &str = "correct horse battery";
+/// Low KDF cost keeps debug test runs fast; the format records the count per archive.
+const TEST_ITERATIONS: u32 = 1_000;
+
+async fn seed_db(pool: &SqlitePool, last_trade_index: i64) {
+    sqlx::query(
+        "INSERT INTO users (i0_pubkey, mnemonic, last_trade_index, created_at) VALUES ('aa', 'words', ?, 0)",
+    )
+    .bind(last_trade_index)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+async fn set_trade_index(pool: &SqlitePool, index: i64) {
+    sqlx::query("UPDATE users SET last_trade_index = ?")
+        .bind(index)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+/// `VACUUM INTO` from an in-memory pool stays in memory, so snapshots need a real file.
+async fn test_pool(root: &Path) -> SqlitePool {
+    common::create_test_db_file(&root.join("live.db"))
+        .await
+        .unwrap()
+}
+
+fn write(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+fn temp_root() -> (PathBuf, PathBuf) {
+    let root = std::env::temp_dir().join(format!("mostrix_backup_{}", Uuid::new_v4()));
+    let data_dir = root.join(".mostrix");
+    write(&data_dir.join("settings.toml"), "theme = \"dark\"\n");
+    write(&data_dir.join("orders_chat").join("order-1.txt"), "hello");
+    write(&data_dir.join("downloads").join("order-1_photo.png"), "png");
+    write(&data_dir.join("mostrix.db"), "live database file");
+    (root, data_dir)
+}
+
+#[tokio::test]
+async fn backup_round_trip_restores_files_and_database_on_next_start() {
+    let (root, data_dir) = temp_root();
+    let pool = test_pool(&root).await;
+    seed_db(&pool, 3).await;
+    let archive = root.join("backup.mxbak");
+
+    let summary = create_backup(&pool, &data_dir, &archive, PASSPHRASE, TEST_ITERATIONS)
+        .await
+        .unwrap();
+    assert_eq!(summary.files, 4); // snapshot + settings + transcript + download
+    assert!(
+        create_backup(&pool, &data_dir, &archive, PASSPHRASE, TEST_ITERATIONS)
+            .await
+            .is_err()
+    );
+
+    // Changes made after the backup are moved aside by the restore.
+    write(&data_dir.join("settings.toml"), "theme = \"light\"\n");
+    write(&data_dir.join("orders_chat").join("order-2.txt"), "later");
+
+    assert!(
+        stage_restore(&pool, &data_dir, &archive, "wrong passphrase")
+            .await
+            .is_err()
+    );
+    let staged = stage_restore(&pool, &data_dir, &archive, PASSPHRASE)
+        .await
+        .unwrap();
+    assert!(!staged.needs_confirmation());
+    assert_eq!(staged.files, 4);
+
+    let applied = apply_pending_restore(&data_dir).unwrap().unwrap();
+    assert_eq!(
+        fs::read_to_string(data_dir.join("settings.toml")).unwrap(),
+        "theme = \"dark\"\n"
+    );
+    assert!(data_dir.join("orders_chat").join("order-1.txt").exists());
+    assert!(!data_dir.join("orders_chat").join("order-2.txt").exists());
+    assert!(applied
+        .previous_data
+        .join("orders_chat")
+        .join("order-2.txt")
+        .exists());
+    assert_eq!(
+        fs::read_to_string(applied.previous_data.join("mostrix.db")).unwrap(),
+        "live database file"
+    );
+
+    // The restored database is the snapshot taken at backup time.
+    let restored = SqlitePool::connect(&format!(
+        "sqlite://{}",
+        data_dir.join("mostrix.db").display()
+    ))
+    .await
+    .unwrap();
+    let index: i64 = sqlx::query_scalar("SELECT last_trade_index FROM users")
+        .fetch_one(&restored)
+        .await
+        .unwrap();
+    assert_eq!(index, 3);
+    restored.close().await;
+
+    assert!(apply_pending_restore(&data_dir).unwrap().is_none());
+    let _ = fs::remove_dir_all(root);
+}
+
+#[tokio::test]
+async fn restore_over_newer_database_waits_for_confirmation() {
+    let (root, data_dir) = temp_root();
+    let pool = test_pool(&root).await;
+    seed_db(&pool, 3).await;
+    let archive = root.join("backup.mxbak");
+    create_backup(&pool, &data_dir, &archive, PASSPHRASE, TEST_ITERATIONS)
+        .await
+        .unwrap();
+    set_trade_index(&pool, 7).await;
+    write(&data_dir.join("settings.toml"), "theme = \"light\"\n");
+
+    let staged = stage_restore(&pool, &data_dir, &archive, PASSPHRASE)
+        .await
+        .unwrap();
+    assert!(staged.needs_confirmation());
+    assert_eq!(
+        (
+            staged.current_db.last_trade_index,
+            staged.backup_db.last_trade_index
+        ),
+        (7, 3)
+    );
+
+    // Not confirmed: startup drops the staging and keeps the current data.
+    assert!(apply_pending_restore(&data_dir).unwrap().is_none());
+    assert!(!data_dir.join("restore_pending").exists());
+    assert_eq!(
+        fs::read_to_string(data_dir.join("settings.toml")).unwrap(),
+        "theme = \"light\"\n"
+    );
+
+    stage_restore(&pool, &data_dir, &archive, PASSPHRASE)
+        .await
+        .unwrap();
+    confirm_staged_restore(&data_dir).unwrap();
+    assert!(apply_pending_restore(&data_dir).unwrap().is_some());
+    assert_eq!(
+        fs::read_to_string(data_dir.join("settings.toml")).unwrap(),
+        "theme = \"dark\"\n"
+    );
+    let _ = fs::remove_dir_all(root);
+}
+
+#[tokio::test]
+async fn damaged_archive_is_rejected_before_staging() {
+    let (root, data_dir) = temp_root();
+    let pool = test_pool(&root).await;
+    seed_db(&pool, 1).await;
+    let archive = root.join("backup.mxbak");
+    create_backup(&pool, &data_dir, &archive, PASSPHRASE, TEST_ITERATIONS)
+        .await
+        .unwrap();
+
+    let mut bytes = fs::read(&archive).unwrap();
+    let last = bytes.len() - 20;
+    bytes[last] ^= 0xff;
+    fs::write(&archive, bytes).unwrap();
+
+    assert!(stage_restore(&pool, &data_dir, &archive, PASSPHRASE)
+        .await
+        .is_err());
+    assert!(!data_dir.join("restore_pending").exists());
+    let _ = fs::remove_dir_all(root);
+}
@@ -0,0 +1,276 @@
+// Blossom upload ranking, mirroring and post-trade deletion against local stand-in servers
+mod common;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use mostrix::util::blossom::{upload_blob_with_retry, UploadedBlobLocations};
+use mostrix::util::blossom_cleanup::{load_uploaded_blobs, record_uploaded_blob};
+use mostrix::util::{run_blossom_cleanup, BlossomHealth};
+use nostr_sdk::prelude::*;
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Blobs held by a stand-in server: sha256 hex → uploader pubkey.
+type BlobStore = Arc<Mutex<HashMap<String, PublicKey>>>;
+
+/// Minimal BUD-02 server: `PUT /upload` stores, `DELETE /<sha256>` removes when signed by the
+/// uploader. With `failing`, every request gets a 500.
+async fn start_blossom_server(failing: bool) -> (String, BlobStore) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://{}", listener.local_addr().unwrap());
+    let store: BlobStore = Arc::new(Mutex::new(HashMap::new()));
+    let blobs = Arc::clone(&store);
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio::spawn(serve_blossom(stream, Arc::clone(&blobs), failing));
+        }
+    });
+    (base, store)
+}
+
+async fn serve_blossom(mut stream: TcpStream, blobs: BlobStore, failing: bool) {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let n = stream.read(&mut chunk).await.unwrap();
+        if n == 0 {
+            return;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let mut lines = head.lines();
+    let mut request_line = lines.next().unwrap().split_whitespace();
+    let method = request_line.next().unwrap().to_string();
+    let path = request_line.next().unwrap().to_string();
+    let headers: HashMap<String, String> = lines
+        .filter_map(|l| l.split_once(':'))
+        .map(|(k, v)| (k.trim().to_ascii_lowercase(), v.trim().to_string()))
+        .collect();
+    let content_length: usize = headers
+        .get("content-length")
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+    while buf.len() < header_end + content_length {
+        let n = stream.read(&mut chunk).await.unwrap();
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    let body = &buf[header_end..];
+
+    let status = if failing {
+        "500 Internal Server Error"
+    } else {
+        let auth = headers.get("authorization").and_then(|h| auth_event(h));
+        match (method.as_str(), auth) {
+            (_, None) => "401 Unauthorized",
+            ("PUT", Some((event, verb))) if path == "/upload" && verb == "upload" => {
+                let hash = hex::encode(Sha256::digest(body));
+                blobs.lock().unwrap().insert(hash, event.pubkey);
+                "200 OK"
+            }
+            ("DELETE", Some((event, verb))) if verb == "delete" => {
+                let hash = path.trim_start_matches('/');
+                let mut blobs = blobs.lock().unwrap();
+                match blobs.get(hash) {
+                    None => "404 Not Found",
+                    Some(owner) if *owner == event.pubkey => {
+                        blobs.remove(hash);
+                        "200 OK"
+                    }
+                    Some(_) => "403 Forbidden",
+                }
+            }
+            _ => "400 Bad Request",
+        }
+    };
+    let response = format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+    let _ = stream.write_all(response.as_bytes()).await;
+}
+
+/// Decodes `Nostr <base64 event>` into the signed kind-24242 event and its `t` verb.
+fn auth_event(header: &str) -> Option<(Event, String)> {
+    let json = BASE64.decode(header.strip_prefix("Nostr ")?).ok()?;
+    let event = Event::from_json(json).ok()?;
+    event.verify().ok()?;
+    if event.kind != Kind::Custom(24242) {
+        return None;
+    }
+    let verb = event
+        .tags
+        .iter()
+        .map(|t| t.as_slice())
+        .find(|t| t.first().map(String::as_str) == Some("t"))?
+        .get(1)?
+        .clone();
+    Some((event, verb))
+}
+
+async fn insert_order(pool: &sqlx::SqlitePool, id: &str, status: &str, trade_keys: &Keys) {
+    sqlx::query(
+        r#"INSERT INTO orders (id, kind, status, amount, fiat_code, fiat_amount, payment_method,
+            premium, trade_keys, is_mine)
+           VALUES (?, 'buy', ?, 0, 'USD', 10, 'cash', 0, ?, 1)"#,
+    )
+    .bind(id)
+    .bind(status)
+    .bind(trade_keys.secret_key().to_secret_hex())
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+fn http() -> reqwest::Client {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    reqwest::Client::new()
+}
+
+fn temp_registry() -> std::path::PathBuf {
+    std::env::temp_dir()
+        .join(format!("mostrix_blossom_{}", uuid::Uuid::new_v4()))
+        .join("blossom_uploads.json")
+}
+
+#[tokio::test]
+async fn upload_skips_failing_server_and_demotes_it() {
+    let (down, _) = start_blossom_server(true).await;
+    let (up, store) = start_blossom_server(false).await;
+    let keys = Keys::generate();
+    let http = http();
+    let mut health = BlossomHealth::default();
+    let servers = vec![down.clone(), up.clone()];
+
+    let first = upload_blob_with_retry(&http, &servers, b"blob one", &keys, false, &mut health)
+        .await
+        .unwrap();
+    assert_eq!(first.servers, vec![up.clone()]);
+    assert_eq!(first.url, format!("{up}/{}", first.hash));
+    assert_eq!(health.get(&down).unwrap().consecutive_failures, 1);
+    assert_eq!(health.rank(&servers), vec![up.clone(), down.clone()]);
+
+    // The healthy server is tried first now, so the failing one is not contacted again.
+    upload_blob_with_retry(&http, &servers, b"blob two", &keys, false, &mut health)
+        .await
+        .unwrap();
+    assert_eq!(health.get(&down).unwrap().failures, 1);
+    assert_eq!(health.get(&up).unwrap().successes, 2);
+    assert_eq!(store.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn mirror_keeps_a_copy_on_a_second_server() {
+    let (a, store_a) = start_blossom_server(false).await;
+    let (down, _) = start_blossom_server(true).await;
+    let (b, store_b) = start_blossom_server(false).await;
+    let keys = Keys::generate();
+    let http = http();
+    let mut health = BlossomHealth::default();
+
+    let locations = upload_blob_with_retry(
+        &http,
+        &[a.clone(), down, b.clone()],
+        b"mirrored",
+        &keys,
+        true,
+        &mut health,
+    )
+    .await
+    .unwrap();
+    assert_eq!(locations.servers, vec![a, b]);
+    assert!(store_a.lock().unwrap().contains_key(&locations.hash));
+    assert!(store_b.lock().unwrap().contains_key(&locations.hash));
+}
+
+#[tokio::test]
+async fn cleanup_deletes_blobs_of_finished_trades_only() {
+    let pool = common::create_test_db().await.unwrap();
+    let (server, store) = start_blossom_server(false).await;
+    let http = http();
+    let registry = temp_registry();
+
+    let done_keys = Keys::generate();
+    let active_keys = Keys::generate();
+    insert_order(&pool, "order-done", "success", &done_keys).await;
+    insert_order(&pool, "order-active", "active", &active_keys).await;
+
+    let mut health = BlossomHealth::default();
+    for (order_id, keys, blob) in [
+        ("order-done", &done_keys, b"done blob".as_slice()),
+        ("order-active", &active_keys, b"active blob".as_slice()),
+        ("order-gone", &done_keys, b"orphan blob".as_slice()),
+    ] {
+        let locations: UploadedBlobLocations = upload_blob_with_retry(
+            &http,
+            std::slice::from_ref(&server),
+            blob,
+            keys,
+            false,
+            &mut health,
+        )
+        .await
+        .unwrap();
+        record_uploaded_blob(&registry, order_id, &locations);
+    }
+    assert_eq!(store.lock().unwrap().len(), 3);
+
+    let summary = run_blossom_cleanup(&pool, &http, &registry).await;
+    assert_eq!(
+        (summary.deleted, summary.failed, summary.waiting),
+        (1, 0, 1)
+    );
+
+    let remaining = load_uploaded_blobs(&registry);
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].order_id, "order-active");
+    // The finished trade's blob is gone; the orphan is dropped from the records but left on
+    // the server since its trade key no longer exists locally.
+    let stored = store.lock().unwrap();
+    assert_eq!(stored.len(), 2);
+    assert!(stored.contains_key(&remaining[0].hash));
+
+    let _ = std::fs::remove_dir_all(registry.parent().unwrap());
+}
+
+#[tokio::test]
+async fn cleanup_keeps_records_when_the_order_lookup_fails() {
+    let pool = common::create_test_db().await.unwrap();
+    let (server, store) = start_blossom_server(false).await;
+    let http = http();
+    let registry = temp_registry();
+
+    let keys = Keys::generate();
+    insert_order(&pool, "order-done", "success", &keys).await;
+    let locations = upload_blob_with_retry(
+        &http,
+        std::slice::from_ref(&server),
+        b"done blob",
+        &keys,
+        false,
+        &mut BlossomHealth::default(),
+    )
+    .await
+    .unwrap();
+    record_uploaded_blob(&registry, "order-done", &locations);
+
+    // A lookup that errors (here: closed pool) is not a deleted order.
+    pool.close().await;
+    let summary = run_blossom_cleanup(&pool, &http, &registry).await;
+    assert_eq!(
+        (summary.deleted, summary.failed, summary.waiting),
+        (0, 0, 1)
+    );
+    assert_eq!(load_uploaded_blobs(&registry).len(), 1);
+    assert_eq!(store.lock().unwrap().len(), 1);
+
+    let _ = std::fs::remove_dir_all(registry.parent().unwrap());
+}
@@ -1,11 +1,25 @@
 // Shared test utilities for Mostrix tests
+use std::path::Path;
+
 use anyhow::Result;
-use sqlx::sqlite::SqlitePool;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool};
 
 /// Create an in-memory SQLite database for testing
+#[allow(dead_code)]
 pub async fn create_test_db() -> Result<SqlitePool> {
-    let pool = SqlitePool::connect("sqlite::memory:").await?;
+    create_schema(SqlitePool::connect("sqlite::memory:").await?).await
+}
+
+/// Create a file-backed SQLite database for tests that need a real database file
+#[allow(dead_code)]
+pub async fn create_test_db_file(path: &Path) -> Result<SqlitePool> {
+    let options = SqliteConnectOptions::new()
+        .filename(path)
+        .create_if_missing(true);
+    create_schema(SqlitePool::connect_with(options).await?).await
+}
 
+async fn create_schema(pool: SqlitePool) -> Result<SqlitePool> {
     // Create tables matching the production schema
     sqlx::query(
         r#"
@@ -49,6 +63,7 @@ pub async fn create_test_db() -> Result<SqlitePool> {
 }
 
 /// Generate a test mnemonic for testing
+#[allow(dead_code)]
 pub fn test_mnemonic() -> String {
     "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about"
         .to_string()
@@ -0,0 +1,381 @@
+// End-to-end tests of the order state machine against the `--demo` relay and Mostro simulator
+use std::pin::Pin;
+use std::time::Duration;
+
+use futures::{Stream, StreamExt};
+use mostrix::demo::{demo_invoice, DemoRelay, MostroSimulator, SimulatorConfig};
+use mostrix::util::order_utils::order_from_tags;
+use mostro_core::prelude::*;
+use nostr_sdk::prelude::Kind;
+use nostr_sdk::prelude::*;
+use uuid::Uuid;
+
+const REPLY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A Mostrix-side trade key talking to the simulator over its transport.
+struct TestTrader {
+    keys: Keys,
+    client: Client,
+    notifications: Pin<Box<dyn Stream<Item = ClientNotification> + Send>>,
+    mostro: PublicKey,
+    transport: Transport,
+    next_request_id: u64,
+}
+
+impl TestTrader {
+    async fn connect(relay: &DemoRelay, simulator: &MostroSimulator) -> Self {
+        let keys = Keys::generate();
+        let client = Client::new();
+        client.add_relay(relay.url()).await.unwrap();
+        client.connect().and_wait(REPLY_TIMEOUT).await;
+        let notifications = client.notifications();
+        client
+            .subscribe(
+                Filter::new()
+                    .kind(simulator.transport().event_kind())
+                    .pubkey(keys.public_key()),
+            )
+            .await
+            .unwrap();
+        Self {
+            keys,
+            client,
+            notifications,
+            mostro: simulator.pubkey(),
+            transport: simulator.transport(),
+            next_request_id: 1,
+        }
+    }
+
+    async fn send(&mut self, order_id: Option<Uuid>, action: Action, payload: Option<Payload>) {
+        let request_id = self.next_request_id;
+        self.next_request_id += 1;
+        let message = Message::new_order(order_id, Some(request_id), None, action, payload);
+        let event = wrap_message_with(
+            self.transport,
+            &message,
+            &self.keys,
+            &self.keys,
+            self.mostro,
+            WrapOptions::default(),
+        )
+        .await
+        .unwrap();
+        self.client.send_event(&event).await.unwrap();
+    }
+
+    /// Next message from Mostro; panics after [`REPLY_TIMEOUT`].
+    async fn recv(&mut self) -> MessageKind {
+        tokio::time::timeout(REPLY_TIMEOUT, async {
+            loop {
+                let ClientNotification::Event { event, .. } =
+                    self.notifications.next().await.expect("client shut down")
+                else {
+                    continue;
+                };
+                if let Ok(Some(unwrapped)) = unwrap_incoming(&event, &self.keys).await {
+                    assert_eq!(unwrapped.sender, self.mostro);
+                    return unwrapped.message.get_inner_message_kind().clone();
+                }
+            }
+        })
+        .await
+        .expect("timed out waiting for a Mostro reply")
+    }
+
+    async fn expect(&mut self, action: Action) -> MessageKind {
+        let kind = self.recv().await;
+        assert_eq!(kind.action, action, "unexpected reply: {kind:?}");
+        kind
+    }
+}
+
+async fn start_with(config: SimulatorConfig) -> (DemoRelay, MostroSimulator) {
+    let relay = DemoRelay::start().await.unwrap();
+    let simulator = MostroSimulator::start(
+        &relay.url(),
+        SimulatorConfig {
+            peer_delay: Duration::from_millis(20),
+            ..config
+        },
+    )
+    .await
+    .unwrap();
+    (relay, simulator)
+}
+
+async fn start(transport: Transport) -> (DemoRelay, MostroSimulator) {
+    start_with(SimulatorConfig {
+        transport,
+        ..SimulatorConfig::default()
+    })
+    .await
+}
+
+fn order_request(kind: mostro_core::order::Kind, fiat_code: &str, fiat_amount: i64) -> SmallOrder {
+    SmallOrder::new(
+        None,
+        Some(kind),
+        Some(Status::Pending),
+        0,
+        fiat_code.to_string(),
+        None,
+        None,
+        fiat_amount,
+        "Bank transfer".to_string(),
+        0,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+fn both_transports() -> [Transport; 2] {
+    #[allow(deprecated)]
+    let v1 = Transport::GiftWrap;
+    [v1, Transport::Nip44Direct]
+}
+
+async fn pending_order(simulator: &MostroSimulator, kind: mostro_core::order::Kind) -> SmallOrder {
+    simulator
+        .pending_peer_orders()
+        .await
+        .into_iter()
+        .find(|o| o.kind == Some(kind) && o.min_amount.is_none())
+        .expect("seeded order book has a fixed-amount order of this kind")
+}
+
+/// Latest status the simulator published for `order_id`, parsed the way the order book does.
+fn published_status(relay: &DemoRelay, simulator: &MostroSimulator, order_id: Uuid) -> Status {
+    let events = relay.stored_events(
+        Filter::new()
+            .author(simulator.pubkey())
+            .kind(Kind::Custom(NOSTR_ORDER_EVENT_KIND))
+            .identifier(order_id.to_string()),
+    );
+    let event = events.first().expect("order event published");
+    order_from_tags(event.tags.clone())
+        .unwrap()
+        .status
+        .expect("status tag")
+}
+
+#[tokio::test]
+async fn test_demo_publishes_info_and_seeded_order_book() {
+    let (relay, simulator) = start(Transport::Nip44Direct).await;
+
+    let info = relay.stored_events(
+        Filter::new()
+            .author(simulator.pubkey())
+            .kind(Kind::Custom(NOSTR_INFO_EVENT_KIND)),
+    );
+    assert_eq!(info.len(), 1);
+
+    let orders: Vec<SmallOrder> = relay
+        .stored_events(
+            Filter::new()
+                .author(simulator.pubkey())
+                .kind(Kind::Custom(NOSTR_ORDER_EVENT_KIND)),
+        )
+        .into_iter()
+        .map(|e| order_from_tags(e.tags).unwrap())
+        .collect();
+    assert_eq!(orders.len(), simulator.pending_peer_orders().await.len());
+    assert!(orders.iter().all(|o| o.status == Some(Status::Pending)));
+    assert!(orders.iter().any(|o| o.min_amount.is_some()));
+}
+
+#[tokio::test]
+async fn test_demo_buyer_takes_sell_order_to_completion() {
+    for transport in both_transports() {
+        let (relay, simulator) = start(transport).await;
+        let mut buyer = TestTrader::connect(&relay, &simulator).await;
+        let order = pending_order(&simulator, mostro_core::order::Kind::Sell).await;
+        let order_id = order.id.unwrap();
+
+        buyer.send(Some(order_id), Action::TakeSell, None).await;
+        let add_invoice = buyer.expect(Action::AddInvoice).await;
+        assert_eq!(add_invoice.request_id, Some(1));
+        let Some(Payload::Order(taken)) = add_invoice.payload else {
+            panic!("AddInvoice carries the order");
+        };
+        assert!(taken.amount > 0);
+
+        let invoice = demo_invoice(taken.amount, "payout");
+        buyer
+            .send(
+                Some(order_id),
+                Action::AddInvoice,
+                Some(Payload::PaymentRequest(None, invoice, None)),
+            )
+            .await;
+        buyer.expect(Action::WaitingSellerToPay).await;
+        buyer.expect(Action::HoldInvoicePaymentAccepted).await;
+        assert_eq!(
+            published_status(&relay, &simulator, order_id),
+            Status::Active
+        );
+
+        buyer.send(Some(order_id), Action::FiatSent, None).await;
+        let fiat_sent = buyer.expect(Action::FiatSentOk).await;
+        assert!(matches!(fiat_sent.payload, Some(Payload::Peer(_))));
+        buyer.expect(Action::Released).await;
+        buyer.expect(Action::PurchaseCompleted).await;
+        buyer.expect(Action::Rate).await;
+        assert_eq!(
+            published_status(&relay, &simulator, order_id),
+            Status::Success
+        );
+
+        buyer
+            .send(
+                Some(order_id),
+                Action::RateUser,
+                Some(Payload::RatingUser(5)),
+            )
+            .await;
+        buyer.expect(Action::RateReceived).await;
+    }
+}
+
+#[tokio::test]
+async fn test_demo_seller_takes_buy_order_and_releases() {
+    for transport in both_transports() {
+        let (relay, simulator) = start(transport).await;
+        let mut seller = TestTrader::connect(&relay, &simulator).await;
+        let order = pending_order(&simulator, mostro_core::order::Kind::Buy).await;
+        let order_id = order.id.unwrap();
+
+        seller.send(Some(order_id), Action::TakeBuy, None).await;
+        let pay = seller.expect(Action::PayInvoice).await;
+        assert!(matches!(
+            pay.payload,
+            Some(Payload::PaymentRequest(Some(_), _, _))
+        ));
+        seller.expect(Action::BuyerTookOrder).await;
+        seller.expect(Action::FiatSentOk).await;
+        assert_eq!(
+            published_status(&relay, &simulator, order_id),
+            Status::FiatSent
+        );
+
+        seller.send(Some(order_id), Action::Release, None).await;
+        seller.expect(Action::HoldInvoicePaymentSettled).await;
+        seller.expect(Action::Rate).await;
+        assert_eq!(
+            published_status(&relay, &simulator, order_id),
+            Status::Success
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_demo_new_sell_order_is_taken_by_simulated_buyer() {
+    let (relay, simulator) = start(Transport::Nip44Direct).await;
+    let mut maker = TestTrader::connect(&relay, &simulator).await;
+    let order = order_request(mostro_core::order::Kind::Sell, "EUR", 40);
+
+    maker
+        .send(None, Action::NewOrder, Some(Payload::Order(order)))
+        .await;
+    let created = maker.expect(Action::NewOrder).await;
+    let order_id = created.id.expect("new order id");
+    maker.expect(Action::PayInvoice).await;
+    maker.expect(Action::BuyerTookOrder).await;
+    maker.expect(Action::FiatSentOk).await;
+    assert_eq!(
+        simulator.order(order_id).await.and_then(|o| o.status),
+        Some(Status::FiatSent)
+    );
+}
+
+#[tokio::test]
+async fn test_demo_dispute_active_trade() {
+    let (relay, simulator) = start(Transport::Nip44Direct).await;
+    let mut buyer = TestTrader::connect(&relay, &simulator).await;
+    let order = pending_order(&simulator, mostro_core::order::Kind::Sell).await;
+    let order_id = order.id.unwrap();
+
+    buyer
+        .send(
+            Some(order_id),
+            Action::TakeSell,
+            Some(Payload::PaymentRequest(
+                None,
+                demo_invoice(1_000, "payout"),
+                None,
+            )),
+        )
+        .await;
+    buyer.expect(Action::WaitingSellerToPay).await;
+    buyer.expect(Action::HoldInvoicePaymentAccepted).await;
+
+    buyer.send(Some(order_id), Action::Dispute, None).await;
+    let dispute = buyer.expect(Action::DisputeInitiatedByYou).await;
+    assert!(matches!(dispute.payload, Some(Payload::Dispute(_, None))));
+    assert_eq!(
+        published_status(&relay, &simulator, order_id),
+        Status::Dispute
+    );
+    let disputes = relay.stored_events(
+        Filter::new()
+            .author(simulator.pubkey())
+            .kind(Kind::Custom(NOSTR_DISPUTE_EVENT_KIND)),
+    );
+    assert_eq!(disputes.len(), 1);
+}
+
+#[tokio::test]
+async fn test_demo_cancel_pending_order() {
+    let (relay, simulator) = start_with(SimulatorConfig {
+        peer_takes_new_orders: false,
+        ..SimulatorConfig::default()
+    })
+    .await;
+    let mut maker = TestTrader::connect(&relay, &simulator).await;
+    let mut stranger = TestTrader::connect(&relay, &simulator).await;
+
+    maker
+        .send(
+            None,
+            Action::NewOrder,
+            Some(Payload::Order(order_request(
+                mostro_core::order::Kind::Buy,
+                "USD",
+                10,
+            ))),
+        )
+        .await;
+    let order_id = maker.expect(Action::NewOrder).await.id.unwrap();
+
+    stranger.send(Some(order_id), Action::Cancel, None).await;
+    let refused = stranger.expect(Action::CantDo).await;
+    assert!(matches!(
+        refused.payload,
+        Some(Payload::CantDo(Some(CantDoReason::IsNotYourOrder)))
+    ));
+
+    maker.send(Some(order_id), Action::Cancel, None).await;
+    maker.expect(Action::Canceled).await;
+    assert_eq!(
+        published_status(&relay, &simulator, order_id),
+        Status::Canceled
+    );
+}
+
+#[tokio::test]
+async fn test_demo_rejects_unknown_currency() {
+    let (relay, simulator) = start(Transport::Nip44Direct).await;
+    let mut maker = TestTrader::connect(&relay, &simulator).await;
+    let order = order_request(mostro_core::order::Kind::Buy, "JPY", 1_000);
+    maker
+        .send(None, Action::NewOrder, Some(Payload::Order(order)))
+        .await;
+    let refused = maker.expect(Action::CantDo).await;
+    assert!(matches!(
+        refused.payload,
+        Some(Payload::CantDo(Some(CantDoReason::InvalidFiatCurrency)))
+    ));
+}
@@ -0,0 +1,103 @@
+// Local JSON-RPC socket: request forwarding, access control and the DM event stream
+#![cfg(unix)]
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use mostrix::util::local_api::{LocalApiMethod, FORBIDDEN};
+use mostrix::util::{start_local_api_server, LocalApiAccess, LocalApiCall};
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::unix::OwnedReadHalf;
+use tokio::net::UnixStream;
+use tokio::sync::{broadcast, mpsc};
+
+fn socket_path() -> PathBuf {
+    std::env::temp_dir()
+        .join(format!("mostrix-api-{}", uuid::Uuid::new_v4()))
+        .join("api")
+        .join("mostrix.sock")
+}
+
+async fn read_json(lines: &mut Lines<BufReader<OwnedReadHalf>>) -> Value {
+    let line = tokio::time::timeout(Duration::from_secs(5), lines.next_line())
+        .await
+        .expect("answer within 5s")
+        .unwrap()
+        .expect("connection open");
+    serde_json::from_str(&line).unwrap()
+}
+
+#[tokio::test]
+async fn test_local_api_forwards_reads_refuses_writes_and_streams_events() {
+    let path = socket_path();
+    // A stale socket left by a crashed run must not block startup.
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+
+    let (call_tx, mut call_rx) = mpsc::unbounded_channel::<LocalApiCall>();
+    let (events_tx, _) = broadcast::channel::<String>(16);
+    let server = start_local_api_server(&path, LocalApiAccess::Read, call_tx, events_tx.clone())
+        .await
+        .unwrap();
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+
+    // Stand-in for the main loop.
+    tokio::spawn(async move {
+        while let Some(call) = call_rx.recv().await {
+            let _ = call
+                .reply
+                .send(Ok(json!({ "method": call.method.as_str() })));
+        }
+    });
+
+    let (read_half, mut write_half) = UnixStream::connect(&path).await.unwrap().into_split();
+    let mut lines = BufReader::new(read_half).lines();
+
+    write_half
+        .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"get_orders\"}\n")
+        .await
+        .unwrap();
+    let answer = read_json(&mut lines).await;
+    assert_eq!(answer["id"], json!(1));
+    assert_eq!(
+        answer["result"]["method"],
+        json!(LocalApiMethod::GetOrders.as_str())
+    );
+
+    write_half
+        .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":2,\"method\":\"new_order\",\"params\":{}}\n")
+        .await
+        .unwrap();
+    let answer = read_json(&mut lines).await;
+    assert_eq!(answer["error"]["code"], json!(FORBIDDEN));
+
+    write_half
+        .write_all(b"{\"jsonrpc\":\"2.0\",\"id\":3,\"method\":\"subscribe_events\"}\n")
+        .await
+        .unwrap();
+    let answer = read_json(&mut lines).await;
+    assert_eq!(answer["result"]["subscribed"], json!(true));
+
+    events_tx
+        .send(
+            json!({ "jsonrpc": "2.0", "method": "event", "params": { "type": "trade-dm" } })
+                .to_string(),
+        )
+        .unwrap();
+    let event = read_json(&mut lines).await;
+    assert_eq!(event["method"], json!("event"));
+
+    // A second client while this one runs is refused.
+    let (second_tx, _) = mpsc::unbounded_channel::<LocalApiCall>();
+    assert!(
+        start_local_api_server(&path, LocalApiAccess::Read, second_tx, events_tx)
+            .await
+            .is_err()
+    );
+
+    server.abort();
+    let _ = std::fs::remove_dir_all(path.parent().unwrap());
+}
@@ -0,0 +1,124 @@
+// Relay traffic through the `proxy` setting, using a minimal SOCKS5 server in front of the demo relay
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use mostrix::demo::DemoRelay;
+use mostrix::settings::Settings;
+use mostrix::util::{active_proxy, any_relay_reachable, init_proxy, nostr_client_builder};
+use nostr_sdk::prelude::*;
+use tokio::io::{copy_bidirectional, AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const USER: &str = "mostrix";
+const PASSWORD: &str = "s3cret";
+
+/// Accepts SOCKS5 CONNECT with username/password auth (RFC 1928/1929) and counts tunnels.
+async fn start_socks5_server() -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    let tunnels = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&tunnels);
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            let counter = Arc::clone(&counter);
+            tokio::spawn(serve_socks5(stream, counter));
+        }
+    });
+    (addr, tunnels)
+}
+
+async fn serve_socks5(mut client: TcpStream, tunnels: Arc<AtomicUsize>) -> std::io::Result<()> {
+    let bad = || std::io::Error::other("bad socks request");
+
+    let mut head = [0u8; 2];
+    client.read_exact(&mut head).await?;
+    let mut methods = vec![0u8; head[1] as usize];
+    client.read_exact(&mut methods).await?;
+    if !methods.contains(&0x02) {
+        client.write_all(&[0x05, 0xff]).await?;
+        return Err(bad());
+    }
+    client.write_all(&[0x05, 0x02]).await?;
+
+    let mut ver_ulen = [0u8; 2];
+    client.read_exact(&mut ver_ulen).await?;
+    let mut user = vec![0u8; ver_ulen[1] as usize];
+    client.read_exact(&mut user).await?;
+    let mut plen = [0u8; 1];
+    client.read_exact(&mut plen).await?;
+    let mut password = vec![0u8; plen[0] as usize];
+    client.read_exact(&mut password).await?;
+    if user != USER.as_bytes() || password != PASSWORD.as_bytes() {
+        client.write_all(&[0x01, 0x01]).await?;
+        return Err(bad());
+    }
+    client.write_all(&[0x01, 0x00]).await?;
+
+    let mut req = [0u8; 4];
+    client.read_exact(&mut req).await?;
+    let host = match req[3] {
+        0x01 => {
+            let mut ip = [0u8; 4];
+            client.read_exact(&mut ip).await?;
+            std::net::Ipv4Addr::from(ip).to_string()
+        }
+        0x03 => {
+            let mut len = [0u8; 1];
+            client.read_exact(&mut len).await?;
+            let mut name = vec![0u8; len[0] as usize];
+            client.read_exact(&mut name).await?;
+            String::from_utf8(name).map_err(|_| bad())?
+        }
+        _ => return Err(bad()),
+    };
+    let mut port = [0u8; 2];
+    client.read_exact(&mut port).await?;
+    let mut upstream = TcpStream::connect((host.as_str(), u16::from_be_bytes(port))).await?;
+    // Counted before the reply, so the client never observes an uncounted tunnel.
+    tunnels.fetch_add(1, Ordering::SeqCst);
+    client
+        .write_all(&[0x05, 0x00, 0x00, 0x01, 0, 0, 0, 0, 0, 0])
+        .await?;
+    tokio::spawn(async move {
+        let _ = copy_bidirectional(&mut client, &mut upstream).await;
+    });
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_relay_traffic_goes_through_authenticated_socks5_proxy() {
+    let relay = DemoRelay::start().await.unwrap();
+    let (proxy_addr, tunnels) = start_socks5_server().await;
+    init_proxy(&Settings {
+        proxy: format!("socks5://{USER}:{PASSWORD}@{proxy_addr}"),
+        ..Settings::default()
+    })
+    .unwrap();
+    assert!(active_proxy().unwrap().has_auth());
+
+    assert!(any_relay_reachable(&[relay.url()]).await);
+    assert_eq!(
+        tunnels.load(Ordering::SeqCst),
+        1,
+        "probe must use the proxy"
+    );
+
+    let keys = Keys::generate();
+    let client = nostr_client_builder().build();
+    client.add_relay(relay.url()).await.unwrap();
+    client.connect().and_wait(Duration::from_secs(5)).await;
+    let note = EventBuilder::new(Kind::TextNote, "over socks")
+        .finalize(&keys)
+        .unwrap();
+    client.send_event(&note).await.unwrap();
+
+    assert_eq!(
+        tunnels.load(Ordering::SeqCst),
+        2,
+        "relay socket must use the proxy"
+    );
+    let stored = relay.stored_events(Filter::new().author(keys.public_key()));
+    assert_eq!(stored.len(), 1);
+}
@@ -0,0 +1,206 @@
+// Retention policy planning and purge against a temp data directory
+mod common;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use mostrix::models::Order;
+use mostrix::util::blossom::UploadedBlobLocations;
+use mostrix::util::blossom_cleanup::{blossom_uploads_path_in, record_uploaded_blob};
+use mostrix::util::retention::{execute_retention, plan_retention};
+use mostrix::util::RetentionPolicy;
+use uuid::Uuid;
+
+const DAY: i64 = 24 * 60 * 60;
+const NOW: i64 = 1_800_000_000;
+
+async fn insert_order(
+    pool: &sqlx::SqlitePool,
+    status: &str,
+    dispute_id: Option<&str>,
+    age_days: i64,
+) -> String {
+    let id = Uuid::new_v4().to_string();
+    sqlx::query(
+        r#"INSERT INTO orders (id, kind, status, amount, fiat_code, fiat_amount, payment_method,
+            premium, trade_keys, dispute_id, is_mine, created_at)
+           VALUES (?, 'buy', ?, 0, 'USD', 10, 'cash', 0, 'aa', ?, 1, ?)"#,
+    )
+    .bind(&id)
+    .bind(status)
+    .bind(dispute_id)
+    .bind(NOW - age_days * DAY)
+    .execute(pool)
+    .await
+    .unwrap();
+    id
+}
+
+/// Writes `path` and backdates its modification time to `age_days` before [`NOW`].
+fn write_file(path: &Path, age_days: u64) -> PathBuf {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, b"secret chat").unwrap();
+    let mtime = SystemTime::UNIX_EPOCH + Duration::from_secs(NOW as u64 - age_days * DAY as u64);
+    fs::File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(mtime)
+        .unwrap();
+    path.to_path_buf()
+}
+
+#[tokio::test]
+async fn retention_purges_expired_trades_and_files_only() {
+    let pool = common::create_test_db().await.unwrap();
+    let dir = std::env::temp_dir().join(format!("mostrix_retention_{}", Uuid::new_v4()));
+
+    let old_done = insert_order(&pool, "success", None, 100).await;
+    let old_disputed = insert_order(&pool, "settled-by-admin", Some("d-1"), 100).await;
+    let recent_done = insert_order(&pool, "success", None, 5).await;
+    let old_active = insert_order(&pool, "active", None, 100).await;
+
+    let old_done_files = [
+        write_file(&dir.join("orders_chat").join(format!("{old_done}.txt")), 1),
+        write_file(
+            &dir.join("orders_chat")
+                .join(format!("{old_done}.inner_ids")),
+            1,
+        ),
+        write_file(
+            &dir.join("user_disputes_chat")
+                .join(format!("{old_done}.txt")),
+            1,
+        ),
+        write_file(
+            &dir.join("downloads").join(format!("{old_done}_photo.png")),
+            1,
+        ),
+    ];
+    let kept = [
+        write_file(
+            &dir.join("orders_chat").join(format!("{old_disputed}.txt")),
+            100,
+        ),
+        write_file(
+            &dir.join("orders_chat").join(format!("{recent_done}.txt")),
+            100,
+        ),
+        write_file(
+            &dir.join("orders_chat").join(format!("{old_active}.txt")),
+            100,
+        ),
+        write_file(&dir.join("downloads").join("recent_doc.pdf"), 3),
+    ];
+    let orphan = write_file(
+        &dir.join("orders_chat")
+            .join(format!("{}.txt", Uuid::new_v4())),
+        60,
+    );
+    let old_download = write_file(&dir.join("downloads").join("old_doc.pdf"), 40);
+
+    let policy = RetentionPolicy {
+        trade_days: 30,
+        disputed_trade_days: 365,
+        downloads_days: 30,
+    };
+    let plan = plan_retention(&pool, policy, &dir, NOW).await.unwrap();
+    assert_eq!(plan.orders.len(), 1);
+    assert_eq!(plan.orders[0].order_id.to_string(), old_done);
+    assert_eq!(plan.orders[0].age_days, 100);
+    assert_eq!(plan.orders[0].files.len(), 4);
+    let mut loose = plan.loose_files.clone();
+    loose.sort();
+    let mut expected = vec![orphan.clone(), old_download.clone()];
+    expected.sort();
+    assert_eq!(loose, expected);
+    assert_eq!(plan.file_count(), 6);
+
+    let outcome = execute_retention(&pool, &plan, &dir).await;
+    assert_eq!(outcome.deleted_order_ids.len(), 1);
+    assert_eq!(outcome.files_removed, 6);
+    assert_eq!(outcome.failures, 0);
+
+    assert!(Order::get_by_id(&pool, &old_done).await.is_err());
+    for id in [&old_disputed, &recent_done, &old_active] {
+        assert!(Order::get_by_id(&pool, id).await.is_ok());
+    }
+    for path in old_done_files.iter().chain([&orphan, &old_download]) {
+        assert!(!path.exists(), "{} should be purged", path.display());
+    }
+    for path in &kept {
+        assert!(path.exists(), "{} should be kept", path.display());
+    }
+
+    // A second pass finds nothing new.
+    assert!(plan_retention(&pool, policy, &dir, NOW)
+        .await
+        .unwrap()
+        .is_empty());
+    let _ = fs::remove_dir_all(dir);
+}
+
+fn record_blob(dir: &Path, order_id: &str) {
+    let locations = UploadedBlobLocations {
+        hash: "ab".repeat(32),
+        servers: vec!["https://blossom.example".to_string()],
+        url: format!("https://blossom.example/{}", "ab".repeat(32)),
+    };
+    record_uploaded_blob(&blossom_uploads_path_in(dir), order_id, &locations);
+}
+
+#[tokio::test]
+async fn retention_waits_for_blossom_deletes_before_removing_a_trade() {
+    let pool = common::create_test_db().await.unwrap();
+    let dir = std::env::temp_dir().join(format!("mostrix_retention_{}", Uuid::new_v4()));
+    let policy = RetentionPolicy {
+        trade_days: 30,
+        ..Default::default()
+    };
+    let with_upload = insert_order(&pool, "success", None, 100).await;
+    record_blob(&dir, &with_upload);
+    let plan = plan_retention(&pool, policy, &dir, NOW).await.unwrap();
+    assert!(
+        plan.is_empty(),
+        "a trade with pending uploads is not planned"
+    );
+
+    // An upload recorded after the plan was built also keeps the row.
+    let late = insert_order(&pool, "success", None, 100).await;
+    let plan = plan_retention(&pool, policy, &dir, NOW).await.unwrap();
+    assert_eq!(plan.orders.len(), 1);
+    record_blob(&dir, &late);
+    let outcome = execute_retention(&pool, &plan, &dir).await;
+    assert!(outcome.deleted_order_ids.is_empty());
+    for id in [&with_upload, &late] {
+        assert!(Order::get_by_id(&pool, id).await.is_ok());
+    }
+
+    // Once the cleanup job has dropped the records, the trades are purged.
+    fs::remove_file(blossom_uploads_path_in(&dir)).unwrap();
+    let plan = plan_retention(&pool, policy, &dir, NOW).await.unwrap();
+    assert_eq!(plan.orders.len(), 2);
+    assert_eq!(
+        execute_retention(&pool, &plan, &dir)
+            .await
+            .deleted_order_ids
+            .len(),
+        2
+    );
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn retention_keeps_everything_when_rules_are_off() {
+    let pool = common::create_test_db().await.unwrap();
+    let dir = std::env::temp_dir().join(format!("mostrix_retention_{}", Uuid::new_v4()));
+    insert_order(&pool, "success", None, 1000).await;
+    write_file(&dir.join("downloads").join("ancient.png"), 1000);
+
+    let plan = plan_retention(&pool, RetentionPolicy::default(), &dir, NOW)
+        .await
+        .unwrap();
+    assert!(plan.is_empty());
+    let _ = fs::remove_dir_all(dir);
+}